@@ -0,0 +1,139 @@
+//! Benchmarks of `gamecore` storages.
+//!
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use ggengine::gamecore::{components::Component, identifiers::GameObjectId, ComponentStorage};
+
+/// Component that every game object has.
+///
+struct Position(f32, f32);
+impl Component for Position {}
+
+/// Component that only some game objects have.
+///
+struct Velocity(f32, f32);
+impl Component for Velocity {}
+
+/// Number of game objects in benchmarks.
+///
+const GAMEOBJECTS: usize = 10_000;
+
+/// Constructs storage in which every game object has `Position`
+/// and every `sparsity`-th game object has `Velocity`,
+/// with every other game object despawned.
+///
+fn storage(sparsity: usize) -> ComponentStorage {
+    let mut storage: ComponentStorage = ComponentStorage::new();
+    let gameobjects: Vec<GameObjectId> = (0..GAMEOBJECTS)
+        .map(|index| {
+            let gameobject: GameObjectId = storage.insert_gameobject();
+            let _ = storage.insert_component(gameobject, Position(index as f32, 0.0));
+            if index % sparsity == 0 {
+                let _ = storage.insert_component(gameobject, Velocity(1.0, 1.0));
+            }
+            gameobject
+        })
+        .collect();
+    for gameobject in gameobjects.into_iter().skip(1).step_by(2) {
+        let _ = storage.remove_gameobject(gameobject);
+    }
+    storage
+}
+
+/// Benchmarks iteration over dense and sparse columns.
+///
+fn iteration(c: &mut Criterion) {
+    let dense: ComponentStorage = storage(1);
+    let _ = c.bench_function("iterate dense column", |b| {
+        b.iter(|| {
+            dense
+                .components::<Position>()
+                .map(|(_, position)| position.0 + position.1)
+                .sum::<f32>()
+        })
+    });
+
+    let mut sparse: ComponentStorage = storage(64);
+    let _ = c.bench_function("iterate sparse column", |b| {
+        b.iter(|| {
+            sparse
+                .components::<Velocity>()
+                .map(|(_, velocity)| velocity.0)
+                .sum::<f32>()
+        })
+    });
+    let _ = c.bench_function("iterate sparse column mutably", |b| {
+        b.iter(|| {
+            for (_, velocity) in sparse.components_mut::<Velocity>() {
+                velocity.1 = black_box(velocity.0);
+            }
+        })
+    });
+}
+
+/// Benchmarks insertion and removal of game objects.
+///
+fn insertion(c: &mut Criterion) {
+    let _ = c.bench_function("spawn and despawn", |b| {
+        b.iter_batched(
+            ComponentStorage::new,
+            |mut storage| {
+                let gameobjects: Vec<GameObjectId> = (0..GAMEOBJECTS)
+                    .map(|_| {
+                        let gameobject: GameObjectId = storage.insert_gameobject();
+                        let _ = storage.insert_component(gameobject, Position(0.0, 0.0));
+                        gameobject
+                    })
+                    .collect();
+                for gameobject in gameobjects {
+                    let _ = storage.remove_gameobject(gameobject);
+                }
+                storage
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+/// Benchmarks insertion of components to freshly spawned game objects one by one and in a batch.
+///
+fn batch_insertion(c: &mut Criterion) {
+    let spawn = || {
+        let mut storage: ComponentStorage = ComponentStorage::new();
+        let gameobjects: Vec<GameObjectId> = (0..GAMEOBJECTS)
+            .map(|_| storage.insert_gameobject())
+            .collect();
+        (storage, gameobjects)
+    };
+
+    let _ = c.bench_function("insert components one by one", |b| {
+        b.iter_batched(
+            spawn,
+            |(mut storage, gameobjects)| {
+                for gameobject in gameobjects.into_iter().rev() {
+                    let _ = storage.insert_component(gameobject, Velocity(1.0, 1.0));
+                }
+                storage
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    let _ = c.bench_function("insert components in a batch", |b| {
+        b.iter_batched(
+            spawn,
+            |(mut storage, gameobjects)| {
+                let _ = storage.insert_components_batch(
+                    gameobjects
+                        .into_iter()
+                        .rev()
+                        .map(|gameobject| (gameobject, Velocity(1.0, 1.0))),
+                );
+                storage
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(benches, iteration, insertion, batch_insertion);
+criterion_main!(benches);
@@ -0,0 +1,13 @@
+//! `ggengine::aicore` module is a core that implements artificial intelligence essentials
+//! for the engine, such as pathfinding and steering.
+//!
+//! # Prelude
+//! `ggengine::aicore` prelude can be imported with `use ggengine::aicore::prelude::*`.
+//!
+
+// submodules and public re-exports
+pub mod pathfinding;
+pub mod steering;
+
+// prelude
+pub mod prelude;
@@ -0,0 +1,762 @@
+//! `aicore::pathfinding` submodule implements A* pathfinding over weighted grids ([`WeightedGrid`])
+//! and over navigation meshes that consist of convex polygons ([`NavMesh`]).
+//!
+//! Both structures return smoothed paths as [`Polyline`]s, which can be followed directly.
+//! Grid paths can be kept as [`GridPath`]s, which know whether changes of the grid
+//! have invalidated them, so agents repath only when it is needed.
+//!
+
+use crate::{
+    mathcore::{
+        floats::equal,
+        polylines::Polyline,
+        shapes::{ConvexPolygon, PolygonLike, Segment, Shape},
+        transforms::Translatable,
+        vectors::{Point, Vector2},
+    },
+    GGError,
+};
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+};
+
+/// [`Candidate`] struct is an entry of A* open set.
+///
+#[derive(Copy, Clone, Debug)]
+struct Candidate {
+    /// Estimated cost of path through node.
+    ///
+    estimate: f32,
+    /// Index of node.
+    ///
+    node: usize,
+}
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for Candidate {}
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Candidate {
+    /// Candidates are ordered in reverse, so that `BinaryHeap` pops the cheapest one.
+    ///
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .total_cmp(&self.estimate)
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+/// Finds the cheapest path between two nodes by A* algorithm.
+///
+/// `neighbours` pushes neighbours of a node with costs of moving to them,
+/// `heuristic` estimates cost of moving from node to goal (it should never overestimate).
+///
+fn astar(
+    start: usize,
+    goal: usize,
+    mut neighbours: impl FnMut(usize, &mut Vec<(usize, f32)>),
+    heuristic: impl Fn(usize) -> f32,
+) -> Option<Vec<usize>> {
+    let mut open: BinaryHeap<Candidate> = BinaryHeap::from([Candidate {
+        estimate: heuristic(start),
+        node: start,
+    }]);
+    let mut costs: HashMap<usize, f32> = HashMap::from([(start, 0.0)]);
+    let mut came_from: HashMap<usize, usize> = HashMap::new();
+    let mut buffer: Vec<(usize, f32)> = Vec::new();
+
+    while let Some(Candidate { estimate, node }) = open.pop() {
+        if node == goal {
+            let mut path: Vec<usize> = vec![goal];
+            let mut current: usize = goal;
+            while let Some(&previous) = came_from.get(&current) {
+                path.push(previous);
+                current = previous;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        let cost: f32 = costs[&node];
+        if estimate > cost + heuristic(node) {
+            // outdated entry
+            continue;
+        }
+
+        buffer.clear();
+        neighbours(node, &mut buffer);
+        for &(neighbour, step_cost) in &buffer {
+            let new_cost: f32 = cost + step_cost;
+            if costs
+                .get(&neighbour)
+                .is_some_and(|&old_cost| old_cost <= new_cost)
+            {
+                continue;
+            }
+            let _ = costs.insert(neighbour, new_cost);
+            let _ = came_from.insert(neighbour, node);
+            open.push(Candidate {
+                estimate: new_cost + heuristic(neighbour),
+                node: neighbour,
+            });
+        }
+    }
+    None
+}
+
+/// Type alias for cell of [`WeightedGrid`] (`(x, y)`).
+///
+pub type GridCell = (usize, usize);
+
+/// [`WeightedGrid`] struct is a navigation grid, whose cells have costs of moving through them.
+///
+/// Cells without cost are blocked. Grid is placed in the world by its origin (position of
+/// the left upper corner of the first cell) and size of cells.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::aicore::pathfinding::{GridCell, WeightedGrid};
+/// # use ggengine::mathcore::{polylines::Polyline, vectors::Point};
+/// let level: &str = "\
+///     .....\
+///     .###.\
+///     ..~..";
+/// let grid: WeightedGrid = WeightedGrid::from_tiles(5, 3, &level.chars().collect::<Vec<char>>(), |tile| {
+///     match tile {
+///         '.' => Some(1.0),
+///         '~' => Some(10.0),
+///         _ => None,
+///     }
+/// })
+/// .expect("Level has 15 tiles");
+///
+/// let path: Vec<GridCell> = grid.find_path((0, 2), (4, 2)).expect("Path exists");
+/// assert_eq!(path.first(), Some(&(0, 2)));
+/// assert!(!path.contains(&(2, 2))); // water is avoided
+///
+/// let smoothed: Polyline = grid
+///     .find_path_between(Point::from([0.5, 0.5]), Point::from([4.5, 0.5]))
+///     .expect("Path exists");
+/// assert_eq!(smoothed.points, [Point::from([0.5, 0.5]), Point::from([4.5, 0.5])]);
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct WeightedGrid {
+    /// Width of a grid (in cells).
+    ///
+    width: usize,
+    /// Height of a grid (in cells).
+    ///
+    height: usize,
+    /// Costs of cells (`None` corresponds to blocked cell).
+    ///
+    costs: Vec<Option<f32>>,
+    /// Versions of a grid at which cells were last changed.
+    ///
+    modified: Vec<u64>,
+    /// Version of a grid, which is increased by every change.
+    ///
+    version: u64,
+
+    /// Position of the left upper corner of a grid.
+    ///
+    pub origin: Point,
+    /// Size of a cell.
+    ///
+    pub cell_size: f32,
+    /// Whether diagonal moves are allowed.
+    ///
+    /// Diagonal moves never cut corners of blocked cells.
+    ///
+    pub allow_diagonal: bool,
+}
+impl WeightedGrid {
+    /// Constructs grid with given size, in which all cells have cost of 1.0.
+    ///
+    /// Grid starts at world origin and has cells of size 1.0.
+    ///
+    pub fn new(width: usize, height: usize) -> Self {
+        WeightedGrid {
+            width,
+            height,
+            costs: vec![Some(1.0); width * height],
+            modified: vec![0; width * height],
+            version: 0,
+
+            origin: Point::zero(),
+            cell_size: 1.0,
+            allow_diagonal: true,
+        }
+    }
+    /// Constructs grid from tiles of a tilemap (given in row-major order),
+    /// using `cost` function to find costs of cells.
+    ///
+    /// # Errors
+    /// Error is returned if number of tiles does not correspond to size of a grid
+    /// or if some cost is not positive.
+    ///
+    pub fn from_tiles<T>(
+        width: usize,
+        height: usize,
+        tiles: &[T],
+        cost: impl Fn(&T) -> Option<f32>,
+    ) -> Result<Self, GGError> {
+        if tiles.len() != width * height {
+            return Err(GGError::InvalidArgument(format!(
+                "Grid of {width}x{height} cells cannot be built from {} tiles",
+                tiles.len()
+            )));
+        }
+        let mut grid: WeightedGrid = WeightedGrid::new(width, height);
+        grid.costs = tiles.iter().map(cost).collect();
+        if grid
+            .costs
+            .iter()
+            .flatten()
+            .any(|&cost| !cost.is_finite() || cost <= 0.0)
+        {
+            return Err(GGError::InvalidArgument(String::from(
+                "Costs of cells should be positive and finite",
+            )));
+        }
+        Ok(grid)
+    }
+
+    /// Returns width of a grid (in cells).
+    ///
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    /// Returns height of a grid (in cells).
+    ///
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    /// Returns version of a grid, which is increased by every change of costs.
+    ///
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Returns index of a cell, if cell lies inside grid.
+    ///
+    fn index(&self, (x, y): GridCell) -> Option<usize> {
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+    /// Returns cost of moving through the cell (`None` if cell is blocked or lies outside grid).
+    ///
+    pub fn cost(&self, cell: GridCell) -> Option<f32> {
+        self.costs[self.index(cell)?]
+    }
+    /// Returns whether cell can be moved through.
+    ///
+    pub fn is_walkable(&self, cell: GridCell) -> bool {
+        self.cost(cell).is_some()
+    }
+    /// Sets cost of moving through the cell (`None` blocks cell).
+    ///
+    /// Non-positive and non-finite costs are ignored. Cells outside grid are ignored.
+    ///
+    pub fn set_cost(&mut self, cell: GridCell, cost: Option<f32>) {
+        let Some(index) = self.index(cell) else {
+            return;
+        };
+        if cost.is_some_and(|cost| !cost.is_finite() || cost <= 0.0) || self.costs[index] == cost {
+            return;
+        }
+        self.version += 1;
+        self.costs[index] = cost;
+        self.modified[index] = self.version;
+    }
+
+    /// Returns cell that contains given point.
+    ///
+    pub fn cell_at(&self, point: Point) -> Option<GridCell> {
+        let local: Vector2 = (point - self.origin) / self.cell_size;
+        if local.x < 0.0 || local.y < 0.0 {
+            return None;
+        }
+        let cell: GridCell = (local.x as usize, local.y as usize);
+        self.index(cell).map(|_| cell)
+    }
+    /// Returns center of the cell in the world.
+    ///
+    pub fn cell_center(&self, (x, y): GridCell) -> Point {
+        self.origin + Vector2::from([x as f32 + 0.5, y as f32 + 0.5]) * self.cell_size
+    }
+
+    /// Pushes walkable neighbours of the cell with costs of moving to them.
+    ///
+    fn neighbours(&self, index: usize, neighbours: &mut Vec<(usize, f32)>) {
+        let (x, y): (isize, isize) = ((index % self.width) as isize, (index / self.width) as isize);
+        let walkable = |dx: isize, dy: isize| -> Option<(usize, f32)> {
+            let cell: GridCell = (usize::try_from(x + dx).ok()?, usize::try_from(y + dy).ok()?);
+            Some((self.index(cell)?, self.cost(cell)?))
+        };
+
+        for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+            if let Some((neighbour, cost)) = walkable(dx, dy) {
+                neighbours.push((neighbour, cost));
+            }
+        }
+        if !self.allow_diagonal {
+            return;
+        }
+        for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+            if walkable(dx, 0).is_none() || walkable(0, dy).is_none() {
+                continue;
+            }
+            if let Some((neighbour, cost)) = walkable(dx, dy) {
+                neighbours.push((neighbour, cost * std::f32::consts::SQRT_2));
+            }
+        }
+    }
+    /// Returns lower bound of cost of moving between two cells, given the minimal cost of a cell.
+    ///
+    fn heuristic(&self, from: usize, to: usize, minimal_cost: f32) -> f32 {
+        let (dx, dy): (f32, f32) = (
+            (from % self.width).abs_diff(to % self.width) as f32,
+            (from / self.width).abs_diff(to / self.width) as f32,
+        );
+        let distance: f32 = if self.allow_diagonal {
+            dx.max(dy) + (std::f32::consts::SQRT_2 - 1.0) * dx.min(dy)
+        } else {
+            dx + dy
+        };
+        distance * minimal_cost
+    }
+
+    /// Finds the cheapest path between two cells (both cells are included).
+    ///
+    /// Cost of moving into a cell is cost of that cell (diagonal moves cost `sqrt(2)` times more).
+    ///
+    pub fn find_path(&self, start: GridCell, goal: GridCell) -> Option<Vec<GridCell>> {
+        let (start_index, goal_index): (usize, usize) = (self.index(start)?, self.index(goal)?);
+        if !self.is_walkable(start) || !self.is_walkable(goal) {
+            return None;
+        }
+        let minimal_cost: f32 = self
+            .costs
+            .iter()
+            .flatten()
+            .copied()
+            .fold(f32::INFINITY, f32::min);
+        let path: Vec<usize> = astar(
+            start_index,
+            goal_index,
+            |index, neighbours| self.neighbours(index, neighbours),
+            |index| self.heuristic(index, goal_index, minimal_cost),
+        )?;
+        Some(
+            path.into_iter()
+                .map(|index| (index % self.width, index / self.width))
+                .collect(),
+        )
+    }
+
+    /// Returns cells that are crossed by segment (supercover line).
+    ///
+    fn crossed_cells(&self, from: Point, to: Point) -> Vec<GridCell> {
+        let (from, to): (Vector2, Vector2) = (
+            (from - self.origin) / self.cell_size,
+            (to - self.origin) / self.cell_size,
+        );
+        let steps: usize = ((to - from).magnitude() * 4.0).ceil() as usize + 1;
+        let mut cells: Vec<GridCell> = Vec::new();
+        for step in 0..=steps {
+            let point: Vector2 = from + (to - from) * (step as f32 / steps as f32);
+            if point.x < 0.0 || point.y < 0.0 {
+                continue;
+            }
+            let cell: GridCell = (point.x as usize, point.y as usize);
+            if cells.last() != Some(&cell) {
+                cells.push(cell);
+            }
+        }
+        cells
+    }
+    /// Smooths path of cells into polyline by removing waypoints that can be skipped.
+    ///
+    /// Shortcut is taken only if it crosses walkable cells that are not more expensive
+    /// than cells of the skipped part of the path.
+    ///
+    pub fn smooth_path(&self, path: &[GridCell], start: Point, goal: Point) -> Polyline {
+        let mut points: Vec<Point> = path.iter().map(|&cell| self.cell_center(cell)).collect();
+        if let Some(first) = points.first_mut() {
+            *first = start;
+        }
+        if let Some(last) = points.last_mut() {
+            *last = goal;
+        }
+
+        let mut smoothed: Vec<Point> = Vec::new();
+        let mut anchor: usize = 0;
+        while anchor < points.len() {
+            smoothed.push(points[anchor]);
+            let mut next: usize = anchor + 1;
+            for candidate in (anchor + 2..points.len()).rev() {
+                let limit: f32 = path[anchor..=candidate]
+                    .iter()
+                    .filter_map(|&cell| self.cost(cell))
+                    .fold(0.0, f32::max);
+                if self
+                    .crossed_cells(points[anchor], points[candidate])
+                    .into_iter()
+                    .all(|cell| self.cost(cell).is_some_and(|cost| cost <= limit))
+                {
+                    next = candidate;
+                    break;
+                }
+            }
+            anchor = next;
+        }
+        Polyline::from(smoothed)
+    }
+    /// Finds path between two points of the world and returns it as smoothed polyline.
+    ///
+    pub fn find_path_between(&self, start: Point, goal: Point) -> Option<Polyline> {
+        let path: Vec<GridCell> = self.find_path(self.cell_at(start)?, self.cell_at(goal)?)?;
+        Some(self.smooth_path(&path, start, goal))
+    }
+}
+
+/// [`GridPath`] struct is a path on [`WeightedGrid`] that supports incremental repathing.
+///
+/// Path remembers version of a grid at which it was found, so it can cheaply check
+/// whether its cells were changed afterwards.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::aicore::pathfinding::{GridPath, WeightedGrid};
+/// let mut grid: WeightedGrid = WeightedGrid::new(5, 5);
+/// let mut path: GridPath = GridPath::find(&grid, (0, 0), (4, 0)).expect("Path exists");
+///
+/// grid.set_cost((2, 4), None);
+/// assert!(!path.needs_repath(&grid)); // change does not affect path
+/// grid.set_cost((2, 0), None);
+/// assert!(path.needs_repath(&grid));
+///
+/// assert!(path.repath(&grid, (0, 0)));
+/// assert!(!path.cells().contains(&(2, 0)));
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct GridPath {
+    /// Cells of a path.
+    ///
+    cells: Vec<GridCell>,
+    /// Goal of a path.
+    ///
+    goal: GridCell,
+    /// Version of a grid at which path was found.
+    ///
+    version: u64,
+}
+impl GridPath {
+    /// Finds path between two cells.
+    ///
+    pub fn find(grid: &WeightedGrid, start: GridCell, goal: GridCell) -> Option<Self> {
+        Some(GridPath {
+            cells: grid.find_path(start, goal)?,
+            goal,
+            version: grid.version(),
+        })
+    }
+
+    /// Returns cells of a path.
+    ///
+    pub fn cells(&self) -> &[GridCell] {
+        &self.cells
+    }
+    /// Returns goal of a path.
+    ///
+    pub fn goal(&self) -> GridCell {
+        self.goal
+    }
+
+    /// Returns whether any cell of a path was changed after path was found.
+    ///
+    pub fn needs_repath(&self, grid: &WeightedGrid) -> bool {
+        grid.version() != self.version
+            && self.cells.iter().any(|&cell| {
+                grid.index(cell)
+                    .is_none_or(|index| grid.modified[index] > self.version)
+            })
+    }
+    /// Finds path again from given cell if path needs repathing, and returns whether goal is still reachable.
+    ///
+    /// If path is still valid, it is only marked as checked against current version of a grid.
+    ///
+    pub fn repath(&mut self, grid: &WeightedGrid, from: GridCell) -> bool {
+        if !self.needs_repath(grid) {
+            self.version = grid.version();
+            return true;
+        }
+        match grid.find_path(from, self.goal) {
+            Some(cells) => {
+                self.cells = cells;
+                self.version = grid.version();
+                true
+            }
+            None => {
+                self.cells.clear();
+                false
+            }
+        }
+    }
+}
+
+/// [`NavMesh`] struct is a navigation mesh that consists of convex polygons.
+///
+/// Polygons are connected if they share an edge (both vertices of an edge should coincide).
+///
+/// # Example
+/// ```rust
+/// # use ggengine::aicore::pathfinding::NavMesh;
+/// # use ggengine::mathcore::{polylines::Polyline, shapes::ConvexPolygon, vectors::Point};
+/// let square = |x: f32, y: f32| {
+///     ConvexPolygon::new(vec![
+///         Point::from([x, y]),
+///         Point::from([x + 10.0, y]),
+///         Point::from([x + 10.0, y + 10.0]),
+///         Point::from([x, y + 10.0]),
+///     ])
+///     .expect("Square is convex")
+/// };
+/// // L-shaped corridor
+/// let mesh: NavMesh = NavMesh::new(vec![square(0.0, 0.0), square(10.0, 0.0), square(10.0, 10.0)]);
+///
+/// let path: Polyline = mesh
+///     .find_path(Point::from([5.0, 5.0]), Point::from([15.0, 15.0]))
+///     .expect("Path exists");
+/// assert_eq!(
+///     path.points,
+///     [Point::from([5.0, 5.0]), Point::from([10.0, 10.0]), Point::from([15.0, 15.0])]
+/// );
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct NavMesh {
+    /// Polygons of a mesh.
+    ///
+    polygons: Vec<ConvexPolygon>,
+    /// Neighbours of every polygon with shared edges (portals).
+    ///
+    neighbours: Vec<Vec<(usize, Segment)>>,
+}
+impl NavMesh {
+    /// Constructs navigation mesh from polygons.
+    ///
+    pub fn new(polygons: Vec<ConvexPolygon>) -> Self {
+        let edges: Vec<Vec<Segment>> = polygons.iter().map(PolygonLike::edges).collect();
+        let same = |a: Point, b: Point| equal(a.x, b.x) && equal(a.y, b.y);
+
+        let mut neighbours: Vec<Vec<(usize, Segment)>> = vec![Vec::new(); polygons.len()];
+        for (i, first) in edges.iter().enumerate() {
+            for (j, second) in edges.iter().enumerate().skip(i + 1) {
+                let shared: Option<Segment> = first.iter().copied().find(|edge| {
+                    second.iter().any(|other| {
+                        same(edge.points[0], other.points[1])
+                            && same(edge.points[1], other.points[0])
+                            || same(edge.points[0], other.points[0])
+                                && same(edge.points[1], other.points[1])
+                    })
+                });
+                if let Some(portal) = shared {
+                    neighbours[i].push((j, portal));
+                    neighbours[j].push((i, Segment::from([portal.points[1], portal.points[0]])));
+                }
+            }
+        }
+        NavMesh {
+            polygons,
+            neighbours,
+        }
+    }
+
+    /// Returns polygons of a mesh.
+    ///
+    pub fn polygons(&self) -> &[ConvexPolygon] {
+        &self.polygons
+    }
+    /// Returns index of a polygon that contains given point.
+    ///
+    pub fn polygon_at(&self, point: Point) -> Option<usize> {
+        self.polygons
+            .iter()
+            .position(|polygon| polygon.contains_point(point))
+    }
+
+    /// Returns portal between two neighbouring polygons.
+    ///
+    fn portal(&self, from: usize, to: usize) -> Segment {
+        self.neighbours[from]
+            .iter()
+            .find(|(neighbour, _)| *neighbour == to)
+            .map(|(_, portal)| *portal)
+            .expect("Polygons of a path are neighbours")
+    }
+    /// Finds the shortest path between two points and returns it as polyline
+    /// that is pulled tight around corners (funnel algorithm).
+    ///
+    /// `None` is returned if points are outside mesh or are not connected.
+    ///
+    pub fn find_path(&self, start: Point, goal: Point) -> Option<Polyline> {
+        let (start_polygon, goal_polygon): (usize, usize) =
+            (self.polygon_at(start)?, self.polygon_at(goal)?);
+
+        // nodes are polygons, which are positioned at their centers (except for start and goal ones)
+        let position = |polygon: usize| -> Point {
+            if polygon == start_polygon {
+                start
+            } else if polygon == goal_polygon {
+                goal
+            } else {
+                self.polygons[polygon].origin()
+            }
+        };
+        let polygons: Vec<usize> = astar(
+            start_polygon,
+            goal_polygon,
+            |polygon, neighbours| {
+                for &(neighbour, portal) in &self.neighbours[polygon] {
+                    let midpoint: Point = portal.origin();
+                    neighbours.push((
+                        neighbour,
+                        (midpoint - position(polygon)).magnitude()
+                            + (position(neighbour) - midpoint).magnitude(),
+                    ));
+                }
+            },
+            |polygon| (goal - position(polygon)).magnitude(),
+        )?;
+
+        let mut portals: Vec<(Point, Point)> = vec![(start, start)];
+        for pair in polygons.windows(2) {
+            let portal: Segment = self.portal(pair[0], pair[1]);
+            portals.push((portal.points[0], portal.points[1]));
+        }
+        portals.push((goal, goal));
+        Some(Polyline::from(funnel(&portals)))
+    }
+}
+
+/// Pulls path through portals tight ("simple stupid funnel algorithm").
+///
+/// Every portal is given as pair of its left and right points relative to direction of movement.
+///
+fn funnel(portals: &[(Point, Point)]) -> Vec<Point> {
+    /// Returns doubled signed area of triangle.
+    ///
+    fn area(a: Point, b: Point, c: Point) -> f32 {
+        (c - a) ^ (b - a)
+    }
+
+    let mut path: Vec<Point> = vec![portals[0].0];
+    let (mut apex, mut left, mut right): (Point, Point, Point) =
+        (portals[0].0, portals[0].0, portals[0].1);
+    let (mut left_index, mut right_index): (usize, usize) = (0, 0);
+
+    let mut i: usize = 1;
+    while i < portals.len() {
+        let (new_left, new_right): (Point, Point) = portals[i];
+
+        if area(apex, right, new_right) <= 0.0 {
+            if apex == right || area(apex, left, new_right) > 0.0 {
+                right = new_right;
+                right_index = i;
+            } else {
+                apex = left;
+                path.push(apex);
+                (left, right) = (apex, apex);
+                right_index = left_index;
+                i = left_index + 1;
+                continue;
+            }
+        }
+        if area(apex, left, new_left) >= 0.0 {
+            if apex == left || area(apex, right, new_left) < 0.0 {
+                left = new_left;
+                left_index = i;
+            } else {
+                apex = right;
+                path.push(apex);
+                (left, right) = (apex, apex);
+                left_index = right_index;
+                i = right_index + 1;
+                continue;
+            }
+        }
+        i += 1;
+    }
+
+    let goal: Point = portals[portals.len() - 1].0;
+    if path.last() != Some(&goal) {
+        path.push(goal);
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GridCell, NavMesh, WeightedGrid};
+    use crate::mathcore::{shapes::ConvexPolygon, vectors::Point};
+
+    #[test]
+    fn grid_paths() {
+        assert!(WeightedGrid::from_tiles(2, 2, &[1.0, 1.0, 1.0], |&cost| Some(cost)).is_err());
+        assert!(WeightedGrid::from_tiles(1, 1, &[0.0], |&cost| Some(cost)).is_err());
+        assert!(WeightedGrid::from_tiles(1, 1, &[f32::NAN], |&cost| Some(cost)).is_err());
+
+        let mut grid: WeightedGrid = WeightedGrid::new(3, 3);
+        grid.allow_diagonal = false;
+        let path: Vec<GridCell> = grid.find_path((0, 0), (2, 2)).expect("Path exists");
+        assert_eq!(path.len(), 5);
+        assert!(path
+            .windows(2)
+            .all(|pair| pair[0].0.abs_diff(pair[1].0) + pair[0].1.abs_diff(pair[1].1) == 1));
+
+        grid.set_cost((1, 1), Some(f32::NAN));
+        grid.set_cost((1, 1), Some(f32::INFINITY));
+        assert_eq!(grid.cost((1, 1)), Some(1.0));
+
+        for y in 0..3 {
+            grid.set_cost((1, y), None);
+        }
+        assert!(grid.find_path((0, 0), (2, 2)).is_none());
+        assert!(grid.find_path((0, 0), (1, 1)).is_none());
+        assert!(grid.find_path((0, 0), (5, 5)).is_none());
+    }
+
+    #[test]
+    fn disconnected_navmesh() {
+        let triangle = |x: f32| {
+            ConvexPolygon::new(vec![
+                Point::from([x, 0.0]),
+                Point::from([x + 1.0, 0.0]),
+                Point::from([x, 1.0]),
+            ])
+            .expect("Triangle is convex")
+        };
+        let mesh: NavMesh = NavMesh::new(vec![triangle(0.0), triangle(5.0)]);
+        assert!(mesh
+            .find_path(Point::from([0.2, 0.2]), Point::from([5.2, 0.2]))
+            .is_none());
+        assert!(mesh
+            .find_path(Point::from([0.2, 0.2]), Point::from([3.0, 3.0]))
+            .is_none());
+        assert_eq!(
+            mesh.find_path(Point::from([0.2, 0.2]), Point::from([0.3, 0.1]))
+                .expect("Points are in the same polygon")
+                .points,
+            [Point::from([0.2, 0.2]), Point::from([0.3, 0.1])]
+        );
+    }
+}
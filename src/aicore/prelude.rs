@@ -0,0 +1,13 @@
+//! Prelude module: `aicore::prelude` re-exports all `ggengine::aicore` items.
+//!
+//! # Examples
+//! Import all the exports.
+//!
+//! ```rust
+//! use ggengine::aicore::prelude::*;
+//! ```
+//!
+
+// re-exports
+pub use crate::aicore::pathfinding::*;
+pub use crate::aicore::steering::*;
@@ -0,0 +1,560 @@
+//! `aicore::steering` submodule implements steering behaviours - seek, flee, arrive, pursue, evade,
+//! wander, obstacle avoidance and flocking.
+//!
+//! Every behaviour produces desired velocity of an [`Agent`]; several behaviours can be blended
+//! with [`Agent::blend`]. Desired velocity can then be fed either to physics body
+//! ([`steer_body`]) or directly to transform ([`steer_transform`]).
+//!
+
+use crate::{
+    gamecore::components::Component,
+    mathcore::{
+        random::Rng,
+        shapes::Aabb,
+        transforms::Transform2D,
+        vectors::{Point, Vector2},
+        Angle,
+    },
+    physicscore::{
+        bodies::{BodyType, RigidBody},
+        queries::{QueryFilter, QueryHit},
+        world::{BodyId, PhysicsWorld},
+    },
+};
+
+/// [`Agent`] struct describes kinematic state of an entity that is steered.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::aicore::steering::Agent;
+/// # use ggengine::mathcore::vectors::{Point, Vector2};
+/// let agent: Agent = Agent::new(Point::zero(), 2.0);
+/// assert_eq!(agent.seek(Point::from([10.0, 0.0])), Vector2::from([2.0, 0.0]));
+/// assert_eq!(agent.flee(Point::from([10.0, 0.0]), 5.0), Vector2::zero());
+/// assert_eq!(agent.arrive(Point::from([1.0, 0.0]), 4.0), Vector2::from([0.5, 0.0]));
+///
+/// let velocity: Vector2 = agent.blend([
+///     (agent.seek(Point::from([0.0, 10.0])), 1.0),
+///     (agent.seek(Point::from([10.0, 0.0])), 1.0),
+/// ]);
+/// assert!(velocity.magnitude() <= 2.0 + 1e-5);
+/// ```
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Agent {
+    /// Position of an agent.
+    ///
+    pub position: Point,
+    /// Current velocity of an agent.
+    ///
+    pub velocity: Vector2,
+    /// Radius of an agent, which is used by obstacle avoidance.
+    ///
+    pub radius: f32,
+    /// Maximal speed of an agent - desired velocities never exceed it.
+    ///
+    pub max_speed: f32,
+}
+impl Agent {
+    /// Constructs agent that stands still at given position.
+    ///
+    pub fn new(position: Point, max_speed: f32) -> Self {
+        Agent {
+            position,
+            velocity: Vector2::zero(),
+            radius: 0.0,
+            max_speed,
+        }
+    }
+    /// Constructs agent from physics body and its transform.
+    ///
+    pub fn from_body(body: &RigidBody, transform: &Transform2D, max_speed: f32) -> Self {
+        Agent {
+            position: transform.translation,
+            velocity: body.velocity,
+            radius: 0.0,
+            max_speed,
+        }
+    }
+
+    /// Returns direction in which agent is moving.
+    ///
+    fn heading(&self) -> Option<Vector2> {
+        self.velocity.try_normalize()
+    }
+
+    /// Returns velocity that moves agent towards target at maximal speed.
+    ///
+    pub fn seek(&self, target: Point) -> Vector2 {
+        (target - self.position)
+            .try_normalize()
+            .map_or(Vector2::zero(), |direction| direction * self.max_speed)
+    }
+    /// Returns velocity that moves agent away from threat at maximal speed
+    /// if threat is closer than `panic_distance`.
+    ///
+    pub fn flee(&self, threat: Point, panic_distance: f32) -> Vector2 {
+        if (self.position - threat).magnitude() > panic_distance {
+            return Vector2::zero();
+        }
+        -self.seek(threat)
+    }
+    /// Returns velocity that moves agent towards target, slowing down inside of `slowing_radius`
+    /// so agent stops at the target.
+    ///
+    pub fn arrive(&self, target: Point, slowing_radius: f32) -> Vector2 {
+        let distance: f32 = (target - self.position).magnitude();
+        if distance >= slowing_radius {
+            return self.seek(target);
+        }
+        self.seek(target) * (distance / slowing_radius)
+    }
+    /// Returns velocity that moves agent towards predicted position of target.
+    ///
+    /// Prediction assumes that target keeps its velocity for the time that agent needs to reach it.
+    ///
+    pub fn pursue(&self, target: &Agent) -> Vector2 {
+        self.seek(self.predict(target))
+    }
+    /// Returns velocity that moves agent away from predicted position of threat
+    /// if threat is closer than `panic_distance`.
+    ///
+    pub fn evade(&self, threat: &Agent, panic_distance: f32) -> Vector2 {
+        if (self.position - threat.position).magnitude() > panic_distance {
+            return Vector2::zero();
+        }
+        -self.seek(self.predict(threat))
+    }
+    /// Returns position of other agent after the time that this agent needs to reach it.
+    ///
+    fn predict(&self, other: &Agent) -> Point {
+        let distance: f32 = (other.position - self.position).magnitude();
+        let time: f32 = if self.max_speed > 0.0 {
+            distance / self.max_speed
+        } else {
+            0.0
+        };
+        other.position + other.velocity * time
+    }
+    /// Returns velocity that makes agent wander around randomly.
+    ///
+    /// State of wandering is stored in [`Wander`] component.
+    ///
+    pub fn wander(&self, wander: &mut Wander, rng: &mut Rng) -> Vector2 {
+        wander.angle +=
+            Angle::from_radians(rng.range_f32(-wander.jitter.radians(), wander.jitter.radians()));
+        let heading: Vector2 = self.heading().unwrap_or(Vector2::from([1.0, 0.0]));
+        let target: Vector2 =
+            heading * wander.distance + heading.rotate_by(wander.angle) * wander.radius;
+        target
+            .try_normalize()
+            .map_or(Vector2::zero(), |direction| direction * self.max_speed)
+    }
+
+    /// Returns velocity that moves agent sideways from the closest circular obstacle
+    /// which lies ahead of it (within `look_ahead` distance).
+    ///
+    /// Obstacles are given by their centers and radii. Velocity is zero if path is clear,
+    /// and it is the stronger the closer obstacle is.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::aicore::steering::Agent;
+    /// # use ggengine::mathcore::vectors::{Point, Vector2};
+    /// let mut agent: Agent = Agent::new(Point::zero(), 1.0);
+    /// agent.velocity = Vector2::from([1.0, 0.0]);
+    /// agent.radius = 0.5;
+    ///
+    /// let avoidance: Vector2 = agent.avoid_obstacles([(Point::from([5.0, 0.5]), 1.0)], 10.0);
+    /// assert_eq!(avoidance.x, 0.0);
+    /// assert!(avoidance.y < 0.0);
+    /// assert_eq!(agent.avoid_obstacles([(Point::from([5.0, 3.0]), 1.0)], 10.0), Vector2::zero());
+    /// ```
+    ///
+    pub fn avoid_obstacles(
+        &self,
+        obstacles: impl IntoIterator<Item = (Point, f32)>,
+        look_ahead: f32,
+    ) -> Vector2 {
+        let Some(heading) = self.heading() else {
+            return Vector2::zero();
+        };
+
+        let mut closest: Option<(f32, f32)> = None;
+        for (center, radius) in obstacles {
+            let offset: Vector2 = center - self.position;
+            let (along, lateral): (f32, f32) = (offset.dot(heading), heading.cross_product(offset));
+            let reach: f32 = radius + self.radius;
+            if along < 0.0 || along > look_ahead + reach || lateral.abs() >= reach {
+                continue;
+            }
+            if closest.is_none_or(|(closest_along, _)| along < closest_along) {
+                closest = Some((along, lateral));
+            }
+        }
+
+        let Some((along, lateral)) = closest else {
+            return Vector2::zero();
+        };
+        let side: Vector2 = if lateral > 0.0 {
+            -heading.perpendicular()
+        } else {
+            heading.perpendicular()
+        };
+        side * self.max_speed * (1.0 - along / (look_ahead + self.radius)).clamp(0.0, 1.0)
+    }
+    /// Returns velocity that moves agent sideways from the closest collider of physics world
+    /// which lies ahead of it (within `look_ahead` distance).
+    ///
+    /// Query uses broadphase that was built on the last step
+    /// (see [`PhysicsWorld::update_broadphase`]).
+    ///
+    pub fn avoid_colliders(
+        &self,
+        world: &PhysicsWorld,
+        filter: &QueryFilter,
+        look_ahead: f32,
+    ) -> Vector2 {
+        let Some(heading) = self.heading() else {
+            return Vector2::zero();
+        };
+        let Some(hit): Option<QueryHit> =
+            world.raycast(self.position, heading * look_ahead, filter)
+        else {
+            return Vector2::zero();
+        };
+
+        let side: Vector2 = (hit.normal - heading * hit.normal.dot(heading))
+            .try_normalize()
+            .unwrap_or(heading.perpendicular());
+        side * self.max_speed * (1.0 - hit.distance / look_ahead).clamp(0.0, 1.0)
+    }
+
+    /// Blends weighted velocities into one, which magnitude does not exceed maximal speed.
+    ///
+    pub fn blend(&self, velocities: impl IntoIterator<Item = (Vector2, f32)>) -> Vector2 {
+        velocities
+            .into_iter()
+            .fold(Vector2::zero(), |sum, (velocity, weight)| {
+                sum + velocity * weight
+            })
+            .clamp_length(0.0, self.max_speed)
+    }
+}
+
+/// [`Wander`] struct is a component that stores state of wandering behaviour
+/// (see [`Agent::wander`]).
+///
+/// Agent aims at a point on a circle that is projected in front of it;
+/// that point slightly moves along the circle every update, which produces smooth random turns.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Wander {
+    /// Distance from an agent to the center of wandering circle.
+    ///
+    pub distance: f32,
+    /// Radius of wandering circle - the bigger it is, the sharper turns are.
+    ///
+    pub radius: f32,
+    /// Maximal angle by which target moves along the circle per update.
+    ///
+    pub jitter: Angle,
+    /// Current position of target on the circle (relative to heading of an agent).
+    ///
+    angle: Angle,
+}
+impl Wander {
+    /// Constructs wandering state with target that is located straight ahead.
+    ///
+    pub fn new(distance: f32, radius: f32, jitter: Angle) -> Self {
+        Wander {
+            distance,
+            radius,
+            jitter,
+            angle: Angle::ZERO,
+        }
+    }
+}
+impl Component for Wander {}
+
+/// [`Flocking`] struct describes weights of flocking behaviour, which is a blend
+/// of separation, alignment and cohesion.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::aicore::steering::{Agent, Flocking};
+/// # use ggengine::mathcore::vectors::{Point, Vector2};
+/// let flocking: Flocking = Flocking {
+///     separation: 0.0,
+///     ..Flocking::default()
+/// };
+/// let agent: Agent = Agent::new(Point::zero(), 1.0);
+/// let mut neighbour: Agent = Agent::new(Point::from([0.0, 4.0]), 1.0);
+/// neighbour.velocity = Vector2::from([1.0, 0.0]);
+///
+/// let velocity: Vector2 = flocking.steer(&agent, [neighbour]);
+/// assert!(velocity.x > 0.0 && velocity.y > 0.0);
+/// assert_eq!(flocking.steer(&agent, []), Vector2::zero());
+/// ```
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Flocking {
+    /// Radius in which other agents are considered neighbours.
+    ///
+    pub neighbour_radius: f32,
+    /// Radius in which neighbours push agent away.
+    ///
+    pub separation_radius: f32,
+
+    /// Weight of separation - steering away from close neighbours.
+    ///
+    pub separation: f32,
+    /// Weight of alignment - matching velocity of neighbours.
+    ///
+    pub alignment: f32,
+    /// Weight of cohesion - steering towards the center of neighbours.
+    ///
+    pub cohesion: f32,
+}
+impl Flocking {
+    /// Returns velocity that makes agent flock with given agents.
+    ///
+    /// Agents that are further than [`Flocking::neighbour_radius`] are ignored,
+    /// as well as agents that share position with this one.
+    ///
+    pub fn steer(&self, agent: &Agent, others: impl IntoIterator<Item = Agent>) -> Vector2 {
+        let (mut push, mut velocity_sum, mut position_sum, mut count): (
+            Vector2,
+            Vector2,
+            Vector2,
+            u32,
+        ) = (Vector2::zero(), Vector2::zero(), Vector2::zero(), 0);
+        for other in others {
+            let offset: Vector2 = agent.position - other.position;
+            let distance: f32 = offset.magnitude();
+            if distance > self.neighbour_radius || distance == 0.0 {
+                continue;
+            }
+            if distance < self.separation_radius {
+                push += offset / (distance * distance);
+            }
+            velocity_sum += other.velocity;
+            position_sum += other.position;
+            count += 1;
+        }
+        if count == 0 {
+            return Vector2::zero();
+        }
+
+        let count: f32 = count as f32;
+        let separation: Vector2 = push
+            .try_normalize()
+            .map_or(Vector2::zero(), |direction| direction * agent.max_speed);
+        let alignment: Vector2 = (velocity_sum / count).clamp_length(0.0, agent.max_speed);
+        let cohesion: Vector2 = agent.seek(position_sum / count);
+        agent.blend([
+            (separation, self.separation),
+            (alignment, self.alignment),
+            (cohesion, self.cohesion),
+        ])
+    }
+    /// Returns velocity that makes body of physics world flock with neighbouring bodies.
+    ///
+    /// Neighbours are found by broadphase of the world ([`SpatialGrid`](crate::physicscore::contacts::SpatialGrid)),
+    /// so only bodies with colliders are considered. Query uses broadphase that was built on the last step
+    /// (see [`PhysicsWorld::update_broadphase`]).
+    ///
+    /// Returns `None` if body is not registered in the world.
+    ///
+    pub fn steer_in_world(
+        &self,
+        world: &PhysicsWorld,
+        id: BodyId,
+        max_speed: f32,
+    ) -> Option<Vector2> {
+        let agent: Agent = Agent::from_body(world.body(id)?, world.transform(id)?, max_speed);
+        let extent: Vector2 = Vector2::from([self.neighbour_radius, self.neighbour_radius]);
+        let neighbours: Vec<Agent> = world
+            .broadphase
+            .query(Aabb::new(agent.position - extent, agent.position + extent))
+            .into_iter()
+            .filter(|&other| other != id)
+            .filter_map(|other| {
+                Some(Agent::from_body(
+                    world.body(other)?,
+                    world.transform(other)?,
+                    max_speed,
+                ))
+            })
+            .collect();
+        Some(self.steer(&agent, neighbours))
+    }
+}
+impl Default for Flocking {
+    /// Returns flocking with neighbour radius of 5.0, separation radius of 2.0
+    /// and separation weight that is twice as big as other weights.
+    ///
+    fn default() -> Self {
+        Flocking {
+            neighbour_radius: 5.0,
+            separation_radius: 2.0,
+
+            separation: 2.0,
+            alignment: 1.0,
+            cohesion: 1.0,
+        }
+    }
+}
+impl Component for Flocking {}
+
+/// Steers physics body towards desired velocity, changing its velocity by at most `max_acceleration * dt`.
+///
+/// Dynamic bodies receive force (so that steering interacts with other forces and collisions),
+/// kinematic bodies have their velocity changed directly and static bodies are not affected.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::aicore::steering::steer_body;
+/// # use ggengine::physicscore::bodies::RigidBody;
+/// # use ggengine::mathcore::{transforms::Transform2D, vectors::Vector2};
+/// let mut body: RigidBody = RigidBody::dynamic(2.0);
+/// let mut transform: Transform2D = Transform2D::identity();
+///
+/// steer_body(&mut body, Vector2::from([10.0, 0.0]), 4.0, 0.5);
+/// body.integrate(&mut transform, Vector2::zero(), 0.5);
+/// assert_eq!(body.velocity, Vector2::from([2.0, 0.0]));
+/// ```
+///
+pub fn steer_body(body: &mut RigidBody, desired: Vector2, max_acceleration: f32, dt: f32) {
+    if dt <= 0.0 {
+        return;
+    }
+    let change: Vector2 = (desired - body.velocity).clamp_length(0.0, max_acceleration * dt);
+    match body.body_type() {
+        BodyType::Dynamic => body.apply_force(change / dt * body.mass()),
+        BodyType::Kinematic => body.velocity += change,
+        BodyType::Static => {}
+    }
+}
+/// Steers transform towards desired velocity, changing velocity by at most `max_acceleration * dt`,
+/// and moves transform by resulting velocity.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::aicore::steering::steer_transform;
+/// # use ggengine::mathcore::{transforms::Transform2D, vectors::Vector2};
+/// let mut transform: Transform2D = Transform2D::identity();
+/// let mut velocity: Vector2 = Vector2::zero();
+///
+/// steer_transform(&mut transform, &mut velocity, Vector2::from([0.0, 10.0]), 4.0, 0.5);
+/// assert_eq!(velocity, Vector2::from([0.0, 2.0]));
+/// assert_eq!(transform.translation, Vector2::from([0.0, 1.0]));
+/// ```
+///
+pub fn steer_transform(
+    transform: &mut Transform2D,
+    velocity: &mut Vector2,
+    desired: Vector2,
+    max_acceleration: f32,
+    dt: f32,
+) {
+    *velocity += (desired - *velocity).clamp_length(0.0, max_acceleration * dt.max(0.0));
+    transform.translation += *velocity * dt;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Agent, Flocking, Wander};
+    use crate::{
+        mathcore::{
+            random::Rng,
+            transforms::Transform2D,
+            vectors::{Point, Vector2},
+            Angle,
+        },
+        physicscore::{
+            bodies::RigidBody,
+            colliders::Collider,
+            queries::QueryFilter,
+            world::{BodyId, PhysicsWorld},
+        },
+    };
+
+    #[test]
+    fn behaviours() {
+        let mut agent: Agent = Agent::new(Point::zero(), 2.0);
+        assert_eq!(agent.seek(Point::zero()), Vector2::zero());
+        assert_eq!(
+            agent.flee(Point::from([3.0, 0.0]), 5.0),
+            Vector2::from([-2.0, 0.0])
+        );
+        assert_eq!(
+            agent.arrive(Point::from([0.0, 8.0]), 4.0),
+            Vector2::from([0.0, 2.0])
+        );
+
+        let mut target: Agent = Agent::new(Point::from([4.0, 0.0]), 1.0);
+        target.velocity = Vector2::from([0.0, 2.0]);
+        let pursuit: Vector2 = agent.pursue(&target);
+        assert!(pursuit.x > 0.0 && pursuit.y > 0.0);
+        let evasion: Vector2 = agent.evade(&target, 10.0);
+        assert!(evasion.x < 0.0 && evasion.y < 0.0);
+
+        agent.velocity = Vector2::from([1.0, 0.0]);
+        let mut wander: Wander = Wander::new(2.0, 1.0, Angle::DEG30);
+        let mut rng: Rng = Rng::new(7);
+        for _ in 0..10 {
+            let velocity: Vector2 = agent.wander(&mut wander, &mut rng);
+            assert!((velocity.magnitude() - 2.0).abs() < 1e-4);
+            assert!(velocity.x > 0.0);
+        }
+    }
+
+    #[test]
+    fn flocking_in_world() {
+        let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::zero());
+        let ids: Vec<BodyId> = [[0.0, 0.0], [1.0, 0.0], [20.0, 0.0]]
+            .into_iter()
+            .map(|position| {
+                let id: BodyId = world.add_body(
+                    RigidBody::dynamic(1.0),
+                    Transform2D::from_translation(Vector2::from(position)),
+                );
+                let _ = world.attach_collider(id, Collider::rect(0.5, 0.5));
+                id
+            })
+            .collect();
+        world.update_broadphase();
+
+        let flocking: Flocking = Flocking::default();
+        let velocity: Vector2 = flocking
+            .steer_in_world(&world, ids[0], 1.0)
+            .expect("Body exists");
+        assert!(velocity.x < 0.0);
+        assert_eq!(
+            flocking.steer_in_world(&world, ids[2], 1.0),
+            Some(Vector2::zero())
+        );
+    }
+
+    #[test]
+    fn collider_avoidance() {
+        let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::zero());
+        let wall: BodyId = world.add_body(
+            RigidBody::fixed(),
+            Transform2D::from_translation(Vector2::from([5.0, 0.0])),
+        );
+        let _ = world.attach_collider(wall, Collider::rect(1.0, 10.0));
+        world.update_broadphase();
+
+        let mut agent: Agent = Agent::new(Point::zero(), 1.0);
+        agent.velocity = Vector2::from([1.0, 0.0]);
+        let avoidance: Vector2 = agent.avoid_colliders(&world, &QueryFilter::default(), 10.0);
+        assert_eq!(avoidance.x, 0.0);
+        assert!(avoidance.y.abs() > 0.0);
+        assert_eq!(
+            agent.avoid_colliders(&world, &QueryFilter::default(), 2.0),
+            Vector2::zero()
+        );
+    }
+}
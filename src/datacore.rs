@@ -10,17 +10,23 @@
 //!
 //! # Usage
 //! `datacore` module provides several structs for work with external data, such as audio, images and
-//! fonts. It also implements `AssetManager` that encapsulates work with filesystem.
+//! fonts. It also implements `AssetManager` that encapsulates work with filesystem
+//! and `AssetServer` that preloads groups of assets with their dependencies.
 //!
 //! This module is similar to `mathcore` in that sense that both are 'helpers' for game engine
 //! implementation.
 //!
 
 // submodules and public re-exports
+mod gif;
+
 pub mod assets;
 pub mod audio;
 pub mod fonts;
 pub mod images;
+pub mod patches;
+pub mod server;
+pub mod settings;
 
 // prelude
 pub mod prelude;
@@ -8,10 +8,13 @@
 //! It is encouraged to read the docs to find out about other types of data.
 //!
 
+use crate::{
+    log::{self, LogTarget},
+    GGError,
+};
 use serde::{Deserialize, Serialize};
 use std::{
-    fs::{create_dir, create_dir_all, File},
-    io::{Error, ErrorKind},
+    fs::{create_dir, create_dir_all, read, File},
     path::{Path, PathBuf},
 };
 
@@ -23,7 +26,7 @@ use std::{
 pub trait FromFile {
     /// Deserializes object from file.
     ///
-    fn from_file(filename: impl AsRef<Path>) -> Result<Self, Error>
+    fn from_file(filename: impl AsRef<Path>) -> Result<Self, GGError>
     where
         Self: Sized;
 }
@@ -32,19 +35,19 @@ impl<T: for<'a> Deserialize<'a>> FromFile for T {
     ///
     /// This function returns an error if file does not exist or if data is not recoverable.
     ///
-    fn from_file(filename: impl AsRef<Path>) -> Result<Self, Error> {
+    fn from_file(filename: impl AsRef<Path>) -> Result<Self, GGError> {
         let file: File = File::open(filename)?;
         serde_cbor::from_reader(file)
-            .map_err(|_| Error::new(ErrorKind::InvalidData, "Wrong data format"))
+            .map_err(|error| GGError::Decode(format!("Wrong data format: {error}")))
     }
 }
 
 /// [`ToFile`] trait is implemented on objects that can be saved to file (serialized).
 ///
-/// There is an auto implementation on all types that implement `serde::Serialized` and there is
-/// also manual implementation for `Image`.
+/// There is an auto implementation on all types that implement `serde::Serialized` and there are
+/// also manual implementations for `Image`, `Sound` (which is saved as WAV) and `Music` (which is saved in its original format).
 ///
-/// To hold up certain constraints, this trait is not implemented on types such as `Sound`, `Music` and `PartialFont`,
+/// To hold up certain constraints, this trait is not implemented on types such as `PartialFont`,
 /// because those are fully external to `ggengine` and `ggengine` cannot change them,
 /// so it's pointless to serialize data that can only be retrieved externally or
 /// to serialize objects that are initialized from data that is serializable.
@@ -52,7 +55,7 @@ impl<T: for<'a> Deserialize<'a>> FromFile for T {
 pub trait ToFile {
     /// Serializes object to file.
     ///
-    fn to_file(&self, filename: impl AsRef<Path>) -> Result<(), Error>;
+    fn to_file(&self, filename: impl AsRef<Path>) -> Result<(), GGError>;
 }
 impl<T: Serialize> ToFile for T {
     /// Saves data to file.
@@ -63,10 +66,10 @@ impl<T: Serialize> ToFile for T {
     /// This function fails if file creation or truncation fails or if data is not
     /// serializable by CBOR.
     ///
-    fn to_file(&self, filename: impl AsRef<Path>) -> Result<(), Error> {
+    fn to_file(&self, filename: impl AsRef<Path>) -> Result<(), GGError> {
         let file: File = File::create(filename)?;
         serde_cbor::to_writer(file, self)
-            .map_err(|_| Error::new(ErrorKind::InvalidInput, "Wrong data format"))
+            .map_err(|error| GGError::InvalidArgument(format!("Wrong data format: {error}")))
     }
 }
 
@@ -109,7 +112,7 @@ impl AssetFormat {
 }
 /// [`AssetMetadata`] struct represents metadata of any asset: it should have filename and specific format.
 ///
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct AssetMetadata {
     /// Name of a loaded asset file.
     ///
@@ -137,7 +140,7 @@ pub struct AssetManager {
 impl AssetManager {
     /// Constructs full path for asset using its metadata.
     ///
-    fn full_path(&self, data: AssetMetadata) -> PathBuf {
+    pub(crate) fn full_path(&self, data: AssetMetadata) -> PathBuf {
         self.root_directory
             .as_path()
             .join(data.format.to_str())
@@ -152,12 +155,15 @@ impl AssetManager {
     ///     .expect("Filename should be correct");
     /// ```
     ///
-    pub fn initialize_at(path: impl AsRef<Path>) -> Result<Self, Error> {
+    pub fn initialize_at(path: impl AsRef<Path>) -> Result<Self, GGError> {
         if !path.as_ref().is_dir() {
             create_dir_all(&path)?;
         }
         for format in AssetFormat::FORMATS {
-            create_dir(path.as_ref().join(format.to_str()).as_path())?;
+            let directory: PathBuf = path.as_ref().join(format.to_str());
+            if !directory.is_dir() {
+                create_dir(directory.as_path())?;
+            }
         }
 
         Ok(AssetManager {
@@ -180,8 +186,14 @@ impl AssetManager {
     /// }, &asset).expect("Metadata should be correct");
     /// ```
     ///
-    pub fn save_asset<T: ToFile>(&self, data: AssetMetadata, asset: &T) -> Result<(), Error> {
-        asset.to_file(self.full_path(data).as_path())
+    pub fn save_asset<T: ToFile>(&self, data: AssetMetadata, asset: &T) -> Result<(), GGError> {
+        let path: PathBuf = self.full_path(data);
+        asset.to_file(path.as_path()).inspect_err(|error| {
+            log::error(
+                LogTarget::DataCore,
+                format_args!("Asset {} was not saved: {error}", path.display()),
+            )
+        })
     }
     /// Loads asset using its metadata.
     ///
@@ -197,7 +209,40 @@ impl AssetManager {
     /// }).expect("Metadata should be correct");
     /// ```
     ///
-    pub fn load_asset<T: FromFile>(&self, data: AssetMetadata) -> Result<T, Error> {
-        T::from_file(self.full_path(data).as_path())
+    pub fn load_asset<T: FromFile>(&self, data: AssetMetadata) -> Result<T, GGError> {
+        let path: PathBuf = self.full_path(data);
+        T::from_file(path.as_path()).inspect_err(|error| {
+            log::error(
+                LogTarget::DataCore,
+                format_args!("Asset {} was not loaded: {error}", path.display()),
+            )
+        })
+    }
+    /// Loads raw bytes of an asset using its metadata.
+    ///
+    /// Unlike `load_asset`, this function does not decode data, and so it never requires `sdl2` subsystems -
+    /// that allows headless applications (e.g. dedicated servers) to read any asset.
+    ///
+    /// ```rust, no_run
+    /// # use ggengine::datacore::assets::{AssetManager, AssetMetadata, AssetFormat};
+    /// # use std::path::PathBuf;
+    /// let manager: AssetManager = AssetManager::initialize_at("assets")
+    ///     .expect("Filename should be correct");
+    ///
+    /// let level: Vec<u8> = manager.load_raw(AssetMetadata {
+    ///     filename: PathBuf::from("level.map"),
+    ///     format: AssetFormat::Other,
+    /// }).expect("Metadata should be correct");
+    /// ```
+    ///
+    pub fn load_raw(&self, data: AssetMetadata) -> Result<Vec<u8>, GGError> {
+        let path: PathBuf = self.full_path(data);
+        read(path.as_path()).map_err(|error| {
+            log::error(
+                LogTarget::DataCore,
+                format_args!("Asset {} was not loaded: {error}", path.display()),
+            );
+            GGError::Io(error)
+        })
     }
 }
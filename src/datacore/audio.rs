@@ -3,18 +3,33 @@
 //! # Model
 //! This submodule consists of structs, traits, enums and constants that can be divided in several groups:
 //! 1. Audio data ([`Sound`], [`Music`] and [`Volume`] newtype which encapsulates volume setting).
-//! 2. Channels that support audio data ([`SoundChannel`] and [`MusicChannel`], which are both implementors of [`Channel`] trait).
-//! 3. Audio system settings ([`AudioSystem::DEFAULT_FREQUENCY`], [`SampleFormat`], [`AudioChannels`], [`AudioSystem::DEFAULT_CHUNK_SIZE`] and
+//!    Sounds can be inspected ([`Sound::samples`], [`Sound::duration`]) and resampled to change their speed and pitch ([`Sound::with_speed`]).
+//! 2. Channels that support audio data ([`SoundChannel`] and [`MusicChannel`], which are both implementors of [`Channel`] trait;
+//!    [`MusicChannel`] also supports seeking, loop points, queue of music and crossfading)
+//!    and channel groups ([`ChannelGroup`]) that control volume and playback of several channels at once.
+//!    Sounds can also be played on automatically picked channels ([`AudioSystem::play`], [`PlaySettings`] and [`VoiceStealing`]).
+//! 3. Audio system settings ([`AudioSettings`], [`AudioSystem::DEFAULT_FREQUENCY`], [`SampleFormat`], [`AudioChannels`], [`AudioSystem::DEFAULT_CHUNK_SIZE`] and
 //!    [`AudioSystem`] empty enum which initializes and prepares this submodule for use.
+//! 4. Positional audio components ([`AudioEmitter`] and [`AudioListener`]) that apply panning and attenuation
+//!    to channels according to transforms of objects.
+//! 5. Custom effects ([`AudioEffect`]) that post-process audio data of channels.
+//! 6. Audio capture ([`AudioCapture`]) that records audio data from microphones.
+//! 7. Audio events ([`AudioEvent`]) that are emitted when playback finishes and are polled from [`AudioSystem`].
 //!
 //! To further understand relations between those structs, traits, enums and constants, it is encouraged to read docs for submodule items.
 //!
 
-use crate::{datacore::assets::FromFile, mathcore::Angle};
+use crate::{
+    datacore::assets::{FromFile, ToFile},
+    gamecore::components::Component,
+    mathcore::{transforms::Transform2D, vectors::Vector2, Angle},
+    GGEngine, GGError,
+};
 use bitflags::bitflags;
 use sdl2::mixer::{
     allocate_channels as mixer_allocate_channels, init as mixer_init,
-    open_audio as mixer_open_audio, Channel as MixerChannel, Chunk as MixerChunk,
+    open_audio as mixer_open_audio, set_channel_finished as mixer_set_channel_finished,
+    Channel as MixerChannel, Chunk as MixerChunk, Fading as MixerFading, Group as MixerGroup,
     InitFlag as MixerInitFlag, Music as MixerMusic, Sdl2MixerContext as MixerContext,
     AUDIO_F32LSB as MixerAUDIO_F32LSB, AUDIO_F32MSB as MixerAUDIO_F32MSB,
     AUDIO_S16LSB as MixerAUDIO_S16LSB, AUDIO_S16MSB as MixerAUDIO_S16MSB,
@@ -22,17 +37,27 @@ use sdl2::mixer::{
     AUDIO_U16LSB as MixerAUDIO_U16LSB, AUDIO_U16MSB as MixerAUDIO_U16MSB,
     DEFAULT_FREQUENCY as MixerDEFAULT_FREQUENCY, MAX_VOLUME as MixerMAX_VOLUME,
 };
+use sdl2::{
+    audio::{AudioCallback, AudioDevice, AudioSpec, AudioSpecDesired, AudioStatus},
+    AudioSubsystem,
+};
+use serde::{Deserialize, Serialize};
 use std::{
-    fmt,
-    io::{Error, ErrorKind},
+    borrow::Cow,
+    cell::{OnceCell, RefCell},
+    collections::{BTreeMap, VecDeque},
+    ffi::{c_int, c_void},
+    fmt, fs,
     num::TryFromIntError,
     path::{Path, PathBuf},
-    sync::OnceLock,
+    sync::{Mutex, OnceLock, PoisonError},
+    time::{Duration, Instant},
 };
 
 /// [`Volume`] is a newtype that restricts volume values to [0; 128].
 ///
-#[derive(Copy, Clone, Debug, Default)]
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
 pub struct Volume(u8);
 impl Volume {
     /// Minimal volume (silence).
@@ -72,6 +97,18 @@ impl Volume {
         ((f32::from(self.0) / f32::from(MixerMAX_VOLUME as u8)) * 100.0) as u8
     }
 }
+impl From<u8> for Volume {
+    /// Initializes `Volume` with given value that will be clamped to [0; 128].
+    ///
+    fn from(value: u8) -> Self {
+        Volume::from_value(value)
+    }
+}
+impl From<Volume> for u8 {
+    fn from(volume: Volume) -> Self {
+        volume.get()
+    }
+}
 
 /// `seal` hidden module is needed to use `sealed trait` pattern.
 ///
@@ -106,6 +143,9 @@ pub struct Sound {
     /// Underlying `sdl2` chunk.
     ///
     chunk: MixerChunk,
+    /// Decoded samples, which are converted lazily on first access.
+    ///
+    samples: OnceCell<Vec<f32>>,
 }
 impl Sound {
     /// Returns name of file from which [`Sound`] was initialized or empty `Path`, if it was created from raw buffer.
@@ -118,11 +158,12 @@ impl Sound {
     ///
     /// No additional conversions will be made.
     ///
-    pub fn from_raw_buffer(buffer: Box<[impl SoundFormat]>) -> Result<Self, Error> {
+    pub fn from_raw_buffer(buffer: Box<[impl SoundFormat]>) -> Result<Self, GGError> {
+        let _ = mixer_context()?;
         Ok(Sound {
             filename: PathBuf::new(),
-            chunk: MixerChunk::from_raw_buffer(buffer)
-                .map_err(|message| Error::new(ErrorKind::InvalidData, message))?,
+            chunk: MixerChunk::from_raw_buffer(buffer).map_err(GGError::Sdl)?,
+            samples: OnceCell::new(),
         })
     }
 
@@ -136,6 +177,121 @@ impl Sound {
     pub fn get_volume(&self) -> Volume {
         Volume(self.chunk.get_volume() as u8)
     }
+
+    /// Returns copy of a sound that is resampled to play `speed` times faster.
+    ///
+    /// Just like with tape, changing speed also changes pitch by the same factor
+    /// (`speed = 2.0` plays sound twice as fast and an octave higher, `speed = 0.5` is a slow motion).
+    /// Sound is resampled into format of opened audio device, so [`AudioSystem`] should be initialized.
+    /// Resampling is done up front, so variations that are used often (e.g. engine revs) should be prepared in advance.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use ggengine::datacore::audio::{AudioSystem, Sound};
+    /// # use ggengine::datacore::assets::FromFile;
+    /// # use std::path::Path;
+    /// let sound: Sound = Sound::from_file(Path::new("engine.wav")).expect("Filename should be correct");
+    /// let revs: Vec<Sound> = (0..4)
+    ///     .map(|i| sound.with_speed(1.0 + i as f32 * 0.25).expect("Audio system is initialized"))
+    ///     .collect();
+    /// let _ = AudioSystem::play(&revs[3]);
+    /// ```
+    ///
+    pub fn with_speed(&self, speed: f32) -> Result<Sound, GGError> {
+        if !(speed.is_finite() && speed > 0.0) {
+            return Err(GGError::InvalidArgument(String::from(
+                "Speed should be positive and finite",
+            )));
+        }
+        let (_, format, channels): (i32, SampleFormat, usize) = device_spec()?;
+        let data: &[u8] = self.data();
+        if data.len() < format.sample_size() * channels {
+            return Err(GGError::InvalidState(String::from("Sound is empty")));
+        }
+
+        let mut sound: Sound =
+            Sound::from_raw_buffer(format.resample(data, channels, speed).into_boxed_slice())?;
+        sound.filename = self.filename.clone();
+        sound.set_volume(self.get_volume());
+        Ok(sound)
+    }
+    /// Returns copy of a sound that is pitched by given number of semitones (which also changes its speed).
+    ///
+    /// This is a shorthand for [`Sound::with_speed`] which is handy for small random variations (e.g. footsteps).
+    ///
+    pub fn with_pitch(&self, semitones: f32) -> Result<Sound, GGError> {
+        self.with_speed(2.0_f32.powf(semitones / 12.0))
+    }
+
+    /// Returns sample rate of a sound (it is equal to frequency of opened audio device).
+    ///
+    pub fn sample_rate(&self) -> Result<u32, GGError> {
+        device_spec().map(|(frequency, _, _)| frequency as u32)
+    }
+    /// Returns number of channels of a sound (it is equal to number of channels of opened audio device).
+    ///
+    pub fn channel_count(&self) -> Result<usize, GGError> {
+        device_spec().map(|(_, _, channels)| channels)
+    }
+    /// Returns number of frames of a sound (frame holds one sample for each channel).
+    ///
+    pub fn frames(&self) -> Result<usize, GGError> {
+        let (_, format, channels): (i32, SampleFormat, usize) = device_spec()?;
+        Ok(self.data().len() / (format.sample_size() * channels))
+    }
+    /// Returns duration of a sound.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use ggengine::datacore::audio::Sound;
+    /// # use ggengine::datacore::assets::FromFile;
+    /// # use std::path::Path;
+    /// let sound: Sound = Sound::from_file(Path::new("beat.wav")).expect("Filename should be correct");
+    /// let samples: &[f32] = sound.samples().expect("Audio system should be initialized");
+    /// let channels: usize = sound.channel_count().expect("Audio system should be initialized");
+    /// let peak: usize = samples
+    ///     .iter()
+    ///     .enumerate()
+    ///     .fold((0, 0.0), |(peak, max), (i, sample)| {
+    ///         if sample.abs() > max { (i, sample.abs()) } else { (peak, max) }
+    ///     })
+    ///     .0;
+    /// let seconds: f64 = (peak / channels) as f64
+    ///     / f64::from(sound.sample_rate().expect("Audio system should be initialized"));
+    /// assert!(seconds <= sound.duration().expect("Audio system should be initialized").as_secs_f64());
+    /// ```
+    ///
+    pub fn duration(&self) -> Result<Duration, GGError> {
+        Ok(Duration::from_secs_f64(
+            self.frames()? as f64 / f64::from(self.sample_rate()?.max(1)),
+        ))
+    }
+    /// Returns decoded interleaved samples of a sound in [-1; 1] range.
+    ///
+    /// Samples are converted from format of opened audio device on first call and are cached afterwards.
+    ///
+    pub fn samples(&self) -> Result<&[f32], GGError> {
+        if let Some(samples) = self.samples.get() {
+            return Ok(samples);
+        }
+        let (_, format, _): (i32, SampleFormat, usize) = device_spec()?;
+        Ok(self.samples.get_or_init(|| {
+            self.data()
+                .chunks_exact(format.sample_size())
+                .map(|bytes| format.decode(bytes))
+                .collect()
+        }))
+    }
+
+    /// Returns decoded audio data of a sound (it is in format of opened audio device).
+    ///
+    fn data(&self) -> &[u8] {
+        // SAFETY: chunk is owned by this sound and is alive, `abuf` points to `alen` bytes of decoded audio data.
+        unsafe {
+            let raw: &sdl2::sys::mixer::Mix_Chunk = &*self.chunk.raw;
+            std::slice::from_raw_parts(raw.abuf, raw.alen as usize)
+        }
+    }
 }
 impl FromFile for Sound {
     /// Initializes [`Sound`] from given file.
@@ -148,14 +304,62 @@ impl FromFile for Sound {
     /// let sound: Sound = Sound::from_file(Path::new("s.wav")).expect("Filename should be correct");
     /// ```
     ///
-    fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+    fn from_file(path: impl AsRef<Path>) -> Result<Self, GGError> {
+        let _ = mixer_context()?;
         Ok(Sound {
             filename: path.as_ref().to_path_buf(),
-            chunk: MixerChunk::from_file(path)
-                .map_err(|message| Error::new(ErrorKind::NotFound, message))?,
+            chunk: MixerChunk::from_file(path).map_err(GGError::Sdl)?,
+            samples: OnceCell::new(),
         })
     }
 }
+impl ToFile for Sound {
+    /// Saves sound to '*.wav' file.
+    ///
+    /// Sound is saved in format of opened audio device (16-bit and 32-bit samples are saved as integer PCM,
+    /// 32-bit floating point samples are saved as IEEE float), so [`AudioSystem`] should be initialized.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use ggengine::datacore::audio::Sound;
+    /// # use ggengine::datacore::assets::ToFile;
+    /// let samples: Vec<f32> = (0..44100)
+    ///     .flat_map(|i| {
+    ///         let sample: f32 = (i as f32 * 440.0 * std::f32::consts::TAU / 44100.0).sin();
+    ///         [sample, sample]
+    ///     })
+    ///     .collect();
+    /// let sound: Sound = Sound::from_raw_buffer(samples.into_boxed_slice()).expect("Buffer is correct");
+    /// sound.to_file("s.wav").expect("Filename should be correct");
+    /// ```
+    ///
+    fn to_file(&self, filename: impl AsRef<Path>) -> Result<(), GGError> {
+        let (frequency, format, channels): (i32, SampleFormat, usize) = device_spec()?;
+        let (tag, bits, samples): (u16, u16, Vec<u8>) = format.to_wav(self.data());
+        let too_large = || GGError::Unsupported(String::from("Sound is too large for WAV file"));
+        let data_size: u32 = u32::try_from(samples.len()).map_err(|_| too_large())?;
+        let riff_size: u32 = data_size.checked_add(36).ok_or_else(too_large)?;
+        let channels: u16 = channels as u16;
+        let block_align: u16 = channels * (bits / 8);
+
+        let mut file: Vec<u8> = Vec::with_capacity(samples.len() + 44);
+        file.extend(b"RIFF");
+        file.extend(riff_size.to_le_bytes());
+        file.extend(b"WAVE");
+        file.extend(b"fmt ");
+        file.extend(16_u32.to_le_bytes());
+        file.extend(tag.to_le_bytes());
+        file.extend(channels.to_le_bytes());
+        file.extend((frequency as u32).to_le_bytes());
+        file.extend((frequency as u32 * u32::from(block_align)).to_le_bytes());
+        file.extend(block_align.to_le_bytes());
+        file.extend(bits.to_le_bytes());
+        file.extend(b"data");
+        file.extend(data_size.to_le_bytes());
+        file.extend(samples);
+        Ok(fs::write(filename, file)?)
+    }
+}
 impl fmt::Debug for Sound {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Sound")
@@ -176,6 +380,12 @@ pub struct Music {
     /// Underlying sdl music.
     ///
     music: MixerMusic<'static>,
+    /// Encoded data of music that was created from raw buffer.
+    ///
+    source: Option<&'static [u8]>,
+    /// Loop start and optional loop end (in seconds).
+    ///
+    loop_points: Option<(f64, Option<f64>)>,
 }
 impl Music {
     /// Returns name of file from which [`Music`] was initialized or empty `Path`, if it was created from raw buffer.
@@ -188,14 +398,40 @@ impl Music {
     ///
     /// This function attempts to guess the file format from incoming data.
     ///
-    pub fn from_raw_buffer(buffer: Box<[u8]>) -> Result<Self, Error> {
+    pub fn from_raw_buffer(buffer: Box<[u8]>) -> Result<Self, GGError> {
+        let _ = mixer_context()?;
+        let source: &'static [u8] = Box::leak::<'static>(buffer);
         Ok(Music {
             filename: PathBuf::new(),
-            music: MixerMusic::from_static_bytes(Box::leak::<'static>(buffer))
-                .map_err(|message| Error::new(ErrorKind::InvalidData, message))?,
+            music: MixerMusic::from_static_bytes(source).map_err(GGError::Sdl)?,
+            source: Some(source),
+            loop_points: None,
         })
     }
 
+    /// Sets loop points of music (in seconds).
+    ///
+    /// When music that is played on [`MusicChannel`] reaches loop end, it jumps back to loop start,
+    /// so intro of a track can be played only once.
+    /// If loop end is `None`, music jumps back when it finishes - this works only for music
+    /// that is owned by [`MusicChannel`] (see [`MusicChannel::queue`]).
+    ///
+    /// Loop end is tracked by [`MusicChannel::update`].
+    ///
+    pub fn set_loop_points(&mut self, start: f64, end: Option<f64>) {
+        self.loop_points = Some((start.max(0.0), end));
+    }
+    /// Removes loop points of music.
+    ///
+    pub fn clear_loop_points(&mut self) {
+        self.loop_points = None;
+    }
+    /// Returns loop points of music.
+    ///
+    pub fn loop_points(&self) -> Option<(f64, Option<f64>)> {
+        self.loop_points
+    }
+
     /// Sets new volume to music.
     ///
     pub fn set_volume(&mut self, volume: Volume) {
@@ -218,18 +454,48 @@ impl FromFile for Music {
     /// let sound: Music = Music::from_file(Path::new("m.mp3")).expect("Filename should be correct");
     /// ```
     ///
-    fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+    fn from_file(path: impl AsRef<Path>) -> Result<Self, GGError> {
+        let _ = mixer_context()?;
         Ok(Music {
             filename: path.as_ref().to_path_buf(),
-            music: MixerMusic::from_file(path)
-                .map_err(|message| Error::new(ErrorKind::NotFound, message))?,
+            music: MixerMusic::from_file(path).map_err(GGError::Sdl)?,
+            source: None,
+            loop_points: None,
         })
     }
 }
+impl ToFile for Music {
+    /// Saves music to file.
+    ///
+    /// Music is decoded on fly, so it is saved in its original encoded format -
+    /// either data of a buffer from which it was created is written or file from which it was loaded is copied.
+    /// File extension should match that format.
+    ///
+    /// Data of the original file is read before anything is written, so music can be saved to the file
+    /// from which it was loaded.
+    ///
+    /// # Errors
+    /// Returns [`GGError::InvalidState`] if music has neither buffer nor file from which it was loaded.
+    ///
+    fn to_file(&self, filename: impl AsRef<Path>) -> Result<(), GGError> {
+        let data: Cow<'_, [u8]> = match self.source {
+            Some(source) => Cow::Borrowed(source),
+            None if self.filename.as_os_str().is_empty() => {
+                return Err(GGError::InvalidState(String::from(
+                    "Music has no source data or file to save",
+                )));
+            }
+            None => Cow::Owned(fs::read(&self.filename)?),
+        };
+        fs::write(filename, data)?;
+        Ok(())
+    }
+}
 impl fmt::Debug for Music {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Music")
             .field("filename", &self.filename)
+            .field("loop_points", &self.loop_points)
             .finish()
     }
 }
@@ -245,12 +511,17 @@ pub trait Channel {
     ///
     /// For `loops = Some(n)` it will play total of `n + 1` times, for `loops = None` it will be looping infinitely.
     ///
-    fn play(&self, data: &Self::AudioData, loops: Option<i32>);
+    fn play(&self, data: &Self::AudioData, loops: Option<i32>) -> Result<(), GGError>;
     /// Plays audio data fading in through `fading_time` milliseconds and looping `loops` times.
     ///
     /// For `loops = Some(n)` it will play total of `n + 1` times, for `loops = None` it will be looping infinitely.
     ///
-    fn fade_in(&self, data: &Self::AudioData, loops: Option<i32>, fading_time: i32);
+    fn fade_in(
+        &self,
+        data: &Self::AudioData,
+        loops: Option<i32>,
+        fading_time: i32,
+    ) -> Result<(), GGError>;
 
     /// Pauses channel.
     ///
@@ -270,7 +541,7 @@ pub trait Channel {
     fn stop(&self);
     /// Stops playing by fading out through `fading_time` milliseconds.
     ///
-    fn fade_out(&self, fading_time: i32);
+    fn fade_out(&self, fading_time: i32) -> Result<(), GGError>;
 }
 
 /// [`SoundChannel`] struct represents channel on which [`Sound`] can be played.
@@ -282,17 +553,24 @@ pub struct SoundChannel(MixerChannel);
 impl Channel for SoundChannel {
     type AudioData = Sound;
 
-    fn play(&self, data: &Self::AudioData, loops: Option<i32>) {
+    fn play(&self, data: &Self::AudioData, loops: Option<i32>) -> Result<(), GGError> {
         let _ = self
             .0
             .play(&data.chunk, loops.unwrap_or(-1))
-            .expect("Audio driver must be available");
+            .map_err(GGError::Sdl)?;
+        Ok(())
     }
-    fn fade_in(&self, data: &Self::AudioData, loops: Option<i32>, fading_time: i32) {
+    fn fade_in(
+        &self,
+        data: &Self::AudioData,
+        loops: Option<i32>,
+        fading_time: i32,
+    ) -> Result<(), GGError> {
         let _ = self
             .0
             .fade_in(&data.chunk, loops.unwrap_or(-1), fading_time)
-            .expect("Audio driver must be available");
+            .map_err(GGError::Sdl)?;
+        Ok(())
     }
 
     fn pause(&self) {
@@ -311,8 +589,9 @@ impl Channel for SoundChannel {
     fn stop(&self) {
         self.0.halt();
     }
-    fn fade_out(&self, fading_time: i32) {
+    fn fade_out(&self, fading_time: i32) -> Result<(), GGError> {
         let _ = self.0.fade_out(fading_time);
+        Ok(())
     }
 }
 impl SoundChannel {
@@ -345,61 +624,102 @@ impl SoundChannel {
 
     /// If `flip = true`, swaps left and right channel sound. If `flip = false`, effect is unregistered.
     ///
-    pub fn reverse_stereo(&self, flip: bool) {
-        self.0
-            .set_reverse_stereo(flip)
-            .expect("Audio driver must be available");
+    pub fn reverse_stereo(&self, flip: bool) -> Result<(), GGError> {
+        self.0.set_reverse_stereo(flip).map_err(GGError::Sdl)
     }
 
     /// Sets a panning effect, where left and right is the volume of the left and right channels.
     ///
     /// `left` and `right` range from 0 (silence) to 255 (loud).
     ///
-    pub fn set_panning(&self, left: u8, right: u8) {
-        self.0
-            .set_panning(left, right)
-            .expect("Audio driver must be available");
+    pub fn set_panning(&self, left: u8, right: u8) -> Result<(), GGError> {
+        self.0.set_panning(left, right).map_err(GGError::Sdl)
     }
     /// This effect simulates a simple attenuation of volume due to distance.
     ///
     /// `distance` ranges from 0 (close/loud) to 255 (far/quiet).
     ///
-    pub fn set_distance(&self, distance: u8) {
-        self.0
-            .set_distance(distance)
-            .expect("Audio driver must be available");
+    pub fn set_distance(&self, distance: u8) -> Result<(), GGError> {
+        self.0.set_distance(distance).map_err(GGError::Sdl)
     }
     /// This effect emulates a simple 3D audio effect.
     ///
     /// `angle` ranges from 0 to 360 degrees going clockwise, where 0 is directly in front.
     /// `distance` ranges from 0 (close/loud) to 255 (far/quiet).
     ///
-    pub fn set_position(&self, angle: Angle, distance: u8) {
+    pub fn set_position(&self, angle: Angle, distance: u8) -> Result<(), GGError> {
         self.0
             .set_position(angle.degrees() as i16, distance)
-            .expect("Audio driver must be available");
+            .map_err(GGError::Sdl)
     }
 
     /// Unregisters panning effect.
     ///
-    pub fn unset_panning(&self) {
-        self.0
-            .unset_panning()
-            .expect("Audio driver must be available");
+    pub fn unset_panning(&self) -> Result<(), GGError> {
+        self.0.unset_panning().map_err(GGError::Sdl)
     }
     /// Unregisters distance effect.
     ///
-    pub fn unset_distance(&self) {
-        self.0
-            .unset_distance()
-            .expect("Audio driver must be available");
+    pub fn unset_distance(&self) -> Result<(), GGError> {
+        self.0.unset_distance().map_err(GGError::Sdl)
     }
     /// Unregisters position effect.
     ///
-    pub fn unset_position(&self) {
-        self.0
-            .unset_position()
-            .expect("Audio driver must be available");
+    pub fn unset_position(&self) -> Result<(), GGError> {
+        self.0.unset_position().map_err(GGError::Sdl)
+    }
+
+    /// Registers custom effect that post-processes audio data of a channel.
+    ///
+    /// Effects are applied in order of registration; built-in effects (panning, distance, position)
+    /// are registered the same way, so their order relative to custom effects matters too.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use ggengine::datacore::audio::SoundChannel;
+    /// let channel: SoundChannel = SoundChannel::from_id(0).expect("Channel exists");
+    /// // simple one-pole low-pass filter for underwater sounds
+    /// let mut previous: Vec<f32> = Vec::new();
+    /// channel
+    ///     .add_effect(move |samples: &mut [f32], channels: usize| {
+    ///         previous.resize(channels, 0.0);
+    ///         for (i, sample) in samples.iter_mut().enumerate() {
+    ///             previous[i % channels] += 0.1 * (*sample - previous[i % channels]);
+    ///             *sample = previous[i % channels];
+    ///         }
+    ///     })
+    ///     .expect("Audio system should be initialized");
+    /// ```
+    ///
+    pub fn add_effect(&self, effect: impl AudioEffect) -> Result<(), GGError> {
+        register_effect(self.id(), Box::new(effect))
+    }
+    /// Unregisters all effects of a channel, including built-in ones (panning, distance, position).
+    ///
+    pub fn clear_effects(&self) -> Result<(), GGError> {
+        self.0.unregister_all_effects().map_err(GGError::Sdl)
+    }
+
+    /// Assigns channel to a group, so that its volume and playback are controlled by that group.
+    ///
+    /// Channel can be assigned only to groups of sound channels - assigning it to [`ChannelGroup::Music`]
+    /// removes channel from its current group.
+    ///
+    pub fn set_group(&self, group: ChannelGroup) {
+        MIXER_STATE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .assign_group(*self, group);
+    }
+    /// Returns group to which channel is assigned.
+    ///
+    pub fn group(&self) -> Option<ChannelGroup> {
+        MIXER_STATE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .channel_groups
+            .get(&self.id())
+            .copied()
     }
 }
 /// [`MusicChannel`] is a singleton that represents channel on which [`Music`] can be played.
@@ -411,41 +731,620 @@ pub struct MusicChannel;
 impl Channel for MusicChannel {
     type AudioData = Music;
 
-    fn play(&self, data: &Self::AudioData, loops: Option<i32>) {
-        data.music
-            .play(loops.unwrap_or(-1))
-            .expect("Audio driver must be available");
+    fn play(&self, data: &Self::AudioData, loops: Option<i32>) -> Result<(), GGError> {
+        data.music.play(loops.unwrap_or(-1)).map_err(GGError::Sdl)?;
+        MUSIC_PLAYER.with_borrow_mut(|player| player.start(None, data.loop_points, 0.0));
+        Ok(())
     }
-    fn fade_in(&self, data: &Self::AudioData, loops: Option<i32>, fading_time: i32) {
+    fn fade_in(
+        &self,
+        data: &Self::AudioData,
+        loops: Option<i32>,
+        fading_time: i32,
+    ) -> Result<(), GGError> {
         data.music
             .fade_in(loops.unwrap_or(-1), fading_time)
-            .expect("Audio driver must be available");
+            .map_err(GGError::Sdl)?;
+        MUSIC_PLAYER.with_borrow_mut(|player| player.start(None, data.loop_points, 0.0));
+        Ok(())
     }
 
     fn pause(&self) {
+        MUSIC_PLAYER.with_borrow_mut(|player| {
+            if player.paused_at.is_none() {
+                player.paused_at = Some(Instant::now());
+            }
+        });
         MixerMusic::pause();
     }
     fn is_paused(&self) -> bool {
         MixerMusic::is_paused()
     }
     fn resume(&self) {
+        MUSIC_PLAYER.with_borrow_mut(|player| {
+            if let Some(paused_at) = player.paused_at.take() {
+                player.started_at += paused_at.elapsed();
+            }
+        });
         MixerMusic::resume();
     }
     fn is_playing(&self) -> bool {
         MixerMusic::is_playing()
     }
 
+    /// Stops playing (halts channel) and clears queue of music.
+    ///
     fn stop(&self) {
         MixerMusic::halt();
+        MUSIC_PLAYER.with_borrow_mut(|player| {
+            player.queue.clear();
+            player.current = None;
+        });
     }
-    fn fade_out(&self, fading_time: i32) {
-        MixerMusic::fade_out(fading_time).expect("Audio driver must be available");
+    fn fade_out(&self, fading_time: i32) -> Result<(), GGError> {
+        MixerMusic::fade_out(fading_time).map_err(GGError::Sdl)
     }
 }
+impl MusicChannel {
+    /// Sets position of music that is being played (in seconds).
+    ///
+    /// Not all music formats support seeking - [`GGError::Unsupported`] is returned for those.
+    ///
+    pub fn set_position(&self, seconds: f64) -> Result<(), GGError> {
+        MixerMusic::set_pos(seconds.max(0.0)).map_err(GGError::Unsupported)?;
+        MUSIC_PLAYER.with_borrow_mut(|player| player.seek(seconds.max(0.0)));
+        Ok(())
+    }
+    /// Returns position of music that is being played (in seconds).
+    ///
+    /// Position is tracked by the channel since the last start of music or the last seek,
+    /// so it is an estimation that does not account for delays of an audio device.
+    ///
+    pub fn position(&self) -> f64 {
+        MUSIC_PLAYER.with_borrow(MusicPlayer::position)
+    }
+
+    /// Adds music to the queue; queued music is played once after current music finishes.
+    ///
+    /// Channel owns queued music, so it is kept alive as long as it is needed.
+    /// Music with loop points never finishes by itself - use [`MusicChannel::crossfade_to`] to leave it.
+    /// Queue is advanced by [`MusicChannel::update`].
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use ggengine::datacore::audio::{MusicChannel, Music};
+    /// # use ggengine::datacore::assets::FromFile;
+    /// # use std::path::Path;
+    /// let mut theme: Music = Music::from_file(Path::new("theme.ogg")).expect("Filename should be correct");
+    /// theme.set_loop_points(12.5, Some(96.0));
+    /// MusicChannel.queue(Music::from_file(Path::new("intro.ogg")).expect("Filename should be correct"));
+    /// MusicChannel.queue(theme);
+    /// loop {
+    ///     MusicChannel.update();
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    pub fn queue(&self, music: Music) {
+        MUSIC_PLAYER.with_borrow_mut(|player| player.queue.push_back((music, 0)));
+    }
+    /// Crossfades to given music through `duration`.
+    ///
+    /// `sdl2::mixer` plays only one music at a time, so current music fades out through
+    /// the first half of `duration` and given music fades in through the second half.
+    /// Given music is played before any other queued music.
+    ///
+    pub fn crossfade_to(&self, music: Music, duration: Duration) {
+        let half: i32 = i32::try_from(duration.as_millis() / 2).unwrap_or(i32::MAX);
+        MUSIC_PLAYER.with_borrow_mut(|player| {
+            player.queue.push_front((music, half));
+            player.loop_points = None;
+        });
+        if MixerMusic::is_playing() && !MixerMusic::is_paused() {
+            let _ = MixerMusic::fade_out(half);
+        } else {
+            MixerMusic::halt();
+        }
+    }
+
+    /// Updates music channel - jumps to loop start when loop end is reached
+    /// and starts queued music when current music finishes.
+    ///
+    /// This function should be called once per frame.
+    ///
+    pub fn update(&self) {
+        MUSIC_PLAYER.with_borrow_mut(|player| {
+            if MixerMusic::is_playing() {
+                if let Some((start, Some(end))) = player.loop_points {
+                    if MixerMusic::get_fading() == MixerFading::NoFading
+                        && player.position() >= end
+                        && MixerMusic::set_pos(start).is_ok()
+                    {
+                        player.seek(start);
+                    }
+                }
+                return;
+            }
+
+            if let Some((start, _)) = player.loop_points {
+                if let Some(current) = player.current.take() {
+                    if current.music.fade_in_from_pos(0, 0, start).is_ok() {
+                        player.start(Some(current), Some((start, None)), start);
+                        return;
+                    }
+                }
+            }
+            if let Some((music, fading_time)) = player.queue.pop_front() {
+                let _ = player.current.take();
+                let result: Result<(), String> = if fading_time > 0 {
+                    music.music.fade_in(0, fading_time)
+                } else {
+                    music.music.play(0)
+                };
+                if result.is_ok() {
+                    let loop_points: Option<(f64, Option<f64>)> = music.loop_points;
+                    player.start(Some(music), loop_points, 0.0);
+                }
+            }
+        });
+    }
+}
+
+/// [`MusicPlayer`] struct holds state of [`MusicChannel`] - music that is owned by the channel,
+/// queue of music, loop points and clock of playback.
+///
+#[derive(Debug)]
+struct MusicPlayer {
+    /// Music that is owned by the channel and is currently played.
+    ///
+    current: Option<Music>,
+    /// Queue of music with times of fading in (in milliseconds).
+    ///
+    queue: VecDeque<(Music, i32)>,
+    /// Loop points of currently played music.
+    ///
+    loop_points: Option<(f64, Option<f64>)>,
+
+    /// Instant at which playback was at `start_position`.
+    ///
+    started_at: Instant,
+    /// Position of playback at `started_at` (in seconds).
+    ///
+    start_position: f64,
+    /// Instant at which playback was paused.
+    ///
+    paused_at: Option<Instant>,
+}
+impl MusicPlayer {
+    /// Records start of playback.
+    ///
+    fn start(
+        &mut self,
+        current: Option<Music>,
+        loop_points: Option<(f64, Option<f64>)>,
+        position: f64,
+    ) {
+        self.current = current;
+        self.loop_points = loop_points;
+        self.seek(position);
+    }
+    /// Records change of playback position.
+    ///
+    fn seek(&mut self, position: f64) {
+        self.started_at = Instant::now();
+        self.start_position = position;
+        if self.paused_at.is_some() {
+            self.paused_at = Some(self.started_at);
+        }
+    }
+    /// Returns estimated position of playback.
+    ///
+    fn position(&self) -> f64 {
+        let elapsed: Duration = match self.paused_at {
+            Some(paused_at) => paused_at.duration_since(self.started_at),
+            None => self.started_at.elapsed(),
+        };
+        self.start_position + elapsed.as_secs_f64()
+    }
+}
+thread_local! {
+    /// [`MUSIC_PLAYER`] thread local variable holds state of [`MusicChannel`].
+    ///
+    /// Music cannot be sent between threads, so state is local to a thread that plays music.
+    ///
+    static MUSIC_PLAYER: RefCell<MusicPlayer> = RefCell::new(MusicPlayer {
+        current: None,
+        queue: VecDeque::new(),
+        loop_points: None,
+
+        started_at: Instant::now(),
+        start_position: 0.0,
+        paused_at: None,
+    });
+}
+
+/// [`ChannelGroup`] enum lists named groups of channels.
+///
+/// Every group has its own volume, which is combined with master volume of audio system
+/// (see [`AudioSystem::set_master_volume`]), and can be paused, resumed or stopped as a whole.
+/// Sound channels are assigned to groups by [`SoundChannel::set_group`];
+/// [`ChannelGroup::Music`] controls [`MusicChannel`].
+///
+/// # Example
+/// ```rust, no_run
+/// # use ggengine::datacore::audio::{AudioSystem, ChannelGroup, SoundChannel, Volume};
+/// for id in 0..4 {
+///     SoundChannel::from_id(id).expect("Channel exists").set_group(ChannelGroup::Sfx);
+/// }
+/// ChannelGroup::Sfx.set_volume(Volume::from_percents(50));
+/// ChannelGroup::Music.set_volume(Volume::from_percents(80));
+/// AudioSystem::set_master_volume(Volume::MAX);
+/// ```
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ChannelGroup {
+    /// Sound effects.
+    ///
+    Sfx = 1,
+    /// Voice lines.
+    ///
+    Voice = 2,
+    /// Background music.
+    ///
+    Music = 3,
+}
+impl ChannelGroup {
+    /// Sets volume of a group.
+    ///
+    pub fn set_volume(self, volume: Volume) {
+        let mut state = MIXER_STATE.lock().unwrap_or_else(PoisonError::into_inner);
+        state.group_volumes[self.index()] = volume;
+        state.apply_volumes();
+    }
+    /// Returns volume of a group.
+    ///
+    pub fn volume(self) -> Volume {
+        MIXER_STATE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .group_volumes[self.index()]
+    }
+
+    /// Returns sound channels that are assigned to a group.
+    ///
+    pub fn channels(self) -> Vec<SoundChannel> {
+        MIXER_STATE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .channel_groups
+            .iter()
+            .filter(|(_, group)| **group == self)
+            .map(|(id, _)| SoundChannel(MixerChannel(*id)))
+            .collect()
+    }
+
+    /// Pauses all channels of a group.
+    ///
+    pub fn pause(self) {
+        match self {
+            ChannelGroup::Music => MusicChannel.pause(),
+            _ => self.channels().iter().for_each(SoundChannel::pause),
+        }
+    }
+    /// Resumes all channels of a group.
+    ///
+    pub fn resume(self) {
+        match self {
+            ChannelGroup::Music => MusicChannel.resume(),
+            _ => self.channels().iter().for_each(SoundChannel::resume),
+        }
+    }
+    /// Stops all channels of a group.
+    ///
+    pub fn stop(self) {
+        match self {
+            ChannelGroup::Music => MusicChannel.stop(),
+            _ => MixerGroup(self as i32).halt(),
+        }
+    }
+
+    /// Returns index of a group in array of group volumes.
+    ///
+    fn index(self) -> usize {
+        self as usize - 1
+    }
+}
+/// [`MixerState`] struct holds volumes of channel groups and assignment of sound channels to groups.
+///
+#[derive(Debug)]
+struct MixerState {
+    /// Master volume.
+    ///
+    master_volume: Volume,
+    /// Volumes of channel groups.
+    ///
+    group_volumes: [Volume; 3],
+    /// Groups to which sound channels are assigned.
+    ///
+    channel_groups: BTreeMap<i32, ChannelGroup>,
+
+    /// Policy of stealing channels for automatically played sounds.
+    ///
+    voice_stealing: VoiceStealing,
+    /// Order in which sounds were automatically played on channels.
+    ///
+    started: BTreeMap<i32, u64>,
+    /// Number of sounds that were automatically played.
+    ///
+    plays: u64,
+}
+impl MixerState {
+    /// Assigns sound channel to a group and applies volume of that group.
+    ///
+    fn assign_group(&mut self, channel: SoundChannel, group: ChannelGroup) {
+        if group == ChannelGroup::Music {
+            let _ = self.channel_groups.remove(&channel.id());
+            let _ = MixerGroup::default().add_channel(channel.0);
+        } else {
+            let _ = self.channel_groups.insert(channel.id(), group);
+            let _ = MixerGroup(group as i32).add_channel(channel.0);
+        }
+        self.apply_channel_volume(channel);
+    }
+    /// Picks channel for automatically played sound.
+    ///
+    /// Free channel is preferred; if all channels are busy, channel is stolen according to the policy.
+    ///
+    fn pick_channel(&self) -> Option<SoundChannel> {
+        let channels: i32 = mixer_allocate_channels(-1);
+        if let Some(id) = (0..channels).find(|id| !MixerChannel(*id).is_playing()) {
+            return Some(SoundChannel(MixerChannel(id)));
+        }
+
+        let started = self
+            .started
+            .iter()
+            .filter(|(id, _)| **id < channels)
+            .map(|(id, order)| (*order, *id));
+        let stolen: Option<(u64, i32)> = match self.voice_stealing {
+            VoiceStealing::Skip => None,
+            VoiceStealing::Oldest => started.min(),
+            VoiceStealing::Newest => started.max(),
+        };
+        stolen.map(|(_, id)| SoundChannel(MixerChannel(id)))
+    }
+
+    /// Returns volume that results from mixing group volume with master volume.
+    ///
+    fn mixed_volume(&self, group: Option<ChannelGroup>) -> i32 {
+        let group_volume: i32 = group.map_or(MixerMAX_VOLUME, |group| {
+            i32::from(self.group_volumes[group.index()].get())
+        });
+        group_volume * i32::from(self.master_volume.get()) / MixerMAX_VOLUME
+    }
+    /// Applies mixed volume to sound channel.
+    ///
+    fn apply_channel_volume(&self, channel: SoundChannel) {
+        let _ = channel
+            .0
+            .set_volume(self.mixed_volume(self.channel_groups.get(&channel.id()).copied()));
+    }
+    /// Applies mixed volumes to all sound channels and to music channel.
+    ///
+    fn apply_volumes(&self) {
+        for id in 0..mixer_allocate_channels(-1) {
+            self.apply_channel_volume(SoundChannel(MixerChannel(id)));
+        }
+        MixerMusic::set_volume(self.mixed_volume(Some(ChannelGroup::Music)));
+    }
+}
+/// [`MIXER_STATE`] global static variable holds volumes of channel groups.
+///
+static MIXER_STATE: Mutex<MixerState> = Mutex::new(MixerState {
+    master_volume: Volume::MAX,
+    group_volumes: [Volume::MAX; 3],
+    channel_groups: BTreeMap::new(),
+
+    voice_stealing: VoiceStealing::Oldest,
+    started: BTreeMap::new(),
+    plays: 0,
+});
+
+/// [`VoiceStealing`] enum lists policies of picking busy channel for a sound
+/// that is played by [`AudioSystem::play`] when all channels are busy.
+///
+/// Only channels that were picked automatically can be stolen -
+/// channels that are managed manually are never interrupted.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum VoiceStealing {
+    /// Sound is not played.
+    ///
+    Skip,
+    /// Sound that was started the earliest is stopped.
+    ///
+    #[default]
+    Oldest,
+    /// Sound that was started the latest is stopped.
+    ///
+    Newest,
+}
+
+/// [`PlaySettings`] struct describes how sound is played by [`AudioSystem::play_with`].
+///
+/// # Example
+/// ```rust, no_run
+/// # use ggengine::datacore::audio::{AudioSystem, ChannelGroup, PlaySettings, Sound};
+/// # use ggengine::datacore::assets::FromFile;
+/// # use std::path::Path;
+/// let sound: Sound = Sound::from_file(Path::new("voice.wav")).expect("Filename should be correct");
+/// let _ = AudioSystem::play_with(
+///     &sound,
+///     PlaySettings {
+///         fade_in: Some(250),
+///         group: ChannelGroup::Voice,
+///         ..PlaySettings::default()
+///     },
+/// );
+/// ```
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PlaySettings {
+    /// Number of loops (for `loops = Some(n)` sound is played total of `n + 1` times,
+    /// for `loops = None` it will be looping infinitely).
+    ///
+    pub loops: Option<i32>,
+    /// Time of fading in (in milliseconds).
+    ///
+    pub fade_in: Option<i32>,
+    /// Group to which channel is assigned.
+    ///
+    pub group: ChannelGroup,
+}
+impl Default for PlaySettings {
+    /// Returns settings that play sound once without fading in on [`ChannelGroup::Sfx`] group.
+    ///
+    fn default() -> Self {
+        PlaySettings {
+            loops: Some(0),
+            fade_in: None,
+            group: ChannelGroup::Sfx,
+        }
+    }
+}
+
+/// [`AudioEmitter`] struct is a component that makes sound on a channel positional -
+/// panning and attenuation of a channel follow position of an emitter relative to [`AudioListener`].
+///
+/// Attenuation is linear: emitter is heard at full volume up to `min_distance`
+/// and becomes silent at `max_distance`.
+///
+#[derive(Copy, Clone, Debug)]
+pub struct AudioEmitter {
+    /// Channel that is owned by an emitter.
+    ///
+    pub channel: SoundChannel,
+    /// Distance up to which sound is not attenuated.
+    ///
+    pub min_distance: f32,
+    /// Distance at which sound becomes silent.
+    ///
+    pub max_distance: f32,
+}
+impl AudioEmitter {
+    /// Constructs emitter that owns given channel and is heard up to `max_distance`.
+    ///
+    pub fn new(channel: SoundChannel, max_distance: f32) -> Self {
+        AudioEmitter {
+            channel,
+            min_distance: 0.0,
+            max_distance,
+        }
+    }
+
+    /// Returns angle and distance effect parameters for an emitter that is heard by a listener
+    /// (those are parameters that are passed to [`SoundChannel::set_position`]).
+    ///
+    /// Angle goes clockwise from the direction that listener faces (up the screen, which is negative y axis),
+    /// distance ranges from 0 (close/loud) to 255 (far/quiet).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::datacore::audio::{AudioEmitter, AudioListener, SoundChannel};
+    /// # use ggengine::mathcore::transforms::Transform2D;
+    /// # use ggengine::mathcore::vectors::Vector2;
+    /// # use ggengine::mathcore::Angle;
+    /// let emitter: AudioEmitter = AudioEmitter::new(SoundChannel::ALL, 100.0);
+    /// let listener: AudioListener = AudioListener::default();
+    ///
+    /// let (angle, distance): (Angle, u8) = emitter.spatialize(
+    ///     &Transform2D::from_translation(Vector2::from([50.0, 0.0])),
+    ///     &listener,
+    ///     &Transform2D::identity(),
+    /// );
+    /// assert_eq!(angle.degrees().round(), 90.0);
+    /// assert_eq!(distance, 127);
+    /// ```
+    ///
+    pub fn spatialize(
+        &self,
+        transform: &Transform2D,
+        listener: &AudioListener,
+        listener_transform: &Transform2D,
+    ) -> (Angle, u8) {
+        let mut offset: Vector2 = transform.translation - listener_transform.translation;
+        if listener.use_rotation {
+            offset = offset.rotate_by(-listener_transform.rotation);
+        }
+
+        let range: f32 = self.max_distance - self.min_distance;
+        let attenuation: f32 = if range > 0.0 {
+            ((offset.magnitude() - self.min_distance) / range).clamp(0.0, 1.0)
+        } else if offset.magnitude() > self.max_distance {
+            1.0
+        } else {
+            0.0
+        };
+        (
+            Angle::from_radians(offset.x.atan2(-offset.y)),
+            (attenuation * 255.0) as u8,
+        )
+    }
+}
+impl Component for AudioEmitter {}
+/// [`AudioListener`] struct is a component that marks an object which hears [`AudioEmitter`]s
+/// (usually that is a camera or a player).
+///
+/// # Example
+/// ```rust, no_run
+/// # use ggengine::datacore::audio::{AudioEmitter, AudioListener, SoundChannel};
+/// # use ggengine::mathcore::transforms::Transform2D;
+/// # use ggengine::mathcore::vectors::Vector2;
+/// let listener: (AudioListener, Transform2D) = (AudioListener::default(), Transform2D::identity());
+/// let engine: AudioEmitter = AudioEmitter::new(SoundChannel::from_id(0).expect("Channel exists"), 500.0);
+/// let mut engine_transform: Transform2D = Transform2D::identity();
+/// loop {
+///     engine_transform.translation += Vector2::from([1.0, 0.0]);
+///     listener
+///         .0
+///         .update(&listener.1, [(&engine, &engine_transform)])
+///         .expect("Audio system should be initialized");
+///     // ...
+/// }
+/// ```
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AudioListener {
+    /// Whether rotation of a listener is accounted (which is useful for top-down games where camera
+    /// rotates with a player), otherwise listener always faces up the screen.
+    ///
+    pub use_rotation: bool,
+}
+impl AudioListener {
+    /// Applies position effects to channels of all given emitters according to their positions
+    /// relative to a listener.
+    ///
+    /// This function should be called once per frame, so that positional sound follows moving objects.
+    ///
+    pub fn update<'a>(
+        &self,
+        transform: &Transform2D,
+        emitters: impl IntoIterator<Item = (&'a AudioEmitter, &'a Transform2D)>,
+    ) -> Result<(), GGError> {
+        for (emitter, emitter_transform) in emitters {
+            let (angle, distance): (Angle, u8) =
+                emitter.spatialize(emitter_transform, self, transform);
+            emitter.channel.set_position(angle, distance)?;
+        }
+        Ok(())
+    }
+}
+impl Component for AudioListener {}
 
 bitflags! (
     /// [`AudioFormat`] bitflag struct lists supported audio formats.
     ///
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
     pub struct AudioFormat : u32 {
         /// FLAC format flag.
         ///
@@ -554,6 +1453,120 @@ impl SampleFormat {
             SampleFormat::U16MSB => MixerAUDIO_U16MSB,
         }
     }
+    /// Returns [`SampleFormat`] that corresponds to `sdl2::mixer` representation.
+    ///
+    pub(crate) fn from_sdl_u16(format: u16) -> Option<Self> {
+        [
+            SampleFormat::F32LSB,
+            SampleFormat::F32MSB,
+            SampleFormat::S16LSB,
+            SampleFormat::S16MSB,
+            SampleFormat::S32LSB,
+            SampleFormat::S32MSB,
+            SampleFormat::U16LSB,
+            SampleFormat::U16MSB,
+        ]
+        .into_iter()
+        .find(|sample_format| sample_format.to_sdl_u16() == format)
+    }
+
+    /// Returns size of one sample in bytes.
+    ///
+    fn sample_size(self) -> usize {
+        match self {
+            SampleFormat::S16LSB
+            | SampleFormat::S16MSB
+            | SampleFormat::U16LSB
+            | SampleFormat::U16MSB => 2,
+            _ => 4,
+        }
+    }
+    /// Decodes sample from bytes into [-1; 1] range.
+    ///
+    fn decode(self, bytes: &[u8]) -> f32 {
+        let bytes2: [u8; 2] = [bytes[0], bytes[1]];
+        let bytes4 = || -> [u8; 4] { [bytes[0], bytes[1], bytes[2], bytes[3]] };
+        match self {
+            SampleFormat::F32LSB => f32::from_le_bytes(bytes4()),
+            SampleFormat::F32MSB => f32::from_be_bytes(bytes4()),
+
+            SampleFormat::S16LSB => f32::from(i16::from_le_bytes(bytes2)) / 32768.0,
+            SampleFormat::S16MSB => f32::from(i16::from_be_bytes(bytes2)) / 32768.0,
+            SampleFormat::S32LSB => i32::from_le_bytes(bytes4()) as f32 / 2147483648.0,
+            SampleFormat::S32MSB => i32::from_be_bytes(bytes4()) as f32 / 2147483648.0,
+
+            SampleFormat::U16LSB => f32::from(u16::from_le_bytes(bytes2)) / 32768.0 - 1.0,
+            SampleFormat::U16MSB => f32::from(u16::from_be_bytes(bytes2)) / 32768.0 - 1.0,
+        }
+    }
+    /// Encodes sample from [-1; 1] range into bytes.
+    ///
+    fn encode(self, sample: f32, bytes: &mut Vec<u8>) {
+        let sample: f32 = sample.clamp(-1.0, 1.0);
+        match self {
+            SampleFormat::F32LSB => bytes.extend(sample.to_le_bytes()),
+            SampleFormat::F32MSB => bytes.extend(sample.to_be_bytes()),
+
+            SampleFormat::S16LSB => bytes.extend(((sample * 32767.0) as i16).to_le_bytes()),
+            SampleFormat::S16MSB => bytes.extend(((sample * 32767.0) as i16).to_be_bytes()),
+            SampleFormat::S32LSB => bytes.extend(((sample * 2147483647.0) as i32).to_le_bytes()),
+            SampleFormat::S32MSB => bytes.extend(((sample * 2147483647.0) as i32).to_be_bytes()),
+
+            SampleFormat::U16LSB => bytes.extend((((sample + 1.0) * 32767.5) as u16).to_le_bytes()),
+            SampleFormat::U16MSB => bytes.extend((((sample + 1.0) * 32767.5) as u16).to_be_bytes()),
+        }
+    }
+    /// Converts audio data into samples of WAV file and returns format tag and bits per sample of those samples.
+    ///
+    /// WAV samples are little-endian, and 16-bit samples are signed, so other formats are converted.
+    ///
+    fn to_wav(self, data: &[u8]) -> (u16, u16, Vec<u8>) {
+        let mut samples: Vec<u8> = Vec::with_capacity(data.len());
+        for sample in data.chunks_exact(self.sample_size()) {
+            match self {
+                SampleFormat::F32LSB | SampleFormat::S16LSB | SampleFormat::S32LSB => {
+                    samples.extend(sample)
+                }
+                SampleFormat::F32MSB | SampleFormat::S16MSB | SampleFormat::S32MSB => {
+                    samples.extend(sample.iter().rev())
+                }
+                SampleFormat::U16LSB => samples
+                    .extend((u16::from_le_bytes([sample[0], sample[1]]) ^ 0x8000).to_le_bytes()),
+                SampleFormat::U16MSB => samples
+                    .extend((u16::from_be_bytes([sample[0], sample[1]]) ^ 0x8000).to_le_bytes()),
+            }
+        }
+        let tag: u16 = match self {
+            SampleFormat::F32LSB | SampleFormat::F32MSB => 3,
+            _ => 1,
+        };
+        (tag, self.sample_size() as u16 * 8, samples)
+    }
+    /// Resamples interleaved audio data so that it plays `speed` times faster
+    /// (which also raises pitch by the same factor), using linear interpolation.
+    ///
+    fn resample(self, data: &[u8], channels: usize, speed: f32) -> Vec<u8> {
+        let frame_size: usize = self.sample_size() * channels;
+        let frames: usize = data.len() / frame_size;
+        let sample = |frame: usize, channel: usize| -> f32 {
+            let start: usize = frame * frame_size + channel * self.sample_size();
+            self.decode(&data[start..start + self.sample_size()])
+        };
+
+        let length: usize = (frames as f32 / speed).ceil() as usize;
+        let mut resampled: Vec<u8> = Vec::with_capacity(length * frame_size);
+        for i in 0..length {
+            let position: f32 = i as f32 * speed;
+            let frame: usize = (position as usize).min(frames - 1);
+            let next: usize = (frame + 1).min(frames - 1);
+            let t: f32 = position - frame as f32;
+            for channel in 0..channels {
+                let (a, b): (f32, f32) = (sample(frame, channel), sample(next, channel));
+                self.encode(a + (b - a) * t, &mut resampled);
+            }
+        }
+        resampled
+    }
 }
 impl Default for SampleFormat {
     fn default() -> Self {
@@ -588,13 +1601,357 @@ impl Default for AudioChannels {
         Self::Stereo
     }
 }
+/// [`AudioEvent`] enum lists events that are emitted by audio system.
+///
+/// Events are queued when they happen and can be polled by [`AudioSystem::poll_events`].
+///
+#[derive(Copy, Clone, Debug)]
+pub enum AudioEvent {
+    /// Sound channel has finished playing (sound has ended or channel was stopped).
+    ///
+    ChannelFinished(SoundChannel),
+    /// Music channel has finished playing (music has ended or channel was stopped).
+    ///
+    MusicFinished,
+}
+/// Returns frequency, sample format and number of channels of opened audio device.
+///
+fn device_spec() -> Result<(i32, SampleFormat, usize), GGError> {
+    let (frequency, format, channels): (i32, u16, i32) =
+        sdl2::mixer::query_spec().map_err(GGError::Sdl)?;
+    let format: SampleFormat = SampleFormat::from_sdl_u16(format)
+        .ok_or_else(|| GGError::Unsupported(String::from("Unsupported sample format")))?;
+    Ok((frequency, format, channels.max(1) as usize))
+}
+
+/// [`AudioEffect`] trait is implemented by custom effects that post-process audio data
+/// (filters, echo, visualizer taps, etc.).
+///
+/// Effect receives interleaved samples in [-1; 1] range, which are converted from format of opened audio device
+/// and are converted back after processing.
+/// Effects are called from the audio thread, so they should be fast and must not panic.
+///
+/// This trait is implemented for all closures that take samples and number of channels.
+///
+pub trait AudioEffect: Send + 'static {
+    /// Processes chunk of interleaved samples for given number of channels.
+    ///
+    fn process(&mut self, samples: &mut [f32], channels: usize);
+}
+impl<F: FnMut(&mut [f32], usize) + Send + 'static> AudioEffect for F {
+    fn process(&mut self, samples: &mut [f32], channels: usize) {
+        self(samples, channels)
+    }
+}
+/// [`EffectState`] struct holds registered effect together with format of audio data and buffers for conversion.
+///
+struct EffectState {
+    /// Registered effect.
+    ///
+    effect: Box<dyn AudioEffect>,
+    /// Format of samples of an audio device.
+    ///
+    format: SampleFormat,
+    /// Number of channels of an audio device.
+    ///
+    channels: usize,
+    /// Buffer of decoded samples.
+    ///
+    samples: Vec<f32>,
+    /// Buffer of encoded samples.
+    ///
+    bytes: Vec<u8>,
+}
+/// Registers effect on a channel with given id (`sdl2::mixer` uses -2 for post-processing of the final mix).
+///
+fn register_effect(channel: i32, effect: Box<dyn AudioEffect>) -> Result<(), GGError> {
+    let (_, format, channels): (i32, SampleFormat, usize) = device_spec()?;
+    let state: *mut EffectState = Box::into_raw(Box::new(EffectState {
+        effect,
+        format,
+        channels,
+        samples: Vec::new(),
+        bytes: Vec::new(),
+    }));
+
+    // SAFETY: `state` is a valid pointer that is owned by `sdl2::mixer` until `effect_done` is called.
+    let result: c_int = unsafe {
+        sdl2::sys::mixer::Mix_RegisterEffect(
+            channel,
+            Some(effect_callback),
+            Some(effect_done),
+            state.cast::<c_void>(),
+        )
+    };
+    if result == 0 {
+        // SAFETY: effect was not registered, so `state` is still owned by this function.
+        drop(unsafe { Box::from_raw(state) });
+        return Err(GGError::Sdl(sdl2::get_error()));
+    }
+    Ok(())
+}
+/// Callback that is called by `sdl2::mixer` to apply registered effect.
+///
+unsafe extern "C" fn effect_callback(
+    _channel: c_int,
+    stream: *mut c_void,
+    length: c_int,
+    state: *mut c_void,
+) {
+    // SAFETY: `state` was created by `register_effect` and is not freed until `effect_done` is called;
+    // `stream` points to `length` bytes of audio data that are exclusively borrowed by effect.
+    let (state, stream): (&mut EffectState, &mut [u8]) = unsafe {
+        (
+            &mut *state.cast::<EffectState>(),
+            std::slice::from_raw_parts_mut(stream.cast::<u8>(), length.max(0) as usize),
+        )
+    };
+    let sample_size: usize = state.format.sample_size();
+
+    state.samples.clear();
+    state.samples.extend(
+        stream
+            .chunks_exact(sample_size)
+            .map(|bytes| state.format.decode(bytes)),
+    );
+    state.effect.process(&mut state.samples, state.channels);
+    state.bytes.clear();
+    for sample in &state.samples {
+        state.format.encode(*sample, &mut state.bytes);
+    }
+    stream[..state.bytes.len()].copy_from_slice(&state.bytes);
+}
+/// Callback that is called by `sdl2::mixer` when registered effect is unregistered.
+///
+unsafe extern "C" fn effect_done(_channel: c_int, state: *mut c_void) {
+    // SAFETY: `state` was created by `register_effect` and `sdl2::mixer` calls this function only once.
+    drop(unsafe { Box::from_raw(state.cast::<EffectState>()) });
+}
+
+/// [`AudioCapture`] struct represents opened capture device (e.g. microphone) that records audio data.
+///
+/// Recorded samples are interleaved 32-bit floating point samples which are stored in a ring buffer -
+/// when buffer is full, the oldest samples are dropped, so buffer should be read regularly.
+/// Recording is paused when device is opened.
+///
+/// # Example
+/// ```rust, no_run
+/// # use ggengine::GGEngine;
+/// # use ggengine::datacore::audio::{AudioCapture, AudioChannels};
+/// let engine: GGEngine = GGEngine::init().expect("Engine should be initialized");
+/// println!("{:?}", AudioCapture::devices(&engine));
+///
+/// let mut capture: AudioCapture = AudioCapture::open(&engine, None, 44100, AudioChannels::Mono, 44100)
+///     .expect("Capture device should be available");
+/// capture.resume();
+/// loop {
+///     let samples: Vec<f32> = capture.read();
+///     let loudness: f32 = samples.iter().fold(0.0, |max, sample| sample.abs().max(max));
+///     // ...
+/// }
+/// ```
+///
+pub struct AudioCapture {
+    /// Underlying `sdl2` audio device.
+    ///
+    device: AudioDevice<CaptureBuffer>,
+    /// Frequency of recorded audio data.
+    ///
+    frequency: u32,
+    /// Number of channels of recorded audio data.
+    ///
+    channels: u8,
+}
+impl AudioCapture {
+    /// Returns names of available capture devices.
+    ///
+    pub fn devices(engine: &GGEngine) -> Vec<String> {
+        let Ok(audio) = engine.get_sdl_audiosubsystem() else {
+            return Vec::new();
+        };
+        (0..audio.num_audio_capture_devices().unwrap_or(0))
+            .filter_map(|index| audio.audio_capture_device_name(index).ok())
+            .collect()
+    }
+
+    /// Opens capture device with given name (or default capture device, if name is `None`).
+    ///
+    /// `frequency` and `channels` are requested from device, although device may choose other settings -
+    /// those can be checked with [`AudioCapture::frequency`] and [`AudioCapture::channels`].
+    /// `capacity` is a number of samples that ring buffer holds.
+    ///
+    pub fn open(
+        engine: &GGEngine,
+        device: Option<&str>,
+        frequency: u32,
+        channels: AudioChannels,
+        capacity: usize,
+    ) -> Result<AudioCapture, GGError> {
+        let audio: AudioSubsystem = engine.get_sdl_audiosubsystem().map_err(GGError::Sdl)?;
+        let desired: AudioSpecDesired = AudioSpecDesired {
+            freq: i32::try_from(frequency).ok(),
+            channels: Some(channels as u8),
+            samples: None,
+        };
+        let mut spec: Option<(u32, u8)> = None;
+        let device: AudioDevice<CaptureBuffer> = audio
+            .open_capture(device, &desired, |obtained: AudioSpec| {
+                spec = Some((obtained.freq as u32, obtained.channels));
+                CaptureBuffer {
+                    samples: VecDeque::with_capacity(capacity),
+                    capacity,
+                }
+            })
+            .map_err(GGError::Sdl)?;
+        let (frequency, channels): (u32, u8) = spec.unwrap_or((frequency, channels as u8));
+        Ok(AudioCapture {
+            device,
+            frequency,
+            channels,
+        })
+    }
+
+    /// Returns frequency of recorded audio data.
+    ///
+    pub fn frequency(&self) -> u32 {
+        self.frequency
+    }
+    /// Returns number of channels of recorded audio data.
+    ///
+    pub fn channels(&self) -> u8 {
+        self.channels
+    }
+
+    /// Starts or continues recording.
+    ///
+    pub fn resume(&self) {
+        self.device.resume();
+    }
+    /// Pauses recording.
+    ///
+    pub fn pause(&self) {
+        self.device.pause();
+    }
+    /// Returns whether device is recording.
+    ///
+    pub fn is_recording(&self) -> bool {
+        self.device.status() == AudioStatus::Playing
+    }
+
+    /// Returns number of samples that are stored in a buffer.
+    ///
+    pub fn available(&mut self) -> usize {
+        self.device.lock().samples.len()
+    }
+    /// Takes all samples that are stored in a buffer.
+    ///
+    pub fn read(&mut self) -> Vec<f32> {
+        self.device.lock().samples.drain(..).collect()
+    }
+}
+impl fmt::Debug for AudioCapture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AudioCapture")
+            .field("frequency", &self.frequency)
+            .field("channels", &self.channels)
+            .finish()
+    }
+}
+/// [`CaptureBuffer`] struct is a ring buffer that receives samples from capture device.
+///
+#[derive(Debug)]
+struct CaptureBuffer {
+    /// Recorded samples.
+    ///
+    samples: VecDeque<f32>,
+    /// Maximal number of stored samples.
+    ///
+    capacity: usize,
+}
+impl AudioCallback for CaptureBuffer {
+    type Channel = f32;
+
+    fn callback(&mut self, samples: &mut [f32]) {
+        self.samples.extend(samples.iter());
+        let overflow: usize = self.samples.len().saturating_sub(self.capacity);
+        let _ = self.samples.drain(..overflow);
+    }
+}
+
+/// [`AUDIO_EVENTS`] global static variable holds queue of audio events that were not polled yet.
+///
+/// Events are pushed by `sdl2::mixer` callbacks, which are called from the audio thread.
+///
+static AUDIO_EVENTS: Mutex<Vec<AudioEvent>> = Mutex::new(Vec::new());
+/// Pushes event to the queue of audio events.
+///
+fn push_audio_event(event: AudioEvent) {
+    AUDIO_EVENTS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(event);
+}
+/// Callback that is called by `sdl2::mixer` when music finishes.
+///
+fn music_finished() {
+    push_audio_event(AudioEvent::MusicFinished);
+}
+
+/// [`AudioSettings`] struct holds arguments of `AudioSystem::init`.
+///
+/// Default settings support OGG and MP3 formats and use recommended values of other arguments.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AudioSettings {
+    /// Audio formats that are going to be supported by the app.
+    ///
+    pub audio_format: AudioFormat,
+    /// Frequency to playback audio at (in Hz).
+    ///
+    pub frequency: u32,
+    /// Sample format that will be used.
+    ///
+    pub sample_format: SampleFormat,
+    /// Number of channels.
+    ///
+    pub channels: AudioChannels,
+    /// Audio buffer size in sample frames.
+    ///
+    pub chunk_size: u32,
+}
+impl Default for AudioSettings {
+    fn default() -> Self {
+        AudioSettings {
+            audio_format: AudioFormat::OGG | AudioFormat::MP3,
+            frequency: AudioSystem::DEFAULT_FREQUENCY,
+            sample_format: SampleFormat::default(),
+            channels: AudioChannels::default(),
+            chunk_size: AudioSystem::DEFAULT_CHUNK_SIZE,
+        }
+    }
+}
+
 /// [`MIXER_CONTEXT`] global static variable handles `sdl2::mixer` context.
 ///
 static MIXER_CONTEXT: OnceLock<MixerContext> = OnceLock::new();
+/// Returns `sdl2::mixer` context or error, if [`AudioSystem`] was not initialized.
+///
+/// Audio system is not initialized lazily, since it opens audio device with settings that should be chosen by a game.
+///
+fn mixer_context() -> Result<&'static MixerContext, GGError> {
+    MIXER_CONTEXT
+        .get()
+        .ok_or_else(|| GGError::SubsystemNotInitialized(String::from("AudioSystem")))
+}
 /// [`AudioSystem`] is a global handler for audio metadata.
 ///
 /// ### `AudioSystem::init` should be called before using anything else from this submodule.
 ///
+/// Audio data cannot be loaded before that (`GGError::SubsystemNotInitialized` is returned).
+///
+/// Alternatively, audio system can be declared in [`GGEngineBuilder`](crate::GGEngineBuilder),
+/// which initializes it together with other subsystems.
+///
 #[derive(Copy, Clone, Debug)]
 pub enum AudioSystem {}
 impl AudioSystem {
@@ -618,8 +1975,9 @@ impl AudioSystem {
     ///    For instance, a chunk size of 256 will give you a latency of 6ms, while a chunk size of 1024 will give you a latency of 23ms for a frequency of 44100kHz.
     ///    Recommended value is provided as [`AudioSystem::DEFAULT_CHUNK_SIZE`] const.
     ///
-    /// # Panics
-    /// This function panics when `frequency` or `chunk_size` exceed `i32::MAX`.
+    /// # Errors
+    /// Error is returned when `frequency` or `chunk_size` exceed `i32::MAX`
+    /// or when audio driver or audio device are not available.
     ///
     /// ### `AudioSystem::init` should be called before using anything else from `ggengine::datacore::audio` submodule.
     ///
@@ -629,25 +1987,151 @@ impl AudioSystem {
         sample_format: SampleFormat,
         channels: AudioChannels,
         chunk_size: u32,
-    ) {
-        if MIXER_CONTEXT
-            .set(
-                mixer_init(MixerInitFlag::from_bits(audio_format.bits()).expect(
-                    "`AudioFormat` constants are the same as in `InitFlag` bitflags struct",
-                ))
-                .expect("Audio driver should be available"),
-            )
-            .is_err()
-        {
-            return;
+    ) -> Result<(), GGError> {
+        if MIXER_CONTEXT.get().is_some() {
+            return Ok(());
         }
+        let frequency: i32 = i32::try_from(frequency).map_err(|_| {
+            GGError::InvalidArgument(String::from("Frequency value should not exceed `i32::MAX`"))
+        })?;
+        let chunk_size: i32 = i32::try_from(chunk_size).map_err(|_| {
+            GGError::InvalidArgument(String::from(
+                "Chunk size value should not exceed `i32::MAX`",
+            ))
+        })?;
+        let context: MixerContext = mixer_init(
+            MixerInitFlag::from_bits(audio_format.bits())
+                .expect("`AudioFormat` constants are the same as in `InitFlag` bitflags struct"),
+        )
+        .map_err(GGError::Sdl)?;
         mixer_open_audio(
-            i32::try_from(frequency).expect("Frequency value should not exceed `i32::MAX`"),
+            frequency,
             sample_format.to_sdl_u16(),
             channels as i32,
-            i32::try_from(chunk_size).expect("Chunk size value should not exceed `i32::MAX`"),
+            chunk_size,
         )
-        .expect("Audio device should be available");
+        .map_err(GGError::Sdl)?;
+        let _ = MIXER_CONTEXT.set(context);
+
+        mixer_set_channel_finished(|channel: MixerChannel| {
+            push_audio_event(AudioEvent::ChannelFinished(SoundChannel(channel)))
+        });
+        MixerMusic::hook_finished(music_finished);
+        Ok(())
+    }
+
+    /// Returns whether audio system is initialized.
+    ///
+    pub fn is_initialized() -> bool {
+        MIXER_CONTEXT.get().is_some()
+    }
+
+    /// Returns all audio events that happened since the last poll, in order of their occurrence.
+    ///
+    /// Polling events is cheaper than checking `is_playing` of every channel each frame,
+    /// and it allows sequencing sounds or freeing channels that were used by one-shot sounds.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use ggengine::datacore::audio::{AudioEvent, AudioSystem, SoundChannel};
+    /// for event in AudioSystem::poll_events() {
+    ///     match event {
+    ///         AudioEvent::ChannelFinished(channel) => println!("Channel {} is free", channel.id()),
+    ///         AudioEvent::MusicFinished => println!("Music has ended"),
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub fn poll_events() -> Vec<AudioEvent> {
+        std::mem::take(&mut *AUDIO_EVENTS.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Plays sound once on a channel that is picked automatically and returns that channel.
+    ///
+    /// Channel is assigned to [`ChannelGroup::Sfx`] group.
+    /// `None` is returned if all channels are busy and none of them could be stolen
+    /// (see [`AudioSystem::set_voice_stealing`]).
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use ggengine::datacore::audio::{AudioSystem, Sound};
+    /// # use ggengine::datacore::assets::FromFile;
+    /// # use std::path::Path;
+    /// let sound: Sound = Sound::from_file(Path::new("shot.wav")).expect("Filename should be correct");
+    /// for _ in 0..16 {
+    ///     let _ = AudioSystem::play(&sound);
+    /// }
+    /// ```
+    ///
+    pub fn play(sound: &Sound) -> Result<Option<SoundChannel>, GGError> {
+        AudioSystem::play_with(sound, PlaySettings::default())
+    }
+    /// Plays sound with given settings on a channel that is picked automatically and returns that channel.
+    ///
+    /// `None` is returned if all channels are busy and none of them could be stolen
+    /// (see [`AudioSystem::set_voice_stealing`]).
+    ///
+    pub fn play_with(
+        sound: &Sound,
+        settings: PlaySettings,
+    ) -> Result<Option<SoundChannel>, GGError> {
+        let mut state = MIXER_STATE.lock().unwrap_or_else(PoisonError::into_inner);
+        let channel: SoundChannel = match state.pick_channel() {
+            Some(channel) => channel,
+            None => return Ok(None),
+        };
+
+        channel.stop();
+        state.assign_group(channel, settings.group);
+        match settings.fade_in {
+            Some(fading_time) => channel.fade_in(sound, settings.loops, fading_time)?,
+            None => channel.play(sound, settings.loops)?,
+        }
+        state.plays += 1;
+        let order: u64 = state.plays;
+        let _ = state.started.insert(channel.id(), order);
+        Ok(Some(channel))
+    }
+    /// Sets policy of stealing channels when all channels are busy.
+    ///
+    /// By default, channel with the oldest sound is stolen.
+    ///
+    pub fn set_voice_stealing(policy: VoiceStealing) {
+        MIXER_STATE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .voice_stealing = policy;
+    }
+    /// Returns policy of stealing channels.
+    ///
+    pub fn voice_stealing() -> VoiceStealing {
+        MIXER_STATE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .voice_stealing
+    }
+
+    /// Sets master volume, which is combined with volumes of all channel groups.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use ggengine::datacore::audio::{AudioSystem, Volume};
+    /// AudioSystem::set_master_volume(Volume::from_percents(25));
+    /// assert_eq!(AudioSystem::master_volume().get_percents(), 25);
+    /// ```
+    ///
+    pub fn set_master_volume(volume: Volume) {
+        let mut state = MIXER_STATE.lock().unwrap_or_else(PoisonError::into_inner);
+        state.master_volume = volume;
+        state.apply_volumes();
+    }
+    /// Returns master volume.
+    ///
+    pub fn master_volume() -> Volume {
+        MIXER_STATE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .master_volume
     }
 
     /// Allocates exact number of sound channels. Any channels that have id greater than or equal to `channels` will be stopped automatically.
@@ -660,4 +2144,18 @@ impl AudioSystem {
         let _ = mixer_allocate_channels(i32::try_from(channels)?);
         Ok(())
     }
+
+    /// Registers custom effect that post-processes the final mix of all channels and music
+    /// (e.g. visualizer tap or master filter).
+    ///
+    pub fn add_post_effect(effect: impl AudioEffect) -> Result<(), GGError> {
+        register_effect(-2, Box::new(effect))
+    }
+    /// Unregisters all effects that post-process the final mix.
+    ///
+    pub fn clear_post_effects() -> Result<(), GGError> {
+        MixerChannel::post()
+            .unregister_all_effects()
+            .map_err(GGError::Sdl)
+    }
 }
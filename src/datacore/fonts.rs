@@ -11,6 +11,7 @@
 use crate::{
     datacore::{assets::FromFile, images::Image},
     mathcore::{vectors::PointInt, Color},
+    GGError,
 };
 use bitflags::bitflags;
 use sdl2::ttf::{
@@ -19,12 +20,7 @@ use sdl2::ttf::{
     Sdl2TtfContext as TTFContext,
 };
 use std::path::PathBuf;
-use std::{
-    fmt,
-    io::{Error, ErrorKind},
-    path::Path,
-    sync::OnceLock,
-};
+use std::{fmt, path::Path, sync::OnceLock};
 
 /// [`FontShowMode`] enum lists possible modes for showing truetype fonts.
 ///
@@ -76,7 +72,7 @@ pub enum FontShowMode {
 impl FontShowMode {
     /// Applies showing mode to font to obtain image.
     ///
-    fn apply<'a>(self, show_object: TTFPartialRendering) -> Result<Image<'a>, Error> {
+    fn apply<'a>(self, show_object: TTFPartialRendering) -> Result<Image<'a>, GGError> {
         (match self {
             FontShowMode::Solid { color } => show_object.solid(color.to_rgba()),
             FontShowMode::Shaded { color, background } => {
@@ -89,12 +85,11 @@ impl FontShowMode {
             } => show_object.blended_wrapped(color.to_rgba(), wrap_max_width),
         })
         .map(|surface| Image::from_sdl_surface(PathBuf::new(), surface))
-        .map_err(|error| {
-            let message: String = match error {
-                TTFontError::InvalidLatin1Text(_) => String::from("Invalid Latin-1 text"),
-                TTFontError::SdlError(message) => message,
-            };
-            Error::new(ErrorKind::InvalidData, message)
+        .map_err(|error| match error {
+            TTFontError::InvalidLatin1Text(_) => {
+                GGError::InvalidArgument(String::from("Invalid Latin-1 text"))
+            }
+            TTFontError::SdlError(message) => GGError::Sdl(message),
         })
     }
 }
@@ -199,18 +194,17 @@ impl PartialFont {
     /// # use ggengine::datacore::fonts::FontSystem;
     /// # use ggengine::datacore::assets::FromFile;
     /// # use std::path::Path;
-    /// FontSystem::init();
+    /// FontSystem::init().expect("Font driver should be available");
     /// let partial_font = PartialFont::from_file(Path::new("font.ttf"))
     ///     .expect("Filename should be correct");
     /// let font = partial_font.with_size(14).expect("FontSystem::init was called");
     /// ```
     ///
-    pub fn with_size(&self, point_size: u16) -> Result<Font, Error> {
+    pub fn with_size(&self, point_size: u16) -> Result<Font, GGError> {
         Ok(Font {
-            font: TTF_CONTEXT
-                .get()
-                .expect("`FontSystem::init` should be called before using anything else from `ggengine::datacore::fonts` submodule")
-                .load_font(&self.filename, point_size).map_err(|message| Error::new(ErrorKind::NotFound, message))?,
+            font: font_context()?
+                .load_font(&self.filename, point_size)
+                .map_err(GGError::Sdl)?,
         })
     }
 
@@ -222,25 +216,24 @@ impl PartialFont {
     /// # use ggengine::datacore::fonts::FontSystem;
     /// # use ggengine::datacore::assets::FromFile;
     /// # use std::path::Path;
-    /// FontSystem::init();
+    /// FontSystem::init().expect("Font driver should be available");
     /// let partial_font = PartialFont::from_file(Path::new("font.ttf"))
     ///     .expect("Filename should be correct");
     /// let font = partial_font.with_size_at_index(14, 0).expect("FontSystem::init was called");
     /// ```
     ///
-    pub fn with_size_at_index(&self, point_size: u16, index: u32) -> Result<Font, Error> {
+    pub fn with_size_at_index(&self, point_size: u16, index: u32) -> Result<Font, GGError> {
         Ok(Font {
-            font: TTF_CONTEXT
-                .get()
-                .expect("`FontSystem::init` should be called before using anything else from `ggengine::datacore::fonts` submodule")
-                .load_font_at_index(&self.filename, index, point_size).map_err(|message| Error::new(ErrorKind::NotFound, message))?,
+            font: font_context()?
+                .load_font_at_index(&self.filename, index, point_size)
+                .map_err(GGError::Sdl)?,
         })
     }
 }
 impl FromFile for PartialFont {
     /// Partially initializes font from file.
     ///
-    fn from_file(filename: impl AsRef<Path>) -> Result<Self, Error> {
+    fn from_file(filename: impl AsRef<Path>) -> Result<Self, GGError> {
         Ok(Self {
             filename: filename.as_ref().to_path_buf(),
         })
@@ -261,7 +254,7 @@ impl fmt::Debug for PartialFont {
 /// # use ggengine::datacore::assets::FromFile;
 /// # use ggengine::mathcore::Color;
 /// # use std::path::Path;
-/// FontSystem::init();
+/// FontSystem::init().expect("Font driver should be available");
 /// let font: Font = PartialFont::from_file(Path::new("font.ttf")).expect("Filename should be correct")
 ///     .with_size(14).expect("FontSystem::init was called");
 /// font.show_text(FontShowMode::Solid { color: Color::BLACK }, "ggengine")
@@ -282,7 +275,7 @@ impl Font {
     /// # use ggengine::datacore::assets::FromFile;
     /// # use ggengine::mathcore::Color;
     /// # use std::path::Path;
-    /// FontSystem::init();
+    /// FontSystem::init().expect("Font driver should be available");
     /// let font: Font = PartialFont::from_file(Path::new("font.ttf"))
     ///     .expect("Filename should be correct")
     ///     .with_size(14).expect("FontSystem::init was called");
@@ -290,7 +283,7 @@ impl Font {
     ///     .expect("Conversion should not fail");
     /// ```
     ///
-    pub fn show_text(&self, mode: FontShowMode, text: &str) -> Result<Image, Error> {
+    pub fn show_text(&self, mode: FontShowMode, text: &str) -> Result<Image, GGError> {
         mode.apply(self.font.render(text))
     }
     /// Transforms given character using this font and given [`FontShowMode`] into image.
@@ -301,7 +294,7 @@ impl Font {
     /// # use ggengine::datacore::assets::FromFile;
     /// # use ggengine::mathcore::Color;
     /// # use std::path::Path;
-    /// FontSystem::init();
+    /// FontSystem::init().expect("Font driver should be available");
     /// let font: Font = PartialFont::from_file(Path::new("font.ttf"))
     ///     .expect("Filename should be correct")
     ///     .with_size(14).expect("FontSystem::init was called");
@@ -309,7 +302,7 @@ impl Font {
     ///     .expect("Conversion should not fail");
     /// ```
     ///
-    pub fn show_character(&self, mode: FontShowMode, character: char) -> Result<Image, Error> {
+    pub fn show_character(&self, mode: FontShowMode, character: char) -> Result<Image, GGError> {
         mode.apply(self.font.render_char(character))
     }
     /// Transforms given Latin-1 text using this font and given [`FontShowMode`] into image.
@@ -320,7 +313,7 @@ impl Font {
     /// # use ggengine::datacore::assets::FromFile;
     /// # use ggengine::mathcore::Color;
     /// # use std::path::Path;
-    /// FontSystem::init();
+    /// FontSystem::init().expect("Font driver should be available");
     /// let font: Font = PartialFont::from_file(Path::new("font.ttf"))
     ///     .expect("Filename should be correct")
     ///     .with_size(14).expect("FontSystem::init was called");
@@ -329,7 +322,11 @@ impl Font {
     /// ).expect("Conversion should not fail");
     /// ```
     ///
-    pub fn show_latin1_text(&self, mode: FontShowMode, latin1_text: &[u8]) -> Result<Image, Error> {
+    pub fn show_latin1_text(
+        &self,
+        mode: FontShowMode,
+        latin1_text: &[u8],
+    ) -> Result<Image, GGError> {
         mode.apply(self.font.render_latin1(latin1_text))
     }
 
@@ -471,21 +468,39 @@ impl fmt::Debug for Font {
 /// [`TTF_CONTEXT`] global static variable handles `sdl2::ttf` context.
 ///
 static TTF_CONTEXT: OnceLock<TTFContext> = OnceLock::new();
+/// Returns `sdl2::ttf` context, initializing [`FontSystem`] if it was not initialized.
+///
+fn font_context() -> Result<&'static TTFContext, GGError> {
+    FontSystem::init()?;
+    TTF_CONTEXT
+        .get()
+        .ok_or_else(|| GGError::SubsystemNotInitialized(String::from("FontSystem")))
+}
 /// [`FontSystem`] is a global handler for truetype fonts metadata.
 ///
-/// ### `FontSystem::init` should be called before using anything else from this submodule.
+/// Font system is initialized when fonts are loaded for the first time, but `FontSystem::init`
+/// can be called beforehand to handle missing font driver at startup.
+///
+/// Font system can also be declared in [`GGEngineBuilder`](crate::GGEngineBuilder),
+/// which initializes it together with other subsystems.
 ///
 #[derive(Copy, Clone, Debug)]
 pub enum FontSystem {}
 impl FontSystem {
     /// Initializes truetype font system, prepares libraries for use and allows different formats to be opened.
     ///
-    /// ### `FontSystem::init` should be called before using anything else from `ggengine::datacore::fonts` submodule.
+    /// Error is returned if font driver is not available.
     ///
-    pub fn init() {
+    pub fn init() -> Result<(), GGError> {
         if TTF_CONTEXT.get().is_some() {
-            return;
+            return Ok(());
         }
-        let _ = TTF_CONTEXT.set(ttf_init().expect("Font driver should be available"));
+        let _ = TTF_CONTEXT.set(ttf_init().map_err(|error| GGError::Sdl(error.to_string()))?);
+        Ok(())
+    }
+    /// Returns whether font system is initialized.
+    ///
+    pub fn is_initialized() -> bool {
+        TTF_CONTEXT.get().is_some()
     }
 }
@@ -0,0 +1,409 @@
+//! `datacore::gif` hidden submodule implements decoder of animated GIF files,
+//! which is used to load [`AnimatedImage`](super::images::AnimatedImage)s.
+//!
+
+use crate::GGError;
+
+/// [`GifFrame`] struct holds fully composed frame of GIF animation.
+///
+#[derive(Clone, Debug)]
+pub(crate) struct GifFrame {
+    /// Pixels of a frame in RGBA byte order (4 bytes per pixel, rows are not padded).
+    ///
+    pub(crate) pixels: Vec<u8>,
+    /// Delay of a frame in hundredths of a second.
+    ///
+    pub(crate) delay: u16,
+}
+
+/// [`Gif`] struct holds decoded GIF animation.
+///
+#[derive(Clone, Debug)]
+pub(crate) struct Gif {
+    /// Width of an animation.
+    ///
+    pub(crate) width: u32,
+    /// Height of an animation.
+    ///
+    pub(crate) height: u32,
+    /// Number of additional repetitions of an animation (`None` means that animation loops infinitely).
+    ///
+    pub(crate) loops: Option<u16>,
+    /// Frames of an animation.
+    ///
+    pub(crate) frames: Vec<GifFrame>,
+}
+
+/// Returns error that signals malformed GIF data.
+///
+fn malformed(message: &str) -> GGError {
+    GGError::Decode(format!("Malformed GIF: {message}"))
+}
+
+/// [`Reader`] struct reads bytes of GIF data.
+///
+#[derive(Debug)]
+struct Reader<'a> {
+    /// GIF data.
+    ///
+    data: &'a [u8],
+    /// Current position.
+    ///
+    position: usize,
+}
+impl<'a> Reader<'a> {
+    /// Reads given number of bytes.
+    ///
+    fn bytes(&mut self, count: usize) -> Result<&'a [u8], GGError> {
+        let bytes: &[u8] = self
+            .data
+            .get(self.position..self.position + count)
+            .ok_or_else(|| malformed("unexpected end of data"))?;
+        self.position += count;
+        Ok(bytes)
+    }
+    /// Reads one byte.
+    ///
+    fn u8(&mut self) -> Result<u8, GGError> {
+        Ok(self.bytes(1)?[0])
+    }
+    /// Reads little-endian `u16`.
+    ///
+    fn u16(&mut self) -> Result<u16, GGError> {
+        let bytes: &[u8] = self.bytes(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+    /// Reads sequence of data sub-blocks and concatenates them.
+    ///
+    fn sub_blocks(&mut self) -> Result<Vec<u8>, GGError> {
+        let mut data: Vec<u8> = Vec::new();
+        loop {
+            let size: usize = self.u8()? as usize;
+            if size == 0 {
+                return Ok(data);
+            }
+            data.extend_from_slice(self.bytes(size)?);
+        }
+    }
+    /// Reads color table of given size.
+    ///
+    fn color_table(&mut self, packed: u8) -> Result<Vec<[u8; 3]>, GGError> {
+        let size: usize = 2 << (packed & 0b111);
+        Ok(self
+            .bytes(size * 3)?
+            .chunks_exact(3)
+            .map(|color| [color[0], color[1], color[2]])
+            .collect())
+    }
+}
+
+/// Decodes LZW-compressed indices of pixels.
+///
+/// Decoding stops at the end code or when `count` indices are decoded.
+///
+fn decompress(data: &[u8], min_code_size: u8, count: usize) -> Result<Vec<u8>, GGError> {
+    if !(1..=11).contains(&min_code_size) {
+        return Err(malformed("invalid LZW code size"));
+    }
+    let clear: u16 = 1 << min_code_size;
+    let end: u16 = clear + 1;
+
+    // every entry is a prefix code, the last byte and the first byte of a string
+    let mut table: Vec<(u16, u8, u8)> = (0..clear).map(|i| (u16::MAX, i as u8, i as u8)).collect();
+    table.extend([(u16::MAX, 0, 0), (u16::MAX, 0, 0)]);
+    let mut code_size: u8 = min_code_size + 1;
+    let mut previous: Option<u16> = None;
+
+    let mut indices: Vec<u8> = Vec::with_capacity(count);
+    let mut string: Vec<u8> = Vec::new();
+    let (mut bits, mut bit_count): (u32, u8) = (0, 0);
+    let mut bytes = data.iter();
+    while indices.len() < count {
+        while bit_count < code_size {
+            match bytes.next() {
+                Some(byte) => {
+                    bits |= u32::from(*byte) << bit_count;
+                    bit_count += 8;
+                }
+                None => return Ok(indices),
+            }
+        }
+        let code: u16 = (bits & ((1 << code_size) - 1)) as u16;
+        bits >>= code_size;
+        bit_count -= code_size;
+
+        if code == clear {
+            table.truncate(end as usize + 1);
+            code_size = min_code_size + 1;
+            previous = None;
+            continue;
+        }
+        if code == end {
+            break;
+        }
+
+        let first: u8 = match (previous, table.get(code as usize)) {
+            (_, Some(entry)) => entry.2,
+            (Some(previous), None) if code as usize == table.len() => table[previous as usize].2,
+            _ => return Err(malformed("invalid LZW code")),
+        };
+        if let Some(previous) = previous {
+            if table.len() < 4096 {
+                table.push((previous, first, table[previous as usize].2));
+            }
+        }
+
+        string.clear();
+        let mut current: u16 = code;
+        while current != u16::MAX {
+            let (prefix, last, _): (u16, u8, u8) = table[current as usize];
+            string.push(last);
+            current = prefix;
+        }
+        indices.extend(string.iter().rev());
+
+        if table.len() == 1 << code_size && code_size < 12 {
+            code_size += 1;
+        }
+        previous = Some(code);
+    }
+    indices.truncate(count);
+    Ok(indices)
+}
+
+/// Decodes GIF data into composed frames.
+///
+pub(crate) fn decode(data: &[u8]) -> Result<Gif, GGError> {
+    let mut reader: Reader = Reader { data, position: 0 };
+    let signature: &[u8] = reader.bytes(6)?;
+    if signature != b"GIF87a" && signature != b"GIF89a" {
+        return Err(GGError::Unsupported(String::from("Data is not a GIF")));
+    }
+
+    let (width, height): (u16, u16) = (reader.u16()?, reader.u16()?);
+    let packed: u8 = reader.u8()?;
+    let _background: u8 = reader.u8()?;
+    let _aspect: u8 = reader.u8()?;
+    let global_table: Vec<[u8; 3]> = if packed & 0x80 != 0 {
+        reader.color_table(packed)?
+    } else {
+        Vec::new()
+    };
+
+    let (width, height): (usize, usize) = (width as usize, height as usize);
+    let mut canvas: Vec<u8> = vec![0; width * height * 4];
+    let mut gif: Gif = Gif {
+        width: width as u32,
+        height: height as u32,
+        loops: Some(0),
+        frames: Vec::new(),
+    };
+    // disposal method, delay and transparent index of the next frame
+    let mut control: (u8, u16, Option<u8>) = (0, 0, None);
+    loop {
+        match reader.u8()? {
+            // extension
+            0x21 => match reader.u8()? {
+                // graphic control extension
+                0xF9 => {
+                    let block: Vec<u8> = reader.sub_blocks()?;
+                    if block.len() < 4 {
+                        return Err(malformed("short graphic control extension"));
+                    }
+                    control = (
+                        (block[0] >> 2) & 0b111,
+                        u16::from_le_bytes([block[1], block[2]]),
+                        (block[0] & 1 != 0).then_some(block[3]),
+                    );
+                }
+                // application extension
+                0xFF => {
+                    let size: usize = reader.u8()? as usize;
+                    let identifier: &[u8] = reader.bytes(size)?;
+                    let block: Vec<u8> = reader.sub_blocks()?;
+                    if identifier.starts_with(b"NETSCAPE") && block.len() >= 3 && block[0] == 1 {
+                        gif.loops = match u16::from_le_bytes([block[1], block[2]]) {
+                            0 => None,
+                            loops => Some(loops),
+                        };
+                    }
+                }
+                _ => {
+                    let _ = reader.sub_blocks()?;
+                }
+            },
+            // image descriptor
+            0x2C => {
+                let (left, top): (usize, usize) = (reader.u16()? as usize, reader.u16()? as usize);
+                let (frame_width, frame_height): (usize, usize) =
+                    (reader.u16()? as usize, reader.u16()? as usize);
+                let packed: u8 = reader.u8()?;
+                let local_table: Vec<[u8; 3]> = if packed & 0x80 != 0 {
+                    reader.color_table(packed)?
+                } else {
+                    Vec::new()
+                };
+                let table: &[[u8; 3]] = if local_table.is_empty() {
+                    &global_table
+                } else {
+                    &local_table
+                };
+                let min_code_size: u8 = reader.u8()?;
+                let indices: Vec<u8> = decompress(
+                    &reader.sub_blocks()?,
+                    min_code_size,
+                    frame_width * frame_height,
+                )?;
+
+                let rows: Vec<usize> = if packed & 0x40 != 0 {
+                    [(0, 8), (4, 8), (2, 4), (1, 2)]
+                        .into_iter()
+                        .flat_map(|(start, step)| (start..frame_height).step_by(step))
+                        .collect()
+                } else {
+                    (0..frame_height).collect()
+                };
+                let (disposal, delay, transparent): (u8, u16, Option<u8>) = control;
+                let previous: Option<Vec<u8>> = (disposal == 3).then(|| canvas.clone());
+                for (i, index) in indices.iter().enumerate() {
+                    let (x, y): (usize, usize) =
+                        (left + i % frame_width, top + rows[i / frame_width]);
+                    if x >= width || y >= height || Some(*index) == transparent {
+                        continue;
+                    }
+                    if let Some([r, g, b]) = table.get(*index as usize) {
+                        canvas[(y * width + x) * 4..][..4].copy_from_slice(&[*r, *g, *b, 255]);
+                    }
+                }
+                gif.frames.push(GifFrame {
+                    pixels: canvas.clone(),
+                    delay,
+                });
+
+                match (disposal, previous) {
+                    (2, _) => {
+                        for y in top..(top + frame_height).min(height) {
+                            for x in left..(left + frame_width).min(width) {
+                                canvas[(y * width + x) * 4..][..4].fill(0);
+                            }
+                        }
+                    }
+                    (3, Some(previous)) => canvas = previous,
+                    _ => {}
+                }
+                control = (0, 0, None);
+            }
+            // trailer
+            0x3B => break,
+            _ => return Err(malformed("unknown block")),
+        }
+    }
+
+    if gif.frames.is_empty() {
+        return Err(malformed("no frames"));
+    }
+    Ok(gif)
+}
+
+#[cfg(test)]
+mod tests {
+    /// Compresses indices by emitting clear code before every index, so code size never changes.
+    ///
+    fn compress(indices: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear: u32 = 1 << min_code_size;
+        let code_size: u32 = u32::from(min_code_size) + 1;
+        let codes: Vec<u32> = indices
+            .iter()
+            .flat_map(|index| [clear, u32::from(*index)])
+            .chain([clear + 1])
+            .collect();
+
+        let (mut bytes, mut bits, mut bit_count): (Vec<u8>, u32, u32) = (Vec::new(), 0, 0);
+        for code in codes {
+            bits |= code << bit_count;
+            bit_count += code_size;
+            while bit_count >= 8 {
+                bytes.push(bits as u8);
+                bits >>= 8;
+                bit_count -= 8;
+            }
+        }
+        if bit_count > 0 {
+            bytes.push(bits as u8);
+        }
+        bytes
+    }
+
+    #[test]
+    fn decompress() {
+        use super::decompress;
+
+        // 1x1 white pixel from a well-known minimal GIF
+        assert_eq!(
+            decompress(&[0x44, 0x01], 2, 1).expect("Data is valid"),
+            vec![0]
+        );
+        let indices: Vec<u8> = vec![1, 1, 1, 1, 1, 1, 2, 3, 2, 3, 2, 3, 0];
+        let compressed: Vec<u8> = compress(&indices, 2);
+        assert_eq!(
+            decompress(&compressed, 2, indices.len()).expect("Data is valid"),
+            indices
+        );
+        // LZW stream that uses KwKwK case: clear, 1, 6, end (decodes to 1 1 1)
+        let codes: [u32; 4] = [4, 1, 6, 5];
+        let bits: u32 = codes
+            .iter()
+            .enumerate()
+            .fold(0, |bits, (i, code)| bits | code << (3 * i));
+        assert_eq!(
+            decompress(&bits.to_le_bytes()[..2], 2, 3).expect("Data is valid"),
+            vec![1, 1, 1]
+        );
+    }
+
+    #[test]
+    fn decode() {
+        use super::{decode, Gif};
+
+        let mut data: Vec<u8> = b"GIF89a".to_vec();
+        // 2x2 canvas with global color table of 4 colors
+        data.extend([2, 0, 2, 0, 0x81, 0, 0]);
+        data.extend([255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0]);
+        // loops infinitely
+        data.extend([0x21, 0xFF, 11]);
+        data.extend(b"NETSCAPE2.0");
+        data.extend([3, 1, 0, 0, 0]);
+        // first frame: whole canvas, delay of 10, restore to background afterwards
+        data.extend([0x21, 0xF9, 4, 2 << 2, 10, 0, 0, 0]);
+        data.extend([0x2C, 0, 0, 0, 0, 2, 0, 2, 0, 0, 2]);
+        let compressed: Vec<u8> = compress(&[0, 1, 2, 3], 2);
+        data.push(compressed.len() as u8);
+        data.extend(&compressed);
+        data.push(0);
+        // second frame: lower right pixel, index 3 is transparent
+        data.extend([0x21, 0xF9, 4, 1, 20, 0, 3, 0]);
+        data.extend([0x2C, 1, 0, 1, 0, 1, 0, 1, 0, 0, 2]);
+        let compressed: Vec<u8> = compress(&[1], 2);
+        data.push(compressed.len() as u8);
+        data.extend(&compressed);
+        data.extend([0, 0x3B]);
+
+        let gif: Gif = decode(&data).expect("Data is valid");
+        assert_eq!((gif.width, gif.height, gif.loops), (2, 2, None));
+        assert_eq!(gif.frames.len(), 2);
+        assert_eq!(gif.frames[0].delay, 10);
+        assert_eq!(
+            gif.frames[0].pixels,
+            vec![255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255, 0, 0, 0, 255]
+        );
+        // first frame was disposed to transparent background
+        assert_eq!(gif.frames[1].delay, 20);
+        assert_eq!(
+            gif.frames[1].pixels,
+            vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 255]
+        );
+
+        assert!(decode(b"GIF89a").is_err());
+        assert!(decode(b"PNG").is_err());
+    }
+}
@@ -1,35 +1,53 @@
 //! `datacore::images` submodule supplies instruments that help in work with image data.
 //!
 //! This submodule provides structs and enums which represent color and image data,
-//! [`PixelFormat`] lists possible pixel formats and [`Image`] encapsulates work with images.
+//! [`PixelFormat`] lists possible pixel formats, [`Image`] encapsulates work with images
+//! and [`AnimatedImage`] holds frames of animated images.
 //!
 //! To further understand relations between those structs, traits, enums and constants, it is encouraged to read docs for submodule items.
 //!
 
-use crate::datacore::assets::{FromFile, ToFile};
+use crate::{
+    datacore::{
+        assets::{FromFile, ToFile},
+        gif,
+    },
+    mathcore::Color,
+    GGError,
+};
 use bitflags::bitflags;
 use sdl2::{
     image::{
         init as image_init, InitFlag as ImageInitFlag, LoadSurface as ImageLoadSurface,
         SaveSurface as ImageSaveSurface, Sdl2ImageContext as ImageContext,
     },
-    pixels::PixelFormatEnum as ImagePixelFormatEnum,
+    pixels::{
+        Color as SdlColor, Palette as SdlPalette, PixelFormat as SdlPixelFormat,
+        PixelFormatEnum as ImagePixelFormatEnum,
+    },
     rect::Rect as Sdl2Rect,
     surface::Surface as ImageSurface,
 };
 use std::{
-    fmt,
-    io::{Error, ErrorKind},
+    ffi::CString,
+    fmt, fs,
+    ops::Range,
     path::{Path, PathBuf},
     sync::OnceLock,
+    time::Duration,
 };
 
 /// [`PixelFormat`] enum lists all possible formats of color encoding.
 ///
 /// Only RGB-based formats are supported, some with alpha channel and some without it.
+/// The only exception is [`PixelFormat::Index8`] format, in which pixels are indices into the palette of an image.
 ///
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum PixelFormat {
+    /// 8 bit indexed color format (pixel is an index of color in the palette of an image).
+    ///
+    Index8 = 318_769_153,
+
     /// RGB332 color format.
     ///
     RGB332 = 336_660_481,
@@ -148,6 +166,8 @@ impl PixelFormat {
     ///
     pub(crate) fn from_sdl_pixel_format_enum(pixel_format: ImagePixelFormatEnum) -> Option<Self> {
         Some(match pixel_format {
+            ImagePixelFormatEnum::Index8 => Self::Index8,
+
             ImagePixelFormatEnum::RGB332 => Self::RGB332,
             ImagePixelFormatEnum::RGB444 => Self::RGB444,
             ImagePixelFormatEnum::RGB555 => Self::RGB555,
@@ -185,6 +205,8 @@ impl PixelFormat {
     ///
     pub(crate) fn to_sdl_pixel_format_enum(self) -> ImagePixelFormatEnum {
         match self {
+            Self::Index8 => ImagePixelFormatEnum::Index8,
+
             Self::RGB332 => ImagePixelFormatEnum::RGB332,
             Self::RGB444 => ImagePixelFormatEnum::RGB444,
             Self::RGB555 => ImagePixelFormatEnum::RGB555,
@@ -220,7 +242,7 @@ impl PixelFormat {
     ///
     pub fn pixel_byte_size(&self) -> usize {
         match self {
-            Self::RGB332 => 1,
+            Self::Index8 | Self::RGB332 => 1,
 
             Self::RGB444
             | Self::RGB555
@@ -299,13 +321,83 @@ impl ImageArea {
     // All functions that are providing gate between `ggengine` and `sdl2` extend their API to `crate` visibility.
     /// Constructs `sdl2` rect that matches image area.
     ///
-    pub(crate) fn to_sdl_rect(self) -> Sdl2Rect {
-        Sdl2Rect::new(
-            i32::try_from(self.left_upper.0).expect("Area width should not exceed `i32::MAX`"),
-            i32::try_from(self.left_upper.0).expect("Area height should not exceed `i32::MAX`"),
+    /// `sdl2` rects cannot represent coordinates that exceed `i32::MAX / 2`,
+    /// so error is returned for areas with such coordinates instead of silently clamping them.
+    ///
+    pub(crate) fn try_to_rect(self) -> Result<Sdl2Rect, GGError> {
+        let max: u32 = sdl2::rect::max_int_value();
+        if self.right_lower.0 > max || self.right_lower.1 > max {
+            return Err(GGError::InvalidArgument(String::from(
+                "Image area coordinates should not exceed `i32::MAX / 2`",
+            )));
+        }
+        Ok(Sdl2Rect::new(
+            self.left_upper.0 as i32,
+            self.left_upper.1 as i32,
             self.width(),
             self.height(),
-        )
+        ))
+    }
+
+    /// Returns whether bounded part is empty (its width or height is zero).
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.width() == 0 || self.height() == 0
+    }
+    /// Returns area that is clamped to bounds of image with given size.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::datacore::images::ImageArea;
+    /// let area: ImageArea = ImageArea::from(((50, 150), (200, 250)));
+    /// let clamped: ImageArea = area.clamp((100, 100));
+    /// assert_eq!(clamped.left_upper(), (50, 100));
+    /// assert_eq!(clamped.right_lower(), (100, 100));
+    /// assert!(clamped.is_empty());
+    /// ```
+    ///
+    pub fn clamp(self, (width, height): (u32, u32)) -> ImageArea {
+        ImageArea {
+            left_upper: (self.left_upper.0.min(width), self.left_upper.1.min(height)),
+            right_lower: (
+                self.right_lower.0.min(width),
+                self.right_lower.1.min(height),
+            ),
+        }
+    }
+    /// Returns intersection of two areas or `None`, if they do not overlap.
+    ///
+    /// Areas that only touch each other do not overlap.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::datacore::images::ImageArea;
+    /// let a: ImageArea = ImageArea::from(((0, 0), (100, 100)));
+    /// let b: ImageArea = ImageArea::from(((50, 80), (150, 150)));
+    /// let intersection: ImageArea = a.intersect(&b).expect("Areas overlap");
+    /// assert_eq!(intersection.left_upper(), (50, 80));
+    /// assert_eq!(intersection.right_lower(), (100, 100));
+    ///
+    /// let c: ImageArea = ImageArea::from(((100, 0), (200, 100)));
+    /// assert!(a.intersect(&c).is_none());
+    /// ```
+    ///
+    pub fn intersect(&self, other: &ImageArea) -> Option<ImageArea> {
+        let area: ImageArea = ImageArea {
+            left_upper: (
+                self.left_upper.0.max(other.left_upper.0),
+                self.left_upper.1.max(other.left_upper.1),
+            ),
+            right_lower: (
+                self.right_lower.0.min(other.right_lower.0),
+                self.right_lower.1.min(other.right_lower.1),
+            ),
+        };
+        if area.left_upper.0 < area.right_lower.0 && area.left_upper.1 < area.right_lower.1 {
+            Some(area)
+        } else {
+            None
+        }
     }
 
     /// Returns left upper point of bounded part.
@@ -371,6 +463,25 @@ impl From<((u32, u32), (u32, u32))> for ImageArea {
     }
 }
 
+/// [`ImageFileFormat`] enum lists formats in which [`Image`] can be saved.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImageFileFormat {
+    /// PNG image format (lossless compression).
+    ///
+    Png,
+    /// JPG image format (lossy compression, alpha channel is discarded).
+    ///
+    Jpg {
+        /// Quality of compression (in range from 0 to 100, bigger values are clamped).
+        ///
+        quality: u8,
+    },
+    /// BMP image format (no compression).
+    ///
+    Bmp,
+}
+
 /// [`Image`] struct is used to represent images and manipulate them.
 ///
 /// It supports loading images from disk, saving them, redacting, blitting and many other transformations.
@@ -441,7 +552,7 @@ impl<'a> Image<'a> {
         height: u32,
         pitch: u32,
         format: PixelFormat,
-    ) -> Result<Self, Error> {
+    ) -> Result<Self, GGError> {
         Ok(Self {
             filename: PathBuf::new(),
             surface: ImageSurface::from_data(
@@ -451,7 +562,7 @@ impl<'a> Image<'a> {
                 pitch,
                 format.to_sdl_pixel_format_enum(),
             )
-            .map_err(|message| Error::new(ErrorKind::InvalidData, message))?,
+            .map_err(GGError::Sdl)?,
         })
     }
     /// Copies the surface into a new one of a specified pixel format.
@@ -546,34 +657,282 @@ impl<'a> Image<'a> {
         }
     }
 
+    /// Returns color of a pixel (x, y) or `None`, if pixel is out of image bounds.
+    ///
+    /// Pixel is decoded according to image format, so this function is the easiest way to read single pixels.
+    /// Formats without alpha channel return opaque colors.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use ggengine::datacore::images::{Image, PixelFormat};
+    /// # use ggengine::mathcore::Color;
+    /// let mut image: Image = Image::new(10, 10, PixelFormat::RGB565);
+    /// image.set_pixel(3, 4, Color::RED);
+    /// assert_eq!(image.get_pixel(3, 4), Some(Color::RED));
+    /// assert_eq!(image.get_pixel(10, 0), None);
+    /// ```
+    ///
+    pub fn get_pixel(&self, x: u32, y: u32) -> Option<Color> {
+        if x >= self.width() || y >= self.height() {
+            return None;
+        }
+        let offset: usize = self.pixel_offset(x, y);
+        let size: usize = self.surface.pixel_format_enum().byte_size_per_pixel();
+        let value: u32 = self.access_data(|data| read_pixel(&data[offset..offset + size]));
+        let color: SdlColor = SdlColor::from_u32(&self.surface.pixel_format(), value);
+        Some(Color::from_rgba(color.r, color.g, color.b, color.a))
+    }
+    /// Sets color of a pixel (x, y).
+    ///
+    /// Color is encoded according to image format (lossy formats lose precision of color components).
+    /// Pixels that are out of image bounds are ignored.
+    ///
+    pub fn set_pixel(&mut self, x: u32, y: u32, color: Color) {
+        if x >= self.width() || y >= self.height() {
+            return;
+        }
+        let offset: usize = self.pixel_offset(x, y);
+        let size: usize = self.surface.pixel_format_enum().byte_size_per_pixel();
+        let value: u32 =
+            SdlColor::RGBA(color.r, color.g, color.b, color.a).to_u32(&self.surface.pixel_format());
+        self.access_data_mut(|data| write_pixel(&mut data[offset..offset + size], value));
+    }
+    /// Replaces all pixels which color is close to `from` color with `to` color.
+    ///
+    /// Colors are close if each of their components (including alpha) differs by no more than `tolerance`,
+    /// so `tolerance = 0` replaces only exact matches. This is handy for palette swaps.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use ggengine::datacore::images::Image;
+    /// # use ggengine::datacore::assets::FromFile;
+    /// # use ggengine::mathcore::Color;
+    /// # use std::path::Path;
+    /// let mut sprites: Image = Image::from_file(Path::new("sprites.png")).expect("Filename should be correct");
+    /// // recolors red team into blue team
+    /// sprites.replace_color(Color::RED, Color::BLUE, 16);
+    /// ```
+    ///
+    pub fn replace_color(&mut self, from: Color, to: Color, tolerance: u8) {
+        let (width, height): (u32, u32) = self.size();
+        let format: SdlPixelFormat = self.surface.pixel_format();
+        let size: usize = self.surface.pixel_format_enum().byte_size_per_pixel();
+        let pitch: usize = self.pitch() as usize;
+        let replacement: u32 = SdlColor::RGBA(to.r, to.g, to.b, to.a).to_u32(&format);
+
+        let close = |a: u8, b: u8| a.abs_diff(b) <= tolerance;
+        self.access_data_mut(|data| {
+            for y in 0..height as usize {
+                for x in 0..width as usize {
+                    let pixel: &mut [u8] = &mut data[y * pitch + x * size..][..size];
+                    let color: SdlColor = SdlColor::from_u32(&format, read_pixel(pixel));
+                    if close(color.r, from.r)
+                        && close(color.g, from.g)
+                        && close(color.b, from.b)
+                        && close(color.a, from.a)
+                    {
+                        write_pixel(pixel, replacement);
+                    }
+                }
+            }
+        });
+    }
+
+    /// Sets global alpha modulation of an image, which is applied when image is blitted
+    /// (source alpha is multiplied by `alpha / 255`).
+    ///
+    /// Together with blend mode (see [`Blendable`](crate::graphicscore::Blendable) trait) this allows translucent overlays when images are composed by `blit_to`.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use ggengine::datacore::images::{Image, PixelFormat};
+    /// # use ggengine::graphicscore::{Blendable, BlendingType};
+    /// let mut overlay: Image = Image::new(100, 100, PixelFormat::RGBA32);
+    /// overlay.set_blend_mode(BlendingType::Alpha);
+    /// overlay.set_alpha_mod(128);
+    ///
+    /// let mut scene: Image = Image::new(100, 100, PixelFormat::RGBA32);
+    /// overlay.blit_to(None, &mut scene, None).expect("Blitting should not fail");
+    /// ```
+    ///
+    pub fn set_alpha_mod(&mut self, alpha: u8) {
+        self.surface.set_alpha_mod(alpha);
+    }
+    /// Returns global alpha modulation of an image.
+    ///
+    pub fn alpha_mod(&self) -> u8 {
+        self.surface.alpha_mod()
+    }
+
+    /// Sets color key (transparent color) of an image.
+    ///
+    /// Pixels of that color are skipped when image is blitted and are transparent
+    /// in textures that are created from an image, which is the way to use legacy sprite sheets
+    /// that mark transparency with a special color (e.g. magenta).
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use ggengine::datacore::images::Image;
+    /// # use ggengine::datacore::assets::FromFile;
+    /// # use ggengine::mathcore::Color;
+    /// # use std::path::Path;
+    /// let mut sprites: Image = Image::from_file(Path::new("sprites.bmp")).expect("Filename should be correct");
+    /// sprites.set_color_key(Color::from_rgba(255, 0, 255, 255));
+    /// ```
+    ///
+    pub fn set_color_key(&mut self, color: Color) {
+        self.surface
+            .set_color_key(true, SdlColor::RGBA(color.r, color.g, color.b, color.a))
+            .expect("Setting color key should not fail");
+    }
+    /// Removes color key of an image.
+    ///
+    pub fn clear_color_key(&mut self) {
+        self.surface
+            .set_color_key(false, SdlColor::RGBA(0, 0, 0, 0))
+            .expect("Removing color key should not fail");
+    }
+    /// Returns color key of an image or `None`, if image has no color key.
+    ///
+    pub fn color_key(&self) -> Option<Color> {
+        self.surface
+            .color_key()
+            .ok()
+            .map(|color| Color::from_rgba(color.r, color.g, color.b, color.a))
+    }
+
+    /// Returns palette of an image or `None`, if image is not palettized.
+    ///
+    /// Only images in [`PixelFormat::Index8`] format have palettes
+    /// (images in that format are created with palette of 256 colors).
+    /// To get image with colors instead of indices, convert it (e.g. `image.convert(PixelFormat::RGBA32)`).
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use ggengine::datacore::images::{Image, PixelFormat};
+    /// # use ggengine::mathcore::Color;
+    /// let mut image: Image = Image::new(16, 16, PixelFormat::Index8);
+    /// image
+    ///     .set_palette(&[Color::BLACK, Color::RED, Color::GREEN, Color::BLUE])
+    ///     .expect("Image is palettized");
+    /// assert_eq!(image.palette().expect("Image is palettized")[1], Color::RED);
+    ///
+    /// let rgba: Image = image.convert(PixelFormat::RGBA32);
+    /// ```
+    ///
+    pub fn palette(&self) -> Option<Vec<Color>> {
+        // SAFETY: format of a valid surface is always valid and its palette is either null or valid.
+        let colors: &[sdl2::sys::SDL_Color] = unsafe {
+            let palette: *mut sdl2::sys::SDL_Palette = (*(*self.surface.raw()).format).palette;
+            if palette.is_null() {
+                return None;
+            }
+            std::slice::from_raw_parts((*palette).colors, (*palette).ncolors as usize)
+        };
+        Some(
+            colors
+                .iter()
+                .map(|color| Color::from_rgba(color.r, color.g, color.b, color.a))
+                .collect(),
+        )
+    }
+    /// Replaces palette of a palettized image.
+    ///
+    /// Pixels are left untouched, so this swaps colors of a whole image at once.
+    /// Palette should not be empty and should contain no more than 256 colors.
+    /// Textures that were created from image are not affected, so they should be recreated after palette swap.
+    ///
+    pub fn set_palette(&mut self, palette: &[Color]) -> Result<(), GGError> {
+        if self.palette().is_none() {
+            return Err(GGError::Unsupported(String::from(
+                "Only palettized images have palettes",
+            )));
+        }
+        if palette.is_empty() || palette.len() > 256 {
+            return Err(GGError::InvalidArgument(String::from(
+                "Palette should contain from 1 to 256 colors",
+            )));
+        }
+        let colors: Vec<SdlColor> = palette
+            .iter()
+            .map(|color| SdlColor::RGBA(color.r, color.g, color.b, color.a))
+            .collect();
+        let palette: SdlPalette = SdlPalette::with_colors(&colors).map_err(GGError::Sdl)?;
+        self.surface.set_palette(&palette).map_err(GGError::Sdl)
+    }
+    /// Rotates colors of a palette in given range by `steps` positions (positive `steps` move colors to higher indices).
+    ///
+    /// Calling this function periodically produces palette cycling effect (e.g. flowing water or flickering fire),
+    /// since all pixels that use colors from that range change their colors at once.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use ggengine::datacore::images::Image;
+    /// # use ggengine::datacore::assets::FromFile;
+    /// # use std::path::Path;
+    /// let mut waterfall: Image = Image::from_file(Path::new("waterfall.bmp")).expect("Filename should be correct");
+    /// // colors 16..24 are used by water
+    /// waterfall.cycle_palette(16..24, 1).expect("Image is palettized");
+    /// ```
+    ///
+    pub fn cycle_palette(&mut self, range: Range<usize>, steps: i32) -> Result<(), GGError> {
+        let mut palette: Vec<Color> = self.palette().ok_or_else(|| {
+            GGError::Unsupported(String::from("Only palettized images have palettes"))
+        })?;
+        let colors: &mut [Color] = palette.get_mut(range).ok_or_else(|| {
+            GGError::InvalidArgument(String::from("Range should be inside of palette"))
+        })?;
+        if !colors.is_empty() {
+            let shift: usize = steps.rem_euclid(colors.len() as i32) as usize;
+            colors.rotate_right(shift);
+        }
+        self.set_palette(&palette)
+    }
+
     /// Crops image using given area which will be left after cropping.
     ///
+    /// Area should lie inside of an image (see [`ImageArea::clamp`]), otherwise error is returned.
+    ///
     /// # Example
     /// ```rust, no_run
     /// # use ggengine::datacore::images::{ImageArea, Image};
     /// # use ggengine::datacore::assets::FromFile;
     /// # use std::path::Path;
     /// let image1: Image = Image::from_file(Path::new("i.png")).expect("Filename should be correct");
-    /// let image2: Image = image1.crop(ImageArea::from(((50, 50), (100, 100))));
+    /// let image2: Image = image1
+    ///     .crop(ImageArea::from(((50, 50), (100, 100))))
+    ///     .expect("Area should be inside of an image");
     /// ```
     ///
-    pub fn crop(&self, area: ImageArea) -> Image {
-        let mut result: ImageSurface = ImageSurface::new(area.width(), area.height(), self.surface.pixel_format_enum())
-            .expect("`ImageSystem::init` should be called before using anything else from `ggengine::datacore::image` submodule.");
-        let _ = self
-            .surface
-            .blit(Some(area.to_sdl_rect()), &mut result, None)
-            .expect("Cropping should be possible.");
-        Image {
+    pub fn crop(&self, area: ImageArea) -> Result<Image, GGError> {
+        if area.right_lower.0 > self.width() || area.right_lower.1 > self.height() {
+            return Err(GGError::InvalidArgument(String::from(
+                "Cropped area should be inside of an image",
+            )));
+        }
+        let mut result: ImageSurface = ImageSurface::new(
+            area.width(),
+            area.height(),
+            self.surface.pixel_format_enum(),
+        )
+        .map_err(GGError::Sdl)?;
+        if !area.is_empty() {
+            let _ = self
+                .surface
+                .blit(Some(area.try_to_rect()?), &mut result, None)
+                .map_err(GGError::Sdl)?;
+        }
+        Ok(Image {
             filename: PathBuf::new(),
             surface: result,
-        }
+        })
     }
     /// Blits (copies) part of source image to part of destination image.
     ///
     /// Blitting can be thought of as overlaying parts of image with part of another.
     /// If `src_area` is `None` then whole part of source image will be used.
     /// If `dst_area` is `None` then source part will be positioned at left upper corner.
+    /// Parts that are out of image bounds are clipped and blitting of empty areas does nothing.
     ///
     /// # Example
     /// ```rust, no_run
@@ -582,27 +941,34 @@ impl<'a> Image<'a> {
     /// # use std::path::Path;
     /// let source: Image = Image::from_file(Path::new("i.png")).expect("Filename should be correct");
     /// let mut destination: Image = Image::new(100, 100, PixelFormat::RGBA32);
-    /// source.blit_to(Some(ImageArea::from(((50, 50), (100, 100)))), &mut destination, None);
+    /// source
+    ///     .blit_to(Some(ImageArea::from(((50, 50), (100, 100)))), &mut destination, None)
+    ///     .expect("Areas should be valid");
     /// ```
     ///
-    /// # Panics
-    /// Blitting can panic or behave wrongly if any [`ImageArea`] bounded part coordinates exceed `i32::MAX`.
-    /// Best efforts to clamp width and height are done, but if the most left upper coordinate exceeds, it's impossible to handle.
+    /// # Errors
+    /// Error is returned if any [`ImageArea`] coordinate exceeds `i32::MAX / 2` or if `sdl2` fails to blit images.
     ///
     pub fn blit_to(
         &self,
         src_area: Option<ImageArea>,
         dst_image: &mut Image,
         dst_area: Option<ImageArea>,
-    ) {
+    ) -> Result<(), GGError> {
+        if src_area.is_some_and(|area| area.is_empty())
+            || dst_area.is_some_and(|area| area.is_empty())
+        {
+            return Ok(());
+        }
         let _ = self
             .surface
             .blit(
-                src_area.map(|area| area.to_sdl_rect()),
+                src_area.map(ImageArea::try_to_rect).transpose()?,
                 &mut dst_image.surface,
-                dst_area.map(|area| area.to_sdl_rect()),
+                dst_area.map(ImageArea::try_to_rect).transpose()?,
             )
-            .expect("Blitting should not fail");
+            .map_err(GGError::Sdl)?;
+        Ok(())
     }
     /// Blits part of another image on this image.
     ///
@@ -615,7 +981,9 @@ impl<'a> Image<'a> {
     /// # use std::path::Path;
     /// let source: Image = Image::from_file(Path::new("i.png")).expect("Filename should be correct");
     /// let mut destination: Image = Image::new(100, 100, PixelFormat::RGBA32);
-    /// destination.blit_from(None, &source, Some(ImageArea::from(((50, 50), (100, 100)))));
+    /// destination
+    ///     .blit_from(None, &source, Some(ImageArea::from(((50, 50), (100, 100)))))
+    ///     .expect("Areas should be valid");
     /// ```
     ///
     pub fn blit_from(
@@ -623,8 +991,8 @@ impl<'a> Image<'a> {
         dst_area: Option<ImageArea>,
         src_image: &Image,
         src_area: Option<ImageArea>,
-    ) {
-        src_image.blit_to(src_area, self, dst_area);
+    ) -> Result<(), GGError> {
+        src_image.blit_to(src_area, self, dst_area)
     }
 
     /// Returns width of image in pixels.
@@ -662,7 +1030,200 @@ impl<'a> Image<'a> {
     pub fn pixel_format(&self) -> Option<PixelFormat> {
         PixelFormat::from_sdl_pixel_format_enum(self.surface.pixel_format_enum())
     }
+
+    /// Saves image to file in given format.
+    ///
+    /// Extension of a file is not checked, so format is determined only by `format` argument.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use ggengine::datacore::images::{Image, ImageFileFormat, PixelFormat};
+    /// let image: Image = Image::new(100, 100, PixelFormat::RGBA32);
+    /// image
+    ///     .save_as("screenshot.jpg", ImageFileFormat::Jpg { quality: 90 })
+    ///     .expect("Filename should be correct");
+    /// ```
+    ///
+    pub fn save_as(
+        &self,
+        filename: impl AsRef<Path>,
+        format: ImageFileFormat,
+    ) -> Result<(), GGError> {
+        if format != ImageFileFormat::Bmp {
+            let _ = image_context()?;
+        }
+        match format {
+            ImageFileFormat::Png => self.surface.save(filename),
+            ImageFileFormat::Jpg { quality } => {
+                let filename: CString = filename
+                    .as_ref()
+                    .to_str()
+                    .and_then(|filename| CString::new(filename).ok())
+                    .ok_or_else(|| {
+                        GGError::InvalidArgument(String::from("Filename should be a valid string"))
+                    })?;
+                // SAFETY: surface pointer is valid and non-null, since it is owned by `self.surface`, which is borrowed
+                // for the whole call; path pointer is a nul-terminated string owned by `filename`, which outlives the call.
+                // `IMG_SaveJPG` neither frees nor retains either pointer.
+                let result: i32 = unsafe {
+                    sdl2::sys::image::IMG_SaveJPG(
+                        self.surface.raw(),
+                        filename.as_ptr(),
+                        i32::from(quality.min(100)),
+                    )
+                };
+                if result == 0 {
+                    Ok(())
+                } else {
+                    Err(sdl2::get_error())
+                }
+            }
+            ImageFileFormat::Bmp => self.surface.save_bmp(filename),
+        }
+        .map_err(GGError::Sdl)
+    }
+}
+/// Reads encoded pixel from its bytes (pixels are stored in native byte order).
+///
+fn read_pixel(bytes: &[u8]) -> u32 {
+    let mut value: [u8; 4] = [0; 4];
+    if cfg!(target_endian = "little") {
+        value[..bytes.len()].copy_from_slice(bytes);
+    } else {
+        value[4 - bytes.len()..].copy_from_slice(bytes);
+    }
+    u32::from_ne_bytes(value)
+}
+/// Writes encoded pixel to its bytes (pixels are stored in native byte order).
+///
+fn write_pixel(bytes: &mut [u8], value: u32) {
+    let value: [u8; 4] = value.to_ne_bytes();
+    if cfg!(target_endian = "little") {
+        bytes.copy_from_slice(&value[..bytes.len()]);
+    } else {
+        bytes.copy_from_slice(&value[4 - bytes.len()..]);
+    }
 }
+
+/// [`AnimationFrame`] struct represents one frame of [`AnimatedImage`].
+///
+#[derive(Debug)]
+pub struct AnimationFrame<'a> {
+    /// Fully composed image of a frame.
+    ///
+    pub image: Image<'a>,
+    /// Time for which frame is shown.
+    ///
+    pub delay: Duration,
+}
+/// [`AnimatedImage`] struct represents animated image as a list of frames with their delays.
+///
+/// Currently only animated GIFs are supported (static GIFs are loaded as animations with one frame).
+/// Frames are fully composed (disposal and transparency of GIF frames are already applied)
+/// and are stored in [`PixelFormat::RGBA32`] format, so they can be directly turned into sprites.
+///
+/// # Example
+/// ```rust, no_run
+/// # use ggengine::datacore::images::{AnimatedImage, AnimationFrame};
+/// # use ggengine::datacore::assets::FromFile;
+/// # use std::path::Path;
+/// let animation: AnimatedImage = AnimatedImage::from_file(Path::new("a.gif")).expect("Filename should be correct");
+/// for frame in animation.frames() {
+///     println!("{:?} for {:?}", frame.image.size(), frame.delay);
+/// }
+/// ```
+///
+#[derive(Debug)]
+pub struct AnimatedImage<'a> {
+    /// Name of a loaded animation file (`PathBuf` is empty only if animation was created from buffer).
+    ///
+    filename: PathBuf,
+    /// Frames of animation.
+    ///
+    frames: Vec<AnimationFrame<'a>>,
+    /// Number of additional repetitions of animation (`None` means that animation loops infinitely).
+    ///
+    loops: Option<u16>,
+}
+impl<'a> AnimatedImage<'a> {
+    /// Minimal delay of a frame.
+    ///
+    /// Many GIFs have frames with zero delays, which are shown for this time (that matches behaviour of browsers).
+    ///
+    pub const MIN_DELAY: Duration = Duration::from_millis(20);
+    /// Delay which is used for frames that have delay less than [`AnimatedImage::MIN_DELAY`].
+    ///
+    pub const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+
+    /// Initializes [`AnimatedImage`] from encoded data of an animation.
+    ///
+    pub fn from_bytes(data: &[u8]) -> Result<Self, GGError> {
+        let gif: gif::Gif = gif::decode(data)?;
+        let frames: Vec<AnimationFrame<'a>> = gif
+            .frames
+            .into_iter()
+            .map(|frame| {
+                let delay: Duration = Duration::from_millis(u64::from(frame.delay) * 10);
+                Ok(AnimationFrame {
+                    image: Image::from_raw_buffer(
+                        frame.pixels.into_boxed_slice(),
+                        gif.width,
+                        gif.height,
+                        gif.width * 4,
+                        PixelFormat::RGBA32,
+                    )?,
+                    delay: if delay < Self::MIN_DELAY {
+                        Self::DEFAULT_DELAY
+                    } else {
+                        delay
+                    },
+                })
+            })
+            .collect::<Result<Vec<AnimationFrame<'a>>, GGError>>()?;
+        Ok(AnimatedImage {
+            filename: PathBuf::new(),
+            frames,
+            loops: gif.loops,
+        })
+    }
+
+    /// Returns name of file from which [`AnimatedImage`] was initialized or empty `Path`, if it was created from buffer.
+    ///
+    pub fn filename(&self) -> &Path {
+        self.filename.as_path()
+    }
+    /// Returns frames of animation.
+    ///
+    pub fn frames(&self) -> &[AnimationFrame<'a>] {
+        &self.frames
+    }
+    /// Consumes animation and returns its frames.
+    ///
+    pub fn into_frames(self) -> Vec<AnimationFrame<'a>> {
+        self.frames
+    }
+    /// Returns number of additional repetitions of animation
+    /// (for `Some(n)` animation is played total of `n + 1` times, for `None` it loops infinitely).
+    ///
+    pub fn loops(&self) -> Option<u16> {
+        self.loops
+    }
+    /// Returns duration of one repetition of animation.
+    ///
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|frame| frame.delay).sum()
+    }
+}
+impl<'a> FromFile for AnimatedImage<'a> {
+    /// Initializes [`AnimatedImage`] from given file.
+    ///
+    fn from_file(path: impl AsRef<Path>) -> Result<Self, GGError> {
+        let mut animation: AnimatedImage = AnimatedImage::from_bytes(&fs::read(&path)?)?;
+        animation.filename = path.as_ref().to_path_buf();
+        Ok(animation)
+    }
+}
+
 impl<'a> FromFile for Image<'a> {
     /// Initializes [`Image`] from given file.
     ///
@@ -676,11 +1237,11 @@ impl<'a> FromFile for Image<'a> {
     /// let image: Image = Image::from_file(Path::new("i.png")).expect("Filename should be correct");
     /// ```
     ///
-    fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
-        let surface: ImageSurface = ImageSurface::from_file(path.as_ref())
-            .map_err(|message| Error::new(ErrorKind::NotFound, message))?;
+    fn from_file(path: impl AsRef<Path>) -> Result<Self, GGError> {
+        let _ = image_context()?;
+        let surface: ImageSurface = ImageSurface::from_file(path.as_ref()).map_err(GGError::Sdl)?;
         if PixelFormat::from_sdl_pixel_format_enum(surface.pixel_format_enum()).is_none() {
-            return Err(Error::new(ErrorKind::InvalidData, "Wrong image format"));
+            return Err(GGError::Unsupported(String::from("Wrong image format")));
         }
         Ok(Image {
             filename: path.as_ref().to_path_buf(),
@@ -691,6 +1252,8 @@ impl<'a> FromFile for Image<'a> {
 impl<'a> ToFile for Image<'a> {
     /// Saves image to '*.png' file.
     ///
+    /// To choose other format, use [`Image::save_as`].
+    ///
     /// # Example
     /// ```rust, no_run
     /// # use ggengine::datacore::images::{Image, PixelFormat};
@@ -699,10 +1262,8 @@ impl<'a> ToFile for Image<'a> {
     /// image.to_file("i.png").expect("Filename should be correct");
     /// ```
     ///
-    fn to_file(&self, filename: impl AsRef<Path>) -> Result<(), Error> {
-        self.surface
-            .save(filename)
-            .map_err(|message| Error::new(ErrorKind::InvalidData, message))
+    fn to_file(&self, filename: impl AsRef<Path>) -> Result<(), GGError> {
+        self.save_as(filename, ImageFileFormat::Png)
     }
 }
 impl<'a> fmt::Debug for Image<'a> {
@@ -716,6 +1277,7 @@ impl<'a> fmt::Debug for Image<'a> {
 bitflags!(
     /// [`ImageFormat`] bitflag struct lists supported image formats.
     ///
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
     pub struct ImageFormat : u32 {
         /// JPG image format.
         ///
@@ -735,24 +1297,51 @@ bitflags!(
 /// [`IMAGE_CONTEXT`] global static variable handles `sdl2::image` context.
 ///
 static IMAGE_CONTEXT: OnceLock<ImageContext> = OnceLock::new();
+/// Returns `sdl2::image` context, initializing [`ImageSystem`] with default formats if it was not initialized.
+///
+fn image_context() -> Result<&'static ImageContext, GGError> {
+    ImageSystem::init(ImageSystem::DEFAULT_FORMAT)?;
+    IMAGE_CONTEXT
+        .get()
+        .ok_or_else(|| GGError::SubsystemNotInitialized(String::from("ImageSystem")))
+}
 /// [`ImageSystem`] is a global handler for image metadata.
 ///
-/// ### `ImageSystem::init` should be called before using anything else from this submodule.
+/// If `ImageSystem::init` was not called, image system is initialized with [`ImageSystem::DEFAULT_FORMAT`]
+/// when image files are loaded or saved for the first time.
+///
+/// Image system can also be declared in [`GGEngineBuilder`](crate::GGEngineBuilder),
+/// which initializes it together with other subsystems.
 ///
 #[derive(Copy, Clone, Debug)]
 pub enum ImageSystem {}
 impl ImageSystem {
+    /// Formats that are supported when image system is initialized lazily.
+    ///
+    pub const DEFAULT_FORMAT: ImageFormat = ImageFormat::PNG.union(ImageFormat::JPG);
+
     /// Initializes image system, prepares libraries for use and allows different formats to be opened.
     ///
-    /// ### `ImageSystem::init` should be called before using anything else from `ggengine::datacore::image` submodule.
+    /// Repeated calls do nothing, so formats should be chosen before image files are loaded.
+    ///
+    /// Error is returned if image driver is not available.
     ///
-    pub fn init(image_format: ImageFormat) {
+    pub fn init(image_format: ImageFormat) -> Result<(), GGError> {
+        if IMAGE_CONTEXT.get().is_some() {
+            return Ok(());
+        }
         let _ =
             IMAGE_CONTEXT.set(
                 image_init(ImageInitFlag::from_bits(image_format.bits()).expect(
                     "`ImageFormat` constants are the same as in `InitFlag` bitflags struct",
                 ))
-                .expect("Image driver should be available"),
+                .map_err(GGError::Sdl)?,
             );
+        Ok(())
+    }
+    /// Returns whether image system is initialized.
+    ///
+    pub fn is_initialized() -> bool {
+        IMAGE_CONTEXT.get().is_some()
     }
 }
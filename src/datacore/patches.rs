@@ -0,0 +1,333 @@
+//! `datacore::patches` submodule implements [`Patch`] - compact binary difference between two
+//! versions of serialized data.
+//!
+//! Patches are used wherever only changes of large serialized data need to be stored or sent:
+//! incremental autosaves store patches against the last full save and
+//! [`replication`](crate::netcore::replication) sends patches of component states against
+//! states that were sent previously.
+//!
+
+use crate::{netcore::lockstep::StateHasher, GGError};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, hash::Hasher};
+
+/// `serde` helpers that store byte vectors as CBOR byte strings instead of arrays of integers.
+///
+mod bytes {
+    use serde::{de, Deserializer, Serializer};
+    use std::fmt;
+
+    /// Serializes bytes as byte string.
+    ///
+    pub(super) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(bytes)
+    }
+
+    /// [`BytesVisitor`] struct deserializes byte strings (and sequences of bytes).
+    ///
+    struct BytesVisitor;
+    impl<'de> de::Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("byte string")
+        }
+        fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+            Ok(bytes.to_vec())
+        }
+        fn visit_byte_buf<E: de::Error>(self, bytes: Vec<u8>) -> Result<Self::Value, E> {
+            Ok(bytes)
+        }
+        fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut bytes: Vec<u8> = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+            Ok(bytes)
+        }
+    }
+
+    /// Deserializes bytes from byte string.
+    ///
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        deserializer.deserialize_byte_buf(BytesVisitor)
+    }
+}
+
+/// Size of blocks of base data that are searched for in target data.
+///
+const BLOCK_SIZE: usize = 16;
+/// Maximal number of blocks with the same hash that are compared with target data.
+///
+const MAX_CANDIDATES: usize = 8;
+
+/// Returns checksum of data.
+///
+fn checksum(data: &[u8]) -> u64 {
+    let mut hasher: StateHasher = StateHasher::new();
+    hasher.write(data);
+    hasher.finish()
+}
+/// Returns length of common prefix of two slices.
+///
+fn common_prefix(first: &[u8], second: &[u8]) -> usize {
+    first
+        .iter()
+        .zip(second)
+        .take_while(|(first, second)| first == second)
+        .count()
+}
+
+/// [`PatchOperation`] enum lists operations that build target data.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PatchOperation {
+    /// Copies range of base data.
+    ///
+    Copy {
+        /// Offset of the range in base data.
+        ///
+        offset: u64,
+        /// Length of the range.
+        ///
+        length: u64,
+    },
+    /// Inserts new bytes.
+    ///
+    Insert(#[serde(with = "bytes")] Vec<u8>),
+}
+
+/// [`Patch`] struct describes how to build target data from base data.
+///
+/// Patch remembers checksums of both base and target data, so applying it to the wrong base
+/// (e.g. after autosave was lost) is detected instead of producing corrupted data.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::datacore::patches::Patch;
+/// let autosave: Vec<u8> = (0..=255).collect();
+/// let mut current: Vec<u8> = autosave.clone();
+/// current[100] = 0;
+///
+/// let patch: Patch = Patch::diff(&autosave, &current);
+/// assert!(patch.encoded_size() < current.len() / 2);
+/// assert_eq!(patch.apply(&autosave).expect("Patch is made for this base"), current);
+/// assert!(patch.apply(&current).is_err());
+/// ```
+///
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[serde(from = "EncodedPatch", into = "EncodedPatch")]
+pub struct Patch {
+    /// Length of base data.
+    ///
+    base_length: u64,
+    /// Checksum of base data.
+    ///
+    base_checksum: u64,
+    /// Checksum of target data.
+    ///
+    target_checksum: u64,
+    /// Operations that build target data.
+    ///
+    operations: Vec<PatchOperation>,
+}
+/// [`EncodedPatch`] struct is a representation of [`Patch`] which is serialized without names of fields,
+/// since patches are supposed to be small.
+///
+#[derive(Serialize, Deserialize)]
+struct EncodedPatch(u64, u64, u64, Vec<PatchOperation>);
+impl From<Patch> for EncodedPatch {
+    fn from(patch: Patch) -> Self {
+        EncodedPatch(
+            patch.base_length,
+            patch.base_checksum,
+            patch.target_checksum,
+            patch.operations,
+        )
+    }
+}
+impl From<EncodedPatch> for Patch {
+    fn from(encoded: EncodedPatch) -> Self {
+        Patch {
+            base_length: encoded.0,
+            base_checksum: encoded.1,
+            target_checksum: encoded.2,
+            operations: encoded.3,
+        }
+    }
+}
+impl Patch {
+    /// Computes patch that builds `target` from `base`.
+    ///
+    /// Unchanged ranges of at least 16 bytes are copied from base, the rest is inserted.
+    ///
+    pub fn diff(base: &[u8], target: &[u8]) -> Patch {
+        let mut blocks: HashMap<u64, Vec<usize>> = HashMap::new();
+        for (index, block) in base.chunks_exact(BLOCK_SIZE).enumerate() {
+            blocks
+                .entry(checksum(block))
+                .or_default()
+                .push(index * BLOCK_SIZE);
+        }
+
+        let mut patch: Patch = Patch {
+            base_length: base.len() as u64,
+            base_checksum: checksum(base),
+            target_checksum: checksum(target),
+            operations: Vec::new(),
+        };
+        let mut literal: Vec<u8> = Vec::new();
+        let mut position: usize = 0;
+        while position < target.len() {
+            let best: Option<(usize, usize)> = target
+                .get(position..position + BLOCK_SIZE)
+                .and_then(|window| blocks.get(&checksum(window)))
+                .and_then(|candidates| {
+                    candidates
+                        .iter()
+                        .rev()
+                        .take(MAX_CANDIDATES)
+                        .map(|&offset| {
+                            (offset, common_prefix(&base[offset..], &target[position..]))
+                        })
+                        .filter(|&(_, length)| length >= BLOCK_SIZE)
+                        .max_by_key(|&(_, length)| length)
+                });
+            match best {
+                Some((mut offset, length)) => {
+                    // bytes right before the match may also be unchanged, but they are not aligned to blocks
+                    let mut copied: usize = length;
+                    while offset > 0 && literal.last() == Some(&base[offset - 1]) {
+                        let _ = literal.pop();
+                        offset -= 1;
+                        copied += 1;
+                    }
+                    if !literal.is_empty() {
+                        patch
+                            .operations
+                            .push(PatchOperation::Insert(std::mem::take(&mut literal)));
+                    }
+                    patch.push_copy(offset as u64, copied as u64);
+                    position += length;
+                }
+                None => {
+                    literal.push(target[position]);
+                    position += 1;
+                }
+            }
+        }
+        if !literal.is_empty() {
+            patch.operations.push(PatchOperation::Insert(literal));
+        }
+        patch
+    }
+    /// Adds copy operation, merging it with the previous one if ranges are adjacent.
+    ///
+    fn push_copy(&mut self, offset: u64, length: u64) {
+        if let Some(PatchOperation::Copy {
+            offset: previous_offset,
+            length: previous_length,
+        }) = self.operations.last_mut()
+        {
+            if *previous_offset + *previous_length == offset {
+                *previous_length += length;
+                return;
+            }
+        }
+        self.operations
+            .push(PatchOperation::Copy { offset, length });
+    }
+
+    /// Builds target data by applying patch to base data.
+    ///
+    /// # Errors
+    /// Error is returned if patch was made for another base data or if it is malformed.
+    ///
+    pub fn apply(&self, base: &[u8]) -> Result<Vec<u8>, GGError> {
+        if base.len() as u64 != self.base_length || checksum(base) != self.base_checksum {
+            return Err(GGError::InvalidArgument(String::from(
+                "Patch was made for another base data",
+            )));
+        }
+        let mut target: Vec<u8> = Vec::with_capacity(base.len());
+        for operation in self.operations.iter() {
+            match operation {
+                PatchOperation::Copy { offset, length } => {
+                    let range: &[u8] = usize::try_from(*offset)
+                        .ok()
+                        .zip(usize::try_from(*length).ok())
+                        .and_then(|(offset, length)| base.get(offset..offset.checked_add(length)?))
+                        .ok_or_else(|| {
+                            GGError::Decode(String::from("Patch copies range outside of base data"))
+                        })?;
+                    target.extend_from_slice(range);
+                }
+                PatchOperation::Insert(bytes) => target.extend_from_slice(bytes),
+            }
+        }
+        if checksum(&target) != self.target_checksum {
+            return Err(GGError::Decode(String::from(
+                "Patch produced data with wrong checksum",
+            )));
+        }
+        Ok(target)
+    }
+
+    /// Returns operations of the patch.
+    ///
+    pub fn operations(&self) -> &[PatchOperation] {
+        &self.operations
+    }
+    /// Returns number of bytes that patch occupies when it is encoded with CBOR.
+    ///
+    pub fn encoded_size(&self) -> usize {
+        serde_cbor::to_vec(self).map_or(usize::MAX, |encoded| encoded.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Patch, PatchOperation};
+    use crate::GGError;
+
+    #[test]
+    fn diff_and_apply() {
+        let base: Vec<u8> = (0..200u8).collect();
+        let mut target: Vec<u8> = vec![7; 5];
+        target.extend_from_slice(&base[100..200]);
+        target.extend_from_slice(&[1, 2, 3]);
+        target.extend_from_slice(&base[0..50]);
+
+        let patch: Patch = Patch::diff(&base, &target);
+        assert_eq!(
+            patch.operations(),
+            [
+                PatchOperation::Insert(vec![7; 5]),
+                PatchOperation::Copy {
+                    offset: 100,
+                    length: 100
+                },
+                PatchOperation::Insert(vec![1, 2, 3]),
+                PatchOperation::Copy {
+                    offset: 0,
+                    length: 50
+                },
+            ]
+        );
+        assert_eq!(patch.apply(&base).ok(), Some(target.clone()));
+
+        let encoded: Vec<u8> = serde_cbor::to_vec(&patch).expect("Patch is serializable");
+        assert_eq!(encoded.len(), patch.encoded_size());
+        let decoded: Patch = serde_cbor::from_slice(&encoded).expect("Patch is deserializable");
+        assert_eq!(decoded, patch);
+
+        assert!(matches!(
+            patch.apply(&target),
+            Err(GGError::InvalidArgument(_))
+        ));
+        assert_eq!(Patch::diff(&[], &[]).apply(&[]).ok(), Some(Vec::new()));
+        assert_eq!(Patch::diff(&base, &[]).operations(), []);
+    }
+}
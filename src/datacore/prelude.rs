@@ -13,3 +13,4 @@ pub use crate::datacore::assets::*;
 pub use crate::datacore::audio::*;
 pub use crate::datacore::fonts::*;
 pub use crate::datacore::images::*;
+pub use crate::datacore::settings::*;
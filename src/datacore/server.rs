@@ -0,0 +1,428 @@
+//! `datacore::server` submodule implements [`AssetServer`] - storage of raw asset data that
+//! loads assets in named groups together with their dependencies.
+//!
+//! Assets declare dependencies on other assets (e.g. atlas depends on its image and animation depends
+//! on its atlas), and preloading an asset preloads its dependencies first.
+//! Groups are loaded incrementally within a time budget, so loading screens can keep rendering and display
+//! progress through [`PreloadHandle`]. Assets are reference counted by groups and are released
+//! when the last group that uses them is released.
+//!
+
+use crate::{
+    datacore::assets::{AssetManager, AssetMetadata},
+    log::{self, LogTarget},
+};
+use std::{
+    cell::Cell,
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    fs,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+/// [`PreloadProgress`] struct describes progress of preloading of a group.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PreloadProgress {
+    /// Number of loaded assets.
+    ///
+    pub loaded: usize,
+    /// Number of assets that failed to load.
+    ///
+    pub failed: usize,
+    /// Number of assets in the group (including dependencies).
+    ///
+    pub total: usize,
+    /// Number of bytes of loaded assets.
+    ///
+    pub loaded_bytes: u64,
+    /// Number of bytes of all assets in the group (sizes of files when preloading started).
+    ///
+    pub total_bytes: u64,
+}
+impl PreloadProgress {
+    /// Returns whether all assets of the group were processed (either loaded or failed).
+    ///
+    pub fn is_done(&self) -> bool {
+        self.loaded + self.failed >= self.total
+    }
+    /// Returns fraction of processed data (from 0.0 to 1.0).
+    ///
+    /// Fraction is computed by bytes, or by number of assets if sizes are unknown.
+    ///
+    pub fn fraction(&self) -> f32 {
+        if self.is_done() {
+            1.0
+        } else if self.total_bytes > 0 {
+            (self.loaded_bytes as f64 / self.total_bytes as f64).min(1.0) as f32
+        } else {
+            (self.loaded + self.failed) as f32 / self.total as f32
+        }
+    }
+}
+
+/// [`PreloadHandle`] struct is a handle to the progress of preloading of a group.
+///
+/// Handle shares progress with [`AssetServer`], so it can be kept by loading screen
+/// while the server loads assets.
+///
+#[derive(Clone, Debug)]
+pub struct PreloadHandle {
+    /// Name of the group.
+    ///
+    name: Rc<str>,
+    /// Progress that is shared with the server.
+    ///
+    progress: Rc<Cell<PreloadProgress>>,
+}
+impl PreloadHandle {
+    /// Returns name of the group.
+    ///
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Returns current progress of the group.
+    ///
+    pub fn progress(&self) -> PreloadProgress {
+        self.progress.get()
+    }
+    /// Returns whether all assets of the group were processed.
+    ///
+    pub fn is_done(&self) -> bool {
+        self.progress.get().is_done()
+    }
+}
+
+/// [`LoadedAsset`] struct holds data of the loaded asset.
+///
+#[derive(Debug)]
+struct LoadedAsset {
+    /// Raw data of the asset.
+    ///
+    bytes: Vec<u8>,
+    /// Number of groups that hold the asset.
+    ///
+    groups: usize,
+}
+
+/// [`PreloadGroup`] struct holds state of the group.
+///
+#[derive(Debug)]
+struct PreloadGroup {
+    /// Assets that are not processed yet (dependencies go before assets that depend on them).
+    ///
+    pending: VecDeque<AssetMetadata>,
+    /// Loaded assets that are held by the group.
+    ///
+    held: Vec<AssetMetadata>,
+    /// Progress that is shared with handles.
+    ///
+    progress: Rc<Cell<PreloadProgress>>,
+}
+
+/// [`AssetServer`] struct loads raw data of assets in groups and keeps it while groups are alive.
+///
+/// Decoding of raw data is left to the game (e.g. with `Image::from_bytes`), so the server does not
+/// require any of `sdl2` subsystems.
+///
+/// # Example
+/// ```rust, no_run
+/// # use ggengine::datacore::assets::{AssetFormat, AssetManager, AssetMetadata};
+/// # use ggengine::datacore::server::{AssetServer, PreloadHandle};
+/// # use std::{path::PathBuf, time::Duration};
+/// let manager: AssetManager = AssetManager::initialize_at("assets")
+///     .expect("Filename should be correct");
+/// let mut server: AssetServer = AssetServer::new(manager);
+///
+/// let image: AssetMetadata = AssetMetadata { filename: PathBuf::from("tiles.png"), format: AssetFormat::Image };
+/// let atlas: AssetMetadata = AssetMetadata { filename: PathBuf::from("tiles.atlas"), format: AssetFormat::Other };
+/// server.add_dependency(atlas.clone(), image);
+///
+/// let level: PreloadHandle = server.preload_group("level1", [atlas]);
+/// while !level.is_done() {
+///     // roughly one frame at 60 FPS
+///     let _ = server.load_pending(Duration::from_millis(16));
+///     println!("Loading: {:.0}%", level.progress().fraction() * 100.0);
+/// }
+///
+/// let _ = server.release_group("level1");
+/// ```
+///
+#[derive(Debug)]
+pub struct AssetServer {
+    /// Manager from which assets are loaded.
+    ///
+    manager: AssetManager,
+    /// Declared dependencies of assets.
+    ///
+    dependencies: HashMap<AssetMetadata, Vec<AssetMetadata>>,
+    /// Loaded assets.
+    ///
+    assets: HashMap<AssetMetadata, LoadedAsset>,
+    /// Groups by their names.
+    ///
+    groups: BTreeMap<String, PreloadGroup>,
+}
+impl AssetServer {
+    /// Initializes [`AssetServer`] without loaded assets.
+    ///
+    pub fn new(manager: AssetManager) -> Self {
+        AssetServer {
+            manager,
+            dependencies: HashMap::new(),
+            assets: HashMap::new(),
+            groups: BTreeMap::new(),
+        }
+    }
+    /// Returns manager from which assets are loaded.
+    ///
+    pub fn manager(&self) -> &AssetManager {
+        &self.manager
+    }
+
+    /// Declares that asset depends on another asset.
+    ///
+    /// Cyclic dependencies are allowed, although order of loading of assets in the cycle is unspecified.
+    ///
+    pub fn add_dependency(&mut self, asset: AssetMetadata, dependency: AssetMetadata) {
+        let dependencies: &mut Vec<AssetMetadata> = self.dependencies.entry(asset).or_default();
+        if !dependencies.contains(&dependency) {
+            dependencies.push(dependency);
+        }
+    }
+    /// Returns declared (direct) dependencies of the asset.
+    ///
+    pub fn dependencies(&self, asset: &AssetMetadata) -> &[AssetMetadata] {
+        self.dependencies.get(asset).map_or(&[], Vec::as_slice)
+    }
+    /// Returns given assets with all of their dependencies, where dependencies go before assets that depend on them.
+    ///
+    fn dependency_order(
+        &self,
+        assets: impl IntoIterator<Item = AssetMetadata>,
+    ) -> Vec<AssetMetadata> {
+        let mut order: Vec<AssetMetadata> = Vec::new();
+        let mut visited: HashSet<AssetMetadata> = HashSet::new();
+        // (asset, whether its dependencies were already pushed onto the stack)
+        let mut stack: Vec<(AssetMetadata, bool)> = assets
+            .into_iter()
+            .map(|asset| (asset, false))
+            .collect::<Vec<(AssetMetadata, bool)>>();
+        stack.reverse();
+        while let Some((asset, expanded)) = stack.pop() {
+            if expanded {
+                order.push(asset);
+                continue;
+            }
+            if !visited.insert(asset.clone()) {
+                continue;
+            }
+            stack.push((asset.clone(), true));
+            for dependency in self.dependencies(&asset).iter().rev() {
+                if !visited.contains(dependency) {
+                    stack.push((dependency.clone(), false));
+                }
+            }
+        }
+        order
+    }
+
+    /// Starts preloading of a group of assets (and their dependencies) and returns handle to its progress.
+    ///
+    /// Assets are loaded by `AssetServer::load_pending`. If group with given name exists, it is released first.
+    ///
+    pub fn preload_group(
+        &mut self,
+        name: &str,
+        assets: impl IntoIterator<Item = AssetMetadata>,
+    ) -> PreloadHandle {
+        let _ = self.release_group(name);
+
+        let pending: VecDeque<AssetMetadata> = VecDeque::from(self.dependency_order(assets));
+        let total_bytes: u64 = pending
+            .iter()
+            .map(|asset| match self.assets.get(asset) {
+                Some(loaded) => loaded.bytes.len() as u64,
+                None => fs::metadata(self.manager.full_path(asset.clone()))
+                    .map_or(0, |metadata| metadata.len()),
+            })
+            .sum();
+        let progress: Rc<Cell<PreloadProgress>> = Rc::new(Cell::new(PreloadProgress {
+            total: pending.len(),
+            total_bytes,
+            ..PreloadProgress::default()
+        }));
+        log::info(
+            LogTarget::DataCore,
+            format_args!(
+                "Preloading group '{name}' of {} assets ({total_bytes} bytes)",
+                pending.len()
+            ),
+        );
+
+        let _ = self.groups.insert(
+            String::from(name),
+            PreloadGroup {
+                pending,
+                held: Vec::new(),
+                progress: Rc::clone(&progress),
+            },
+        );
+        PreloadHandle {
+            name: Rc::from(name),
+            progress,
+        }
+    }
+    /// Loads pending assets of all groups until time budget is exhausted
+    /// (at least one asset is loaded if any is pending).
+    ///
+    /// Returns number of processed assets.
+    ///
+    pub fn load_pending(&mut self, budget: Duration) -> usize {
+        let start: Instant = Instant::now();
+        let mut processed: usize = 0;
+        for (name, group) in self.groups.iter_mut() {
+            while let Some(asset) = group.pending.pop_front() {
+                let mut progress: PreloadProgress = group.progress.get();
+                if let Some(loaded) = self.assets.get_mut(&asset) {
+                    loaded.groups += 1;
+                    progress.loaded += 1;
+                    progress.loaded_bytes += loaded.bytes.len() as u64;
+                    group.held.push(asset);
+                } else {
+                    match self.manager.load_raw(asset.clone()) {
+                        Ok(bytes) => {
+                            progress.loaded += 1;
+                            progress.loaded_bytes += bytes.len() as u64;
+                            let _ = self
+                                .assets
+                                .insert(asset.clone(), LoadedAsset { bytes, groups: 1 });
+                            group.held.push(asset);
+                        }
+                        Err(_) => progress.failed += 1,
+                    }
+                }
+                group.progress.set(progress);
+                processed += 1;
+
+                if group.pending.is_empty() {
+                    log::info(
+                        LogTarget::DataCore,
+                        format_args!(
+                            "Group '{name}' is preloaded ({} of {} assets)",
+                            progress.loaded, progress.total
+                        ),
+                    );
+                }
+                if start.elapsed() >= budget {
+                    return processed;
+                }
+            }
+        }
+        processed
+    }
+    /// Returns handle to the progress of the group.
+    ///
+    pub fn group(&self, name: &str) -> Option<PreloadHandle> {
+        self.groups.get(name).map(|group| PreloadHandle {
+            name: Rc::from(name),
+            progress: Rc::clone(&group.progress),
+        })
+    }
+    /// Releases the group, removing assets that are not held by other groups.
+    ///
+    /// Returns number of removed assets.
+    ///
+    pub fn release_group(&mut self, name: &str) -> usize {
+        let Some(group) = self.groups.remove(name) else {
+            return 0;
+        };
+        let mut removed: usize = 0;
+        for asset in group.held {
+            if let Some(loaded) = self.assets.get_mut(&asset) {
+                loaded.groups -= 1;
+                if loaded.groups == 0 {
+                    let _ = self.assets.remove(&asset);
+                    removed += 1;
+                }
+            }
+        }
+        removed
+    }
+
+    /// Returns whether asset is loaded.
+    ///
+    pub fn is_loaded(&self, asset: &AssetMetadata) -> bool {
+        self.assets.contains_key(asset)
+    }
+    /// Returns raw data of the loaded asset.
+    ///
+    pub fn raw(&self, asset: &AssetMetadata) -> Option<&[u8]> {
+        self.assets.get(asset).map(|loaded| loaded.bytes.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssetServer, PreloadHandle, PreloadProgress};
+    use crate::datacore::assets::{AssetFormat, AssetManager, AssetMetadata};
+    use std::{fs, path::PathBuf, time::Duration};
+
+    fn asset(name: &str) -> AssetMetadata {
+        AssetMetadata {
+            filename: PathBuf::from(name),
+            format: AssetFormat::Other,
+        }
+    }
+
+    #[test]
+    fn preload_groups() {
+        let directory: PathBuf = std::env::temp_dir().join("ggengine_server_test");
+        let _ = fs::remove_dir_all(&directory);
+        let manager: AssetManager =
+            AssetManager::initialize_at(&directory).expect("Directory is writable");
+        for (name, size) in [("image", 40), ("atlas", 10), ("animation", 5)] {
+            fs::write(manager.full_path(asset(name)), vec![0u8; size]).expect("File is writable");
+        }
+
+        let mut server: AssetServer = AssetServer::new(manager);
+        server.add_dependency(asset("atlas"), asset("image"));
+        server.add_dependency(asset("animation"), asset("atlas"));
+        server.add_dependency(asset("image"), asset("animation"));
+        assert_eq!(
+            server.dependency_order([asset("animation"), asset("missing")]),
+            [
+                asset("image"),
+                asset("atlas"),
+                asset("animation"),
+                asset("missing")
+            ]
+        );
+
+        let level: PreloadHandle =
+            server.preload_group("level", [asset("atlas"), asset("missing")]);
+        let menu: PreloadHandle = server.preload_group("menu", [asset("image")]);
+        assert_eq!(level.progress().total_bytes, 55);
+        assert!(!level.is_done());
+
+        while server.load_pending(Duration::ZERO) > 0 {}
+        assert_eq!(
+            level.progress(),
+            PreloadProgress {
+                loaded: 3,
+                failed: 1,
+                total: 4,
+                loaded_bytes: 55,
+                total_bytes: 55,
+            }
+        );
+        assert!(menu.is_done());
+        assert_eq!(server.raw(&asset("atlas")).map(<[u8]>::len), Some(10));
+
+        assert_eq!(server.release_group("level"), 0);
+        assert_eq!(server.release_group("menu"), 3);
+        assert!(!server.is_loaded(&asset("image")));
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+}
@@ -0,0 +1,297 @@
+//! `datacore::settings` submodule implements [`Settings`] - persistent configuration of a game,
+//! which consists of engine settings ([`EngineSettings`]) and user-defined settings.
+//!
+//! Settings are stored in platform-appropriate configuration directory
+//! (see [`Settings::config_directory`]) and are versioned, so they can be migrated
+//! when layout of settings changes between releases of a game.
+//!
+
+use crate::{
+    datacore::{
+        assets::{FromFile, ToFile},
+        audio::{AudioSystem, ChannelGroup, Volume},
+    },
+    inputcore::actions::InputAccessibility,
+    log::{self, LogTarget},
+    physicscore::layers::CollisionLayers,
+    utils::{FullscreenType, WindowSettings},
+    GGError,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+/// [`EngineSettings`] struct holds settings that are used by `ggengine` itself.
+///
+/// Fields that are missing in stored data are filled with defaults,
+/// so adding new fields does not break settings that were saved earlier.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct EngineSettings {
+    /// Fullscreen mode of the window (`None` corresponds to windowed mode).
+    ///
+    pub fullscreen: Option<FullscreenType>,
+    /// Resolution of the window.
+    ///
+    pub resolution: (u32, u32),
+    /// Whether vertical synchronization is enabled.
+    ///
+    pub vsync: bool,
+
+    /// Master volume of audio system.
+    ///
+    pub master_volume: Volume,
+    /// Volume of [`ChannelGroup::Sfx`].
+    ///
+    pub sfx_volume: Volume,
+    /// Volume of [`ChannelGroup::Voice`].
+    ///
+    pub voice_volume: Volume,
+    /// Volume of [`ChannelGroup::Music`].
+    ///
+    pub music_volume: Volume,
+
+    /// Key bindings - names of keys that are bound to names of actions.
+    ///
+    pub key_bindings: BTreeMap<String, String>,
+    /// Accessibility options of actions and axes (see [`Actions`](crate::inputcore::actions::Actions)).
+    ///
+    pub input_accessibility: InputAccessibility,
+
+    /// Collision layers of physics (see [`PhysicsWorld::collision_layers`](crate::physicscore::world::PhysicsWorld::collision_layers)).
+    ///
+    pub collision_layers: CollisionLayers,
+}
+impl EngineSettings {
+    /// Returns [`WindowSettings`] that correspond to these settings.
+    ///
+    /// Resolution should be passed to `build_window` separately.
+    ///
+    pub fn window_settings(&self) -> WindowSettings {
+        WindowSettings {
+            initial_fullscreen: self.fullscreen,
+            ..WindowSettings::default()
+        }
+    }
+    /// Applies volumes to audio system.
+    ///
+    pub fn apply_volumes(&self) {
+        AudioSystem::set_master_volume(self.master_volume);
+        ChannelGroup::Sfx.set_volume(self.sfx_volume);
+        ChannelGroup::Voice.set_volume(self.voice_volume);
+        ChannelGroup::Music.set_volume(self.music_volume);
+    }
+}
+impl Default for EngineSettings {
+    /// Returns windowed 1280x720 mode with vsync and all volumes at maximum.
+    ///
+    fn default() -> Self {
+        EngineSettings {
+            fullscreen: None,
+            resolution: (1280, 720),
+            vsync: true,
+
+            master_volume: Volume::MAX,
+            sfx_volume: Volume::MAX,
+            voice_volume: Volume::MAX,
+            music_volume: Volume::MAX,
+
+            key_bindings: BTreeMap::new(),
+            input_accessibility: InputAccessibility::default(),
+
+            collision_layers: CollisionLayers::default(),
+        }
+    }
+}
+
+/// [`Settings`] struct is a persistent configuration of a game.
+///
+/// `U` is a type of user-defined settings; it is recommended to mark it with `#[serde(default)]`,
+/// so newly added fields would not break loading of older settings.
+///
+/// # Versioning
+/// Every game passes current version of its settings when loading them.
+/// If stored version differs, migration function is called with stored settings and their version,
+/// after which settings are marked with the current version.
+///
+/// # Example
+/// ```rust, no_run
+/// # use ggengine::datacore::settings::Settings;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize, Default)]
+/// #[serde(default)]
+/// struct GameSettings {
+///     difficulty: u8,
+///     subtitles: bool,
+/// }
+///
+/// let mut settings: Settings<GameSettings> =
+///     Settings::<GameSettings>::load("ggengine", "game", 2, |settings, version| {
+///         // subtitles were added in the second version
+///         if version < 2 {
+///             settings.user.subtitles = true;
+///         }
+///     })
+///     .expect("Settings should be readable");
+/// settings.engine.resolution = (1920, 1080);
+/// settings.save("ggengine", "game").expect("Settings should be writable");
+/// ```
+///
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct Settings<U = ()> {
+    /// Version of settings layout.
+    ///
+    pub version: u32,
+    /// Settings of the engine.
+    ///
+    pub engine: EngineSettings,
+    /// User-defined settings.
+    ///
+    pub user: U,
+}
+impl<U: Serialize + DeserializeOwned + Default> Settings<U> {
+    /// Name of a file in which settings are stored.
+    ///
+    pub const FILENAME: &'static str = "settings.cbor";
+
+    /// Returns platform-appropriate directory for configuration of given application,
+    /// creating it if needed.
+    ///
+    /// Organization and application names should not change between releases of a game.
+    ///
+    pub fn config_directory(organization: &str, application: &str) -> Result<PathBuf, GGError> {
+        sdl2::filesystem::pref_path(organization, application)
+            .map(PathBuf::from)
+            .map_err(|error| GGError::Sdl(error.to_string()))
+    }
+
+    /// Loads settings from the file, migrating them to given version if needed.
+    ///
+    /// If file does not exist, default settings with given version are returned.
+    ///
+    /// # Errors
+    /// Error is returned if file exists, but cannot be read or decoded.
+    ///
+    pub fn load_from(
+        filename: impl AsRef<Path>,
+        version: u32,
+        migrate: impl FnOnce(&mut Settings<U>, u32),
+    ) -> Result<Settings<U>, GGError> {
+        let filename: &Path = filename.as_ref();
+        if !filename.exists() {
+            log::info(
+                LogTarget::DataCore,
+                format_args!(
+                    "Settings {} were not found, using defaults",
+                    filename.display()
+                ),
+            );
+            return Ok(Settings {
+                version,
+                ..Settings::default()
+            });
+        }
+
+        let mut settings: Settings<U> = Settings::from_file(filename)?;
+        if settings.version != version {
+            log::info(
+                LogTarget::DataCore,
+                format_args!(
+                    "Migrating settings from version {} to version {version}",
+                    settings.version
+                ),
+            );
+            let stored_version: u32 = settings.version;
+            migrate(&mut settings, stored_version);
+            settings.version = version;
+        }
+        Ok(settings)
+    }
+    /// Saves settings to the file.
+    ///
+    pub fn save_to(&self, filename: impl AsRef<Path>) -> Result<(), GGError> {
+        self.to_file(filename)
+    }
+
+    /// Loads settings from configuration directory of given application
+    /// (see [`Settings::config_directory`] and [`Settings::load_from`]).
+    ///
+    pub fn load(
+        organization: &str,
+        application: &str,
+        version: u32,
+        migrate: impl FnOnce(&mut Settings<U>, u32),
+    ) -> Result<Settings<U>, GGError> {
+        Settings::load_from(
+            Settings::<U>::config_directory(organization, application)?.join(Self::FILENAME),
+            version,
+            migrate,
+        )
+    }
+    /// Saves settings to configuration directory of given application.
+    ///
+    pub fn save(&self, organization: &str, application: &str) -> Result<(), GGError> {
+        self.save_to(
+            Settings::<U>::config_directory(organization, application)?.join(Self::FILENAME),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Settings;
+    use crate::{
+        datacore::audio::Volume, inputcore::actions::ActionOptions,
+        physicscore::layers::CollisionLayers, utils::FullscreenType,
+    };
+    use std::{fs, path::PathBuf};
+
+    #[test]
+    fn persistence_and_migration() {
+        let filename: PathBuf = std::env::temp_dir().join("ggengine_settings_test.cbor");
+        let _ = fs::remove_file(&filename);
+
+        let mut settings: Settings<u32> =
+            Settings::load_from(&filename, 1, |_, _| unreachable!()).expect("Defaults are used");
+        assert_eq!(settings.version, 1);
+        assert_eq!(settings.engine.master_volume, Volume::MAX);
+
+        settings.engine.fullscreen = Some(FullscreenType::DesktopFullscreen);
+        let _ = settings
+            .engine
+            .key_bindings
+            .insert(String::from("Space"), String::from("jump"));
+        let _ = settings.engine.input_accessibility.actions.insert(
+            String::from("jump"),
+            ActionOptions {
+                toggle: true,
+                repeat: None,
+            },
+        );
+        settings.engine.collision_layers = CollisionLayers::new().with_layer("bullets");
+        let _ = settings
+            .engine
+            .collision_layers
+            .set_interaction("bullets", "bullets", false);
+        settings.user = 10;
+        settings.save_to(&filename).expect("File is writable");
+
+        let loaded: Settings<u32> =
+            Settings::load_from(&filename, 1, |_, _| unreachable!()).expect("File is readable");
+        assert_eq!(loaded, settings);
+
+        let migrated: Settings<u32> = Settings::load_from(&filename, 2, |settings, version| {
+            assert_eq!(version, 1);
+            settings.user *= 2;
+        })
+        .expect("File is readable");
+        assert_eq!(migrated.version, 2);
+        assert_eq!(migrated.user, 20);
+
+        let _ = fs::remove_file(&filename);
+    }
+}
@@ -0,0 +1,77 @@
+//! `errors` hidden submodule implements [`GGError`] enum - error type that is used by all fallible
+//! operations of `ggengine`.
+//!
+
+use std::{error::Error, fmt, io};
+
+/// [`GGError`] enum lists all kinds of errors that can occur in `ggengine`.
+///
+/// Fallible operations return `Result<T, GGError>`, so games can recover from missing drivers,
+/// broken assets or wrong arguments instead of panicking.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::GGError;
+/// # use std::io;
+/// fn read_config() -> Result<String, GGError> {
+///     Ok(std::fs::read_to_string("missing_config.toml")?)
+/// }
+///
+/// match read_config() {
+///     Err(GGError::Io(error)) => assert_eq!(error.kind(), io::ErrorKind::NotFound),
+///     _ => unreachable!(),
+/// }
+/// ```
+///
+#[derive(Debug)]
+pub enum GGError {
+    /// Error that was reported by underlying `sdl2` library (e.g. driver is missing or device cannot be opened).
+    ///
+    Sdl(String),
+    /// Error of input/output operation (e.g. file is missing).
+    ///
+    Io(io::Error),
+    /// Data is malformed and cannot be decoded.
+    ///
+    Decode(String),
+    /// Argument that was passed to a function is invalid.
+    ///
+    InvalidArgument(String),
+    /// Operation cannot be performed in current state (e.g. subsystem is not initialized).
+    ///
+    InvalidState(String),
+    /// Operation or format is not supported.
+    ///
+    Unsupported(String),
+    /// Subsystem that is required by operation was not initialized (name of subsystem is given).
+    ///
+    SubsystemNotInitialized(String),
+}
+impl fmt::Display for GGError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GGError::Sdl(message) => write!(f, "sdl2 error: {message}"),
+            GGError::Io(error) => write!(f, "io error: {error}"),
+            GGError::Decode(message) => write!(f, "decoding error: {message}"),
+            GGError::InvalidArgument(message) => write!(f, "invalid argument: {message}"),
+            GGError::InvalidState(message) => write!(f, "invalid state: {message}"),
+            GGError::Unsupported(message) => write!(f, "unsupported: {message}"),
+            GGError::SubsystemNotInitialized(subsystem) => {
+                write!(f, "subsystem is not initialized: {subsystem}")
+            }
+        }
+    }
+}
+impl Error for GGError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GGError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+impl From<io::Error> for GGError {
+    fn from(error: io::Error) -> Self {
+        GGError::Io(error)
+    }
+}
@@ -4,8 +4,18 @@
 
 // submodules and public re-exports
 mod storages;
+pub use crate::gamecore::storages::{ComponentStorage, ResourceStorage, SystemStorage};
 
 pub mod components;
 pub mod gameobjects;
 pub mod identifiers;
+pub mod inspection;
+pub mod plugins;
 pub mod scenes;
+pub mod schedules;
+pub mod scripting;
+pub mod sequences;
+pub mod serialization;
+pub mod statemachines;
+pub mod streaming;
+pub mod time;
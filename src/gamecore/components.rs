@@ -4,6 +4,10 @@
 //! and implements several basic components used in games.
 //!
 
+use crate::{
+    gamecore::scripting::ScriptValue,
+    mathcore::{random::Rng, transforms::Transform2D},
+};
 use std::{
     any::{type_name, Any},
     collections::LinkedList,
@@ -148,6 +152,55 @@ impl fmt::Debug for dyn Component {
 ///
 pub type BoxedComponent = Box<dyn Component>;
 
+/// [`ComponentLayout`] struct describes component that has no Rust type and is registered at runtime
+/// (e.g. by scripts or mods).
+///
+/// Such components are stored as raw bytes of fixed size; functions of the layout
+/// define how those bytes are cloned and serialized.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::{components::ComponentLayout, scripting::ScriptValue};
+/// let layout: ComponentLayout = ComponentLayout {
+///     serialize: |data| ScriptValue::Integer(i128::from(u32::from_le_bytes([data[0], data[1], data[2], data[3]]))),
+///     ..ComponentLayout::new("Mana", 4)
+/// };
+/// assert_eq!((layout.serialize)(&7u32.to_le_bytes()), ScriptValue::Integer(7));
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct ComponentLayout {
+    /// Unique name of a component.
+    ///
+    pub name: String,
+    /// Size of a component in bytes.
+    ///
+    pub size: usize,
+    /// Function that clones data of a component.
+    ///
+    pub clone: fn(&[u8]) -> Vec<u8>,
+    /// Function that serializes data of a component.
+    ///
+    pub serialize: fn(&[u8]) -> ScriptValue,
+}
+impl ComponentLayout {
+    /// Constructs layout with given name and size, which data is cloned bytewise
+    /// and serialized as a byte string.
+    ///
+    pub fn new(name: &str, size: usize) -> Self {
+        ComponentLayout {
+            name: String::from(name),
+            size,
+            clone: <[u8]>::to_vec,
+            serialize: |data| ScriptValue::Bytes(data.to_vec()),
+        }
+    }
+}
+
+/// [`Transform2D`] is a component that describes placement of a `GameObject` (its position, rotation and scale).
+///
+impl Component for Transform2D {}
+
 /// [`Bundle`] trait defines a set of [`Component`]s.
 ///
 /// In ECS, components define objects and systems operate on combinations of components.
@@ -421,6 +474,13 @@ impl fmt::Debug for dyn Resource {
         write!(f, "dyn Resource ({:?})", type_name::<Self>())
     }
 }
+
+/// [`Rng`] is a resource that holds random number generator of a [`Scene`](super::scenes::Scene).
+///
+/// Every scene is constructed with its own generator (see `Scene::new`), and systems should
+/// draw random values from it (or from streams that are forked from it), so that runs can be reproduced by seed.
+///
+impl Resource for Rng {}
 /// Type alias for `Box<dyn Resource>`.
 ///
 /// This type alias will be frequently used in situations in which
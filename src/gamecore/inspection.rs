@@ -0,0 +1,297 @@
+//! `gamecore::inspection` submodule implements data model of entity inspectors - trees of names and values
+//! that describe components of game objects.
+//!
+//! Trees are built from serialized data of components that are registered in
+//! [`ComponentRegistry`](crate::gamecore::serialization::ComponentRegistry)
+//! (see `ComponentRegistry::inspect`), so any serializable component can be shown by in-game debug inspectors
+//! or by external editors without additional code. Primitive fields can be edited with `ComponentRegistry::set_field`.
+//!
+
+use crate::{gamecore::scripting::ScriptValue, GGError};
+
+/// [`InspectorField`] struct is a named node of inspector tree.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct InspectorField {
+    /// Name of the field (fields of sequences are named by their indices).
+    ///
+    pub name: String,
+    /// Value of the field.
+    ///
+    pub value: InspectorValue,
+}
+
+/// [`InspectorValue`] enum lists values that are shown by inspectors.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum InspectorValue {
+    /// Absent value (e.g. `None`).
+    ///
+    Null,
+    /// Boolean value.
+    ///
+    Bool(bool),
+    /// Integer value.
+    ///
+    Integer(i128),
+    /// Floating point value.
+    ///
+    Float(f64),
+    /// Text value (unit variants of enums are also shown as text).
+    ///
+    Text(String),
+    /// Raw bytes.
+    ///
+    Bytes(Vec<u8>),
+    /// Value that consists of other fields (structs, maps, sequences and variants of enums).
+    ///
+    Compound(Vec<InspectorField>),
+}
+impl InspectorValue {
+    /// Returns whether value is primitive and can be edited.
+    ///
+    pub fn is_primitive(&self) -> bool {
+        matches!(
+            self,
+            InspectorValue::Bool(_)
+                | InspectorValue::Integer(_)
+                | InspectorValue::Float(_)
+                | InspectorValue::Text(_)
+        )
+    }
+    /// Returns nested value by path of names of fields.
+    ///
+    /// Empty path returns the value itself.
+    ///
+    pub fn get(&self, path: &[&str]) -> Option<&InspectorValue> {
+        let Some((name, rest)) = path.split_first() else {
+            return Some(self);
+        };
+        match self {
+            InspectorValue::Compound(fields) => fields
+                .iter()
+                .find(|field| field.name == *name)
+                .and_then(|field| field.value.get(rest)),
+            _ => None,
+        }
+    }
+
+    /// Builds inspector tree from serialized value.
+    ///
+    pub(crate) fn from_script_value(value: &ScriptValue) -> InspectorValue {
+        match value {
+            ScriptValue::Bool(value) => InspectorValue::Bool(*value),
+            ScriptValue::Integer(value) => InspectorValue::Integer(*value),
+            ScriptValue::Float(value) => InspectorValue::Float(*value),
+            ScriptValue::Text(value) => InspectorValue::Text(value.clone()),
+            ScriptValue::Bytes(value) => InspectorValue::Bytes(value.clone()),
+            ScriptValue::Array(items) => InspectorValue::Compound(
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| InspectorField {
+                        name: index.to_string(),
+                        value: InspectorValue::from_script_value(item),
+                    })
+                    .collect(),
+            ),
+            ScriptValue::Map(entries) => InspectorValue::Compound(
+                entries
+                    .iter()
+                    .map(|(key, value)| InspectorField {
+                        name: field_name(key),
+                        value: InspectorValue::from_script_value(value),
+                    })
+                    .collect(),
+            ),
+            ScriptValue::Tag(_, value) => InspectorValue::from_script_value(value),
+            _ => InspectorValue::Null,
+        }
+    }
+}
+
+/// Returns name under which entry of serialized map is shown.
+///
+fn field_name(key: &ScriptValue) -> String {
+    match key {
+        ScriptValue::Text(name) => name.clone(),
+        ScriptValue::Integer(index) => index.to_string(),
+        key => format!("{key:?}"),
+    }
+}
+
+/// Replaces primitive field of serialized value that is found by path of names of fields.
+///
+/// New value should have the same type as the old one (integers are also accepted by floating point fields).
+///
+pub(crate) fn set_field(
+    value: &mut ScriptValue,
+    path: &[&str],
+    new_value: &InspectorValue,
+) -> Result<(), GGError> {
+    let Some((name, rest)) = path.split_first() else {
+        let replacement: ScriptValue = match (&*value, new_value) {
+            (ScriptValue::Bool(_), InspectorValue::Bool(new_value)) => {
+                ScriptValue::Bool(*new_value)
+            }
+            (ScriptValue::Integer(_), InspectorValue::Integer(new_value)) => {
+                ScriptValue::Integer(*new_value)
+            }
+            (ScriptValue::Float(_), InspectorValue::Float(new_value)) => {
+                ScriptValue::Float(*new_value)
+            }
+            (ScriptValue::Float(_), InspectorValue::Integer(new_value)) => {
+                ScriptValue::Float(*new_value as f64)
+            }
+            (ScriptValue::Text(_), InspectorValue::Text(new_value)) => {
+                ScriptValue::Text(new_value.clone())
+            }
+            (ScriptValue::Bool(_), _)
+            | (ScriptValue::Integer(_), _)
+            | (ScriptValue::Float(_), _)
+            | (ScriptValue::Text(_), _) => {
+                return Err(GGError::InvalidArgument(format!(
+                    "{new_value:?} does not match type of the field"
+                )))
+            }
+            _ => {
+                return Err(GGError::InvalidArgument(String::from(
+                    "Field is not primitive",
+                )))
+            }
+        };
+        *value = replacement;
+        return Ok(());
+    };
+    let field: Option<&mut ScriptValue> = match value {
+        ScriptValue::Array(items) => name
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| items.get_mut(index)),
+        ScriptValue::Map(entries) => entries
+            .iter_mut()
+            .find(|(key, _)| field_name(key) == *name)
+            .map(|(_, value)| value),
+        ScriptValue::Tag(_, tagged) => return set_field(tagged, path, new_value),
+        _ => None,
+    };
+    set_field(
+        field.ok_or_else(|| GGError::InvalidArgument(format!("There is no field {name}")))?,
+        rest,
+        new_value,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InspectorField, InspectorValue};
+    use crate::{
+        gamecore::{
+            components::Component, identifiers::GameObjectId, scenes::Scene,
+            serialization::ComponentRegistry,
+        },
+        GGError,
+    };
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum Team {
+        Red,
+        Blue,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Unit {
+        health: u8,
+        speed: f32,
+        team: Team,
+        path: Vec<(i32, i32)>,
+    }
+    impl Component for Unit {}
+
+    #[test]
+    fn inspect_and_edit() {
+        let mut registry: ComponentRegistry = ComponentRegistry::new();
+        registry.register::<Unit>("Unit", 1);
+        let mut scene: Scene = Scene::new();
+        let unit: GameObjectId = scene.spawn(Unit {
+            health: 10,
+            speed: 1.5,
+            team: Team::Red,
+            path: vec![(0, 0), (3, 4)],
+        });
+        let empty: GameObjectId = scene.spawn(());
+
+        let components: Vec<InspectorField> = registry
+            .inspect(&scene, unit)
+            .expect("Unit is serializable");
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].name, "Unit");
+        let tree: &InspectorValue = &components[0].value;
+        assert_eq!(tree.get(&["health"]), Some(&InspectorValue::Integer(10)));
+        assert_eq!(
+            tree.get(&["team"]),
+            Some(&InspectorValue::Text(String::from("Red")))
+        );
+        assert_eq!(
+            tree.get(&["path", "1", "0"]),
+            Some(&InspectorValue::Integer(3))
+        );
+        assert!(!tree
+            .get(&["path"])
+            .is_some_and(InspectorValue::is_primitive));
+        assert!(registry
+            .inspect(&scene, empty)
+            .expect("Nothing to serialize")
+            .is_empty());
+
+        for (path, value) in [
+            (&["health"][..], InspectorValue::Integer(7)),
+            (&["speed"], InspectorValue::Integer(2)),
+            (&["team"], InspectorValue::Text(String::from("Blue"))),
+            (&["path", "1", "1"], InspectorValue::Integer(-4)),
+        ] {
+            registry
+                .set_field(&mut scene, unit, "Unit", path, &value)
+                .expect("Field is editable");
+        }
+        assert_eq!(
+            scene.components().get_component::<Unit>(unit),
+            Some(&Unit {
+                health: 7,
+                speed: 2.0,
+                team: Team::Blue,
+                path: vec![(0, 0), (3, -4)],
+            })
+        );
+
+        for (path, value) in [
+            (&["health"][..], InspectorValue::Integer(300)),
+            (&["health"], InspectorValue::Bool(true)),
+            (&["team"], InspectorValue::Text(String::from("Green"))),
+            (&["path"], InspectorValue::Null),
+            (&["mana"], InspectorValue::Integer(1)),
+        ] {
+            assert!(registry
+                .set_field(&mut scene, unit, "Unit", path, &value)
+                .is_err());
+        }
+        assert_eq!(
+            scene
+                .components()
+                .get_component::<Unit>(unit)
+                .map(|unit| unit.health),
+            Some(7)
+        );
+        assert!(matches!(
+            registry.set_field(
+                &mut scene,
+                empty,
+                "Unit",
+                &["health"],
+                &InspectorValue::Integer(1)
+            ),
+            Err(GGError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,155 @@
+//! `gamecore::plugins` submodule defines [`Plugin`] trait - composable unit of a game or an engine feature
+//! that registers its resources and systems at once.
+//!
+//! Resources are inserted into [`Scene`] by `Scene::add_plugin`, and systems of a plugin are grouped
+//! into a nested [`Schedule`] that is labelled by the name of the plugin (see `Schedule::add_plugin`).
+//! Since nested schedules can be replaced and removed as a single unit, plugins can be hot-swapped
+//! while game is running (see `ScheduleRunner::add_plugin` and `ScheduleRunner::remove_plugin`).
+//!
+
+use crate::gamecore::{scenes::Scene, schedules::Schedule};
+use std::any::type_name;
+
+/// [`Plugin`] trait is implemented by features that extend a game.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::{components::Resource, plugins::Plugin, scenes::Scene, schedules::Schedule};
+/// struct Score(u32);
+/// impl Resource for Score {}
+///
+/// struct ScorePlugin;
+/// impl Plugin for ScorePlugin {
+///     fn build(&self, scene: &mut Scene) {
+///         let _ = scene.resources_mut().insert_resource(Score(0));
+///     }
+///     fn systems(&self, schedule: &mut Schedule) {
+///         schedule.add_system("count", |scene: &mut Scene| {
+///             scene.resources_mut().get_resource_mut::<Score>().expect("Plugin was added").0 += 1;
+///         });
+///     }
+/// }
+///
+/// let mut scene: Scene = Scene::new();
+/// let mut schedule: Schedule = Schedule::new();
+/// scene.add_plugin(&ScorePlugin);
+/// schedule.add_plugin(&ScorePlugin);
+/// schedule.run(&mut scene);
+/// assert_eq!(scene.resources().get_resource::<Score>().map(|score| score.0), Some(1));
+/// ```
+///
+pub trait Plugin {
+    /// Returns name of the plugin, which labels its systems in [`Schedule`].
+    ///
+    /// By default, name of the type is used.
+    ///
+    fn name(&self) -> &str {
+        type_name::<Self>()
+    }
+    /// Registers resources (and game objects) of the plugin in the scene.
+    ///
+    fn build(&self, scene: &mut Scene);
+    /// Registers systems of the plugin in the schedule that is nested into the schedule of the game.
+    ///
+    /// Default implementation registers nothing.
+    ///
+    fn systems(&self, schedule: &mut Schedule) {
+        let _ = schedule;
+    }
+    /// Is called when plugin is removed (e.g. to remove its resources).
+    ///
+    /// Default implementation does nothing.
+    ///
+    fn cleanup(&self, scene: &mut Scene) {
+        let _ = scene;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Plugin;
+    use crate::gamecore::{
+        components::Resource,
+        scenes::Scene,
+        schedules::{Schedule, ScheduleRunner},
+    };
+    use std::time::Duration;
+
+    struct Counter(u32);
+    impl Resource for Counter {}
+
+    struct CounterPlugin {
+        step: u32,
+    }
+    impl Plugin for CounterPlugin {
+        fn name(&self) -> &str {
+            "counter"
+        }
+        fn build(&self, scene: &mut Scene) {
+            let _ = scene
+                .resources_mut()
+                .get_resource_or_insert_with(|| Counter(0));
+        }
+        fn systems(&self, schedule: &mut Schedule) {
+            let step: u32 = self.step;
+            schedule.add_startup_system("reset", |scene: &mut Scene| {
+                let _ = scene.resources_mut().insert_resource(Counter(100));
+            });
+            schedule.add_system("count", move |scene: &mut Scene| {
+                if let Some(counter) = scene.resources_mut().get_resource_mut::<Counter>() {
+                    counter.0 += step;
+                }
+            });
+        }
+        fn cleanup(&self, scene: &mut Scene) {
+            let _ = scene.resources_mut().remove_resource::<Counter>();
+        }
+    }
+
+    fn counter(runner: &ScheduleRunner) -> Option<u32> {
+        runner
+            .scene
+            .resources()
+            .get_resource::<Counter>()
+            .map(|counter| counter.0)
+    }
+
+    #[test]
+    fn hot_swap() {
+        let mut runner: ScheduleRunner =
+            ScheduleRunner::new(Scene::new(), Schedule::new(), Duration::ZERO);
+        runner.add_plugin(&CounterPlugin { step: 1 });
+        assert_eq!(counter(&runner), Some(0));
+        runner.run_frames(2);
+        assert_eq!(counter(&runner), Some(102));
+
+        // startup systems of plugins that are added to running game are run immediately
+        runner.add_plugin(&CounterPlugin { step: 10 });
+        assert_eq!(counter(&runner), Some(100));
+        runner.run_frames(1);
+        assert_eq!(counter(&runner), Some(110));
+        assert_eq!(
+            runner.schedule.systems().labels().collect::<Vec<&str>>(),
+            ["counter"]
+        );
+
+        assert!(runner.remove_plugin(&CounterPlugin { step: 10 }));
+        assert!(!runner.remove_plugin(&CounterPlugin { step: 10 }));
+        runner.run_frames(1);
+        assert_eq!(counter(&runner), None);
+    }
+
+    #[test]
+    fn removing_absent_plugin() {
+        let mut scene: Scene = Scene::new();
+        let _ = scene.resources_mut().insert_resource(Counter(7));
+        let mut runner: ScheduleRunner =
+            ScheduleRunner::new(scene, Schedule::new(), Duration::ZERO);
+
+        // resource that was not added by the plugin is not cleaned up
+        assert!(!runner.remove_plugin(&CounterPlugin { step: 1 }));
+        assert_eq!(counter(&runner), Some(7));
+        runner.run_frames(1);
+        assert_eq!(counter(&runner), Some(7));
+    }
+}
@@ -2,7 +2,382 @@
 //! all game objects, components and systems that are binded to that [`Scene`] and
 //! [`SceneManager`] - struct that provides convenient storage for those [`Scene`]s.
 //!
+//! [`SceneManager`] replaces scenes either immediately or with a [`Transition`], whose progress
+//! is tracked by manager and which is drawn by `graphicscore::transitions` submodule.
+//!
+//! [`Scene`] does not depend on any `sdl2` subsystem, so it can be constructed and updated
+//! without [`GGEngine`](crate::GGEngine) (e.g. on dedicated servers or in tests).
+//!
+//! Every [`Scene`] is created with [`Rng`] resource. Seeds of scenes are drawn from global generator,
+//! which is seeded randomly, unless global seed is set by `Scene::set_global_seed` -
+//! in that case whole run (all scenes in order of their creation) can be reproduced.
+//!
+
+use crate::{
+    gamecore::{
+        components::Bundle, identifiers::GameObjectId, plugins::Plugin, ComponentStorage,
+        ResourceStorage,
+    },
+    mathcore::{random::Rng, Color},
+};
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
+
+/// Global generator of seeds of new scenes (it is seeded randomly on first use, unless global seed was set).
+///
+static SCENE_SEEDS: Mutex<Option<Rng>> = Mutex::new(None);
+
+/// [`Scene`] struct holds game objects with their components and resources of a game.
+///
+/// Systems are not owned by [`Scene`] - they are stored in a
+/// [`Schedule`](super::schedules::Schedule), which runs them on a [`Scene`].
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::{components::Component, identifiers::GameObjectId, scenes::Scene};
+/// struct Name(&'static str);
+/// impl Component for Name {}
+/// struct Score(u32);
+/// impl Component for Score {}
+///
+/// let mut scene: Scene = Scene::new();
+/// let player: GameObjectId = scene.spawn((Name("player"), Score(0)));
+/// let _ = scene.spawn(Name("spectator"));
+///
+/// assert_eq!(scene.components().components::<Name>().count(), 2);
+/// scene.components_mut().get_component_mut::<Score>(player).expect("Player has score").0 += 10;
+///
+/// assert!(scene.despawn(player));
+/// assert_eq!(scene.components().gameobject_count(), 1);
+/// ```
+///
+#[derive(Debug)]
+pub struct Scene {
+    /// Storage of game objects and their components.
+    ///
+    components: ComponentStorage,
+    /// Storage of resources.
+    ///
+    resources: ResourceStorage,
+}
+impl Scene {
+    /// Initializes empty [`Scene`] with [`Rng`] resource, which seed is drawn from global generator.
+    ///
+    pub fn new() -> Scene {
+        Scene::with_seed(Scene::next_seed())
+    }
+    /// Initializes empty [`Scene`] with [`Rng`] resource that has given seed.
+    ///
+    pub fn with_seed(seed: u64) -> Scene {
+        let mut scene: Scene = Scene {
+            components: ComponentStorage::new(),
+            resources: ResourceStorage::new(),
+        };
+        let _ = scene.resources.insert_resource(Rng::new(seed));
+        scene
+    }
+
+    /// Seeds global generator from which seeds of new scenes are drawn.
+    ///
+    /// After that, scenes that are created by `Scene::new` get the same seeds in the same order on every run.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::gamecore::scenes::Scene;
+    /// let seed = || Scene::new().rng().seed();
+    ///
+    /// Scene::set_global_seed(7);
+    /// let seeds: [u64; 2] = [seed(), seed()];
+    /// Scene::set_global_seed(7);
+    /// assert_eq!([seed(), seed()], seeds);
+    /// ```
+    ///
+    pub fn set_global_seed(seed: u64) {
+        *SCENE_SEEDS.lock().unwrap_or_else(PoisonError::into_inner) = Some(Rng::new(seed));
+    }
+    /// Draws seed of a new scene from global generator.
+    ///
+    fn next_seed() -> u64 {
+        SCENE_SEEDS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_or_insert_with(|| Rng::new(RandomState::new().build_hasher().finish()))
+            .next_u64()
+    }
+
+    /// Spawns game object with components of given bundle and returns its id.
+    ///
+    pub fn spawn<B: Bundle>(&mut self, bundle: B) -> GameObjectId {
+        let gameobject_id: GameObjectId = self.components.insert_gameobject();
+        let _ = self.components.insert_components(gameobject_id, bundle);
+        gameobject_id
+    }
+    /// Despawns game object with all its components and returns whether it was present.
+    ///
+    pub fn despawn(&mut self, gameobject_id: GameObjectId) -> bool {
+        self.components.remove_gameobject(gameobject_id)
+    }
+
+    /// Returns reference to the storage of game objects and their components.
+    ///
+    pub fn components(&self) -> &ComponentStorage {
+        &self.components
+    }
+    /// Returns mutable reference to the storage of game objects and their components.
+    ///
+    pub fn components_mut(&mut self) -> &mut ComponentStorage {
+        &mut self.components
+    }
+    /// Returns reference to the storage of resources.
+    ///
+    pub fn resources(&self) -> &ResourceStorage {
+        &self.resources
+    }
+    /// Returns mutable reference to the storage of resources.
+    ///
+    pub fn resources_mut(&mut self) -> &mut ResourceStorage {
+        &mut self.resources
+    }
+    /// Returns [`Rng`] resource of the scene (it is reinserted with new seed if it was removed).
+    ///
+    pub fn rng(&mut self) -> &mut Rng {
+        self.resources
+            .get_resource_or_insert_with(|| Rng::new(Scene::next_seed()))
+    }
+
+    /// Registers resources of the plugin in the scene.
+    ///
+    /// Systems of the plugin are registered separately by `Schedule::add_plugin`
+    /// (`ScheduleRunner::add_plugin` does both).
+    ///
+    pub fn add_plugin(&mut self, plugin: &impl Plugin) {
+        plugin.build(self);
+    }
+}
+impl Default for Scene {
+    fn default() -> Self {
+        Scene::new()
+    }
+}
+
+/// [`WipeDirection`] enum lists directions in which [`Transition::Wipe`] reveals new scene.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum WipeDirection {
+    /// New scene is revealed from the left side.
+    ///
+    #[default]
+    LeftToRight,
+    /// New scene is revealed from the right side.
+    ///
+    RightToLeft,
+    /// New scene is revealed from the upper side.
+    ///
+    TopToBottom,
+    /// New scene is revealed from the lower side.
+    ///
+    BottomToTop,
+}
+
+/// [`Transition`] enum lists effects that are shown when [`SceneManager`] replaces scenes.
+///
+/// Every variant holds duration of the effect in seconds.
+/// Effects are drawn by [`TransitionRenderer`](crate::graphicscore::transitions::TransitionRenderer).
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Transition {
+    /// Old scene fades to black, then new scene fades in from black.
+    ///
+    Fade(f32),
+    /// Old scene fades to given color, then new scene fades in from that color.
+    ///
+    FadeToColor(Color, f32),
+    /// Captured frame of old scene fades out over new scene.
+    ///
+    Crossfade(f32),
+    /// Captured frame of old scene is wiped away in given direction, revealing new scene.
+    ///
+    Wipe(WipeDirection, f32),
+    /// Old scene becomes more and more pixelated, then new scene is unpixelated.
+    ///
+    Pixelate(f32),
+}
+impl Transition {
+    /// Returns duration of transition in seconds.
+    ///
+    pub fn duration(self) -> f32 {
+        match self {
+            Transition::Fade(duration)
+            | Transition::FadeToColor(_, duration)
+            | Transition::Crossfade(duration)
+            | Transition::Wipe(_, duration)
+            | Transition::Pixelate(duration) => duration,
+        }
+    }
+    /// Returns progress of transition after which scenes are swapped.
+    ///
+    /// Transitions that are drawn over captured frame of old scene swap scenes right after that frame was shown.
+    ///
+    pub fn swap_progress(self) -> f32 {
+        match self {
+            Transition::Fade(_) | Transition::FadeToColor(..) | Transition::Pixelate(_) => 0.5,
+            Transition::Crossfade(_) | Transition::Wipe(..) => 0.0,
+        }
+    }
+}
+
+/// [`TransitionState`] struct describes transition that is currently shown by [`SceneManager`].
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TransitionState {
+    /// Transition that is shown.
+    ///
+    pub transition: Transition,
+    /// Progress of transition (from 0.0 to 1.0).
+    ///
+    pub progress: f32,
+    /// Whether scenes were already swapped.
+    ///
+    pub swapped: bool,
+}
+
+/// [`ActiveTransition`] struct holds transition that is in progress.
+///
+#[derive(Debug)]
+struct ActiveTransition {
+    /// Shown transition.
+    ///
+    transition: Transition,
+    /// Time (in seconds) since transition has started.
+    ///
+    elapsed: f32,
+    /// Scene that will replace current one (`None` if scenes were already swapped).
+    ///
+    next: Option<Scene>,
+}
+impl ActiveTransition {
+    /// Returns progress of transition (from 0.0 to 1.0).
+    ///
+    fn progress(&self) -> f32 {
+        let duration: f32 = self.transition.duration();
+        if duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / duration).min(1.0)
+        }
+    }
+}
+
+/// [`SceneManager`] struct holds current [`Scene`] and replaces it, optionally with a [`Transition`].
+///
+/// Transitions are advanced by `update` function, which swaps scenes when transition reaches
+/// its swap point and returns replaced scene.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::scenes::{Scene, SceneManager, Transition};
+/// # use std::time::Duration;
+/// let mut manager: SceneManager = SceneManager::new(Scene::new());
+/// let _ = manager.scene_mut().spawn(());
+///
+/// manager.replace_with_transition(Scene::new(), Transition::Fade(0.5));
+/// assert!(manager.update(Duration::from_millis(200)).is_none());
+/// assert!(manager.update(Duration::from_millis(100)).is_none());
+/// let old: Option<Scene> = manager.update(Duration::from_millis(100));
+/// assert_eq!(old.map(|scene| scene.components().gameobject_count()), Some(1));
+///
+/// assert!(manager.is_transitioning());
+/// let _ = manager.update(Duration::from_millis(200));
+/// assert!(!manager.is_transitioning());
+/// ```
+///
+#[derive(Debug, Default)]
+pub struct SceneManager {
+    /// Scene that is currently shown.
+    ///
+    scene: Scene,
+    /// Transition that is in progress.
+    ///
+    transition: Option<ActiveTransition>,
+}
+impl SceneManager {
+    /// Initializes [`SceneManager`] with given scene.
+    ///
+    pub fn new(scene: Scene) -> SceneManager {
+        SceneManager {
+            scene,
+            transition: None,
+        }
+    }
+
+    /// Returns reference to the current scene.
+    ///
+    pub fn scene(&self) -> &Scene {
+        &self.scene
+    }
+    /// Returns mutable reference to the current scene.
+    ///
+    pub fn scene_mut(&mut self) -> &mut Scene {
+        &mut self.scene
+    }
 
-pub struct Scene { }
+    /// Immediately replaces current scene with given one and returns replaced scene.
+    ///
+    /// Transition that is in progress is cancelled.
+    ///
+    pub fn replace(&mut self, scene: Scene) -> Scene {
+        self.transition = None;
+        std::mem::replace(&mut self.scene, scene)
+    }
+    /// Starts transition that will replace current scene with given one.
+    ///
+    /// Transition that is in progress is cancelled (if its scenes were not swapped yet,
+    /// its scene is dropped).
+    ///
+    pub fn replace_with_transition(&mut self, scene: Scene, transition: Transition) {
+        self.transition = Some(ActiveTransition {
+            transition,
+            elapsed: 0.0,
+            next: Some(scene),
+        });
+    }
 
-pub struct SceneManager { }
+    /// Advances transition by given delta.
+    ///
+    /// Scenes are swapped when progress of transition exceeds its swap point
+    /// (swap is always made by the call after the one that reached it, so that frame
+    /// of old scene on that point can be drawn); in that case replaced scene is returned.
+    ///
+    pub fn update(&mut self, delta: Duration) -> Option<Scene> {
+        let active: &mut ActiveTransition = self.transition.as_mut()?;
+        let mut replaced: Option<Scene> = None;
+        if active.progress() > active.transition.swap_progress() {
+            if let Some(next) = active.next.take() {
+                replaced = Some(std::mem::replace(&mut self.scene, next));
+            }
+        }
+        active.elapsed += delta.as_secs_f32();
+        if active.next.is_none() && active.progress() >= 1.0 {
+            self.transition = None;
+        }
+        replaced
+    }
+    /// Returns state of transition that is in progress.
+    ///
+    pub fn transition(&self) -> Option<TransitionState> {
+        self.transition.as_ref().map(|active| TransitionState {
+            transition: active.transition,
+            progress: active.progress(),
+            swapped: active.next.is_none(),
+        })
+    }
+    /// Returns whether transition is in progress.
+    ///
+    pub fn is_transitioning(&self) -> bool {
+        self.transition.is_some()
+    }
+}
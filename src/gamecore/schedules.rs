@@ -0,0 +1,356 @@
+//! `gamecore::schedules` submodule implements [`Schedule`] - ordered collection of systems
+//! that operate on a [`Scene`], and [`ScheduleRunner`] - loop that drives [`Schedule`] with fixed timestep.
+//!
+//! Neither of those needs `sdl2` subsystems, so [`ScheduleRunner`] is suitable for
+//! headless execution: dedicated servers and CI tests can run game logic and physics
+//! without window, audio device or display.
+//!
+
+use crate::gamecore::{plugins::Plugin, scenes::Scene, time::Time, SystemStorage};
+use std::{
+    fmt,
+    thread::sleep,
+    time::{Duration, Instant},
+};
+
+/// Type alias for `Box<dyn FnMut(&mut Scene)>`.
+///
+/// Systems are closures that are run on a [`Scene`] once per frame (or once at startup).
+///
+pub type BoxedSystem = Box<dyn FnMut(&mut Scene)>;
+
+/// [`SystemNode`] enum lists kinds of nodes that [`SystemStorage`] holds.
+///
+pub enum SystemNode {
+    /// Single system.
+    ///
+    System(BoxedSystem),
+    /// Nested schedule, which is run as a single unit.
+    ///
+    Schedule(Schedule),
+}
+impl SystemNode {
+    /// Runs system or per-frame systems of nested schedule.
+    ///
+    pub fn run(&mut self, scene: &mut Scene) {
+        match self {
+            SystemNode::System(system) => system(scene),
+            SystemNode::Schedule(schedule) => schedule.run(scene),
+        }
+    }
+}
+impl fmt::Debug for SystemNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SystemNode::System(_) => f.write_str("System"),
+            SystemNode::Schedule(schedule) => f.debug_tuple("Schedule").field(schedule).finish(),
+        }
+    }
+}
+
+/// [`Schedule`] struct holds systems that are run once at startup and systems that are run every frame.
+///
+/// Schedules can be nested (see `add_schedule`): startup systems of nested schedule are run
+/// together with startup systems of the outer one, and its per-frame systems are run
+/// at its place among per-frame systems of the outer one.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::{components::Resource, scenes::Scene, schedules::Schedule};
+/// struct Countdown(u32);
+/// impl Resource for Countdown {}
+///
+/// let mut schedule: Schedule = Schedule::new();
+/// schedule.add_startup_system("setup", |scene: &mut Scene| {
+///     let _ = scene.resources_mut().insert_resource(Countdown(10));
+/// });
+/// schedule.add_system("countdown", |scene: &mut Scene| {
+///     scene.resources_mut().get_resource_mut::<Countdown>().expect("Resource was inserted").0 -= 1;
+/// });
+///
+/// let mut scene: Scene = Scene::new();
+/// schedule.run_startup(&mut scene);
+/// schedule.run(&mut scene);
+/// assert_eq!(scene.resources().get_resource::<Countdown>().map(|countdown| countdown.0), Some(9));
+/// ```
+///
+#[derive(Debug, Default)]
+pub struct Schedule {
+    /// Systems that are run once at startup.
+    ///
+    startup: SystemStorage,
+    /// Systems that are run every frame.
+    ///
+    update: SystemStorage,
+}
+impl Schedule {
+    /// Constructs empty schedule.
+    ///
+    pub fn new() -> Self {
+        Schedule {
+            startup: SystemStorage::new(),
+            update: SystemStorage::new(),
+        }
+    }
+
+    /// Adds system that is run once at startup, replacing startup system with the same label.
+    ///
+    pub fn add_startup_system(&mut self, label: &str, system: impl FnMut(&mut Scene) + 'static) {
+        let _ = self.startup.insert_system(label, system);
+    }
+    /// Adds system that is run every frame, replacing system with the same label.
+    ///
+    pub fn add_system(&mut self, label: &str, system: impl FnMut(&mut Scene) + 'static) {
+        let _ = self.update.insert_system(label, system);
+    }
+    /// Adds nested schedule that is run every frame, replacing system with the same label.
+    ///
+    /// This allows features to provide self-contained group of ordered systems,
+    /// which can later be removed as a single unit by `remove_system`.
+    ///
+    pub fn add_schedule(&mut self, label: &str, schedule: Schedule) {
+        let _ = self.update.insert_schedule(label, schedule);
+    }
+    /// Adds systems of the plugin as nested schedule that is labelled by the name of the plugin,
+    /// replacing systems of the plugin with the same name.
+    ///
+    /// Resources of the plugin are registered separately by `Scene::add_plugin`.
+    ///
+    pub fn add_plugin(&mut self, plugin: &impl Plugin) {
+        let mut schedule: Schedule = Schedule::new();
+        plugin.systems(&mut schedule);
+        self.add_schedule(plugin.name(), schedule);
+    }
+    /// Removes startup and per-frame systems (or nested schedules) with given label
+    /// and returns whether any of them existed.
+    ///
+    pub fn remove_system(&mut self, label: &str) -> bool {
+        let startup: bool = self.startup.remove_system(label).is_some();
+        let update: bool = self.update.remove_system(label).is_some();
+        startup || update
+    }
+
+    /// Returns reference to the storage of startup systems.
+    ///
+    pub fn startup_systems(&self) -> &SystemStorage {
+        &self.startup
+    }
+    /// Returns mutable reference to the storage of startup systems.
+    ///
+    pub fn startup_systems_mut(&mut self) -> &mut SystemStorage {
+        &mut self.startup
+    }
+    /// Returns reference to the storage of per-frame systems.
+    ///
+    pub fn systems(&self) -> &SystemStorage {
+        &self.update
+    }
+    /// Returns mutable reference to the storage of per-frame systems.
+    ///
+    pub fn systems_mut(&mut self) -> &mut SystemStorage {
+        &mut self.update
+    }
+
+    /// Runs startup systems on the scene, including startup systems of nested schedules.
+    ///
+    pub fn run_startup(&mut self, scene: &mut Scene) {
+        self.startup.run_systems(scene);
+        self.update.run_nested_startup(scene);
+    }
+    /// Runs per-frame systems on the scene.
+    ///
+    pub fn run(&mut self, scene: &mut Scene) {
+        self.update.run_systems(scene);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Schedule, SystemNode};
+    use crate::gamecore::{components::Resource, scenes::Scene};
+
+    struct Log(Vec<&'static str>);
+    impl Resource for Log {}
+
+    fn log(scene: &mut Scene, entry: &'static str) {
+        scene
+            .resources_mut()
+            .get_resource_or_insert_with(|| Log(Vec::new()))
+            .0
+            .push(entry);
+    }
+
+    #[test]
+    fn nested_schedules() {
+        let mut feature: Schedule = Schedule::new();
+        feature.add_startup_system("setup", |scene: &mut Scene| log(scene, "feature setup"));
+        feature.add_system("first", |scene: &mut Scene| log(scene, "feature first"));
+        feature.add_system("second", |scene: &mut Scene| log(scene, "feature second"));
+
+        let mut schedule: Schedule = Schedule::new();
+        schedule.add_startup_system("setup", |scene: &mut Scene| log(scene, "setup"));
+        schedule.add_system("before", |scene: &mut Scene| log(scene, "before"));
+        schedule.add_schedule("feature", feature);
+        schedule.add_system("after", |scene: &mut Scene| log(scene, "after"));
+
+        let mut scene: Scene = Scene::new();
+        schedule.run_startup(&mut scene);
+        schedule.run(&mut scene);
+        assert!(schedule.remove_system("feature"));
+        schedule.run(&mut scene);
+
+        assert_eq!(
+            scene
+                .resources()
+                .get_resource::<Log>()
+                .map(|log| log.0.clone()),
+            Some(vec![
+                "setup",
+                "feature setup",
+                "before",
+                "feature first",
+                "feature second",
+                "after",
+                "before",
+                "after",
+            ])
+        );
+        assert!(matches!(
+            schedule.systems_mut().remove_system("before"),
+            Some(SystemNode::System(_))
+        ));
+        assert_eq!(
+            schedule.systems().labels().collect::<Vec<&str>>(),
+            ["after"]
+        );
+    }
+}
+
+/// [`ScheduleRunner`] struct owns [`Scene`] and [`Schedule`] and runs frames with fixed timestep.
+///
+/// Before every frame, [`Time`] resource of the scene is updated by the timestep
+/// (it is inserted if scene does not have it); startup systems are run before the first frame.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::{
+/// #     components::Resource, scenes::Scene, schedules::{Schedule, ScheduleRunner}, time::Time,
+/// # };
+/// # use std::time::Duration;
+/// struct Distance(f32);
+/// impl Resource for Distance {}
+///
+/// let mut schedule: Schedule = Schedule::new();
+/// schedule.add_system("movement", |scene: &mut Scene| {
+///     let delta: f32 = scene.resources().get_resource::<Time>().expect("Time is inserted").delta_seconds();
+///     scene.resources_mut().get_resource_or_insert_with(|| Distance(0.0)).0 += 2.0 * delta;
+/// });
+///
+/// // no `GGEngine` is needed
+/// let mut runner: ScheduleRunner =
+///     ScheduleRunner::new(Scene::new(), schedule, Duration::from_millis(250));
+/// runner.run_frames(4);
+/// assert_eq!(runner.scene.resources().get_resource::<Distance>().map(|distance| distance.0), Some(2.0));
+/// ```
+///
+#[derive(Debug)]
+pub struct ScheduleRunner {
+    /// Scene that is updated.
+    ///
+    pub scene: Scene,
+    /// Schedule that is run on the scene.
+    ///
+    pub schedule: Schedule,
+
+    /// Real time between frames.
+    ///
+    timestep: Duration,
+    /// Whether startup systems were already run.
+    ///
+    is_started: bool,
+}
+impl ScheduleRunner {
+    /// Constructs runner with given timestep.
+    ///
+    pub fn new(scene: Scene, schedule: Schedule, timestep: Duration) -> Self {
+        ScheduleRunner {
+            scene,
+            schedule,
+
+            timestep,
+            is_started: false,
+        }
+    }
+
+    /// Returns real time between frames.
+    ///
+    pub fn timestep(&self) -> Duration {
+        self.timestep
+    }
+    /// Sets real time between frames.
+    ///
+    pub fn set_timestep(&mut self, timestep: Duration) {
+        self.timestep = timestep;
+    }
+
+    /// Adds plugin to the scene and to the schedule, replacing plugin with the same name.
+    ///
+    /// If frames were already run, startup systems of the plugin are run immediately,
+    /// so plugins can be added (or swapped) while game is running.
+    ///
+    pub fn add_plugin(&mut self, plugin: &impl Plugin) {
+        self.scene.add_plugin(plugin);
+        let mut schedule: Schedule = Schedule::new();
+        plugin.systems(&mut schedule);
+        if self.is_started {
+            schedule.run_startup(&mut self.scene);
+        }
+        self.schedule.add_schedule(plugin.name(), schedule);
+    }
+    /// Removes systems of the plugin from the schedule and cleans plugin up.
+    ///
+    /// Plugin is cleaned up only if schedule had its systems, so removing plugin that was never added
+    /// does not touch the scene. Returns whether schedule had systems of the plugin.
+    ///
+    pub fn remove_plugin(&mut self, plugin: &impl Plugin) -> bool {
+        if !self.schedule.remove_system(plugin.name()) {
+            return false;
+        }
+        plugin.cleanup(&mut self.scene);
+        true
+    }
+
+    /// Runs one frame immediately.
+    ///
+    pub fn step(&mut self) {
+        if !self.is_started {
+            self.schedule.run_startup(&mut self.scene);
+            self.is_started = true;
+        }
+        self.scene
+            .resources_mut()
+            .get_resource_or_insert_with(Time::new)
+            .update_with(self.timestep);
+        self.schedule.run(&mut self.scene);
+    }
+    /// Runs given number of frames immediately, without waiting for real time to pass.
+    ///
+    /// This is useful for tests and simulations that should run as fast as possible.
+    ///
+    pub fn run_frames(&mut self, frames: u64) {
+        for _ in 0..frames {
+            self.step();
+        }
+    }
+    /// Runs frames in real time (sleeping between them) until given predicate returns `true`.
+    ///
+    /// Predicate is checked before every frame.
+    ///
+    pub fn run_until(&mut self, mut should_stop: impl FnMut(&Scene) -> bool) {
+        while !should_stop(&self.scene) {
+            let frame_start: Instant = Instant::now();
+            self.step();
+            sleep(self.timestep.saturating_sub(frame_start.elapsed()));
+        }
+    }
+}
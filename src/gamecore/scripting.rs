@@ -0,0 +1,572 @@
+//! `gamecore::scripting` submodule implements [`ScriptBridge`] - reflection-based API
+//! that exposes operations on game world to embedded scripting languages (e.g. Lua or Rhai).
+//!
+//! Scripts do not know Rust types, so everything is addressed by names: components, events and systems
+//! are registered in the bridge under stable names, entities are represented by plain integers,
+//! and values are passed as [`ScriptValue`]s, which map naturally onto dynamic values of scripting languages.
+//! Binding of a concrete language only needs to wrap methods of [`ScriptBridge`].
+//!
+//! [`Scene`] is a [`ScriptHost`] itself - handles of its entities are values of [`GameObjectId`]s,
+//! and its components can be registered by [`ScriptBridge::register_scene_component`].
+//!
+
+use crate::{
+    gamecore::{components::Component, identifiers::GameObjectId, scenes::Scene},
+    GGError,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{collections::BTreeMap, fmt};
+
+/// Dynamic value that is passed between scripts and the engine.
+///
+/// Any type that implements `serde::Serialize` and `serde::Deserialize` can be converted to and from
+/// [`ScriptValue`] (see [`to_script_value`] and [`from_script_value`]).
+///
+pub type ScriptValue = serde_cbor::Value;
+
+/// Converts value to [`ScriptValue`].
+///
+pub fn to_script_value<T: Serialize>(value: &T) -> Result<ScriptValue, GGError> {
+    serde_cbor::value::to_value(value)
+        .map_err(|error| GGError::InvalidArgument(format!("Value is not serializable: {error}")))
+}
+/// Converts [`ScriptValue`] to value of given type.
+///
+pub fn from_script_value<T: DeserializeOwned>(value: ScriptValue) -> Result<T, GGError> {
+    serde_cbor::value::from_value(value)
+        .map_err(|error| GGError::Decode(format!("Script value has wrong layout: {error}")))
+}
+
+/// [`ScriptHost`] trait is implemented by game world that is exposed to scripts through [`ScriptBridge`].
+///
+/// Entities are identified by `u64` handles, since scripts cannot hold Rust types.
+///
+pub trait ScriptHost: 'static {
+    /// Spawns entity with no components and returns its handle.
+    ///
+    fn spawn(&mut self) -> u64;
+    /// Despawns entity and returns whether it existed.
+    ///
+    fn despawn(&mut self, entity: u64) -> bool;
+    /// Returns handles of all entities.
+    ///
+    fn entities(&self) -> Vec<u64>;
+}
+impl ScriptHost for Scene {
+    fn spawn(&mut self) -> u64 {
+        self.components_mut().insert_gameobject().value()
+    }
+    fn despawn(&mut self, entity: u64) -> bool {
+        Scene::despawn(self, GameObjectId::new(entity))
+    }
+    fn entities(&self) -> Vec<u64> {
+        self.components()
+            .gameobjects()
+            .map(|gameobject_id| gameobject_id.value())
+            .collect()
+    }
+}
+
+/// Function that reads component of an entity.
+///
+type ComponentGetter<W> = Box<dyn Fn(&W, u64) -> Option<Result<ScriptValue, GGError>>>;
+/// Function that writes component of an entity.
+///
+type ComponentSetter<W> = Box<dyn Fn(&mut W, u64, ScriptValue) -> Result<(), GGError>>;
+/// Function that removes component from an entity.
+///
+type ComponentRemover<W> = Box<dyn Fn(&mut W, u64) -> bool>;
+/// Function that handles event.
+///
+type EventHandler<W> = Box<dyn FnMut(&mut W, ScriptValue) -> Result<(), GGError>>;
+/// Labeled system.
+///
+type System<W> = Box<dyn FnMut(&mut W)>;
+
+/// [`ComponentReflection`] struct holds functions that access one registered component.
+///
+struct ComponentReflection<W> {
+    /// Reads component.
+    ///
+    get: ComponentGetter<W>,
+    /// Writes component.
+    ///
+    set: ComponentSetter<W>,
+    /// Removes component.
+    ///
+    remove: ComponentRemover<W>,
+}
+
+/// [`ScriptBridge`] struct is a registry of components, events and systems that are exposed to scripts.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::scripting::{from_script_value, to_script_value, ScriptBridge, ScriptHost, ScriptValue};
+/// # use serde::{Deserialize, Serialize};
+/// # use std::collections::BTreeMap;
+/// #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+/// struct Health(u32);
+///
+/// #[derive(Default)]
+/// struct World {
+///     next_entity: u64,
+///     health: BTreeMap<u64, Health>,
+///     log: Vec<String>,
+/// }
+/// impl ScriptHost for World {
+///     fn spawn(&mut self) -> u64 {
+///         self.next_entity += 1;
+///         self.next_entity
+///     }
+///     fn despawn(&mut self, entity: u64) -> bool {
+///         self.health.remove(&entity).is_some()
+///     }
+///     fn entities(&self) -> Vec<u64> {
+///         (1..=self.next_entity).collect()
+///     }
+/// }
+///
+/// let mut bridge: ScriptBridge<World> = ScriptBridge::new();
+/// bridge.register_component::<Health>(
+///     "Health",
+///     |world, entity| world.health.get(&entity),
+///     |world, entity, health| {
+///         let _ = world.health.insert(entity, health);
+///     },
+///     |world, entity| world.health.remove(&entity).is_some(),
+/// );
+/// bridge.register_event::<String>("say", |world, text| world.log.push(text));
+/// bridge.register_system("regenerate", |world| {
+///     world.health.values_mut().for_each(|health| health.0 += 1);
+/// });
+///
+/// // calls that would be made by a script
+/// let mut world: World = World::default();
+/// let entity: u64 = bridge.spawn(&mut world);
+/// let _ = bridge.spawn(&mut world);
+/// bridge
+///     .set_component(&mut world, entity, "Health", to_script_value(&Health(10)).expect("Health is serializable"))
+///     .expect("Health is registered");
+/// bridge.call_system(&mut world, "regenerate").expect("System is registered");
+/// bridge
+///     .send_event(&mut world, "say", ScriptValue::Text(String::from("Healed!")))
+///     .expect("Event is registered");
+///
+/// assert_eq!(bridge.query(&world, &["Health"]).expect("Health is registered"), [entity]);
+/// let health: ScriptValue = bridge
+///     .get_component(&world, entity, "Health")
+///     .expect("Health is registered")
+///     .expect("Entity has health");
+/// assert_eq!(from_script_value::<Health>(health).expect("Value is health"), Health(11));
+/// assert_eq!(world.log, ["Healed!"]);
+/// ```
+///
+pub struct ScriptBridge<W> {
+    /// Registered components by their names.
+    ///
+    components: BTreeMap<String, ComponentReflection<W>>,
+    /// Registered event handlers by names of events.
+    ///
+    events: BTreeMap<String, EventHandler<W>>,
+    /// Registered systems by their labels.
+    ///
+    systems: BTreeMap<String, System<W>>,
+}
+impl<W: ScriptHost> ScriptBridge<W> {
+    /// Constructs bridge with nothing registered.
+    ///
+    pub fn new() -> Self {
+        ScriptBridge {
+            components: BTreeMap::new(),
+            events: BTreeMap::new(),
+            systems: BTreeMap::new(),
+        }
+    }
+
+    /// Registers component under given name by functions that get, set and remove it.
+    ///
+    /// Registering another component under the same name replaces previous one.
+    ///
+    pub fn register_component<C: Serialize + DeserializeOwned + 'static>(
+        &mut self,
+        name: &str,
+        get: impl Fn(&W, u64) -> Option<&C> + 'static,
+        set: impl Fn(&mut W, u64, C) + 'static,
+        remove: impl Fn(&mut W, u64) -> bool + 'static,
+    ) {
+        let _ = self.components.insert(
+            String::from(name),
+            ComponentReflection {
+                get: Box::new(move |world, entity| get(world, entity).map(to_script_value)),
+                set: Box::new(move |world, entity, value| {
+                    set(world, entity, from_script_value(value)?);
+                    Ok(())
+                }),
+                remove: Box::new(remove),
+            },
+        );
+    }
+    /// Registers handler of an event with given name.
+    ///
+    /// Registering another handler under the same name replaces previous one.
+    ///
+    pub fn register_event<E: DeserializeOwned + 'static>(
+        &mut self,
+        name: &str,
+        mut handler: impl FnMut(&mut W, E) + 'static,
+    ) {
+        let _ = self.events.insert(
+            String::from(name),
+            Box::new(move |world, value| {
+                handler(world, from_script_value(value)?);
+                Ok(())
+            }),
+        );
+    }
+    /// Registers system under given label.
+    ///
+    /// Registering another system under the same label replaces previous one.
+    ///
+    pub fn register_system(&mut self, label: &str, system: impl FnMut(&mut W) + 'static) {
+        let _ = self.systems.insert(String::from(label), Box::new(system));
+    }
+
+    /// Returns names of registered components.
+    ///
+    pub fn component_names(&self) -> impl Iterator<Item = &str> {
+        self.components.keys().map(String::as_str)
+    }
+    /// Returns names of registered events.
+    ///
+    pub fn event_names(&self) -> impl Iterator<Item = &str> {
+        self.events.keys().map(String::as_str)
+    }
+    /// Returns labels of registered systems.
+    ///
+    pub fn system_labels(&self) -> impl Iterator<Item = &str> {
+        self.systems.keys().map(String::as_str)
+    }
+
+    /// Returns reflection of a component with given name.
+    ///
+    fn component(&self, name: &str) -> Result<&ComponentReflection<W>, GGError> {
+        self.components
+            .get(name)
+            .ok_or_else(|| GGError::InvalidArgument(format!("Component {name} is not registered")))
+    }
+
+    /// Spawns entity and returns its handle.
+    ///
+    pub fn spawn(&self, world: &mut W) -> u64 {
+        world.spawn()
+    }
+    /// Despawns entity and returns whether it existed.
+    ///
+    pub fn despawn(&self, world: &mut W, entity: u64) -> bool {
+        world.despawn(entity)
+    }
+
+    /// Returns component of an entity with given name, or `None` if entity does not have it.
+    ///
+    /// # Errors
+    /// Error is returned if component is not registered or cannot be converted to [`ScriptValue`].
+    ///
+    pub fn get_component(
+        &self,
+        world: &W,
+        entity: u64,
+        name: &str,
+    ) -> Result<Option<ScriptValue>, GGError> {
+        (self.component(name)?.get)(world, entity).transpose()
+    }
+    /// Sets component of an entity with given name.
+    ///
+    /// # Errors
+    /// Error is returned if component is not registered or if value has wrong layout.
+    ///
+    pub fn set_component(
+        &self,
+        world: &mut W,
+        entity: u64,
+        name: &str,
+        value: ScriptValue,
+    ) -> Result<(), GGError> {
+        (self.component(name)?.set)(world, entity, value)
+    }
+    /// Removes component of an entity with given name and returns whether entity had it.
+    ///
+    /// # Errors
+    /// Error is returned if component is not registered.
+    ///
+    pub fn remove_component(
+        &self,
+        world: &mut W,
+        entity: u64,
+        name: &str,
+    ) -> Result<bool, GGError> {
+        Ok((self.component(name)?.remove)(world, entity))
+    }
+    /// Returns handles of entities that have all components with given names.
+    ///
+    /// # Errors
+    /// Error is returned if any of components is not registered.
+    ///
+    pub fn query(&self, world: &W, names: &[&str]) -> Result<Vec<u64>, GGError> {
+        let components: Vec<&ComponentReflection<W>> = names
+            .iter()
+            .map(|name| self.component(name))
+            .collect::<Result<_, _>>()?;
+        Ok(world
+            .entities()
+            .into_iter()
+            .filter(|entity| {
+                components
+                    .iter()
+                    .all(|component| (component.get)(world, *entity).is_some())
+            })
+            .collect())
+    }
+
+    /// Sends event with given name, immediately calling its handler.
+    ///
+    /// # Errors
+    /// Error is returned if event is not registered or if value has wrong layout.
+    ///
+    pub fn send_event(
+        &mut self,
+        world: &mut W,
+        name: &str,
+        value: ScriptValue,
+    ) -> Result<(), GGError> {
+        let handler: &mut EventHandler<W> = self
+            .events
+            .get_mut(name)
+            .ok_or_else(|| GGError::InvalidArgument(format!("Event {name} is not registered")))?;
+        handler(world, value)
+    }
+    /// Calls system with given label.
+    ///
+    /// # Errors
+    /// Error is returned if system is not registered.
+    ///
+    pub fn call_system(&mut self, world: &mut W, label: &str) -> Result<(), GGError> {
+        let system: &mut System<W> = self
+            .systems
+            .get_mut(label)
+            .ok_or_else(|| GGError::InvalidArgument(format!("System {label} is not registered")))?;
+        system(world);
+        Ok(())
+    }
+}
+impl ScriptBridge<Scene> {
+    /// Registers component of game objects of a [`Scene`] under given name.
+    ///
+    /// Registering another component under the same name replaces previous one.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::gamecore::{components::Component, scenes::Scene};
+    /// # use ggengine::gamecore::scripting::{ScriptBridge, ScriptValue};
+    /// # use serde::{Deserialize, Serialize};
+    /// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    /// struct Health(u32);
+    /// impl Component for Health {}
+    ///
+    /// let mut bridge: ScriptBridge<Scene> = ScriptBridge::new();
+    /// bridge.register_scene_component::<Health>("Health");
+    ///
+    /// let mut scene: Scene = Scene::new();
+    /// let entity: u64 = bridge.spawn(&mut scene);
+    /// bridge
+    ///     .set_component(&mut scene, entity, "Health", ScriptValue::Integer(10))
+    ///     .expect("Health is registered");
+    /// assert_eq!(scene.components().components::<Health>().next().map(|(_, health)| health), Some(&Health(10)));
+    /// ```
+    ///
+    pub fn register_scene_component<C: Component + Serialize + DeserializeOwned>(
+        &mut self,
+        name: &str,
+    ) {
+        self.register_component::<C>(
+            name,
+            |scene, entity| {
+                scene
+                    .components()
+                    .get_component::<C>(GameObjectId::new(entity))
+            },
+            |scene, entity, component| {
+                let _ = scene
+                    .components_mut()
+                    .insert_component(GameObjectId::new(entity), component);
+            },
+            |scene, entity| {
+                scene
+                    .components_mut()
+                    .remove_component::<C>(GameObjectId::new(entity))
+                    .is_some()
+            },
+        );
+    }
+}
+impl<W: ScriptHost> Default for ScriptBridge<W> {
+    fn default() -> Self {
+        ScriptBridge::new()
+    }
+}
+impl<W> fmt::Debug for ScriptBridge<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScriptBridge")
+            .field("components", &self.components.keys())
+            .field("events", &self.events.keys())
+            .field("systems", &self.systems.keys())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_script_value, to_script_value, ScriptBridge, ScriptHost, ScriptValue};
+    use crate::{
+        gamecore::{components::Component, identifiers::GameObjectId, scenes::Scene},
+        GGError,
+    };
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    #[derive(Default)]
+    struct World {
+        next_entity: u64,
+        names: BTreeMap<u64, String>,
+        scores: BTreeMap<u64, i32>,
+    }
+    impl ScriptHost for World {
+        fn spawn(&mut self) -> u64 {
+            self.next_entity += 1;
+            self.next_entity
+        }
+        fn despawn(&mut self, entity: u64) -> bool {
+            let had_name: bool = self.names.remove(&entity).is_some();
+            let had_score: bool = self.scores.remove(&entity).is_some();
+            had_name || had_score
+        }
+        fn entities(&self) -> Vec<u64> {
+            (1..=self.next_entity).collect()
+        }
+    }
+
+    #[test]
+    fn queries_and_errors() {
+        let mut bridge: ScriptBridge<World> = ScriptBridge::new();
+        bridge.register_component::<String>(
+            "Name",
+            |world, entity| world.names.get(&entity),
+            |world, entity, name| {
+                let _ = world.names.insert(entity, name);
+            },
+            |world, entity| world.names.remove(&entity).is_some(),
+        );
+        bridge.register_component::<i32>(
+            "Score",
+            |world, entity| world.scores.get(&entity),
+            |world, entity, score| {
+                let _ = world.scores.insert(entity, score);
+            },
+            |world, entity| world.scores.remove(&entity).is_some(),
+        );
+        assert_eq!(
+            bridge.component_names().collect::<Vec<&str>>(),
+            ["Name", "Score"]
+        );
+
+        let mut world: World = World::default();
+        let (a, b): (u64, u64) = (bridge.spawn(&mut world), bridge.spawn(&mut world));
+        for entity in [a, b] {
+            bridge
+                .set_component(
+                    &mut world,
+                    entity,
+                    "Name",
+                    to_script_value(&"player").expect("String is serializable"),
+                )
+                .expect("Name is registered");
+        }
+        bridge
+            .set_component(&mut world, b, "Score", ScriptValue::Integer(3))
+            .expect("Score is registered");
+        assert_eq!(
+            bridge
+                .query(&world, &["Name", "Score"])
+                .expect("Components are registered"),
+            [b]
+        );
+
+        assert!(matches!(
+            bridge.set_component(&mut world, a, "Score", ScriptValue::Text(String::from("3"))),
+            Err(GGError::Decode(_))
+        ));
+        assert!(matches!(
+            bridge.query(&world, &["Velocity"]),
+            Err(GGError::InvalidArgument(_))
+        ));
+        assert!(bridge.call_system(&mut world, "missing").is_err());
+        assert_eq!(
+            bridge.remove_component(&mut world, b, "Score").ok(),
+            Some(true)
+        );
+        assert!(bridge.despawn(&mut world, b));
+        assert_eq!(bridge.get_component(&world, b, "Name").ok(), Some(None));
+    }
+
+    #[test]
+    fn scene_host() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Score(i32);
+        impl Component for Score {}
+
+        let mut bridge: ScriptBridge<Scene> = ScriptBridge::new();
+        bridge.register_scene_component::<Score>("Score");
+        bridge.register_system("bonus", |scene| {
+            scene
+                .components_mut()
+                .components_mut::<Score>()
+                .for_each(|(_, score)| score.0 += 1);
+        });
+
+        let mut scene: Scene = Scene::new();
+        let native: GameObjectId = scene.spawn(Score(10));
+        // calls that would be made by a script
+        let (a, b): (u64, u64) = (bridge.spawn(&mut scene), bridge.spawn(&mut scene));
+        assert_eq!(scene.entities(), [native.value(), a, b]);
+        bridge
+            .set_component(&mut scene, a, "Score", ScriptValue::Integer(1))
+            .expect("Score is registered");
+        bridge
+            .call_system(&mut scene, "bonus")
+            .expect("System is registered");
+        assert_eq!(
+            bridge
+                .query(&scene, &["Score"])
+                .expect("Score is registered"),
+            [native.value(), a]
+        );
+        let score: ScriptValue = bridge
+            .get_component(&scene, native.value(), "Score")
+            .expect("Score is registered")
+            .expect("Game object has score");
+        assert_eq!(
+            from_script_value::<Score>(score).expect("Value is score"),
+            Score(11)
+        );
+
+        assert!(bridge.despawn(&mut scene, a));
+        assert!(!bridge.despawn(&mut scene, a));
+        assert_eq!(
+            bridge
+                .remove_component(&mut scene, native.value(), "Score")
+                .ok(),
+            Some(true)
+        );
+        assert!(scene.components().components::<Score>().next().is_none());
+        assert_eq!(scene.components().gameobject_count(), 2);
+    }
+}
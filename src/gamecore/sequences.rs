@@ -0,0 +1,366 @@
+//! `gamecore::sequences` submodule implements [`Sequence`] - coroutine-like chain of steps,
+//! which allows writing cutscenes and scripted encounters linearly.
+//!
+//! Every step operates on a context - any type that the game chooses
+//! (it could be state of a level, a scene or a single object).
+//! Sequence is advanced by [`Time`] and stops on steps that are not finished yet,
+//! resuming from them on the next frame.
+//! Sequences can be sped up or slowed down and can use unscaled time (see [`Clock`]),
+//! so tweens of UI keep playing while the game is paused.
+//!
+
+use crate::gamecore::{
+    components::Component,
+    time::{Clock, Time},
+};
+use std::{collections::VecDeque, fmt, time::Duration};
+
+/// Closure that is called by tweening step with progress in [0; 1] range.
+///
+type TweenFn<C> = Box<dyn FnMut(&mut C, f32)>;
+
+/// [`Step`] enum lists steps from which [`Sequence`] is built.
+///
+/// Usually, steps are added by builder methods of [`Sequence`].
+///
+pub enum Step<C> {
+    /// Waits for given time.
+    ///
+    Wait(Duration),
+    /// Waits until condition is met (e.g. until an event happens).
+    ///
+    /// Condition is checked once per update.
+    ///
+    WaitUntil(Box<dyn FnMut(&mut C) -> bool>),
+    /// Runs closure once.
+    ///
+    Run(Box<dyn FnOnce(&mut C)>),
+    /// Calls closure every update for given time, passing progress in [0; 1] range.
+    ///
+    /// Closure is guaranteed to be called with progress of 1.0 when tween ends.
+    ///
+    Tween(Duration, TweenFn<C>),
+}
+impl<C> fmt::Debug for Step<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Wait(duration) => f.debug_tuple("Wait").field(duration).finish(),
+            Step::WaitUntil(_) => f.debug_tuple("WaitUntil").finish_non_exhaustive(),
+            Step::Run(_) => f.debug_tuple("Run").finish_non_exhaustive(),
+            Step::Tween(duration, _) => f
+                .debug_tuple("Tween")
+                .field(duration)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+/// [`Sequence`] struct is a component that executes chain of steps one after another.
+///
+/// Time that is left after finishing timed step is passed to the next step,
+/// so timings of long sequences do not drift, and instant steps are executed in the same update
+/// as the step before them.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::{sequences::Sequence, time::Time};
+/// # use std::time::Duration;
+/// struct Cutscene {
+///     door_opened: bool,
+///     camera_x: f32,
+///     subtitles: Vec<&'static str>,
+/// }
+///
+/// let mut sequence: Sequence<Cutscene> = Sequence::<Cutscene>::new()
+///     .run(|cutscene| cutscene.subtitles.push("Who is there?"))
+///     .wait(Duration::from_secs(1))
+///     .wait_until(|cutscene| cutscene.door_opened)
+///     .tween(Duration::from_secs(2), |cutscene, t| cutscene.camera_x = 100.0 * t)
+///     .run(|cutscene| cutscene.subtitles.push("Oh, it's you."));
+///
+/// let mut cutscene: Cutscene = Cutscene { door_opened: false, camera_x: 0.0, subtitles: Vec::new() };
+/// let mut time: Time = Time::new();
+/// time.update_with(Duration::from_millis(1500));
+/// assert!(!sequence.update(&mut cutscene, &time));
+/// assert_eq!(cutscene.subtitles, ["Who is there?"]);
+///
+/// cutscene.door_opened = true;
+/// assert!(!sequence.update(&mut cutscene, &time));
+/// assert_eq!(cutscene.camera_x, 75.0);
+///
+/// assert!(sequence.update(&mut cutscene, &time));
+/// assert_eq!(cutscene.camera_x, 100.0);
+/// assert_eq!(cutscene.subtitles, ["Who is there?", "Oh, it's you."]);
+/// ```
+///
+pub struct Sequence<C> {
+    /// Steps that are not finished yet.
+    ///
+    steps: VecDeque<Step<C>>,
+    /// Time that current step has been running for.
+    ///
+    step_elapsed: Duration,
+    /// Whether sequence is paused.
+    ///
+    is_paused: bool,
+    /// Multiplier of time by which sequence is advanced.
+    ///
+    speed: f32,
+    /// Clock by which sequence is advanced.
+    ///
+    clock: Clock,
+}
+impl<C> Sequence<C> {
+    /// Constructs empty sequence.
+    ///
+    pub fn new() -> Self {
+        Sequence {
+            steps: VecDeque::new(),
+            step_elapsed: Duration::ZERO,
+            is_paused: false,
+            speed: 1.0,
+            clock: Clock::Scaled,
+        }
+    }
+
+    /// Adds step to the end of sequence.
+    ///
+    pub fn then(mut self, step: Step<C>) -> Self {
+        self.steps.push_back(step);
+        self
+    }
+    /// Adds step that waits for given time.
+    ///
+    pub fn wait(self, duration: Duration) -> Self {
+        self.then(Step::Wait(duration))
+    }
+    /// Adds step that waits for given number of seconds.
+    ///
+    pub fn wait_seconds(self, seconds: f32) -> Self {
+        self.wait(Duration::from_secs_f32(seconds.max(0.0)))
+    }
+    /// Adds step that waits until condition is met.
+    ///
+    pub fn wait_until(self, condition: impl FnMut(&mut C) -> bool + 'static) -> Self {
+        self.then(Step::WaitUntil(Box::new(condition)))
+    }
+    /// Adds step that runs closure once.
+    ///
+    pub fn run(self, f: impl FnOnce(&mut C) + 'static) -> Self {
+        self.then(Step::Run(Box::new(f)))
+    }
+    /// Adds step that calls closure with progress in [0; 1] range for given time.
+    ///
+    pub fn tween(self, duration: Duration, f: impl FnMut(&mut C, f32) + 'static) -> Self {
+        self.then(Step::Tween(duration, Box::new(f)))
+    }
+
+    /// Advances sequence by delta of [`Time`] (measured by clock of the sequence and scaled by its speed)
+    /// and returns whether sequence is finished.
+    ///
+    pub fn update(&mut self, context: &mut C, time: &Time) -> bool {
+        self.advance(
+            context,
+            time.delta_by(self.clock).mul_f32(self.speed.max(0.0)),
+        )
+    }
+    /// Advances sequence by given delta and returns whether sequence is finished.
+    ///
+    pub fn advance(&mut self, context: &mut C, delta: Duration) -> bool {
+        if self.is_paused {
+            return self.is_finished();
+        }
+
+        let mut remaining: Duration = delta;
+        while let Some(step) = self.steps.front_mut() {
+            match step {
+                Step::Wait(duration) => {
+                    let left: Duration = duration.saturating_sub(self.step_elapsed);
+                    if remaining < left {
+                        self.step_elapsed += remaining;
+                        return false;
+                    }
+                    remaining -= left;
+                }
+                Step::WaitUntil(condition) => {
+                    if !condition(context) {
+                        return false;
+                    }
+                }
+                Step::Run(_) => {
+                    if let Some(Step::Run(f)) = self.steps.pop_front() {
+                        f(context);
+                    }
+                    self.step_elapsed = Duration::ZERO;
+                    continue;
+                }
+                Step::Tween(duration, f) => {
+                    let left: Duration = duration.saturating_sub(self.step_elapsed);
+                    if remaining < left {
+                        self.step_elapsed += remaining;
+                        f(
+                            context,
+                            self.step_elapsed.as_secs_f32() / duration.as_secs_f32(),
+                        );
+                        return false;
+                    }
+                    remaining -= left;
+                    f(context, 1.0);
+                }
+            }
+            let _ = self.steps.pop_front();
+            self.step_elapsed = Duration::ZERO;
+        }
+        true
+    }
+
+    /// Returns whether all steps are finished.
+    ///
+    pub fn is_finished(&self) -> bool {
+        self.steps.is_empty()
+    }
+    /// Returns number of steps that are not finished yet.
+    ///
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+    /// Returns whether sequence has no steps.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+    /// Removes all steps.
+    ///
+    pub fn clear(&mut self) {
+        self.steps.clear();
+        self.step_elapsed = Duration::ZERO;
+    }
+
+    /// Pauses sequence.
+    ///
+    pub fn pause(&mut self) {
+        self.is_paused = true;
+    }
+    /// Unpauses sequence.
+    ///
+    pub fn unpause(&mut self) {
+        self.is_paused = false;
+    }
+    /// Returns whether sequence is paused.
+    ///
+    pub fn is_paused(&self) -> bool {
+        self.is_paused
+    }
+
+    /// Returns multiplier of time by which sequence is advanced by `Sequence::update`.
+    ///
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+    /// Sets multiplier of time by which sequence is advanced by `Sequence::update`.
+    ///
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed;
+    }
+    /// Returns clock by which sequence is advanced by `Sequence::update`.
+    ///
+    pub fn clock(&self) -> Clock {
+        self.clock
+    }
+    /// Sets clock by which sequence is advanced by `Sequence::update`.
+    ///
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.clock = clock;
+    }
+}
+impl<C> Default for Sequence<C> {
+    fn default() -> Self {
+        Sequence::new()
+    }
+}
+impl<C> fmt::Debug for Sequence<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sequence")
+            .field("steps", &self.steps)
+            .field("step_elapsed", &self.step_elapsed)
+            .field("is_paused", &self.is_paused)
+            .field("speed", &self.speed)
+            .field("clock", &self.clock)
+            .finish()
+    }
+}
+impl<C: 'static> Component for Sequence<C> {}
+
+/// Advances all given sequences by delta of [`Time`] and removes finished ones.
+///
+/// This system should be run once per frame, after [`Time`] is updated.
+///
+pub fn run_sequences<C>(time: &Time, context: &mut C, sequences: &mut Vec<Sequence<C>>) {
+    sequences.retain_mut(|sequence| !sequence.update(context, time));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run_sequences, Sequence};
+    use crate::gamecore::time::{Clock, Time};
+    use std::time::Duration;
+
+    #[test]
+    fn leftover_time() {
+        let mut log: Vec<u32> = Vec::new();
+        let mut sequences: Vec<Sequence<Vec<u32>>> = vec![
+            Sequence::<Vec<u32>>::new()
+                .wait(Duration::from_millis(300))
+                .run(|log| log.push(1))
+                .wait(Duration::from_millis(300))
+                .run(|log| log.push(2)),
+            Sequence::<Vec<u32>>::new().run(|log| log.push(0)),
+        ];
+
+        let mut time: Time = Time::new();
+        time.update_with(Duration::from_millis(400));
+        run_sequences(&time, &mut log, &mut sequences);
+        assert_eq!(log, [1, 0]);
+        assert_eq!(sequences.len(), 1);
+
+        time.update_with(Duration::from_millis(200));
+        run_sequences(&time, &mut log, &mut sequences);
+        assert_eq!(log, [1, 0, 2]);
+        assert!(sequences.is_empty());
+    }
+
+    #[test]
+    fn pause() {
+        let mut value: f32 = 0.0;
+        let mut sequence: Sequence<f32> =
+            Sequence::new().tween(Duration::from_secs(1), |value, t| *value = t);
+        sequence.pause();
+        assert!(!sequence.advance(&mut value, Duration::from_secs(2)));
+        assert_eq!(value, 0.0);
+
+        sequence.unpause();
+        assert!(!sequence.advance(&mut value, Duration::from_millis(500)));
+        assert_eq!(value, 0.5);
+        assert!(sequence.advance(&mut value, Duration::from_millis(500)));
+        assert_eq!(value, 1.0);
+    }
+
+    #[test]
+    fn unscaled_time() {
+        let mut value: f32 = 0.0;
+        let mut sequence: Sequence<f32> =
+            Sequence::new().tween(Duration::from_secs(1), |value, t| *value = t);
+        sequence.set_clock(Clock::Unscaled);
+        sequence.set_speed(2.0);
+
+        let mut time: Time = Time::new();
+        time.is_paused = true;
+        time.update_with(Duration::from_millis(250));
+        assert!(!sequence.update(&mut value, &time));
+        assert_eq!(value, 0.5);
+
+        sequence.set_clock(Clock::Scaled);
+        assert!(!sequence.update(&mut value, &time));
+        assert_eq!(value, 0.5);
+    }
+}
@@ -0,0 +1,552 @@
+//! `gamecore::serialization` submodule implements [`ComponentRegistry`] - registry of serializable
+//! components that converts scenes (or groups of game objects, e.g. prefabs) to [`SerializedScene`]s and back.
+//!
+//! Every registered component has a version, which is stored with its serialized data.
+//! When fields of a component change, its version is incremented and migration hook from the previous
+//! version is registered, so old save files and scenes still load. Components that cannot be migrated
+//! are reported all at once, before anything is spawned.
+//!
+//! Registry is also a backend of entity inspectors (see [`inspection`](crate::gamecore::inspection)).
+//!
+
+use crate::{
+    gamecore::{
+        components::Component,
+        identifiers::GameObjectId,
+        inspection::{self, InspectorField, InspectorValue},
+        scenes::Scene,
+        scripting::{from_script_value, to_script_value, ScriptValue},
+        ComponentStorage,
+    },
+    GGError,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt};
+
+/// Version of the layout of [`SerializedScene`] itself.
+///
+/// Scenes with newer format version cannot be loaded.
+///
+pub const SCENE_FORMAT_VERSION: u32 = 1;
+
+/// [`SerializedComponent`] struct holds serialized data of a component with its version.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SerializedComponent {
+    /// Version of the component at the moment of serialization.
+    ///
+    pub version: u32,
+    /// Serialized data.
+    ///
+    pub value: ScriptValue,
+}
+
+/// [`SerializedGameObject`] struct holds serialized components of a game object by their registered names.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct SerializedGameObject {
+    /// Serialized components.
+    ///
+    pub components: BTreeMap<String, SerializedComponent>,
+}
+
+/// [`SerializedScene`] struct holds serialized game objects of a scene or of a prefab.
+///
+/// It can be saved and loaded as any other asset (see [`ToFile`](crate::datacore::assets::ToFile)).
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct SerializedScene {
+    /// Version of the layout of serialized scene (see [`SCENE_FORMAT_VERSION`]).
+    ///
+    pub format_version: u32,
+    /// Serialized game objects.
+    ///
+    pub gameobjects: Vec<SerializedGameObject>,
+}
+impl Default for SerializedScene {
+    fn default() -> Self {
+        SerializedScene {
+            format_version: SCENE_FORMAT_VERSION,
+            gameobjects: Vec::new(),
+        }
+    }
+}
+
+/// Function that serializes component of a game object.
+///
+type ComponentSerializer =
+    Box<dyn Fn(&ComponentStorage, GameObjectId) -> Option<Result<ScriptValue, GGError>>>;
+/// Function that inserts deserialized component into a game object.
+///
+type ComponentInserter =
+    Box<dyn Fn(&mut ComponentStorage, GameObjectId, ScriptValue) -> Result<(), GGError>>;
+/// Function that migrates serialized data of a component to the next version.
+///
+type Migration = Box<dyn Fn(ScriptValue) -> Result<ScriptValue, GGError>>;
+
+/// [`RegisteredComponent`] struct holds functions of one registered component.
+///
+struct RegisteredComponent {
+    /// Current version of the component.
+    ///
+    version: u32,
+    /// Serializes component.
+    ///
+    serialize: ComponentSerializer,
+    /// Inserts component.
+    ///
+    insert: ComponentInserter,
+    /// Migrations by versions from which they migrate.
+    ///
+    migrations: BTreeMap<u32, Migration>,
+}
+
+/// [`ComponentRegistry`] struct is a registry of components that can be serialized.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::{components::Component, scenes::Scene};
+/// # use ggengine::gamecore::serialization::{ComponentRegistry, SerializedScene};
+/// # use ggengine::gamecore::scripting::{from_script_value, to_script_value};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Health {
+///     current: u32,
+///     max: u32,
+/// }
+/// impl Component for Health {}
+///
+/// let mut registry: ComponentRegistry = ComponentRegistry::new();
+/// registry.register::<Health>("Health", 2);
+/// // first version stored health as a single number
+/// registry
+///     .register_migration("Health", 1, |value| {
+///         let current: u32 = from_script_value(value)?;
+///         to_script_value(&Health { current, max: current })
+///     })
+///     .expect("Health is registered");
+///
+/// let mut scene: Scene = Scene::new();
+/// let _ = scene.spawn(Health { current: 5, max: 10 });
+/// let saved: SerializedScene = registry.serialize(&scene).expect("Components are serializable");
+/// let loaded: Scene = registry.deserialize(&saved).expect("Scene is loadable");
+/// assert_eq!(loaded.components().components::<Health>().count(), 1);
+/// ```
+///
+pub struct ComponentRegistry {
+    /// Registered components by their names.
+    ///
+    components: BTreeMap<String, RegisteredComponent>,
+}
+impl ComponentRegistry {
+    /// Initializes [`ComponentRegistry`] without registered components.
+    ///
+    pub fn new() -> Self {
+        ComponentRegistry {
+            components: BTreeMap::new(),
+        }
+    }
+
+    /// Registers component under given name with its current version.
+    ///
+    /// Name is stored in serialized data, so it should not change between releases of a game.
+    /// Registering another component under the same name replaces previous one (with its migrations).
+    ///
+    pub fn register<C: Component + Serialize + DeserializeOwned>(
+        &mut self,
+        name: &str,
+        version: u32,
+    ) {
+        let _ = self.components.insert(
+            String::from(name),
+            RegisteredComponent {
+                version,
+                serialize: Box::new(|components, gameobject_id| {
+                    components
+                        .get_component::<C>(gameobject_id)
+                        .map(to_script_value)
+                }),
+                insert: Box::new(|components, gameobject_id, value| {
+                    let _ =
+                        components.insert_component(gameobject_id, from_script_value::<C>(value)?);
+                    Ok(())
+                }),
+                migrations: BTreeMap::new(),
+            },
+        );
+    }
+    /// Registers hook that migrates serialized data of the component from given version to the next one.
+    ///
+    /// Data is migrated step by step, so data of any older version is loadable if there are hooks for every step.
+    ///
+    /// # Errors
+    /// Error is returned if component is not registered.
+    ///
+    pub fn register_migration(
+        &mut self,
+        name: &str,
+        from_version: u32,
+        migration: impl Fn(ScriptValue) -> Result<ScriptValue, GGError> + 'static,
+    ) -> Result<(), GGError> {
+        let component: &mut RegisteredComponent =
+            self.components.get_mut(name).ok_or_else(|| {
+                GGError::InvalidArgument(format!("Component {name} is not registered"))
+            })?;
+        let _ = component
+            .migrations
+            .insert(from_version, Box::new(migration));
+        Ok(())
+    }
+
+    /// Returns names of registered components.
+    ///
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.components.keys().map(String::as_str)
+    }
+    /// Returns current version of the component.
+    ///
+    pub fn version(&self, name: &str) -> Option<u32> {
+        self.components.get(name).map(|component| component.version)
+    }
+
+    /// Serializes registered components of the game object.
+    ///
+    /// # Errors
+    /// Error is returned if any of components cannot be serialized.
+    ///
+    pub fn serialize_gameobject(
+        &self,
+        scene: &Scene,
+        gameobject_id: GameObjectId,
+    ) -> Result<SerializedGameObject, GGError> {
+        let mut gameobject: SerializedGameObject = SerializedGameObject::default();
+        for (name, component) in self.components.iter() {
+            if let Some(value) = (component.serialize)(scene.components(), gameobject_id) {
+                let _ = gameobject.components.insert(
+                    name.clone(),
+                    SerializedComponent {
+                        version: component.version,
+                        value: value?,
+                    },
+                );
+            }
+        }
+        Ok(gameobject)
+    }
+    /// Serializes given game objects of the scene (e.g. to create prefab).
+    ///
+    /// # Errors
+    /// Error is returned if any of components cannot be serialized.
+    ///
+    pub fn serialize_gameobjects(
+        &self,
+        scene: &Scene,
+        gameobjects: impl IntoIterator<Item = GameObjectId>,
+    ) -> Result<SerializedScene, GGError> {
+        Ok(SerializedScene {
+            format_version: SCENE_FORMAT_VERSION,
+            gameobjects: gameobjects
+                .into_iter()
+                .map(|gameobject_id| self.serialize_gameobject(scene, gameobject_id))
+                .collect::<Result<Vec<SerializedGameObject>, GGError>>()?,
+        })
+    }
+    /// Serializes all game objects of the scene.
+    ///
+    /// # Errors
+    /// Error is returned if any of components cannot be serialized.
+    ///
+    pub fn serialize(&self, scene: &Scene) -> Result<SerializedScene, GGError> {
+        self.serialize_gameobjects(scene, scene.components().gameobjects())
+    }
+
+    /// Returns inspector trees of registered components of the game object
+    /// (fields are named by names of components).
+    ///
+    /// # Errors
+    /// Error is returned if any of components cannot be serialized.
+    ///
+    pub fn inspect(
+        &self,
+        scene: &Scene,
+        gameobject_id: GameObjectId,
+    ) -> Result<Vec<InspectorField>, GGError> {
+        Ok(self
+            .serialize_gameobject(scene, gameobject_id)?
+            .components
+            .into_iter()
+            .map(|(name, component)| InspectorField {
+                name,
+                value: InspectorValue::from_script_value(&component.value),
+            })
+            .collect())
+    }
+    /// Replaces primitive field of the component of the game object.
+    ///
+    /// Field is found by path of names of fields (as they are shown by `ComponentRegistry::inspect`).
+    /// New value should have the same type as the old one (integers are also accepted by floating point fields).
+    ///
+    /// # Errors
+    /// Error is returned if component is not registered or game object does not have it,
+    /// if field is not found or is not primitive, or if component cannot hold new value
+    /// (component stays unchanged in that case).
+    ///
+    pub fn set_field(
+        &self,
+        scene: &mut Scene,
+        gameobject_id: GameObjectId,
+        name: &str,
+        path: &[&str],
+        value: &InspectorValue,
+    ) -> Result<(), GGError> {
+        let component: &RegisteredComponent = self.components.get(name).ok_or_else(|| {
+            GGError::InvalidArgument(format!("Component {name} is not registered"))
+        })?;
+        let mut serialized: ScriptValue = (component.serialize)(scene.components(), gameobject_id)
+            .ok_or_else(|| {
+                GGError::InvalidArgument(format!("Game object does not have component {name}"))
+            })??;
+        inspection::set_field(&mut serialized, path, value)?;
+        (component.insert)(scene.components_mut(), gameobject_id, serialized)
+    }
+
+    /// Migrates serialized component to its current version.
+    ///
+    /// Reason is returned if component cannot be migrated.
+    ///
+    fn migrate(&self, name: &str, component: &SerializedComponent) -> Result<ScriptValue, String> {
+        let registered: &RegisteredComponent = self
+            .components
+            .get(name)
+            .ok_or_else(|| String::from("component is not registered"))?;
+        if component.version > registered.version {
+            return Err(format!(
+                "version {} is newer than registered version {}",
+                component.version, registered.version
+            ));
+        }
+        let mut value: ScriptValue = component.value.clone();
+        for version in component.version..registered.version {
+            let migration: &Migration = registered
+                .migrations
+                .get(&version)
+                .ok_or_else(|| format!("there is no migration from version {version}"))?;
+            value = migration(value)
+                .map_err(|error| format!("migration from version {version} failed ({error})"))?;
+        }
+        Ok(value)
+    }
+    /// Spawns game objects of serialized scene (or prefab) into the scene and returns their ids.
+    ///
+    /// # Errors
+    /// Error is returned if format of serialized scene is newer than supported one,
+    /// if any of components cannot be migrated (all of them are listed in the error) or if data is malformed.
+    /// Nothing is spawned if components cannot be migrated.
+    ///
+    pub fn spawn(
+        &self,
+        scene: &mut Scene,
+        serialized: &SerializedScene,
+    ) -> Result<Vec<GameObjectId>, GGError> {
+        if serialized.format_version > SCENE_FORMAT_VERSION {
+            return Err(GGError::Unsupported(format!(
+                "Scene format version {} is newer than supported version {SCENE_FORMAT_VERSION}",
+                serialized.format_version
+            )));
+        }
+
+        let mut gameobjects: Vec<Vec<(&str, ScriptValue)>> = Vec::new();
+        let mut unmigratable: Vec<String> = Vec::new();
+        for gameobject in serialized.gameobjects.iter() {
+            let mut components: Vec<(&str, ScriptValue)> = Vec::new();
+            for (name, component) in gameobject.components.iter() {
+                match self.migrate(name, component) {
+                    Ok(value) => components.push((name, value)),
+                    Err(reason) => {
+                        let description: String = format!("{name} ({reason})");
+                        if !unmigratable.contains(&description) {
+                            unmigratable.push(description);
+                        }
+                    }
+                }
+            }
+            gameobjects.push(components);
+        }
+        if !unmigratable.is_empty() {
+            return Err(GGError::Decode(format!(
+                "Components cannot be migrated: {}",
+                unmigratable.join(", ")
+            )));
+        }
+
+        let mut spawned: Vec<GameObjectId> = Vec::with_capacity(gameobjects.len());
+        for components in gameobjects {
+            let gameobject_id: GameObjectId = scene.spawn(());
+            spawned.push(gameobject_id);
+            for (name, value) in components {
+                (self.components[name].insert)(scene.components_mut(), gameobject_id, value)?;
+            }
+        }
+        Ok(spawned)
+    }
+    /// Creates new scene from serialized scene.
+    ///
+    /// # Errors
+    /// See `ComponentRegistry::spawn`.
+    ///
+    pub fn deserialize(&self, serialized: &SerializedScene) -> Result<Scene, GGError> {
+        let mut scene: Scene = Scene::new();
+        let _ = self.spawn(&mut scene, serialized)?;
+        Ok(scene)
+    }
+}
+impl Default for ComponentRegistry {
+    fn default() -> Self {
+        ComponentRegistry::new()
+    }
+}
+impl fmt::Debug for ComponentRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.components
+                    .iter()
+                    .map(|(name, component)| (name, component.version)),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ComponentRegistry, SerializedComponent, SerializedGameObject, SerializedScene};
+    use crate::{
+        gamecore::{
+            components::Component,
+            scenes::Scene,
+            scripting::{from_script_value, to_script_value, ScriptValue},
+        },
+        GGError,
+    };
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Speed {
+        walk: f32,
+        run: f32,
+    }
+    impl Component for Speed {}
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Name(String);
+    impl Component for Name {}
+
+    fn old_gameobject(components: &[(&str, u32, ScriptValue)]) -> SerializedGameObject {
+        SerializedGameObject {
+            components: components
+                .iter()
+                .map(|(name, version, value)| {
+                    (
+                        String::from(*name),
+                        SerializedComponent {
+                            version: *version,
+                            value: value.clone(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn versioned_scenes() {
+        let mut registry: ComponentRegistry = ComponentRegistry::new();
+        registry.register::<Speed>("Speed", 3);
+        registry.register::<Name>("Name", 1);
+        // version 1 stored walking speed as a number, version 2 added running speed as a number
+        registry
+            .register_migration("Speed", 1, |value| {
+                let walk: f32 = from_script_value(value)?;
+                to_script_value(&(walk, walk * 2.0))
+            })
+            .expect("Speed is registered");
+        registry
+            .register_migration("Speed", 2, |value| {
+                let (walk, run): (f32, f32) = from_script_value(value)?;
+                to_script_value(&Speed { walk, run })
+            })
+            .expect("Speed is registered");
+        assert!(registry.register_migration("Missing", 1, Ok).is_err());
+
+        let old: SerializedScene = SerializedScene {
+            gameobjects: vec![
+                old_gameobject(&[("Speed", 1, ScriptValue::Float(1.5))]),
+                old_gameobject(&[
+                    ("Name", 1, ScriptValue::Text(String::from("bob"))),
+                    (
+                        "Speed",
+                        3,
+                        to_script_value(&Speed {
+                            walk: 1.0,
+                            run: 4.0,
+                        })
+                        .expect("Speed is serializable"),
+                    ),
+                ]),
+            ],
+            ..SerializedScene::default()
+        };
+        let bytes: Vec<u8> = serde_cbor::to_vec(&old).expect("Scene is serializable");
+        let old: SerializedScene = serde_cbor::from_slice(&bytes).expect("Scene is deserializable");
+        let mut scene: Scene = registry.deserialize(&old).expect("Scene is migratable");
+        let speeds: Vec<&Speed> = scene
+            .components()
+            .components::<Speed>()
+            .map(|(_, speed)| speed)
+            .collect();
+        assert_eq!(
+            speeds,
+            [
+                &Speed {
+                    walk: 1.5,
+                    run: 3.0
+                },
+                &Speed {
+                    walk: 1.0,
+                    run: 4.0
+                }
+            ]
+        );
+        assert_eq!(
+            registry
+                .serialize(&scene)
+                .expect("Scene is serializable")
+                .gameobjects[0]
+                .components["Speed"]
+                .version,
+            3
+        );
+
+        let broken: SerializedScene = SerializedScene {
+            gameobjects: vec![
+                old_gameobject(&[("Speed", 0, ScriptValue::Null)]),
+                old_gameobject(&[
+                    ("Name", 2, ScriptValue::Null),
+                    ("Mana", 1, ScriptValue::Null),
+                ]),
+            ],
+            ..SerializedScene::default()
+        };
+        let Err(GGError::Decode(message)) = registry.spawn(&mut scene, &broken) else {
+            panic!("Components are not migratable");
+        };
+        assert_eq!(
+            message,
+            "Components cannot be migrated: Speed (there is no migration from version 0), \
+             Mana (component is not registered), Name (version 2 is newer than registered version 1)"
+        );
+        assert_eq!(scene.components().gameobject_count(), 2);
+    }
+}
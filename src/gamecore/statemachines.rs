@@ -0,0 +1,350 @@
+//! `gamecore::statemachines` submodule implements [`StateMachine`] - finite state machine component,
+//! which is suitable for enemy AI and player controllers that have outgrown simple enums.
+//!
+//! States are values of any small `Copy` type (usually fieldless enum). Every state can have
+//! enter, exit and update hooks, and transitions between states are guarded by conditions
+//! that are checked after every update.
+//!
+
+use crate::gamecore::{components::Component, time::Time};
+use std::{collections::HashMap, fmt, hash::Hash, time::Duration};
+
+/// Hook that is called upon entering or exiting a state.
+///
+type TransitionHook<C> = Box<dyn FnMut(&mut C)>;
+/// Hook that is called on every update of a state.
+///
+type UpdateHook<C> = Box<dyn FnMut(&mut C, &Time)>;
+/// Guard that decides whether transition should happen; it receives time spent in current state.
+///
+type Guard<C> = Box<dyn FnMut(&C, Duration) -> bool>;
+
+/// [`StateHooks`] struct holds hooks of one state.
+///
+struct StateHooks<C> {
+    /// Hook that is called when state is entered.
+    ///
+    enter: Option<TransitionHook<C>>,
+    /// Hook that is called when state is exited.
+    ///
+    exit: Option<TransitionHook<C>>,
+    /// Hook that is called on every update while state is active.
+    ///
+    update: Option<UpdateHook<C>>,
+}
+impl<C> Default for StateHooks<C> {
+    fn default() -> Self {
+        StateHooks {
+            enter: None,
+            exit: None,
+            update: None,
+        }
+    }
+}
+
+/// [`Transition`] struct describes guarded transition between states.
+///
+struct Transition<S, C> {
+    /// State from which transition happens (`None` corresponds to any state).
+    ///
+    from: Option<S>,
+    /// State to which transition happens.
+    ///
+    to: S,
+    /// Condition of transition.
+    ///
+    guard: Guard<C>,
+}
+
+/// [`StateMachine`] struct is a component that implements finite state machine.
+///
+/// On every update, update hook of current state is called, then transitions from current state
+/// are checked in order of their addition (transitions from any state are checked along with them);
+/// the first transition whose guard passes is performed - exit hook of current state is called,
+/// then enter hook of the new state.
+/// Enter hook of initial state is called on the first update.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::{statemachines::StateMachine, time::Time};
+/// # use std::time::Duration;
+/// #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+/// enum Enemy {
+///     Patrol,
+///     Chase,
+///     Attack,
+/// }
+/// struct Senses {
+///     distance_to_player: f32,
+///     attacks: u32,
+/// }
+///
+/// let mut ai: StateMachine<Enemy, Senses> = StateMachine::<Enemy, Senses>::new(Enemy::Patrol)
+///     .on_enter(Enemy::Attack, |senses| senses.attacks += 1)
+///     .transition(Enemy::Patrol, Enemy::Chase, |senses, _| senses.distance_to_player < 10.0)
+///     .transition(Enemy::Chase, Enemy::Attack, |senses, _| senses.distance_to_player < 1.0)
+///     .transition(Enemy::Attack, Enemy::Chase, |_, time_in_state| time_in_state >= Duration::from_secs(1))
+///     .transition_from_any(Enemy::Patrol, |senses, _| senses.distance_to_player > 20.0);
+///
+/// let mut senses: Senses = Senses { distance_to_player: 5.0, attacks: 0 };
+/// let mut time: Time = Time::new();
+/// time.update_with(Duration::from_millis(500));
+///
+/// ai.update(&mut senses, &time);
+/// assert_eq!(ai.state(), Enemy::Chase);
+///
+/// senses.distance_to_player = 0.5;
+/// ai.update(&mut senses, &time);
+/// ai.update(&mut senses, &time);
+/// assert_eq!(ai.state(), Enemy::Attack);
+/// ai.update(&mut senses, &time);
+/// assert_eq!(ai.state(), Enemy::Chase);
+/// assert_eq!(senses.attacks, 1);
+/// ```
+///
+pub struct StateMachine<S, C = ()> {
+    /// Current state.
+    ///
+    state: S,
+    /// Previous state.
+    ///
+    previous_state: Option<S>,
+    /// Time that was spent in current state.
+    ///
+    time_in_state: Duration,
+    /// Whether enter hook of initial state was called.
+    ///
+    is_started: bool,
+
+    /// Hooks of states.
+    ///
+    hooks: HashMap<S, StateHooks<C>>,
+    /// Guarded transitions.
+    ///
+    transitions: Vec<Transition<S, C>>,
+}
+impl<S: Copy + Eq + Hash, C> StateMachine<S, C> {
+    /// Constructs state machine with given initial state.
+    ///
+    pub fn new(initial_state: S) -> Self {
+        StateMachine {
+            state: initial_state,
+            previous_state: None,
+            time_in_state: Duration::ZERO,
+            is_started: false,
+
+            hooks: HashMap::new(),
+            transitions: Vec::new(),
+        }
+    }
+
+    /// Sets hook that is called when given state is entered.
+    ///
+    pub fn on_enter(mut self, state: S, hook: impl FnMut(&mut C) + 'static) -> Self {
+        self.hooks.entry(state).or_default().enter = Some(Box::new(hook));
+        self
+    }
+    /// Sets hook that is called when given state is exited.
+    ///
+    pub fn on_exit(mut self, state: S, hook: impl FnMut(&mut C) + 'static) -> Self {
+        self.hooks.entry(state).or_default().exit = Some(Box::new(hook));
+        self
+    }
+    /// Sets hook that is called on every update while given state is active.
+    ///
+    pub fn on_update(mut self, state: S, hook: impl FnMut(&mut C, &Time) + 'static) -> Self {
+        self.hooks.entry(state).or_default().update = Some(Box::new(hook));
+        self
+    }
+    /// Adds transition between two states that happens when guard passes.
+    ///
+    /// Guard receives context and time that was spent in current state.
+    ///
+    pub fn transition(
+        mut self,
+        from: S,
+        to: S,
+        guard: impl FnMut(&C, Duration) -> bool + 'static,
+    ) -> Self {
+        self.transitions.push(Transition {
+            from: Some(from),
+            to,
+            guard: Box::new(guard),
+        });
+        self
+    }
+    /// Adds transition from any state (except for the target one) that happens when guard passes.
+    ///
+    pub fn transition_from_any(
+        mut self,
+        to: S,
+        guard: impl FnMut(&C, Duration) -> bool + 'static,
+    ) -> Self {
+        self.transitions.push(Transition {
+            from: None,
+            to,
+            guard: Box::new(guard),
+        });
+        self
+    }
+
+    /// Returns current state.
+    ///
+    pub fn state(&self) -> S {
+        self.state
+    }
+    /// Returns previous state.
+    ///
+    pub fn previous_state(&self) -> Option<S> {
+        self.previous_state
+    }
+    /// Returns time that was spent in current state.
+    ///
+    pub fn time_in_state(&self) -> Duration {
+        self.time_in_state
+    }
+
+    /// Calls enter hook of initial state if it was not called yet.
+    ///
+    fn start(&mut self, context: &mut C) {
+        if self.is_started {
+            return;
+        }
+        self.is_started = true;
+        if let Some(enter) = self
+            .hooks
+            .get_mut(&self.state)
+            .and_then(|hooks| hooks.enter.as_mut())
+        {
+            enter(context);
+        }
+    }
+    /// Changes state immediately, calling exit and enter hooks (guards are not checked).
+    ///
+    /// Changing state to the current one restarts it.
+    ///
+    pub fn set_state(&mut self, context: &mut C, state: S) {
+        self.start(context);
+        if let Some(exit) = self
+            .hooks
+            .get_mut(&self.state)
+            .and_then(|hooks| hooks.exit.as_mut())
+        {
+            exit(context);
+        }
+        self.previous_state = Some(self.state);
+        self.state = state;
+        self.time_in_state = Duration::ZERO;
+        if let Some(enter) = self
+            .hooks
+            .get_mut(&self.state)
+            .and_then(|hooks| hooks.enter.as_mut())
+        {
+            enter(context);
+        }
+    }
+
+    /// Updates current state by [`Time`] and performs the first transition whose guard passes.
+    ///
+    pub fn update(&mut self, context: &mut C, time: &Time) {
+        self.start(context);
+        if let Some(update) = self
+            .hooks
+            .get_mut(&self.state)
+            .and_then(|hooks| hooks.update.as_mut())
+        {
+            update(context, time);
+        }
+        self.time_in_state += time.delta();
+
+        let (state, time_in_state): (S, Duration) = (self.state, self.time_in_state);
+        let next_state: Option<S> = self
+            .transitions
+            .iter_mut()
+            .filter(|transition| match transition.from {
+                Some(from) => from == state,
+                None => transition.to != state,
+            })
+            .find_map(|transition| {
+                (transition.guard)(context, time_in_state).then_some(transition.to)
+            });
+        if let Some(next_state) = next_state {
+            self.set_state(context, next_state);
+        }
+    }
+}
+impl<S: fmt::Debug, C> fmt::Debug for StateMachine<S, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StateMachine")
+            .field("state", &self.state)
+            .field("previous_state", &self.previous_state)
+            .field("time_in_state", &self.time_in_state)
+            .field("transitions", &self.transitions.len())
+            .finish_non_exhaustive()
+    }
+}
+impl<S: 'static, C: 'static> Component for StateMachine<S, C> {}
+
+/// Updates all given state machines by [`Time`].
+///
+/// This system should be run once per frame, after [`Time`] is updated.
+///
+pub fn run_state_machines<'a, S: Copy + Eq + Hash + 'a, C: 'a>(
+    time: &Time,
+    context: &mut C,
+    state_machines: impl IntoIterator<Item = &'a mut StateMachine<S, C>>,
+) {
+    for state_machine in state_machines {
+        state_machine.update(context, time);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run_state_machines, StateMachine};
+    use crate::gamecore::time::Time;
+    use std::time::Duration;
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    enum Light {
+        Red,
+        Green,
+    }
+
+    #[test]
+    fn hooks_order() {
+        let mut log: Vec<&'static str> = Vec::new();
+        let mut light: StateMachine<Light, Vec<&'static str>> =
+            StateMachine::<Light, Vec<&'static str>>::new(Light::Red)
+                .on_enter(Light::Red, |log| log.push("enter red"))
+                .on_exit(Light::Red, |log| log.push("exit red"))
+                .on_update(Light::Red, |log, _| log.push("update red"))
+                .on_enter(Light::Green, |log| log.push("enter green"))
+                .transition(Light::Red, Light::Green, |_, time| {
+                    time >= Duration::from_secs(2)
+                });
+
+        let mut time: Time = Time::new();
+        time.update_with(Duration::from_secs(1));
+        run_state_machines(&time, &mut log, [&mut light]);
+        assert_eq!(light.state(), Light::Red);
+        assert_eq!(light.time_in_state(), Duration::from_secs(1));
+        run_state_machines(&time, &mut log, [&mut light]);
+        assert_eq!(light.state(), Light::Green);
+        assert_eq!(light.previous_state(), Some(Light::Red));
+        assert_eq!(
+            log,
+            [
+                "enter red",
+                "update red",
+                "update red",
+                "exit red",
+                "enter green"
+            ]
+        );
+
+        light.set_state(&mut log, Light::Red);
+        assert_eq!(light.time_in_state(), Duration::ZERO);
+        assert_eq!(log.last(), Some(&"enter red"));
+    }
+}
@@ -2,13 +2,24 @@
 //! are used to store ECS-related data for game engine.
 //!
 
-use crate::gamecore::{
-    components::{as_any::AsAny, BoxedComponent, BoxedResource, Component, Resource},
-    identifiers::{ComponentId, GameObjectId, ResourceId},
+use crate::{
+    gamecore::{
+        components::{
+            as_any::AsAny, BoxedComponent, BoxedResource, Bundle, Component, ComponentLayout,
+            Resource,
+        },
+        identifiers::{ComponentId, GameObjectId, ResourceId},
+        scenes::Scene,
+        schedules::{Schedule, SystemNode},
+        scripting::ScriptValue,
+    },
+    log::{self, LogTarget},
+    GGError,
 };
 use std::{
     any::{Any, TypeId},
     collections::HashMap,
+    fmt,
     hash::{BuildHasher, Hasher},
 };
 
@@ -101,7 +112,15 @@ macro_rules! impl_type_map {
             /// Insertion and lookup in the map are both amortized `O(1)`.
             ///
             pub(super) fn get_or_insert<T: $type>(&mut self) -> $id {
-                let type_id: TypeId = TypeId::of::<T>();
+                self.get_or_insert_by_type_id(TypeId::of::<T>())
+            }
+            /// Initializes type with given `TypeId` in the map and returns assigned id
+            /// (this function is a counterpart of `get_or_insert` for types that are erased at compile time).
+            ///
+            /// # Complexity
+            /// Insertion and lookup in the map are both amortized `O(1)`.
+            ///
+            pub(super) fn get_or_insert_by_type_id(&mut self, type_id: TypeId) -> $id {
                 let new_id: u64 = self.map.len() as u64;
                 *self
                     .map
@@ -172,6 +191,157 @@ pub(super) struct ComponentMap {
     removed: Vec<ComponentId>,
 }
 impl_type_map!(ComponentMap, Component, ComponentId);
+/// Returns iterator over indices of set bits of a bitmask in ascending order.
+///
+/// Every word is consumed bit by bit with `trailing_zeros`, so empty words are skipped at once.
+///
+fn set_bits(bitmask: &[u64]) -> impl Iterator<Item = usize> + '_ {
+    bitmask.iter().enumerate().flat_map(|(word_index, &word)| {
+        let mut word: u64 = word;
+        std::iter::from_fn(move || {
+            if word == 0 {
+                return None;
+            }
+            let bit: usize = word.trailing_zeros() as usize;
+            word &= word - 1;
+            Some(word_index * u64::BITS as usize + bit)
+        })
+    })
+}
+/// [`Column`] struct stores components of one type by rows of [`ComponentTable`].
+///
+/// Alongside with components, [`Column`] maintains packed bitmask of occupied rows,
+/// which allows iterating only over rows that hold components
+/// without checking every row and skipping 64 empty rows at once.
+///
+#[derive(Debug, Default)]
+struct Column {
+    /// Components by rows.
+    ///
+    components: Vec<Option<BoxedComponent>>,
+    /// Bitmask of occupied rows (`n`-th row is occupied if `n % 64` bit of `n / 64` word is set).
+    ///
+    occupied: Vec<u64>,
+}
+impl Column {
+    /// Initializes empty [`Column`] that can hold at least `capacity` rows without reallocating.
+    ///
+    fn with_capacity(capacity: usize) -> Column {
+        Column {
+            components: Vec::with_capacity(capacity),
+            occupied: Vec::with_capacity(capacity.div_ceil(u64::BITS as usize)),
+        }
+    }
+
+    /// Returns word index and mask of bit that corresponds to the row.
+    ///
+    fn bit(row: usize) -> (usize, u64) {
+        (row / u64::BITS as usize, 1 << (row % u64::BITS as usize))
+    }
+
+    /// Inserts component to the row and returns component that was previously there.
+    ///
+    fn insert(&mut self, row: usize, component: BoxedComponent) -> Option<BoxedComponent> {
+        if row >= self.components.len() {
+            self.components.resize_with(row + 1, || None);
+        }
+        let (word, mask): (usize, u64) = Column::bit(row);
+        if word >= self.occupied.len() {
+            self.occupied.resize(word + 1, 0);
+        }
+        self.occupied[word] |= mask;
+        self.components[row].replace(component)
+    }
+    /// Inserts components to rows, which should be sorted in ascending order.
+    ///
+    /// Column is grown only once (up to the last row), and then components are written in a single pass;
+    /// if there are several components for one row, the last one is kept.
+    ///
+    fn insert_sorted(&mut self, components: Vec<(usize, BoxedComponent)>) {
+        let Some(&(last_row, _)) = components.last() else {
+            return;
+        };
+        if last_row >= self.components.len() {
+            self.components.resize_with(last_row + 1, || None);
+        }
+        let (last_word, _): (usize, u64) = Column::bit(last_row);
+        if last_word >= self.occupied.len() {
+            self.occupied.resize(last_word + 1, 0);
+        }
+        for (row, component) in components {
+            let (word, mask): (usize, u64) = Column::bit(row);
+            self.occupied[word] |= mask;
+            self.components[row] = Some(component);
+        }
+    }
+    /// Removes component from the row and returns it.
+    ///
+    fn remove(&mut self, row: usize) -> Option<BoxedComponent> {
+        let (word, mask): (usize, u64) = Column::bit(row);
+        if let Some(occupied) = self.occupied.get_mut(word) {
+            *occupied &= !mask;
+        }
+        self.components.get_mut(row)?.take()
+    }
+
+    /// Reserves capacity for at least `rows` rows in total.
+    ///
+    fn reserve(&mut self, rows: usize) {
+        self.components
+            .reserve(rows.saturating_sub(self.components.len()));
+        self.occupied.reserve(
+            rows.div_ceil(u64::BITS as usize)
+                .saturating_sub(self.occupied.len()),
+        );
+    }
+    /// Returns the number of rows the column can hold without reallocating.
+    ///
+    fn capacity(&self) -> usize {
+        self.components.capacity()
+    }
+
+    /// Returns place of the component in the row.
+    ///
+    fn get(&self, row: usize) -> &Option<BoxedComponent> {
+        self.components.get(row).unwrap_or(&None)
+    }
+    /// Returns mutable reference to the component in the row.
+    ///
+    fn get_mut(&mut self, row: usize) -> Option<&mut BoxedComponent> {
+        self.components.get_mut(row)?.as_mut()
+    }
+
+    /// Returns iterator over occupied rows and their components.
+    ///
+    fn iter(&self) -> impl Iterator<Item = (usize, &BoxedComponent)> {
+        set_bits(&self.occupied).map(|row| {
+            (
+                row,
+                self.components[row]
+                    .as_ref()
+                    .expect("Occupied rows hold components."),
+            )
+        })
+    }
+    /// Returns iterator over occupied rows and their components, allowing to modify those components.
+    ///
+    fn iter_mut(&mut self) -> impl Iterator<Item = (usize, &mut BoxedComponent)> {
+        let mut rest: &mut [Option<BoxedComponent>] = &mut self.components;
+        let mut offset: usize = 0;
+        set_bits(&self.occupied).map(move |row| {
+            let (_, tail) = std::mem::take(&mut rest).split_at_mut(row - offset);
+            let (component, tail) = tail
+                .split_first_mut()
+                .expect("Occupied rows are in bounds.");
+            rest = tail;
+            offset = row + 1;
+            (
+                row,
+                component.as_mut().expect("Occupied rows hold components."),
+            )
+        })
+    }
+}
 /// [`ComponentTable`] is a column-oriented structure-of-arrays based storage
 /// that maps `GameObject`s to their `Component`s.
 ///
@@ -192,6 +362,8 @@ impl_type_map!(ComponentMap, Component, ComponentId);
 ///
 /// Since components are stored in columnar contiguous blocks of memory, table is optimized for fast querying,
 /// but frequent insertion and removal can be relatively slow.
+/// Every column maintains bitmask of its occupied rows on insertion and removal,
+/// so iteration over a column does not visit rows that do not hold components.
 ///
 /// Implementation with an actual table (represented by `Vec` that emulates `Vec<Vec<T: Component>>`)
 /// could be a bit faster on querying due to cache locality, but insertion and removal would be very slow
@@ -217,10 +389,15 @@ pub(super) struct ComponentTable {
     /// following insertions.
     ///
     removed: Vec<usize>,
+    /// Vector that maps rows of the table back to `GameObjectId`s that occupy them.
+    ///
+    /// Rows that were freed by removal of `GameObjectId`s are `None` until they are reused.
+    ///
+    rows: Vec<Option<GameObjectId>>,
 
     /// Table that holds all components.
     ///
-    component_table: IdMap<ComponentId, Vec<Option<BoxedComponent>>>,
+    component_table: IdMap<ComponentId, Column>,
 }
 impl ComponentTable {
     /// Initializes new [`ComponentTable`].
@@ -236,6 +413,7 @@ impl ComponentTable {
         ComponentTable {
             gameobject_map: IdMap::with_hasher(NoOpHasherState),
             removed: Vec::new(),
+            rows: Vec::new(),
 
             component_table: IdMap::with_hasher(NoOpHasherState),
         }
@@ -256,6 +434,7 @@ impl ComponentTable {
         ComponentTable {
             gameobject_map: IdMap::with_capacity_and_hasher(gameobject_capacity, NoOpHasherState),
             removed: Vec::with_capacity(gameobject_capacity),
+            rows: Vec::with_capacity(gameobject_capacity),
 
             component_table: IdMap::with_capacity_and_hasher(component_capacity, NoOpHasherState),
         }
@@ -274,11 +453,15 @@ impl ComponentTable {
     /// Overall complexity is amortized `O(1)`.
     ///
     pub(super) fn insert_gameobject(&mut self, gameobject_id: GameObjectId) {
+        if self.has_gameobject(gameobject_id) {
+            return;
+        }
         let new_index: usize = self.removed.pop().unwrap_or(self.gameobject_count());
-        let _ = self
-            .gameobject_map
-            .entry(gameobject_id)
-            .or_insert(new_index);
+        let _ = self.gameobject_map.insert(gameobject_id, new_index);
+        if new_index >= self.rows.len() {
+            self.rows.resize(new_index + 1, None);
+        }
+        self.rows[new_index] = Some(gameobject_id);
     }
     /// Removes `GameObjectId` from the [`ComponentTable`].
     ///
@@ -295,10 +478,9 @@ impl ComponentTable {
             return;
         };
         self.removed.push(deleted_index);
-        for components in self.component_table.values_mut() {
-            if let Some(component) = components.get_mut(deleted_index) {
-                *component = None;
-            }
+        self.rows[deleted_index] = None;
+        for column in self.component_table.values_mut() {
+            let _ = column.remove(deleted_index);
         }
     }
 
@@ -311,12 +493,11 @@ impl ComponentTable {
     /// Overall complexity is amortized `O(1)`.
     ///
     pub(super) fn insert_component(&mut self, component_id: ComponentId) {
-        let gameobjects: usize = self.gameobject_count();
         let gameobject_capacity: usize = self.gameobject_capacity();
-        self.component_table
+        let _ = self
+            .component_table
             .entry(component_id)
-            .or_insert(Vec::with_capacity(gameobject_capacity))
-            .resize_with(gameobjects, || None);
+            .or_insert_with(|| Column::with_capacity(gameobject_capacity));
     }
     /// Adds component to `GameObjectId` if both `GameObjectId` and `ComponentId` are tracked by [`ComponentTable`].
     /// If either `GameObjectId` or `ComponentId` are not present, does nothing.
@@ -335,19 +516,56 @@ impl ComponentTable {
         gameobject_id: GameObjectId,
     ) {
         let Some(&gameobject_index) = self.gameobject_map.get(&gameobject_id) else {
+            log::warn(
+                LogTarget::GameCore,
+                format_args!("Component was not added to untracked {gameobject_id:?}"),
+            );
             return;
         };
-        let Some(components) = self.component_table.get_mut(&component_id) else {
+        let Some(column) = self.component_table.get_mut(&component_id) else {
+            log::warn(
+                LogTarget::GameCore,
+                format_args!("Untracked {component_id:?} was not added to {gameobject_id:?}"),
+            );
             return;
         };
-        if gameobject_index >= components.len() {
-            components.resize_with(gameobject_index + 1, || None);
+        let _ = column.insert(gameobject_index, component);
+    }
+    /// Adds components to `GameObjectId`s if `ComponentId` is tracked by [`ComponentTable`],
+    /// skipping `GameObjectId`s that are not tracked, and returns the number of added components.
+    ///
+    /// Components are sorted by rows of their `GameObject`s, so that column is grown once
+    /// and filled in a single pass.
+    ///
+    /// # Complexity
+    /// Insertion requires lookup on map for every component which is amortized `O(1)`
+    /// and sorting of components which is `O(n * log(n))`.
+    /// Overall complexity is `O(n * log(n))`, where `n` is the number of components.
+    ///
+    pub(super) fn add_components_to_gameobjects(
+        &mut self,
+        component_id: ComponentId,
+        components: impl IntoIterator<Item = (GameObjectId, BoxedComponent)>,
+    ) -> usize {
+        let Some(column) = self.component_table.get_mut(&component_id) else {
+            log::warn(
+                LogTarget::GameCore,
+                format_args!("Untracked {component_id:?} was not added to game objects"),
+            );
+            return 0;
+        };
+        let components = components.into_iter();
+        let mut rows: Vec<(usize, BoxedComponent)> = Vec::with_capacity(components.size_hint().0);
+        for (gameobject_id, component) in components {
+            if let Some(&row) = self.gameobject_map.get(&gameobject_id) {
+                rows.push((row, component));
+            }
         }
+        rows.sort_by_key(|(row, _)| *row);
 
-        let place: &mut Option<BoxedComponent> = components
-            .get_mut(gameobject_index)
-            .expect("Existence of index has been ensured.");
-        *place = Some(component);
+        let added: usize = rows.len();
+        column.insert_sorted(rows);
+        added
     }
     /// Removes column that corresponds to given `ComponentId` if present.
     ///
@@ -371,16 +589,25 @@ impl ComponentTable {
         component_id: ComponentId,
         gameobject_id: GameObjectId,
     ) {
-        let Some(&gameobject_index) = self.gameobject_map.get(&gameobject_id) else {
-            return;
-        };
-        let Some(components) = self.component_table.get_mut(&component_id) else {
-            return;
-        };
-        let Some(component) = components.get_mut(gameobject_index) else {
-            return;
-        };
-        *component = None;
+        let _ = self.take_gameobject_component(gameobject_id, component_id);
+    }
+    /// Removes component that has given `ComponentId` from `GameObject` with given id and returns it,
+    /// if both `GameObjectId` and `ComponentId` are tracked by [`ComponentTable`] and component is present.
+    ///
+    /// # Complexity
+    /// Removal requires 2 lookups on maps which are amortized `O(1)`
+    /// and changing value in a vector which is `O(1)`.
+    /// Overall complexity is amortized `O(1)`.
+    ///
+    pub(super) fn take_gameobject_component(
+        &mut self,
+        gameobject_id: GameObjectId,
+        component_id: ComponentId,
+    ) -> Option<BoxedComponent> {
+        let gameobject_index: usize = *self.gameobject_map.get(&gameobject_id)?;
+        self.component_table
+            .get_mut(&component_id)?
+            .remove(gameobject_index)
     }
 
     /// Returns component that has given `ComponentId` and is assigned to `GameObject` with given id if present,
@@ -397,8 +624,97 @@ impl ComponentTable {
         component_id: ComponentId,
     ) -> Option<&Option<BoxedComponent>> {
         let gameobject_index: &usize = self.gameobject_map.get(&gameobject_id)?;
-        let components: &Vec<Option<BoxedComponent>> = self.component_table.get(&component_id)?;
-        Some(components.get(*gameobject_index).unwrap_or_else(|| &None))
+        let column: &Column = self.component_table.get(&component_id)?;
+        Some(column.get(*gameobject_index))
+    }
+    /// Returns mutable reference to the component that has given `ComponentId`
+    /// and is assigned to `GameObject` with given id if present, otherwise `None`.
+    ///
+    /// # Complexity
+    /// Retrieval requires 2 lookups on maps which are amortized `O(1)`
+    /// and retrieving value from a vector which is `O(1)`.
+    /// Overall complexity is amortized `O(1)`.
+    ///
+    pub(super) fn get_gameobject_component_mut(
+        &mut self,
+        gameobject_id: GameObjectId,
+        component_id: ComponentId,
+    ) -> Option<&mut BoxedComponent> {
+        let gameobject_index: usize = *self.gameobject_map.get(&gameobject_id)?;
+        self.component_table
+            .get_mut(&component_id)?
+            .get_mut(gameobject_index)
+    }
+    /// Returns iterator over all components that have given `ComponentId`
+    /// together with ids of `GameObject`s to which they are assigned.
+    ///
+    /// # Complexity
+    /// Iteration visits only occupied rows of the column and scans its bitmask,
+    /// so it is `O(n + self.gameobject_count() / 64)`, where `n` is the number of components.
+    ///
+    pub(super) fn iter_component(
+        &self,
+        component_id: ComponentId,
+    ) -> impl Iterator<Item = (GameObjectId, &BoxedComponent)> {
+        let rows: &[Option<GameObjectId>] = &self.rows;
+        self.component_table
+            .get(&component_id)
+            .into_iter()
+            .flat_map(Column::iter)
+            .map(move |(index, component)| {
+                (
+                    rows[index].expect("Occupied rows belong to tracked `GameObjectId`s."),
+                    component,
+                )
+            })
+    }
+    /// Returns iterator over all components that have given `ComponentId`
+    /// together with ids of `GameObject`s to which they are assigned, allowing to modify those components.
+    ///
+    /// # Complexity
+    /// Iteration visits only occupied rows of the column and scans its bitmask,
+    /// so it is `O(n + self.gameobject_count() / 64)`, where `n` is the number of components.
+    ///
+    pub(super) fn iter_component_mut(
+        &mut self,
+        component_id: ComponentId,
+    ) -> impl Iterator<Item = (GameObjectId, &mut BoxedComponent)> {
+        let rows: &[Option<GameObjectId>] = &self.rows;
+        self.component_table
+            .get_mut(&component_id)
+            .into_iter()
+            .flat_map(Column::iter_mut)
+            .map(move |(index, component)| {
+                (
+                    rows[index].expect("Occupied rows belong to tracked `GameObjectId`s."),
+                    component,
+                )
+            })
+    }
+    /// Returns iterator over `GameObjectId`s that are tracked by [`ComponentTable`] in order of their rows.
+    ///
+    pub(super) fn gameobjects(&self) -> impl Iterator<Item = GameObjectId> + '_ {
+        self.rows.iter().flatten().copied()
+    }
+
+    /// Reserves capacity for at least `additional` more `GameObjectId`s.
+    ///
+    /// Columns are not affected - use `reserve_component` to pre-size them.
+    ///
+    pub(super) fn reserve_gameobjects(&mut self, additional: usize) {
+        self.gameobject_map.reserve(additional);
+        self.rows
+            .reserve(additional.saturating_sub(self.removed.len()));
+    }
+    /// Reserves capacity of column that corresponds to given `ComponentId`
+    /// for at least `additional` more rows than the table currently has.
+    /// If `ComponentId` is not present, does nothing.
+    ///
+    pub(super) fn reserve_component(&mut self, component_id: ComponentId, additional: usize) {
+        let rows: usize = self.rows.len() + additional;
+        if let Some(column) = self.component_table.get_mut(&component_id) {
+            column.reserve(rows);
+        }
     }
 
     /// Returns the number of `GameObject`s the table can hold without reallocating.
@@ -409,6 +725,14 @@ impl ComponentTable {
     pub(super) fn gameobject_capacity(&self) -> usize {
         self.gameobject_map.capacity()
     }
+    /// Returns the number of rows that column of given `ComponentId` can hold without reallocating,
+    /// or 0 if `ComponentId` is not present.
+    ///
+    pub(super) fn column_capacity(&self, component_id: ComponentId) -> usize {
+        self.component_table
+            .get(&component_id)
+            .map_or(0, Column::capacity)
+    }
     /// Returns the number of `Component`s the table can hold without reallocating.
     ///
     /// This number is a lower bound; the [`ComponentTable`] might be able to hold more,
@@ -441,6 +765,499 @@ impl ComponentTable {
     }
 }
 
+/// [`DynamicComponent`] struct is a component that has no Rust type and stores raw bytes
+/// that are described by [`ComponentLayout`].
+///
+#[derive(Debug)]
+struct DynamicComponent(Box<[u8]>);
+impl Component for DynamicComponent {}
+/// [`ComponentStorage`] struct implements typed API over the storage of `GameObject`s and their `Component`s.
+///
+/// Internally, components are stored in a column-oriented table as `BoxedComponent`s,
+/// and [`ComponentStorage`] dispatches concrete types to columns of that table and restores those types back.
+/// `GameObjectId`s are never reused, so ids of despawned `GameObject`s stay invalid.
+///
+/// There are also `*_by_id` functions that work with components which were registered at runtime
+/// by their [`ComponentLayout`] (e.g. by scripts or mods) and have no Rust type.
+/// Those components are addressed by `ComponentId`s that are assigned on registration and
+/// their data is passed as raw bytes.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::{components::Component, identifiers::GameObjectId, ComponentStorage};
+/// struct Health(u32);
+/// impl Component for Health {}
+///
+/// let mut storage: ComponentStorage = ComponentStorage::new();
+/// let gameobject: GameObjectId = storage.insert_gameobject();
+/// assert!(storage.insert_component(gameobject, Health(10)).is_none());
+///
+/// storage.get_component_mut::<Health>(gameobject).expect("Component was inserted").0 -= 3;
+/// assert_eq!(storage.get_component::<Health>(gameobject).map(|health| health.0), Some(7));
+///
+/// assert!(storage.remove_gameobject(gameobject));
+/// assert_eq!(storage.components::<Health>().count(), 0);
+/// ```
+///
+#[derive(Debug, Default)]
+pub struct ComponentStorage {
+    /// Map that dispatches on `Component` types.
+    ///
+    component_map: ComponentMap,
+    /// Table that stores components.
+    ///
+    component_table: ComponentTable,
+    /// Value of the next `GameObjectId`.
+    ///
+    next_gameobject_id: u64,
+
+    /// Layouts of components that were registered at runtime.
+    ///
+    dynamic_layouts: IdMap<ComponentId, ComponentLayout>,
+}
+impl ComponentStorage {
+    /// Initializes new [`ComponentStorage`].
+    ///
+    /// Created [`ComponentStorage`] will not allocate until first insertions.
+    ///
+    pub fn new() -> ComponentStorage {
+        ComponentStorage {
+            component_map: ComponentMap::new(),
+            component_table: ComponentTable::new(),
+            next_gameobject_id: 0,
+
+            dynamic_layouts: IdMap::with_hasher(NoOpHasherState),
+        }
+    }
+    /// Initializes [`ComponentStorage`] with specified capacity for both `GameObject`s and `Component` types.
+    ///
+    /// If you are unsure of one of the capacities, pass 0 to it.
+    ///
+    pub fn with_capacity(
+        gameobject_capacity: usize,
+        component_capacity: usize,
+    ) -> ComponentStorage {
+        ComponentStorage {
+            component_map: ComponentMap::with_capacity(component_capacity),
+            component_table: ComponentTable::with_capacity(gameobject_capacity, component_capacity),
+            next_gameobject_id: 0,
+
+            dynamic_layouts: IdMap::with_hasher(NoOpHasherState),
+        }
+    }
+
+    /// Inserts new `GameObject` without components and returns its id.
+    ///
+    pub fn insert_gameobject(&mut self) -> GameObjectId {
+        let gameobject_id: GameObjectId = GameObjectId::new(self.next_gameobject_id);
+        self.next_gameobject_id += 1;
+        self.component_table.insert_gameobject(gameobject_id);
+        gameobject_id
+    }
+    /// Removes `GameObject` with all its components and returns whether it was present.
+    ///
+    pub fn remove_gameobject(&mut self, gameobject_id: GameObjectId) -> bool {
+        let is_present: bool = self.contains_gameobject(gameobject_id);
+        self.component_table.remove_gameobject(gameobject_id);
+        is_present
+    }
+    /// Returns whether `GameObject` with given id is present.
+    ///
+    pub fn contains_gameobject(&self, gameobject_id: GameObjectId) -> bool {
+        self.component_table.has_gameobject(gameobject_id)
+    }
+    /// Returns iterator over ids of all present `GameObject`s.
+    ///
+    pub fn gameobjects(&self) -> impl Iterator<Item = GameObjectId> + '_ {
+        self.component_table.gameobjects()
+    }
+    /// Returns the number of present `GameObject`s.
+    ///
+    pub fn gameobject_count(&self) -> usize {
+        self.component_table.gameobject_count()
+    }
+
+    /// Reserves capacity for at least `additional` more `GameObject`s.
+    ///
+    /// Loading of large levels should reserve capacity beforehand (together with `reserve_component`),
+    /// so that storage is not reallocated many times during the load.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::gamecore::{components::Component, ComponentStorage};
+    /// struct Tile(u16);
+    /// impl Component for Tile {}
+    ///
+    /// let mut storage: ComponentStorage = ComponentStorage::new();
+    /// storage.reserve_gameobjects(4096);
+    /// storage.reserve_component::<Tile>(4096);
+    /// assert!(storage.gameobject_capacity() >= 4096);
+    /// assert!(storage.component_capacity::<Tile>() >= 4096);
+    /// ```
+    ///
+    pub fn reserve_gameobjects(&mut self, additional: usize) {
+        self.component_table.reserve_gameobjects(additional);
+    }
+    /// Reserves capacity of components of given type for at least `additional` more `GameObject`s
+    /// than storage currently holds.
+    ///
+    pub fn reserve_component<T: Component>(&mut self, additional: usize) {
+        let component_id: ComponentId = self.component_map.get_or_insert::<T>();
+        self.component_table.insert_component(component_id);
+        self.component_table
+            .reserve_component(component_id, additional);
+    }
+    /// Returns the number of `GameObject`s the storage can hold without reallocating.
+    ///
+    pub fn gameobject_capacity(&self) -> usize {
+        self.component_table.gameobject_capacity()
+    }
+    /// Returns the number of `GameObject`s that can have component of given type
+    /// without reallocating its column.
+    ///
+    pub fn component_capacity<T: Component>(&self) -> usize {
+        self.component_map.get::<T>().map_or(0, |component_id| {
+            self.component_table.column_capacity(component_id)
+        })
+    }
+
+    /// Inserts component to the `GameObject`.
+    ///
+    /// If `GameObject` already had a component of this type, it is replaced and old value is returned.
+    /// If `GameObject` is not present, component is dropped.
+    ///
+    pub fn insert_component<T: Component>(
+        &mut self,
+        gameobject_id: GameObjectId,
+        component: T,
+    ) -> Option<T> {
+        let component_id: ComponentId = self.component_map.get_or_insert::<T>();
+        self.component_table.insert_component(component_id);
+        let previous: Option<BoxedComponent> = self
+            .component_table
+            .take_gameobject_component(gameobject_id, component_id);
+        self.component_table.add_component_to_gameobject(
+            component_id,
+            Box::new(component),
+            gameobject_id,
+        );
+        previous.map(|boxed_component| {
+            *(boxed_component
+                .as_any_box()
+                .downcast::<T>()
+                .expect("This type's id corresponds to this value."))
+        })
+    }
+    /// Inserts components of one type to many `GameObject`s at once, replacing components of the same type,
+    /// and returns the number of inserted components.
+    ///
+    /// This is faster than inserting components one by one (e.g. when spawning lots of particles or projectiles),
+    /// since type of components is resolved once and its column is grown only once.
+    /// Components for `GameObject`s that are not present are dropped;
+    /// if there are several components for one `GameObject`, the last one is kept.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::gamecore::{components::Component, identifiers::GameObjectId, ComponentStorage};
+    /// struct Lifetime(f32);
+    /// impl Component for Lifetime {}
+    ///
+    /// let mut storage: ComponentStorage = ComponentStorage::new();
+    /// let particles: Vec<GameObjectId> = (0..100).map(|_| storage.insert_gameobject()).collect();
+    ///
+    /// let inserted: usize = storage.insert_components_batch(
+    ///     particles.iter().map(|&particle| (particle, Lifetime(1.5))),
+    /// );
+    /// assert_eq!(inserted, 100);
+    /// assert_eq!(storage.components::<Lifetime>().count(), 100);
+    /// ```
+    ///
+    pub fn insert_components_batch<T: Component>(
+        &mut self,
+        components: impl IntoIterator<Item = (GameObjectId, T)>,
+    ) -> usize {
+        let component_id: ComponentId = self.component_map.get_or_insert::<T>();
+        self.component_table.insert_component(component_id);
+        self.component_table.add_components_to_gameobjects(
+            component_id,
+            components.into_iter().map(|(gameobject_id, component)| {
+                let boxed_component: BoxedComponent = Box::new(component);
+                (gameobject_id, boxed_component)
+            }),
+        )
+    }
+    /// Inserts all components of a bundle to the `GameObject`, replacing components of the same types.
+    ///
+    /// Returns whether `GameObject` is present (if it is not, components are dropped).
+    ///
+    pub fn insert_components<B: Bundle>(&mut self, gameobject_id: GameObjectId, bundle: B) -> bool {
+        if !self.contains_gameobject(gameobject_id) {
+            return false;
+        }
+        for component in bundle.components() {
+            let component_id: ComponentId = self
+                .component_map
+                .get_or_insert_by_type_id((*component).as_any_ref().type_id());
+            self.component_table.insert_component(component_id);
+            self.component_table.add_component_to_gameobject(
+                component_id,
+                component,
+                gameobject_id,
+            );
+        }
+        true
+    }
+    /// Removes component of given type from the `GameObject` and returns it, if it exists.
+    ///
+    pub fn remove_component<T: Component>(&mut self, gameobject_id: GameObjectId) -> Option<T> {
+        let component_id: ComponentId = self.component_map.get::<T>()?;
+        self.component_table
+            .take_gameobject_component(gameobject_id, component_id)
+            .map(|boxed_component| {
+                *(boxed_component
+                    .as_any_box()
+                    .downcast::<T>()
+                    .expect("This type's id corresponds to this value."))
+            })
+    }
+    /// Returns whether `GameObject` has component of given type.
+    ///
+    pub fn contains_component<T: Component>(&self, gameobject_id: GameObjectId) -> bool {
+        self.get_component::<T>(gameobject_id).is_some()
+    }
+
+    /// Gets a reference to the component of given type that belongs to the `GameObject`, if it exists.
+    ///
+    pub fn get_component<T: Component>(&self, gameobject_id: GameObjectId) -> Option<&T> {
+        let component_id: ComponentId = self.component_map.get::<T>()?;
+        let boxed_component: &BoxedComponent = self
+            .component_table
+            .get_gameobject_component(gameobject_id, component_id)?
+            .as_ref()?;
+        (**boxed_component).as_any_ref().downcast_ref::<T>()
+    }
+    /// Gets a mutable reference to the component of given type that belongs to the `GameObject`, if it exists.
+    ///
+    pub fn get_component_mut<T: Component>(
+        &mut self,
+        gameobject_id: GameObjectId,
+    ) -> Option<&mut T> {
+        let component_id: ComponentId = self.component_map.get::<T>()?;
+        let boxed_component: &mut BoxedComponent = self
+            .component_table
+            .get_gameobject_component_mut(gameobject_id, component_id)?;
+        (**boxed_component).as_any_mut().downcast_mut::<T>()
+    }
+    /// Returns iterator over all components of given type together with ids of `GameObject`s that own them.
+    ///
+    pub fn components<T: Component>(&self) -> impl Iterator<Item = (GameObjectId, &T)> {
+        let component_table: &ComponentTable = &self.component_table;
+        self.component_map
+            .get::<T>()
+            .map(|component_id| component_table.iter_component(component_id))
+            .into_iter()
+            .flatten()
+            .map(|(gameobject_id, boxed_component)| {
+                (
+                    gameobject_id,
+                    (**boxed_component)
+                        .as_any_ref()
+                        .downcast_ref::<T>()
+                        .expect("This type's id corresponds to this value."),
+                )
+            })
+    }
+    /// Returns iterator over all components of given type together with ids of `GameObject`s that own them,
+    /// allowing to modify those components.
+    ///
+    pub fn components_mut<T: Component>(&mut self) -> impl Iterator<Item = (GameObjectId, &mut T)> {
+        let component_table: &mut ComponentTable = &mut self.component_table;
+        self.component_map
+            .get::<T>()
+            .map(|component_id| component_table.iter_component_mut(component_id))
+            .into_iter()
+            .flatten()
+            .map(|(gameobject_id, boxed_component)| {
+                (
+                    gameobject_id,
+                    (**boxed_component)
+                        .as_any_mut()
+                        .downcast_mut::<T>()
+                        .expect("This type's id corresponds to this value."),
+                )
+            })
+    }
+
+    /// Registers component layout at runtime and returns `ComponentId` that is assigned to it.
+    /// If layout with the same name was already registered, returns its `ComponentId`.
+    ///
+    /// Ids of runtime components are assigned from `u64::MAX` downwards,
+    /// so that they do not collide with ids of Rust types.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::gamecore::{components::ComponentLayout, identifiers::{ComponentId, GameObjectId}, ComponentStorage};
+    /// let mut storage: ComponentStorage = ComponentStorage::new();
+    /// let mana: ComponentId = storage.register_component_layout(ComponentLayout::new("Mana", 4));
+    /// assert_eq!(storage.component_id_by_name("Mana"), Some(mana));
+    ///
+    /// let wizard: GameObjectId = storage.insert_gameobject();
+    /// storage
+    ///     .insert_component_by_id(wizard, mana, 100u32.to_le_bytes().to_vec())
+    ///     .expect("Data has size of a layout");
+    /// storage.get_component_by_id_mut(wizard, mana).expect("Component was inserted")[0] = 50;
+    /// assert_eq!(storage.get_component_by_id(wizard, mana), Some(&50u32.to_le_bytes()[..]));
+    /// ```
+    ///
+    pub fn register_component_layout(&mut self, layout: ComponentLayout) -> ComponentId {
+        if let Some(component_id) = self.component_id_by_name(&layout.name) {
+            return component_id;
+        }
+        let component_id: ComponentId =
+            ComponentId::new(u64::MAX - self.dynamic_layouts.len() as u64);
+        self.component_table.insert_component(component_id);
+        let _ = self.dynamic_layouts.insert(component_id, layout);
+        component_id
+    }
+    /// Returns `ComponentId` of component layout with given name, if it was registered.
+    ///
+    pub fn component_id_by_name(&self, name: &str) -> Option<ComponentId> {
+        self.dynamic_layouts
+            .iter()
+            .find(|(_, layout)| layout.name == name)
+            .map(|(component_id, _)| *component_id)
+    }
+    /// Returns component layout that corresponds to given `ComponentId`, if it was registered.
+    ///
+    pub fn component_layout(&self, component_id: ComponentId) -> Option<&ComponentLayout> {
+        self.dynamic_layouts.get(&component_id)
+    }
+
+    /// Inserts runtime component with given data to the `GameObject`
+    /// (this function is a counterpart of `insert_component` - read [`ComponentStorage`] docs).
+    ///
+    /// If `GameObject` already had this component, it is replaced and old data is returned.
+    /// Error is returned if layout is not registered or if size of data does not match layout.
+    ///
+    pub fn insert_component_by_id(
+        &mut self,
+        gameobject_id: GameObjectId,
+        component_id: ComponentId,
+        data: Vec<u8>,
+    ) -> Result<Option<Box<[u8]>>, GGError> {
+        let layout: &ComponentLayout =
+            self.dynamic_layouts.get(&component_id).ok_or_else(|| {
+                GGError::InvalidArgument(format!("{component_id:?} is not a registered layout"))
+            })?;
+        if data.len() != layout.size {
+            return Err(GGError::InvalidArgument(format!(
+                "Component {} has size of {} bytes, but {} bytes were given",
+                layout.name,
+                layout.size,
+                data.len()
+            )));
+        }
+        let previous: Option<Box<[u8]>> = self.remove_component_by_id(gameobject_id, component_id);
+        self.component_table.add_component_to_gameobject(
+            component_id,
+            Box::new(DynamicComponent(data.into_boxed_slice())),
+            gameobject_id,
+        );
+        Ok(previous)
+    }
+    /// Removes runtime component from the `GameObject` and returns its data, if it exists
+    /// (this function is a counterpart of `remove_component` - read [`ComponentStorage`] docs).
+    ///
+    pub fn remove_component_by_id(
+        &mut self,
+        gameobject_id: GameObjectId,
+        component_id: ComponentId,
+    ) -> Option<Box<[u8]>> {
+        let boxed_component: BoxedComponent = self
+            .component_table
+            .take_gameobject_component(gameobject_id, component_id)?;
+        Some(
+            boxed_component
+                .as_any_box()
+                .downcast::<DynamicComponent>()
+                .expect("Runtime components are stored as `DynamicComponent`.")
+                .0,
+        )
+    }
+    /// Gets data of runtime component that belongs to the `GameObject`, if it exists
+    /// (this function is a counterpart of `get_component` - read [`ComponentStorage`] docs).
+    ///
+    pub fn get_component_by_id(
+        &self,
+        gameobject_id: GameObjectId,
+        component_id: ComponentId,
+    ) -> Option<&[u8]> {
+        if !self.dynamic_layouts.contains_key(&component_id) {
+            return None;
+        }
+        let boxed_component: &BoxedComponent = self
+            .component_table
+            .get_gameobject_component(gameobject_id, component_id)?
+            .as_ref()?;
+        (**boxed_component)
+            .as_any_ref()
+            .downcast_ref::<DynamicComponent>()
+            .map(|component| &*component.0)
+    }
+    /// Gets mutable data of runtime component that belongs to the `GameObject`, if it exists
+    /// (this function is a counterpart of `get_component_mut` - read [`ComponentStorage`] docs).
+    ///
+    /// Size of data is fixed by layout, so only the contents of data can be changed.
+    ///
+    pub fn get_component_by_id_mut(
+        &mut self,
+        gameobject_id: GameObjectId,
+        component_id: ComponentId,
+    ) -> Option<&mut [u8]> {
+        if !self.dynamic_layouts.contains_key(&component_id) {
+            return None;
+        }
+        let boxed_component: &mut BoxedComponent = self
+            .component_table
+            .get_gameobject_component_mut(gameobject_id, component_id)?;
+        (**boxed_component)
+            .as_any_mut()
+            .downcast_mut::<DynamicComponent>()
+            .map(|component| &mut *component.0)
+    }
+    /// Clones runtime component from one `GameObject` to another by the clone function of its layout
+    /// and returns whether it was cloned.
+    ///
+    pub fn clone_component_by_id(
+        &mut self,
+        from: GameObjectId,
+        to: GameObjectId,
+        component_id: ComponentId,
+    ) -> bool {
+        let Some(layout) = self.dynamic_layouts.get(&component_id) else {
+            return false;
+        };
+        let Some(data) = self.get_component_by_id(from, component_id) else {
+            return false;
+        };
+        let data: Vec<u8> = (layout.clone)(data);
+        self.contains_gameobject(to) && self.insert_component_by_id(to, component_id, data).is_ok()
+    }
+    /// Serializes runtime component of the `GameObject` by the serialize function of its layout,
+    /// if component exists.
+    ///
+    pub fn serialize_component_by_id(
+        &self,
+        gameobject_id: GameObjectId,
+        component_id: ComponentId,
+    ) -> Option<ScriptValue> {
+        let layout: &ComponentLayout = self.dynamic_layouts.get(&component_id)?;
+        Some((layout.serialize)(
+            self.get_component_by_id(gameobject_id, component_id)?,
+        ))
+    }
+}
+
 /// [`ResourceMap`] struct handles `Resource` initialization by binding specific `TypeId`s to exact `ResourceId`.
 /// This approach allows for describing `Resource`s as Rust types.
 ///
@@ -652,6 +1469,157 @@ impl ResourceStorage {
     }
 }
 
+/// [`SystemStorage`] struct stores labeled systems in order in which they should be run.
+///
+/// System is any closure that operates on a [`Scene`]; labels allow replacing or removing systems later.
+/// Storage can also hold whole [`Schedule`]s as its nodes - those allow features to ship
+/// self-contained groups of ordered systems, which are inserted and removed as a single unit.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::{components::Resource, scenes::Scene, schedules::Schedule, SystemStorage};
+/// struct Frames(u32);
+/// impl Resource for Frames {}
+///
+/// let mut storage: SystemStorage = SystemStorage::new();
+/// let _ = storage.insert_system("count", |scene: &mut Scene| {
+///     scene.resources_mut().get_resource_or_insert_with(|| Frames(0)).0 += 1;
+/// });
+///
+/// let mut feature: Schedule = Schedule::new();
+/// feature.add_system("double", |scene: &mut Scene| {
+///     scene.resources_mut().get_resource_mut::<Frames>().expect("Resource was inserted").0 *= 2;
+/// });
+/// let _ = storage.insert_schedule("feature", feature);
+///
+/// let mut scene: Scene = Scene::new();
+/// storage.run_systems(&mut scene);
+/// storage.run_systems(&mut scene);
+/// assert_eq!(scene.resources().get_resource::<Frames>().map(|frames| frames.0), Some(6));
+/// ```
+///
+#[derive(Default)]
+pub struct SystemStorage {
+    /// Labeled nodes in order of their execution.
+    ///
+    systems: Vec<(String, SystemNode)>,
+}
+impl SystemStorage {
+    /// Initializes new [`SystemStorage`].
+    ///
+    /// Created [`SystemStorage`] will not allocate until first insertions.
+    ///
+    pub fn new() -> SystemStorage {
+        SystemStorage {
+            systems: Vec::new(),
+        }
+    }
+
+    /// Inserts node with given label.
+    ///
+    /// New nodes are run after all previously inserted ones;
+    /// if there is a node with the same label, it is replaced in place and returned.
+    ///
+    pub fn insert_node(&mut self, label: &str, node: SystemNode) -> Option<SystemNode> {
+        match self.systems.iter_mut().find(|(name, _)| name == label) {
+            Some((_, place)) => Some(std::mem::replace(place, node)),
+            None => {
+                self.systems.push((String::from(label), node));
+                None
+            }
+        }
+    }
+    /// Inserts system with given label
+    /// (this function is a shorthand for `insert_node` with `SystemNode::System`).
+    ///
+    pub fn insert_system(
+        &mut self,
+        label: &str,
+        system: impl FnMut(&mut Scene) + 'static,
+    ) -> Option<SystemNode> {
+        self.insert_node(label, SystemNode::System(Box::new(system)))
+    }
+    /// Inserts nested schedule with given label
+    /// (this function is a shorthand for `insert_node` with `SystemNode::Schedule`).
+    ///
+    pub fn insert_schedule(&mut self, label: &str, schedule: Schedule) -> Option<SystemNode> {
+        self.insert_node(label, SystemNode::Schedule(schedule))
+    }
+    /// Removes node with given label and returns it, if it exists.
+    ///
+    /// Nested schedule is removed with all its systems.
+    ///
+    pub fn remove_system(&mut self, label: &str) -> Option<SystemNode> {
+        let index: usize = self.systems.iter().position(|(name, _)| name == label)?;
+        Some(self.systems.remove(index).1)
+    }
+    /// Returns whether node with given label exists.
+    ///
+    pub fn contains_system(&self, label: &str) -> bool {
+        self.systems.iter().any(|(name, _)| name == label)
+    }
+    /// Returns mutable reference to the nested schedule with given label, if it exists.
+    ///
+    pub fn get_schedule_mut(&mut self, label: &str) -> Option<&mut Schedule> {
+        self.systems.iter_mut().find_map(|(name, node)| match node {
+            SystemNode::Schedule(schedule) if name == label => Some(schedule),
+            _ => None,
+        })
+    }
+    /// Returns iterator over labels of nodes in order of their execution.
+    ///
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.systems.iter().map(|(name, _)| name.as_str())
+    }
+    /// Returns the number of nodes.
+    ///
+    pub fn len(&self) -> usize {
+        self.systems.len()
+    }
+    /// Returns `true` if there are no nodes, otherwise `false`.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.systems.is_empty()
+    }
+
+    /// Runs node with given label and returns whether it exists.
+    ///
+    pub fn run_system(&mut self, label: &str, scene: &mut Scene) -> bool {
+        match self.systems.iter_mut().find(|(name, _)| name == label) {
+            Some((_, node)) => {
+                node.run(scene);
+                true
+            }
+            None => false,
+        }
+    }
+    /// Runs all nodes in order.
+    ///
+    /// Nested schedules run their per-frame systems.
+    ///
+    pub fn run_systems(&mut self, scene: &mut Scene) {
+        for (_, node) in self.systems.iter_mut() {
+            node.run(scene);
+        }
+    }
+    /// Runs startup systems of all nested schedules in order.
+    ///
+    pub fn run_nested_startup(&mut self, scene: &mut Scene) {
+        for (_, node) in self.systems.iter_mut() {
+            if let SystemNode::Schedule(schedule) = node {
+                schedule.run_startup(scene);
+            }
+        }
+    }
+}
+impl fmt::Debug for SystemStorage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SystemStorage")
+            .field("systems", &self.systems)
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::gamecore::components::{Component, Resource};
@@ -833,6 +1801,187 @@ mod tests {
             .is_none());
     }
 
+    #[test]
+    fn column() {
+        use super::{BoxedComponent, Column};
+        use std::ops::Deref;
+
+        let mut column: Column = Column::with_capacity(0);
+        for row in [130, 0, 63, 64] {
+            assert!(column.insert(row, Box::new(row as u8)).is_none());
+        }
+        assert!(column.insert(63, Box::new(0u8)).is_some());
+        assert!(column.remove(0).is_some());
+        assert!(column.remove(1000).is_none());
+
+        for (_, component) in column.iter_mut() {
+            let component: &mut u8 = (**component)
+                .as_any_mut()
+                .downcast_mut::<u8>()
+                .expect("u8 was packed.");
+            *component += 1;
+        }
+        let components: Vec<(usize, u8)> = column
+            .iter()
+            .map(|(row, component): (usize, &BoxedComponent)| {
+                (
+                    row,
+                    *component
+                        .deref()
+                        .as_any_ref()
+                        .downcast_ref::<u8>()
+                        .expect("u8 was packed."),
+                )
+            })
+            .collect();
+        assert_eq!(components, [(63, 1), (64, 65), (130, 131)]);
+    }
+
+    #[test]
+    fn component_storage() {
+        use super::ComponentStorage;
+        use crate::gamecore::identifiers::GameObjectId;
+
+        let mut component_storage: ComponentStorage = ComponentStorage::new();
+        let gameobject_id0: GameObjectId = component_storage.insert_gameobject();
+        let gameobject_id1: GameObjectId = component_storage.insert_gameobject();
+
+        assert!(component_storage.insert_components(gameobject_id0, (0u8, 0i8)));
+        assert!(component_storage
+            .insert_component(gameobject_id1, 1u8)
+            .is_none());
+        assert_eq!(
+            component_storage.insert_component(gameobject_id1, 2u8),
+            Some(1u8)
+        );
+        assert!(!component_storage.contains_component::<i8>(gameobject_id1));
+
+        for (_, component) in component_storage.components_mut::<u8>() {
+            *component += 1;
+        }
+        assert_eq!(
+            component_storage
+                .components::<u8>()
+                .collect::<Vec<(GameObjectId, &u8)>>(),
+            [(gameobject_id0, &1u8), (gameobject_id1, &3u8)]
+        );
+
+        assert!(component_storage.remove_gameobject(gameobject_id0));
+        assert!(!component_storage.remove_gameobject(gameobject_id0));
+        assert!(!component_storage.insert_components(gameobject_id0, 0u8));
+        assert_eq!(component_storage.components::<i8>().count(), 0);
+
+        let gameobject_id2: GameObjectId = component_storage.insert_gameobject();
+        assert_ne!(gameobject_id2, gameobject_id0);
+        assert!(component_storage
+            .get_component::<u8>(gameobject_id2)
+            .is_none());
+        assert_eq!(
+            component_storage
+                .gameobjects()
+                .collect::<Vec<GameObjectId>>(),
+            [gameobject_id2, gameobject_id1]
+        );
+        assert_eq!(
+            component_storage.remove_component::<u8>(gameobject_id1),
+            Some(3u8)
+        );
+        assert_eq!(component_storage.components::<u8>().count(), 0);
+    }
+
+    #[test]
+    fn batch_insertion() {
+        use super::ComponentStorage;
+        use crate::gamecore::identifiers::GameObjectId;
+
+        let mut component_storage: ComponentStorage = ComponentStorage::new();
+        let gameobjects: Vec<GameObjectId> = (0..100)
+            .map(|_| component_storage.insert_gameobject())
+            .collect();
+        assert!(component_storage.remove_gameobject(gameobjects[1]));
+        assert!(component_storage
+            .insert_component(gameobjects[0], 200u8)
+            .is_none());
+
+        let inserted: usize = component_storage.insert_components_batch(
+            gameobjects
+                .iter()
+                .rev()
+                .enumerate()
+                .map(|(index, &gameobject_id)| (gameobject_id, index as u8))
+                .chain([(gameobjects[50], 0u8)]),
+        );
+        assert_eq!(inserted, 100);
+        assert_eq!(component_storage.components::<u8>().count(), 99);
+        assert_eq!(
+            component_storage.get_component::<u8>(gameobjects[0]),
+            Some(&99u8)
+        );
+        assert_eq!(
+            component_storage.get_component::<u8>(gameobjects[50]),
+            Some(&0u8)
+        );
+        assert!(component_storage
+            .get_component::<u8>(gameobjects[1])
+            .is_none());
+    }
+
+    #[test]
+    fn dynamic_components() {
+        use super::ComponentStorage;
+        use crate::gamecore::{
+            components::ComponentLayout,
+            identifiers::{ComponentId, GameObjectId},
+            scripting::ScriptValue,
+        };
+
+        let mut component_storage: ComponentStorage = ComponentStorage::new();
+        let typed_id: ComponentId = {
+            let gameobject_id: GameObjectId = component_storage.insert_gameobject();
+            let _ = component_storage.insert_component(gameobject_id, 0u8);
+            ComponentId::new(0)
+        };
+        let dynamic_id: ComponentId =
+            component_storage.register_component_layout(ComponentLayout::new("Pair", 2));
+        assert_ne!(dynamic_id, typed_id);
+        assert_eq!(
+            component_storage.register_component_layout(ComponentLayout::new("Pair", 8)),
+            dynamic_id
+        );
+
+        let from: GameObjectId = component_storage.insert_gameobject();
+        let to: GameObjectId = component_storage.insert_gameobject();
+        assert!(component_storage
+            .insert_component_by_id(from, dynamic_id, vec![1, 2, 3])
+            .is_err());
+        assert!(component_storage
+            .insert_component_by_id(from, typed_id, vec![1])
+            .is_err());
+        assert!(component_storage
+            .insert_component_by_id(from, dynamic_id, vec![1, 2])
+            .expect("Data has size of a layout")
+            .is_none());
+        assert!(component_storage
+            .get_component_by_id(from, typed_id)
+            .is_none());
+
+        assert!(component_storage.clone_component_by_id(from, to, dynamic_id));
+        assert_eq!(
+            component_storage.serialize_component_by_id(to, dynamic_id),
+            Some(ScriptValue::Bytes(vec![1, 2]))
+        );
+        assert_eq!(
+            component_storage
+                .remove_component_by_id(from, dynamic_id)
+                .as_deref(),
+            Some(&[1u8, 2][..])
+        );
+        assert!(component_storage
+            .get_component_by_id(from, dynamic_id)
+            .is_none());
+        assert!(!component_storage.clone_component_by_id(from, to, dynamic_id));
+    }
+
     #[test]
     fn resource_map() {
         use super::ResourceMap;
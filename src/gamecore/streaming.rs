@@ -0,0 +1,287 @@
+//! `gamecore::streaming` submodule implements [`ChunkStreamer`] - helper that loads and unloads
+//! chunks of a large world (tilemap chunks and their game objects) around the visible area.
+//!
+//! World is split into square chunks that are identified by their integer coordinates.
+//! Chunks near the visible area are loaded by the [`ChunkLoader`] of the game and chunks that are far
+//! from it are unloaded, so maps that do not fit into memory at once can be explored seamlessly.
+//!
+
+use crate::{
+    gamecore::{identifiers::GameObjectId, scenes::Scene},
+    mathcore::{shapes::Aabb, vectors::Point},
+};
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+/// [`ChunkLoader`] trait is implemented by games to load content of chunks.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::{components::Component, identifiers::GameObjectId, scenes::Scene, streaming::ChunkLoader};
+/// struct Tile(u8);
+/// impl Component for Tile {}
+///
+/// struct Terrain;
+/// impl ChunkLoader for Terrain {
+///     fn load_chunk(&mut self, chunk: (i32, i32), scene: &mut Scene) -> Vec<GameObjectId> {
+///         // tiles would usually be read from the file of the chunk
+///         (0..16).map(|tile| scene.spawn(Tile((chunk.0 + chunk.1 + tile) as u8))).collect()
+///     }
+/// }
+/// ```
+///
+pub trait ChunkLoader {
+    /// Loads chunk by spawning its game objects and returns their ids.
+    ///
+    fn load_chunk(&mut self, chunk: (i32, i32), scene: &mut Scene) -> Vec<GameObjectId>;
+    /// Is called before game objects of the chunk are despawned (e.g. to save their state).
+    ///
+    /// Default implementation does nothing.
+    ///
+    fn unload_chunk(&mut self, chunk: (i32, i32), scene: &mut Scene) {
+        let _ = (chunk, scene);
+    }
+}
+
+/// [`StreamingStats`] struct describes work of the [`ChunkStreamer`] during one update.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StreamingStats {
+    /// Number of chunks that were loaded.
+    ///
+    pub loaded: usize,
+    /// Number of chunks that were unloaded.
+    ///
+    pub unloaded: usize,
+    /// Number of chunks that should be loaded, but did not fit into the budget.
+    ///
+    pub pending: usize,
+}
+
+/// [`ChunkStreamer`] struct loads chunks around the visible area and unloads chunks that are far from it.
+///
+/// Chunks are loaded when they are closer than `load_margin` to the visible area and are unloaded
+/// when they are farther than `unload_margin` from it. Since unload margin is larger, chunks on the border
+/// are not reloaded every time camera moves back and forth (hysteresis).
+///
+/// Chunks are loaded in order of their distance to the visible area until loading budget of the frame
+/// is exhausted, and the rest is loaded during next frames.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::{identifiers::GameObjectId, scenes::Scene, streaming::{ChunkLoader, ChunkStreamer, StreamingStats}};
+/// # use ggengine::mathcore::{shapes::Aabb, vectors::Point};
+/// struct Empty;
+/// impl ChunkLoader for Empty {
+///     fn load_chunk(&mut self, _: (i32, i32), scene: &mut Scene) -> Vec<GameObjectId> {
+///         vec![scene.spawn(())]
+///     }
+/// }
+///
+/// let mut scene: Scene = Scene::new();
+/// let mut streamer: ChunkStreamer = ChunkStreamer::new(256.0);
+/// let view: Aabb = Aabb::new(Point::from([0.0, 0.0]), Point::from([100.0, 100.0]));
+/// let stats: StreamingStats = streamer.update(&mut scene, view, &mut Empty);
+/// assert_eq!(stats.loaded + stats.pending, 4);
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct ChunkStreamer {
+    /// Size of a side of square chunk in world coordinates.
+    ///
+    pub chunk_size: f32,
+    /// Distance from the visible area at which chunks are loaded.
+    ///
+    pub load_margin: f32,
+    /// Distance from the visible area at which chunks are unloaded (it should not be less than `load_margin`).
+    ///
+    pub unload_margin: f32,
+    /// Time that can be spent on loading of chunks during one update (at least one chunk is always loaded).
+    ///
+    pub budget: Duration,
+
+    /// Loaded chunks and their game objects.
+    ///
+    loaded: BTreeMap<(i32, i32), Vec<GameObjectId>>,
+}
+impl ChunkStreamer {
+    /// Initializes [`ChunkStreamer`] with given size of chunks.
+    ///
+    /// Chunks are loaded within half of chunk from the visible area and are unloaded
+    /// farther than one and a half of chunk; loading budget is 2 milliseconds.
+    ///
+    pub fn new(chunk_size: f32) -> Self {
+        ChunkStreamer {
+            chunk_size,
+            load_margin: chunk_size * 0.5,
+            unload_margin: chunk_size * 1.5,
+            budget: Duration::from_millis(2),
+
+            loaded: BTreeMap::new(),
+        }
+    }
+
+    /// Returns chunk that contains given point.
+    ///
+    pub fn chunk_of(&self, point: Point) -> (i32, i32) {
+        (
+            (point.x / self.chunk_size).floor() as i32,
+            (point.y / self.chunk_size).floor() as i32,
+        )
+    }
+    /// Returns area of the chunk in world coordinates.
+    ///
+    pub fn chunk_bounds(&self, chunk: (i32, i32)) -> Aabb {
+        let min: Point = Point::from([
+            chunk.0 as f32 * self.chunk_size,
+            chunk.1 as f32 * self.chunk_size,
+        ]);
+        Aabb::new(min, min + self.chunk_size)
+    }
+    /// Returns whether chunk is loaded.
+    ///
+    pub fn is_loaded(&self, chunk: (i32, i32)) -> bool {
+        self.loaded.contains_key(&chunk)
+    }
+    /// Returns loaded chunks.
+    ///
+    pub fn loaded_chunks(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        self.loaded.keys().copied()
+    }
+    /// Returns game objects of the loaded chunk.
+    ///
+    pub fn chunk_gameobjects(&self, chunk: (i32, i32)) -> &[GameObjectId] {
+        self.loaded.get(&chunk).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns chunks that are not loaded, but should be loaded for given visible area
+    /// (the closest chunks go first).
+    ///
+    fn missing_chunks(&self, view: Aabb) -> Vec<(i32, i32)> {
+        let area: Aabb = view.expand(self.load_margin);
+        let (min, max): ((i32, i32), (i32, i32)) =
+            (self.chunk_of(area.min), self.chunk_of(area.max));
+        let mut missing: Vec<((i32, i32), f32)> = (min.0..=max.0)
+            .flat_map(|x| (min.1..=max.1).map(move |y| (x, y)))
+            .filter(|chunk| !self.is_loaded(*chunk))
+            .map(|chunk| {
+                (
+                    chunk,
+                    (self.chunk_bounds(chunk).center() - view.center()).magnitude(),
+                )
+            })
+            .collect();
+        missing.sort_by(|(_, first), (_, second)| first.total_cmp(second));
+        missing.into_iter().map(|(chunk, _)| chunk).collect()
+    }
+
+    /// Unloads chunks that are far from the visible area and loads chunks that are close to it within the budget.
+    ///
+    /// Visible area is usually obtained by `Camera2D::view_rect`.
+    ///
+    pub fn update(
+        &mut self,
+        scene: &mut Scene,
+        view: Aabb,
+        loader: &mut impl ChunkLoader,
+    ) -> StreamingStats {
+        let mut stats: StreamingStats = StreamingStats::default();
+
+        let keep: Aabb = view.expand(self.unload_margin.max(self.load_margin));
+        let far: Vec<(i32, i32)> = self
+            .loaded_chunks()
+            .filter(|&chunk| !self.chunk_bounds(chunk).intersects(keep))
+            .collect();
+        for chunk in far {
+            self.unload(chunk, scene, loader);
+            stats.unloaded += 1;
+        }
+
+        let start: Instant = Instant::now();
+        let missing: Vec<(i32, i32)> = self.missing_chunks(view);
+        for (index, &chunk) in missing.iter().enumerate() {
+            if index > 0 && start.elapsed() >= self.budget {
+                stats.pending = missing.len() - index;
+                break;
+            }
+            let gameobjects: Vec<GameObjectId> = loader.load_chunk(chunk, scene);
+            let _ = self.loaded.insert(chunk, gameobjects);
+            stats.loaded += 1;
+        }
+        stats
+    }
+    /// Unloads all chunks.
+    ///
+    pub fn unload_all(&mut self, scene: &mut Scene, loader: &mut impl ChunkLoader) {
+        let chunks: Vec<(i32, i32)> = self.loaded_chunks().collect();
+        for chunk in chunks {
+            self.unload(chunk, scene, loader);
+        }
+    }
+    /// Unloads the chunk and despawns its game objects.
+    ///
+    fn unload(&mut self, chunk: (i32, i32), scene: &mut Scene, loader: &mut impl ChunkLoader) {
+        loader.unload_chunk(chunk, scene);
+        for gameobject_id in self.loaded.remove(&chunk).unwrap_or_default() {
+            let _ = scene.despawn(gameobject_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChunkLoader, ChunkStreamer, StreamingStats};
+    use crate::{
+        gamecore::{identifiers::GameObjectId, scenes::Scene},
+        mathcore::{shapes::Aabb, vectors::Point},
+    };
+    use std::time::Duration;
+
+    struct Counter {
+        unloaded: Vec<(i32, i32)>,
+    }
+    impl ChunkLoader for Counter {
+        fn load_chunk(&mut self, _: (i32, i32), scene: &mut Scene) -> Vec<GameObjectId> {
+            vec![scene.spawn(()), scene.spawn(())]
+        }
+        fn unload_chunk(&mut self, chunk: (i32, i32), _: &mut Scene) {
+            self.unloaded.push(chunk);
+        }
+    }
+
+    #[test]
+    fn chunk_streaming() {
+        let mut scene: Scene = Scene::new();
+        let mut loader: Counter = Counter {
+            unloaded: Vec::new(),
+        };
+        let mut streamer: ChunkStreamer = ChunkStreamer::new(10.0);
+        streamer.budget = Duration::ZERO;
+        assert_eq!(streamer.chunk_of(Point::from([-0.5, 25.0])), (-1, 2));
+
+        // view covers chunk (0, 0), load margin adds its neighbours
+        let view: Aabb = Aabb::new(Point::from([2.0, 2.0]), Point::from([8.0, 8.0]));
+        let stats: StreamingStats = streamer.update(&mut scene, view, &mut loader);
+        assert_eq!((stats.loaded, stats.pending), (1, 8));
+        assert!(streamer.is_loaded((0, 0)));
+        while streamer.update(&mut scene, view, &mut loader).pending > 0 {}
+        assert_eq!(streamer.loaded_chunks().count(), 9);
+        assert_eq!(streamer.chunk_gameobjects((1, 1)).len(), 2);
+
+        // chunks within unload margin are kept
+        let moved: Aabb = Aabb::new(Point::from([12.0, 2.0]), Point::from([18.0, 8.0]));
+        let stats: StreamingStats = streamer.update(&mut scene, moved, &mut loader);
+        assert_eq!((stats.loaded, stats.unloaded), (1, 0));
+
+        let far: Aabb = Aabb::new(Point::from([102.0, 2.0]), Point::from([108.0, 8.0]));
+        let stats: StreamingStats = streamer.update(&mut scene, far, &mut loader);
+        assert_eq!(stats.unloaded, 10);
+        assert_eq!(loader.unloaded.len(), stats.unloaded);
+
+        streamer.unload_all(&mut scene, &mut loader);
+        assert_eq!(streamer.loaded_chunks().count(), 0);
+        assert_eq!(scene.components().gameobjects().count(), 0);
+    }
+}
@@ -0,0 +1,563 @@
+//! `gamecore::time` submodule implements [`Time`] resource, which tracks game time,
+//! and [`Timer`] and [`Cooldown`] components, which are ticked by that time.
+//!
+//! Timers replace ad-hoc countdown fields: instead of decreasing `f32` every frame and
+//! checking whether it became negative, games tick timers by [`Time`] and ask whether they
+//! have just finished.
+//!
+//! Besides game time, [`Time`] tracks unscaled (real) time, which is not affected by
+//! time scale and pausing; animations and tweens choose between them by [`Clock`].
+//!
+
+use crate::gamecore::components::{Component, Resource};
+use std::time::{Duration, Instant};
+
+/// [`Time`] struct is a resource that tracks time of a game.
+///
+/// [`Time`] should be updated once per frame; all systems of that frame then use the same delta.
+/// Game time can be scaled (e.g. for slow motion) or paused, which affects everything that is ticked by it.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::time::Time;
+/// # use std::time::Duration;
+/// let mut time: Time = Time::new();
+/// time.time_scale = 0.5;
+/// time.update_with(Duration::from_millis(100));
+/// assert_eq!(time.delta(), Duration::from_millis(50));
+/// assert_eq!(time.unscaled_delta(), Duration::from_millis(100));
+/// assert_eq!(time.frame(), 1);
+/// ```
+///
+#[derive(Copy, Clone, Debug)]
+pub struct Time {
+    /// Scale of game time relative to real time.
+    ///
+    pub time_scale: f32,
+    /// Whether game time is paused.
+    ///
+    pub is_paused: bool,
+
+    /// Time of the last update.
+    ///
+    last_update: Option<Instant>,
+    /// Game time that elapsed during the last frame.
+    ///
+    delta: Duration,
+    /// Game time that elapsed since the start.
+    ///
+    elapsed: Duration,
+    /// Real time that elapsed during the last frame.
+    ///
+    unscaled_delta: Duration,
+    /// Real time that elapsed since the start.
+    ///
+    unscaled_elapsed: Duration,
+    /// Number of updates.
+    ///
+    frame: u64,
+}
+impl Time {
+    /// Constructs time with no elapsed time and time scale of 1.0.
+    ///
+    pub fn new() -> Self {
+        Time {
+            time_scale: 1.0,
+            is_paused: false,
+
+            last_update: None,
+            delta: Duration::ZERO,
+            elapsed: Duration::ZERO,
+            unscaled_delta: Duration::ZERO,
+            unscaled_elapsed: Duration::ZERO,
+            frame: 0,
+        }
+    }
+
+    /// Updates time by measuring real time that elapsed since the previous update.
+    ///
+    /// Delta of the first update is zero.
+    ///
+    pub fn update(&mut self) {
+        let now: Instant = Instant::now();
+        let real_delta: Duration = self
+            .last_update
+            .map_or(Duration::ZERO, |last_update| now - last_update);
+        self.update_with(real_delta);
+        self.last_update = Some(now);
+    }
+    /// Updates time by given real time delta.
+    ///
+    /// This function is useful for fixed timesteps and tests.
+    ///
+    pub fn update_with(&mut self, real_delta: Duration) {
+        self.delta = if self.is_paused {
+            Duration::ZERO
+        } else {
+            real_delta.mul_f64(f64::from(self.time_scale.max(0.0)))
+        };
+        self.elapsed += self.delta;
+        self.unscaled_delta = real_delta;
+        self.unscaled_elapsed += real_delta;
+        self.frame += 1;
+    }
+
+    /// Returns game time that elapsed during the last frame.
+    ///
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+    /// Returns game time that elapsed during the last frame in seconds.
+    ///
+    pub fn delta_seconds(&self) -> f32 {
+        self.delta.as_secs_f32()
+    }
+    /// Returns game time that elapsed since the start.
+    ///
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+    /// Returns real time that elapsed during the last frame (it is not affected by time scale and pausing).
+    ///
+    pub fn unscaled_delta(&self) -> Duration {
+        self.unscaled_delta
+    }
+    /// Returns real time that elapsed during the last frame in seconds.
+    ///
+    pub fn unscaled_delta_seconds(&self) -> f32 {
+        self.unscaled_delta.as_secs_f32()
+    }
+    /// Returns real time that elapsed since the start.
+    ///
+    pub fn unscaled_elapsed(&self) -> Duration {
+        self.unscaled_elapsed
+    }
+    /// Returns time that elapsed during the last frame by given clock.
+    ///
+    pub fn delta_by(&self, clock: Clock) -> Duration {
+        match clock {
+            Clock::Scaled => self.delta,
+            Clock::Unscaled => self.unscaled_delta,
+        }
+    }
+    /// Returns number of updates.
+    ///
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+}
+impl Default for Time {
+    fn default() -> Self {
+        Time::new()
+    }
+}
+impl Resource for Time {}
+
+/// [`Clock`] enum lists kinds of time by which animations and tweens can be advanced.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Clock {
+    /// Game time, which is scaled and paused with the game.
+    ///
+    #[default]
+    Scaled,
+    /// Real time, which keeps going during pause (e.g. for UI of pause menu).
+    ///
+    Unscaled,
+}
+
+/// [`TimerMode`] enum lists modes of [`Timer`].
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TimerMode {
+    /// Timer finishes once and stays finished until it is reset.
+    ///
+    #[default]
+    Once,
+    /// Timer restarts every time it finishes.
+    ///
+    Repeating,
+}
+
+/// [`Timer`] struct is a component that counts time up to given duration.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::time::{Timer, TimerMode};
+/// # use std::time::Duration;
+/// let mut spawner: Timer = Timer::new(Duration::from_secs(2), TimerMode::Repeating);
+///
+/// let _ = spawner.tick(Duration::from_millis(1500));
+/// assert!(!spawner.just_finished());
+///
+/// let _ = spawner.tick(Duration::from_millis(4500));
+/// assert!(spawner.just_finished());
+/// assert_eq!(spawner.times_finished_this_tick(), 3);
+/// assert_eq!(spawner.elapsed(), Duration::ZERO);
+/// ```
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Timer {
+    /// Duration after which timer finishes.
+    ///
+    duration: Duration,
+    /// Time that elapsed since timer was started.
+    ///
+    elapsed: Duration,
+    /// Mode of a timer.
+    ///
+    mode: TimerMode,
+
+    /// Whether timer is paused.
+    ///
+    is_paused: bool,
+    /// Whether timer has finished (for [`TimerMode::Repeating`] it is the same as `just_finished`).
+    ///
+    is_finished: bool,
+    /// Number of times timer has finished during the last tick.
+    ///
+    times_finished_this_tick: u32,
+}
+impl Timer {
+    /// Constructs timer with given duration and mode.
+    ///
+    pub fn new(duration: Duration, mode: TimerMode) -> Self {
+        Timer {
+            duration,
+            elapsed: Duration::ZERO,
+            mode,
+
+            is_paused: false,
+            is_finished: false,
+            times_finished_this_tick: 0,
+        }
+    }
+    /// Constructs timer with given duration in seconds and mode.
+    ///
+    pub fn from_seconds(seconds: f32, mode: TimerMode) -> Self {
+        Timer::new(Duration::from_secs_f32(seconds.max(0.0)), mode)
+    }
+
+    /// Advances timer by given delta.
+    ///
+    /// Paused timers and finished [`TimerMode::Once`] timers are not advanced.
+    ///
+    pub fn tick(&mut self, delta: Duration) -> &Self {
+        self.times_finished_this_tick = 0;
+        if self.is_paused {
+            return self;
+        }
+        if self.mode == TimerMode::Once && self.is_finished {
+            return self;
+        }
+        if self.mode == TimerMode::Repeating {
+            self.is_finished = false;
+        }
+
+        self.elapsed += delta;
+        if self.elapsed < self.duration {
+            return self;
+        }
+        self.is_finished = true;
+        match self.mode {
+            TimerMode::Once => {
+                self.elapsed = self.duration;
+                self.times_finished_this_tick = 1;
+            }
+            TimerMode::Repeating if self.duration.is_zero() => {
+                self.elapsed = Duration::ZERO;
+                self.times_finished_this_tick = 1;
+            }
+            TimerMode::Repeating => {
+                let times: u128 = self.elapsed.as_nanos() / self.duration.as_nanos();
+                self.times_finished_this_tick = u32::try_from(times).unwrap_or(u32::MAX);
+                self.elapsed = Duration::from_nanos(
+                    (self.elapsed.as_nanos() % self.duration.as_nanos()) as u64,
+                );
+            }
+        }
+        self
+    }
+    /// Advances timer by delta of given [`Time`].
+    ///
+    pub fn tick_by(&mut self, time: &Time) -> &Self {
+        self.tick(time.delta())
+    }
+
+    /// Returns whether timer has finished during the last tick.
+    ///
+    pub fn just_finished(&self) -> bool {
+        self.times_finished_this_tick > 0
+    }
+    /// Returns whether timer has finished.
+    ///
+    /// [`TimerMode::Repeating`] timers are finished only on ticks during which they were restarted.
+    ///
+    pub fn is_finished(&self) -> bool {
+        self.is_finished
+    }
+    /// Returns how many times timer has finished during the last tick
+    /// (it can be more than one for [`TimerMode::Repeating`] timers if delta was large).
+    ///
+    pub fn times_finished_this_tick(&self) -> u32 {
+        self.times_finished_this_tick
+    }
+
+    /// Pauses timer.
+    ///
+    pub fn pause(&mut self) {
+        self.is_paused = true;
+    }
+    /// Unpauses timer.
+    ///
+    pub fn unpause(&mut self) {
+        self.is_paused = false;
+    }
+    /// Returns whether timer is paused.
+    ///
+    pub fn is_paused(&self) -> bool {
+        self.is_paused
+    }
+    /// Restarts timer.
+    ///
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.is_finished = false;
+        self.times_finished_this_tick = 0;
+    }
+
+    /// Returns duration after which timer finishes.
+    ///
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+    /// Sets duration after which timer finishes.
+    ///
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.duration = duration;
+    }
+    /// Returns mode of a timer.
+    ///
+    pub fn mode(&self) -> TimerMode {
+        self.mode
+    }
+    /// Sets mode of a timer.
+    ///
+    pub fn set_mode(&mut self, mode: TimerMode) {
+        self.mode = mode;
+    }
+    /// Returns time that elapsed since timer was started.
+    ///
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+    /// Sets time that elapsed since timer was started.
+    ///
+    /// Timer will finish on the next tick if elapsed time exceeds duration.
+    ///
+    pub fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed;
+    }
+    /// Returns time that is left until timer finishes.
+    ///
+    pub fn remaining(&self) -> Duration {
+        self.duration.saturating_sub(self.elapsed)
+    }
+    /// Returns fraction of duration that has elapsed (in [0; 1] range).
+    ///
+    pub fn fraction(&self) -> f32 {
+        if self.duration.is_zero() {
+            1.0
+        } else {
+            (self.elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+        }
+    }
+}
+impl Component for Timer {}
+
+/// [`Cooldown`] struct is a component that limits how often an action can be performed.
+///
+/// Cooldown is ready when it is created; triggering it starts countdown.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::time::Cooldown;
+/// # use std::time::Duration;
+/// let mut dash: Cooldown = Cooldown::new(Duration::from_secs(1));
+/// assert!(dash.trigger());
+/// assert!(!dash.trigger());
+///
+/// dash.tick(Duration::from_secs(1));
+/// assert!(dash.is_ready());
+/// assert!(dash.trigger());
+/// ```
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Cooldown {
+    /// Underlying timer.
+    ///
+    timer: Timer,
+}
+impl Cooldown {
+    /// Constructs ready cooldown with given duration.
+    ///
+    pub fn new(duration: Duration) -> Self {
+        let mut timer: Timer = Timer::new(duration, TimerMode::Once);
+        timer.elapsed = duration;
+        timer.is_finished = true;
+        Cooldown { timer }
+    }
+    /// Constructs ready cooldown with given duration in seconds.
+    ///
+    pub fn from_seconds(seconds: f32) -> Self {
+        Cooldown::new(Duration::from_secs_f32(seconds.max(0.0)))
+    }
+
+    /// Advances cooldown by given delta.
+    ///
+    pub fn tick(&mut self, delta: Duration) {
+        let _ = self.timer.tick(delta);
+    }
+    /// Advances cooldown by delta of given [`Time`].
+    ///
+    pub fn tick_by(&mut self, time: &Time) {
+        self.tick(time.delta());
+    }
+
+    /// Returns whether action can be performed.
+    ///
+    pub fn is_ready(&self) -> bool {
+        self.timer.is_finished()
+    }
+    /// Returns whether cooldown became ready during the last tick.
+    ///
+    pub fn just_became_ready(&self) -> bool {
+        self.timer.just_finished()
+    }
+    /// Starts cooldown if it is ready and returns whether it was ready.
+    ///
+    pub fn trigger(&mut self) -> bool {
+        if !self.is_ready() {
+            return false;
+        }
+        self.timer.reset();
+        true
+    }
+    /// Makes cooldown ready immediately.
+    ///
+    pub fn finish(&mut self) {
+        self.timer.elapsed = self.timer.duration;
+        self.timer.is_finished = true;
+    }
+
+    /// Returns duration of a cooldown.
+    ///
+    pub fn duration(&self) -> Duration {
+        self.timer.duration()
+    }
+    /// Sets duration of a cooldown.
+    ///
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.timer.set_duration(duration);
+    }
+    /// Returns time that is left until cooldown is ready.
+    ///
+    pub fn remaining(&self) -> Duration {
+        if self.is_ready() {
+            Duration::ZERO
+        } else {
+            self.timer.remaining()
+        }
+    }
+    /// Returns fraction of cooldown that has elapsed (in [0; 1] range).
+    ///
+    /// This is useful for drawing cooldown indicators.
+    ///
+    pub fn fraction(&self) -> f32 {
+        if self.is_ready() {
+            1.0
+        } else {
+            self.timer.fraction()
+        }
+    }
+
+    /// Pauses cooldown.
+    ///
+    pub fn pause(&mut self) {
+        self.timer.pause();
+    }
+    /// Unpauses cooldown.
+    ///
+    pub fn unpause(&mut self) {
+        self.timer.unpause();
+    }
+}
+impl Component for Cooldown {}
+
+/// Ticks all given timers by delta of [`Time`].
+///
+/// This system should be run once per frame, after [`Time`] is updated.
+///
+pub fn tick_timers<'a>(time: &Time, timers: impl IntoIterator<Item = &'a mut Timer>) {
+    for timer in timers {
+        let _ = timer.tick_by(time);
+    }
+}
+/// Ticks all given cooldowns by delta of [`Time`].
+///
+/// This system should be run once per frame, after [`Time`] is updated.
+///
+pub fn tick_cooldowns<'a>(time: &Time, cooldowns: impl IntoIterator<Item = &'a mut Cooldown>) {
+    for cooldown in cooldowns {
+        cooldown.tick_by(time);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tick_timers, Clock, Time, Timer, TimerMode};
+    use std::time::Duration;
+
+    #[test]
+    fn once_timer() {
+        let mut timer: Timer = Timer::from_seconds(1.0, TimerMode::Once);
+        let mut time: Time = Time::new();
+
+        time.update_with(Duration::from_millis(600));
+        tick_timers(&time, [&mut timer]);
+        assert!(!timer.just_finished());
+        assert_eq!(timer.remaining(), Duration::from_millis(400));
+
+        timer.pause();
+        tick_timers(&time, [&mut timer]);
+        assert_eq!(timer.remaining(), Duration::from_millis(400));
+        timer.unpause();
+
+        tick_timers(&time, [&mut timer]);
+        assert!(timer.just_finished() && timer.is_finished());
+        assert_eq!(timer.fraction(), 1.0);
+
+        tick_timers(&time, [&mut timer]);
+        assert!(!timer.just_finished() && timer.is_finished());
+
+        timer.reset();
+        assert!(!timer.is_finished());
+        assert_eq!(timer.elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn paused_and_scaled_time() {
+        let mut time: Time = Time::new();
+        time.is_paused = true;
+        time.update_with(Duration::from_secs(1));
+        assert_eq!(time.delta(), Duration::ZERO);
+        assert_eq!(time.delta_by(Clock::Unscaled), Duration::from_secs(1));
+
+        time.is_paused = false;
+        time.time_scale = 2.0;
+        time.update_with(Duration::from_secs(1));
+        assert_eq!(time.elapsed(), Duration::from_secs(2));
+        assert_eq!(time.unscaled_elapsed(), Duration::from_secs(2));
+        assert_eq!(time.frame(), 2);
+    }
+}
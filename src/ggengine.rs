@@ -1,9 +1,23 @@
 //! `ggengine` hidden submodule implements [`GGEngine`] struct that handles
-//! all subsystems that are needed for `ggengine` work.
+//! all subsystems that are needed for `ggengine` work, and [`GGEngineBuilder`] struct that
+//! initializes [`GGEngine`] together with optional subsystems.
 //!
 
-use sdl2::{init as sdl_initialization, Sdl, VideoSubsystem as SdlVideoSubsystem};
-use std::fmt;
+use crate::{
+    datacore::{
+        audio::{AudioSettings, AudioSystem},
+        fonts::FontSystem,
+        images::{ImageFormat, ImageSystem},
+    },
+    utils::{Window, WindowSettings},
+    GGError,
+};
+use sdl2::{
+    init as sdl_initialization, AudioSubsystem as SdlAudioSubsystem, EventPump as SdlEventPump,
+    GameControllerSubsystem as SdlGameControllerSubsystem, Sdl,
+    VideoSubsystem as SdlVideoSubsystem,
+};
+use std::{fmt, marker::PhantomData};
 
 /// [`GGEngine`] struct handles global context for `ggengine`.
 ///
@@ -14,13 +28,20 @@ use std::fmt;
 ///
 /// [`GGEngine`] struct initializes **ONLY** basic subsystems that are needed for work -
 /// that includes video system and event system;
-/// other subsystems such as audio, images and fonts systems should be initialized manually.
+/// other subsystems such as audio, images and fonts systems should be initialized manually
+/// or declared in [`GGEngineBuilder`].
+///
+/// Game logic does not require [`GGEngine`] at all: scenes, schedules, physics and raw assets
+/// work without any `sdl2` subsystem, which allows running games headless
+/// (see [`ScheduleRunner`](crate::gamecore::schedules::ScheduleRunner)).
 ///
 /// # Example
 /// ```rust, no_run
 /// # use ggengine::{GGEngine, utils::Window};
-/// let engine: GGEngine = GGEngine::init();
-/// let window: Window = engine.build_window("GGENGINE", 1600, 900, Default::default());
+/// let engine: GGEngine = GGEngine::init().expect("Engine should be initialized");
+/// let window: Window = engine
+///     .build_window("GGENGINE", 1600, 900, Default::default())
+///     .expect("Window should be built");
 /// ```
 ///
 pub struct GGEngine {
@@ -38,24 +59,39 @@ impl GGEngine {
     pub(crate) fn get_sdl_videosubsystem(&self) -> &SdlVideoSubsystem {
         &self.video
     }
+    /// Returns underlying `AudioSubsystem` handler, initializing it if needed.
+    ///
+    pub(crate) fn get_sdl_audiosubsystem(&self) -> Result<SdlAudioSubsystem, String> {
+        self.sdl.audio()
+    }
+    /// Returns underlying `GameControllerSubsystem` handler, initializing it if needed.
+    ///
+    pub(crate) fn get_sdl_gamecontrollersubsystem(
+        &self,
+    ) -> Result<SdlGameControllerSubsystem, String> {
+        self.sdl.game_controller()
+    }
+    /// Returns underlying `EventPump` handler (only one can exist at a time).
+    ///
+    pub(crate) fn get_sdl_eventpump(&self) -> Result<SdlEventPump, String> {
+        self.sdl.event_pump()
+    }
 
     /// Internally initializes global handler for `ggengine` library.
     ///
     /// This function loads and prepares all submodules for usage.
+    /// Error is returned if `sdl2` or its video subsystem cannot be initialized (e.g. no video driver is available).
     ///
     /// # Example
     /// ```rust
     /// # use ggengine::GGEngine;
-    /// let engine: GGEngine = GGEngine::init();
+    /// let engine: GGEngine = GGEngine::init().expect("Engine should be initialized");
     /// ```
     ///
-    pub fn init() -> GGEngine {
-        let sdl: Sdl = sdl_initialization()
-            .expect("`ggengine` should be able to initialize underlying `sdl2` handler");
-        let video: SdlVideoSubsystem = sdl
-            .video()
-            .expect("`ggengine` should be able to initialize underlying `video` handler");
-        GGEngine { sdl, video }
+    pub fn init() -> Result<GGEngine, GGError> {
+        let sdl: Sdl = sdl_initialization().map_err(GGError::Sdl)?;
+        let video: SdlVideoSubsystem = sdl.video().map_err(GGError::Sdl)?;
+        Ok(GGEngine { sdl, video })
     }
 }
 impl fmt::Debug for GGEngine {
@@ -63,3 +99,163 @@ impl fmt::Debug for GGEngine {
         f.debug_struct("GGEngine").finish()
     }
 }
+
+/// [`Initialized`] struct is a handle which proves that subsystem `S` was initialized.
+///
+/// Handles can only be obtained from [`GGEngineBuilder`], so functions that require
+/// subsystem can take its handle to make initialization order explicit.
+///
+#[derive(Copy, Clone, Debug)]
+pub struct Initialized<S> {
+    /// Initialized subsystem.
+    ///
+    subsystem: PhantomData<S>,
+}
+impl<S> Initialized<S> {
+    /// Creates handle of subsystem that was initialized.
+    ///
+    fn new() -> Self {
+        Initialized {
+            subsystem: PhantomData,
+        }
+    }
+}
+
+/// [`GGEngineHandles`] struct holds [`GGEngine`], window and handles of subsystems that were initialized by [`GGEngineBuilder`].
+///
+#[derive(Debug)]
+pub struct GGEngineHandles {
+    /// Global handler of `ggengine`.
+    ///
+    pub engine: GGEngine,
+    /// Window, if it was declared.
+    ///
+    pub window: Option<Window>,
+    /// Handle of image system, if it was declared.
+    ///
+    pub images: Option<Initialized<ImageSystem>>,
+    /// Handle of font system, if it was declared.
+    ///
+    pub fonts: Option<Initialized<FontSystem>>,
+    /// Handle of audio system, if it was declared.
+    ///
+    pub audio: Option<Initialized<AudioSystem>>,
+}
+
+/// [`GGEngineBuilder`] struct declares subsystems that are required by a game and initializes them in the right order.
+///
+/// # Example
+/// ```rust, no_run
+/// # use ggengine::{GGEngineBuilder, GGEngineHandles};
+/// # use ggengine::datacore::{audio::AudioSettings, images::ImageFormat};
+/// let handles: GGEngineHandles = GGEngineBuilder::new()
+///     .images(ImageFormat::PNG)
+///     .fonts()
+///     .audio(AudioSettings::default())
+///     .window("GGENGINE", 1600, 900, Default::default())
+///     .build()
+///     .expect("Subsystems should be initialized");
+/// assert!(handles.window.is_some());
+/// ```
+///
+#[derive(Clone, Debug, Default)]
+pub struct GGEngineBuilder {
+    /// Image formats, if image system is required.
+    ///
+    images: Option<ImageFormat>,
+    /// Whether font system is required.
+    ///
+    fonts: bool,
+    /// Audio settings, if audio system is required.
+    ///
+    audio: Option<AudioSettings>,
+    /// Title, width, height and settings of the window, if window is required.
+    ///
+    window: Option<(String, u32, u32, WindowSettings)>,
+}
+impl GGEngineBuilder {
+    /// Initializes [`GGEngineBuilder`] that requires only basic subsystems of [`GGEngine`].
+    ///
+    pub fn new() -> Self {
+        GGEngineBuilder::default()
+    }
+
+    /// Requires image system that supports given formats.
+    ///
+    pub fn images(mut self, image_format: ImageFormat) -> Self {
+        self.images = Some(image_format);
+        self
+    }
+    /// Requires font system.
+    ///
+    pub fn fonts(mut self) -> Self {
+        self.fonts = true;
+        self
+    }
+    /// Requires audio system with given settings.
+    ///
+    pub fn audio(mut self, audio_settings: AudioSettings) -> Self {
+        self.audio = Some(audio_settings);
+        self
+    }
+    /// Requires window with given title, size and settings.
+    ///
+    pub fn window(
+        mut self,
+        title: &str,
+        width: u32,
+        height: u32,
+        window_settings: WindowSettings,
+    ) -> Self {
+        self.window = Some((String::from(title), width, height, window_settings));
+        self
+    }
+
+    /// Initializes [`GGEngine`] and all declared subsystems, after which builds window.
+    ///
+    /// # Errors
+    /// Error of the first subsystem that could not be initialized is returned.
+    ///
+    pub fn build(self) -> Result<GGEngineHandles, GGError> {
+        let engine: GGEngine = GGEngine::init()?;
+        let images: Option<Initialized<ImageSystem>> = match self.images {
+            Some(image_format) => {
+                ImageSystem::init(image_format)?;
+                Some(Initialized::new())
+            }
+            None => None,
+        };
+        let fonts: Option<Initialized<FontSystem>> = if self.fonts {
+            FontSystem::init()?;
+            Some(Initialized::new())
+        } else {
+            None
+        };
+        let audio: Option<Initialized<AudioSystem>> = match self.audio {
+            Some(audio_settings) => {
+                AudioSystem::init(
+                    audio_settings.audio_format,
+                    audio_settings.frequency,
+                    audio_settings.sample_format,
+                    audio_settings.channels,
+                    audio_settings.chunk_size,
+                )?;
+                Some(Initialized::new())
+            }
+            None => None,
+        };
+        let window: Option<Window> = match self.window {
+            Some((title, width, height, window_settings)) => {
+                Some(engine.build_window(&title, width, height, window_settings)?)
+            }
+            None => None,
+        };
+        Ok(GGEngineHandles {
+            engine,
+            window,
+            images,
+            fonts,
+            audio,
+        })
+    }
+}
@@ -6,5 +6,19 @@
 mod ext;
 pub use ext::*;
 
+pub mod animation;
+pub mod atlas;
+pub mod cache;
+pub mod camera;
+pub mod cameraeffects;
 pub mod drawing;
+pub mod grading;
+pub mod meshes;
+pub mod queue;
+pub mod skeletons;
+pub mod software;
+pub mod sprites;
 pub mod textures;
+pub mod tilemaps;
+pub mod trails;
+pub mod transitions;
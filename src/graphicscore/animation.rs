@@ -0,0 +1,727 @@
+//! `graphicscore::animation` submodule implements sprite animation - [`AnimationClip`]s, which are
+//! sequences of frames of a texture, and [`Animator`] component, which switches between clips.
+//!
+//! [`Animator`] is a state machine which states are clips and which transitions are driven
+//! by parameters (bools, floats and triggers) that are set by gameplay code.
+//! Switching between states can be blended by duration - during blending both previous and
+//! current frames are available, so renderers can crossfade them.
+//!
+//! [`update_animators`] system advances all animators of a scene by [`Time`] resource
+//! (animators sample clips by elapsed time, so animations do not depend on frame rate),
+//! writes their current frames
+//! to [`Sprite`]s of the same game objects and reports performed transitions as [`AnimatorEvents`] resource.
+//!
+
+use crate::{
+    gamecore::{
+        components::{Component, Resource},
+        identifiers::GameObjectId,
+        scenes::Scene,
+        time::{Clock, Time},
+    },
+    graphicscore::sprites::Sprite,
+    mathcore::shapes::Rect,
+};
+use std::{collections::HashMap, time::Duration};
+
+/// [`AnimationFrame`] struct is one frame of [`AnimationClip`].
+///
+#[derive(Copy, Clone, Debug)]
+pub struct AnimationFrame {
+    /// Area of texture that is drawn during the frame.
+    ///
+    pub src_area: Rect,
+    /// How long the frame is shown.
+    ///
+    pub duration: Duration,
+}
+
+/// [`AnimationClip`] struct is a sequence of frames of a texture.
+///
+#[derive(Clone, Debug, Default)]
+pub struct AnimationClip {
+    /// Frames of the clip.
+    ///
+    pub frames: Vec<AnimationFrame>,
+    /// Whether clip starts over after its last frame.
+    ///
+    pub is_looping: bool,
+}
+impl AnimationClip {
+    /// Constructs clip from given frames.
+    ///
+    pub fn new(frames: Vec<AnimationFrame>, is_looping: bool) -> Self {
+        AnimationClip { frames, is_looping }
+    }
+    /// Constructs clip in which every frame is shown for the same duration.
+    ///
+    pub fn uniform(
+        src_areas: impl IntoIterator<Item = Rect>,
+        frame_duration: Duration,
+        is_looping: bool,
+    ) -> Self {
+        AnimationClip::new(
+            src_areas
+                .into_iter()
+                .map(|src_area| AnimationFrame {
+                    src_area,
+                    duration: frame_duration,
+                })
+                .collect(),
+            is_looping,
+        )
+    }
+
+    /// Returns duration of one run of the clip.
+    ///
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|frame| frame.duration).sum()
+    }
+    /// Returns whether clip has finished after given time (looping clips never finish).
+    ///
+    pub fn is_finished(&self, elapsed: Duration) -> bool {
+        !self.is_looping && elapsed >= self.duration()
+    }
+    /// Returns index of the frame that is shown after given time.
+    ///
+    /// Non-looping clips stay on their last frame. Returns `None` if clip has no frames.
+    ///
+    pub fn frame_index(&self, elapsed: Duration) -> Option<usize> {
+        let last: usize = self.frames.len().checked_sub(1)?;
+        let duration: Duration = self.duration();
+        let mut time: Duration = if self.is_looping && !duration.is_zero() {
+            Duration::from_nanos((elapsed.as_nanos() % duration.as_nanos()) as u64)
+        } else {
+            elapsed
+        };
+        for (index, frame) in self.frames.iter().enumerate() {
+            if time < frame.duration {
+                return Some(index);
+            }
+            time -= frame.duration;
+        }
+        Some(last)
+    }
+    /// Returns frame that is shown after given time.
+    ///
+    pub fn frame(&self, elapsed: Duration) -> Option<&AnimationFrame> {
+        self.frame_index(elapsed).map(|index| &self.frames[index])
+    }
+}
+
+/// [`AnimatorParameter`] enum lists parameters that drive transitions of [`Animator`].
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[allow(variant_size_differences)] // all variants are small enough to be copied
+pub enum AnimatorParameter {
+    /// Boolean flag (e.g. `grounded`).
+    ///
+    Bool(bool),
+    /// Number (e.g. `speed`).
+    ///
+    Float(f32),
+    /// Flag that is reset when transition that checks it is performed (e.g. `attack`).
+    ///
+    Trigger(bool),
+}
+
+/// [`AnimatorCondition`] enum lists conditions of transitions of [`Animator`].
+///
+/// Missing parameters are treated as `false` and `0.0`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnimatorCondition {
+    /// Bool parameter with given name has given value.
+    ///
+    Bool(String, bool),
+    /// Float parameter with given name is greater than given value.
+    ///
+    Greater(String, f32),
+    /// Float parameter with given name is less than given value.
+    ///
+    Less(String, f32),
+    /// Trigger with given name is set (it is reset by transition).
+    ///
+    Trigger(String),
+    /// Clip of current state has finished.
+    ///
+    ClipFinished,
+}
+
+/// [`AnimatorTransition`] struct describes transition between states of [`Animator`].
+///
+#[derive(Clone, Debug)]
+struct AnimatorTransition {
+    /// State from which transition happens (`None` corresponds to any state).
+    ///
+    from: Option<String>,
+    /// State to which transition happens.
+    ///
+    to: String,
+    /// Conditions that all should pass.
+    ///
+    conditions: Vec<AnimatorCondition>,
+    /// Duration of blending between states.
+    ///
+    blend_duration: Duration,
+}
+
+/// [`Blending`] struct holds state of blending between previous and current states.
+///
+#[derive(Clone, Debug)]
+struct Blending {
+    /// Previous state.
+    ///
+    from: String,
+    /// Time that was spent in previous state (it continues to play during blending).
+    ///
+    from_time: Duration,
+    /// Time that has elapsed since blending started.
+    ///
+    elapsed: Duration,
+    /// Duration of blending.
+    ///
+    duration: Duration,
+}
+
+/// [`AnimatorBlend`] struct describes blending between previous and current states of [`Animator`].
+///
+#[derive(Copy, Clone, Debug)]
+pub struct AnimatorBlend {
+    /// Frame of previous state.
+    ///
+    pub previous_frame: Option<AnimationFrame>,
+    /// Weight of current state (in [0; 1] range); weight of previous state is `1.0 - weight`.
+    ///
+    pub weight: f32,
+}
+
+/// [`Animator`] struct is a component that plays [`AnimationClip`]s as states of state machine.
+///
+/// On every update, time of current state is advanced, then transitions from current state
+/// are checked in order of their addition (transitions from any state are checked along with them);
+/// the first transition whose conditions all pass is performed.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::graphicscore::animation::{AnimationClip, Animator, AnimatorCondition};
+/// # use ggengine::mathcore::{shapes::Rect, vectors::Point, Size};
+/// # use std::time::Duration;
+/// let frames = |row: f32| (0..4).map(move |column| Rect::from_top_left_size(
+///     Point::from([column as f32 * 16.0, row * 16.0]),
+///     Size::from_value(16.0),
+///     Size::from_value(16.0),
+/// ));
+/// let frame: Duration = Duration::from_millis(100);
+///
+/// let mut animator: Animator = Animator::new("idle", AnimationClip::uniform(frames(0.0), frame, true))
+///     .state("run", AnimationClip::uniform(frames(1.0), frame, true))
+///     .state("attack", AnimationClip::uniform(frames(2.0), frame, false))
+///     .transition("idle", "run", [AnimatorCondition::Greater(String::from("speed"), 0.1)], Duration::ZERO)
+///     .transition("run", "idle", [AnimatorCondition::Less(String::from("speed"), 0.1)], Duration::ZERO)
+///     .transition_from_any("attack", [AnimatorCondition::Trigger(String::from("attack"))], Duration::ZERO)
+///     .transition("attack", "idle", [AnimatorCondition::ClipFinished], Duration::from_millis(200));
+///
+/// animator.set_float("speed", 3.0);
+/// assert_eq!(animator.update(frame), Some((String::from("idle"), String::from("run"))));
+///
+/// animator.set_trigger("attack");
+/// let _ = animator.update(frame);
+/// assert_eq!(animator.current_state(), "attack");
+/// assert!(!animator.trigger("attack"));
+///
+/// let _ = animator.update(Duration::from_millis(400));
+/// assert_eq!(animator.current_state(), "idle");
+/// assert!(animator.blend().is_some());
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct Animator {
+    /// Clips of states.
+    ///
+    states: HashMap<String, AnimationClip>,
+    /// Transitions between states.
+    ///
+    transitions: Vec<AnimatorTransition>,
+    /// Parameters that drive transitions.
+    ///
+    parameters: HashMap<String, AnimatorParameter>,
+
+    /// Current state.
+    ///
+    state: String,
+    /// Time that was spent in current state.
+    ///
+    time_in_state: Duration,
+    /// Blending between previous and current states.
+    ///
+    blending: Option<Blending>,
+    /// Multiplier of time by which animator is advanced.
+    ///
+    pub speed: f32,
+    /// Clock by which animator is advanced by `Animator::update_by`.
+    ///
+    pub clock: Clock,
+}
+impl Animator {
+    /// Constructs animator with given initial state.
+    ///
+    pub fn new(initial_state: &str, clip: AnimationClip) -> Self {
+        Animator {
+            states: HashMap::from([(initial_state.to_string(), clip)]),
+            transitions: Vec::new(),
+            parameters: HashMap::new(),
+
+            state: initial_state.to_string(),
+            time_in_state: Duration::ZERO,
+            blending: None,
+            speed: 1.0,
+            clock: Clock::Scaled,
+        }
+    }
+
+    /// Adds state with given clip (clip of existing state is replaced).
+    ///
+    pub fn state(mut self, name: &str, clip: AnimationClip) -> Self {
+        let _ = self.states.insert(name.to_string(), clip);
+        self
+    }
+    /// Adds transition between two states that happens when all conditions pass
+    /// (transition without conditions happens immediately).
+    ///
+    pub fn transition(
+        mut self,
+        from: &str,
+        to: &str,
+        conditions: impl IntoIterator<Item = AnimatorCondition>,
+        blend_duration: Duration,
+    ) -> Self {
+        self.transitions.push(AnimatorTransition {
+            from: Some(from.to_string()),
+            to: to.to_string(),
+            conditions: conditions.into_iter().collect(),
+            blend_duration,
+        });
+        self
+    }
+    /// Adds transition from any state (except for the target one) that happens when all conditions pass.
+    ///
+    pub fn transition_from_any(
+        mut self,
+        to: &str,
+        conditions: impl IntoIterator<Item = AnimatorCondition>,
+        blend_duration: Duration,
+    ) -> Self {
+        self.transitions.push(AnimatorTransition {
+            from: None,
+            to: to.to_string(),
+            conditions: conditions.into_iter().collect(),
+            blend_duration,
+        });
+        self
+    }
+
+    /// Sets bool parameter.
+    ///
+    pub fn set_bool(&mut self, name: &str, value: bool) {
+        let _ = self
+            .parameters
+            .insert(name.to_string(), AnimatorParameter::Bool(value));
+    }
+    /// Sets float parameter.
+    ///
+    pub fn set_float(&mut self, name: &str, value: f32) {
+        let _ = self
+            .parameters
+            .insert(name.to_string(), AnimatorParameter::Float(value));
+    }
+    /// Sets trigger, which stays set until transition that checks it is performed.
+    ///
+    pub fn set_trigger(&mut self, name: &str) {
+        let _ = self
+            .parameters
+            .insert(name.to_string(), AnimatorParameter::Trigger(true));
+    }
+    /// Resets trigger.
+    ///
+    pub fn reset_trigger(&mut self, name: &str) {
+        let _ = self
+            .parameters
+            .insert(name.to_string(), AnimatorParameter::Trigger(false));
+    }
+    /// Returns parameter with given name.
+    ///
+    pub fn parameter(&self, name: &str) -> Option<AnimatorParameter> {
+        self.parameters.get(name).copied()
+    }
+    /// Returns value of bool parameter (`false` if it is missing or is not a bool).
+    ///
+    pub fn bool(&self, name: &str) -> bool {
+        matches!(self.parameter(name), Some(AnimatorParameter::Bool(true)))
+    }
+    /// Returns value of float parameter (`0.0` if it is missing or is not a float).
+    ///
+    pub fn float(&self, name: &str) -> f32 {
+        match self.parameter(name) {
+            Some(AnimatorParameter::Float(value)) => value,
+            _ => 0.0,
+        }
+    }
+    /// Returns whether trigger is set.
+    ///
+    pub fn trigger(&self, name: &str) -> bool {
+        matches!(self.parameter(name), Some(AnimatorParameter::Trigger(true)))
+    }
+
+    /// Returns name of current state.
+    ///
+    pub fn current_state(&self) -> &str {
+        &self.state
+    }
+    /// Returns clip of current state.
+    ///
+    pub fn current_clip(&self) -> Option<&AnimationClip> {
+        self.states.get(&self.state)
+    }
+    /// Returns time that was spent in current state.
+    ///
+    pub fn time_in_state(&self) -> Duration {
+        self.time_in_state
+    }
+    /// Returns frame of current state.
+    ///
+    pub fn current_frame(&self) -> Option<&AnimationFrame> {
+        self.current_clip()?.frame(self.time_in_state)
+    }
+    /// Returns blending between previous and current states if it is in progress.
+    ///
+    pub fn blend(&self) -> Option<AnimatorBlend> {
+        let blending: &Blending = self.blending.as_ref()?;
+        Some(AnimatorBlend {
+            previous_frame: self
+                .states
+                .get(&blending.from)
+                .and_then(|clip| clip.frame(blending.from_time))
+                .copied(),
+            weight: (blending.elapsed.as_secs_f32() / blending.duration.as_secs_f32()).min(1.0),
+        })
+    }
+
+    /// Switches to given state immediately and without blending (transitions are not checked).
+    ///
+    /// Switching to the current state restarts it. Returns `false` if there is no such state.
+    ///
+    pub fn play(&mut self, state: &str) -> bool {
+        if !self.states.contains_key(state) {
+            return false;
+        }
+        self.state = state.to_string();
+        self.time_in_state = Duration::ZERO;
+        self.blending = None;
+        true
+    }
+
+    /// Returns whether condition passes in current state.
+    ///
+    fn check(&self, condition: &AnimatorCondition) -> bool {
+        match condition {
+            AnimatorCondition::Bool(name, value) => self.bool(name) == *value,
+            AnimatorCondition::Greater(name, value) => self.float(name) > *value,
+            AnimatorCondition::Less(name, value) => self.float(name) < *value,
+            AnimatorCondition::Trigger(name) => self.trigger(name),
+            AnimatorCondition::ClipFinished => self
+                .current_clip()
+                .is_some_and(|clip| clip.is_finished(self.time_in_state)),
+        }
+    }
+    /// Advances animator by given delta (scaled by speed of animator) and performs
+    /// the first transition whose conditions pass.
+    ///
+    /// Returns names of previous and new states if transition was performed.
+    ///
+    pub fn update(&mut self, delta: Duration) -> Option<(String, String)> {
+        let delta: Duration = delta.mul_f32(self.speed.max(0.0));
+        self.time_in_state += delta;
+        if let Some(blending) = self.blending.as_mut() {
+            blending.from_time += delta;
+            blending.elapsed += delta;
+            if blending.elapsed >= blending.duration {
+                self.blending = None;
+            }
+        }
+
+        let transition: AnimatorTransition = self
+            .transitions
+            .iter()
+            .filter(|transition| match &transition.from {
+                Some(from) => *from == self.state,
+                None => transition.to != self.state,
+            })
+            .filter(|transition| self.states.contains_key(&transition.to))
+            .find(|transition| {
+                transition
+                    .conditions
+                    .iter()
+                    .all(|condition| self.check(condition))
+            })?
+            .clone();
+        for condition in &transition.conditions {
+            if let AnimatorCondition::Trigger(name) = condition {
+                self.reset_trigger(name);
+            }
+        }
+
+        let from: String = std::mem::replace(&mut self.state, transition.to.clone());
+        self.blending = (!transition.blend_duration.is_zero()).then(|| Blending {
+            from: from.clone(),
+            from_time: self.time_in_state,
+            elapsed: Duration::ZERO,
+            duration: transition.blend_duration,
+        });
+        self.time_in_state = Duration::ZERO;
+        Some((from, transition.to))
+    }
+    /// Advances animator by delta of [`Time`] that is measured by clock of the animator.
+    ///
+    /// See `Animator::update`.
+    ///
+    pub fn update_by(&mut self, time: &Time) -> Option<(String, String)> {
+        self.update(time.delta_by(self.clock))
+    }
+}
+impl Component for Animator {}
+
+/// [`AnimatorEvent`] struct describes transition that was performed by [`Animator`] of a game object.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnimatorEvent {
+    /// Game object which animator performed transition.
+    ///
+    pub gameobject_id: GameObjectId,
+    /// Previous state.
+    ///
+    pub from: String,
+    /// New state.
+    ///
+    pub to: String,
+}
+
+/// [`AnimatorEvents`] struct is a resource that holds transitions that were performed during the last run
+/// of [`update_animators`] system.
+///
+#[derive(Clone, Debug, Default)]
+pub struct AnimatorEvents {
+    /// Performed transitions.
+    ///
+    events: Vec<AnimatorEvent>,
+}
+impl AnimatorEvents {
+    /// Returns iterator over performed transitions.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = &AnimatorEvent> {
+        self.events.iter()
+    }
+    /// Returns number of performed transitions.
+    ///
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+    /// Returns whether no transitions were performed.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+impl Resource for AnimatorEvents {}
+
+/// Advances all animators of the scene by [`Time`] resource, writes their current frames to [`Sprite`]s
+/// of their game objects and replaces [`AnimatorEvents`] resource with transitions that were performed.
+///
+/// Animators are not advanced if there is no [`Time`] resource.
+///
+pub fn update_animators(scene: &mut Scene) {
+    let time: Time = scene
+        .resources()
+        .get_resource::<Time>()
+        .copied()
+        .unwrap_or_default();
+    let mut events: Vec<AnimatorEvent> = Vec::new();
+    let mut frames: Vec<(GameObjectId, Rect)> = Vec::new();
+    for (gameobject_id, animator) in scene.components_mut().components_mut::<Animator>() {
+        if let Some((from, to)) = animator.update_by(&time) {
+            events.push(AnimatorEvent {
+                gameobject_id,
+                from,
+                to,
+            });
+        }
+        if let Some(frame) = animator.current_frame() {
+            frames.push((gameobject_id, frame.src_area));
+        }
+    }
+    for (gameobject_id, src_area) in frames {
+        if let Some(sprite) = scene
+            .components_mut()
+            .get_component_mut::<Sprite>(gameobject_id)
+        {
+            sprite.src_area = Some(src_area);
+        }
+    }
+    let _ = scene
+        .resources_mut()
+        .insert_resource(AnimatorEvents { events });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        update_animators, AnimationClip, Animator, AnimatorCondition, AnimatorEvent, AnimatorEvents,
+    };
+    use crate::{
+        gamecore::{
+            identifiers::GameObjectId,
+            scenes::Scene,
+            time::{Clock, Time},
+        },
+        graphicscore::sprites::Sprite,
+        mathcore::{shapes::Rect, transforms::Translatable, vectors::Point, Angle, Size},
+    };
+    use std::{path::PathBuf, time::Duration};
+
+    fn frames(count: usize) -> impl Iterator<Item = Rect> {
+        (0..count).map(|index| {
+            Rect::from_origin(
+                Point::from([index as f32, 0.0]),
+                Angle::ZERO,
+                Size::from_value(1.0),
+                Size::from_value(1.0),
+            )
+        })
+    }
+
+    fn animator(scene: &mut Scene, gameobject_id: GameObjectId) -> &mut Animator {
+        scene
+            .components_mut()
+            .get_component_mut::<Animator>(gameobject_id)
+            .expect("Game object has animator")
+    }
+
+    fn step(scene: &mut Scene, delta: Duration) {
+        scene
+            .resources_mut()
+            .get_resource_or_insert_with(Time::new)
+            .update_with(delta);
+        update_animators(scene);
+    }
+
+    fn src_x(scene: &Scene, gameobject_id: GameObjectId) -> Option<f32> {
+        scene
+            .components()
+            .get_component::<Sprite>(gameobject_id)
+            .and_then(|sprite| sprite.src_area)
+            .map(|src_area| src_area.origin().x)
+    }
+
+    #[test]
+    fn clips() {
+        let frame: Duration = Duration::from_millis(100);
+        let looping: AnimationClip = AnimationClip::uniform(frames(3), frame, true);
+        let once: AnimationClip = AnimationClip::uniform(frames(3), frame, false);
+        assert_eq!(looping.duration(), Duration::from_millis(300));
+        assert_eq!(looping.frame_index(Duration::from_millis(250)), Some(2));
+        assert_eq!(looping.frame_index(Duration::from_millis(350)), Some(0));
+        assert_eq!(once.frame_index(Duration::from_millis(350)), Some(2));
+        assert!(once.is_finished(Duration::from_millis(300)));
+        assert!(!looping.is_finished(Duration::from_secs(10)));
+        assert_eq!(AnimationClip::default().frame_index(Duration::ZERO), None);
+    }
+
+    #[test]
+    fn animator_system() {
+        let frame: Duration = Duration::from_millis(100);
+        let player_animator: Animator =
+            Animator::new("idle", AnimationClip::uniform(frames(2), frame, true))
+                .state("jump", AnimationClip::uniform(frames(4), frame, false))
+                .transition(
+                    "idle",
+                    "jump",
+                    [
+                        AnimatorCondition::Trigger(String::from("jump")),
+                        AnimatorCondition::Bool(String::from("grounded"), true),
+                    ],
+                    Duration::ZERO,
+                )
+                .transition(
+                    "jump",
+                    "idle",
+                    [AnimatorCondition::ClipFinished],
+                    Duration::from_millis(200),
+                );
+        let mut scene: Scene = Scene::new();
+        let player: GameObjectId = scene.spawn((
+            player_animator,
+            Sprite {
+                texture: PathBuf::from("player.png"),
+                size: (1.0, 1.0),
+                src_area: None,
+                layer: 0,
+            },
+        ));
+
+        step(&mut scene, Duration::from_millis(150));
+        assert_eq!(src_x(&scene, player), Some(1.0));
+
+        // trigger is kept until all conditions pass
+        animator(&mut scene, player).set_trigger("jump");
+        step(&mut scene, frame);
+        assert_eq!(animator(&mut scene, player).current_state(), "idle");
+        animator(&mut scene, player).set_bool("grounded", true);
+        step(&mut scene, frame);
+        assert_eq!(
+            scene
+                .resources()
+                .get_resource::<AnimatorEvents>()
+                .map(|events| events.iter().cloned().collect::<Vec<AnimatorEvent>>()),
+            Some(vec![AnimatorEvent {
+                gameobject_id: player,
+                from: String::from("idle"),
+                to: String::from("jump"),
+            }])
+        );
+        assert!(!animator(&mut scene, player).trigger("jump"));
+        assert_eq!(src_x(&scene, player), Some(0.0));
+
+        animator(&mut scene, player).speed = 2.0;
+        step(&mut scene, Duration::from_millis(150));
+        assert_eq!(src_x(&scene, player), Some(3.0));
+        assert!(scene
+            .resources()
+            .get_resource::<AnimatorEvents>()
+            .is_some_and(AnimatorEvents::is_empty));
+        step(&mut scene, Duration::from_millis(50));
+        assert_eq!(animator(&mut scene, player).current_state(), "idle");
+
+        step(&mut scene, Duration::from_millis(50));
+        let blend = animator(&mut scene, player)
+            .blend()
+            .expect("States are blended");
+        assert_eq!(blend.weight, 0.5);
+        assert_eq!(
+            blend.previous_frame.map(|frame| frame.src_area.origin().x),
+            Some(3.0)
+        );
+        step(&mut scene, Duration::from_millis(50));
+        assert!(animator(&mut scene, player).blend().is_none());
+
+        // animators that use unscaled time keep playing during pause
+        let time_in_state: Duration = animator(&mut scene, player).time_in_state();
+        if let Some(time) = scene.resources_mut().get_resource_mut::<Time>() {
+            time.is_paused = true;
+        }
+        step(&mut scene, frame);
+        assert_eq!(animator(&mut scene, player).time_in_state(), time_in_state);
+        animator(&mut scene, player).clock = Clock::Unscaled;
+        step(&mut scene, frame);
+        assert!(animator(&mut scene, player).time_in_state() > time_in_state);
+    }
+}
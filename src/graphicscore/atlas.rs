@@ -0,0 +1,351 @@
+//! `graphicscore::atlas` submodule implements [`AtlasBuilder`] - runtime rectangle packer that
+//! combines many small [`Image`]s into one large [`Atlas`].
+//!
+//! Drawing from a single texture instead of many small ones reduces number of texture binds,
+//! which is especially noticeable for fonts, particles and UI icons.
+//!
+//! Images are packed in shelves (rows of images sorted by height) with given padding between them.
+//! Padding alone does not prevent filtering from sampling neighbouring pixels, so images can also
+//! be extruded - their border pixels are repeated outwards for given number of pixels.
+//!
+
+use crate::{
+    datacore::images::{Image, ImageArea, PixelFormat},
+    graphicscore::{
+        textures::{Texture, TextureCreator},
+        Blendable, BlendingType,
+    },
+    mathcore::vectors::Vector2,
+    GGError,
+};
+use std::{collections::HashMap, fmt, hash::Hash};
+
+/// [`AtlasRegion`] struct describes where packed image is located in the [`Atlas`].
+///
+#[derive(Copy, Clone, Debug)]
+pub struct AtlasRegion {
+    /// Area of the atlas (in pixels) that is occupied by the image (without padding and extrusion).
+    ///
+    pub area: ImageArea,
+    /// Texture coordinates of the left upper corner of the image.
+    ///
+    pub uv_min: Vector2,
+    /// Texture coordinates of the right lower corner of the image.
+    ///
+    pub uv_max: Vector2,
+}
+
+/// [`Atlas`] struct holds packed image and regions of all images that were packed in it.
+///
+pub struct Atlas<K> {
+    /// Image with all packed images.
+    ///
+    image: Image<'static>,
+    /// Regions of packed images.
+    ///
+    regions: HashMap<K, AtlasRegion>,
+}
+impl<K: Eq + Hash> Atlas<K> {
+    /// Returns image with all packed images.
+    ///
+    pub fn image(&self) -> &Image<'static> {
+        &self.image
+    }
+    /// Consumes atlas and returns image with all packed images.
+    ///
+    pub fn into_image(self) -> Image<'static> {
+        self.image
+    }
+    /// Creates texture from the packed image.
+    ///
+    pub fn create_texture<'t>(
+        &self,
+        texture_creator: &'t TextureCreator,
+    ) -> Result<Texture<'t>, GGError> {
+        texture_creator.create_texture_from_image(&self.image)
+    }
+
+    /// Returns region of the image with given key.
+    ///
+    pub fn region(&self, key: &K) -> Option<&AtlasRegion> {
+        self.regions.get(key)
+    }
+    /// Returns iterator over keys and regions of all packed images.
+    ///
+    pub fn regions(&self) -> impl Iterator<Item = (&K, &AtlasRegion)> {
+        self.regions.iter()
+    }
+}
+impl<K> fmt::Debug for Atlas<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Atlas")
+            .field("image", &self.image)
+            .field("regions", &self.regions.len())
+            .finish()
+    }
+}
+
+/// Packs rectangles of given sizes into shelves of given width, leaving padding between them
+/// and around them.
+///
+/// Returns positions of rectangles (in the same order as their sizes) and total height,
+/// or `None` if any of rectangles is too wide.
+///
+fn pack(sizes: &[(u32, u32)], width: u32, padding: u32) -> Option<(Vec<(u32, u32)>, u32)> {
+    let mut order: Vec<usize> = (0..sizes.len()).collect();
+    order.sort_by_key(|&index| std::cmp::Reverse(sizes[index].1));
+
+    let mut positions: Vec<(u32, u32)> = vec![(0, 0); sizes.len()];
+    let (mut x, mut y, mut shelf_height): (u32, u32, u32) = (padding, padding, 0);
+    for index in order {
+        let (rectangle_width, rectangle_height): (u32, u32) = sizes[index];
+        if rectangle_width + 2 * padding > width {
+            return None;
+        }
+        if x + rectangle_width + padding > width {
+            y += shelf_height + padding;
+            x = padding;
+            shelf_height = 0;
+        }
+        positions[index] = (x, y);
+        x += rectangle_width + padding;
+        shelf_height = shelf_height.max(rectangle_height);
+    }
+    Some((positions, y + shelf_height + padding))
+}
+
+/// [`AtlasBuilder`] struct collects images and packs them into [`Atlas`].
+///
+/// Atlas is always square-ish with power of two sides (width is a power of two and
+/// height is a power of two that does not exceed width).
+///
+/// # Example
+/// ```rust, no_run
+/// # use ggengine::datacore::images::{Image, PixelFormat};
+/// # use ggengine::graphicscore::atlas::{Atlas, AtlasBuilder, AtlasRegion};
+/// let coin: Image = Image::new(16, 16, PixelFormat::RGBA32);
+/// let heart: Image = Image::new(24, 20, PixelFormat::RGBA32);
+///
+/// let atlas: Atlas<&str> = AtlasBuilder::new()
+///     .padding(2)
+///     .extrusion(1)
+///     .add_image("coin", &coin)
+///     .add_image("heart", &heart)
+///     .build()
+///     .expect("Images should fit into atlas");
+/// let region: &AtlasRegion = atlas.region(&"heart").expect("Image was packed");
+/// assert_eq!(region.area.width(), 24);
+/// ```
+///
+pub struct AtlasBuilder<'i, K> {
+    /// Images that will be packed.
+    ///
+    images: Vec<(K, &'i Image<'i>)>,
+
+    /// Number of transparent pixels between images.
+    ///
+    padding: u32,
+    /// Number of pixels by which borders of images are extruded.
+    ///
+    extrusion: u32,
+    /// Maximal width and height of the atlas.
+    ///
+    max_size: u32,
+    /// Pixel format of the atlas.
+    ///
+    pixel_format: PixelFormat,
+}
+impl<'i, K: Eq + Hash> AtlasBuilder<'i, K> {
+    /// Initializes [`AtlasBuilder`] without images, padding and extrusion,
+    /// with maximal size of 4096 pixels and `PixelFormat::RGBA32`.
+    ///
+    pub fn new() -> Self {
+        AtlasBuilder {
+            images: Vec::new(),
+
+            padding: 0,
+            extrusion: 0,
+            max_size: 4096,
+            pixel_format: PixelFormat::RGBA32,
+        }
+    }
+
+    /// Adds image that will be packed under given key.
+    ///
+    pub fn add_image(mut self, key: K, image: &'i Image<'i>) -> Self {
+        self.images.push((key, image));
+        self
+    }
+    /// Sets number of transparent pixels between images.
+    ///
+    pub fn padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    }
+    /// Sets number of pixels by which borders of images are extruded.
+    ///
+    pub fn extrusion(mut self, extrusion: u32) -> Self {
+        self.extrusion = extrusion;
+        self
+    }
+    /// Sets maximal width and height of the atlas.
+    ///
+    pub fn max_size(mut self, max_size: u32) -> Self {
+        self.max_size = max_size;
+        self
+    }
+    /// Sets pixel format of the atlas.
+    ///
+    pub fn pixel_format(mut self, pixel_format: PixelFormat) -> Self {
+        self.pixel_format = pixel_format;
+        self
+    }
+
+    /// Packs images into the atlas.
+    ///
+    /// # Errors
+    /// Error is returned if images do not fit into atlas of maximal size or if `sdl2` fails to blit images.
+    ///
+    pub fn build(self) -> Result<Atlas<K>, GGError> {
+        let sizes: Vec<(u32, u32)> = self
+            .images
+            .iter()
+            .map(|(_, image)| {
+                (
+                    image.width() + 2 * self.extrusion,
+                    image.height() + 2 * self.extrusion,
+                )
+            })
+            .collect();
+        let area: u64 = sizes
+            .iter()
+            .map(|&(width, height)| {
+                u64::from(width + self.padding) * u64::from(height + self.padding)
+            })
+            .sum();
+
+        let mut width: u32 = ((area as f64).sqrt().ceil() as u32)
+            .max(1)
+            .next_power_of_two();
+        let (positions, height): (Vec<(u32, u32)>, u32) = loop {
+            if width > self.max_size {
+                return Err(GGError::InvalidArgument(format!(
+                    "Images do not fit into atlas of {0}x{0} pixels",
+                    self.max_size
+                )));
+            }
+            match pack(&sizes, width, self.padding) {
+                Some((positions, height)) if height <= width => break (positions, height),
+                _ => width *= 2,
+            }
+        };
+
+        let mut atlas: Image<'static> =
+            Image::new(width, height.next_power_of_two(), self.pixel_format);
+        atlas.set_blend_mode(BlendingType::None);
+        let mut regions: HashMap<K, AtlasRegion> = HashMap::with_capacity(self.images.len());
+        for ((key, image), (x, y)) in self.images.into_iter().zip(positions) {
+            let (left, upper): (u32, u32) = (x + self.extrusion, y + self.extrusion);
+            let area: ImageArea = ImageArea::from((
+                (left, upper),
+                (left + image.width(), upper + image.height()),
+            ));
+
+            let mut source: Image = image.convert(self.pixel_format);
+            source.set_blend_mode(BlendingType::None);
+            source.blit_to(None, &mut atlas, Some(area))?;
+            extrude(&mut atlas, area, self.extrusion);
+
+            let (atlas_width, atlas_height): (f32, f32) =
+                (atlas.width() as f32, atlas.height() as f32);
+            let _ = regions.insert(
+                key,
+                AtlasRegion {
+                    area,
+                    uv_min: Vector2 {
+                        x: left as f32 / atlas_width,
+                        y: upper as f32 / atlas_height,
+                    },
+                    uv_max: Vector2 {
+                        x: area.right_lower().0 as f32 / atlas_width,
+                        y: area.right_lower().1 as f32 / atlas_height,
+                    },
+                },
+            );
+        }
+        Ok(Atlas {
+            image: atlas,
+            regions,
+        })
+    }
+}
+impl<'i, K: Eq + Hash> Default for AtlasBuilder<'i, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<'i, K> fmt::Debug for AtlasBuilder<'i, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AtlasBuilder")
+            .field("images", &self.images.len())
+            .field("padding", &self.padding)
+            .field("extrusion", &self.extrusion)
+            .field("max_size", &self.max_size)
+            .field("pixel_format", &self.pixel_format)
+            .finish()
+    }
+}
+
+/// Repeats border pixels of given area of the atlas outwards for given number of pixels.
+///
+fn extrude(atlas: &mut Image, area: ImageArea, extrusion: u32) {
+    if area.is_empty() || extrusion == 0 {
+        return;
+    }
+    let ((left, upper), (right, lower)): ((u32, u32), (u32, u32)) =
+        (area.left_upper(), area.right_lower());
+    let pitch: usize = atlas.pitch() as usize;
+    let pixel_size: usize = atlas.pixel_offset(1, 0);
+    let offset = |x: u32, y: u32| x as usize * pixel_size + y as usize * pitch;
+    let row_size: usize = (right - left) as usize * pixel_size;
+
+    atlas.access_data_mut(|data| {
+        // rows are copied first, so that columns (which include extruded rows) fill the corners
+        for distance in 1..=extrusion {
+            let first: usize = offset(left, upper);
+            data.copy_within(first..first + row_size, offset(left, upper - distance));
+            let last: usize = offset(left, lower - 1);
+            data.copy_within(last..last + row_size, offset(left, lower - 1 + distance));
+        }
+        for y in upper - extrusion..lower + extrusion {
+            for distance in 1..=extrusion {
+                let first: usize = offset(left, y);
+                data.copy_within(first..first + pixel_size, offset(left - distance, y));
+                let last: usize = offset(right - 1, y);
+                data.copy_within(last..last + pixel_size, offset(right - 1 + distance, y));
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pack;
+
+    #[test]
+    fn shelf_packing() {
+        let sizes: [(u32, u32); 4] = [(10, 4), (6, 8), (8, 8), (20, 2)];
+        let (positions, height): (Vec<(u32, u32)>, u32) =
+            pack(&sizes, 32, 1).expect("Rectangles are narrower than atlas");
+        assert_eq!(positions, [(17, 1), (1, 1), (8, 1), (1, 10)]);
+        assert_eq!(height, 13);
+
+        for (index, (&(x1, y1), &(w1, h1))) in positions.iter().zip(&sizes).enumerate() {
+            assert!(x1 + w1 < 32);
+            for (&(x2, y2), &(w2, h2)) in positions.iter().zip(&sizes).skip(index + 1) {
+                assert!(x1 + w1 < x2 || x2 + w2 < x1 || y1 + h1 < y2 || y2 + h2 < y1);
+            }
+        }
+        assert!(pack(&[(31, 1)], 32, 1).is_none());
+    }
+}
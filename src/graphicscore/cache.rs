@@ -0,0 +1,332 @@
+//! `graphicscore::cache` submodule implements [`TextureCache`] - reference counted storage of
+//! image assets that keeps their [`Texture`]s within the budget of texture memory.
+//!
+//! Every asset in the cache is stored as an [`Image`] (in RAM) and, while it is used, as a [`Texture`] (in VRAM).
+//! Assets are referred to by [`TextureHandle`]s, which are reference counted - asset is considered
+//! referenced while at least one of its handles is alive.
+//!
+//! When texture memory exceeds the budget, textures of unreferenced assets are destroyed
+//! in least recently used order. Images of those assets stay in the cache, so textures are
+//! transparently recreated on next use.
+//!
+
+use crate::{
+    datacore::{
+        assets::{AssetFormat, AssetManager, AssetMetadata},
+        images::Image,
+    },
+    graphicscore::textures::{Texture, TextureCreator},
+    log::{self, LogTarget},
+    GGError,
+};
+use std::{
+    collections::HashMap,
+    fmt,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+/// [`TextureHandle`] struct is a reference counted handle to the asset of [`TextureCache`].
+///
+/// Cloning handle increases reference count of the asset and dropping decreases it.
+///
+#[derive(Clone, Debug)]
+pub struct TextureHandle {
+    /// Name of the asset that is shared between all handles and the cache.
+    ///
+    name: Rc<PathBuf>,
+}
+impl TextureHandle {
+    /// Returns name of the asset.
+    ///
+    pub fn name(&self) -> &Path {
+        self.name.as_path()
+    }
+}
+impl PartialEq for TextureHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.name, &other.name)
+    }
+}
+impl Eq for TextureHandle {}
+
+/// [`CacheEntry`] struct holds single asset of [`TextureCache`].
+///
+struct CacheEntry<'t> {
+    /// Name of the asset that is shared with handles (used for reference counting).
+    ///
+    name: Rc<PathBuf>,
+    /// Image from which texture is created.
+    ///
+    image: Image<'static>,
+    /// Texture of the asset, if it is resident.
+    ///
+    texture: Option<Texture<'t>>,
+    /// Value of cache clock when the asset was used last time.
+    ///
+    last_used: u64,
+}
+impl<'t> CacheEntry<'t> {
+    /// Returns whether any handle to the asset is alive.
+    ///
+    fn is_referenced(&self) -> bool {
+        Rc::strong_count(&self.name) > 1
+    }
+}
+
+/// Returns number of bytes that texture occupies in texture memory.
+///
+fn texture_byte_size(texture: &Texture) -> usize {
+    let (width, height): (u32, u32) = texture.size();
+    let pixel_size: usize = texture
+        .pixel_format()
+        .map_or(4, |pixel_format| pixel_format.pixel_byte_size());
+    width as usize * height as usize * pixel_size
+}
+
+/// [`TextureCache`] struct stores image assets and creates textures for them on demand,
+/// keeping texture memory within given budget.
+///
+/// Budget is soft: textures of referenced assets are never destroyed, so if all assets are
+/// referenced, budget can be exceeded.
+///
+/// # Example
+/// ```rust, no_run
+/// # use ggengine::GGEngine;
+/// # use ggengine::utils::Window;
+/// # use ggengine::graphicscore::drawing::{Canvas, WindowCanvas};
+/// # use ggengine::datacore::assets::AssetManager;
+/// # use ggengine::graphicscore::cache::{TextureCache, TextureHandle};
+/// # use ggengine::graphicscore::textures::TextureCreator;
+/// let engine: GGEngine = GGEngine::init().expect("Engine should be initialized");
+/// let window: Window = engine
+///     .build_window("ggengine", 1000, 1000, Default::default())
+///     .expect("Window should be built");
+/// let canvas: WindowCanvas = WindowCanvas::from_window(window, true).expect("Canvas should be created");
+/// let texture_creator: TextureCreator = canvas.texture_creator();
+/// let manager: AssetManager = AssetManager::initialize_at("assets")
+///     .expect("Filename should be correct");
+///
+/// // 64 MiB of texture memory
+/// let mut cache: TextureCache = TextureCache::new(&texture_creator, 64 * 1024 * 1024);
+/// let player: TextureHandle = cache.load(&manager, "player.png").expect("Image should be loaded");
+///
+/// let (width, height): (u32, u32) = cache.texture(&player).expect("Texture should be created").size();
+/// drop(player);
+/// // texture of the player may now be evicted, but it will be recreated when it is needed again
+/// ```
+///
+pub struct TextureCache<'t, 'c> {
+    /// Texture creator that is used to create textures.
+    ///
+    texture_creator: &'t TextureCreator<'c>,
+    /// Assets of the cache.
+    ///
+    entries: HashMap<PathBuf, CacheEntry<'t>>,
+
+    /// Maximal number of bytes of texture memory that unreferenced textures are allowed to occupy.
+    ///
+    budget: usize,
+    /// Number of bytes of texture memory that resident textures occupy.
+    ///
+    used: usize,
+    /// Counter that is incremented on every use of texture.
+    ///
+    clock: u64,
+}
+impl<'t, 'c> TextureCache<'t, 'c> {
+    /// Initializes empty [`TextureCache`] with given budget (in bytes of texture memory).
+    ///
+    pub fn new(texture_creator: &'t TextureCreator<'c>, budget: usize) -> Self {
+        TextureCache {
+            texture_creator,
+            entries: HashMap::new(),
+
+            budget,
+            used: 0,
+            clock: 0,
+        }
+    }
+
+    /// Returns budget of the cache in bytes of texture memory.
+    ///
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+    /// Sets budget of the cache in bytes of texture memory, evicting textures if needed.
+    ///
+    pub fn set_budget(&mut self, budget: usize) {
+        self.budget = budget;
+        self.evict(None);
+    }
+    /// Returns number of bytes of texture memory that resident textures occupy.
+    ///
+    pub fn used_bytes(&self) -> usize {
+        self.used
+    }
+    /// Returns number of assets in the cache.
+    ///
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    /// Returns whether the cache has no assets.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Inserts image under given name and returns handle to it.
+    ///
+    /// If asset with the same name was already present, its image is replaced
+    /// (texture will be recreated from new image) and old handles stay valid.
+    ///
+    pub fn insert_image(&mut self, name: impl AsRef<Path>, image: Image<'static>) -> TextureHandle {
+        if let Some(entry) = self.entries.get_mut(name.as_ref()) {
+            entry.image = image;
+            if let Some(texture) = entry.texture.take() {
+                self.used -= texture_byte_size(&texture);
+            }
+            return TextureHandle {
+                name: Rc::clone(&entry.name),
+            };
+        }
+
+        let name: Rc<PathBuf> = Rc::new(name.as_ref().to_path_buf());
+        let _ = self.entries.insert(
+            name.to_path_buf(),
+            CacheEntry {
+                name: Rc::clone(&name),
+                image,
+                texture: None,
+                last_used: self.clock,
+            },
+        );
+        TextureHandle { name }
+    }
+    /// Loads image from asset manager (or takes it from the cache, if it was already loaded)
+    /// and returns handle to it.
+    ///
+    pub fn load(
+        &mut self,
+        manager: &AssetManager,
+        filename: impl AsRef<Path>,
+    ) -> Result<TextureHandle, GGError> {
+        if let Some(entry) = self.entries.get(filename.as_ref()) {
+            return Ok(TextureHandle {
+                name: Rc::clone(&entry.name),
+            });
+        }
+        let image: Image<'static> = manager.load_asset(AssetMetadata {
+            filename: filename.as_ref().to_path_buf(),
+            format: AssetFormat::Image,
+        })?;
+        Ok(self.insert_image(filename, image))
+    }
+    /// Returns handle to the asset with given name, if it is present.
+    ///
+    pub fn handle(&self, name: impl AsRef<Path>) -> Option<TextureHandle> {
+        self.entries.get(name.as_ref()).map(|entry| TextureHandle {
+            name: Rc::clone(&entry.name),
+        })
+    }
+
+    /// Returns image of the asset, if it is present.
+    ///
+    pub fn image(&self, handle: &TextureHandle) -> Option<&Image<'static>> {
+        self.entries
+            .get(handle.name.as_ref())
+            .map(|entry| &entry.image)
+    }
+    /// Returns texture of the asset, creating it from the image if it is not resident.
+    ///
+    /// Using texture marks it as the most recently used one; if texture was created, textures of
+    /// other unreferenced assets could be evicted to fit into the budget.
+    ///
+    /// This function fails if asset was removed from the cache or if texture creation fails.
+    ///
+    pub fn texture(&mut self, handle: &TextureHandle) -> Result<&Texture<'t>, GGError> {
+        self.clock += 1;
+        let clock: u64 = self.clock;
+        let entry: &mut CacheEntry<'t> =
+            self.entries.get_mut(handle.name.as_ref()).ok_or_else(|| {
+                GGError::InvalidArgument(format!(
+                    "Asset {} is not in the cache",
+                    handle.name.display()
+                ))
+            })?;
+        entry.last_used = clock;
+
+        if entry.texture.is_none() {
+            let texture: Texture<'t> = self
+                .texture_creator
+                .create_texture_from_image(&entry.image)?;
+            self.used += texture_byte_size(&texture);
+            entry.texture = Some(texture);
+            self.evict(Some(handle.name.as_ref()));
+        }
+        Ok(self
+            .entries
+            .get(handle.name.as_ref())
+            .and_then(|entry| entry.texture.as_ref())
+            .expect("Texture was created"))
+    }
+    /// Returns whether texture of the asset is resident.
+    ///
+    pub fn is_resident(&self, handle: &TextureHandle) -> bool {
+        self.entries
+            .get(handle.name.as_ref())
+            .is_some_and(|entry| entry.texture.is_some())
+    }
+
+    /// Removes assets to which no handles are alive and returns number of removed assets.
+    ///
+    pub fn remove_unused(&mut self) -> usize {
+        let before: usize = self.entries.len();
+        let mut freed: usize = 0;
+        self.entries.retain(|_, entry| {
+            let is_used: bool = entry.is_referenced();
+            if !is_used {
+                freed += entry.texture.as_ref().map_or(0, texture_byte_size);
+            }
+            is_used
+        });
+        self.used -= freed;
+        before - self.entries.len()
+    }
+
+    /// Destroys least recently used textures of unreferenced assets until texture memory fits into the budget.
+    ///
+    /// Texture of asset with the `keep` name is never destroyed.
+    ///
+    fn evict(&mut self, keep: Option<&Path>) {
+        while self.used > self.budget {
+            let Some(entry) = self
+                .entries
+                .values_mut()
+                .filter(|entry| {
+                    entry.texture.is_some()
+                        && !entry.is_referenced()
+                        && Some(entry.name.as_path()) != keep
+                })
+                .min_by_key(|entry| entry.last_used)
+            else {
+                break;
+            };
+            let texture: Texture<'t> = entry.texture.take().expect("Texture is resident");
+            self.used -= texture_byte_size(&texture);
+            log::debug(
+                LogTarget::GraphicsCore,
+                format_args!("Texture of {} was evicted", entry.name.display()),
+            );
+        }
+    }
+}
+impl<'t, 'c> fmt::Debug for TextureCache<'t, 'c> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextureCache")
+            .field("assets", &self.entries.len())
+            .field("budget", &self.budget)
+            .field("used", &self.used)
+            .finish()
+    }
+}
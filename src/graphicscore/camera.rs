@@ -0,0 +1,220 @@
+//! `graphicscore::camera` submodule implements [`Camera2D`] - resource that defines which part
+//! of the world is shown on the screen.
+//!
+//! World coordinates are converted to screen coordinates by moving camera position to the center
+//! of the viewport, rotating by inverse of camera rotation and scaling by camera zoom.
+//!
+//! Objects that are marked with [`ScreenSpace`] component are not affected by camera - they are
+//! positioned relative to [`Anchor`] of the viewport, respecting [`SafeArea`] margins.
+//!
+
+use crate::{
+    gamecore::components::{Component, Resource},
+    mathcore::{
+        shapes::Aabb,
+        vectors::{Point, Vector2},
+        Angle,
+    },
+};
+
+/// [`Camera2D`] struct is a resource that describes view of the world.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::graphicscore::camera::Camera2D;
+/// # use ggengine::mathcore::{shapes::Aabb, vectors::Point};
+/// let mut camera: Camera2D = Camera2D::new((800.0, 600.0));
+/// camera.position = Point::from([100.0, 100.0]);
+/// camera.zoom = 2.0;
+///
+/// let screen: Point = camera.world_to_screen(Point::from([110.0, 100.0]));
+/// assert_eq!((screen.x, screen.y), (420.0, 300.0));
+/// assert_eq!(
+///     camera.view_rect(),
+///     Aabb::new(Point::from([-100.0, -50.0]), Point::from([300.0, 250.0]))
+/// );
+/// ```
+///
+#[derive(Copy, Clone, Debug)]
+pub struct Camera2D {
+    /// Point of the world that is shown in the center of the viewport.
+    ///
+    pub position: Point,
+    /// Rotation of the camera.
+    ///
+    pub rotation: Angle,
+    /// Zoom of the camera (zoom that is bigger than 1 shows objects bigger).
+    ///
+    pub zoom: f32,
+    /// Size of the viewport in pixels (width and height).
+    ///
+    pub viewport: (f32, f32),
+}
+impl Camera2D {
+    /// Constructs camera with given viewport that is centered at the origin of the world,
+    /// without rotation and zoom.
+    ///
+    pub fn new(viewport: (f32, f32)) -> Self {
+        Camera2D {
+            position: Point::zero(),
+            rotation: Angle::ZERO,
+            zoom: 1.0,
+            viewport,
+        }
+    }
+
+    /// Returns center of the viewport in screen coordinates.
+    ///
+    fn viewport_center(&self) -> Vector2 {
+        Vector2::from([self.viewport.0 / 2.0, self.viewport.1 / 2.0])
+    }
+
+    /// Converts point from world coordinates to screen coordinates.
+    ///
+    pub fn world_to_screen(&self, point: Point) -> Point {
+        (point - self.position).rotate_by(-self.rotation) * self.zoom + self.viewport_center()
+    }
+    /// Converts point from screen coordinates to world coordinates.
+    ///
+    pub fn screen_to_world(&self, point: Point) -> Point {
+        ((point - self.viewport_center()) / self.zoom).rotate_by(self.rotation) + self.position
+    }
+
+    /// Returns the smallest box in world coordinates that contains everything that is shown in the viewport.
+    ///
+    pub fn view_rect(&self) -> Aabb {
+        let (width, height): (f32, f32) = self.viewport;
+        Aabb::from_points(&[
+            self.screen_to_world(Point::from([0.0, 0.0])),
+            self.screen_to_world(Point::from([width, 0.0])),
+            self.screen_to_world(Point::from([width, height])),
+            self.screen_to_world(Point::from([0.0, height])),
+        ])
+        .expect("Slice of points is not empty")
+    }
+}
+impl Resource for Camera2D {}
+
+/// [`Anchor`] enum lists points of the viewport to which screen space objects can be anchored.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Anchor {
+    /// Left upper corner.
+    ///
+    #[default]
+    TopLeft,
+    /// Middle of the upper side.
+    ///
+    Top,
+    /// Right upper corner.
+    ///
+    TopRight,
+    /// Middle of the left side.
+    ///
+    Left,
+    /// Center.
+    ///
+    Center,
+    /// Middle of the right side.
+    ///
+    Right,
+    /// Left lower corner.
+    ///
+    BottomLeft,
+    /// Middle of the lower side.
+    ///
+    Bottom,
+    /// Right lower corner.
+    ///
+    BottomRight,
+}
+impl Anchor {
+    /// Returns relative position of the anchor inside of an area (`(0.0, 0.0)` is left upper corner
+    /// and `(1.0, 1.0)` is right lower one).
+    ///
+    fn relative_position(self) -> (f32, f32) {
+        match self {
+            Anchor::TopLeft => (0.0, 0.0),
+            Anchor::Top => (0.5, 0.0),
+            Anchor::TopRight => (1.0, 0.0),
+            Anchor::Left => (0.0, 0.5),
+            Anchor::Center => (0.5, 0.5),
+            Anchor::Right => (1.0, 0.5),
+            Anchor::BottomLeft => (0.0, 1.0),
+            Anchor::Bottom => (0.5, 1.0),
+            Anchor::BottomRight => (1.0, 1.0),
+        }
+    }
+}
+
+/// [`SafeArea`] struct is a resource that holds margins (in pixels) of the viewport which
+/// screen space objects should not be anchored to (e.g. because of display cutouts or overscan).
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SafeArea {
+    /// Margin of the left side.
+    ///
+    pub left: f32,
+    /// Margin of the upper side.
+    ///
+    pub top: f32,
+    /// Margin of the right side.
+    ///
+    pub right: f32,
+    /// Margin of the lower side.
+    ///
+    pub bottom: f32,
+}
+impl SafeArea {
+    /// Constructs safe area with the same margin on all sides.
+    ///
+    pub fn uniform(margin: f32) -> Self {
+        SafeArea {
+            left: margin,
+            top: margin,
+            right: margin,
+            bottom: margin,
+        }
+    }
+}
+impl Resource for SafeArea {}
+
+/// [`ScreenSpace`] struct is a component that marks game object as positioned in screen coordinates.
+///
+/// Screen space objects are not affected by [`Camera2D`]: translation of their
+/// [`Transform2D`](crate::mathcore::transforms::Transform2D) is an offset (in pixels) from the anchor point
+/// of the viewport (shrunk by [`SafeArea`]), which makes them suitable for HUD and damage numbers.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::graphicscore::camera::{Anchor, Camera2D, SafeArea, ScreenSpace};
+/// # use ggengine::mathcore::vectors::{Point, Vector2};
+/// let camera: Camera2D = Camera2D::new((800.0, 600.0));
+/// let health_bar: ScreenSpace = ScreenSpace { anchor: Anchor::BottomRight };
+///
+/// let position: Point = health_bar.screen_position(Vector2::from([-50.0, -20.0]), &camera, &SafeArea::uniform(10.0));
+/// assert_eq!((position.x, position.y), (740.0, 570.0));
+/// ```
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ScreenSpace {
+    /// Point of the viewport from which object is offset.
+    ///
+    pub anchor: Anchor,
+}
+impl ScreenSpace {
+    /// Returns position of object with given offset in screen coordinates.
+    ///
+    pub fn screen_position(
+        &self,
+        offset: Vector2,
+        camera: &Camera2D,
+        safe_area: &SafeArea,
+    ) -> Point {
+        let (x, y): (f32, f32) = self.anchor.relative_position();
+        let width: f32 = camera.viewport.0 - safe_area.left - safe_area.right;
+        let height: f32 = camera.viewport.1 - safe_area.top - safe_area.bottom;
+        Point::from([safe_area.left + x * width, safe_area.top + y * height]) + offset
+    }
+}
+impl Component for ScreenSpace {}
@@ -0,0 +1,416 @@
+//! `graphicscore::cameraeffects` submodule implements camera effects - components that are
+//! applied to [`Camera2D`] resource by [`update_camera_effects`] system before rendering.
+//!
+//! [`CameraFollow`] moves camera after its game object (with dead zone and smoothing),
+//! [`CameraShake`] shakes camera by noise (trauma-based) and impulses, and
+//! [`ZoomPunch`] briefly zooms camera in.
+//!
+//! Effects are advanced by [`Time`] resource, each by its own [`Clock`].
+//!
+//! Shake and zoom punch are temporary - system reverts what was applied during the previous update
+//! (it is stored in [`AppliedCameraEffects`] resource) before applying new effects,
+//! so they do not accumulate in the camera.
+//!
+
+use crate::{
+    gamecore::{
+        components::{Component, Resource},
+        scenes::Scene,
+        time::{Clock, Time},
+    },
+    graphicscore::camera::Camera2D,
+    mathcore::{
+        noise::{Noise, Perlin},
+        transforms::Transform2D,
+        vectors::{Point, Vector2},
+        Angle,
+    },
+};
+
+/// [`CameraFollow`] struct is a component that makes camera follow its game object.
+///
+/// Camera is moved only when game object leaves the dead zone around the center of the view
+/// (to the edge of the dead zone).
+/// If several game objects have this component, camera follows the first one.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CameraFollow {
+    /// Offset of the followed point from translation of game object.
+    ///
+    pub offset: Vector2,
+    /// Half of width and half of height of the dead zone (in world units).
+    ///
+    pub dead_zone: (f32, f32),
+    /// Rate (per second) with which camera catches up with its target (zero makes it instant).
+    ///
+    pub smoothing: f32,
+    /// Clock by which smoothing is advanced.
+    ///
+    pub clock: Clock,
+}
+impl CameraFollow {
+    /// Constructs component that makes camera follow game object instantly, without dead zone.
+    ///
+    pub fn new() -> Self {
+        CameraFollow {
+            offset: Vector2::zero(),
+            dead_zone: (0.0, 0.0),
+            smoothing: 0.0,
+            clock: Clock::Scaled,
+        }
+    }
+
+    /// Returns position of the camera that follows target after given delta (in seconds).
+    ///
+    fn follow(&self, position: Point, target: Point, delta: f32) -> Point {
+        let excess = |distance: f32, half_size: f32| -> f32 {
+            distance - distance.clamp(-half_size.abs(), half_size.abs())
+        };
+        let distance: Vector2 = target + self.offset - position;
+        let excess: Vector2 = Vector2::from([
+            excess(distance.x, self.dead_zone.0),
+            excess(distance.y, self.dead_zone.1),
+        ]);
+        if self.smoothing > 0.0 {
+            position + excess * (1.0 - (-self.smoothing * delta).exp())
+        } else {
+            position + excess
+        }
+    }
+}
+impl Default for CameraFollow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Component for CameraFollow {}
+
+/// [`CameraShake`] struct is a component that shakes camera.
+///
+/// Shake is a sum of noise shake and impulse shake.
+/// Noise shake is driven by trauma (from 0.0 to 1.0) - its offset is proportional to the square of trauma,
+/// which linearly decays over time (explosions add trauma).
+/// Impulse shake is a directional kick that oscillates and exponentially decays (recoil, hits).
+///
+/// # Example
+/// ```rust
+/// # use ggengine::graphicscore::cameraeffects::CameraShake;
+/// # use ggengine::mathcore::vectors::Vector2;
+/// let mut shake: CameraShake = CameraShake::new(42);
+/// shake.add_trauma(0.8);
+/// shake.add_impulse(Vector2::from([5.0, 0.0]));
+/// assert_eq!(shake.trauma(), 0.8);
+///
+/// shake.advance(0.5);
+/// assert_eq!(shake.trauma(), 0.3);
+/// ```
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CameraShake {
+    /// Maximal offset (in world units) of noise shake.
+    ///
+    pub max_offset: f32,
+    /// Maximal rotation of noise shake.
+    ///
+    pub max_rotation: Angle,
+    /// Frequency of shake (in oscillations per second).
+    ///
+    pub frequency: f32,
+    /// Amount of trauma that decays every second.
+    ///
+    pub trauma_decay: f32,
+    /// Rate (per second) of exponential decay of impulses.
+    ///
+    pub impulse_decay: f32,
+    /// Clock by which shake is advanced.
+    ///
+    pub clock: Clock,
+
+    /// Current trauma.
+    ///
+    trauma: f32,
+    /// Current impulse.
+    ///
+    impulse: Vector2,
+    /// Time (in seconds) since shake was created.
+    ///
+    time: f32,
+    /// Noise generator.
+    ///
+    noise: Perlin,
+}
+impl CameraShake {
+    /// Constructs shake without trauma and impulses with given noise seed,
+    /// maximal offset of 10 units, maximal rotation of 5 degrees, frequency of 15,
+    /// trauma decay of 1, impulse decay of 8 and scaled clock.
+    ///
+    pub fn new(seed: u64) -> Self {
+        CameraShake {
+            max_offset: 10.0,
+            max_rotation: Angle::from_degrees(5.0),
+            frequency: 15.0,
+            trauma_decay: 1.0,
+            impulse_decay: 8.0,
+            clock: Clock::Scaled,
+
+            trauma: 0.0,
+            impulse: Vector2::zero(),
+            time: 0.0,
+            noise: Perlin::new(seed),
+        }
+    }
+
+    /// Returns current trauma.
+    ///
+    pub fn trauma(&self) -> f32 {
+        self.trauma
+    }
+    /// Adds trauma (resulting trauma is clamped between 0.0 and 1.0).
+    ///
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0.0, 1.0);
+    }
+    /// Adds impulse (initial offset of camera in world units).
+    ///
+    pub fn add_impulse(&mut self, impulse: Vector2) {
+        self.impulse += impulse;
+    }
+
+    /// Advances shake by given time (in seconds), decaying trauma and impulses.
+    ///
+    pub fn advance(&mut self, delta: f32) {
+        self.time += delta;
+        self.trauma = (self.trauma - self.trauma_decay * delta).max(0.0);
+        self.impulse *= (-self.impulse_decay * delta).exp();
+    }
+    /// Returns current offset and rotation of the camera.
+    ///
+    pub fn shake(&self) -> (Vector2, Angle) {
+        let time: f32 = self.time * self.frequency;
+        let noise = |channel: f32| self.noise.get(Point::from([time, channel + 0.5]));
+        let amount: f32 = self.trauma * self.trauma;
+        let impulse: Vector2 = self.impulse * (time * std::f32::consts::TAU).cos();
+        (
+            Vector2::from([noise(0.0), noise(10.0)]) * (amount * self.max_offset) + impulse,
+            self.max_rotation * (amount * noise(20.0)),
+        )
+    }
+}
+impl Component for CameraShake {}
+
+/// [`ZoomPunch`] struct is a component that briefly zooms camera in.
+///
+/// Punch multiplies zoom of the camera by `1.0 + strength`, and strength exponentially decays over time.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ZoomPunch {
+    /// Rate (per second) of exponential decay of strength.
+    ///
+    pub decay: f32,
+    /// Clock by which punch is advanced.
+    ///
+    pub clock: Clock,
+
+    /// Current strength.
+    ///
+    strength: f32,
+}
+impl ZoomPunch {
+    /// Constructs zoom punch without strength with given decay and scaled clock.
+    ///
+    pub fn new(decay: f32) -> Self {
+        ZoomPunch {
+            decay,
+            clock: Clock::Scaled,
+            strength: 0.0,
+        }
+    }
+
+    /// Returns current strength.
+    ///
+    pub fn strength(&self) -> f32 {
+        self.strength
+    }
+    /// Adds strength (negative strength zooms out, but resulting strength is never less than -0.9).
+    ///
+    pub fn punch(&mut self, strength: f32) {
+        self.strength = (self.strength + strength).max(-0.9);
+    }
+    /// Advances punch by given time (in seconds), decaying its strength.
+    ///
+    pub fn advance(&mut self, delta: f32) {
+        self.strength *= (-self.decay * delta).exp();
+    }
+}
+impl Component for ZoomPunch {}
+
+/// [`AppliedCameraEffects`] struct is a resource that holds temporary effects that were applied
+/// to the camera during the last run of [`update_camera_effects`].
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AppliedCameraEffects {
+    /// Applied offset of position.
+    ///
+    pub offset: Vector2,
+    /// Applied rotation.
+    ///
+    pub rotation: Angle,
+    /// Applied factor of zoom.
+    ///
+    pub zoom: f32,
+}
+impl Default for AppliedCameraEffects {
+    fn default() -> Self {
+        AppliedCameraEffects {
+            offset: Vector2::zero(),
+            rotation: Angle::ZERO,
+            zoom: 1.0,
+        }
+    }
+}
+impl Resource for AppliedCameraEffects {}
+
+/// Advances all camera effects of the scene by [`Time`] resource and applies them to [`Camera2D`] resource.
+///
+/// Does nothing if there is no camera; effects are not advanced if there is no [`Time`] resource.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::scenes::Scene;
+/// # use ggengine::graphicscore::{camera::Camera2D, cameraeffects::{update_camera_effects, CameraFollow, ZoomPunch}};
+/// # use ggengine::mathcore::{transforms::Transform2D, vectors::{Point, Vector2}};
+/// let mut scene: Scene = Scene::new();
+/// let _ = scene.resources_mut().insert_resource(Camera2D::new((800.0, 600.0)));
+/// let follow: CameraFollow = CameraFollow { dead_zone: (50.0, 50.0), ..CameraFollow::new() };
+/// let _ = scene.spawn((follow, Transform2D::from_translation(Vector2::from([80.0, 20.0]))));
+///
+/// let mut punch: ZoomPunch = ZoomPunch::new(10.0);
+/// punch.punch(0.5);
+/// let _ = scene.spawn(punch);
+///
+/// update_camera_effects(&mut scene);
+/// let camera: Camera2D = *scene.resources().get_resource::<Camera2D>().expect("Camera was inserted");
+/// assert_eq!(camera.position, Point::from([30.0, 0.0]));
+/// assert_eq!(camera.zoom, 1.5);
+/// ```
+///
+pub fn update_camera_effects(scene: &mut Scene) {
+    let Some(&camera) = scene.resources().get_resource::<Camera2D>() else {
+        return;
+    };
+    let time: Time = scene
+        .resources()
+        .get_resource::<Time>()
+        .copied()
+        .unwrap_or_default();
+    let applied: AppliedCameraEffects = scene
+        .resources()
+        .get_resource::<AppliedCameraEffects>()
+        .copied()
+        .unwrap_or_default();
+    let mut camera: Camera2D = Camera2D {
+        position: camera.position - applied.offset,
+        rotation: camera.rotation - applied.rotation,
+        zoom: camera.zoom / applied.zoom,
+        ..camera
+    };
+
+    let target: Option<(CameraFollow, Point)> = scene
+        .components()
+        .components::<CameraFollow>()
+        .find_map(|(gameobject_id, follow)| {
+            scene
+                .components()
+                .get_component::<Transform2D>(gameobject_id)
+                .map(|transform| (*follow, transform.translation))
+        });
+    if let Some((follow, target)) = target {
+        camera.position = follow.follow(
+            camera.position,
+            target,
+            time.delta_by(follow.clock).as_secs_f32(),
+        );
+    }
+
+    let mut effects: AppliedCameraEffects = AppliedCameraEffects::default();
+    for (_, shake) in scene.components_mut().components_mut::<CameraShake>() {
+        shake.advance(time.delta_by(shake.clock).as_secs_f32());
+        let (offset, rotation): (Vector2, Angle) = shake.shake();
+        effects.offset += offset;
+        effects.rotation += rotation;
+    }
+    for (_, punch) in scene.components_mut().components_mut::<ZoomPunch>() {
+        punch.advance(time.delta_by(punch.clock).as_secs_f32());
+        effects.zoom *= 1.0 + punch.strength;
+    }
+    camera.position += effects.offset;
+    camera.rotation += effects.rotation;
+    camera.zoom *= effects.zoom;
+
+    let _ = scene.resources_mut().insert_resource(camera);
+    let _ = scene.resources_mut().insert_resource(effects);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{update_camera_effects, CameraFollow, CameraShake, ZoomPunch};
+    use crate::{
+        gamecore::{
+            scenes::Scene,
+            time::{Clock, Time},
+        },
+        graphicscore::camera::Camera2D,
+        mathcore::{
+            vectors::{Point, Vector2},
+            Angle,
+        },
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn camera_effects() {
+        let follow: CameraFollow = CameraFollow {
+            offset: Vector2::zero(),
+            dead_zone: (10.0, 10.0),
+            smoothing: 2.0_f32.ln(),
+            clock: Clock::Scaled,
+        };
+        let position: Point = follow.follow(Point::zero(), Point::from([30.0, -5.0]), 1.0);
+        assert!((position - Point::from([10.0, 0.0])).magnitude() < 1e-4);
+
+        let mut shake: CameraShake = CameraShake::new(3);
+        assert_eq!(shake.shake(), (Vector2::zero(), Angle::ZERO));
+        shake.add_trauma(2.0);
+        assert_eq!(shake.trauma(), 1.0);
+        shake.advance(0.37);
+        assert_ne!(shake.shake().0, Vector2::zero());
+
+        let mut scene: Scene = Scene::new();
+        let _ = scene
+            .resources_mut()
+            .insert_resource(Camera2D::new((100.0, 100.0)));
+        let mut punch: ZoomPunch = ZoomPunch::new(2.0_f32.ln());
+        punch.punch(1.0);
+        let _ = scene.spawn((punch, CameraShake::new(5)));
+        let mut time: Time = Time::new();
+        time.update_with(Duration::from_secs(1));
+        let _ = scene.resources_mut().insert_resource(time);
+        update_camera_effects(&mut scene);
+        let camera: Camera2D = *scene
+            .resources()
+            .get_resource::<Camera2D>()
+            .expect("Camera was inserted");
+        assert!((camera.zoom - 1.5).abs() < 1e-4);
+
+        scene
+            .components_mut()
+            .components_mut::<ZoomPunch>()
+            .for_each(|(_, punch)| *punch = ZoomPunch::new(0.0));
+        update_camera_effects(&mut scene);
+        let camera: Camera2D = *scene
+            .resources()
+            .get_resource::<Camera2D>()
+            .expect("Camera was inserted");
+        assert_eq!((camera.position, camera.zoom), (Point::zero(), 1.0));
+    }
+}
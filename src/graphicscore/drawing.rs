@@ -1,5 +1,5 @@
 //! `graphicscore::drawing` submodule implements several canvases
-//! ([`WindowCanvas`], [`TextureCanvas`] and [`TextureCanvas`]) that allow drawing on them.
+//! ([`WindowCanvas`], [`TextureCanvas`], [`RenderTarget`] and [`ImageCanvas`]) that allow drawing on them.
 //!
 //! This submodule provides [`Canvas`] trait that defines drawing interface for canvases,
 //! [`WindowCanvas`] struct as main canvas that is a base for all other canvases, because
@@ -18,35 +18,32 @@
 //! Texture creator can outlive its canvas and can still produce texturess - it is, however, useless.
 //! Also, you can create multiple texture creators from the same canvas and all of them are valid.
 //!
-//! But there is one constraint that can cause undefined behaviour if broken - textures should only
+//! But there is one constraint that would cause undefined behaviour if it was broken - textures should only
 //! be used in the canvas that created their texture creator.
 //!
-//! Implementation of managing functions makes its best efforts are made to ensure this:
-//! you cannot instantiate most of the canvases, and you are only given access to them in managing functions;
-//! closures that capture external values (and thus can bring external textures) are prohibited;
-//! absence of return value of managing functions makes it
-//! impossible to return texture that is created by underlying canvas.
-//!
-//! However, `ggengine` cannot track textures if they come from different window canvases,
-//! because enforcing proper constraints will make managing lifetimes and borrows really hard and will severely hurt the ergonomics.
-//! Realistically, you should only be using one window in a game, and thus you will be operating with only one window canvas.
-//! That said, it is important for user of `ggengine` to know about this caveat and to do his best
-//! to avoid causing this problem.
+//! Every canvas has its own [`CanvasId`], which brands texture creators and textures that come from it
+//! ([`TextureCanvas`]es share id of their [`WindowCanvas`], since they use the same renderer).
+//! Blitting texture to a canvas with another id, or managing texture of another window canvas,
+//! returns an error instead.
+//! Thanks to that, closures that are passed to managing functions can capture any state of a game,
+//! and textures that are created inside of them cannot outlive managed canvas.
 //!
 
 use crate::{
-    datacore::images::Image,
+    datacore::images::{Image, PixelFormat},
     graphicscore::{
-        textures::{AccessType, Texture, TextureCreator},
+        textures::{AccessType, MipmappedTexture, Texture, TextureCreator},
         {Blendable, BlendingType},
     },
+    log::{self, LogTarget},
     mathcore::{
-        shapes::{PolygonLike, Rect, Segment},
+        shapes::{Aabb, PolygonLike, Rect, Segment, Shape},
         transforms::{Rotatable, Scalable, Translatable},
         vectors::{Point, Vector2},
         {Color, Size},
     },
     utils::Window,
+    GGError,
 };
 use sdl2::{
     rect::{FRect as SdlFRect, Rect as SdlRect},
@@ -55,8 +52,106 @@ use sdl2::{
         Texture as RenderTexture, WindowCanvas as RenderWindowCanvas,
     },
     surface::Surface as SdlSurface,
+    sys::{
+        SDL_Color as SdlColor, SDL_FPoint as SdlFPoint, SDL_GetRenderTarget, SDL_SetRenderTarget,
+        SDL_Texture as SdlTexture, SDL_Vertex as SdlVertex,
+    },
 };
-use std::{fmt, path::PathBuf};
+use std::{
+    fmt,
+    ops::AddAssign,
+    path::PathBuf,
+    ptr,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// [`CanvasId`] struct identifies renderer of a canvas.
+///
+/// Texture creators and textures remember id of the canvas that created them,
+/// so textures that are used in the wrong canvas are detected (see `Texture::canvas_id`).
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CanvasId(u64);
+impl CanvasId {
+    /// Returns new unique id.
+    ///
+    fn new() -> CanvasId {
+        /// Counter of created ids.
+        ///
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        CanvasId(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+/// Checks that texture can be used in the canvas with given id.
+///
+fn check_texture(canvas_id: CanvasId, texture: &Texture) -> Result<(), GGError> {
+    if texture.canvas_id() == canvas_id {
+        Ok(())
+    } else {
+        Err(GGError::InvalidArgument(String::from(
+            "Texture was created by texture creator of another canvas",
+        )))
+    }
+}
+
+/// [`GeometryVertex`] struct represents vertex of triangles that are drawn by `Canvas::draw_geometry`.
+///
+#[derive(Copy, Clone, Debug)]
+pub struct GeometryVertex {
+    /// Position of vertex on the canvas.
+    ///
+    pub position: Point,
+    /// Color of vertex (colors of pixels between vertices are interpolated).
+    ///
+    pub color: Color,
+    /// Normalized texture coordinates of vertex (irrelevant if geometry is not textured).
+    ///
+    pub uv: Vector2,
+}
+
+/// [`DrawCalls`] struct holds numbers of draw calls of each kind that were submitted to a canvas.
+///
+/// Large number of draw calls usually means that something is drawn in a per-entity loop
+/// and could be batched (e.g. by an atlas, a mesh or a render queue).
+///
+/// # Example
+/// ```rust
+/// # use ggengine::graphicscore::drawing::DrawCalls;
+/// let mut frame: DrawCalls = DrawCalls { copies: 120, fills: 1, ..Default::default() };
+/// frame += DrawCalls { geometry: 4, ..Default::default() };
+/// assert_eq!(frame.total(), 125);
+/// ```
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DrawCalls {
+    /// Number of blitted textures.
+    ///
+    pub copies: u32,
+    /// Number of clears (fills of the whole canvas).
+    ///
+    pub fills: u32,
+    /// Number of drawn points and segments (outlines of rectangles and polygons are drawn segment by segment).
+    ///
+    pub primitives: u32,
+    /// Number of geometry submissions.
+    ///
+    pub geometry: u32,
+}
+impl DrawCalls {
+    /// Returns total number of draw calls.
+    ///
+    pub fn total(&self) -> u32 {
+        self.copies + self.fills + self.primitives + self.geometry
+    }
+}
+impl AddAssign for DrawCalls {
+    fn add_assign(&mut self, other: Self) {
+        self.copies += other.copies;
+        self.fills += other.fills;
+        self.primitives += other.primitives;
+        self.geometry += other.geometry;
+    }
+}
 
 /// [`Canvas`] trait defines drawing methods that should be implemented on any canvas.
 ///
@@ -72,49 +167,52 @@ use std::{fmt, path::PathBuf};
 /// # use ggengine::graphicscore::{textures::{Texture, TextureCreator}, drawing::{Canvas, WindowCanvas}};
 /// # use ggengine::datacore::{assets::ToFile, images::{Image, PixelFormat}};
 /// # use ggengine::mathcore::{{Angle, Size, Color}, vectors::Point, shapes::{Segment, Rect}};
-/// let engine: GGEngine = GGEngine::init();
-/// let window: Window = engine.build_window("ggengine", 1000, 1000, Default::default());
-/// let mut canvas: WindowCanvas = WindowCanvas::from_window(window, true);
+/// let engine: GGEngine = GGEngine::init().expect("Engine should be initialized");
+/// let window: Window = engine
+///     .build_window("ggengine", 1000, 1000, Default::default())
+///     .expect("Window should be built");
+/// let mut canvas: WindowCanvas =
+///     WindowCanvas::from_window(window, true).expect("Canvas should be created");
 /// let image: Image = canvas.manage_image(
 ///     Image::new(100, 100, PixelFormat::RGBA8888),
 ///     |image_canvas| {
 ///         image_canvas.set_draw_color(Color::RED);
 ///         assert_eq!(image_canvas.get_draw_color(), Color::RED);
 ///
-///         image_canvas.draw_point(Point::from([0.0, 0.0]));
+///         image_canvas.draw_point(Point::from([0.0, 0.0]))?;
 ///         image_canvas.draw_segment(
 ///             Segment::from([
 ///                 Point::from([10.0, 10.0]), Point::from([50.0, 50.0])
 ///             ])
-///         );
+///         )?;
 ///         image_canvas.draw_rect(
 ///             Rect::from_origin(
 ///                 Point::from([100.0, 100.0]),
 ///                 Angle::DEG45,
 ///                 Size::from_value(30.0), Size::from_value(30.0),
 ///             )
-///         );
+///         )?;
 ///         image_canvas.draw_polygon(&[
 ///             Point::from([200.0, 200.0]),
 ///             Point::from([300.0, 300.0]),
 ///             Point::from([400.0, 100.0]),
-///         ]);
+///         ])?;
 ///
 ///         image_canvas.clear();
 ///
 ///         let texture_creator: TextureCreator = image_canvas.texture_creator();
+///         let texture: Texture = texture_creator.create_texture_from_file("texture.png")?;
 ///         image_canvas.blit_from_texture(
 ///             Some(Rect::from_origin(
 ///                 Point::from([600.0, 600.0]),
 ///                 Angle::DEG60,
 ///                 Size::from_value(100.0), Size::from_value(100.0),
 ///             )),
-///             &texture_creator.create_texture_from_file("texture.png")
-///                 .expect("Filename should be correct"),
+///             &texture,
 ///             None,
-///         );
+///         )
 ///     }
-/// );
+/// ).expect("Drawing should not fail");
 /// image.to_file("image.png").expect("File creation or truncation should not fail");
 /// ```
 ///
@@ -133,38 +231,45 @@ pub trait Canvas<'a>: Blendable {
     ///
     /// Points coordinates are truncated towards integers.
     ///
-    fn draw_point(&mut self, point: Point);
+    fn draw_point(&mut self, point: Point) -> Result<(), GGError>;
     /// Draws segment on the canvas.
     ///
     /// Points coordinates are truncated towards integers.
     ///
-    fn draw_segment(&mut self, segment: Segment);
+    fn draw_segment(&mut self, segment: Segment) -> Result<(), GGError>;
     /// Draws rectangle on the canvas.
     ///
     /// Points coordinates are truncated towards integers.
     ///
-    fn draw_rect(&mut self, rect: Rect) {
+    fn draw_rect(&mut self, rect: Rect) -> Result<(), GGError> {
         let vertices: &[Point] = rect.vertices();
         let length: usize = vertices.len();
         for i in 1..=length {
-            self.draw_segment(Segment::from([vertices[i - 1], vertices[i % length]]));
+            self.draw_segment(Segment::from([vertices[i - 1], vertices[i % length]]))?;
         }
+        Ok(())
     }
     /// Draws polygon on the canvas.
     ///
     /// Points coordinates are truncated towards integers.
     ///
-    fn draw_polygon(&mut self, polygon: &[Point]) {
+    fn draw_polygon(&mut self, polygon: &[Point]) -> Result<(), GGError> {
         let length: usize = polygon.len();
         for i in 1..=length {
-            self.draw_segment(Segment::from([polygon[i - 1], polygon[i % length]]));
+            self.draw_segment(Segment::from([polygon[i - 1], polygon[i % length]]))?;
         }
+        Ok(())
     }
 
     /// Clears canvas by filling it out with current draw color.
     ///
     fn clear(&mut self);
 
+    /// Returns number of draw calls that were submitted to the canvas during the current frame
+    /// ([`WindowCanvas`] starts new frame on `update`, other canvases count draw calls since start of their management).
+    ///
+    fn draw_calls(&self) -> DrawCalls;
+
     /// Returns canvas's texture creator.
     ///
     fn texture_creator(&self) -> TextureCreator<'a>;
@@ -186,12 +291,50 @@ pub trait Canvas<'a>: Blendable {
         dst_area: Option<Rect>,
         texture: &Texture,
         src_area: Option<Rect>,
-    );
+    ) -> Result<(), GGError>;
+    /// Draws triangles that are formed by vertices on the canvas.
+    ///
+    /// If `indices` are `None`, every three consecutive vertices form a triangle,
+    /// otherwise every three consecutive indices of vertices form a triangle.
+    ///
+    /// If `texture` is present, triangles are textured using texture coordinates of vertices
+    /// (and colors of vertices modulate texture), otherwise they are filled with colors of vertices.
+    ///
+    fn draw_geometry(
+        &mut self,
+        vertices: &[GeometryVertex],
+        indices: Option<&[i32]>,
+        texture: Option<&Texture>,
+    ) -> Result<(), GGError>;
+    /// Blits mipmapped texture to the canvas, using mip level that corresponds to given scale
+    /// (e.g. zoom of camera).
+    ///
+    /// `dst_area` and `src_area` work as in `blit_from_texture` function, but `src_area` is
+    /// in coordinates of the original size texture (it is scaled to the selected mip level).
+    ///
+    fn blit_from_mipmapped_texture(
+        &mut self,
+        dst_area: Option<Rect>,
+        texture: &MipmappedTexture,
+        src_area: Option<Rect>,
+        scale: f32,
+    ) -> Result<(), GGError> {
+        let level: usize = texture.level_for_scale(scale);
+        let factor: f32 = 1.0 / (1u64 << level) as f32;
+        self.blit_from_texture(
+            dst_area,
+            texture.texture_for_scale(scale),
+            src_area.map(|rect| {
+                let aabb: Aabb = rect.aabb();
+                Rect::from_aabb(Aabb::new(aabb.min * factor, aabb.max * factor))
+            }),
+        )
+    }
 }
 /// [`impl_canvas`] macro implements [`Blendable`] and [`Canvas`] traits
-/// for [`WindowCanvas`], [`TextureCanvas`] and [`ImageCanvas`].
+/// for [`WindowCanvas`], [`TextureCanvas`], [`RenderTarget`] and [`ImageCanvas`].
 ///
-/// Canvas must have `canvas` field.
+/// Canvas must have `canvas`, `canvas_id` and `draw_calls` fields.
 ///
 macro_rules! impl_canvas {
     ($struct:ty, $texture_creator_fn:path) => {
@@ -212,43 +355,104 @@ macro_rules! impl_canvas {
                 Color::from_rgba(r, g, b, a)
             }
 
-            fn draw_point(&mut self, point: Point) {
+            fn draw_point(&mut self, point: Point) -> Result<(), GGError> {
+                self.draw_calls.primitives += 1;
                 self.canvas
                     .draw_fpoint((point.x, point.y))
-                    .expect("`ggengine` renderer should be able to draw a point");
+                    .map_err(GGError::Sdl)
             }
-            fn draw_segment(&mut self, segment: Segment) {
+            fn draw_segment(&mut self, segment: Segment) -> Result<(), GGError> {
+                self.draw_calls.primitives += 1;
                 self.canvas
                     .draw_fline(
                         (segment.points[0].x, segment.points[0].y),
                         (segment.points[1].x, segment.points[1].y),
                     )
-                    .expect("`ggengine` renderer should be able to draw a point");
+                    .map_err(GGError::Sdl)
             }
 
             fn clear(&mut self) {
+                self.draw_calls.fills += 1;
                 self.canvas.clear();
             }
 
+            fn draw_calls(&self) -> DrawCalls {
+                self.draw_calls
+            }
+
             fn texture_creator(&self) -> TextureCreator<'a> {
-                $texture_creator_fn(self.canvas.texture_creator())
+                $texture_creator_fn(self.canvas.texture_creator(), self.canvas_id)
+            }
+            fn draw_geometry(
+                &mut self,
+                vertices: &[GeometryVertex],
+                indices: Option<&[i32]>,
+                texture: Option<&Texture>,
+            ) -> Result<(), GGError> {
+                if let Some(texture) = texture {
+                    check_texture(self.canvas_id, texture)?;
+                }
+                self.draw_calls.geometry += 1;
+                let vertices: Vec<SdlVertex> = vertices
+                    .iter()
+                    .map(|vertex| SdlVertex {
+                        position: SdlFPoint {
+                            x: vertex.position.x,
+                            y: vertex.position.y,
+                        },
+                        color: SdlColor {
+                            r: vertex.color.r,
+                            g: vertex.color.g,
+                            b: vertex.color.b,
+                            a: vertex.color.a,
+                        },
+                        tex_coord: SdlFPoint {
+                            x: vertex.uv.x,
+                            y: vertex.uv.y,
+                        },
+                    })
+                    .collect();
+                let indices: &[i32] = indices.unwrap_or(&[]);
+                // SAFETY: renderer and texture are valid, and both slices outlive the call.
+                let result: i32 = unsafe {
+                    sdl2::sys::SDL_RenderGeometry(
+                        self.canvas.raw(),
+                        texture.map_or(ptr::null_mut(), |texture| texture.get_sdl_texture().raw()),
+                        vertices.as_ptr(),
+                        vertices.len() as i32,
+                        if indices.is_empty() {
+                            ptr::null()
+                        } else {
+                            indices.as_ptr()
+                        },
+                        indices.len() as i32,
+                    )
+                };
+                if result == 0 {
+                    Ok(())
+                } else {
+                    Err(GGError::Sdl(sdl2::get_error()))
+                }
             }
             fn blit_from_texture(
                 &mut self,
                 dst_area: Option<Rect>,
                 texture: &Texture,
                 src_area: Option<Rect>,
-            ) {
+            ) -> Result<(), GGError> {
+                check_texture(self.canvas_id, texture)?;
+                self.draw_calls.copies += 1;
                 self.canvas
                     .copy_ex_f(
                         texture.get_sdl_texture(),
                         src_area.map(|rect| {
-                            let [point1, point2] = rect.aabb();
-                            let (width, height): (u32, u32) = {
-                                let diff: Vector2 = point2 - point1;
-                                (diff.x as u32, diff.y as u32)
-                            };
-                            SdlRect::new(point1.x as i32, point1.y as i32, width, height)
+                            let aabb: Aabb = rect.aabb();
+                            SdlRect::new(
+                                aabb.min.x as i32,
+                                aabb.min.y as i32,
+                                aabb.width() as u32,
+                                aabb.height() as u32,
+                            )
                         }),
                         dst_area.map(|rect| {
                             let origin: Point = rect.origin();
@@ -260,7 +464,7 @@ macro_rules! impl_canvas {
                         false,
                         false,
                     )
-                    .expect("`ggengine` renderer should be able to perform texture blitting");
+                    .map_err(GGError::Sdl)
             }
         }
     };
@@ -275,6 +479,12 @@ pub struct ImageCanvas<'a> {
     /// Underlying `sdl2` canvas.
     ///
     canvas: RenderSurfaceCanvas<'a>,
+    /// Id of the canvas.
+    ///
+    canvas_id: CanvasId,
+    /// Draw calls that were submitted to the canvas.
+    ///
+    draw_calls: DrawCalls,
 }
 impl<'a> fmt::Debug for ImageCanvas<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -297,6 +507,12 @@ pub struct TextureCanvas<'a> {
     /// Instance of canvas is borrowed from the [`WindowCanvas`] due to `sdl2` implementation.
     ///
     canvas: &'a mut RenderWindowCanvas,
+    /// Id of the canvas (it is the same as id of the [`WindowCanvas`]).
+    ///
+    canvas_id: CanvasId,
+    /// Draw calls that were submitted to the canvas (they are added to draw calls of [`WindowCanvas`]).
+    ///
+    draw_calls: DrawCalls,
 }
 impl<'a> fmt::Debug for TextureCanvas<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -308,6 +524,129 @@ impl_canvas!(
     TextureCreator::from_sdl_texture_creator_window
 );
 
+/// [`RenderTarget`] struct represents canvas that draws on a [`Texture`] which was pushed
+/// on the stack of render targets of [`WindowCanvas`].
+///
+/// Render targets can be nested (e.g. UI is drawn into a panel texture, which is drawn into a screen texture),
+/// and previous target is restored when render target is popped (or dropped).
+/// Borrows ensure that targets are popped in reverse order and that textures outlive them.
+///
+/// # Example
+/// ```rust, no_run
+/// # use ggengine::GGEngine;
+/// # use ggengine::graphicscore::drawing::{Canvas, RenderTarget, WindowCanvas};
+/// # use ggengine::graphicscore::textures::{AccessType, Texture, TextureCreator};
+/// # use ggengine::mathcore::Color;
+/// let engine: GGEngine = GGEngine::init().expect("Engine should be initialized");
+/// let mut canvas: WindowCanvas = WindowCanvas::from_window(
+///     engine.build_window("ggengine", 1000, 1000, Default::default()).expect("Window should be built"),
+///     true,
+/// ).expect("Canvas should be created");
+/// let texture_creator: TextureCreator = canvas.texture_creator();
+/// let mut screen: Texture = texture_creator
+///     .create_texture(1000, 1000, None, AccessType::Targeted)
+///     .expect("Texture should be created");
+/// let mut panel: Texture = texture_creator
+///     .create_texture(200, 100, None, AccessType::Targeted)
+///     .expect("Texture should be created");
+///
+/// let mut screen_target: RenderTarget = canvas.push_target(&mut screen).expect("Screen is targeted");
+/// {
+///     let mut panel_target: RenderTarget = screen_target.push_target(&mut panel).expect("Panel is targeted");
+///     panel_target.set_draw_color(Color::RED);
+///     panel_target.clear();
+///     panel_target.pop_target();
+/// }
+/// screen_target.blit_from_texture(None, &panel, None).expect("Panel should be blitted");
+/// screen_target.pop_target();
+/// canvas.blit_from_texture(None, &screen, None).expect("Screen should be blitted");
+/// canvas.update();
+/// ```
+///
+pub struct RenderTarget<'a> {
+    /// Underlying mutable reference to `sdl2` canvas.
+    ///
+    canvas: &'a mut RenderWindowCanvas,
+    /// Id of the canvas (it is the same as id of the [`WindowCanvas`]).
+    ///
+    canvas_id: CanvasId,
+    /// Draw calls that were submitted to the canvas.
+    ///
+    draw_calls: DrawCalls,
+    /// Draw calls of the parent canvas, to which draw calls are added when target is popped.
+    ///
+    parent_draw_calls: &'a mut DrawCalls,
+    /// Render target that was set before this one was pushed.
+    ///
+    previous: *mut SdlTexture,
+}
+impl<'a> RenderTarget<'a> {
+    /// Sets texture as render target of the canvas and returns [`RenderTarget`] that draws on it.
+    ///
+    fn push(
+        canvas: &'a mut RenderWindowCanvas,
+        canvas_id: CanvasId,
+        parent_draw_calls: &'a mut DrawCalls,
+        texture: &'a mut Texture,
+    ) -> Result<RenderTarget<'a>, GGError> {
+        check_texture(canvas_id, texture)?;
+        if texture.access_type() != AccessType::Targeted {
+            return Err(GGError::InvalidArgument(String::from(
+                "Texture should have `AccessType::Targeted` to be a render target",
+            )));
+        }
+        if !canvas.render_target_supported() {
+            return Err(GGError::Unsupported(String::from(
+                "Renderer does not support render targets",
+            )));
+        }
+        // SAFETY: renderer is valid, and texture outlives render target, since it is borrowed for `'a`.
+        let previous: *mut SdlTexture = unsafe { SDL_GetRenderTarget(canvas.raw()) };
+        if unsafe { SDL_SetRenderTarget(canvas.raw(), texture.get_sdl_texture().raw()) } != 0 {
+            return Err(GGError::Sdl(sdl2::get_error()));
+        }
+        Ok(RenderTarget {
+            canvas,
+            canvas_id,
+            draw_calls: DrawCalls::default(),
+            parent_draw_calls,
+            previous,
+        })
+    }
+
+    /// Pushes texture on the stack of render targets, so that it is drawn on instead of this target,
+    /// until returned [`RenderTarget`] is popped.
+    ///
+    /// # Errors
+    /// Error is returned if texture was created by another canvas or if its access type is not `AccessType::Targeted`.
+    ///
+    pub fn push_target<'t>(
+        &'t mut self,
+        texture: &'t mut Texture,
+    ) -> Result<RenderTarget<'t>, GGError> {
+        RenderTarget::push(self.canvas, self.canvas_id, &mut self.draw_calls, texture)
+    }
+    /// Pops render target, restoring previous one (dropping [`RenderTarget`] does the same).
+    ///
+    pub fn pop_target(self) {}
+}
+impl<'a> Drop for RenderTarget<'a> {
+    fn drop(&mut self) {
+        // SAFETY: previous target is either the window or a texture of outer render target, which is still borrowed.
+        let _ = unsafe { SDL_SetRenderTarget(self.canvas.raw(), self.previous) };
+        *self.parent_draw_calls += self.draw_calls;
+    }
+}
+impl<'a> fmt::Debug for RenderTarget<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RenderTarget")
+    }
+}
+impl_canvas!(
+    RenderTarget<'a>,
+    TextureCreator::from_sdl_texture_creator_window
+);
+
 /// [`WindowCanvas`] struct represents canvas that allows drawing on a [`Window`].
 ///
 /// [`WindowCanvas`] is instantiated from [`Window`] struct by consuming it (OS shell of window is not destroyed).
@@ -321,6 +660,19 @@ pub struct WindowCanvas {
     /// Underlying `sdl2` canvas.
     ///
     canvas: RenderWindowCanvas,
+    /// Id of the canvas.
+    ///
+    canvas_id: CanvasId,
+
+    /// Draw calls that were submitted during the current frame.
+    ///
+    draw_calls: DrawCalls,
+    /// Draw calls that were submitted during the previous frame.
+    ///
+    last_frame_draw_calls: DrawCalls,
+    /// Maximal total number of draw calls per frame, exceeding which is logged.
+    ///
+    draw_call_budget: Option<u32>,
 }
 impl WindowCanvas {
     /// Constructs [`WindowCanvas`] from the [`Window`] by consuming it (OS shell of window is not destroyed)..
@@ -330,12 +682,15 @@ impl WindowCanvas {
     /// # use ggengine::GGEngine;
     /// # use ggengine::utils::Window;
     /// # use ggengine::graphicscore::drawing::{Canvas, WindowCanvas};
-    /// let engine: GGEngine = GGEngine::init();
-    /// let window: Window = engine.build_window("ggengine", 1000, 1000, Default::default());
-    /// let canvas: WindowCanvas = WindowCanvas::from_window(window, true);
+    /// let engine: GGEngine = GGEngine::init().expect("Engine should be initialized");
+    /// let window: Window = engine
+    ///     .build_window("ggengine", 1000, 1000, Default::default())
+    ///     .expect("Window should be built");
+    /// let canvas: WindowCanvas =
+    ///     WindowCanvas::from_window(window, true).expect("Canvas should be created");
     /// ```
     ///
-    pub fn from_window(window: Window, vsync: bool) -> Self {
+    pub fn from_window(window: Window, vsync: bool) -> Result<Self, GGError> {
         let builder: CanvasBuilder = {
             let builder: CanvasBuilder = window.destructure().into_canvas().target_texture();
             if vsync {
@@ -344,11 +699,22 @@ impl WindowCanvas {
                 builder
             }
         };
-        WindowCanvas {
+        Ok(WindowCanvas {
             canvas: builder
                 .build()
-                .expect("`ggengine` should be able to initialize canvas from the window"),
-        }
+                .map_err(|error| GGError::Sdl(error.to_string()))
+                .inspect_err(|error| {
+                    log::error(
+                        LogTarget::GraphicsCore,
+                        format_args!("Canvas creation failed: {error}"),
+                    )
+                })?,
+            canvas_id: CanvasId::new(),
+
+            draw_calls: DrawCalls::default(),
+            last_frame_draw_calls: DrawCalls::default(),
+            draw_call_budget: None,
+        })
     }
     /// Consumes [`WindowCanvas`] to get back [`Window`] instance from which it was created.
     ///
@@ -357,9 +723,12 @@ impl WindowCanvas {
     /// # use ggengine::GGEngine;
     /// # use ggengine::utils::Window;
     /// # use ggengine::graphicscore::drawing::{Canvas, WindowCanvas};
-    /// let engine: GGEngine = GGEngine::init();
-    /// let window: Window = engine.build_window("ggengine", 1000, 1000, Default::default());
-    /// let canvas: WindowCanvas = WindowCanvas::from_window(window, true);
+    /// let engine: GGEngine = GGEngine::init().expect("Engine should be initialized");
+    /// let window: Window = engine
+    ///     .build_window("ggengine", 1000, 1000, Default::default())
+    ///     .expect("Window should be built");
+    /// let canvas: WindowCanvas =
+    ///     WindowCanvas::from_window(window, true).expect("Canvas should be created");
     /// let window: Window = canvas.into_window();
     /// ```
     ///
@@ -369,11 +738,12 @@ impl WindowCanvas {
 
     /// [`WindowCanvas`] manages [`Image`] by consuming it and allowing drawing on [`ImageCanvas`]
     /// inside passed function. New image is returned after drawing.
+    /// If `f` returns an error, the image is dropped and that error is returned.
     ///
     /// # Note
-    /// Type of `f` is function pointer, because it disallows passing closures that capture external values.
-    /// This constraint is necessary due to fact that every canvas should work only with [`Texture`]s that
-    /// are obtained from [`TextureCreator`] whose parent is that canvas.
+    /// `f` can capture game state, including textures, but only [`Texture`]s that were created
+    /// by this [`WindowCanvas`] can be drawn inside it (textures of other canvases are rejected with an error).
+    /// Textures that are created by texture creator of the managed canvas cannot leave `f`.
     /// You can read more about this in the docs for `graphicscore::drawing` submodule.
     ///
     /// # Example
@@ -386,32 +756,42 @@ impl WindowCanvas {
     /// # use ggengine::graphicscore::drawing::{Canvas, WindowCanvas};
     /// # use ggengine::datacore::{assets::ToFile, images::{Image, PixelFormat}};
     /// # use ggengine::mathcore::Color;
-    /// let engine: GGEngine = GGEngine::init();
-    /// let window: Window = engine.build_window("ggengine", 1000, 1000, Default::default());
-    /// let mut canvas: WindowCanvas = WindowCanvas::from_window(window, true);
+    /// let engine: GGEngine = GGEngine::init().expect("Engine should be initialized");
+    /// let window: Window = engine
+    ///     .build_window("ggengine", 1000, 1000, Default::default())
+    ///     .expect("Window should be built");
+    /// let mut canvas: WindowCanvas =
+    ///     WindowCanvas::from_window(window, true).expect("Canvas should be created");
     ///
     /// let image: Image = canvas.manage_image(
     ///     Image::new(100, 100, PixelFormat::RGBA8888),
     ///     |image_canvas| {
     ///         image_canvas.set_draw_color(Color::RED);
     ///         image_canvas.clear();
+    ///         Ok(())
     ///     }
-    /// );
+    /// ).expect("Drawing should not fail");
     /// image.to_file("image.png").expect("File creation or truncation should not fail");
     /// ```
     ///
     pub fn manage_image<'image>(
         &mut self,
         image: Image<'image>,
-        f: fn(&mut ImageCanvas) -> (),
-    ) -> Image<'image> {
+        f: impl FnOnce(&mut ImageCanvas) -> Result<(), GGError>,
+    ) -> Result<Image<'image>, GGError> {
         let (filename, surface): (PathBuf, SdlSurface<'image>) = image.destructure();
-        let canvas: SurfaceCanvas = SurfaceCanvas::from_surface(surface)
-            .expect("`ggengine` should be able to initialize canvas from the image");
-        let mut image_canvas: ImageCanvas<'image> = ImageCanvas { canvas };
-        f(&mut image_canvas);
+        let canvas: SurfaceCanvas = SurfaceCanvas::from_surface(surface).map_err(GGError::Sdl)?;
+        let mut image_canvas: ImageCanvas<'image> = ImageCanvas {
+            canvas,
+            canvas_id: CanvasId::new(),
+            draw_calls: DrawCalls::default(),
+        };
+        f(&mut image_canvas)?;
         image_canvas.canvas.present();
-        Image::from_sdl_surface(filename, image_canvas.canvas.into_surface())
+        Ok(Image::from_sdl_surface(
+            filename,
+            image_canvas.canvas.into_surface(),
+        ))
     }
 
     /// Returns whether the canvas supports texture management or not.
@@ -429,12 +809,13 @@ impl WindowCanvas {
     ///
     /// Calling this function in a loop is not optimal, because canvas resets its target back to
     /// itself (although this issue is not applicable to image managing).
-    /// If you want to manage multiple textures, use `manage_textures` function.
+    /// If you want to manage multiple textures, use `manage_textures` function,
+    /// and if you want to render into textures in a nested way, use `push_target` function.
     ///
     /// # Note
-    /// Type of `f` is function pointer, because it disallows passing closures that capture external values.
-    /// This constraint is necessary due to fact that every canvas should work only with [`Texture`]s that
-    /// are obtained from [`TextureCreator`] whose parent is that canvas.
+    /// `f` can capture game state, including textures, but only [`Texture`]s that were created
+    /// by this [`WindowCanvas`] can be drawn inside it (textures of other canvases are rejected with an error).
+    /// Textures that are created by texture creator of the managed canvas cannot leave `f`.
     /// You can read more about this in the docs for `graphicscore::drawing` submodule.
     ///
     /// # Example
@@ -446,42 +827,59 @@ impl WindowCanvas {
     /// # use ggengine::graphicscore::drawing::{Canvas, WindowCanvas};
     /// # use ggengine::graphicscore::textures::{Texture, TextureCreator, AccessType};
     /// # use ggengine::mathcore::Color;
-    /// let engine: GGEngine = GGEngine::init();
-    /// let window: Window = engine.build_window("ggengine", 1000, 1000, Default::default());
-    /// let mut canvas: WindowCanvas = WindowCanvas::from_window(window, true);
+    /// let engine: GGEngine = GGEngine::init().expect("Engine should be initialized");
+    /// let window: Window = engine
+    ///     .build_window("ggengine", 1000, 1000, Default::default())
+    ///     .expect("Window should be built");
+    /// let mut canvas: WindowCanvas =
+    ///     WindowCanvas::from_window(window, true).expect("Canvas should be created");
     ///
     /// let texture_creator: TextureCreator = canvas.texture_creator();
     /// let mut texture: Texture = texture_creator.create_texture(
     ///     100, 100,
     ///     texture_creator.default_pixel_format(),
     ///     AccessType::Targeted
-    /// );
+    /// ).expect("Texture should be created");
     ///
+    /// let background: Color = Color::RED;
     /// canvas.manage_texture(
     ///     &mut texture,
     ///     |texture_canvas| {
-    ///         texture_canvas.set_draw_color(Color::RED);
+    ///         texture_canvas.set_draw_color(background);
     ///         texture_canvas.clear();
+    ///         Ok(())
     ///     }
-    /// );
+    /// ).expect("Drawing should not fail");
     ///
-    /// canvas.blit_from_texture(None, &texture, None);
+    /// canvas.blit_from_texture(None, &texture, None).expect("Texture should be blitted");
     /// canvas.update();
     /// ```
     ///
     pub fn manage_texture<'managing, 'texture: 'managing>(
         &mut self,
         texture: &'managing mut Texture<'texture>,
-        f: fn(&mut TextureCanvas) -> (),
-    ) {
+        f: impl FnOnce(&mut TextureCanvas) -> Result<(), GGError>,
+    ) -> Result<(), GGError> {
+        check_texture(self.canvas_id, texture)?;
         if texture.access_type() != AccessType::Targeted || !self.supports_texture_management() {
-            return;
+            return Ok(());
         }
+        let canvas_id: CanvasId = self.canvas_id;
+        let mut result: Result<(), GGError> = Ok(());
+        let mut draw_calls: DrawCalls = DrawCalls::default();
         self.canvas
             .with_texture_canvas(texture.get_sdl_texture_mut(), |canvas| {
-                f(&mut TextureCanvas { canvas })
+                let mut texture_canvas: TextureCanvas = TextureCanvas {
+                    canvas,
+                    canvas_id,
+                    draw_calls: DrawCalls::default(),
+                };
+                result = f(&mut texture_canvas);
+                draw_calls = texture_canvas.draw_calls;
             })
-            .expect("`ggengine` should be able to initialize canvas from the texture");
+            .map_err(|error| GGError::Sdl(error.to_string()))?;
+        self.draw_calls += draw_calls;
+        result
     }
     /// [`WindowCanvas`] manages [`Texture`]s by borrowing them and allowing drawing on [`TextureCanvas`]
     /// inside passed function. [`Texture`]s are changed in place.
@@ -494,9 +892,9 @@ impl WindowCanvas {
     /// (`AccessType::Targeted` should be set for texture to allow management).
     ///
     /// # Note
-    /// Type of `f` is function pointer, because it disallows passing closures that capture external values.
-    /// This constraint is necessary due to fact that every canvas should work only with [`Texture`]s that
-    /// are obtained from [`TextureCreator`] whose parent is that canvas.
+    /// `f` can capture game state, including textures, but only [`Texture`]s that were created
+    /// by this [`WindowCanvas`] can be drawn inside it (textures of other canvases are rejected with an error).
+    /// Textures that are created by texture creator of the managed canvas cannot leave `f`.
     /// You can read more about this in the docs for `graphicscore::drawing` submodule.
     ///
     /// # Example
@@ -510,21 +908,24 @@ impl WindowCanvas {
     /// # use ggengine::graphicscore::drawing::{Canvas, WindowCanvas};
     /// # use ggengine::graphicscore::textures::{Texture, TextureCreator, AccessType};
     /// # use ggengine::mathcore::{{Angle, Size, Color}, vectors::Point, shapes::Rect};
-    /// let engine: GGEngine = GGEngine::init();
-    /// let window: Window = engine.build_window("ggengine", 1000, 1000, Default::default());
-    /// let mut canvas: WindowCanvas = WindowCanvas::from_window(window, true);
+    /// let engine: GGEngine = GGEngine::init().expect("Engine should be initialized");
+    /// let window: Window = engine
+    ///     .build_window("ggengine", 1000, 1000, Default::default())
+    ///     .expect("Window should be built");
+    /// let mut canvas: WindowCanvas =
+    ///     WindowCanvas::from_window(window, true).expect("Canvas should be created");
     ///
     /// let texture_creator: TextureCreator = canvas.texture_creator();
     /// let mut texture1: Texture = texture_creator.create_texture(
     ///     100, 100,
     ///     texture_creator.default_pixel_format(),
     ///     AccessType::Targeted
-    /// );
+    /// ).expect("Texture should be created");
     /// let mut texture2: Texture = texture_creator.create_texture(
     ///     100, 100,
     ///     texture_creator.default_pixel_format(),
     ///     AccessType::Targeted
-    /// );
+    /// ).expect("Texture should be created");
     ///
     /// let mut package: Vec<(Color, &mut Texture)> = vec![
     ///     (Color::RED, &mut texture1),
@@ -535,8 +936,9 @@ impl WindowCanvas {
     ///     |texture_canvas, index| {
     ///         texture_canvas.set_draw_color(*index);
     ///         texture_canvas.clear();
+    ///         Ok(())
     ///     }
-    /// );
+    /// ).expect("Drawing should not fail");
     ///
     /// canvas.blit_from_texture(
     ///     Some(Rect::from_origin(
@@ -546,7 +948,7 @@ impl WindowCanvas {
     ///     )),
     ///     &texture1,
     ///     None
-    /// );
+    /// ).expect("Texture should be blitted");
     /// canvas.blit_from_texture(
     ///     Some(Rect::from_origin(
     ///         Point::from([400.0, 400.0]),
@@ -555,28 +957,65 @@ impl WindowCanvas {
     ///     )),
     ///     &texture2,
     ///     None
-    /// );
+    /// ).expect("Texture should be blitted");
     /// canvas.update();
     /// ```
     ///
     pub fn manage_textures<'managing, 'texture: 'managing, Index: 'managing>(
         &mut self,
         textures: &'managing mut [(Index, &'managing mut Texture<'texture>)],
-        f: fn(&mut TextureCanvas, &Index) -> (),
-    ) {
+        mut f: impl FnMut(&mut TextureCanvas, &Index) -> Result<(), GGError>,
+    ) -> Result<(), GGError> {
+        for (_, texture) in textures.iter() {
+            check_texture(self.canvas_id, texture)?;
+        }
         if !self.supports_texture_management() {
-            return;
+            return Ok(());
         }
+        let canvas_id: CanvasId = self.canvas_id;
         let textures: Vec<(&'managing mut RenderTexture<'texture>, &Index)> = textures
             .iter_mut()
             .filter(|(_, texture)| texture.access_type() == AccessType::Targeted)
             .map(|(ref index, ref mut texture)| (texture.get_sdl_texture_mut(), index))
             .collect();
+        let mut result: Result<(), GGError> = Ok(());
+        let mut draw_calls: DrawCalls = DrawCalls::default();
         self.canvas
             .with_multiple_texture_canvas(textures.iter(), |canvas, index| {
-                f(&mut TextureCanvas { canvas }, *index)
+                if result.is_ok() {
+                    let mut texture_canvas: TextureCanvas = TextureCanvas {
+                        canvas,
+                        canvas_id,
+                        draw_calls: DrawCalls::default(),
+                    };
+                    result = f(&mut texture_canvas, *index);
+                    draw_calls += texture_canvas.draw_calls;
+                }
             })
-            .expect("`ggengine` should be able to initialize canvas from the texture");
+            .map_err(|error| GGError::Sdl(error.to_string()))?;
+        self.draw_calls += draw_calls;
+        result
+    }
+
+    /// Pushes texture on the stack of render targets and returns [`RenderTarget`] that draws on it.
+    ///
+    /// Unlike `manage_texture`, which resets target back to window after every call, render targets can be nested
+    /// (see `RenderTarget::push_target`), and window becomes target again only when the outermost one is popped.
+    ///
+    /// # Errors
+    /// Error is returned if texture was created by another canvas, if its access type is not `AccessType::Targeted`
+    /// or if [`WindowCanvas`] does not support texture management.
+    ///
+    pub fn push_target<'t>(
+        &'t mut self,
+        texture: &'t mut Texture,
+    ) -> Result<RenderTarget<'t>, GGError> {
+        RenderTarget::push(
+            &mut self.canvas,
+            self.canvas_id,
+            &mut self.draw_calls,
+            texture,
+        )
     }
 
     /// Updates the image on the window.
@@ -585,8 +1024,76 @@ impl WindowCanvas {
     /// To commit your work you need to call `update`
     /// (this function is called automatically for images and textures after your work).
     ///
+    /// This function also finishes current frame of draw call statistics
+    /// and logs a warning if frame exceeded draw call budget.
+    ///
     pub fn update(&mut self) {
         self.canvas.present();
+
+        let draw_calls: DrawCalls = std::mem::take(&mut self.draw_calls);
+        if let Some(budget) = self.draw_call_budget {
+            if draw_calls.total() > budget {
+                log::warn(
+                    LogTarget::GraphicsCore,
+                    format_args!(
+                        "Frame exceeded draw call budget of {budget} with {} draw calls \
+                         ({} copies, {} fills, {} primitives, {} geometry submissions)",
+                        draw_calls.total(),
+                        draw_calls.copies,
+                        draw_calls.fills,
+                        draw_calls.primitives,
+                        draw_calls.geometry,
+                    ),
+                );
+            }
+        }
+        self.last_frame_draw_calls = draw_calls;
+    }
+    /// Returns draw calls that were submitted during the previous frame (before the last `update`).
+    ///
+    pub fn last_frame_draw_calls(&self) -> DrawCalls {
+        self.last_frame_draw_calls
+    }
+    /// Returns maximal total number of draw calls per frame, exceeding which is logged.
+    ///
+    pub fn draw_call_budget(&self) -> Option<u32> {
+        self.draw_call_budget
+    }
+    /// Sets maximal total number of draw calls per frame, exceeding which is logged
+    /// (`None` disables warnings).
+    ///
+    pub fn set_draw_call_budget(&mut self, budget: Option<u32>) {
+        self.draw_call_budget = budget;
+    }
+
+    /// Returns size of the canvas in pixels (width and height).
+    ///
+    pub fn output_size(&self) -> Result<(u32, u32), GGError> {
+        self.canvas.output_size().map_err(GGError::Sdl)
+    }
+    /// Reads pixels that were drawn on the canvas buffer (before it is presented by `update`) into an opaque `RGBA32` image.
+    ///
+    /// This function is slow, since it transfers pixels from GPU, so it should not be used frequently.
+    ///
+    pub fn capture_frame(&self) -> Result<Image<'static>, GGError> {
+        let (width, height): (u32, u32) = self.output_size()?;
+        let pixels: Vec<u8> = self
+            .canvas
+            .read_pixels(None, PixelFormat::RGBA32.to_sdl_pixel_format_enum())
+            .map_err(GGError::Sdl)?;
+        let mut frame: Image<'static> = Image::new(width, height, PixelFormat::RGBA32);
+        let row_size: usize = width as usize * 4;
+        let pitch: usize = frame.pitch() as usize;
+        frame.access_data_mut(|data| {
+            for (row, pixels) in pixels.chunks_exact(row_size).enumerate() {
+                let data: &mut [u8] = &mut data[row * pitch..row * pitch + row_size];
+                data.copy_from_slice(pixels);
+                for alpha in data.iter_mut().skip(3).step_by(4) {
+                    *alpha = u8::MAX;
+                }
+            }
+        });
+        Ok(frame)
     }
 }
 impl fmt::Debug for WindowCanvas {
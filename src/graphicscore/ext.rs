@@ -153,11 +153,12 @@ pub trait ColorModulatable {
 impl<'a> ColorModulatable for Image<'a> {
     fn set_color_modulation(&mut self, color: Color) {
         self.get_sdl_surface_mut()
-            .set_color_mod(SdlColor::from(color.to_rgba()))
+            .set_color_mod(SdlColor::from(color.to_rgba()));
+        self.set_alpha_mod(color.a);
     }
     fn color_modulation(&self) -> Color {
-        let (r, g, b, a): (u8, u8, u8, u8) = self.get_sdl_surface().color_mod().rgba();
-        Color::from_rgba(r, g, b, a)
+        let (r, g, b): (u8, u8, u8) = self.get_sdl_surface().color_mod().rgb();
+        Color::from_rgba(r, g, b, self.alpha_mod())
     }
 }
 impl<'a> ColorModulatable for Texture<'a> {
@@ -0,0 +1,221 @@
+//! `graphicscore::grading` submodule implements [`ColorGrading`] - post-process pass that
+//! maps colors of the composited frame through a lookup.
+//!
+//! Pass works on [`Image`]s (frames that are composited on CPU, e.g. by `WindowCanvas::manage_image`),
+//! so it does not need shaders. Lookup is either per-channel curves (day/night tinting, contrast,
+//! brightness) or a palette, in which case every color is replaced by the closest color of the palette
+//! (retro palette limits).
+//!
+
+use crate::{datacore::images::Image, mathcore::Color};
+use std::fmt;
+
+/// Number of bits per channel that are used to index palette lookup.
+///
+const PALETTE_BITS: u32 = 5;
+
+/// [`Lookup`] enum lists kinds of lookups of [`ColorGrading`].
+///
+#[derive(Clone)]
+enum Lookup {
+    /// Per-channel curves.
+    ///
+    Curves(Box<[[u8; 256]; 3]>),
+    /// Closest colors of palette for every color with channels truncated to [`PALETTE_BITS`] bits.
+    ///
+    Palette(Box<[Color]>),
+}
+
+/// [`ColorGrading`] struct maps colors through lookup of per-channel curves or a palette.
+///
+/// Alpha channel is never changed.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::graphicscore::grading::ColorGrading;
+/// # use ggengine::mathcore::Color;
+/// let night: ColorGrading = ColorGrading::tint(Color::from_rgba(128, 128, 255, 255));
+/// assert_eq!(
+///     night.map_color(Color::from_rgba(255, 100, 200, 255)),
+///     Color::from_rgba(128, 50, 200, 255)
+/// );
+///
+/// let black: Color = Color::from_rgba(0, 0, 0, 255);
+/// let white: Color = Color::from_rgba(255, 255, 255, 255);
+/// let retro: ColorGrading = ColorGrading::palette(&[black, white, Color::RED]);
+/// assert_eq!(retro.map_color(Color::from_rgba(200, 30, 40, 100)), Color::from_rgba(255, 0, 0, 100));
+/// ```
+///
+#[derive(Clone)]
+pub struct ColorGrading {
+    /// Lookup through which colors are mapped.
+    ///
+    lookup: Lookup,
+}
+impl ColorGrading {
+    /// Constructs grading from per-channel curves (functions that map values of red, green and blue channels).
+    ///
+    pub fn curves(
+        red: impl Fn(u8) -> u8,
+        green: impl Fn(u8) -> u8,
+        blue: impl Fn(u8) -> u8,
+    ) -> Self {
+        let mut curves: [[u8; 256]; 3] = [[0; 256]; 3];
+        for value in 0..=255u8 {
+            curves[0][value as usize] = red(value);
+            curves[1][value as usize] = green(value);
+            curves[2][value as usize] = blue(value);
+        }
+        ColorGrading {
+            lookup: Lookup::Curves(Box::new(curves)),
+        }
+    }
+    /// Constructs grading that does not change colors.
+    ///
+    pub fn identity() -> Self {
+        Self::curves(|red| red, |green| green, |blue| blue)
+    }
+    /// Constructs grading that multiplies colors by given tint (e.g. bluish tint for night).
+    ///
+    pub fn tint(tint: Color) -> Self {
+        let multiply = |factor: u8| {
+            move |value: u8| ((u16::from(value) * u16::from(factor) + 127) / 255) as u8
+        };
+        Self::curves(multiply(tint.r), multiply(tint.g), multiply(tint.b))
+    }
+    /// Constructs grading that replaces colors with the closest (by euclidean distance) colors of the palette.
+    ///
+    /// Colors are matched with precision of 5 bits per channel.
+    /// If palette is empty, colors are not changed.
+    ///
+    pub fn palette(palette: &[Color]) -> Self {
+        if palette.is_empty() {
+            return Self::identity();
+        }
+        let levels: u32 = 1 << PALETTE_BITS;
+        let channel = |index: u32| ((index * 255 + (levels - 1) / 2) / (levels - 1)) as i32;
+        let lookup: Box<[Color]> = (0..levels.pow(3))
+            .map(|index| {
+                let (r, g, b): (i32, i32, i32) = (
+                    channel(index >> (2 * PALETTE_BITS)),
+                    channel((index >> PALETTE_BITS) & (levels - 1)),
+                    channel(index & (levels - 1)),
+                );
+                *palette
+                    .iter()
+                    .min_by_key(|color| {
+                        (i32::from(color.r) - r).pow(2)
+                            + (i32::from(color.g) - g).pow(2)
+                            + (i32::from(color.b) - b).pow(2)
+                    })
+                    .expect("Palette is not empty")
+            })
+            .collect();
+        ColorGrading {
+            lookup: Lookup::Palette(lookup),
+        }
+    }
+    /// Constructs grading that replaces colors with the closest colors of the palette,
+    /// which consists of all distinct opaque colors of the image (palette texture).
+    ///
+    pub fn palette_from_image(image: &Image) -> Self {
+        let (width, height): (u32, u32) = image.size();
+        let mut palette: Vec<Color> = Vec::new();
+        for y in 0..height {
+            for x in 0..width {
+                if let Some(color) = image.get_pixel(x, y) {
+                    let color: Color = Color::from_rgba(color.r, color.g, color.b, 255);
+                    if !palette.contains(&color) {
+                        palette.push(color);
+                    }
+                }
+            }
+        }
+        Self::palette(&palette)
+    }
+
+    /// Maps color through the lookup, keeping its alpha channel.
+    ///
+    pub fn map_color(&self, color: Color) -> Color {
+        match &self.lookup {
+            Lookup::Curves(curves) => Color::from_rgba(
+                curves[0][color.r as usize],
+                curves[1][color.g as usize],
+                curves[2][color.b as usize],
+                color.a,
+            ),
+            Lookup::Palette(lookup) => {
+                let shift: u32 = 8 - PALETTE_BITS;
+                let index: usize = (usize::from(color.r >> shift) << (2 * PALETTE_BITS))
+                    | (usize::from(color.g >> shift) << PALETTE_BITS)
+                    | usize::from(color.b >> shift);
+                Color {
+                    a: color.a,
+                    ..lookup[index]
+                }
+            }
+        }
+    }
+    /// Maps all pixels of the image (e.g. composited frame) through the lookup.
+    ///
+    pub fn apply(&self, image: &mut Image) {
+        let (width, height): (u32, u32) = image.size();
+        for y in 0..height {
+            for x in 0..width {
+                if let Some(color) = image.get_pixel(x, y) {
+                    image.set_pixel(x, y, self.map_color(color));
+                }
+            }
+        }
+    }
+}
+impl fmt::Debug for ColorGrading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.lookup {
+            Lookup::Curves(_) => f.write_str("ColorGrading::Curves"),
+            Lookup::Palette(_) => f.write_str("ColorGrading::Palette"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ColorGrading;
+    use crate::mathcore::Color;
+
+    #[test]
+    fn curves_and_palette() {
+        let black: Color = Color::from_rgba(0, 0, 0, 255);
+        let white: Color = Color::from_rgba(255, 255, 255, 255);
+        let inverted: ColorGrading =
+            ColorGrading::curves(|red| 255 - red, |green| green, |blue| blue / 2);
+        assert_eq!(
+            inverted.map_color(Color::from_rgba(10, 20, 30, 40)),
+            Color::from_rgba(245, 20, 15, 40)
+        );
+        assert_eq!(
+            ColorGrading::tint(white).map_color(Color::MAGENTA),
+            Color::MAGENTA
+        );
+        assert_eq!(ColorGrading::tint(black).map_color(white), black);
+
+        let gray: Color = Color::from_rgba(128, 128, 128, 255);
+        let palette: ColorGrading = ColorGrading::palette(&[black, gray, white]);
+        assert_eq!(
+            palette.map_color(Color::from_rgba(20, 10, 0, 0)),
+            Color::from_rgba(0, 0, 0, 0)
+        );
+        assert_eq!(
+            palette.map_color(Color::from_rgba(100, 140, 120, 255)),
+            gray
+        );
+        assert_eq!(
+            palette.map_color(Color::from_rgba(250, 240, 230, 255)),
+            white
+        );
+        assert_eq!(
+            ColorGrading::palette(&[]).map_color(Color::CYAN),
+            Color::CYAN
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! `graphicscore::meshes` submodule implements [`Mesh2D`] component and [`render_meshes`] system,
+//! which records meshes of a scene as geometry commands into its [`RenderQueue`].
+//!
+//! Meshes are arbitrary (optionally textured) triangles in local coordinates of a game object,
+//! so procedural shapes (water surfaces, outlines of destructible terrain) can be drawn
+//! by updating vertices of the component every frame.
+//!
+
+use crate::{
+    gamecore::{components::Component, scenes::Scene},
+    graphicscore::{
+        camera::Camera2D,
+        drawing::GeometryVertex,
+        queue::{DrawKind, RenderQueue},
+    },
+    mathcore::{
+        shapes::Aabb,
+        transforms::Transform2D,
+        vectors::{Point, Vector2},
+        Color,
+    },
+};
+use std::path::PathBuf;
+
+/// [`Mesh2D`] struct is a component that draws triangles at the [`Transform2D`] of a game object.
+///
+/// If `indices` are empty, every three consecutive vertices form a triangle,
+/// otherwise every three consecutive indices of vertices form a triangle.
+/// If `uvs` are shorter than `vertices`, missing texture coordinates are zero.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::graphicscore::{camera::Camera2D, drawing::GeometryVertex, meshes::Mesh2D};
+/// # use ggengine::mathcore::{transforms::Transform2D, vectors::{Point, Vector2}};
+/// let mut water: Mesh2D = Mesh2D::rect(100.0, 20.0);
+/// assert_eq!(water.indices, [0, 1, 2, 0, 2, 3]);
+/// water.vertices[0].y -= 5.0; // wave
+///
+/// let transform: Transform2D = Transform2D::from_translation(Vector2::from([50.0, 50.0]));
+/// let vertices: Vec<GeometryVertex> = water.geometry(&transform, &Camera2D::new((0.0, 0.0)));
+/// assert_eq!(vertices[0].position, Point::from([0.0, 35.0]));
+/// assert_eq!(vertices[2].uv, Vector2::from([1.0, 1.0]));
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct Mesh2D {
+    /// Vertices in local coordinates of game object.
+    ///
+    pub vertices: Vec<Point>,
+    /// Normalized texture coordinates of vertices.
+    ///
+    pub uvs: Vec<Vector2>,
+    /// Indices of vertices that form triangles.
+    ///
+    pub indices: Vec<i32>,
+    /// Color of all vertices (it modulates texture if mesh is textured).
+    ///
+    pub color: Color,
+    /// Name of the asset in texture cache that textures the mesh.
+    ///
+    pub texture: Option<PathBuf>,
+    /// Layer of mesh (see [`DrawCommand`](super::queue::DrawCommand)).
+    ///
+    pub layer: i32,
+}
+impl Mesh2D {
+    /// Constructs white untextured mesh from vertices and indices.
+    ///
+    pub fn new(vertices: Vec<Point>, indices: Vec<i32>) -> Self {
+        Mesh2D {
+            vertices,
+            uvs: Vec::new(),
+            indices,
+            color: Color::from_rgba(255, 255, 255, 255),
+            texture: None,
+            layer: 0,
+        }
+    }
+    /// Constructs white mesh that fills convex polygon (by fan of triangles from its first vertex).
+    ///
+    /// Texture coordinates map bounding box of the polygon to the whole texture.
+    ///
+    pub fn from_convex_polygon(polygon: &[Point]) -> Self {
+        let indices: Vec<i32> = (1..polygon.len().saturating_sub(1) as i32)
+            .flat_map(|index| [0, index, index + 1])
+            .collect();
+        let uvs: Vec<Vector2> = match Aabb::from_points(polygon) {
+            Some(aabb) => polygon
+                .iter()
+                .map(|&point| {
+                    let relative: Vector2 = point - aabb.min;
+                    Vector2::from([
+                        relative.x / aabb.width().max(f32::EPSILON),
+                        relative.y / aabb.height().max(f32::EPSILON),
+                    ])
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+        Mesh2D {
+            uvs,
+            ..Mesh2D::new(polygon.to_vec(), indices)
+        }
+    }
+    /// Constructs white rectangular mesh with given width and height that is centered at the origin.
+    ///
+    /// Vertices start from the left upper corner and go clockwise.
+    ///
+    pub fn rect(width: f32, height: f32) -> Self {
+        let (x, y): (f32, f32) = (width / 2.0, height / 2.0);
+        Self::from_convex_polygon(&[
+            Point::from([-x, -y]),
+            Point::from([x, -y]),
+            Point::from([x, y]),
+            Point::from([-x, y]),
+        ])
+    }
+
+    /// Returns vertices of the mesh in screen coordinates of given camera.
+    ///
+    pub fn geometry(&self, transform: &Transform2D, camera: &Camera2D) -> Vec<GeometryVertex> {
+        self.vertices
+            .iter()
+            .enumerate()
+            .map(|(index, &vertex)| GeometryVertex {
+                position: camera.world_to_screen(transform.transform_point(vertex)),
+                color: self.color,
+                uv: self.uvs.get(index).copied().unwrap_or(Vector2::zero()),
+            })
+            .collect()
+    }
+}
+impl Component for Mesh2D {}
+
+/// Records all meshes of the scene which have [`Transform2D`] into [`RenderQueue`] resource,
+/// culling those that are not visible by [`Camera2D`] resource.
+///
+/// Both resources are inserted if they are missing (see [`render_sprites`](super::sprites::render_sprites)).
+/// Meshes are recorded with depth equal to `y` coordinate of their translation.
+///
+pub fn render_meshes(scene: &mut Scene) {
+    let camera: Camera2D = *scene
+        .resources_mut()
+        .get_resource_or_insert_with(|| Camera2D::new((0.0, 0.0)));
+    let screen: Aabb = Aabb::new(
+        Point::zero(),
+        Point::from([camera.viewport.0, camera.viewport.1]),
+    );
+
+    let mut commands: Vec<(i32, f32, DrawKind)> = Vec::new();
+    for (gameobject_id, mesh) in scene.components().components::<Mesh2D>() {
+        let Some(transform) = scene
+            .components()
+            .get_component::<Transform2D>(gameobject_id)
+        else {
+            continue;
+        };
+        let vertices: Vec<GeometryVertex> = mesh.geometry(transform, &camera);
+        let bounds: Option<Aabb> = Aabb::from_points(
+            &vertices
+                .iter()
+                .map(|vertex| vertex.position)
+                .collect::<Vec<Point>>(),
+        );
+        if !bounds.is_some_and(|bounds| bounds.intersects(screen)) {
+            continue;
+        }
+
+        commands.push((
+            mesh.layer,
+            transform.translation.y,
+            DrawKind::Geometry {
+                vertices,
+                indices: (!mesh.indices.is_empty()).then(|| mesh.indices.clone()),
+                texture: mesh.texture.clone(),
+            },
+        ));
+    }
+
+    let queue: &mut RenderQueue = scene
+        .resources_mut()
+        .get_resource_or_insert_with(RenderQueue::new);
+    for (layer, depth, kind) in commands {
+        queue.push(layer, depth, kind);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_meshes, Mesh2D};
+    use crate::{
+        gamecore::scenes::Scene,
+        graphicscore::{camera::Camera2D, queue::RenderQueue},
+        mathcore::{
+            transforms::Transform2D,
+            vectors::{Point, Vector2},
+        },
+    };
+
+    #[test]
+    fn mesh_rendering() {
+        let pentagon: Mesh2D = Mesh2D::from_convex_polygon(&[
+            Point::from([0.0, -2.0]),
+            Point::from([2.0, 0.0]),
+            Point::from([1.0, 2.0]),
+            Point::from([-1.0, 2.0]),
+            Point::from([-2.0, 0.0]),
+        ]);
+        assert_eq!(pentagon.indices, [0, 1, 2, 0, 2, 3, 0, 3, 4]);
+        assert_eq!(pentagon.uvs[2], Vector2::from([0.75, 1.0]));
+        assert!(Mesh2D::from_convex_polygon(&[]).indices.is_empty());
+
+        let mut scene: Scene = Scene::new();
+        let _ = scene
+            .resources_mut()
+            .insert_resource(Camera2D::new((100.0, 100.0)));
+        let _ = scene.spawn((pentagon.clone(), Transform2D::identity()));
+        let _ = scene.spawn((
+            pentagon,
+            Transform2D::from_translation(Vector2::from([500.0, 0.0])),
+        ));
+        let _ = scene.spawn(Mesh2D::rect(1.0, 1.0));
+        render_meshes(&mut scene);
+        assert_eq!(
+            scene
+                .resources()
+                .get_resource::<RenderQueue>()
+                .map(RenderQueue::len),
+            Some(1)
+        );
+    }
+}
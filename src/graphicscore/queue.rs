@@ -0,0 +1,397 @@
+//! `graphicscore::queue` submodule implements [`RenderQueue`] - recording of draw commands
+//! that are later sorted and executed on a canvas in a single render stage.
+//!
+//! Systems push [`DrawCommand`]s into the queue during update without calling `sdl2` at all,
+//! so commands can be generated on any thread (queues are `Send` and can be merged by `RenderQueue::append`).
+//! Render stage then sorts commands by their layer and depth, culls those that are out of the viewport
+//! and executes the rest on the canvas.
+//!
+//! Commands refer to textures and fonts by their names - textures are taken from [`TextureCache`]
+//! and fonts are taken from the map that is passed to `RenderQueue::execute`.
+//!
+
+use crate::{
+    datacore::fonts::{Font, FontShowMode},
+    gamecore::components::Resource,
+    graphicscore::{
+        cache::{TextureCache, TextureHandle},
+        drawing::{Canvas, GeometryVertex},
+        textures::{Texture, TextureCreator},
+    },
+    log::{self, LogTarget},
+    mathcore::{
+        shapes::{Aabb, Rect, Segment, Shape},
+        vectors::Point,
+        Angle, Color, Size,
+    },
+    GGError,
+};
+use std::{collections::HashMap, path::PathBuf};
+
+/// [`DrawShape`] enum lists graphical primitives that can be drawn by [`DrawKind::Shape`] command.
+///
+#[derive(Clone, Debug)]
+pub enum DrawShape {
+    /// Single point.
+    ///
+    Point(Point),
+    /// Segment.
+    ///
+    Segment(Segment),
+    /// Outline of rectangle.
+    ///
+    Rect(Rect),
+    /// Outline of polygon.
+    ///
+    Polygon(Vec<Point>),
+}
+
+/// [`DrawKind`] enum lists what can be drawn by [`DrawCommand`].
+///
+#[derive(Clone, Debug)]
+pub enum DrawKind {
+    /// Texture of [`TextureCache`] asset that is blitted to the canvas.
+    ///
+    Sprite {
+        /// Name of the asset in [`TextureCache`].
+        ///
+        texture: PathBuf,
+        /// Area of canvas to which texture is blitted (see `Canvas::blit_from_texture`).
+        ///
+        dst_area: Rect,
+        /// Area of texture that is blitted (whole texture if `None`).
+        ///
+        src_area: Option<Rect>,
+    },
+    /// Text that is shown with font and blitted to the canvas.
+    ///
+    Text {
+        /// Text that is shown.
+        ///
+        text: String,
+        /// Name of the font.
+        ///
+        font: String,
+        /// Mode in which text is shown.
+        ///
+        mode: FontShowMode,
+        /// Position of the left upper corner of text.
+        ///
+        position: Point,
+    },
+    /// Graphical primitive that is drawn with given color.
+    ///
+    Shape {
+        /// Primitive that is drawn.
+        ///
+        shape: DrawShape,
+        /// Color of the primitive.
+        ///
+        color: Color,
+    },
+    /// Triangles that are drawn by `Canvas::draw_geometry`.
+    ///
+    Geometry {
+        /// Vertices of triangles.
+        ///
+        vertices: Vec<GeometryVertex>,
+        /// Indices of vertices (see `Canvas::draw_geometry`).
+        ///
+        indices: Option<Vec<i32>>,
+        /// Name of the asset in [`TextureCache`] that textures triangles.
+        ///
+        texture: Option<PathBuf>,
+    },
+}
+
+/// [`DrawCommand`] struct represents single draw command with its sorting keys.
+///
+/// Commands are executed in order of increasing layer, commands of the same layer are
+/// executed in order of increasing depth, and commands with equal layer and depth are
+/// executed in order in which they were pushed.
+///
+#[derive(Clone, Debug)]
+pub struct DrawCommand {
+    /// Layer of the command.
+    ///
+    pub layer: i32,
+    /// Depth of the command inside of its layer (e.g. y coordinate for top-down games).
+    ///
+    pub depth: f32,
+    /// What is drawn.
+    ///
+    pub kind: DrawKind,
+}
+impl DrawCommand {
+    /// Returns bounding box of what is drawn or `None` if it cannot be known before execution.
+    ///
+    fn aabb(&self) -> Option<Aabb> {
+        match &self.kind {
+            DrawKind::Sprite { dst_area, .. } => Some(dst_area.aabb()),
+            DrawKind::Text { .. } => None,
+            DrawKind::Shape { shape, .. } => match shape {
+                DrawShape::Point(point) => Some(Aabb::new(*point, *point)),
+                DrawShape::Segment(segment) => {
+                    Some(Aabb::new(segment.points[0], segment.points[1]))
+                }
+                DrawShape::Rect(rect) => Some(rect.aabb()),
+                DrawShape::Polygon(polygon) => Aabb::from_points(polygon),
+            },
+            DrawKind::Geometry { vertices, .. } => Aabb::from_points(
+                &vertices
+                    .iter()
+                    .map(|vertex| vertex.position)
+                    .collect::<Vec<Point>>(),
+            ),
+        }
+    }
+}
+
+/// [`RenderQueue`] struct records [`DrawCommand`]s and executes them on a canvas.
+///
+/// [`RenderQueue`] is a resource, so systems of a scene can push commands into it
+/// and render stage can execute them once per frame.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::graphicscore::queue::{DrawKind, DrawShape, RenderQueue};
+/// # use ggengine::mathcore::{vectors::Point, Color};
+/// let mut queue: RenderQueue = RenderQueue::new();
+/// queue.push(1, 0.0, DrawKind::Shape {
+///     shape: DrawShape::Point(Point::from([10.0, 10.0])),
+///     color: Color::RED,
+/// });
+///
+/// // commands can be recorded on another thread
+/// let mut background: RenderQueue = std::thread::spawn(|| {
+///     let mut queue: RenderQueue = RenderQueue::new();
+///     queue.push(0, 0.0, DrawKind::Shape {
+///         shape: DrawShape::Point(Point::from([0.0, 0.0])),
+///         color: Color::BLUE,
+///     });
+///     queue
+/// }).join().expect("Thread should not panic");
+///
+/// queue.append(&mut background);
+/// queue.sort();
+/// assert_eq!(queue.commands()[0].layer, 0);
+/// ```
+///
+#[derive(Debug, Default)]
+pub struct RenderQueue {
+    /// Recorded commands.
+    ///
+    commands: Vec<DrawCommand>,
+}
+impl RenderQueue {
+    /// Initializes empty [`RenderQueue`].
+    ///
+    pub fn new() -> Self {
+        RenderQueue {
+            commands: Vec::new(),
+        }
+    }
+
+    /// Pushes draw command with given layer and depth to the queue.
+    ///
+    pub fn push(&mut self, layer: i32, depth: f32, kind: DrawKind) {
+        self.commands.push(DrawCommand { layer, depth, kind });
+    }
+    /// Moves all commands of another queue to the end of this queue, leaving another queue empty.
+    ///
+    pub fn append(&mut self, other: &mut RenderQueue) {
+        self.commands.append(&mut other.commands);
+    }
+    /// Removes all commands from the queue.
+    ///
+    pub fn clear(&mut self) {
+        self.commands.clear();
+    }
+
+    /// Returns recorded commands.
+    ///
+    pub fn commands(&self) -> &[DrawCommand] {
+        &self.commands
+    }
+    /// Returns number of recorded commands.
+    ///
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+    /// Returns whether the queue has no commands.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Sorts commands by their layer and depth, keeping order of commands with equal keys.
+    ///
+    pub fn sort(&mut self) {
+        self.commands.sort_by(|a, b| {
+            a.layer
+                .cmp(&b.layer)
+                .then_with(|| a.depth.total_cmp(&b.depth))
+        });
+    }
+    /// Removes commands that do not intersect the viewport and returns number of removed commands.
+    ///
+    /// Commands which bounds cannot be known before execution (text) are never removed.
+    ///
+    pub fn cull(&mut self, viewport: Aabb) -> usize {
+        let before: usize = self.commands.len();
+        self.commands
+            .retain(|command| command.aabb().is_none_or(|aabb| aabb.intersects(viewport)));
+        before - self.commands.len()
+    }
+
+    /// Sorts, culls (if viewport is present) and executes all commands on the canvas, leaving queue empty.
+    ///
+    /// Commands which textures or fonts are missing are skipped with a warning.
+    ///
+    /// # Errors
+    /// Error is returned if drawing or texture creation fails; remaining commands are discarded.
+    ///
+    pub fn execute<'a>(
+        &mut self,
+        canvas: &mut impl Canvas<'a>,
+        textures: &mut TextureCache,
+        fonts: &HashMap<String, Font>,
+        viewport: Option<Aabb>,
+    ) -> Result<(), GGError> {
+        if let Some(viewport) = viewport {
+            let _ = self.cull(viewport);
+        }
+        self.sort();
+        for command in self.commands.drain(..) {
+            match command.kind {
+                DrawKind::Sprite {
+                    texture,
+                    dst_area,
+                    src_area,
+                } => {
+                    let Some(handle) = find_texture(textures, &texture) else {
+                        continue;
+                    };
+                    canvas.blit_from_texture(
+                        Some(dst_area),
+                        textures.texture(&handle)?,
+                        src_area,
+                    )?;
+                }
+                DrawKind::Text {
+                    text,
+                    font,
+                    mode,
+                    position,
+                } => {
+                    let Some(font) = fonts.get(&font) else {
+                        log::warn(
+                            LogTarget::GraphicsCore,
+                            format_args!("Font {font} is missing, text is skipped"),
+                        );
+                        continue;
+                    };
+                    let texture_creator: TextureCreator = canvas.texture_creator();
+                    let texture: Texture =
+                        texture_creator.create_texture_from_image(&font.show_text(mode, &text)?)?;
+                    let (width, height): (u32, u32) = texture.size();
+                    let dst_area: Rect = Rect::from_origin(
+                        Point::from([
+                            position.x + width as f32 / 2.0,
+                            position.y + height as f32 / 2.0,
+                        ]),
+                        Angle::ZERO,
+                        Size::from_value(width as f32),
+                        Size::from_value(height as f32),
+                    );
+                    canvas.blit_from_texture(Some(dst_area), &texture, None)?;
+                }
+                DrawKind::Shape { shape, color } => {
+                    canvas.set_draw_color(color);
+                    match shape {
+                        DrawShape::Point(point) => canvas.draw_point(point)?,
+                        DrawShape::Segment(segment) => canvas.draw_segment(segment)?,
+                        DrawShape::Rect(rect) => canvas.draw_rect(rect)?,
+                        DrawShape::Polygon(polygon) => canvas.draw_polygon(&polygon)?,
+                    }
+                }
+                DrawKind::Geometry {
+                    vertices,
+                    indices,
+                    texture,
+                } => match texture {
+                    Some(texture) => {
+                        let Some(handle) = find_texture(textures, &texture) else {
+                            continue;
+                        };
+                        canvas.draw_geometry(
+                            &vertices,
+                            indices.as_deref(),
+                            Some(textures.texture(&handle)?),
+                        )?;
+                    }
+                    None => canvas.draw_geometry(&vertices, indices.as_deref(), None)?,
+                },
+            }
+        }
+        Ok(())
+    }
+}
+impl Resource for RenderQueue {}
+
+/// Returns handle to the texture with given name, logging warning if it is missing.
+///
+fn find_texture(textures: &TextureCache, name: &PathBuf) -> Option<TextureHandle> {
+    let handle: Option<TextureHandle> = textures.handle(name);
+    if handle.is_none() {
+        log::warn(
+            LogTarget::GraphicsCore,
+            format_args!("Texture {} is missing, command is skipped", name.display()),
+        );
+    }
+    handle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DrawKind, DrawShape, RenderQueue};
+    use crate::mathcore::{shapes::Aabb, vectors::Point, Color};
+
+    fn point(x: f32) -> DrawKind {
+        DrawKind::Shape {
+            shape: DrawShape::Point(Point::from([x, 0.0])),
+            color: Color::RED,
+        }
+    }
+
+    #[test]
+    fn sorting_and_culling() {
+        let mut queue: RenderQueue = RenderQueue::new();
+        queue.push(1, 0.0, point(0.0));
+        queue.push(0, 5.0, point(1.0));
+        queue.push(0, -1.0, point(2.0));
+        queue.push(0, 5.0, point(3.0));
+        queue.push(-2, 100.0, point(400.0));
+
+        assert_eq!(
+            queue.cull(Aabb::new(
+                Point::from([-10.0, -10.0]),
+                Point::from([10.0, 10.0])
+            )),
+            1
+        );
+        queue.sort();
+        let order: Vec<f32> = queue
+            .commands()
+            .iter()
+            .map(|command| match &command.kind {
+                DrawKind::Shape {
+                    shape: DrawShape::Point(point),
+                    ..
+                } => point.x,
+                _ => unreachable!("Only points were pushed"),
+            })
+            .collect();
+        assert_eq!(order, [2.0, 1.0, 3.0, 0.0]);
+    }
+}
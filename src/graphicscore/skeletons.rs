@@ -0,0 +1,1143 @@
+//! `graphicscore::skeletons` submodule implements skeletal (bone-based) 2D animation.
+//!
+//! [`Skeleton`] asset holds hierarchy of [`Bone`]s with their bind poses, [`SkinnedPart`]s
+//! (textured quads that are attached to bones) and named [`SkeletalAnimation`]s, which are
+//! keyframed tracks of bone transforms. Skeletons can be built by hand or imported from
+//! a subset of Spine JSON format (see `Skeleton::from_spine_json`).
+//!
+//! [`SkeletalAnimator`] component plays animations of a skeleton; [`update_skeletons`] system
+//! advances animators by [`Time`] resource and [`render_skeletons`] system records their parts into [`RenderQueue`]
+//! as textured geometry.
+//!
+
+use crate::{
+    gamecore::{
+        components::Component,
+        scenes::Scene,
+        time::{Clock, Time},
+    },
+    graphicscore::{
+        camera::Camera2D,
+        drawing::GeometryVertex,
+        queue::{DrawKind, RenderQueue},
+    },
+    mathcore::{transforms::Transform2D, vectors::Vector2, Angle, Color, Size},
+    GGError,
+};
+use std::{collections::HashMap, path::PathBuf, rc::Rc, time::Duration};
+
+/// [`Bone`] struct is a node of [`Skeleton`] hierarchy.
+///
+#[derive(Clone, Debug)]
+pub struct Bone {
+    /// Name of the bone.
+    ///
+    pub name: String,
+    /// Index of parent bone (bones without parent are attached to the game object).
+    ///
+    pub parent: Option<usize>,
+    /// Transform of the bone relative to its parent when no animation is applied.
+    ///
+    pub bind_pose: Transform2D,
+}
+
+/// [`SkinnedPart`] struct is a textured quad that is attached to a bone of [`Skeleton`].
+///
+/// Parts are drawn in order in which they were added to the skeleton.
+///
+#[derive(Clone, Debug)]
+pub struct SkinnedPart {
+    /// Index of the bone to which part is attached.
+    ///
+    pub bone: usize,
+    /// Transform of the center of the part relative to its bone.
+    ///
+    pub offset: Transform2D,
+    /// Size of the part (width and height).
+    ///
+    pub size: (f32, f32),
+    /// Name of the asset in [`TextureCache`](super::cache::TextureCache) that textures the part.
+    ///
+    pub texture: Option<PathBuf>,
+    /// Texture coordinates of the left upper corner of the part.
+    ///
+    pub uv_min: Vector2,
+    /// Texture coordinates of the right lower corner of the part.
+    ///
+    pub uv_max: Vector2,
+    /// Color by which texture of the part is modulated.
+    ///
+    pub color: Color,
+}
+impl SkinnedPart {
+    /// Constructs part that is textured by the whole texture.
+    ///
+    pub fn new(bone: usize, offset: Transform2D, size: (f32, f32), texture: PathBuf) -> Self {
+        SkinnedPart {
+            bone,
+            offset,
+            size,
+            texture: Some(texture),
+            uv_min: Vector2::zero(),
+            uv_max: Vector2::from([1.0, 1.0]),
+            color: Color::from_rgba(255, 255, 255, 255),
+        }
+    }
+}
+
+/// [`Interpolation`] enum lists how values between keyframes are computed.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    /// Value changes linearly until the next keyframe.
+    ///
+    #[default]
+    Linear,
+    /// Value stays the same until the next keyframe.
+    ///
+    Stepped,
+}
+
+/// [`Keyframe`] struct is a value of animated property at given time.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Keyframe<T> {
+    /// Time of the keyframe since the start of animation.
+    ///
+    pub time: Duration,
+    /// Value of the property.
+    ///
+    pub value: T,
+    /// Interpolation between this keyframe and the next one.
+    ///
+    pub interpolation: Interpolation,
+}
+impl<T> Keyframe<T> {
+    /// Constructs keyframe with linear interpolation.
+    ///
+    pub fn new(time: Duration, value: T) -> Self {
+        Keyframe {
+            time,
+            value,
+            interpolation: Interpolation::Linear,
+        }
+    }
+}
+
+/// Samples keyframes (which should be sorted by time) at given time.
+///
+/// Returns `None` if there are no keyframes.
+///
+fn sample<T: Copy>(
+    keyframes: &[Keyframe<T>],
+    time: Duration,
+    lerp: impl Fn(T, T, f32) -> T,
+) -> Option<T> {
+    let next: usize = keyframes.partition_point(|keyframe| keyframe.time <= time);
+    let Some(previous) = next.checked_sub(1).map(|index| &keyframes[index]) else {
+        return keyframes.first().map(|keyframe| keyframe.value);
+    };
+    Some(match (keyframes.get(next), previous.interpolation) {
+        (Some(next), Interpolation::Linear) => lerp(
+            previous.value,
+            next.value,
+            (time - previous.time).as_secs_f32() / (next.time - previous.time).as_secs_f32(),
+        ),
+        _ => previous.value,
+    })
+}
+
+/// [`BoneTrack`] struct holds keyframes of one bone.
+///
+/// Values are applied on top of bind pose of the bone - translation and rotation are added to it
+/// and scale multiplies it.
+///
+#[derive(Clone, Debug, Default)]
+pub struct BoneTrack {
+    /// Index of animated bone.
+    ///
+    pub bone: usize,
+    /// Keyframes of translation.
+    ///
+    pub translation: Vec<Keyframe<Vector2>>,
+    /// Keyframes of rotation.
+    ///
+    pub rotation: Vec<Keyframe<Angle>>,
+    /// Keyframes of scale (width and height).
+    ///
+    pub scale: Vec<Keyframe<(f32, f32)>>,
+}
+
+/// [`SkeletalAnimation`] struct is a set of keyframed bone tracks.
+///
+#[derive(Clone, Debug, Default)]
+pub struct SkeletalAnimation {
+    /// Duration of the animation.
+    ///
+    pub duration: Duration,
+    /// Tracks of animated bones.
+    ///
+    pub tracks: Vec<BoneTrack>,
+}
+impl SkeletalAnimation {
+    /// Applies animation at given time to local poses of bones (which are usually bind poses).
+    ///
+    /// Tracks of bones that are out of bounds are ignored.
+    ///
+    pub fn apply(&self, time: Duration, poses: &mut [Transform2D]) {
+        for track in &self.tracks {
+            let Some(pose) = poses.get_mut(track.bone) else {
+                continue;
+            };
+            if let Some(translation) = sample(&track.translation, time, Vector2::lerp) {
+                pose.translation += translation;
+            }
+            if let Some(rotation) = sample(&track.rotation, time, Angle::lerp) {
+                pose.rotation += rotation;
+            }
+            if let Some((x, y)) = sample(&track.scale, time, |from, to, t| {
+                (from.0 + (to.0 - from.0) * t, from.1 + (to.1 - from.1) * t)
+            }) {
+                pose.scale = (
+                    Size::from_value(pose.scale.0.get() * x),
+                    Size::from_value(pose.scale.1.get() * y),
+                );
+            }
+        }
+    }
+}
+
+/// [`Skeleton`] struct is an asset that describes bones, parts and animations of a character.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::graphicscore::skeletons::{BoneTrack, Keyframe, SkeletalAnimation, Skeleton};
+/// # use ggengine::mathcore::{floats::FloatOperations, transforms::Transform2D, vectors::{Point, Vector2}, Angle};
+/// # use std::time::Duration;
+/// let mut skeleton: Skeleton = Skeleton::new();
+/// let shoulder: usize = skeleton.add_bone("shoulder", None, Transform2D::identity()).expect("Bone is new");
+/// let elbow: usize = skeleton
+///     .add_bone("elbow", Some("shoulder"), Transform2D::from_translation(Vector2::from([10.0, 0.0])))
+///     .expect("Parent exists");
+/// skeleton.add_animation("wave", SkeletalAnimation {
+///     duration: Duration::from_secs(1),
+///     tracks: vec![BoneTrack {
+///         bone: shoulder,
+///         rotation: vec![
+///             Keyframe::new(Duration::ZERO, Angle::ZERO),
+///             Keyframe::new(Duration::from_secs(1), Angle::DEG90),
+///         ],
+///         ..Default::default()
+///     }],
+/// });
+///
+/// let mut poses: Vec<Transform2D> = skeleton.bind_poses();
+/// skeleton.animation("wave").expect("Animation exists").apply(Duration::from_secs(1), &mut poses);
+/// let elbow_position: Point = skeleton.world_poses(&poses)[elbow].translation;
+/// assert_eq!(elbow_position.round_up_to(3), Point::from([0.0, 10.0]));
+/// ```
+///
+#[derive(Clone, Debug, Default)]
+pub struct Skeleton {
+    /// Bones of the skeleton (parents always precede their children).
+    ///
+    bones: Vec<Bone>,
+    /// Parts of the skeleton in order of drawing.
+    ///
+    parts: Vec<SkinnedPart>,
+    /// Animations of the skeleton.
+    ///
+    animations: HashMap<String, SkeletalAnimation>,
+}
+impl Skeleton {
+    /// Constructs empty skeleton.
+    ///
+    pub fn new() -> Self {
+        Skeleton::default()
+    }
+
+    /// Adds bone to the skeleton and returns its index.
+    ///
+    /// # Errors
+    /// Error is returned if skeleton already has bone with the same name or if there is no parent bone
+    /// (parents should be added before their children).
+    ///
+    pub fn add_bone(
+        &mut self,
+        name: &str,
+        parent: Option<&str>,
+        bind_pose: Transform2D,
+    ) -> Result<usize, GGError> {
+        if self.bone_index(name).is_some() {
+            return Err(GGError::InvalidArgument(format!(
+                "Skeleton already has bone {name}"
+            )));
+        }
+        let parent: Option<usize> = parent
+            .map(|parent| {
+                self.bone_index(parent).ok_or_else(|| {
+                    GGError::InvalidArgument(format!("Skeleton has no bone {parent}"))
+                })
+            })
+            .transpose()?;
+        self.bones.push(Bone {
+            name: name.to_string(),
+            parent,
+            bind_pose,
+        });
+        Ok(self.bones.len() - 1)
+    }
+    /// Adds part to the skeleton (it will be drawn on top of previously added parts).
+    ///
+    /// # Errors
+    /// Error is returned if part is attached to bone that does not exist.
+    ///
+    pub fn add_part(&mut self, part: SkinnedPart) -> Result<(), GGError> {
+        if part.bone >= self.bones.len() {
+            return Err(GGError::InvalidArgument(format!(
+                "Skeleton has no bone with index {}",
+                part.bone
+            )));
+        }
+        self.parts.push(part);
+        Ok(())
+    }
+    /// Adds animation to the skeleton, returning previous animation with the same name.
+    ///
+    pub fn add_animation(
+        &mut self,
+        name: &str,
+        animation: SkeletalAnimation,
+    ) -> Option<SkeletalAnimation> {
+        self.animations.insert(name.to_string(), animation)
+    }
+
+    /// Returns index of the bone with given name.
+    ///
+    pub fn bone_index(&self, name: &str) -> Option<usize> {
+        self.bones.iter().position(|bone| bone.name == name)
+    }
+    /// Returns bones of the skeleton.
+    ///
+    pub fn bones(&self) -> &[Bone] {
+        &self.bones
+    }
+    /// Returns parts of the skeleton.
+    ///
+    pub fn parts(&self) -> &[SkinnedPart] {
+        &self.parts
+    }
+    /// Returns animation with given name.
+    ///
+    pub fn animation(&self, name: &str) -> Option<&SkeletalAnimation> {
+        self.animations.get(name)
+    }
+    /// Returns iterator over names of all animations.
+    ///
+    pub fn animation_names(&self) -> impl Iterator<Item = &str> {
+        self.animations.keys().map(String::as_str)
+    }
+
+    /// Returns local poses of bones when no animation is applied.
+    ///
+    pub fn bind_poses(&self) -> Vec<Transform2D> {
+        self.bones.iter().map(|bone| bone.bind_pose).collect()
+    }
+    /// Converts local poses of bones (poses relative to parent bones) to poses relative to the skeleton.
+    ///
+    pub fn world_poses(&self, local_poses: &[Transform2D]) -> Vec<Transform2D> {
+        let mut poses: Vec<Transform2D> = Vec::with_capacity(self.bones.len());
+        for (bone, local_pose) in self.bones.iter().zip(local_poses) {
+            let pose: Transform2D = match bone.parent {
+                Some(parent) => poses[parent] * *local_pose,
+                None => *local_pose,
+            };
+            poses.push(pose);
+        }
+        poses
+    }
+
+    /// Imports skeleton from Spine JSON.
+    ///
+    /// Supported subset consists of bones (translation, rotation and scale), slots with region
+    /// attachments of the default skin (which become parts that are textured by the whole texture
+    /// named by attachment path) and rotate, translate and scale timelines of animations
+    /// (bezier curves are interpolated linearly).
+    /// Spine uses y-up coordinates, so vertical axis and rotations are flipped;
+    /// negative scales are not supported and are replaced with their absolute values.
+    ///
+    /// # Errors
+    /// Error is returned if JSON is malformed or if skeleton references missing bones.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::graphicscore::skeletons::Skeleton;
+    /// let skeleton: Skeleton = Skeleton::from_spine_json(r#"{
+    ///     "bones": [{ "name": "root" }, { "name": "head", "parent": "root", "y": 20 }],
+    ///     "slots": [{ "name": "head", "bone": "head", "attachment": "head" }],
+    ///     "skins": [{ "name": "default", "attachments": {
+    ///         "head": { "head": { "width": 16, "height": 16 } }
+    ///     } }],
+    ///     "animations": { "nod": { "bones": { "head": {
+    ///         "rotate": [{ "value": 0 }, { "time": 0.5, "value": 15 }, { "time": 1, "value": 0 }]
+    ///     } } } }
+    /// }"#).expect("Skeleton is valid");
+    /// assert_eq!(skeleton.bones().len(), 2);
+    /// assert_eq!(skeleton.parts().len(), 1);
+    /// assert!(skeleton.animation("nod").is_some());
+    /// ```
+    ///
+    pub fn from_spine_json(json: &str) -> Result<Skeleton, GGError> {
+        let data: spine::SkeletonData =
+            serde_json::from_str(json).map_err(|error| GGError::Decode(error.to_string()))?;
+        data.into_skeleton()
+    }
+}
+
+/// [`SkeletalAnimator`] struct is a component that plays animations of a [`Skeleton`].
+///
+/// Skeleton is shared between animators through [`Rc`], so many characters can use the same asset.
+///
+#[derive(Clone, Debug)]
+pub struct SkeletalAnimator {
+    /// Skeleton that is animated.
+    ///
+    pub skeleton: Rc<Skeleton>,
+    /// Layer of parts (see [`DrawCommand`](super::queue::DrawCommand)).
+    ///
+    pub layer: i32,
+    /// Multiplier of time by which animation is advanced.
+    ///
+    pub speed: f32,
+    /// Whether animation starts over after it has finished.
+    ///
+    pub is_looping: bool,
+    /// Clock by which animation is advanced by `SkeletalAnimator::advance_by`.
+    ///
+    pub clock: Clock,
+
+    /// Name of the animation that is played.
+    ///
+    animation: Option<String>,
+    /// Time since the start of the animation.
+    ///
+    time: Duration,
+}
+impl SkeletalAnimator {
+    /// Constructs animator that shows bind pose of the skeleton.
+    ///
+    pub fn new(skeleton: Rc<Skeleton>) -> Self {
+        SkeletalAnimator {
+            skeleton,
+            layer: 0,
+            speed: 1.0,
+            is_looping: true,
+            clock: Clock::Scaled,
+
+            animation: None,
+            time: Duration::ZERO,
+        }
+    }
+
+    /// Starts animation with given name from the beginning.
+    ///
+    /// Returns `false` if skeleton has no such animation.
+    ///
+    pub fn play(&mut self, animation: &str) -> bool {
+        if self.skeleton.animation(animation).is_none() {
+            return false;
+        }
+        self.animation = Some(animation.to_string());
+        self.time = Duration::ZERO;
+        true
+    }
+    /// Stops animation, returning skeleton to bind pose.
+    ///
+    pub fn stop(&mut self) {
+        self.animation = None;
+        self.time = Duration::ZERO;
+    }
+    /// Returns name of the animation that is played.
+    ///
+    pub fn animation(&self) -> Option<&str> {
+        self.animation.as_deref()
+    }
+    /// Returns time since the start of the animation.
+    ///
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+    /// Returns whether non-looping animation has finished.
+    ///
+    pub fn is_finished(&self) -> bool {
+        !self.is_looping
+            && self
+                .animation
+                .as_deref()
+                .and_then(|animation| self.skeleton.animation(animation))
+                .is_some_and(|animation| self.time >= animation.duration)
+    }
+
+    /// Advances animation by given delta (scaled by speed of animator).
+    ///
+    pub fn advance(&mut self, delta: Duration) {
+        let Some(animation) = self
+            .animation
+            .as_deref()
+            .and_then(|animation| self.skeleton.animation(animation))
+        else {
+            return;
+        };
+        self.time += delta.mul_f32(self.speed.max(0.0));
+        if self.time >= animation.duration {
+            self.time = if !self.is_looping {
+                animation.duration
+            } else if animation.duration.is_zero() {
+                Duration::ZERO
+            } else {
+                Duration::from_nanos((self.time.as_nanos() % animation.duration.as_nanos()) as u64)
+            };
+        }
+    }
+    /// Advances animation by delta of [`Time`] that is measured by clock of the animator.
+    ///
+    pub fn advance_by(&mut self, time: &Time) {
+        self.advance(time.delta_by(self.clock));
+    }
+
+    /// Returns poses of bones relative to the skeleton.
+    ///
+    pub fn world_poses(&self) -> Vec<Transform2D> {
+        let mut poses: Vec<Transform2D> = self.skeleton.bind_poses();
+        if let Some(animation) = self
+            .animation
+            .as_deref()
+            .and_then(|animation| self.skeleton.animation(animation))
+        {
+            animation.apply(self.time, &mut poses);
+        }
+        self.skeleton.world_poses(&poses)
+    }
+    /// Builds geometry of parts in screen coordinates of the camera.
+    ///
+    /// Consecutive parts that share a texture are merged into one batch,
+    /// so every batch can be drawn by one call of `Canvas::draw_geometry`.
+    ///
+    pub fn geometry(
+        &self,
+        transform: &Transform2D,
+        camera: &Camera2D,
+    ) -> Vec<(Option<PathBuf>, Vec<GeometryVertex>, Vec<i32>)> {
+        let poses: Vec<Transform2D> = self.world_poses();
+        let mut batches: Vec<(Option<PathBuf>, Vec<GeometryVertex>, Vec<i32>)> = Vec::new();
+        for part in self.skeleton.parts() {
+            let Some(pose) = poses.get(part.bone) else {
+                continue;
+            };
+            if batches.last().is_none_or(|batch| batch.0 != part.texture) {
+                batches.push((part.texture.clone(), Vec::new(), Vec::new()));
+            }
+            let Some((_, vertices, indices)) = batches.last_mut() else {
+                continue;
+            };
+            let first: i32 = vertices.len() as i32;
+            let part_transform: Transform2D = *transform * *pose * part.offset;
+            let (half_width, half_height): (f32, f32) = (part.size.0 / 2.0, part.size.1 / 2.0);
+            for (x, y, u, v) in [
+                (-half_width, -half_height, part.uv_min.x, part.uv_min.y),
+                (half_width, -half_height, part.uv_max.x, part.uv_min.y),
+                (half_width, half_height, part.uv_max.x, part.uv_max.y),
+                (-half_width, half_height, part.uv_min.x, part.uv_max.y),
+            ] {
+                vertices.push(GeometryVertex {
+                    position: camera
+                        .world_to_screen(part_transform.transform_point(Vector2::from([x, y]))),
+                    color: part.color,
+                    uv: Vector2::from([u, v]),
+                });
+            }
+            indices.extend([first, first + 1, first + 2, first, first + 2, first + 3]);
+        }
+        batches
+    }
+}
+impl Component for SkeletalAnimator {}
+
+/// Advances all skeletal animators of the scene by [`Time`] resource.
+///
+/// Animators are not advanced if there is no [`Time`] resource.
+///
+pub fn update_skeletons(scene: &mut Scene) {
+    let time: Time = scene
+        .resources()
+        .get_resource::<Time>()
+        .copied()
+        .unwrap_or_default();
+    for (_, animator) in scene.components_mut().components_mut::<SkeletalAnimator>() {
+        animator.advance_by(&time);
+    }
+}
+
+/// Records parts of all skeletons of the scene which have [`Transform2D`] into [`RenderQueue`] resource,
+/// using [`Camera2D`] resource to convert them into screen coordinates.
+///
+/// Both resources are inserted if they are missing (see [`render_sprites`](super::sprites::render_sprites)).
+/// Parts are recorded with depth equal to `y` coordinate of translation of their game object,
+/// so parts of one skeleton are never interleaved with other objects of the same layer.
+///
+pub fn render_skeletons(scene: &mut Scene) {
+    let camera: Camera2D = *scene
+        .resources_mut()
+        .get_resource_or_insert_with(|| Camera2D::new((0.0, 0.0)));
+    let mut commands: Vec<(i32, f32, DrawKind)> = Vec::new();
+    for (gameobject_id, animator) in scene.components().components::<SkeletalAnimator>() {
+        let Some(transform) = scene
+            .components()
+            .get_component::<Transform2D>(gameobject_id)
+        else {
+            continue;
+        };
+        for (texture, vertices, indices) in animator.geometry(transform, &camera) {
+            commands.push((
+                animator.layer,
+                transform.translation.y,
+                DrawKind::Geometry {
+                    vertices,
+                    indices: Some(indices),
+                    texture,
+                },
+            ));
+        }
+    }
+
+    let queue: &mut RenderQueue = scene
+        .resources_mut()
+        .get_resource_or_insert_with(RenderQueue::new);
+    for (layer, depth, kind) in commands {
+        queue.push(layer, depth, kind);
+    }
+}
+
+/// `spine` submodule declares subset of Spine JSON format that is supported by `Skeleton::from_spine_json`.
+///
+mod spine {
+    use super::{BoneTrack, Interpolation, Keyframe, SkeletalAnimation, Skeleton, SkinnedPart};
+    use crate::{
+        mathcore::{transforms::Transform2D, vectors::Vector2, Angle, Color, Size},
+        GGError,
+    };
+    use serde::Deserialize;
+    use std::{collections::HashMap, path::PathBuf, time::Duration};
+
+    /// Returns default value of scale.
+    ///
+    fn one() -> f32 {
+        1.0
+    }
+
+    /// Converts Spine transform (which uses y-up coordinates and degrees) to [`Transform2D`].
+    ///
+    fn transform(x: f32, y: f32, rotation: f32, scale_x: f32, scale_y: f32) -> Transform2D {
+        Transform2D::new(
+            Vector2::from([x, -y]),
+            Angle::from_degrees(-rotation),
+            (Size::from_value(scale_x), Size::from_value(scale_y)),
+        )
+    }
+
+    /// Root of Spine JSON.
+    ///
+    #[derive(Deserialize)]
+    pub(super) struct SkeletonData {
+        /// Bones in order in which parents precede children.
+        ///
+        #[serde(default)]
+        bones: Vec<BoneData>,
+        /// Slots in order of drawing.
+        ///
+        #[serde(default)]
+        slots: Vec<SlotData>,
+        /// Skins (list in Spine 3.8+, map in older versions).
+        ///
+        #[serde(default)]
+        skins: SkinsData,
+        /// Animations by name.
+        ///
+        #[serde(default)]
+        animations: HashMap<String, AnimationData>,
+    }
+
+    /// Bone of Spine skeleton.
+    ///
+    #[derive(Deserialize)]
+    struct BoneData {
+        /// Name of the bone.
+        ///
+        name: String,
+        /// Name of parent bone.
+        ///
+        parent: Option<String>,
+        /// Horizontal translation.
+        ///
+        #[serde(default)]
+        x: f32,
+        /// Vertical translation.
+        ///
+        #[serde(default)]
+        y: f32,
+        /// Rotation in degrees.
+        ///
+        #[serde(default)]
+        rotation: f32,
+        /// Horizontal scale.
+        ///
+        #[serde(rename = "scaleX", default = "one")]
+        scale_x: f32,
+        /// Vertical scale.
+        ///
+        #[serde(rename = "scaleY", default = "one")]
+        scale_y: f32,
+    }
+
+    /// Slot of Spine skeleton.
+    ///
+    #[derive(Deserialize)]
+    struct SlotData {
+        /// Name of the slot.
+        ///
+        name: String,
+        /// Name of the bone of the slot.
+        ///
+        bone: String,
+        /// Name of attachment that is shown in setup pose.
+        ///
+        attachment: Option<String>,
+        /// Color of the slot in `RRGGBBAA` format.
+        ///
+        color: Option<String>,
+    }
+
+    /// Attachments of skin by slot name and attachment name.
+    ///
+    type Attachments = HashMap<String, HashMap<String, AttachmentData>>;
+
+    /// Skins of Spine skeleton.
+    ///
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum SkinsData {
+        /// Skins of Spine 3.8+.
+        ///
+        List(Vec<SkinData>),
+        /// Skins of older versions.
+        ///
+        Map(HashMap<String, Attachments>),
+    }
+    impl Default for SkinsData {
+        fn default() -> Self {
+            SkinsData::List(Vec::new())
+        }
+    }
+
+    /// Skin of Spine 3.8+.
+    ///
+    #[derive(Deserialize)]
+    struct SkinData {
+        /// Name of the skin.
+        ///
+        name: String,
+        /// Attachments of the skin.
+        ///
+        #[serde(default)]
+        attachments: Attachments,
+    }
+
+    /// Attachment of Spine skeleton (only region attachments are supported).
+    ///
+    #[derive(Deserialize)]
+    struct AttachmentData {
+        /// Type of attachment (region if it is missing).
+        ///
+        #[serde(rename = "type")]
+        kind: Option<String>,
+        /// Name of the image (name of attachment if it is missing).
+        ///
+        path: Option<String>,
+        /// Horizontal offset from the bone.
+        ///
+        #[serde(default)]
+        x: f32,
+        /// Vertical offset from the bone.
+        ///
+        #[serde(default)]
+        y: f32,
+        /// Rotation in degrees.
+        ///
+        #[serde(default)]
+        rotation: f32,
+        /// Horizontal scale.
+        ///
+        #[serde(rename = "scaleX", default = "one")]
+        scale_x: f32,
+        /// Vertical scale.
+        ///
+        #[serde(rename = "scaleY", default = "one")]
+        scale_y: f32,
+        /// Width of the image.
+        ///
+        #[serde(default)]
+        width: f32,
+        /// Height of the image.
+        ///
+        #[serde(default)]
+        height: f32,
+    }
+
+    /// Animation of Spine skeleton.
+    ///
+    #[derive(Deserialize)]
+    struct AnimationData {
+        /// Timelines of bones by bone name.
+        ///
+        #[serde(default)]
+        bones: HashMap<String, BoneTimelines>,
+    }
+
+    /// Timelines of one bone.
+    ///
+    #[derive(Deserialize)]
+    struct BoneTimelines {
+        /// Keys of rotation.
+        ///
+        #[serde(default)]
+        rotate: Vec<KeyData>,
+        /// Keys of translation.
+        ///
+        #[serde(default)]
+        translate: Vec<KeyData>,
+        /// Keys of scale.
+        ///
+        #[serde(default)]
+        scale: Vec<KeyData>,
+    }
+
+    /// Key of a timeline.
+    ///
+    #[derive(Deserialize)]
+    struct KeyData {
+        /// Time of the key in seconds.
+        ///
+        #[serde(default)]
+        time: f32,
+        /// Rotation in degrees (Spine 4.0+).
+        ///
+        value: Option<f32>,
+        /// Rotation in degrees (older versions).
+        ///
+        angle: Option<f32>,
+        /// Horizontal component.
+        ///
+        x: Option<f32>,
+        /// Vertical component.
+        ///
+        y: Option<f32>,
+        /// Curve of interpolation (string `"stepped"` or bezier parameters).
+        ///
+        curve: Option<serde_json::Value>,
+    }
+    impl KeyData {
+        /// Converts key to keyframe with given value.
+        ///
+        fn keyframe<T>(&self, value: T) -> Keyframe<T> {
+            Keyframe {
+                time: Duration::from_secs_f32(self.time.max(0.0)),
+                value,
+                interpolation: match self.curve.as_ref().and_then(serde_json::Value::as_str) {
+                    Some("stepped") => Interpolation::Stepped,
+                    _ => Interpolation::Linear,
+                },
+            }
+        }
+    }
+
+    /// Parses color in `RRGGBBAA` (or `RRGGBB`) format.
+    ///
+    fn parse_color(color: &str) -> Result<Color, GGError> {
+        let channel = |index: usize| -> Result<u8, GGError> {
+            color
+                .get(index * 2..index * 2 + 2)
+                .map_or(Ok(255), |channel| u8::from_str_radix(channel, 16))
+                .map_err(|_| GGError::Decode(format!("{color} is not a valid color")))
+        };
+        Ok(Color::from_rgba(
+            channel(0)?,
+            channel(1)?,
+            channel(2)?,
+            channel(3)?,
+        ))
+    }
+
+    impl SkeletonData {
+        /// Builds [`Skeleton`] from Spine data.
+        ///
+        pub(super) fn into_skeleton(self) -> Result<Skeleton, GGError> {
+            let mut skeleton: Skeleton = Skeleton::new();
+            for bone in &self.bones {
+                let _ = skeleton.add_bone(
+                    &bone.name,
+                    bone.parent.as_deref(),
+                    transform(bone.x, bone.y, bone.rotation, bone.scale_x, bone.scale_y),
+                )?;
+            }
+
+            let mut skins: HashMap<String, Attachments> = match self.skins {
+                SkinsData::List(skins) => skins
+                    .into_iter()
+                    .map(|skin| (skin.name, skin.attachments))
+                    .collect(),
+                SkinsData::Map(skins) => skins,
+            };
+            let attachments: Attachments = skins.remove("default").unwrap_or_default();
+            for slot in &self.slots {
+                let Some(name) = slot.attachment.as_deref() else {
+                    continue;
+                };
+                let Some(attachment) = attachments
+                    .get(&slot.name)
+                    .and_then(|attachments| attachments.get(name))
+                else {
+                    continue;
+                };
+                if attachment
+                    .kind
+                    .as_deref()
+                    .is_some_and(|kind| kind != "region")
+                {
+                    continue;
+                }
+                let bone: usize = skeleton.bone_index(&slot.bone).ok_or_else(|| {
+                    GGError::InvalidArgument(format!("Skeleton has no bone {}", slot.bone))
+                })?;
+                let mut part: SkinnedPart = SkinnedPart::new(
+                    bone,
+                    transform(
+                        attachment.x,
+                        attachment.y,
+                        attachment.rotation,
+                        attachment.scale_x,
+                        attachment.scale_y,
+                    ),
+                    (attachment.width, attachment.height),
+                    PathBuf::from(attachment.path.as_deref().unwrap_or(name)),
+                );
+                if let Some(color) = slot.color.as_deref() {
+                    part.color = parse_color(color)?;
+                }
+                skeleton.add_part(part)?;
+            }
+
+            for (name, animation) in self.animations {
+                let mut result: SkeletalAnimation = SkeletalAnimation::default();
+                for (bone, timelines) in animation.bones {
+                    let bone: usize = skeleton.bone_index(&bone).ok_or_else(|| {
+                        GGError::InvalidArgument(format!("Skeleton has no bone {bone}"))
+                    })?;
+                    let track: BoneTrack = BoneTrack {
+                        bone,
+                        translation: timelines
+                            .translate
+                            .iter()
+                            .map(|key| {
+                                key.keyframe(Vector2::from([
+                                    key.x.unwrap_or(0.0),
+                                    -key.y.unwrap_or(0.0),
+                                ]))
+                            })
+                            .collect(),
+                        rotation: timelines
+                            .rotate
+                            .iter()
+                            .map(|key| {
+                                key.keyframe(Angle::from_degrees(
+                                    -key.value.or(key.angle).unwrap_or(0.0),
+                                ))
+                            })
+                            .collect(),
+                        scale: timelines
+                            .scale
+                            .iter()
+                            .map(|key| {
+                                key.keyframe((
+                                    key.x.unwrap_or(1.0).abs(),
+                                    key.y.unwrap_or(1.0).abs(),
+                                ))
+                            })
+                            .collect(),
+                    };
+                    result.duration = track
+                        .translation
+                        .iter()
+                        .map(|keyframe| keyframe.time)
+                        .chain(track.rotation.iter().map(|keyframe| keyframe.time))
+                        .chain(track.scale.iter().map(|keyframe| keyframe.time))
+                        .fold(result.duration, Duration::max);
+                    result.tracks.push(track);
+                }
+                let _ = skeleton.add_animation(&name, result);
+            }
+            Ok(skeleton)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_skeletons, update_skeletons, SkeletalAnimator, Skeleton, SkinnedPart};
+    use crate::{
+        gamecore::{scenes::Scene, time::Time},
+        graphicscore::{
+            camera::Camera2D,
+            queue::{DrawKind, RenderQueue},
+        },
+        mathcore::{
+            floats::FloatOperations,
+            transforms::Transform2D,
+            vectors::{Point, Vector2},
+            Color,
+        },
+    };
+    use std::{path::PathBuf, rc::Rc, time::Duration};
+
+    const SPINE: &str = r#"{
+        "skeleton": { "spine": "3.8.99" },
+        "bones": [
+            { "name": "root" },
+            { "name": "arm", "parent": "root", "x": 10, "rotation": 90 },
+            { "name": "hand", "parent": "arm", "x": 5 }
+        ],
+        "slots": [
+            { "name": "arm", "bone": "arm", "attachment": "arm", "color": "ff000080" },
+            { "name": "hand", "bone": "hand", "attachment": "hand" },
+            { "name": "hitbox", "bone": "hand", "attachment": "hitbox" }
+        ],
+        "skins": {
+            "default": {
+                "arm": { "arm": { "path": "body/arm", "width": 4, "height": 2 } },
+                "hand": { "hand": { "x": 1, "width": 2, "height": 2 } },
+                "hitbox": { "hitbox": { "type": "boundingbox", "vertexCount": 0 } }
+            }
+        },
+        "animations": {
+            "swing": {
+                "bones": {
+                    "arm": {
+                        "rotate": [
+                            { "time": 0, "angle": 0 },
+                            { "time": 1, "angle": -90, "curve": "stepped" },
+                            { "time": 2, "angle": 0 }
+                        ]
+                    }
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn spine_import() {
+        let skeleton: Skeleton = Skeleton::from_spine_json(SPINE).expect("Skeleton is valid");
+        assert_eq!(skeleton.bone_index("hand"), Some(2));
+        assert_eq!(skeleton.parts().len(), 2);
+        assert_eq!(skeleton.parts()[0].texture, Some(PathBuf::from("body/arm")));
+        assert_eq!(skeleton.parts()[0].color, Color::from_rgba(255, 0, 0, 128));
+        assert_eq!(
+            skeleton.parts()[1].color,
+            Color::from_rgba(255, 255, 255, 255)
+        );
+        assert_eq!(
+            skeleton.animation("swing").map(|swing| swing.duration),
+            Some(Duration::from_secs(2))
+        );
+        assert!(
+            Skeleton::from_spine_json(r#"{ "bones": [{ "name": "a", "parent": "b" }] }"#).is_err()
+        );
+
+        let mut animator: SkeletalAnimator = SkeletalAnimator::new(Rc::new(skeleton));
+        let hand = |animator: &SkeletalAnimator| -> Point {
+            animator.world_poses()[2].translation.round_up_to(3)
+        };
+        // rotation of 90 degrees in y-up coordinates points arm up, which is negative y
+        assert_eq!(hand(&animator), Point::from([10.0, -5.0]));
+        assert!(animator.play("swing"));
+        animator.advance(Duration::from_millis(500));
+        assert_eq!(hand(&animator).round_up_to(2), Point::from([13.54, -3.54]));
+        animator.advance(Duration::from_millis(1000));
+        assert_eq!(hand(&animator), Point::from([15.0, 0.0]));
+        animator.is_looping = false;
+        animator.advance(Duration::from_secs(5));
+        assert!(animator.is_finished());
+        assert_eq!(animator.time(), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn render() {
+        let mut skeleton: Skeleton = Skeleton::new();
+        let body: usize = skeleton
+            .add_bone("body", None, Transform2D::identity())
+            .expect("Bone is new");
+        assert!(skeleton
+            .add_bone("body", None, Transform2D::identity())
+            .is_err());
+        for texture in ["atlas.png", "atlas.png", "eyes.png"] {
+            skeleton
+                .add_part(SkinnedPart::new(
+                    body,
+                    Transform2D::identity(),
+                    (2.0, 2.0),
+                    PathBuf::from(texture),
+                ))
+                .expect("Bone exists");
+        }
+        assert!(skeleton
+            .add_part(SkinnedPart::new(
+                5,
+                Transform2D::identity(),
+                (1.0, 1.0),
+                PathBuf::new()
+            ))
+            .is_err());
+
+        let mut scene: Scene = Scene::new();
+        let _ = scene
+            .resources_mut()
+            .insert_resource(Camera2D::new((100.0, 100.0)));
+        let _ = scene.spawn((
+            SkeletalAnimator::new(Rc::new(skeleton)),
+            Transform2D::from_translation(Vector2::from([10.0, 0.0])),
+        ));
+        let mut time: Time = Time::new();
+        time.update_with(Duration::from_secs(1));
+        let _ = scene.resources_mut().insert_resource(time);
+        update_skeletons(&mut scene);
+        render_skeletons(&mut scene);
+
+        let queue: &RenderQueue = scene
+            .resources()
+            .get_resource::<RenderQueue>()
+            .expect("Queue was inserted");
+        assert_eq!(queue.len(), 2);
+        let first: Option<(usize, Option<Point>)> =
+            queue
+                .commands()
+                .first()
+                .and_then(|command| match &command.kind {
+                    DrawKind::Geometry {
+                        vertices, indices, ..
+                    } => Some((
+                        indices.as_ref().map_or(0, Vec::len),
+                        vertices.first().map(|vertex| vertex.position),
+                    )),
+                    _ => None,
+                });
+        assert_eq!(first, Some((12, Some(Point::from([59.0, 49.0])))));
+    }
+}
@@ -0,0 +1,167 @@
+//! `graphicscore::software` submodule implements [`SoftwareCanvas`] - software presentation mode
+//! in which [`Image`]s are blitted directly to the surface of a window.
+//!
+//! Software presentation does not use renderer or textures at all, so it works on targets without
+//! hardware acceleration and is well suited for tools and for pixel-perfect emulator-style games,
+//! which compose whole frame on CPU anyway.
+//!
+
+use crate::{
+    datacore::images::{Image, ImageArea},
+    mathcore::Color,
+    utils::Window,
+    GGError,
+};
+use sdl2::{
+    surface::SurfaceRef as SdlSurfaceRef,
+    sys::{SDL_GetWindowSurface, SDL_Surface as SdlSurface, SDL_UpdateWindowSurface},
+    video::Window as SdlWindow,
+};
+use std::fmt;
+
+/// [`SoftwareCanvas`] struct presents [`Image`]s by blitting them to the surface of a [`Window`].
+///
+/// [`SoftwareCanvas`] is instantiated from [`Window`] by consuming it, so window cannot have
+/// [`WindowCanvas`](super::drawing::WindowCanvas) at the same time.
+///
+/// # Example
+/// ```rust, no_run
+/// # use ggengine::GGEngine;
+/// # use ggengine::graphicscore::software::SoftwareCanvas;
+/// # use ggengine::datacore::images::{Image, ImageArea, PixelFormat};
+/// # use ggengine::mathcore::Color;
+/// let engine: GGEngine = GGEngine::init().expect("Engine should be initialized");
+/// let mut canvas: SoftwareCanvas = SoftwareCanvas::from_window(
+///     engine.build_window("ggengine", 640, 480, Default::default()).expect("Window should be built"),
+/// );
+/// let frame: Image = Image::new(160, 120, PixelFormat::RGBA32);
+///
+/// canvas.clear(Color::from_rgba(0, 0, 0, 255)).expect("Window surface should be available");
+/// // every pixel of the frame becomes 4x4 pixels of the window
+/// canvas.blit_image_scaled(None, &frame, None).expect("Frame should be blitted");
+/// canvas.update().expect("Window surface should be updated");
+/// ```
+///
+pub struct SoftwareCanvas {
+    /// Underlying `sdl2` window.
+    ///
+    window: SdlWindow,
+}
+impl SoftwareCanvas {
+    /// Constructs [`SoftwareCanvas`] from the [`Window`] by consuming it (OS shell of window is not destroyed).
+    ///
+    pub fn from_window(window: Window) -> Self {
+        SoftwareCanvas {
+            window: window.destructure(),
+        }
+    }
+    /// Consumes [`SoftwareCanvas`] to get back [`Window`] instance from which it was created.
+    ///
+    pub fn into_window(self) -> Window {
+        Window::from_sdl_window(self.window)
+    }
+
+    /// Returns surface of the window.
+    ///
+    /// Surface is recreated by `sdl2` when window is resized, so it should not be stored.
+    ///
+    fn surface(&mut self) -> Result<&mut SdlSurfaceRef, GGError> {
+        // SAFETY: window is valid, and surface is owned by the window, which is borrowed for the lifetime of the reference.
+        let surface: *mut SdlSurface = unsafe { SDL_GetWindowSurface(self.window.raw()) };
+        if surface.is_null() {
+            return Err(GGError::Sdl(sdl2::get_error()));
+        }
+        // SAFETY: pointer was checked to be non-null.
+        Ok(unsafe { SdlSurfaceRef::from_ll_mut(surface) })
+    }
+
+    /// Returns size of the window surface in pixels (width and height).
+    ///
+    pub fn size(&mut self) -> Result<(u32, u32), GGError> {
+        self.surface().map(|surface| surface.size())
+    }
+    /// Fills the whole window surface with given color.
+    ///
+    pub fn clear(&mut self, color: Color) -> Result<(), GGError> {
+        let (r, g, b, a): (u8, u8, u8, u8) = color.to_rgba();
+        self.surface()?
+            .fill_rect(None, sdl2::pixels::Color::RGBA(r, g, b, a))
+            .map_err(GGError::Sdl)
+    }
+    /// Blits (copies) part of image to part of the window surface.
+    ///
+    /// `src_area` and `dst_area` work as in `Image::blit_to` function.
+    ///
+    /// # Errors
+    /// Error is returned if any [`ImageArea`] coordinate exceeds `i32::MAX / 2`,
+    /// if window surface is not available or if `sdl2` fails to blit image.
+    ///
+    pub fn blit_image(
+        &mut self,
+        dst_area: Option<ImageArea>,
+        image: &Image,
+        src_area: Option<ImageArea>,
+    ) -> Result<(), GGError> {
+        if src_area.is_some_and(|area| area.is_empty())
+            || dst_area.is_some_and(|area| area.is_empty())
+        {
+            return Ok(());
+        }
+        let _ = image
+            .get_sdl_surface()
+            .blit(
+                src_area.map(ImageArea::try_to_rect).transpose()?,
+                self.surface()?,
+                dst_area.map(ImageArea::try_to_rect).transpose()?,
+            )
+            .map_err(GGError::Sdl)?;
+        Ok(())
+    }
+    /// Blits part of image to part of the window surface, stretching it to fill destination area.
+    ///
+    /// If `dst_area` is `None`, image is stretched to fill the whole window surface.
+    /// Scaling uses nearest pixels, so integer scale factors keep pixel art crisp.
+    ///
+    /// # Errors
+    /// See `SoftwareCanvas::blit_image`.
+    ///
+    pub fn blit_image_scaled(
+        &mut self,
+        dst_area: Option<ImageArea>,
+        image: &Image,
+        src_area: Option<ImageArea>,
+    ) -> Result<(), GGError> {
+        if src_area.is_some_and(|area| area.is_empty())
+            || dst_area.is_some_and(|area| area.is_empty())
+        {
+            return Ok(());
+        }
+        let _ = image
+            .get_sdl_surface()
+            .blit_scaled(
+                src_area.map(ImageArea::try_to_rect).transpose()?,
+                self.surface()?,
+                dst_area.map(ImageArea::try_to_rect).transpose()?,
+            )
+            .map_err(GGError::Sdl)?;
+        Ok(())
+    }
+
+    /// Copies window surface to the screen.
+    ///
+    /// Just as with `WindowCanvas::update`, nothing is shown on the window until this function is called.
+    ///
+    pub fn update(&mut self) -> Result<(), GGError> {
+        // SAFETY: window is valid.
+        if unsafe { SDL_UpdateWindowSurface(self.window.raw()) } == 0 {
+            Ok(())
+        } else {
+            Err(GGError::Sdl(sdl2::get_error()))
+        }
+    }
+}
+impl fmt::Debug for SoftwareCanvas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SoftwareCanvas")
+    }
+}
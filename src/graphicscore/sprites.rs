@@ -0,0 +1,179 @@
+//! `graphicscore::sprites` submodule implements [`Sprite`] component and [`render_sprites`] system,
+//! which records sprites of a scene into its [`RenderQueue`].
+//!
+//! Sprites which bounding boxes do not intersect view of [`Camera2D`] are culled before any
+//! command is recorded, so huge worlds do not submit every sprite every frame.
+//! Number of drawn and culled sprites is written to [`RenderStats`] resource.
+//!
+//! Sprites of game objects that are marked with [`ScreenSpace`] ignore camera and are placed
+//! in window coordinates (see [`camera`](super::camera) submodule).
+//!
+
+use crate::{
+    gamecore::{
+        components::{Component, Resource},
+        scenes::Scene,
+    },
+    graphicscore::{
+        camera::{Camera2D, SafeArea, ScreenSpace},
+        queue::{DrawKind, RenderQueue},
+    },
+    mathcore::{
+        shapes::{Aabb, Rect, Shape},
+        transforms::Transform2D,
+        vectors::Point,
+        Size,
+    },
+};
+use std::path::PathBuf;
+
+/// [`Sprite`] struct is a component that draws texture of [`TextureCache`](super::cache::TextureCache)
+/// asset at the [`Transform2D`] of a game object.
+///
+#[derive(Clone, Debug)]
+pub struct Sprite {
+    /// Name of the asset in texture cache.
+    ///
+    pub texture: PathBuf,
+    /// Size of sprite in world units (width and height) before scaling of transform is applied.
+    ///
+    pub size: (f32, f32),
+    /// Area of texture that is drawn (whole texture if `None`).
+    ///
+    pub src_area: Option<Rect>,
+    /// Layer of sprite (see [`DrawCommand`](super::queue::DrawCommand)).
+    ///
+    pub layer: i32,
+}
+impl Component for Sprite {}
+
+/// [`RenderStats`] struct is a resource that holds statistics of the last run of render systems.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    /// Number of items that were recorded into render queue.
+    ///
+    pub drawn: usize,
+    /// Number of items that were culled.
+    ///
+    pub culled: usize,
+}
+impl Resource for RenderStats {}
+
+/// Records all sprites of the scene which have [`Transform2D`] into [`RenderQueue`] resource,
+/// culling those that are not visible by [`Camera2D`] resource.
+///
+/// Both resources are inserted if they are missing (camera is inserted with zero viewport,
+/// which makes everything culled, so camera should be inserted beforehand).
+/// Sprites are recorded in screen coordinates with depth equal to `y` coordinate of their translation.
+/// [`RenderStats`] resource is reset by this system, so other render systems that count
+/// into it (e.g. [`render_tilemaps`](super::tilemaps::render_tilemaps)) should be run after it.
+///
+/// Sprites of game objects with [`ScreenSpace`] component are positioned relative to their anchor
+/// and are not affected by camera (they are culled only if they are out of the viewport).
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::scenes::Scene;
+/// # use ggengine::graphicscore::{camera::Camera2D, queue::RenderQueue, sprites::{render_sprites, RenderStats, Sprite}};
+/// # use ggengine::mathcore::{transforms::Transform2D, vectors::Vector2};
+/// # use std::path::PathBuf;
+/// let sprite = |x: f32| (
+///     Sprite { texture: PathBuf::from("tree.png"), size: (32.0, 32.0), src_area: None, layer: 0 },
+///     Transform2D::from_translation(Vector2::from([x, 0.0])),
+/// );
+/// let mut scene: Scene = Scene::new();
+/// let _ = scene.resources_mut().insert_resource(Camera2D::new((800.0, 600.0)));
+/// let _ = scene.spawn(sprite(0.0));
+/// let _ = scene.spawn(sprite(10_000.0));
+///
+/// render_sprites(&mut scene);
+/// assert_eq!(
+///     scene.resources().get_resource::<RenderStats>(),
+///     Some(&RenderStats { drawn: 1, culled: 1 })
+/// );
+/// assert_eq!(scene.resources().get_resource::<RenderQueue>().map(RenderQueue::len), Some(1));
+/// ```
+///
+pub fn render_sprites(scene: &mut Scene) {
+    let camera: Camera2D = *scene
+        .resources_mut()
+        .get_resource_or_insert_with(|| Camera2D::new((0.0, 0.0)));
+    let view: Aabb = camera.view_rect();
+    let screen: Aabb = Aabb::new(
+        Point::zero(),
+        Point::from([camera.viewport.0, camera.viewport.1]),
+    );
+    let safe_area: SafeArea = scene
+        .resources()
+        .get_resource::<SafeArea>()
+        .copied()
+        .unwrap_or_default();
+
+    let mut stats: RenderStats = RenderStats::default();
+    let mut commands: Vec<(i32, f32, DrawKind)> = Vec::new();
+    for (gameobject_id, sprite) in scene.components().components::<Sprite>() {
+        let Some(transform) = scene
+            .components()
+            .get_component::<Transform2D>(gameobject_id)
+        else {
+            continue;
+        };
+        let (width, height): (f32, f32) = (
+            sprite.size.0 * transform.scale.0.get(),
+            sprite.size.1 * transform.scale.1.get(),
+        );
+        let dst_area: Rect = match scene
+            .components()
+            .get_component::<ScreenSpace>(gameobject_id)
+        {
+            Some(screen_space) => Rect::from_origin(
+                screen_space.screen_position(transform.translation, &camera, &safe_area),
+                transform.rotation,
+                Size::from_value(width),
+                Size::from_value(height),
+            ),
+            None => {
+                let world_area: Rect = Rect::from_origin(
+                    transform.translation,
+                    transform.rotation,
+                    Size::from_value(width),
+                    Size::from_value(height),
+                );
+                if !world_area.aabb().intersects(view) {
+                    stats.culled += 1;
+                    continue;
+                }
+                Rect::from_origin(
+                    camera.world_to_screen(transform.translation),
+                    transform.rotation - camera.rotation,
+                    Size::from_value(width * camera.zoom),
+                    Size::from_value(height * camera.zoom),
+                )
+            }
+        };
+        if !dst_area.aabb().intersects(screen) {
+            stats.culled += 1;
+            continue;
+        }
+        stats.drawn += 1;
+
+        commands.push((
+            sprite.layer,
+            transform.translation.y,
+            DrawKind::Sprite {
+                texture: sprite.texture.clone(),
+                dst_area,
+                src_area: sprite.src_area,
+            },
+        ));
+    }
+
+    let queue: &mut RenderQueue = scene
+        .resources_mut()
+        .get_resource_or_insert_with(RenderQueue::new);
+    for (layer, depth, kind) in commands {
+        queue.push(layer, depth, kind);
+    }
+    let _ = scene.resources_mut().insert_resource(stats);
+}
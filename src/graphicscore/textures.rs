@@ -17,7 +17,12 @@
 //! `Texture`s, you would want to use `Image` and then convert it to the actual `Texture` when rendering.
 //!
 
-use crate::datacore::images::{Image, PixelFormat};
+use crate::{
+    datacore::images::{Image, PixelFormat},
+    graphicscore::drawing::CanvasId,
+    log::{self, LogTarget},
+    GGError,
+};
 use sdl2::{
     image::LoadTexture,
     render::{
@@ -27,11 +32,7 @@ use sdl2::{
     surface::SurfaceContext,
     video::WindowContext,
 };
-use std::{
-    fmt,
-    io::{Error, ErrorKind},
-    path::Path,
-};
+use std::{fmt, path::Path};
 
 /// [`AccessType`] enum lists variants how texture can be accessed by the renderer.
 ///
@@ -111,45 +112,39 @@ impl<'a> InnerTextureCreator<'a> {
         height: u32,
         format: Option<PixelFormat>,
         access_type: AccessType,
-    ) -> Texture {
-        Texture {
-            texture: match self {
-                InnerTextureCreator::ForImage(texture_creator) => texture_creator
-                    .create_texture(
-                        format.map(|pixel_format| pixel_format.to_sdl_pixel_format_enum()),
-                        access_type.to_sdl_texture_access(),
-                        width,
-                        height,
-                    )
-                    .expect("Texture creation should not fail"),
-                InnerTextureCreator::ForWindow(texture_creator) => texture_creator
-                    .create_texture(
-                        format.map(|pixel_format| pixel_format.to_sdl_pixel_format_enum()),
-                        access_type.to_sdl_texture_access(),
-                        width,
-                        height,
-                    )
-                    .expect("Texture creation should not fail"),
-            },
+    ) -> Result<RenderTexture, GGError> {
+        match self {
+            InnerTextureCreator::ForImage(texture_creator) => texture_creator.create_texture(
+                format.map(|pixel_format| pixel_format.to_sdl_pixel_format_enum()),
+                access_type.to_sdl_texture_access(),
+                width,
+                height,
+            ),
+            InnerTextureCreator::ForWindow(texture_creator) => texture_creator.create_texture(
+                format.map(|pixel_format| pixel_format.to_sdl_pixel_format_enum()),
+                access_type.to_sdl_texture_access(),
+                width,
+                height,
+            ),
         }
+        .map_err(|error| GGError::Sdl(error.to_string()))
     }
     /// Creates [`Texture`] from the [`Image`].
     ///
-    fn create_texture_from_image(&self, image: &Image) -> Texture {
-        Texture {
-            texture: match self {
-                InnerTextureCreator::ForImage(texture_creator) => texture_creator
-                    .create_texture_from_surface(image.get_sdl_surface())
-                    .expect("Texture creation should not fail"),
-                InnerTextureCreator::ForWindow(texture_creator) => texture_creator
-                    .create_texture_from_surface(image.get_sdl_surface())
-                    .expect("Texture creation should not fail"),
-            },
+    fn create_texture_from_image(&self, image: &Image) -> Result<RenderTexture, GGError> {
+        match self {
+            InnerTextureCreator::ForImage(texture_creator) => {
+                texture_creator.create_texture_from_surface(image.get_sdl_surface())
+            }
+            InnerTextureCreator::ForWindow(texture_creator) => {
+                texture_creator.create_texture_from_surface(image.get_sdl_surface())
+            }
         }
+        .map_err(|error| GGError::Sdl(error.to_string()))
     }
     /// Creates [`Texture`] from bytes of supported format ('.png', '.jpg', but not raw buffer).
     ///
-    fn create_texture_from_bytes(&self, bytes: Box<[u8]>) -> Result<Texture, Error> {
+    fn create_texture_from_bytes(&self, bytes: Box<[u8]>) -> Result<RenderTexture, GGError> {
         match self {
             InnerTextureCreator::ForImage(texture_creator) => {
                 texture_creator.load_texture_bytes(&bytes)
@@ -158,12 +153,14 @@ impl<'a> InnerTextureCreator<'a> {
                 texture_creator.load_texture_bytes(&bytes)
             }
         }
-        .map(|texture| Texture { texture })
-        .map_err(|message| Error::new(ErrorKind::InvalidData, message))
+        .map_err(GGError::Sdl)
     }
     /// Creates [`Texture`] from the file.
     ///
-    fn create_texture_from_file(&self, filename: impl AsRef<Path>) -> Result<Texture, Error> {
+    fn create_texture_from_file(
+        &self,
+        filename: impl AsRef<Path>,
+    ) -> Result<RenderTexture, GGError> {
         match self {
             InnerTextureCreator::ForImage(texture_creator) => {
                 texture_creator.load_texture(filename)
@@ -172,8 +169,7 @@ impl<'a> InnerTextureCreator<'a> {
                 texture_creator.load_texture(filename)
             }
         }
-        .map(|texture| Texture { texture })
-        .map_err(|message| Error::new(ErrorKind::InvalidInput, message))
+        .map_err(GGError::Sdl)
     }
 }
 impl<'a> fmt::Debug for InnerTextureCreator<'a> {
@@ -190,11 +186,17 @@ impl<'a> fmt::Debug for InnerTextureCreator<'a> {
 /// You cannot manually instantiate [`TextureCreator`], you have to get it from the other structs.
 /// It is encouraged to read docs to find out how.
 ///
+/// Texture creator is branded with id of the canvas that created it, and so are its textures
+/// (see [`CanvasId`]).
+///
 #[derive(Debug)]
 pub struct TextureCreator<'a> {
     /// Underlying `sdl` texture creator.
     ///
     texture_creator: InnerTextureCreator<'a>,
+    /// Id of the canvas that created texture creator.
+    ///
+    canvas_id: CanvasId,
 }
 impl<'a> TextureCreator<'a> {
     // All functions that are providing gate between `ggengine` and `sdl2` extend their API to `crate` visibility.
@@ -202,9 +204,11 @@ impl<'a> TextureCreator<'a> {
     ///
     pub(crate) fn from_sdl_texture_creator_image(
         texture_creator: RenderTextureCreator<SurfaceContext<'a>>,
+        canvas_id: CanvasId,
     ) -> TextureCreator<'a> {
         TextureCreator {
             texture_creator: InnerTextureCreator::ForImage(texture_creator),
+            canvas_id,
         }
     }
     // All functions that are providing gate between `ggengine` and `sdl2` extend their API to `crate` visibility.
@@ -212,12 +216,20 @@ impl<'a> TextureCreator<'a> {
     ///
     pub(crate) fn from_sdl_texture_creator_window(
         texture_creator: RenderTextureCreator<WindowContext>,
+        canvas_id: CanvasId,
     ) -> TextureCreator<'a> {
         TextureCreator {
             texture_creator: InnerTextureCreator::ForWindow(texture_creator),
+            canvas_id,
         }
     }
 
+    /// Returns id of the canvas that created texture creator.
+    ///
+    pub fn canvas_id(&self) -> CanvasId {
+        self.canvas_id
+    }
+
     /// Returns the best pixel format for [`TextureCreator`] or `None`, if the format is not recognised by `ggengine`.
     ///
     /// Even if the format is not recognised, it is still usable by `ggengine`.
@@ -236,26 +248,57 @@ impl<'a> TextureCreator<'a> {
         height: u32,
         format: Option<PixelFormat>,
         access_type: AccessType,
-    ) -> Texture {
+    ) -> Result<Texture, GGError> {
         self.texture_creator
             .create_texture(width, height, format, access_type)
+            .map(|texture| Texture {
+                texture,
+                canvas_id: self.canvas_id,
+            })
+            .inspect_err(log_creation_error)
     }
     /// Creates [`Texture`] from the [`Image`].
     ///
-    pub fn create_texture_from_image(&self, image: &Image) -> Texture {
-        self.texture_creator.create_texture_from_image(image)
+    pub fn create_texture_from_image(&self, image: &Image) -> Result<Texture, GGError> {
+        self.texture_creator
+            .create_texture_from_image(image)
+            .map(|texture| Texture {
+                texture,
+                canvas_id: self.canvas_id,
+            })
+            .inspect_err(log_creation_error)
     }
     /// Creates [`Texture`] from bytes of supported format ('.png', '.jpg', but not raw buffer).
     ///
-    pub fn create_texture_from_bytes(&self, bytes: Box<[u8]>) -> Result<Texture, Error> {
-        self.texture_creator.create_texture_from_bytes(bytes)
+    pub fn create_texture_from_bytes(&self, bytes: Box<[u8]>) -> Result<Texture, GGError> {
+        self.texture_creator
+            .create_texture_from_bytes(bytes)
+            .map(|texture| Texture {
+                texture,
+                canvas_id: self.canvas_id,
+            })
+            .inspect_err(log_creation_error)
     }
     /// Creates [`Texture`] from the file.
     ///
-    pub fn create_texture_from_file(&self, filename: impl AsRef<Path>) -> Result<Texture, Error> {
-        self.texture_creator.create_texture_from_file(filename)
+    pub fn create_texture_from_file(&self, filename: impl AsRef<Path>) -> Result<Texture, GGError> {
+        self.texture_creator
+            .create_texture_from_file(filename)
+            .map(|texture| Texture {
+                texture,
+                canvas_id: self.canvas_id,
+            })
+            .inspect_err(log_creation_error)
     }
 }
+/// Logs failure of [`Texture`] creation.
+///
+fn log_creation_error(error: &GGError) {
+    log::error(
+        LogTarget::GraphicsCore,
+        format_args!("Texture creation failed: {error}"),
+    );
+}
 
 /// [`Texture`] struct is a hardware image that is used in rendering.
 ///
@@ -266,17 +309,18 @@ impl<'a> TextureCreator<'a> {
 /// # use ggengine::graphicscore::textures::{TextureCreator, Texture, AccessType};
 /// # use ggengine::datacore::images::PixelFormat;
 /// let texture_creator: TextureCreator = todo!("obtain the texture creator");
-/// let texture: Texture = texture_creator.create_texture(
-///     300, 300,
-///     Some(PixelFormat::RGBA8888),
-///     AccessType::Static,
-/// );
+/// let texture: Texture = texture_creator
+///     .create_texture(300, 300, Some(PixelFormat::RGBA8888), AccessType::Static)
+///     .expect("Texture should be created");
 /// ```
 ///
 pub struct Texture<'a> {
     /// Underlying `sdl` texture.
     ///
     texture: RenderTexture<'a>,
+    /// Id of the canvas in which texture can be used.
+    ///
+    canvas_id: CanvasId,
 }
 impl<'a> Texture<'a> {
     // All functions that are providing gate between `ggengine` and `sdl2` extend their API to `crate` visibility.
@@ -292,6 +336,14 @@ impl<'a> Texture<'a> {
         &mut self.texture
     }
 
+    /// Returns id of the canvas in which texture can be used.
+    ///
+    /// Blitting texture to another canvas returns an error.
+    ///
+    pub fn canvas_id(&self) -> CanvasId {
+        self.canvas_id
+    }
+
     /// Accesses inner data of the [`Texture`].
     ///
     /// Texture's access type must be `AccessType::Streaming`, otherwise it is a no-op and this function
@@ -366,3 +418,210 @@ impl<'a> fmt::Debug for Texture<'a> {
         f.debug_struct("Texture").finish()
     }
 }
+
+/// [`TexturePrecision`] enum lists variants of how precisely colors of texture are stored.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TexturePrecision {
+    /// Colors are stored as they are stored in the image.
+    ///
+    #[default]
+    Full,
+    /// Colors are stored in 16 bit formats (`PixelFormat::RGB565` for opaque images and
+    /// `PixelFormat::RGBA4444` for images with alpha channel), which halves memory of 32 bit images.
+    ///
+    /// This is well suited for large backgrounds, where loss of precision is hardly visible.
+    /// Renderers that do not support 16 bit textures will store them in the closest supported format.
+    ///
+    Reduced,
+}
+impl TexturePrecision {
+    /// Returns format in which image with given format should be stored,
+    /// or `None` if image should not be converted.
+    ///
+    fn pixel_format(self, image_format: Option<PixelFormat>) -> Option<PixelFormat> {
+        match self {
+            TexturePrecision::Full => None,
+            TexturePrecision::Reduced => Some(
+                if image_format.is_some_and(|pixel_format| pixel_format.supports_alpha()) {
+                    PixelFormat::RGBA4444
+                } else {
+                    PixelFormat::RGB565
+                },
+            ),
+        }
+    }
+}
+/// [`TextureOptions`] struct lists options that are applied when creating [`MipmappedTexture`] from the [`Image`].
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TextureOptions {
+    /// Whether downscaled mip chain should be generated (every level is twice smaller than the previous one,
+    /// down to 1x1 texture).
+    ///
+    pub mipmaps: bool,
+    /// Precision of colors of texture.
+    ///
+    pub precision: TexturePrecision,
+}
+
+/// Returns image that is twice smaller than given image (in RGBA32 format),
+/// each pixel of which is an average of 2x2 pixels of given image.
+///
+fn halve_image(image: &Image) -> Image<'static> {
+    let (width, height): (u32, u32) = image.size();
+    let mut result: Image<'static> =
+        Image::new((width / 2).max(1), (height / 2).max(1), PixelFormat::RGBA32);
+    let (source_pitch, result_pitch): (usize, usize) =
+        (image.pitch() as usize, result.pitch() as usize);
+    let (result_width, result_height): (u32, u32) = result.size();
+    image.access_data(|source| {
+        result.access_data_mut(|data| {
+            for y in 0..result_height {
+                for x in 0..result_width {
+                    let (x0, y0): (usize, usize) = (2 * x as usize, 2 * y as usize);
+                    let (x1, y1): (usize, usize) = (
+                        (x0 + 1).min(width as usize - 1),
+                        (y0 + 1).min(height as usize - 1),
+                    );
+                    let offset: usize = x as usize * 4 + y as usize * result_pitch;
+                    // components are averaged independently, so their order does not matter
+                    for component in 0..4 {
+                        let sum: u32 = [(x0, y0), (x1, y0), (x0, y1), (x1, y1)]
+                            .iter()
+                            .map(|&(x, y)| u32::from(source[x * 4 + y * source_pitch + component]))
+                            .sum();
+                        data[offset + component] = ((sum + 2) / 4) as u8;
+                    }
+                }
+            }
+        })
+    });
+    result
+}
+
+/// Returns index of mip level that should be used when texture is drawn with given scale.
+///
+fn mip_level(scale: f32, levels: usize) -> usize {
+    if !(scale > 0.0 && scale < 1.0) {
+        return 0;
+    }
+    ((1.0 / scale).log2().floor() as usize).min(levels - 1)
+}
+
+/// [`MipmappedTexture`] struct holds chain of [`Texture`]s, each of which is twice smaller than the previous one.
+///
+/// Drawing zoomed out textures from appropriate mip level reduces shimmering
+/// (since each texel of a level is an average of texels of the previous one) and
+/// amount of texture memory that is read.
+///
+/// # Example
+/// ```rust, no_run
+/// # use ggengine::GGEngine;
+/// # use ggengine::utils::Window;
+/// # use ggengine::graphicscore::drawing::{Canvas, WindowCanvas};
+/// # use ggengine::graphicscore::textures::{
+/// #     TextureCreator, MipmappedTexture, TextureOptions, TexturePrecision,
+/// # };
+/// # use ggengine::datacore::{assets::FromFile, images::Image};
+/// let engine: GGEngine = GGEngine::init().expect("Engine should be initialized");
+/// let window: Window = engine
+///     .build_window("ggengine", 1000, 1000, Default::default())
+///     .expect("Window should be built");
+/// let canvas: WindowCanvas = WindowCanvas::from_window(window, true).expect("Canvas should be created");
+/// let texture_creator: TextureCreator = canvas.texture_creator();
+/// let background: Image = Image::from_file("background.png").expect("Filename should be correct");
+/// let texture: MipmappedTexture = texture_creator
+///     .create_texture_with_options(
+///         &background,
+///         TextureOptions { mipmaps: true, precision: TexturePrecision::Reduced },
+///     )
+///     .expect("Texture should be created");
+/// // camera is zoomed out 3 times, so texture that is twice smaller is used
+/// assert_eq!(texture.level_for_scale(1.0 / 3.0), 1);
+/// ```
+///
+#[derive(Debug)]
+pub struct MipmappedTexture<'a> {
+    /// Mip levels, starting from the texture of the original size.
+    ///
+    levels: Vec<Texture<'a>>,
+}
+impl<'a> MipmappedTexture<'a> {
+    /// Returns number of mip levels (always at least 1).
+    ///
+    pub fn level_count(&self) -> usize {
+        self.levels.len()
+    }
+    /// Returns texture of given mip level, if it is present.
+    ///
+    pub fn level(&self, level: usize) -> Option<&Texture<'a>> {
+        self.levels.get(level)
+    }
+    /// Returns texture of the original size.
+    ///
+    pub fn base(&self) -> &Texture<'a> {
+        &self.levels[0]
+    }
+
+    /// Returns index of the mip level that should be used when texture is drawn with given scale
+    /// (e.g. zoom of camera).
+    ///
+    /// Level `n` is used for scales in `(1 / 2^(n + 1); 1 / 2^n]`.
+    ///
+    pub fn level_for_scale(&self, scale: f32) -> usize {
+        mip_level(scale, self.levels.len())
+    }
+    /// Returns texture of the mip level that should be used when texture is drawn with given scale
+    /// (e.g. zoom of camera).
+    ///
+    pub fn texture_for_scale(&self, scale: f32) -> &Texture<'a> {
+        &self.levels[self.level_for_scale(scale)]
+    }
+}
+impl<'a> TextureCreator<'a> {
+    /// Creates [`MipmappedTexture`] from the [`Image`] with given options.
+    ///
+    /// If mipmaps are not requested, the only level of texture is the image itself.
+    ///
+    pub fn create_texture_with_options(
+        &self,
+        image: &Image,
+        options: TextureOptions,
+    ) -> Result<MipmappedTexture<'_>, GGError> {
+        let pixel_format: Option<PixelFormat> =
+            options.precision.pixel_format(image.pixel_format());
+        let create = |level: &Image| match pixel_format {
+            Some(pixel_format) => self.create_texture_from_image(&level.convert(pixel_format)),
+            None => self.create_texture_from_image(level),
+        };
+
+        let mut levels: Vec<Texture> = vec![create(image)?];
+        if options.mipmaps {
+            let mut level: Image = image.convert(PixelFormat::RGBA32);
+            while level.width() > 1 || level.height() > 1 {
+                level = halve_image(&level);
+                levels.push(create(&level)?);
+            }
+        }
+        Ok(MipmappedTexture { levels })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mip_level;
+
+    #[test]
+    fn mip_level_selection() {
+        assert_eq!(mip_level(2.0, 5), 0);
+        assert_eq!(mip_level(1.0, 5), 0);
+        assert_eq!(mip_level(0.75, 5), 0);
+        assert_eq!(mip_level(0.5, 5), 1);
+        assert_eq!(mip_level(0.3, 5), 1);
+        assert_eq!(mip_level(0.25, 5), 2);
+        assert_eq!(mip_level(0.001, 5), 4);
+        assert_eq!(mip_level(0.0, 5), 0);
+        assert_eq!(mip_level(f32::NAN, 5), 0);
+    }
+}
@@ -0,0 +1,258 @@
+//! `graphicscore::tilemaps` submodule implements [`Tilemap`] component and [`render_tilemaps`] system,
+//! which records visible tiles of a scene into its [`RenderQueue`].
+//!
+//! Only tiles that are in view of [`Camera2D`] are visited, so size of a tilemap does not affect
+//! the cost of rendering it.
+//!
+
+use crate::{
+    gamecore::{components::Component, scenes::Scene},
+    graphicscore::{
+        camera::Camera2D,
+        queue::{DrawKind, RenderQueue},
+        sprites::RenderStats,
+    },
+    mathcore::{
+        shapes::{Aabb, Rect},
+        transforms::Transform2D,
+        vectors::Point,
+        Size,
+    },
+};
+use std::path::PathBuf;
+
+/// [`Tilemap`] struct is a component that draws grid of tiles from tileset of
+/// [`TextureCache`](super::cache::TextureCache) asset.
+///
+/// Tile `(0, 0)` is placed with its minimal corner at translation of [`Transform2D`] of a game object;
+/// `x` grows along the `x` axis and `y` grows along the `y` axis.
+/// Scale of transform is applied to the tiles, but its rotation is ignored.
+///
+/// Tiles are indices into tileset, which are counted row by row from its top-left tile.
+///
+#[derive(Clone, Debug)]
+pub struct Tilemap {
+    /// Name of the tileset asset in texture cache.
+    ///
+    pub texture: PathBuf,
+    /// Size of tile in world units (width and height) before scaling of transform is applied.
+    ///
+    pub tile_size: (f32, f32),
+    /// Size of tile in tileset in pixels (width and height).
+    ///
+    pub tileset_tile_size: (u32, u32),
+    /// Number of tiles in a row of tileset.
+    ///
+    pub tileset_columns: u32,
+    /// Layer of tilemap (see [`DrawCommand`](super::queue::DrawCommand)).
+    ///
+    pub layer: i32,
+
+    /// Number of columns of the map.
+    ///
+    width: usize,
+    /// Number of rows of the map.
+    ///
+    height: usize,
+    /// Tiles of the map, row by row.
+    ///
+    tiles: Vec<Option<u32>>,
+    /// Number of non-empty tiles.
+    ///
+    tile_count: usize,
+}
+impl Tilemap {
+    /// Constructs empty tilemap with given number of columns and rows.
+    ///
+    pub fn new(
+        texture: PathBuf,
+        tile_size: (f32, f32),
+        tileset_tile_size: (u32, u32),
+        tileset_columns: u32,
+        (width, height): (usize, usize),
+        layer: i32,
+    ) -> Self {
+        Tilemap {
+            texture,
+            tile_size,
+            tileset_tile_size,
+            tileset_columns: tileset_columns.max(1),
+            layer,
+
+            width,
+            height,
+            tiles: vec![None; width * height],
+            tile_count: 0,
+        }
+    }
+
+    /// Returns number of columns and rows of the map.
+    ///
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+    /// Returns number of non-empty tiles.
+    ///
+    pub fn tile_count(&self) -> usize {
+        self.tile_count
+    }
+
+    /// Returns tile at given position (`None` if tile is empty or position is out of the map).
+    ///
+    pub fn tile(&self, x: usize, y: usize) -> Option<u32> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.tiles[y * self.width + x]
+    }
+    /// Sets tile at given position, returning whether position is in the map.
+    ///
+    pub fn set_tile(&mut self, x: usize, y: usize, tile: Option<u32>) -> bool {
+        if x >= self.width || y >= self.height {
+            return false;
+        }
+        let old: Option<u32> = std::mem::replace(&mut self.tiles[y * self.width + x], tile);
+        self.tile_count =
+            self.tile_count - usize::from(old.is_some()) + usize::from(tile.is_some());
+        true
+    }
+
+    /// Returns area of tileset that is drawn for given tile.
+    ///
+    fn src_area(&self, tile: u32) -> Rect {
+        let (column, row): (u32, u32) = (tile % self.tileset_columns, tile / self.tileset_columns);
+        let (width, height): (f32, f32) = (
+            self.tileset_tile_size.0 as f32,
+            self.tileset_tile_size.1 as f32,
+        );
+        let min: Point = Point::from([column as f32 * width, row as f32 * height]);
+        Rect::from_aabb(Aabb::new(min, Point::from([min.x + width, min.y + height])))
+    }
+}
+impl Component for Tilemap {}
+
+/// Returns range of tiles along one axis which cells overlap `[view_min, view_max]`.
+///
+fn visible_range(
+    origin: f32,
+    tile: f32,
+    count: usize,
+    view_min: f32,
+    view_max: f32,
+) -> (usize, usize) {
+    if tile.is_nan() || tile <= 0.0 {
+        return (0, 0);
+    }
+    let first: f32 = ((view_min - origin) / tile).floor().max(0.0);
+    let last: f32 = ((view_max - origin) / tile).ceil();
+    let last: usize = if last > 0.0 {
+        (last as usize).min(count)
+    } else {
+        0
+    };
+    ((first as usize).min(last), last)
+}
+
+/// Records visible tiles of all tilemaps of the scene which have [`Transform2D`] into [`RenderQueue`] resource.
+///
+/// Only tiles which cells intersect view of [`Camera2D`] resource are visited;
+/// both resources are inserted if they are missing.
+/// Tiles are recorded in screen coordinates with depth equal to `y` coordinate of their center.
+///
+/// Drawn and culled tiles are added to [`RenderStats`] resource, so this system should be run
+/// after [`render_sprites`](super::sprites::render_sprites), which resets it.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::scenes::Scene;
+/// # use ggengine::graphicscore::{camera::Camera2D, queue::RenderQueue, sprites::RenderStats, tilemaps::{render_tilemaps, Tilemap}};
+/// # use ggengine::mathcore::{transforms::Transform2D, vectors::Point};
+/// # use std::path::PathBuf;
+/// let mut tilemap: Tilemap =
+///     Tilemap::new(PathBuf::from("tiles.png"), (32.0, 32.0), (16, 16), 8, (1000, 1000), 0);
+/// for x in 0..1000 {
+///     let _ = tilemap.set_tile(x, 0, Some(1));
+/// }
+///
+/// let mut scene: Scene = Scene::new();
+/// let mut camera: Camera2D = Camera2D::new((320.0, 32.0));
+/// camera.position = Point::from([160.0, 16.0]);
+/// let _ = scene.resources_mut().insert_resource(camera);
+/// let _ = scene.spawn((tilemap, Transform2D::default()));
+///
+/// render_tilemaps(&mut scene);
+/// assert_eq!(
+///     scene.resources().get_resource::<RenderStats>(),
+///     Some(&RenderStats { drawn: 10, culled: 990 })
+/// );
+/// assert_eq!(scene.resources().get_resource::<RenderQueue>().map(RenderQueue::len), Some(10));
+/// ```
+///
+pub fn render_tilemaps(scene: &mut Scene) {
+    let camera: Camera2D = *scene
+        .resources_mut()
+        .get_resource_or_insert_with(|| Camera2D::new((0.0, 0.0)));
+    let view: Aabb = camera.view_rect();
+
+    let mut stats: RenderStats = RenderStats::default();
+    let mut commands: Vec<(i32, f32, DrawKind)> = Vec::new();
+    for (gameobject_id, tilemap) in scene.components().components::<Tilemap>() {
+        let Some(transform) = scene
+            .components()
+            .get_component::<Transform2D>(gameobject_id)
+        else {
+            continue;
+        };
+        let origin: Point = transform.translation;
+        let (width, height): (f32, f32) = (
+            tilemap.tile_size.0 * transform.scale.0.get(),
+            tilemap.tile_size.1 * transform.scale.1.get(),
+        );
+        let (min_x, max_x): (usize, usize) =
+            visible_range(origin.x, width, tilemap.width, view.min.x, view.max.x);
+        let (min_y, max_y): (usize, usize) =
+            visible_range(origin.y, height, tilemap.height, view.min.y, view.max.y);
+
+        let mut drawn: usize = 0;
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                let Some(tile) = tilemap.tile(x, y) else {
+                    continue;
+                };
+                let center: Point = Point::from([
+                    origin.x + (x as f32 + 0.5) * width,
+                    origin.y + (y as f32 + 0.5) * height,
+                ]);
+                commands.push((
+                    tilemap.layer,
+                    center.y,
+                    DrawKind::Sprite {
+                        texture: tilemap.texture.clone(),
+                        dst_area: Rect::from_origin(
+                            camera.world_to_screen(center),
+                            -camera.rotation,
+                            Size::from_value(width * camera.zoom),
+                            Size::from_value(height * camera.zoom),
+                        ),
+                        src_area: Some(tilemap.src_area(tile)),
+                    },
+                ));
+                drawn += 1;
+            }
+        }
+        stats.drawn += drawn;
+        stats.culled += tilemap.tile_count - drawn;
+    }
+
+    let queue: &mut RenderQueue = scene
+        .resources_mut()
+        .get_resource_or_insert_with(RenderQueue::new);
+    for (layer, depth, kind) in commands {
+        queue.push(layer, depth, kind);
+    }
+    let total: &mut RenderStats = scene
+        .resources_mut()
+        .get_resource_or_insert_with(RenderStats::default);
+    total.drawn += stats.drawn;
+    total.culled += stats.culled;
+}
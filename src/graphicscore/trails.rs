@@ -0,0 +1,402 @@
+//! `graphicscore::trails` submodule implements [`Trail`] component, which leaves fading strip
+//! behind moving game object (projectiles, swords, skating lines).
+//!
+//! [`update_trails`] system records positions of game objects into their trails and
+//! [`render_trails`] system records trails as (optionally textured) triangle strips into [`RenderQueue`].
+//!
+//! Width and color of a trail are sampled from piecewise linear curves by age of its points:
+//! first key is used for the newest point and last key is used for points that are about to expire.
+//!
+
+use crate::{
+    gamecore::{
+        components::Component,
+        identifiers::GameObjectId,
+        scenes::Scene,
+        time::{Clock, Time},
+    },
+    graphicscore::{
+        camera::Camera2D,
+        drawing::GeometryVertex,
+        queue::{DrawKind, RenderQueue},
+    },
+    mathcore::{
+        transforms::Transform2D,
+        vectors::{Point, Vector2},
+        Color,
+    },
+};
+use std::{collections::VecDeque, path::PathBuf};
+
+/// Samples piecewise linear curve, which keys are evenly distributed on `[0; 1]`, at given position.
+///
+/// Returns `None` if there are no keys.
+///
+fn sample<T: Copy>(keys: &[T], t: f32, lerp: impl Fn(T, T, f32) -> T) -> Option<T> {
+    let last: usize = keys.len().checked_sub(1)?;
+    let position: f32 = t.clamp(0.0, 1.0) * last as f32;
+    let index: usize = (position.floor() as usize).min(last);
+    Some(match keys.get(index + 1) {
+        Some(&next) => lerp(keys[index], next, position - index as f32),
+        None => keys[index],
+    })
+}
+
+/// [`TrailPoint`] struct is a point of [`Trail`].
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TrailPoint {
+    /// Position of point in world coordinates.
+    ///
+    pub position: Point,
+    /// Time (in seconds) since point was recorded.
+    ///
+    pub age: f32,
+}
+
+/// [`Trail`] struct is a component that records positions of game object and draws them as a strip.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::graphicscore::{camera::Camera2D, drawing::GeometryVertex, trails::Trail};
+/// # use ggengine::mathcore::{vectors::Point, Color};
+/// let mut trail: Trail = Trail::new(16, 0.5);
+/// trail.widths = vec![8.0, 0.0];
+/// trail.colors = vec![Color::from_rgba(255, 200, 0, 255), Color::from_rgba(255, 0, 0, 0)];
+///
+/// trail.record(Point::from([0.0, 0.0]));
+/// trail.advance(0.25);
+/// trail.record(Point::from([10.0, 0.0]));
+///
+/// let (vertices, indices): (Vec<GeometryVertex>, Vec<i32>) = trail.geometry(&Camera2D::new((0.0, 0.0)));
+/// assert_eq!((vertices.len(), indices.len()), (4, 6));
+/// assert_eq!(vertices[0].position, Point::from([10.0, 4.0]));
+/// assert_eq!(vertices[2].position, Point::from([0.0, 2.0]));
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct Trail {
+    /// Maximal number of points in the trail.
+    ///
+    pub max_points: usize,
+    /// Time (in seconds) after which points of the trail expire.
+    ///
+    pub lifetime: f32,
+    /// Minimal distance that game object should move for new point to be recorded
+    /// (otherwise the newest point is moved).
+    ///
+    pub min_distance: f32,
+    /// Curve of width of the trail (in world units) by age of points.
+    ///
+    pub widths: Vec<f32>,
+    /// Gradient of color of the trail by age of points.
+    ///
+    pub colors: Vec<Color>,
+    /// Name of the asset in texture cache that textures the strip
+    /// (it is stretched along the trail, `u` coordinate goes from the newest point to the oldest).
+    ///
+    pub texture: Option<PathBuf>,
+    /// Layer of the trail (see [`DrawCommand`](super::queue::DrawCommand)).
+    ///
+    pub layer: i32,
+    /// Multiplier of time by which points of the trail are aged.
+    ///
+    pub speed: f32,
+    /// Clock by which points of the trail are aged by `Trail::advance_by`.
+    ///
+    pub clock: Clock,
+
+    /// Points of the trail (the newest is the first).
+    ///
+    points: VecDeque<TrailPoint>,
+}
+impl Trail {
+    /// Constructs empty white trail with width of 1 unit, minimal distance of 1 unit,
+    /// given maximal number of points and lifetime.
+    ///
+    pub fn new(max_points: usize, lifetime: f32) -> Self {
+        Trail {
+            max_points,
+            lifetime,
+            min_distance: 1.0,
+            widths: vec![1.0],
+            colors: vec![Color::from_rgba(255, 255, 255, 255)],
+            texture: None,
+            layer: 0,
+            speed: 1.0,
+            clock: Clock::Scaled,
+
+            points: VecDeque::with_capacity(max_points),
+        }
+    }
+
+    /// Returns points of the trail (the newest is the first).
+    ///
+    pub fn points(&self) -> impl Iterator<Item = &TrailPoint> {
+        self.points.iter()
+    }
+    /// Removes all points of the trail (e.g. when projectile is teleported).
+    ///
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+
+    /// Records new position of game object.
+    ///
+    pub fn record(&mut self, position: Point) {
+        let has_tail: bool = self.points.len() > 1;
+        match self.points.front_mut() {
+            Some(newest)
+                if has_tail && (position - newest.position).magnitude() < self.min_distance =>
+            {
+                newest.position = position;
+            }
+            _ => {
+                self.points.push_front(TrailPoint { position, age: 0.0 });
+                self.points.truncate(self.max_points);
+            }
+        }
+    }
+    /// Ages points of the trail by given time (in seconds, not scaled by speed of trail)
+    /// and removes expired ones.
+    ///
+    pub fn advance(&mut self, delta: f32) {
+        for point in self.points.iter_mut() {
+            point.age += delta;
+        }
+        while self
+            .points
+            .back()
+            .is_some_and(|point| point.age >= self.lifetime)
+        {
+            let _ = self.points.pop_back();
+        }
+    }
+    /// Ages points of the trail by delta of [`Time`] that is measured by clock of the trail
+    /// (scaled by speed of trail).
+    ///
+    pub fn advance_by(&mut self, time: &Time) {
+        self.advance(time.delta_by(self.clock).as_secs_f32() * self.speed.max(0.0));
+    }
+
+    /// Returns width and color of the trail at the point of given age.
+    ///
+    fn style(&self, age: f32) -> (f32, Color) {
+        let t: f32 = if self.lifetime > 0.0 {
+            age / self.lifetime
+        } else {
+            1.0
+        };
+        (
+            sample(&self.widths, t, |a, b, t| a + (b - a) * t).unwrap_or(0.0),
+            sample(&self.colors, t, Color::lerp).unwrap_or(Color::from_rgba(0, 0, 0, 0)),
+        )
+    }
+    /// Builds triangle strip of the trail in screen coordinates of given camera.
+    ///
+    /// Every point produces two vertices (on both sides of the trail), every pair of consecutive
+    /// points produces two triangles. Trails with less than two points produce no geometry.
+    ///
+    pub fn geometry(&self, camera: &Camera2D) -> (Vec<GeometryVertex>, Vec<i32>) {
+        let count: usize = self.points.len();
+        if count < 2 {
+            return (Vec::new(), Vec::new());
+        }
+        let positions: Vec<Point> = self
+            .points
+            .iter()
+            .map(|point| camera.world_to_screen(point.position))
+            .collect();
+
+        let mut vertices: Vec<GeometryVertex> = Vec::with_capacity(count * 2);
+        for (index, point) in self.points.iter().enumerate() {
+            let direction: Vector2 =
+                positions[index.saturating_sub(1)] - positions[(index + 1).min(count - 1)];
+            let normal: Vector2 = direction
+                .try_normalize()
+                .map_or(Vector2::zero(), Vector2::perpendicular);
+            let (width, color): (f32, Color) = self.style(point.age);
+            let offset: Vector2 = normal * (width * camera.zoom / 2.0);
+            let u: f32 = index as f32 / (count - 1) as f32;
+            vertices.push(GeometryVertex {
+                position: positions[index] + offset,
+                color,
+                uv: Vector2::from([u, 0.0]),
+            });
+            vertices.push(GeometryVertex {
+                position: positions[index] - offset,
+                color,
+                uv: Vector2::from([u, 1.0]),
+            });
+        }
+        let indices: Vec<i32> = (0..count as i32 - 1)
+            .flat_map(|index| {
+                let first: i32 = index * 2;
+                [first, first + 1, first + 2, first + 1, first + 3, first + 2]
+            })
+            .collect();
+        (vertices, indices)
+    }
+}
+impl Component for Trail {}
+
+/// Ages points of all trails of the scene by [`Time`] resource and records translations of
+/// [`Transform2D`] of their game objects.
+///
+/// Trails are not aged if there is no [`Time`] resource.
+///
+pub fn update_trails(scene: &mut Scene) {
+    let time: Time = scene
+        .resources()
+        .get_resource::<Time>()
+        .copied()
+        .unwrap_or_default();
+    let positions: Vec<(GameObjectId, Option<Point>)> = scene
+        .components()
+        .components::<Trail>()
+        .map(|(gameobject_id, _)| {
+            (
+                gameobject_id,
+                scene
+                    .components()
+                    .get_component::<Transform2D>(gameobject_id)
+                    .map(|transform| transform.translation),
+            )
+        })
+        .collect();
+    for (gameobject_id, position) in positions {
+        let Some(trail) = scene
+            .components_mut()
+            .get_component_mut::<Trail>(gameobject_id)
+        else {
+            continue;
+        };
+        trail.advance_by(&time);
+        if let Some(position) = position {
+            trail.record(position);
+        }
+    }
+}
+
+/// Records all trails of the scene into [`RenderQueue`] resource, using [`Camera2D`] resource
+/// to convert them into screen coordinates.
+///
+/// Both resources are inserted if they are missing (see [`render_sprites`](super::sprites::render_sprites)).
+/// Trails are recorded with depth equal to `y` coordinate of their newest point.
+///
+pub fn render_trails(scene: &mut Scene) {
+    let camera: Camera2D = *scene
+        .resources_mut()
+        .get_resource_or_insert_with(|| Camera2D::new((0.0, 0.0)));
+    let commands: Vec<(i32, f32, DrawKind)> = scene
+        .components()
+        .components::<Trail>()
+        .filter_map(|(_, trail)| {
+            let (vertices, indices): (Vec<GeometryVertex>, Vec<i32>) = trail.geometry(&camera);
+            if vertices.is_empty() {
+                return None;
+            }
+            Some((
+                trail.layer,
+                trail.points.front().map_or(0.0, |point| point.position.y),
+                DrawKind::Geometry {
+                    vertices,
+                    indices: Some(indices),
+                    texture: trail.texture.clone(),
+                },
+            ))
+        })
+        .collect();
+
+    let queue: &mut RenderQueue = scene
+        .resources_mut()
+        .get_resource_or_insert_with(RenderQueue::new);
+    for (layer, depth, kind) in commands {
+        queue.push(layer, depth, kind);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sample, update_trails, Trail};
+    use crate::{
+        gamecore::{identifiers::GameObjectId, scenes::Scene, time::Time},
+        mathcore::{
+            transforms::Transform2D,
+            vectors::{Point, Vector2},
+            Color,
+        },
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn trail_recording_and_styles() {
+        let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+        assert_eq!(sample(&[], 0.5, lerp), None);
+        assert_eq!(sample(&[2.0], 0.5, lerp), Some(2.0));
+        assert_eq!(sample(&[0.0, 10.0, 30.0], 0.75, lerp), Some(20.0));
+        assert_eq!(sample(&[0.0, 10.0, 30.0], 2.0, lerp), Some(30.0));
+
+        let mut trail: Trail = Trail::new(3, 1.0);
+        trail.widths = vec![4.0, 0.0];
+        trail.colors = vec![
+            Color::from_rgba(255, 255, 255, 255),
+            Color::from_rgba(255, 255, 255, 0),
+        ];
+        for x in 0..5 {
+            trail.record(Point::from([x as f32 * 10.0, 0.0]));
+            trail.advance(0.3);
+        }
+        let positions: Vec<f32> = trail.points().map(|point| point.position.x).collect();
+        assert_eq!(positions, [40.0, 30.0, 20.0]);
+        assert_eq!(
+            trail.style(0.5),
+            (2.0, Color::from_rgba(255, 255, 255, 128))
+        );
+
+        trail.record(Point::from([40.5, 0.0]));
+        assert_eq!(trail.points().count(), 3);
+        trail.advance(0.5);
+        assert_eq!(trail.points().count(), 1);
+
+        let mut scene: Scene = Scene::new();
+        let mut trail: Trail = Trail::new(8, 1.0);
+        trail.speed = 2.0;
+        let gameobject_id: GameObjectId = scene.spawn((trail, Transform2D::default()));
+        let mut time: Time = Time::new();
+        time.is_paused = true;
+        time.update_with(Duration::from_secs(1));
+        let _ = scene.resources_mut().insert_resource(time);
+        update_trails(&mut scene);
+        update_trails(&mut scene);
+        let ages = |scene: &Scene| -> Vec<f32> {
+            scene
+                .components()
+                .get_component::<Trail>(gameobject_id)
+                .expect("Game object has trail")
+                .points()
+                .map(|point| point.age)
+                .collect()
+        };
+        assert_eq!(ages(&scene), [0.0, 0.0]);
+
+        scene
+            .resources_mut()
+            .get_resource_mut::<Time>()
+            .expect("Time was inserted")
+            .is_paused = false;
+        scene
+            .resources_mut()
+            .get_resource_mut::<Time>()
+            .expect("Time was inserted")
+            .update_with(Duration::from_millis(250));
+        if let Some(transform) = scene
+            .components_mut()
+            .get_component_mut::<Transform2D>(gameobject_id)
+        {
+            transform.translation = Vector2::from([10.0, 0.0]);
+        }
+        update_trails(&mut scene);
+        assert_eq!(ages(&scene), [0.0, 0.5, 0.5]);
+    }
+}
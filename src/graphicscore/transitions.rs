@@ -0,0 +1,289 @@
+//! `graphicscore::transitions` submodule implements [`TransitionRenderer`] - struct that draws
+//! [`Transition`]s of [`SceneManager`](crate::gamecore::scenes::SceneManager) over rendered frames.
+//!
+//! Fades are drawn as colored overlay, crossfades and wipes are drawn by blitting captured frame
+//! of the old scene over the new one, and pixelation is done by downscaling captured frame
+//! and stretching it back over the canvas.
+//!
+
+use crate::{
+    datacore::images::{Image, PixelFormat},
+    gamecore::scenes::{Transition, TransitionState, WipeDirection},
+    graphicscore::{
+        drawing::{Canvas, GeometryVertex, WindowCanvas},
+        textures::{Texture, TextureCreator},
+        Blendable, BlendingType, ColorModulatable,
+    },
+    mathcore::{
+        shapes::{Aabb, Rect},
+        vectors::{Point, Vector2},
+        Color,
+    },
+    GGError,
+};
+
+/// Size (in pixels) of blocks of the most pixelated frame of [`Transition::Pixelate`].
+///
+const MAX_PIXELATION: u32 = 32;
+
+/// Returns how strongly effects that peak on the middle of transition are applied (from 0.0 to 1.0).
+///
+fn intensity(progress: f32) -> f32 {
+    1.0 - (2.0 * progress.clamp(0.0, 1.0) - 1.0).abs()
+}
+/// Returns alpha of the overlay of fade transitions.
+///
+fn fade_alpha(progress: f32) -> u8 {
+    (intensity(progress) * 255.0).round() as u8
+}
+/// Returns size of blocks of pixelated frame.
+///
+fn pixelation(progress: f32) -> u32 {
+    1 + (intensity(progress) * (MAX_PIXELATION - 1) as f32).round() as u32
+}
+/// Returns area of the canvas that is still covered by old scene during wipe.
+///
+fn wipe_area(direction: WipeDirection, progress: f32, (width, height): (f32, f32)) -> Aabb {
+    let progress: f32 = progress.clamp(0.0, 1.0);
+    let (min, max): ([f32; 2], [f32; 2]) = match direction {
+        WipeDirection::LeftToRight => ([progress * width, 0.0], [width, height]),
+        WipeDirection::RightToLeft => ([0.0, 0.0], [(1.0 - progress) * width, height]),
+        WipeDirection::TopToBottom => ([0.0, progress * height], [width, height]),
+        WipeDirection::BottomToTop => ([0.0, 0.0], [width, (1.0 - progress) * height]),
+    };
+    Aabb::new(Point::from(min), Point::from(max))
+}
+/// Averages colors of square blocks of tightly packed `RGBA32` pixels.
+///
+/// Returns averaged pixels (tightly packed) and their width and height.
+///
+fn average_blocks(pixels: &[u8], width: u32, height: u32, block: u32) -> (Vec<u8>, u32, u32) {
+    let (blocks_width, blocks_height): (u32, u32) = (width.div_ceil(block), height.div_ceil(block));
+    let mut averaged: Vec<u8> = Vec::with_capacity((blocks_width * blocks_height * 4) as usize);
+    for block_y in 0..blocks_height {
+        for block_x in 0..blocks_width {
+            let mut sums: [u32; 4] = [0; 4];
+            let mut count: u32 = 0;
+            for y in block_y * block..((block_y + 1) * block).min(height) {
+                for x in block_x * block..((block_x + 1) * block).min(width) {
+                    let offset: usize = ((y * width + x) * 4) as usize;
+                    for (sum, &value) in sums.iter_mut().zip(&pixels[offset..offset + 4]) {
+                        *sum += u32::from(value);
+                    }
+                    count += 1;
+                }
+            }
+            averaged.extend(sums.map(|sum| ((sum + count / 2) / count) as u8));
+        }
+    }
+    (averaged, blocks_width, blocks_height)
+}
+
+/// [`TransitionRenderer`] struct draws transitions of scene manager on the [`WindowCanvas`].
+///
+/// `render` should be called every frame after the current scene was drawn and before canvas is updated,
+/// so that renderer could capture frames of the old scene.
+///
+/// # Example
+/// ```rust, no_run
+/// # use ggengine::GGEngine;
+/// # use ggengine::utils::Window;
+/// # use ggengine::gamecore::scenes::{Scene, SceneManager, Transition};
+/// # use ggengine::graphicscore::{drawing::WindowCanvas, transitions::TransitionRenderer};
+/// # use std::time::Duration;
+/// let engine: GGEngine = GGEngine::init().expect("Engine should be initialized");
+/// let window: Window = engine
+///     .build_window("ggengine", 1000, 1000, Default::default())
+///     .expect("Window should be built");
+/// let mut canvas: WindowCanvas =
+///     WindowCanvas::from_window(window, true).expect("Canvas should be created");
+///
+/// let mut manager: SceneManager = SceneManager::new(Scene::new());
+/// let mut renderer: TransitionRenderer = TransitionRenderer::new();
+/// manager.replace_with_transition(Scene::new(), Transition::Fade(0.5));
+/// while manager.is_transitioning() {
+///     let _ = manager.update(Duration::from_millis(16));
+///     // drawing of `manager.scene()`
+///     renderer.render(&mut canvas, manager.transition()).expect("Transition should be drawn");
+///     canvas.update();
+/// }
+/// ```
+///
+#[derive(Debug, Default)]
+pub struct TransitionRenderer {
+    /// Captured frame of the old scene.
+    ///
+    captured: Option<Image<'static>>,
+}
+impl TransitionRenderer {
+    /// Initializes [`TransitionRenderer`] without captured frame.
+    ///
+    pub fn new() -> Self {
+        TransitionRenderer { captured: None }
+    }
+
+    /// Draws transition with given state over the canvas.
+    ///
+    /// If there is no transition, captured frame is dropped and nothing is drawn.
+    ///
+    pub fn render(
+        &mut self,
+        canvas: &mut WindowCanvas,
+        state: Option<TransitionState>,
+    ) -> Result<(), GGError> {
+        let Some(state) = state else {
+            self.captured = None;
+            return Ok(());
+        };
+        match state.transition {
+            Transition::Fade(_) => draw_overlay(
+                canvas,
+                Color::from_rgba(0, 0, 0, fade_alpha(state.progress)),
+            ),
+            Transition::FadeToColor(color, _) => {
+                let alpha: u16 = u16::from(color.a) * u16::from(fade_alpha(state.progress));
+                draw_overlay(
+                    canvas,
+                    Color {
+                        a: ((alpha + 127) / 255) as u8,
+                        ..color
+                    },
+                )
+            }
+            Transition::Crossfade(_) | Transition::Wipe(..) if !state.swapped => {
+                self.captured = Some(canvas.capture_frame()?);
+                Ok(())
+            }
+            Transition::Crossfade(_) => {
+                let Some(captured) = &self.captured else {
+                    return Ok(());
+                };
+                let texture_creator: TextureCreator = canvas.texture_creator();
+                let mut texture: Texture = texture_creator.create_texture_from_image(captured)?;
+                texture.set_blend_mode(BlendingType::Alpha);
+                texture.set_color_modulation(Color::from_rgba(
+                    255,
+                    255,
+                    255,
+                    ((1.0 - state.progress.clamp(0.0, 1.0)) * 255.0).round() as u8,
+                ));
+                canvas.blit_from_texture(None, &texture, None)
+            }
+            Transition::Wipe(direction, _) => {
+                let Some(captured) = &self.captured else {
+                    return Ok(());
+                };
+                let (width, height): (u32, u32) = captured.size();
+                let area: Aabb =
+                    wipe_area(direction, state.progress, (width as f32, height as f32));
+                if area.width() < 1.0 || area.height() < 1.0 {
+                    return Ok(());
+                }
+                let texture_creator: TextureCreator = canvas.texture_creator();
+                let texture: Texture = texture_creator.create_texture_from_image(captured)?;
+                let area: Rect = Rect::from_aabb(area);
+                canvas.blit_from_texture(Some(area), &texture, Some(area))
+            }
+            Transition::Pixelate(_) => {
+                let block: u32 = pixelation(state.progress);
+                if block == 1 {
+                    return Ok(());
+                }
+                let frame: Image = canvas.capture_frame()?;
+                let (width, height): (u32, u32) = frame.size();
+                let pitch: usize = frame.pitch() as usize;
+                let row_size: usize = width as usize * 4;
+                let pixels: Vec<u8> = frame.access_data(|data| {
+                    data.chunks(pitch)
+                        .take(height as usize)
+                        .flat_map(|row| &row[..row_size])
+                        .copied()
+                        .collect()
+                });
+                let (averaged, blocks_width, blocks_height): (Vec<u8>, u32, u32) =
+                    average_blocks(&pixels, width, height, block);
+
+                let mut pixelated: Image =
+                    Image::new(blocks_width, blocks_height, PixelFormat::RGBA32);
+                let pitch: usize = pixelated.pitch() as usize;
+                let row_size: usize = blocks_width as usize * 4;
+                pixelated.access_data_mut(|data| {
+                    for (row, pixels) in averaged.chunks_exact(row_size).enumerate() {
+                        data[row * pitch..row * pitch + row_size].copy_from_slice(pixels);
+                    }
+                });
+                let texture_creator: TextureCreator = canvas.texture_creator();
+                let texture: Texture = texture_creator.create_texture_from_image(&pixelated)?;
+                canvas.blit_from_texture(None, &texture, None)
+            }
+        }
+    }
+}
+
+/// Fills the whole canvas with given color, blending it with drawn frame.
+///
+fn draw_overlay(canvas: &mut WindowCanvas, color: Color) -> Result<(), GGError> {
+    if color.a == 0 {
+        return Ok(());
+    }
+    let (width, height): (u32, u32) = canvas.output_size()?;
+    let vertex = |x: u32, y: u32| GeometryVertex {
+        position: Point::from([x as f32, y as f32]),
+        color,
+        uv: Vector2::zero(),
+    };
+    let vertices: [GeometryVertex; 4] = [
+        vertex(0, 0),
+        vertex(width, 0),
+        vertex(width, height),
+        vertex(0, height),
+    ];
+
+    let blend_mode: BlendingType = canvas.blend_mode();
+    canvas.set_blend_mode(BlendingType::Alpha);
+    let result: Result<(), GGError> =
+        canvas.draw_geometry(&vertices, Some(&[0, 1, 2, 0, 2, 3]), None);
+    canvas.set_blend_mode(blend_mode);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{average_blocks, fade_alpha, pixelation, wipe_area, MAX_PIXELATION};
+    use crate::{
+        gamecore::scenes::WipeDirection,
+        mathcore::{shapes::Aabb, vectors::Point},
+    };
+
+    #[test]
+    fn transition_effects() {
+        assert_eq!(fade_alpha(0.0), 0);
+        assert_eq!(fade_alpha(0.25), 128);
+        assert_eq!(fade_alpha(0.5), 255);
+        assert_eq!(fade_alpha(1.0), 0);
+        assert_eq!(pixelation(0.0), 1);
+        assert_eq!(pixelation(0.5), MAX_PIXELATION);
+
+        assert_eq!(
+            wipe_area(WipeDirection::LeftToRight, 0.25, (800.0, 600.0)),
+            Aabb::new(Point::from([200.0, 0.0]), Point::from([800.0, 600.0]))
+        );
+        assert_eq!(
+            wipe_area(WipeDirection::BottomToTop, 0.25, (800.0, 600.0)),
+            Aabb::new(Point::from([0.0, 0.0]), Point::from([800.0, 450.0]))
+        );
+
+        #[rustfmt::skip]
+        let pixels: [u8; 36] = [
+            0, 0, 0, 255,     10, 20, 30, 255,  100, 100, 100, 255,
+            20, 40, 60, 255,  30, 60, 90, 255,  200, 200, 200, 255,
+            1, 2, 3, 4,       1, 2, 3, 4,       5, 5, 5, 5,
+        ];
+        let (averaged, width, height): (Vec<u8>, u32, u32) = average_blocks(&pixels, 3, 3, 2);
+        assert_eq!((width, height), (2, 2));
+        assert_eq!(
+            averaged,
+            [15, 30, 45, 255, 150, 150, 150, 255, 1, 2, 3, 4, 5, 5, 5, 5]
+        );
+    }
+}
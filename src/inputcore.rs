@@ -0,0 +1,10 @@
+//! `ggengine::inputcore` module is a core that implements handling of input devices
+//! (gamepads, mouse and keyboard) for the engine.
+//!
+
+// submodules and public re-exports
+pub mod actions;
+pub mod events;
+pub mod gamepads;
+pub mod mouse;
+pub mod players;
@@ -0,0 +1,445 @@
+//! `inputcore::actions` submodule implements [`Actions`] - resource that maps input to named actions
+//! and axes, so gameplay systems do not depend on specific keys or devices.
+//!
+//! Mapping also implements accessibility options ([`InputAccessibility`]): actions can be toggled
+//! instead of held and repeated while held, and axes can have dead-zones and response curves.
+//! Those options are stored in [`EngineSettings`], so they persist between runs of the game.
+//!
+
+use crate::{datacore::settings::EngineSettings, gamecore::components::Resource};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, time::Duration};
+
+/// [`RepeatRate`] struct describes how often held action is triggered again.
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RepeatRate {
+    /// Delay after press before the first repeat.
+    ///
+    pub delay: Duration,
+    /// Interval between repeats (it cannot be shorter than one millisecond).
+    ///
+    pub interval: Duration,
+}
+impl RepeatRate {
+    /// Returns number of repeats that happened during given time of holding after press.
+    ///
+    fn repeats(&self, held: Duration) -> u128 {
+        held.checked_sub(self.delay).map_or(0, |repeating| {
+            1 + repeating.as_millis() / self.interval.as_millis().max(1)
+        })
+    }
+}
+
+/// [`ActionOptions`] struct holds accessibility options of an action.
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[serde(default)]
+pub struct ActionOptions {
+    /// Whether press of the action toggles it instead of activating it while input is held.
+    ///
+    pub toggle: bool,
+    /// Rate at which held action is triggered again (`None` disables repeats).
+    ///
+    pub repeat: Option<RepeatRate>,
+}
+
+/// [`ResponseCurve`] enum lists curves that map magnitude of an axis after dead-zone.
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Default, PartialEq)]
+pub enum ResponseCurve {
+    /// Value is unchanged.
+    ///
+    #[default]
+    Linear,
+    /// Value is squared (precise small movements).
+    ///
+    Quadratic,
+    /// Value is cubed (even more precise small movements).
+    ///
+    Cubic,
+    /// Value is raised to given power.
+    ///
+    Power(f32),
+}
+impl ResponseCurve {
+    /// Maps magnitude (from 0.0 to 1.0).
+    ///
+    pub fn apply(&self, magnitude: f32) -> f32 {
+        match self {
+            ResponseCurve::Linear => magnitude,
+            ResponseCurve::Quadratic => magnitude.powi(2),
+            ResponseCurve::Cubic => magnitude.powi(3),
+            ResponseCurve::Power(power) => magnitude.powf(power.max(0.0)),
+        }
+    }
+}
+
+/// [`AxisOptions`] struct holds accessibility options of an axis.
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct AxisOptions {
+    /// Magnitude under which axis is considered to be at rest (from 0.0 to 1.0).
+    ///
+    pub dead_zone: f32,
+    /// Curve that maps magnitude after dead-zone.
+    ///
+    pub curve: ResponseCurve,
+    /// Multiplier of value after curve.
+    ///
+    pub sensitivity: f32,
+    /// Whether axis is inverted.
+    ///
+    pub inverted: bool,
+}
+impl AxisOptions {
+    /// Maps raw value of an axis (from -1.0 to 1.0).
+    ///
+    /// Magnitude outside of dead-zone is rescaled to start from zero, so there is no jump at its edge.
+    ///
+    pub fn apply(&self, value: f32) -> f32 {
+        let dead_zone: f32 = self.dead_zone.clamp(0.0, 0.99);
+        let magnitude: f32 = ((value.abs().min(1.0) - dead_zone) / (1.0 - dead_zone)).max(0.0);
+        let sign: f32 = if self.inverted { -1.0 } else { 1.0 };
+        sign * value.signum() * self.curve.apply(magnitude) * self.sensitivity
+    }
+}
+impl Default for AxisOptions {
+    /// Returns linear axis with a small dead-zone.
+    ///
+    fn default() -> Self {
+        AxisOptions {
+            dead_zone: 0.1,
+            curve: ResponseCurve::Linear,
+            sensitivity: 1.0,
+            inverted: false,
+        }
+    }
+}
+
+/// [`InputAccessibility`] struct holds accessibility options of actions and axes by their names.
+///
+/// Actions and axes that are not listed use default options.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct InputAccessibility {
+    /// Options of actions.
+    ///
+    pub actions: BTreeMap<String, ActionOptions>,
+    /// Options of axes.
+    ///
+    pub axes: BTreeMap<String, AxisOptions>,
+}
+impl InputAccessibility {
+    /// Returns options of the action.
+    ///
+    pub fn action(&self, action: &str) -> ActionOptions {
+        self.actions.get(action).copied().unwrap_or_default()
+    }
+    /// Returns options of the axis.
+    ///
+    pub fn axis(&self, axis: &str) -> AxisOptions {
+        self.axes.get(axis).copied().unwrap_or_default()
+    }
+}
+
+/// [`ActionState`] struct holds state of one action.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+struct ActionState {
+    /// Number of inputs that hold the action.
+    ///
+    held: u32,
+    /// Whether action was held during previous frame.
+    ///
+    was_held: bool,
+    /// For how long action is held.
+    ///
+    held_for: Duration,
+
+    /// Whether action is active.
+    ///
+    active: bool,
+    /// Whether action was activated during the current frame.
+    ///
+    just_activated: bool,
+    /// Whether action was deactivated during the current frame.
+    ///
+    just_deactivated: bool,
+    /// Whether action was pressed or repeated during the current frame.
+    ///
+    triggered: bool,
+}
+
+/// [`Actions`] struct is a resource that maps input to actions and axes.
+///
+/// Input is fed by [`InputPump`](super::events::InputPump) (for bound keys) or manually
+/// (with `Actions::press`, `Actions::release` and `Actions::set_axis`),
+/// after which `Actions::update` should be called once per frame.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::inputcore::actions::{ActionOptions, Actions, AxisOptions, ResponseCurve};
+/// # use std::time::Duration;
+/// let mut actions: Actions = Actions::new();
+/// let _ = actions.accessibility.actions.insert(
+///     String::from("crouch"),
+///     ActionOptions { toggle: true, repeat: None },
+/// );
+/// let _ = actions.accessibility.axes.insert(
+///     String::from("move_x"),
+///     AxisOptions { dead_zone: 0.2, curve: ResponseCurve::Quadratic, ..AxisOptions::default() },
+/// );
+/// actions.bind_key("C", "crouch");
+///
+/// actions.key_down("C");
+/// actions.key_up("C");
+/// actions.set_axis("move_x", 0.1);
+/// actions.update(Duration::from_millis(16));
+/// assert!(actions.is_active("crouch"));
+/// assert_eq!(actions.axis("move_x"), 0.0);
+/// ```
+///
+#[derive(Clone, Debug, Default)]
+pub struct Actions {
+    /// Accessibility options of actions and axes.
+    ///
+    pub accessibility: InputAccessibility,
+
+    /// Names of keys that are bound to names of actions.
+    ///
+    key_bindings: BTreeMap<String, String>,
+    /// Raw input that is not yet applied by `Actions::update`
+    /// (pressed or released actions in order).
+    ///
+    pending: Vec<(String, bool)>,
+    /// States of actions.
+    ///
+    actions: BTreeMap<String, ActionState>,
+    /// Raw values of axes.
+    ///
+    axes: BTreeMap<String, f32>,
+}
+impl Actions {
+    /// Initializes [`Actions`] without bindings and with default accessibility options.
+    ///
+    pub fn new() -> Self {
+        Actions {
+            accessibility: InputAccessibility::default(),
+
+            key_bindings: BTreeMap::new(),
+            pending: Vec::new(),
+            actions: BTreeMap::new(),
+            axes: BTreeMap::new(),
+        }
+    }
+    /// Initializes [`Actions`] with key bindings and accessibility options from settings.
+    ///
+    pub fn from_settings(settings: &EngineSettings) -> Self {
+        Actions {
+            accessibility: settings.input_accessibility.clone(),
+            key_bindings: settings.key_bindings.clone(),
+            ..Actions::new()
+        }
+    }
+    /// Stores key bindings and accessibility options into settings.
+    ///
+    pub fn store_settings(&self, settings: &mut EngineSettings) {
+        settings.input_accessibility = self.accessibility.clone();
+        settings.key_bindings = self.key_bindings.clone();
+    }
+
+    /// Binds key (by its name) to the action, replacing previous binding of that key.
+    ///
+    pub fn bind_key(&mut self, key: &str, action: &str) {
+        let _ = self
+            .key_bindings
+            .insert(String::from(key), String::from(action));
+    }
+    /// Removes binding of the key.
+    ///
+    pub fn unbind_key(&mut self, key: &str) {
+        let _ = self.key_bindings.remove(key);
+    }
+    /// Returns action to which key is bound.
+    ///
+    pub fn key_binding(&self, key: &str) -> Option<&str> {
+        self.key_bindings.get(key).map(String::as_str)
+    }
+
+    /// Registers press of the key (unbound keys are ignored).
+    ///
+    pub fn key_down(&mut self, key: &str) {
+        if let Some(action) = self.key_bindings.get(key) {
+            self.pending.push((action.clone(), true));
+        }
+    }
+    /// Registers release of the key (unbound keys are ignored).
+    ///
+    pub fn key_up(&mut self, key: &str) {
+        if let Some(action) = self.key_bindings.get(key) {
+            self.pending.push((action.clone(), false));
+        }
+    }
+    /// Registers press of an input that holds the action.
+    ///
+    pub fn press(&mut self, action: &str) {
+        self.pending.push((String::from(action), true));
+    }
+    /// Registers release of an input that held the action.
+    ///
+    pub fn release(&mut self, action: &str) {
+        self.pending.push((String::from(action), false));
+    }
+    /// Sets raw value of the axis (from -1.0 to 1.0).
+    ///
+    pub fn set_axis(&mut self, axis: &str, value: f32) {
+        let _ = self.axes.insert(String::from(axis), value);
+    }
+
+    /// Applies registered input and advances repeats of held actions.
+    ///
+    /// Action that is pressed and released during the same frame is still activated (or toggled) once.
+    ///
+    pub fn update(&mut self, delta: Duration) {
+        let mut tapped: Vec<String> = Vec::new();
+        for (action, pressed) in self.pending.drain(..) {
+            let state: &mut ActionState = self.actions.entry(action.clone()).or_default();
+            if pressed {
+                state.held += 1;
+            } else if state.held > 0 {
+                state.held -= 1;
+                if state.held == 0 && !state.was_held {
+                    tapped.push(action);
+                }
+            }
+        }
+
+        for (action, state) in self.actions.iter_mut() {
+            let options: ActionOptions = self.accessibility.action(action);
+            let tap: bool = tapped.contains(action);
+            let held: bool = state.held > 0;
+            let pressed: bool = (held && !state.was_held) || tap;
+
+            let was_active: bool = state.active;
+            state.active = if options.toggle {
+                state.active != pressed
+            } else {
+                held || tap
+            };
+            state.just_activated = state.active && !was_active;
+            state.just_deactivated = !state.active && was_active;
+
+            state.triggered = pressed;
+            if held && !pressed {
+                let previous: Duration = state.held_for;
+                state.held_for += delta;
+                if let Some(repeat) = options.repeat {
+                    state.triggered = repeat.repeats(state.held_for) > repeat.repeats(previous);
+                }
+            } else {
+                state.held_for = Duration::ZERO;
+            }
+            state.was_held = held;
+        }
+    }
+
+    /// Returns whether action is active (held or toggled on).
+    ///
+    pub fn is_active(&self, action: &str) -> bool {
+        self.actions.get(action).is_some_and(|state| state.active)
+    }
+    /// Returns whether action was activated during the current frame.
+    ///
+    pub fn just_activated(&self, action: &str) -> bool {
+        self.actions
+            .get(action)
+            .is_some_and(|state| state.just_activated)
+    }
+    /// Returns whether action was deactivated during the current frame.
+    ///
+    pub fn just_deactivated(&self, action: &str) -> bool {
+        self.actions
+            .get(action)
+            .is_some_and(|state| state.just_deactivated)
+    }
+    /// Returns whether action was pressed or repeated during the current frame
+    /// (this is what menus should react to).
+    ///
+    pub fn triggered(&self, action: &str) -> bool {
+        self.actions
+            .get(action)
+            .is_some_and(|state| state.triggered)
+    }
+    /// Returns value of the axis after its accessibility options are applied.
+    ///
+    pub fn axis(&self, axis: &str) -> f32 {
+        self.axes
+            .get(axis)
+            .map_or(0.0, |&value| self.accessibility.axis(axis).apply(value))
+    }
+}
+impl Resource for Actions {}
+
+#[cfg(test)]
+mod tests {
+    use super::{ActionOptions, Actions, AxisOptions, RepeatRate, ResponseCurve};
+    use std::time::Duration;
+
+    #[test]
+    fn accessibility_options() {
+        let frame: Duration = Duration::from_millis(100);
+        let mut actions: Actions = Actions::new();
+        let _ = actions.accessibility.actions.insert(
+            String::from("down"),
+            ActionOptions {
+                toggle: false,
+                repeat: Some(RepeatRate {
+                    delay: Duration::from_millis(250),
+                    interval: Duration::from_millis(100),
+                }),
+            },
+        );
+        let _ = actions.accessibility.actions.insert(
+            String::from("aim"),
+            ActionOptions {
+                toggle: true,
+                repeat: None,
+            },
+        );
+
+        actions.press("down");
+        let mut triggers: Vec<bool> = Vec::new();
+        for _ in 0..5 {
+            actions.update(frame);
+            triggers.push(actions.triggered("down"));
+        }
+        assert_eq!(triggers, [true, false, false, true, true]);
+        actions.release("down");
+        actions.update(frame);
+        assert!(actions.just_deactivated("down") && !actions.triggered("down"));
+
+        actions.press("aim");
+        actions.update(frame);
+        actions.release("aim");
+        actions.update(frame);
+        assert!(actions.is_active("aim") && !actions.just_activated("aim"));
+        actions.press("aim");
+        actions.release("aim");
+        actions.update(frame);
+        assert!(actions.just_deactivated("aim"));
+
+        let options: AxisOptions = AxisOptions {
+            dead_zone: 0.5,
+            curve: ResponseCurve::Quadratic,
+            sensitivity: 2.0,
+            inverted: true,
+        };
+        assert_eq!(options.apply(0.45), 0.0);
+        assert_eq!(options.apply(-0.75), 0.5);
+        assert_eq!(options.apply(1.5), -2.0);
+        assert_eq!(actions.axis("unknown"), 0.0);
+    }
+}
@@ -0,0 +1,136 @@
+//! `inputcore::events` submodule implements [`InputPump`] - struct that polls events of `sdl2`
+//! once per frame and updates input resources.
+//!
+//! Events that do not change state of input devices are returned as [`InputEvent`]s.
+//!
+
+use crate::{
+    inputcore::{
+        actions::Actions,
+        mouse::{Mouse, MouseButton},
+    },
+    mathcore::vectors::{Point, Vector2},
+    GGEngine, GGError,
+};
+use sdl2::{event::Event as SdlEvent, EventPump as SdlEventPump};
+use std::fmt;
+
+/// [`InputEvent`] enum lists events that are returned by [`InputPump`].
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InputEvent {
+    /// User requested to close the application (e.g. by closing the last window).
+    ///
+    Quit,
+    /// Gamepad was connected, disconnected or remapped
+    /// (`Gamepads::open_connected` should be called to update opened gamepads).
+    ///
+    GamepadsChanged,
+}
+
+/// [`InputPump`] struct polls events of `sdl2` and updates input resources.
+///
+/// Only one [`InputPump`] can exist at a time.
+///
+/// # Example
+/// ```rust, no_run
+/// # use ggengine::GGEngine;
+/// # use ggengine::inputcore::{actions::Actions, events::{InputEvent, InputPump}, mouse::Mouse};
+/// # use std::time::Duration;
+/// let engine: GGEngine = GGEngine::init().expect("Engine should be initialized");
+/// let mut pump: InputPump = InputPump::new(&engine).expect("Event pump should be created");
+/// let mut mouse: Mouse = Mouse::new();
+/// let mut actions: Actions = Actions::new();
+/// actions.bind_key("Space", "jump");
+/// 'game: loop {
+///     for event in pump.update(&mut mouse, &mut actions) {
+///         if event == InputEvent::Quit {
+///             break 'game;
+///         }
+///     }
+///     actions.update(Duration::from_millis(16));
+///     if actions.just_activated("jump") {
+///         println!("Jump!");
+///     }
+///     println!("Mouse moved by {:?}", mouse.delta());
+/// }
+/// ```
+///
+pub struct InputPump {
+    /// Underlying `sdl2` event pump.
+    ///
+    pump: SdlEventPump,
+}
+impl InputPump {
+    /// Creates [`InputPump`].
+    ///
+    /// # Errors
+    /// Error is returned if another [`InputPump`] exists.
+    ///
+    pub fn new(engine: &GGEngine) -> Result<Self, GGError> {
+        Ok(InputPump {
+            pump: engine.get_sdl_eventpump().map_err(GGError::InvalidState)?,
+        })
+    }
+
+    /// Starts new frame of input resources, polls all pending events and applies them to resources.
+    ///
+    /// Keys are passed to [`Actions`] by their names, repeats of keys by the system are ignored
+    /// (repeats of actions are configured by their accessibility options).
+    /// `Actions::update` should be called afterwards.
+    /// Returns events that are not related to state of input devices.
+    ///
+    pub fn update(&mut self, mouse: &mut Mouse, actions: &mut Actions) -> Vec<InputEvent> {
+        mouse.begin_frame();
+        let mut events: Vec<InputEvent> = Vec::new();
+        for event in self.pump.poll_iter() {
+            match event {
+                SdlEvent::Quit { .. } => events.push(InputEvent::Quit),
+                SdlEvent::ControllerDeviceAdded { .. }
+                | SdlEvent::ControllerDeviceRemoved { .. }
+                | SdlEvent::ControllerDeviceRemapped { .. }
+                    if !events.contains(&InputEvent::GamepadsChanged) =>
+                {
+                    events.push(InputEvent::GamepadsChanged)
+                }
+                SdlEvent::KeyDown {
+                    keycode: Some(keycode),
+                    repeat: false,
+                    ..
+                } => actions.key_down(&keycode.name()),
+                SdlEvent::KeyUp {
+                    keycode: Some(keycode),
+                    ..
+                } => actions.key_up(&keycode.name()),
+                SdlEvent::MouseMotion {
+                    x, y, xrel, yrel, ..
+                } => mouse.move_to(
+                    Point::from([x as f32, y as f32]),
+                    Vector2::from([xrel as f32, yrel as f32]),
+                ),
+                SdlEvent::MouseWheel {
+                    precise_x,
+                    precise_y,
+                    ..
+                } => mouse.scroll(Vector2::from([precise_x, precise_y])),
+                SdlEvent::MouseButtonDown { mouse_btn, .. } => {
+                    if let Some(button) = MouseButton::from_sdl_mouse_button(mouse_btn) {
+                        mouse.press(button);
+                    }
+                }
+                SdlEvent::MouseButtonUp { mouse_btn, .. } => {
+                    if let Some(button) = MouseButton::from_sdl_mouse_button(mouse_btn) {
+                        mouse.release(button);
+                    }
+                }
+                _ => {}
+            }
+        }
+        events
+    }
+}
+impl fmt::Debug for InputPump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InputPump").finish()
+    }
+}
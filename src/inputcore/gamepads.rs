@@ -0,0 +1,493 @@
+//! `inputcore::gamepads` submodule implements [`Gamepads`] - struct that opens connected
+//! game controllers and provides access to their features (rumble and LED).
+//!
+//! Gameplay systems do not need access to devices to request haptic feedback - they push
+//! [`RumbleRequest`]s into [`RumbleRequests`] resource, and those requests are applied to devices
+//! once per frame by `Gamepads::apply_rumble_requests`.
+//!
+
+use crate::{
+    gamecore::components::Resource,
+    log::{self, LogTarget},
+    mathcore::Color,
+    GGEngine, GGError,
+};
+use sdl2::{controller::GameController as SdlGameController, GameControllerSubsystem};
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    fmt,
+    time::Duration,
+};
+
+/// [`GamepadId`] id struct is needed to identify connected [`Gamepad`]s.
+///
+/// Id is assigned by `sdl2` when gamepad is connected and is not reused during the run of the game
+/// (reconnected gamepad receives new id).
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GamepadId(u32);
+impl GamepadId {
+    /// Creates new id with given value.
+    ///
+    pub(crate) fn new(id: u32) -> Self {
+        Self(id)
+    }
+
+    /// Returns underlying id.
+    ///
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+}
+
+/// Converts intensity (from 0.0 to 1.0) to `sdl2` representation.
+///
+fn to_sdl_intensity(intensity: f32) -> u16 {
+    (intensity.clamp(0.0, 1.0) * f32::from(u16::MAX)).round() as u16
+}
+/// Converts duration to `sdl2` representation (in milliseconds).
+///
+/// Durations are clamped to one hour, since `sdl2` stops effects immediately when duration overflows.
+///
+fn to_sdl_duration(duration: Duration) -> u32 {
+    duration.min(Duration::from_secs(3600)).as_millis() as u32
+}
+
+/// [`Gamepad`] struct represents opened game controller.
+///
+pub struct Gamepad {
+    /// Underlying `sdl2` game controller.
+    ///
+    controller: SdlGameController,
+}
+impl Gamepad {
+    /// Returns id of the gamepad.
+    ///
+    pub fn id(&self) -> GamepadId {
+        GamepadId::new(self.controller.instance_id())
+    }
+    /// Returns name of the gamepad.
+    ///
+    pub fn name(&self) -> String {
+        self.controller.name()
+    }
+    /// Returns whether gamepad is still connected.
+    ///
+    pub fn is_attached(&self) -> bool {
+        self.controller.attached()
+    }
+
+    /// Returns whether gamepad supports rumble.
+    ///
+    pub fn supports_rumble(&self) -> bool {
+        self.controller.has_rumble()
+    }
+    /// Returns whether gamepad supports rumble of triggers.
+    ///
+    pub fn supports_trigger_rumble(&self) -> bool {
+        self.controller.has_rumble_triggers()
+    }
+    /// Returns whether gamepad has LED.
+    ///
+    pub fn supports_led(&self) -> bool {
+        self.controller.has_led()
+    }
+
+    /// Starts rumble of low frequency (strong) and high frequency (weak) motors with given intensities
+    /// (from 0.0 to 1.0) for given duration.
+    ///
+    /// New rumble replaces previous one, so zero intensities stop the rumble.
+    ///
+    /// # Errors
+    /// Error is returned if gamepad does not support rumble.
+    ///
+    pub fn rumble(
+        &mut self,
+        low_frequency: f32,
+        high_frequency: f32,
+        duration: Duration,
+    ) -> Result<(), GGError> {
+        self.controller
+            .set_rumble(
+                to_sdl_intensity(low_frequency),
+                to_sdl_intensity(high_frequency),
+                to_sdl_duration(duration),
+            )
+            .map_err(|error| GGError::Unsupported(error.to_string()))
+    }
+    /// Starts rumble of left and right triggers with given intensities (from 0.0 to 1.0) for given duration.
+    ///
+    /// # Errors
+    /// Error is returned if gamepad does not support rumble of triggers.
+    ///
+    pub fn rumble_triggers(
+        &mut self,
+        left: f32,
+        right: f32,
+        duration: Duration,
+    ) -> Result<(), GGError> {
+        self.controller
+            .set_rumble_triggers(
+                to_sdl_intensity(left),
+                to_sdl_intensity(right),
+                to_sdl_duration(duration),
+            )
+            .map_err(|error| GGError::Unsupported(error.to_string()))
+    }
+    /// Stops rumble of motors and triggers.
+    ///
+    pub fn stop_rumble(&mut self) {
+        let _ = self.rumble(0.0, 0.0, Duration::ZERO);
+        let _ = self.rumble_triggers(0.0, 0.0, Duration::ZERO);
+    }
+    /// Sets color of the LED (alpha channel is ignored).
+    ///
+    /// # Errors
+    /// Error is returned if gamepad has no LED.
+    ///
+    pub fn set_led(&mut self, color: Color) -> Result<(), GGError> {
+        self.controller
+            .set_led(color.r, color.g, color.b)
+            .map_err(|error| GGError::Unsupported(error.to_string()))
+    }
+}
+impl fmt::Debug for Gamepad {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Gamepad")
+            .field("id", &self.id())
+            .field("name", &self.name())
+            .finish()
+    }
+}
+
+/// [`RumbleMotors`] enum lists motors that can be rumbled by [`RumbleRequest`].
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RumbleMotors {
+    /// Motors of the body of gamepad (`strong` is low frequency motor and `weak` is high frequency motor).
+    ///
+    #[default]
+    Body,
+    /// Motors of triggers (`strong` is left trigger and `weak` is right trigger).
+    ///
+    Triggers,
+}
+
+/// [`RumbleRequest`] struct describes haptic feedback that gameplay requests.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RumbleRequest {
+    /// Gamepad that should rumble (`None` requests rumble of all gamepads).
+    ///
+    pub gamepad: Option<GamepadId>,
+    /// Motors that should rumble.
+    ///
+    pub motors: RumbleMotors,
+    /// Intensity of the first motor (from 0.0 to 1.0).
+    ///
+    pub strong: f32,
+    /// Intensity of the second motor (from 0.0 to 1.0).
+    ///
+    pub weak: f32,
+    /// Duration of rumble.
+    ///
+    pub duration: Duration,
+}
+impl RumbleRequest {
+    /// Constructs request of rumble of body motors of all gamepads.
+    ///
+    pub fn new(strong: f32, weak: f32, duration: Duration) -> Self {
+        RumbleRequest {
+            gamepad: None,
+            motors: RumbleMotors::Body,
+            strong,
+            weak,
+            duration,
+        }
+    }
+    /// Returns whether request targets given gamepad.
+    ///
+    fn targets(&self, gamepad: GamepadId) -> bool {
+        self.gamepad.is_none_or(|id| id == gamepad)
+    }
+    /// Combines two requests for the same motors (the strongest intensities and the longest duration win).
+    ///
+    fn combine(self, other: RumbleRequest) -> RumbleRequest {
+        RumbleRequest {
+            strong: self.strong.max(other.strong),
+            weak: self.weak.max(other.weak),
+            duration: self.duration.max(other.duration),
+            ..self
+        }
+    }
+}
+
+/// [`RumbleRequests`] struct is a resource that collects [`RumbleRequest`]s during the frame.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::inputcore::gamepads::{RumbleRequest, RumbleRequests};
+/// # use std::time::Duration;
+/// let mut requests: RumbleRequests = RumbleRequests::new();
+/// requests.request(RumbleRequest::new(1.0, 0.5, Duration::from_millis(200)));
+/// assert_eq!(requests.len(), 1);
+/// ```
+///
+#[derive(Clone, Debug, Default)]
+pub struct RumbleRequests {
+    /// Requests of the current frame.
+    ///
+    requests: Vec<RumbleRequest>,
+}
+impl RumbleRequests {
+    /// Initializes empty [`RumbleRequests`].
+    ///
+    pub fn new() -> Self {
+        RumbleRequests {
+            requests: Vec::new(),
+        }
+    }
+
+    /// Adds request.
+    ///
+    pub fn request(&mut self, request: RumbleRequest) {
+        self.requests.push(request);
+    }
+    /// Returns number of requests.
+    ///
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+    /// Returns whether there are no requests.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+    /// Removes all requests.
+    ///
+    pub fn clear(&mut self) {
+        self.requests.clear();
+    }
+
+    /// Removes all requests and combines those that target given gamepads into one request per gamepad and motors.
+    ///
+    fn drain_combined(
+        &mut self,
+        gamepads: impl Iterator<Item = GamepadId>,
+    ) -> BTreeMap<(GamepadId, bool), RumbleRequest> {
+        let mut combined: BTreeMap<(GamepadId, bool), RumbleRequest> = BTreeMap::new();
+        for gamepad in gamepads {
+            for request in self
+                .requests
+                .iter()
+                .filter(|request| request.targets(gamepad))
+            {
+                let key: (GamepadId, bool) = (gamepad, request.motors == RumbleMotors::Triggers);
+                let request: RumbleRequest = match combined.get(&key) {
+                    Some(&previous) => previous.combine(*request),
+                    None => *request,
+                };
+                let _ = combined.insert(key, request);
+            }
+        }
+        self.requests.clear();
+        combined
+    }
+}
+impl Resource for RumbleRequests {}
+
+/// [`GamepadEvent`] enum lists changes of opened gamepads that are returned by `Gamepads::open_connected`.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GamepadEvent {
+    /// Gamepad was connected and opened.
+    ///
+    Connected(GamepadId),
+    /// Gamepad was disconnected and closed.
+    ///
+    Disconnected(GamepadId),
+}
+
+/// [`Gamepads`] struct opens connected gamepads and provides access to them.
+///
+/// # Example
+/// ```rust, no_run
+/// # use ggengine::GGEngine;
+/// # use ggengine::inputcore::gamepads::{Gamepad, GamepadId, Gamepads, RumbleRequest, RumbleRequests};
+/// # use ggengine::mathcore::Color;
+/// # use std::time::Duration;
+/// let engine: GGEngine = GGEngine::init().expect("Engine should be initialized");
+/// let mut gamepads: Gamepads = Gamepads::new(&engine).expect("Gamepads should be initialized");
+/// let _ = gamepads.open_connected();
+///
+/// let ids: Vec<GamepadId> = gamepads.ids().collect();
+/// for id in ids {
+///     let gamepad: &mut Gamepad = gamepads.get_mut(id).expect("Gamepad is opened");
+///     if gamepad.supports_led() {
+///         gamepad.set_led(Color::from_rgba(255, 0, 0, 255)).expect("Gamepad has LED");
+///     }
+/// }
+///
+/// let mut requests: RumbleRequests = RumbleRequests::new();
+/// requests.request(RumbleRequest::new(0.8, 0.3, Duration::from_millis(150)));
+/// gamepads.apply_rumble_requests(&mut requests);
+/// ```
+///
+pub struct Gamepads {
+    /// Underlying `sdl2` game controller subsystem.
+    ///
+    subsystem: GameControllerSubsystem,
+    /// Opened gamepads.
+    ///
+    gamepads: BTreeMap<GamepadId, Gamepad>,
+}
+impl Gamepads {
+    /// Initializes game controller subsystem without opened gamepads.
+    ///
+    /// # Errors
+    /// Error is returned if `sdl2` fails to initialize game controller subsystem.
+    ///
+    pub fn new(engine: &GGEngine) -> Result<Self, GGError> {
+        Ok(Gamepads {
+            subsystem: engine
+                .get_sdl_gamecontrollersubsystem()
+                .map_err(GGError::Sdl)?,
+            gamepads: BTreeMap::new(),
+        })
+    }
+
+    /// Opens all connected gamepads that were not opened yet and closes disconnected ones.
+    ///
+    /// This function should be called once at the start and then every time
+    /// [`InputEvent::GamepadsChanged`](super::events::InputEvent::GamepadsChanged) is received.
+    /// Returns events of connected and disconnected gamepads.
+    ///
+    pub fn open_connected(&mut self) -> Vec<GamepadEvent> {
+        let mut events: Vec<GamepadEvent> = Vec::new();
+        self.gamepads.retain(|&id, gamepad| {
+            let attached: bool = gamepad.is_attached();
+            if !attached {
+                log::info(
+                    LogTarget::InputCore,
+                    format_args!("Gamepad '{}' is disconnected", gamepad.name()),
+                );
+                events.push(GamepadEvent::Disconnected(id));
+            }
+            attached
+        });
+        let count: u32 = self.subsystem.num_joysticks().unwrap_or(0);
+        for index in (0..count).filter(|&index| self.subsystem.is_game_controller(index)) {
+            let controller: SdlGameController = match self.subsystem.open(index) {
+                Ok(controller) => controller,
+                Err(error) => {
+                    log::warn(
+                        LogTarget::InputCore,
+                        format_args!("Gamepad {index} cannot be opened: {error}"),
+                    );
+                    continue;
+                }
+            };
+            let gamepad: Gamepad = Gamepad { controller };
+            let id: GamepadId = gamepad.id();
+            if let Entry::Vacant(entry) = self.gamepads.entry(id) {
+                log::info(
+                    LogTarget::InputCore,
+                    format_args!("Gamepad '{}' is connected", gamepad.name()),
+                );
+                let _ = entry.insert(gamepad);
+                events.push(GamepadEvent::Connected(id));
+            }
+        }
+        events
+    }
+
+    /// Returns ids of opened gamepads.
+    ///
+    pub fn ids(&self) -> impl Iterator<Item = GamepadId> + '_ {
+        self.gamepads.keys().copied()
+    }
+    /// Returns reference to the opened gamepad.
+    ///
+    pub fn get(&self, id: GamepadId) -> Option<&Gamepad> {
+        self.gamepads.get(&id)
+    }
+    /// Returns mutable reference to the opened gamepad.
+    ///
+    pub fn get_mut(&mut self, id: GamepadId) -> Option<&mut Gamepad> {
+        self.gamepads.get_mut(&id)
+    }
+
+    /// Applies all requests to opened gamepads and clears them.
+    ///
+    /// Requests that target the same motors of a gamepad are combined (the strongest intensities and the longest
+    /// duration win). Requests of unsupported features are ignored.
+    ///
+    pub fn apply_rumble_requests(&mut self, requests: &mut RumbleRequests) {
+        for ((id, _), request) in requests.drain_combined(self.gamepads.keys().copied()) {
+            let Some(gamepad) = self.gamepads.get_mut(&id) else {
+                continue;
+            };
+            let _ = match request.motors {
+                RumbleMotors::Body if gamepad.supports_rumble() => {
+                    gamepad.rumble(request.strong, request.weak, request.duration)
+                }
+                RumbleMotors::Triggers if gamepad.supports_trigger_rumble() => {
+                    gamepad.rumble_triggers(request.strong, request.weak, request.duration)
+                }
+                _ => Ok(()),
+            };
+        }
+    }
+}
+impl fmt::Debug for Gamepads {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Gamepads")
+            .field("gamepads", &self.gamepads)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        to_sdl_duration, to_sdl_intensity, GamepadId, RumbleMotors, RumbleRequest, RumbleRequests,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn rumble_requests() {
+        assert_eq!(to_sdl_intensity(2.0), u16::MAX);
+        assert_eq!(to_sdl_intensity(-1.0), 0);
+        assert_eq!(to_sdl_duration(Duration::MAX), 3_600_000);
+
+        let (first, second): (GamepadId, GamepadId) = (GamepadId::new(1), GamepadId::new(2));
+        let mut requests: RumbleRequests = RumbleRequests::new();
+        requests.request(RumbleRequest::new(0.5, 0.1, Duration::from_millis(300)));
+        requests.request(RumbleRequest {
+            gamepad: Some(second),
+            ..RumbleRequest::new(0.2, 0.9, Duration::from_millis(100))
+        });
+        requests.request(RumbleRequest {
+            gamepad: Some(first),
+            motors: RumbleMotors::Triggers,
+            ..RumbleRequest::new(1.0, 0.0, Duration::from_millis(50))
+        });
+
+        let combined: Vec<((GamepadId, bool), RumbleRequest)> = requests
+            .drain_combined([first, second].into_iter())
+            .into_iter()
+            .collect();
+        assert!(requests.is_empty());
+        assert_eq!(combined.len(), 3);
+        assert_eq!(combined[0].1.strong, 0.5);
+        assert_eq!(combined[1].0, (first, true));
+        assert_eq!(
+            (
+                combined[2].1.strong,
+                combined[2].1.weak,
+                combined[2].1.duration
+            ),
+            (0.5, 0.9, Duration::from_millis(300))
+        );
+    }
+}
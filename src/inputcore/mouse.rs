@@ -0,0 +1,183 @@
+//! `inputcore::mouse` submodule implements [`Mouse`] - resource that holds state of the mouse
+//! during the current frame.
+//!
+//! State is updated by [`InputPump`](super::events::InputPump) from events of the window.
+//! When relative mouse mode of the [`Window`](crate::utils::Window) is enabled, cursor is hidden and
+//! its position does not change, but `Mouse::delta` still reports movement of the mouse, which is
+//! required for drag-to-look controls and twin-stick aiming.
+//!
+
+use crate::{
+    gamecore::components::Resource,
+    mathcore::vectors::{Point, Vector2},
+};
+
+/// [`MouseButton`] enum lists buttons of the mouse.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MouseButton {
+    /// Left button.
+    ///
+    Left,
+    /// Middle button (wheel).
+    ///
+    Middle,
+    /// Right button.
+    ///
+    Right,
+    /// First extra button.
+    ///
+    X1,
+    /// Second extra button.
+    ///
+    X2,
+}
+impl MouseButton {
+    /// Number of buttons.
+    ///
+    const COUNT: usize = 5;
+
+    // All functions that are providing gate between `ggengine` and `sdl2` extend their API to `crate` visibility.
+    /// Converts `sdl2` representation of mouse button into [`MouseButton`] (unknown buttons are `None`).
+    ///
+    pub(crate) fn from_sdl_mouse_button(button: sdl2::mouse::MouseButton) -> Option<Self> {
+        match button {
+            sdl2::mouse::MouseButton::Left => Some(MouseButton::Left),
+            sdl2::mouse::MouseButton::Middle => Some(MouseButton::Middle),
+            sdl2::mouse::MouseButton::Right => Some(MouseButton::Right),
+            sdl2::mouse::MouseButton::X1 => Some(MouseButton::X1),
+            sdl2::mouse::MouseButton::X2 => Some(MouseButton::X2),
+            sdl2::mouse::MouseButton::Unknown => None,
+        }
+    }
+}
+
+/// [`Mouse`] struct is a resource that holds state of the mouse.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::inputcore::mouse::{Mouse, MouseButton};
+/// # use ggengine::mathcore::vectors::{Point, Vector2};
+/// let mut mouse: Mouse = Mouse::new();
+/// mouse.move_to(Point::from([100.0, 50.0]), Vector2::from([4.0, -2.0]));
+/// mouse.move_to(Point::from([100.0, 50.0]), Vector2::from([1.0, 0.0])); // relative mode
+/// mouse.press(MouseButton::Left);
+/// assert_eq!(mouse.delta(), Vector2::from([5.0, -2.0]));
+/// assert!(mouse.just_pressed(MouseButton::Left));
+///
+/// mouse.begin_frame();
+/// assert_eq!(mouse.delta(), Vector2::zero());
+/// assert!(mouse.is_pressed(MouseButton::Left) && !mouse.just_pressed(MouseButton::Left));
+/// ```
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Mouse {
+    /// Position of the cursor in the window.
+    ///
+    position: Point,
+    /// Movement of the mouse during the current frame.
+    ///
+    delta: Vector2,
+    /// Scroll of the wheel during the current frame.
+    ///
+    wheel: Vector2,
+
+    /// Whether buttons are pressed.
+    ///
+    pressed: [bool; MouseButton::COUNT],
+    /// Whether buttons were pressed during the current frame.
+    ///
+    just_pressed: [bool; MouseButton::COUNT],
+    /// Whether buttons were released during the current frame.
+    ///
+    just_released: [bool; MouseButton::COUNT],
+}
+impl Mouse {
+    /// Initializes [`Mouse`] without movement and pressed buttons.
+    ///
+    pub fn new() -> Self {
+        Mouse {
+            position: Point::zero(),
+            delta: Vector2::zero(),
+            wheel: Vector2::zero(),
+
+            pressed: [false; MouseButton::COUNT],
+            just_pressed: [false; MouseButton::COUNT],
+            just_released: [false; MouseButton::COUNT],
+        }
+    }
+
+    /// Starts new frame by resetting movement, scroll and changes of buttons.
+    ///
+    pub fn begin_frame(&mut self) {
+        self.delta = Vector2::zero();
+        self.wheel = Vector2::zero();
+        self.just_pressed = [false; MouseButton::COUNT];
+        self.just_released = [false; MouseButton::COUNT];
+    }
+    /// Registers movement of the mouse (new position of the cursor and relative movement).
+    ///
+    pub fn move_to(&mut self, position: Point, relative: Vector2) {
+        self.position = position;
+        self.delta += relative;
+    }
+    /// Registers scroll of the wheel.
+    ///
+    pub fn scroll(&mut self, amount: Vector2) {
+        self.wheel += amount;
+    }
+    /// Registers press of the button.
+    ///
+    pub fn press(&mut self, button: MouseButton) {
+        if !self.pressed[button as usize] {
+            self.just_pressed[button as usize] = true;
+        }
+        self.pressed[button as usize] = true;
+    }
+    /// Registers release of the button.
+    ///
+    pub fn release(&mut self, button: MouseButton) {
+        if self.pressed[button as usize] {
+            self.just_released[button as usize] = true;
+        }
+        self.pressed[button as usize] = false;
+    }
+
+    /// Returns position of the cursor in the window.
+    ///
+    pub fn position(&self) -> Point {
+        self.position
+    }
+    /// Returns movement of the mouse during the current frame
+    /// (it is reported even if relative mouse mode is enabled).
+    ///
+    pub fn delta(&self) -> Vector2 {
+        self.delta
+    }
+    /// Returns scroll of the wheel during the current frame.
+    ///
+    pub fn wheel(&self) -> Vector2 {
+        self.wheel
+    }
+    /// Returns whether button is pressed.
+    ///
+    pub fn is_pressed(&self, button: MouseButton) -> bool {
+        self.pressed[button as usize]
+    }
+    /// Returns whether button was pressed during the current frame.
+    ///
+    pub fn just_pressed(&self, button: MouseButton) -> bool {
+        self.just_pressed[button as usize]
+    }
+    /// Returns whether button was released during the current frame.
+    ///
+    pub fn just_released(&self, button: MouseButton) -> bool {
+        self.just_released[button as usize]
+    }
+}
+impl Default for Mouse {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Resource for Mouse {}
@@ -0,0 +1,255 @@
+//! `inputcore::players` submodule implements [`PlayerInput`] - resource that assigns input devices
+//! to player slots of local multiplayer.
+//!
+//! Gameplay systems ask [`PlayerInput`] which devices belong to a player instead of routing
+//! devices themselves. When gamepads are connected or disconnected ([`GamepadEvent`]s), [`PlayerInput`]
+//! reassigns them and reports changes as [`PlayerInputEvent`]s, so the game can pause when a player
+//! loses the controller and resume when it is reconnected.
+//!
+
+use crate::{
+    gamecore::components::Resource,
+    inputcore::gamepads::{GamepadEvent, GamepadId},
+};
+use std::collections::VecDeque;
+
+/// [`KeyboardSplit`] enum lists parts of the keyboard that can be assigned to different players.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum KeyboardSplit {
+    /// Whole keyboard.
+    ///
+    #[default]
+    Whole,
+    /// Left half of the keyboard (WASD area).
+    ///
+    Left,
+    /// Right half of the keyboard (arrows and numpad).
+    ///
+    Right,
+}
+
+/// [`InputDevice`] enum lists devices that can be assigned to a player.
+///
+#[allow(variant_size_differences)] // id of gamepad is small enough to be copied
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InputDevice {
+    /// Keyboard (or part of it).
+    ///
+    Keyboard(KeyboardSplit),
+    /// Mouse.
+    ///
+    Mouse,
+    /// Specific gamepad.
+    ///
+    Gamepad(GamepadId),
+}
+impl InputDevice {
+    /// Returns whether two devices cannot be used by different players at the same time
+    /// (whole keyboard overlaps with both of its halves).
+    ///
+    pub fn overlaps(&self, other: &InputDevice) -> bool {
+        match (self, other) {
+            (InputDevice::Keyboard(first), InputDevice::Keyboard(second)) => {
+                first == second || *first == KeyboardSplit::Whole || *second == KeyboardSplit::Whole
+            }
+            _ => self == other,
+        }
+    }
+}
+
+/// [`PlayerInputEvent`] enum lists changes of device assignment that are reported by [`PlayerInput`].
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PlayerInputEvent {
+    /// Device was assigned to the player.
+    ///
+    DeviceAssigned {
+        /// Slot of the player.
+        ///
+        player: usize,
+        /// Assigned device.
+        ///
+        device: InputDevice,
+    },
+    /// Device of the player was disconnected.
+    ///
+    DeviceLost {
+        /// Slot of the player.
+        ///
+        player: usize,
+        /// Disconnected device.
+        ///
+        device: InputDevice,
+    },
+}
+
+/// [`PlayerInput`] struct is a resource that maps input devices to player slots.
+///
+/// Each device belongs to at most one player, but one player can use several devices
+/// (e.g. keyboard and mouse). Connected gamepads are assigned to players that lost their gamepad first
+/// (hot-swap) and then, if `auto_assign` is enabled, to the first player without a gamepad.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::inputcore::{gamepads::{GamepadEvent, GamepadId}, players::{InputDevice, KeyboardSplit, PlayerInput, PlayerInputEvent}};
+/// let mut players: PlayerInput = PlayerInput::new(2);
+/// players.assign(0, InputDevice::Keyboard(KeyboardSplit::Left));
+/// players.assign(1, InputDevice::Keyboard(KeyboardSplit::Right));
+/// assert_eq!(players.player_of(InputDevice::Keyboard(KeyboardSplit::Right)), Some(1));
+/// ```
+///
+#[derive(Clone, Debug, Default)]
+pub struct PlayerInput {
+    /// Whether connected gamepads are assigned to players without a gamepad.
+    ///
+    pub auto_assign: bool,
+
+    /// Devices of players.
+    ///
+    slots: Vec<Vec<InputDevice>>,
+    /// Players that lost their gamepads (in order of disconnection).
+    ///
+    waiting: VecDeque<usize>,
+}
+impl PlayerInput {
+    /// Initializes [`PlayerInput`] with given number of players without devices
+    /// (connected gamepads are assigned automatically).
+    ///
+    pub fn new(players: usize) -> Self {
+        PlayerInput {
+            auto_assign: true,
+
+            slots: vec![Vec::new(); players],
+            waiting: VecDeque::new(),
+        }
+    }
+
+    /// Returns number of player slots.
+    ///
+    pub fn players(&self) -> usize {
+        self.slots.len()
+    }
+    /// Returns devices of the player (empty slice if there is no such player).
+    ///
+    pub fn devices(&self, player: usize) -> &[InputDevice] {
+        self.slots.get(player).map_or(&[], Vec::as_slice)
+    }
+    /// Returns player to whom device is assigned.
+    ///
+    /// Whole keyboard is considered to belong to a player if any of its halves does.
+    ///
+    pub fn player_of(&self, device: InputDevice) -> Option<usize> {
+        self.slots
+            .iter()
+            .position(|devices| devices.iter().any(|assigned| assigned.overlaps(&device)))
+    }
+    /// Returns first gamepad of the player.
+    ///
+    pub fn gamepad(&self, player: usize) -> Option<GamepadId> {
+        self.devices(player).iter().find_map(|device| match device {
+            InputDevice::Gamepad(id) => Some(*id),
+            _ => None,
+        })
+    }
+
+    /// Assigns device to the player, removing it (and overlapping devices) from other players.
+    ///
+    /// Players that do not exist are ignored.
+    ///
+    pub fn assign(&mut self, player: usize, device: InputDevice) {
+        if player >= self.slots.len() {
+            return;
+        }
+        self.unassign(device);
+        self.slots[player].push(device);
+        if matches!(device, InputDevice::Gamepad(_)) {
+            self.waiting.retain(|&waiting| waiting != player);
+        }
+    }
+    /// Removes device (and overlapping devices) from all players.
+    ///
+    pub fn unassign(&mut self, device: InputDevice) {
+        for devices in self.slots.iter_mut() {
+            devices.retain(|assigned| !assigned.overlaps(&device));
+        }
+    }
+
+    /// Reassigns gamepads according to events of connection and disconnection.
+    ///
+    /// Returns changes of assignment.
+    ///
+    pub fn handle_gamepad_events(&mut self, events: &[GamepadEvent]) -> Vec<PlayerInputEvent> {
+        let mut changes: Vec<PlayerInputEvent> = Vec::new();
+        for &event in events {
+            match event {
+                GamepadEvent::Connected(id) => {
+                    let device: InputDevice = InputDevice::Gamepad(id);
+                    if self.player_of(device).is_some() {
+                        continue;
+                    }
+                    let player: Option<usize> = self.waiting.pop_front().or_else(|| {
+                        self.auto_assign
+                            .then(|| {
+                                (0..self.slots.len()).find(|&player| self.gamepad(player).is_none())
+                            })
+                            .flatten()
+                    });
+                    if let Some(player) = player {
+                        self.assign(player, device);
+                        changes.push(PlayerInputEvent::DeviceAssigned { player, device });
+                    }
+                }
+                GamepadEvent::Disconnected(id) => {
+                    let device: InputDevice = InputDevice::Gamepad(id);
+                    if let Some(player) = self.player_of(device) {
+                        self.unassign(device);
+                        if self.gamepad(player).is_none() {
+                            self.waiting.push_back(player);
+                        }
+                        changes.push(PlayerInputEvent::DeviceLost { player, device });
+                    }
+                }
+            }
+        }
+        changes
+    }
+}
+impl Resource for PlayerInput {}
+
+#[cfg(test)]
+mod tests {
+    use super::{InputDevice, KeyboardSplit, PlayerInput, PlayerInputEvent};
+    use crate::inputcore::gamepads::{GamepadEvent, GamepadId};
+
+    #[test]
+    fn hot_swap() {
+        let (first, second, third): (GamepadId, GamepadId, GamepadId) =
+            (GamepadId::new(0), GamepadId::new(1), GamepadId::new(2));
+        let mut players: PlayerInput = PlayerInput::new(2);
+        players.assign(0, InputDevice::Keyboard(KeyboardSplit::Left));
+        players.assign(1, InputDevice::Keyboard(KeyboardSplit::Whole));
+        assert!(players.devices(0).is_empty());
+
+        let changes: Vec<PlayerInputEvent> = players.handle_gamepad_events(&[
+            GamepadEvent::Connected(first),
+            GamepadEvent::Connected(second),
+            GamepadEvent::Connected(third),
+        ]);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(players.gamepad(1), Some(second));
+
+        assert_eq!(
+            players.handle_gamepad_events(&[GamepadEvent::Disconnected(first)]),
+            [PlayerInputEvent::DeviceLost {
+                player: 0,
+                device: InputDevice::Gamepad(first),
+            }]
+        );
+        let reconnected: GamepadId = GamepadId::new(3);
+        let _ = players.handle_gamepad_events(&[GamepadEvent::Connected(reconnected)]);
+        assert_eq!(players.gamepad(0), Some(reconnected));
+        assert_eq!(players.player_of(InputDevice::Gamepad(third)), None);
+        assert_eq!(players.player_of(InputDevice::Mouse), None);
+    }
+}
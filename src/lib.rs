@@ -21,11 +21,18 @@ extern crate serde_cbor;
 // utils
 mod ggengine;
 pub use crate::ggengine::*;
+mod errors;
+pub use crate::errors::*;
 
+pub mod log;
 pub mod utils;
 
 // cores
+pub mod aicore;
 pub mod datacore;
 pub mod gamecore;
 pub mod graphicscore;
+pub mod inputcore;
 pub mod mathcore;
+pub mod netcore;
+pub mod physicscore;
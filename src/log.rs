@@ -0,0 +1,377 @@
+//! `ggengine::log` module implements lightweight structured logging facility that is used
+//! by all cores of `ggengine` and can be used by games themselves.
+//!
+//! Every record has a level ([`LogLevel`]) and a target ([`LogTarget`]) - core from which it was sent.
+//! Records are filtered by level (which can be overridden for every target separately),
+//! printed to standard error stream, optionally written to a file sink and kept
+//! in a bounded history, which can be displayed by diagnostics overlays.
+//!
+//! # Example
+//! ```rust
+//! # use ggengine::log::{self, Logger, LogLevel, LogTarget};
+//! Logger::set_level(LogLevel::Info);
+//! Logger::set_target_level(LogTarget::PhysicsCore, Some(LogLevel::Trace));
+//!
+//! log::info(LogTarget::Game, "Level is loaded");
+//! log::debug(LogTarget::Game, "This record is filtered out");
+//! log::trace(LogTarget::PhysicsCore, format_args!("{} contacts were found", 3));
+//! ```
+//!
+
+use crate::GGError;
+use std::{
+    collections::VecDeque,
+    fmt,
+    fs::{File, OpenOptions},
+    io::Write,
+    path::Path,
+    sync::{Mutex, MutexGuard, OnceLock, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// [`LogLevel`] enum lists levels of importance of log records.
+///
+/// Levels are ordered from the least important one to the most important one.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    /// Very detailed information that is useful only while tracing a specific issue.
+    ///
+    Trace,
+    /// Information that is useful for debugging.
+    ///
+    Debug,
+    /// General information about work of the engine.
+    ///
+    #[default]
+    Info,
+    /// Something unexpected happened, but work can be continued.
+    ///
+    Warn,
+    /// Operation has failed.
+    ///
+    Error,
+}
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            LogLevel::Trace => "TRACE",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Info => "INFO",
+            LogLevel::Warn => "WARN",
+            LogLevel::Error => "ERROR",
+        })
+    }
+}
+
+/// [`LogTarget`] enum lists sources of log records.
+///
+/// Every core of `ggengine` has its own target, so their verbosity can be configured separately.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogTarget {
+    /// Records of engine itself (windows, global handlers and utils).
+    ///
+    Engine,
+    /// Records of `datacore` (assets, audio, fonts and images).
+    ///
+    DataCore,
+    /// Records of `gamecore` (ECS).
+    ///
+    GameCore,
+    /// Records of `graphicscore` (rendering).
+    ///
+    GraphicsCore,
+    /// Records of `mathcore`.
+    ///
+    MathCore,
+    /// Records of `physicscore`.
+    ///
+    PhysicsCore,
+    /// Records of `netcore`.
+    ///
+    NetCore,
+    /// Records of `inputcore` (devices and input handling).
+    ///
+    InputCore,
+    /// Records of a game that uses `ggengine`.
+    ///
+    Game,
+}
+impl LogTarget {
+    /// Number of targets.
+    ///
+    const COUNT: usize = 9;
+}
+impl fmt::Display for LogTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            LogTarget::Engine => "engine",
+            LogTarget::DataCore => "datacore",
+            LogTarget::GameCore => "gamecore",
+            LogTarget::GraphicsCore => "graphicscore",
+            LogTarget::MathCore => "mathcore",
+            LogTarget::PhysicsCore => "physicscore",
+            LogTarget::NetCore => "netcore",
+            LogTarget::InputCore => "inputcore",
+            LogTarget::Game => "game",
+        })
+    }
+}
+
+/// [`LogRecord`] struct represents one record of a log.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LogRecord {
+    /// Level of a record.
+    ///
+    pub level: LogLevel,
+    /// Source of a record.
+    ///
+    pub target: LogTarget,
+    /// Time that elapsed since the first record was sent.
+    ///
+    pub elapsed: Duration,
+    /// Message of a record.
+    ///
+    pub message: String,
+}
+impl fmt::Display for LogRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{:>10.3}s {:<5} {}] {}",
+            self.elapsed.as_secs_f64(),
+            self.level,
+            self.target,
+            self.message
+        )
+    }
+}
+
+/// [`LoggerState`] struct holds configuration of [`Logger`] and records history.
+///
+struct LoggerState {
+    /// Level that is used for targets that do not have their own level.
+    ///
+    level: LogLevel,
+    /// Levels that override default level for each target.
+    ///
+    target_levels: [Option<LogLevel>; LogTarget::COUNT],
+
+    /// Whether records are printed to standard error stream.
+    ///
+    stderr_output: bool,
+    /// File to which records are written.
+    ///
+    file_sink: Option<File>,
+
+    /// Last records.
+    ///
+    history: VecDeque<LogRecord>,
+    /// Maximal number of records in history.
+    ///
+    history_capacity: usize,
+}
+impl fmt::Debug for LoggerState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoggerState")
+            .field("level", &self.level)
+            .field("target_levels", &self.target_levels)
+            .field("stderr_output", &self.stderr_output)
+            .field("file_sink", &self.file_sink.is_some())
+            .field("history_capacity", &self.history_capacity)
+            .finish()
+    }
+}
+
+/// [`LOGGER_STATE`] global static variable holds state of [`Logger`].
+///
+static LOGGER_STATE: Mutex<LoggerState> = Mutex::new(LoggerState {
+    level: LogLevel::Info,
+    target_levels: [None; LogTarget::COUNT],
+
+    stderr_output: true,
+    file_sink: None,
+
+    history: VecDeque::new(),
+    history_capacity: Logger::DEFAULT_HISTORY_CAPACITY,
+});
+/// [`LOGGER_START`] global static variable holds time at which the first record was sent.
+///
+static LOGGER_START: OnceLock<Instant> = OnceLock::new();
+/// Locks state of [`Logger`].
+///
+/// Logging should never panic, so poisoned state is still used.
+///
+fn logger_state() -> MutexGuard<'static, LoggerState> {
+    LOGGER_STATE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// [`Logger`] is a global handler for log configuration.
+///
+/// Logger does not need initialization - by default it prints records of [`LogLevel::Info`]
+/// and higher to standard error stream and keeps last [`Logger::DEFAULT_HISTORY_CAPACITY`] records.
+///
+#[derive(Copy, Clone, Debug)]
+pub enum Logger {}
+impl Logger {
+    /// Default number of records that are kept in history.
+    ///
+    pub const DEFAULT_HISTORY_CAPACITY: usize = 256;
+
+    /// Returns level that is used for targets that do not have their own level.
+    ///
+    pub fn level() -> LogLevel {
+        logger_state().level
+    }
+    /// Sets level that is used for targets that do not have their own level.
+    ///
+    pub fn set_level(level: LogLevel) {
+        logger_state().level = level;
+    }
+    /// Returns level that is used for given target.
+    ///
+    pub fn target_level(target: LogTarget) -> LogLevel {
+        let state = logger_state();
+        state.target_levels[target as usize].unwrap_or(state.level)
+    }
+    /// Overrides level for given target.
+    ///
+    /// Passing `None` makes target use default level.
+    ///
+    pub fn set_target_level(target: LogTarget, level: Option<LogLevel>) {
+        logger_state().target_levels[target as usize] = level;
+    }
+    /// Returns whether records of given level and target are going to be logged.
+    ///
+    /// This can be used to skip preparation of expensive messages.
+    ///
+    pub fn is_enabled(level: LogLevel, target: LogTarget) -> bool {
+        level >= Logger::target_level(target)
+    }
+
+    /// Sets whether records are printed to standard error stream.
+    ///
+    pub fn set_stderr_output(enabled: bool) {
+        logger_state().stderr_output = enabled;
+    }
+    /// Sets file to which records are going to be appended (file is created if it does not exist).
+    ///
+    /// # Errors
+    /// Error is returned if file cannot be opened.
+    ///
+    pub fn set_file_sink(filename: impl AsRef<Path>) -> Result<(), GGError> {
+        let file: File = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(filename)?;
+        logger_state().file_sink = Some(file);
+        Ok(())
+    }
+    /// Stops writing records to file.
+    ///
+    pub fn remove_file_sink() {
+        logger_state().file_sink = None;
+    }
+
+    /// Returns last records in order of their occurrence.
+    ///
+    /// Diagnostics overlays can use this history to display recent issues on screen.
+    ///
+    pub fn history() -> Vec<LogRecord> {
+        logger_state().history.iter().cloned().collect()
+    }
+    /// Sets maximal number of records in history; the oldest records are dropped if needed.
+    ///
+    pub fn set_history_capacity(capacity: usize) {
+        let mut state = logger_state();
+        state.history_capacity = capacity;
+        let overflow: usize = state.history.len().saturating_sub(capacity);
+        let _ = state.history.drain(..overflow);
+    }
+    /// Clears history of records.
+    ///
+    pub fn clear_history() {
+        logger_state().history.clear();
+    }
+
+    /// Logs message with given level and target.
+    ///
+    pub fn log(level: LogLevel, target: LogTarget, message: impl fmt::Display) {
+        let mut state = logger_state();
+        if level < state.target_levels[target as usize].unwrap_or(state.level) {
+            return;
+        }
+
+        let record: LogRecord = LogRecord {
+            level,
+            target,
+            elapsed: LOGGER_START.get_or_init(Instant::now).elapsed(),
+            message: message.to_string(),
+        };
+        if state.stderr_output {
+            eprintln!("{record}");
+        }
+        if let Some(file) = state.file_sink.as_mut() {
+            // failure of logging should not break the game
+            let _ = writeln!(file, "{record}");
+        }
+        if state.history_capacity > 0 {
+            if state.history.len() >= state.history_capacity {
+                let _ = state.history.pop_front();
+            }
+            state.history.push_back(record);
+        }
+    }
+}
+
+/// Logs message with [`LogLevel::Trace`] level.
+///
+pub fn trace(target: LogTarget, message: impl fmt::Display) {
+    Logger::log(LogLevel::Trace, target, message);
+}
+/// Logs message with [`LogLevel::Debug`] level.
+///
+pub fn debug(target: LogTarget, message: impl fmt::Display) {
+    Logger::log(LogLevel::Debug, target, message);
+}
+/// Logs message with [`LogLevel::Info`] level.
+///
+pub fn info(target: LogTarget, message: impl fmt::Display) {
+    Logger::log(LogLevel::Info, target, message);
+}
+/// Logs message with [`LogLevel::Warn`] level.
+///
+pub fn warn(target: LogTarget, message: impl fmt::Display) {
+    Logger::log(LogLevel::Warn, target, message);
+}
+/// Logs message with [`LogLevel::Error`] level.
+///
+pub fn error(target: LogTarget, message: impl fmt::Display) {
+    Logger::log(LogLevel::Error, target, message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LogLevel, LogRecord, LogTarget, Logger};
+
+    #[test]
+    fn filtering() {
+        Logger::set_stderr_output(false);
+        Logger::set_target_level(LogTarget::Game, Some(LogLevel::Warn));
+        assert!(!Logger::is_enabled(LogLevel::Info, LogTarget::Game));
+        assert!(Logger::is_enabled(LogLevel::Error, LogTarget::Game));
+
+        super::info(LogTarget::Game, "filtered");
+        super::warn(LogTarget::Game, format_args!("kept {}", 1));
+        let records: Vec<LogRecord> = Logger::history()
+            .into_iter()
+            .filter(|record| record.target == LogTarget::Game)
+            .collect();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].level, LogLevel::Warn);
+        assert_eq!(records[0].message, "kept 1");
+        assert!(records[0].to_string().ends_with("WARN  game] kept 1"));
+    }
+}
@@ -20,8 +20,14 @@ mod ext;
 pub use ext::*;
 
 pub mod collisions;
+pub mod curves;
 pub mod floats;
+pub mod gradients;
 pub mod matrices;
+pub mod noise;
+pub mod polylines;
+pub mod quadtrees;
+pub mod random;
 pub mod shapes;
 pub mod transforms;
 pub mod vectors;
@@ -4,6 +4,7 @@
 
 use crate::mathcore::vectors::Vertex;
 use crate::mathcore::{
+    floats::{equal, FloatOperations},
     shapes::{Convex, Segment, Shape},
     vectors::{Point, Vector2},
     Sign,
@@ -54,62 +55,381 @@ where
     S2: Convex,
 {
     fn is_colliding(&self, shape1: &S1, shape2: &S2) -> bool {
-        let (mut s1, mut s2): (&dyn Convex, &dyn Convex) = (shape1, shape2);
+        intersects(shape1, shape2)
+    }
+    fn resolve(&self, shape1: &mut S1, shape2: &S2) {
+        if let Some(overlap) = overlap_info(shape1, shape2) {
+            shape1.translate_on(overlap.mtv);
+        }
+    }
+}
 
-        for shape in 0..2 {
-            if shape == 1 {
-                (s1, s2) = (s2, s1);
+/// [`OverlapInfo`] struct holds information about overlapping of two convex shapes.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OverlapInfo {
+    /// Penetration depth (length of the smallest overlap of shapes' projections).
+    ///
+    pub depth: f32,
+    /// Minimum translation vector - the shortest vector by which first shape should be translated
+    /// so that shapes no longer overlap.
+    ///
+    pub mtv: Vector2,
+}
+/// Returns whether two convex shapes intersect or not. Touching shapes are considered intersecting.
+///
+/// This function uses separating axis theorem and returns early on the first found separating axis.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::collisions::intersects;
+/// # use ggengine::mathcore::{shapes::{Rect, Triangle}, vectors::Point, {Angle, Size}};
+/// let rect: Rect = Rect::from_origin(
+///     Point::from([0.0, 0.0]),
+///     Angle::default(),
+///     Size::from_value(2.0),
+///     Size::from_value(2.0),
+/// );
+/// let triangle: Triangle = Triangle::new([
+///     Point::from([1.5, 0.0]),
+///     Point::from([3.0, 0.0]),
+///     Point::from([3.0, 3.0]),
+/// ]).expect("Vertices form a triangle");
+/// assert!(!intersects(&rect, &triangle));
+/// ```
+///
+pub fn intersects(shape1: &impl Convex, shape2: &impl Convex) -> bool {
+    let (mut s1, mut s2): (&dyn Convex, &dyn Convex) = (shape1, shape2);
+
+    for shape in 0..2 {
+        if shape == 1 {
+            (s1, s2) = (s2, s1);
+        }
+
+        for edge in s1.edges() {
+            let [a, b] = edge.points;
+            let axis_projection: Vector2 = Vector2::from([-(b.y - a.y), b.x - a.x]).normalized();
+
+            let (min1, max1): (f32, f32) =
+                SATSystem::axis_projection_boundaries(axis_projection, s1.vertices());
+            let (min2, max2): (f32, f32) =
+                SATSystem::axis_projection_boundaries(axis_projection, s2.vertices());
+
+            if !(max2 >= min1 && max1 >= min2) {
+                return false;
             }
+        }
+    }
+    true
+}
+/// Returns information about overlapping of two convex shapes or `None` if they do not intersect.
+///
+/// Penetration depth and minimum translation vector are found by using separating axis theorem.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::collisions::{overlap_info, OverlapInfo};
+/// # use ggengine::mathcore::{shapes::Rect, vectors::{Point, Vector2}, {Angle, Size}};
+/// let rect1: Rect = Rect::from_origin(
+///     Point::from([0.0, 0.0]),
+///     Angle::default(),
+///     Size::from_value(2.0),
+///     Size::from_value(2.0),
+/// );
+/// let rect2: Rect = Rect::from_origin(
+///     Point::from([1.5, 0.0]),
+///     Angle::default(),
+///     Size::from_value(2.0),
+///     Size::from_value(2.0),
+/// );
+/// let overlap: OverlapInfo = overlap_info(&rect1, &rect2).expect("Rectangles are overlapping");
+/// assert_eq!(overlap.depth, 0.5);
+/// assert_eq!(overlap.mtv, Vector2::from([-0.5, 0.0]));
+/// ```
+///
+pub fn overlap_info(shape1: &impl Convex, shape2: &impl Convex) -> Option<OverlapInfo> {
+    let (mut s1, mut s2): (&dyn Convex, &dyn Convex) = (shape1, shape2);
+
+    let mut depth: f32 = f32::INFINITY;
+    let mut axis: Vector2 = Vector2::zero();
+    for shape in 0..2 {
+        if shape == 1 {
+            (s1, s2) = (s2, s1);
+        }
 
-            for edge in s1.edges() {
-                let [a, b] = edge.points;
-                let axis_projection: Vector2 =
-                    Vector2::from([-(b.y - a.y), b.x - a.x]).normalized();
+        for edge in s1.edges() {
+            let [a, b] = edge.points;
+            let axis_projection: Vector2 = Vector2::from([-(b.y - a.y), b.x - a.x]).normalized();
 
-                let (min1, max1): (f32, f32) =
-                    SATSystem::axis_projection_boundaries(axis_projection, s1.vertices());
-                let (min2, max2): (f32, f32) =
-                    SATSystem::axis_projection_boundaries(axis_projection, s2.vertices());
+            let (min1, max1): (f32, f32) =
+                SATSystem::axis_projection_boundaries(axis_projection, s1.vertices());
+            let (min2, max2): (f32, f32) =
+                SATSystem::axis_projection_boundaries(axis_projection, s2.vertices());
 
-                if !(max2 >= min1 && max1 >= min2) {
-                    return false;
-                }
+            if !(max2 >= min1 && max1 >= min2) {
+                return None;
+            }
+
+            let overlap: f32 = max1.min(max2) - min1.max(min2);
+            if overlap < depth {
+                depth = overlap;
+                axis = axis_projection;
             }
         }
-        true
     }
-    fn resolve(&self, shape1: &mut S1, shape2: &S2) {
-        let (mut s1, mut s2): (&dyn Convex, &dyn Convex) = (shape1, shape2);
 
-        let mut overlap: f32 = f32::INFINITY;
+    // Axis should be directed from the first shape to the second one.
+    if (shape2.origin() - shape1.origin()) * axis < 0.0 {
+        axis = -axis;
+    }
+    Some(OverlapInfo {
+        depth,
+        mtv: -(axis * depth),
+    })
+}
 
-        for shape in 0..2 {
-            if shape == 1 {
-                (s1, s2) = (s2, s1);
+/// [`ClosestPoints`] struct holds closest points of two convex shapes and distance between them.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ClosestPoints {
+    /// Pair of closest points - first one lies on the first shape and second one lies on the second shape.
+    ///
+    /// If shapes are intersecting, those are the deepest points of penetration.
+    ///
+    pub points: [Point; 2],
+    /// Signed distance between shapes.
+    ///
+    /// If shapes are intersecting, it is equal to negated penetration depth.
+    ///
+    pub distance: f32,
+}
+/// [`SupportPoint`] struct represents point of Minkowski difference of two shapes, remembering points of both shapes that formed it.
+///
+#[derive(Copy, Clone, Debug)]
+struct SupportPoint {
+    /// Point of Minkowski difference (`a - b`).
+    ///
+    point: Point,
+    /// Point of the first shape.
+    ///
+    a: Point,
+    /// Point of the second shape.
+    ///
+    b: Point,
+}
+impl SupportPoint {
+    /// Finds point of Minkowski difference of two shapes that is the furthest along given direction.
+    ///
+    fn new(shape1: &dyn Convex, shape2: &dyn Convex, direction: Vector2) -> Self {
+        let support = |vertices: &[Vertex], direction: Vector2| -> Point {
+            vertices
+                .iter()
+                .copied()
+                .fold((Point::zero(), f32::NEG_INFINITY), |(best, max), vertex| {
+                    let projection: f32 = vertex * direction;
+                    if projection > max {
+                        (vertex, projection)
+                    } else {
+                        (best, max)
+                    }
+                })
+                .0
+        };
+        let (a, b): (Point, Point) = (
+            support(shape1.vertices(), direction),
+            support(shape2.vertices(), -direction),
+        );
+        SupportPoint { point: a - b, a, b }
+    }
+
+    /// Interpolates between two support points by `t` (`t = 0.0` corresponds to `self` and `t = 1.0` corresponds to `other`).
+    ///
+    fn interpolate(self, other: SupportPoint, t: f32) -> SupportPoint {
+        SupportPoint {
+            point: self.point + (other.point - self.point) * t,
+            a: self.a + (other.a - self.a) * t,
+            b: self.b + (other.b - self.b) * t,
+        }
+    }
+    /// Returns point of a segment between two support points that is the closest to the origin of coordinates.
+    ///
+    fn closest_on_segment(self, other: SupportPoint) -> SupportPoint {
+        let edge: Vector2 = other.point - self.point;
+        let sqr_length: f32 = edge.sqr_magnitude();
+        if equal(sqr_length, 0.0) {
+            return self;
+        }
+        self.interpolate(other, (-(self.point * edge) / sqr_length).clamp(0.0, 1.0))
+    }
+}
+/// Maximal number of iterations that are performed by GJK and EPA algorithms.
+///
+const GJK_MAX_ITERATIONS: usize = 64;
+/// Tolerance that is used by GJK and EPA algorithms to detect convergence.
+///
+const GJK_TOLERANCE: f32 = 0.0001;
+/// Implements expanding polytope algorithm, which finds penetration depth of two intersecting shapes.
+///
+/// `simplex` should contain points of Minkowski difference that enclose origin of coordinates.
+///
+fn expanding_polytope(
+    shape1: &dyn Convex,
+    shape2: &dyn Convex,
+    simplex: Vec<SupportPoint>,
+) -> ClosestPoints {
+    let mut polytope: Vec<SupportPoint> = simplex;
+    // Degenerate simplex is expanded to a triangle (or at least to a segment).
+    for direction in [
+        Vector2::from([1.0, 0.0]),
+        Vector2::from([0.0, 1.0]),
+        Vector2::from([-1.0, 0.0]),
+        Vector2::from([0.0, -1.0]),
+    ] {
+        if polytope.len() >= 3 {
+            break;
+        }
+        let support_point: SupportPoint = SupportPoint::new(shape1, shape2, direction);
+        if polytope
+            .iter()
+            .all(|point| !equal((point.point - support_point.point).sqr_magnitude(), 0.0))
+        {
+            polytope.push(support_point);
+        }
+    }
+    if polytope.len() < 3 {
+        let point: SupportPoint = polytope[0];
+        return ClosestPoints {
+            points: [point.a, point.b],
+            distance: 0.0,
+        };
+    }
+    // Polytope is kept in counterclockwise order.
+    if ((polytope[1].point - polytope[0].point) ^ (polytope[2].point - polytope[0].point)) < 0.0 {
+        polytope.swap(1, 2);
+    }
+
+    let mut closest: (usize, Vector2, f32) = (0, Vector2::zero(), f32::INFINITY);
+    for _ in 0..GJK_MAX_ITERATIONS {
+        closest = (0, Vector2::zero(), f32::INFINITY);
+        let n: usize = polytope.len();
+        for i in 0..n {
+            let edge: Vector2 = polytope[(i + 1) % n].point - polytope[i].point;
+            if equal(edge.sqr_magnitude(), 0.0) {
+                continue;
+            }
+            let normal: Vector2 = Vector2::from([edge.y, -edge.x]).normalized();
+            let distance: f32 = polytope[i].point * normal;
+            if distance < closest.2 {
+                closest = (i, normal, distance);
             }
+        }
 
-            for edge in s1.edges() {
-                let [a, b] = edge.points;
-                let axis_projection: Vector2 =
-                    Vector2::from([-(b.y - a.y), b.x - a.x]).normalized();
+        let support_point: SupportPoint = SupportPoint::new(shape1, shape2, closest.1);
+        if support_point.point * closest.1 - closest.2 < GJK_TOLERANCE {
+            break;
+        }
+        polytope.insert(closest.0 + 1, support_point);
+    }
 
-                let (min1, max1): (f32, f32) =
-                    SATSystem::axis_projection_boundaries(axis_projection, s1.vertices());
-                let (min2, max2): (f32, f32) =
-                    SATSystem::axis_projection_boundaries(axis_projection, s2.vertices());
+    let n: usize = polytope.len();
+    let (i, _, depth): (usize, Vector2, f32) = closest;
+    let contact: SupportPoint = polytope[i].closest_on_segment(polytope[(i + 1) % n]);
+    ClosestPoints {
+        points: [contact.a, contact.b],
+        distance: -depth.max(0.0),
+    }
+}
+/// Returns closest points of two convex shapes and signed distance between them.
+///
+/// Closest points are found by using Gilbert-Johnson-Keerthi algorithm; if shapes are intersecting,
+/// expanding polytope algorithm is used to find penetration depth and deepest points of penetration.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::collisions::{closest_points, ClosestPoints};
+/// # use ggengine::mathcore::{shapes::{Rect, Triangle}, vectors::Point, {Angle, Size}};
+/// # use ggengine::mathcore::floats::FloatOperations;
+/// let rect: Rect = Rect::from_origin(
+///     Point::from([0.0, 0.0]),
+///     Angle::default(),
+///     Size::from_value(2.0),
+///     Size::from_value(2.0),
+/// );
+/// let triangle: Triangle = Triangle::new([
+///     Point::from([2.0, 2.0]),
+///     Point::from([4.0, 2.0]),
+///     Point::from([4.0, 4.0]),
+/// ]).expect("Vertices form a triangle");
+/// let closest: ClosestPoints = closest_points(&rect, &triangle);
+/// assert_eq!(closest.points[0].correct(3), Point::from([1.0, 1.0]));
+/// assert_eq!(closest.points[1].correct(3), Point::from([2.0, 2.0]));
+/// assert_eq!(closest.distance, 2.0_f32.sqrt());
+/// ```
+///
+pub fn closest_points(shape1: &impl Convex, shape2: &impl Convex) -> ClosestPoints {
+    let (s1, s2): (&dyn Convex, &dyn Convex) = (shape1, shape2);
+
+    let initial_direction: Vector2 = shape2.origin() - shape1.origin();
+    let mut simplex: Vec<SupportPoint> = vec![SupportPoint::new(
+        s1,
+        s2,
+        if equal(initial_direction.sqr_magnitude(), 0.0) {
+            Vector2::from([1.0, 0.0])
+        } else {
+            initial_direction
+        },
+    )];
+    let mut closest: SupportPoint = simplex[0];
+
+    for _ in 0..GJK_MAX_ITERATIONS {
+        let v: Vector2 = closest.point;
+        if v.sqr_magnitude() < GJK_TOLERANCE * GJK_TOLERANCE {
+            return expanding_polytope(s1, s2, simplex);
+        }
 
-                overlap = overlap.min(max1.min(max2) - min1.max(min2));
+        let support_point: SupportPoint = SupportPoint::new(s1, s2, -v);
+        if v * v - v * support_point.point <= GJK_TOLERANCE * v.sqr_magnitude().max(1.0) {
+            break;
+        }
+        simplex.push(support_point);
+
+        if simplex.len() == 3 {
+            let [p0, p1, p2] = [simplex[0], simplex[1], simplex[2]];
+            let signs: [Sign; 3] = [(p0, p1), (p1, p2), (p2, p0)]
+                .map(|(a, b)| Sign::from(((b.point - a.point) ^ (-a.point)).correct(6)));
+            if !signs.contains(&Sign::Positive) || !signs.contains(&Sign::Negative) {
+                return expanding_polytope(s1, s2, simplex);
+            }
 
-                if !(max2 >= min1 && max1 >= min2) {
-                    return;
+            let (mut best, mut kept): (SupportPoint, [SupportPoint; 2]) =
+                (p0.closest_on_segment(p1), [p0, p1]);
+            for pair in [[p1, p2], [p2, p0]] {
+                let candidate: SupportPoint = pair[0].closest_on_segment(pair[1]);
+                if candidate.point.sqr_magnitude() < best.point.sqr_magnitude() {
+                    (best, kept) = (candidate, pair);
                 }
             }
+            closest = best;
+            simplex = kept.to_vec();
+        } else {
+            closest = simplex[0].closest_on_segment(simplex[1]);
         }
+    }
 
-        let d: Vector2 = (shape2.origin() - shape1.origin()).normalized();
-        shape1.translate_on(-(d * overlap));
+    ClosestPoints {
+        points: [closest.a, closest.b],
+        distance: closest.point.magnitude(),
     }
 }
+/// Returns signed distance between two convex shapes.
+///
+/// If shapes are intersecting, returned distance is equal to negated penetration depth.
+///
+/// This function is a shorthand for `closest_points(shape1, shape2).distance`.
+///
+pub fn distance(shape1: &impl Convex, shape2: &impl Convex) -> f32 {
+    closest_points(shape1, shape2).distance
+}
 
 /// `DiagonalsSystem` is a collision system that uses intersections between shapes edges and diagonals to detect and resolve collision.
 ///
@@ -311,6 +631,81 @@ mod tests {
         )
     }
 
+    #[test]
+    fn sat_helpers() {
+        use super::{intersects, overlap_info, OverlapInfo};
+        use crate::mathcore::{floats::FloatOperations, shapes::Triangle, vectors::Vector2};
+
+        let rect: Rect = Rect::from_origin(
+            Point::from([0.0, 0.0]),
+            Angle::default(),
+            Size::from_value(2.0),
+            Size::from_value(2.0),
+        );
+        let triangle: Triangle = Triangle::new([
+            Point::from([0.5, 0.8]),
+            Point::from([3.0, 0.8]),
+            Point::from([3.0, 3.0]),
+        ])
+        .expect("Vertices form a triangle");
+        assert!(intersects(&rect, &triangle));
+        let overlap: OverlapInfo = overlap_info(&rect, &triangle).expect("Shapes are overlapping");
+        assert_eq!(overlap.depth.round_up_to(3), 0.2);
+        assert_eq!(overlap.mtv.round_up_to(3), Vector2::from([0.0, -0.2]));
+
+        let far_triangle: Triangle = Triangle::new([
+            Point::from([1.5, 0.0]),
+            Point::from([3.0, 0.0]),
+            Point::from([3.0, 3.0]),
+        ])
+        .expect("Vertices form a triangle");
+        assert!(!intersects(&rect, &far_triangle));
+        assert!(overlap_info(&rect, &far_triangle).is_none());
+    }
+
+    #[test]
+    fn gjk_epa() {
+        use super::{closest_points, distance, ClosestPoints};
+        use crate::mathcore::{floats::FloatOperations, shapes::Triangle};
+
+        let rect1: Rect = Rect::from_origin(
+            Point::from([0.0, 0.0]),
+            Angle::default(),
+            Size::from_value(2.0),
+            Size::from_value(2.0),
+        );
+        let rect2: Rect = Rect::from_origin(
+            Point::from([4.0, 0.0]),
+            Angle::default(),
+            Size::from_value(2.0),
+            Size::from_value(2.0),
+        );
+        let closest: ClosestPoints = closest_points(&rect1, &rect2);
+        assert_eq!(closest.distance.round_up_to(3), 2.0);
+        assert_eq!(closest.points[0].x.round_up_to(3), 1.0);
+        assert_eq!(closest.points[1].x.round_up_to(3), 3.0);
+
+        let rect3: Rect = Rect::from_origin(
+            Point::from([1.5, 0.0]),
+            Angle::default(),
+            Size::from_value(2.0),
+            Size::from_value(2.0),
+        );
+        assert_eq!(distance(&rect1, &rect3).round_up_to(3), -0.5);
+        assert_eq!(distance(&rect1, &rect1).round_up_to(3), -2.0);
+
+        let triangle: Triangle = Triangle::new([
+            Point::from([3.0, -1.0]),
+            Point::from([5.0, 0.0]),
+            Point::from([3.0, 1.0]),
+        ])
+        .expect("Vertices form a triangle");
+        let closest: ClosestPoints = closest_points(&triangle, &rect1);
+        assert_eq!(closest.distance.round_up_to(3), 2.0);
+        assert_eq!(closest.points[0].x.round_up_to(3), 3.0);
+        assert_eq!(closest.points[1].x.round_up_to(3), 1.0);
+    }
+
     #[test]
     fn helper_fns() {
         use super::{is_colliding, CollisionSystem, DiagonalsSystem, SATSystem};
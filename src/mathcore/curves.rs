@@ -0,0 +1,273 @@
+//! `mathcore::curves` submodule implements [`Curve`] - serializable keyframed function of one parameter,
+//! which is used to describe how some value changes over time (size of a particle over its lifetime,
+//! progress of a tween, volume of a fade and so on).
+//!
+
+use serde::{Deserialize, Serialize};
+
+/// [`CurveInterpolation`] enum lists how values between two keys of a [`Curve`] are computed.
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CurveInterpolation {
+    /// Value stays the same until the next key.
+    ///
+    Constant,
+    /// Value changes linearly until the next key.
+    ///
+    #[default]
+    Linear,
+    /// Value changes slowly near both keys and quickly between them (smoothstep).
+    ///
+    EaseInOut,
+    /// Value follows smooth cubic curve which passes through neighbouring keys (Catmull-Rom spline).
+    ///
+    Cubic,
+}
+
+/// [`CurveKey`] struct is a value of a [`Curve`] at given time.
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq)]
+pub struct CurveKey {
+    /// Time (parameter) of the key.
+    ///
+    pub time: f32,
+    /// Value of the curve at the time of the key.
+    ///
+    pub value: f32,
+    /// Interpolation between this key and the next one.
+    ///
+    #[serde(default)]
+    pub interpolation: CurveInterpolation,
+}
+impl CurveKey {
+    /// Constructs key with linear interpolation.
+    ///
+    pub fn new(time: f32, value: f32) -> Self {
+        CurveKey {
+            time,
+            value,
+            interpolation: CurveInterpolation::Linear,
+        }
+    }
+    /// Constructs key with given interpolation.
+    ///
+    pub fn with_interpolation(time: f32, value: f32, interpolation: CurveInterpolation) -> Self {
+        CurveKey {
+            time,
+            value,
+            interpolation,
+        }
+    }
+}
+
+/// [`Curve`] struct is a function of one parameter that is defined by keys and interpolation between them.
+///
+/// Keys are always kept sorted by time; before the first key and after the last key curve is constant.
+/// Curve without keys evaluates to zero.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::curves::{Curve, CurveInterpolation, CurveKey};
+/// // particle grows quickly and then slowly fades out
+/// let size: Curve = Curve::new()
+///     .with_key(CurveKey::new(0.0, 0.0))
+///     .with_key(CurveKey::with_interpolation(0.25, 1.0, CurveInterpolation::EaseInOut))
+///     .with_key(CurveKey::new(1.0, 0.0));
+/// assert_eq!(size.evaluate(0.125), 0.5);
+/// assert_eq!(size.evaluate(0.25), 1.0);
+/// assert_eq!(size.evaluate(0.625), 0.5);
+/// assert_eq!(size.evaluate(2.0), 0.0);
+/// ```
+///
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(from = "Vec<CurveKey>", into = "Vec<CurveKey>")]
+pub struct Curve {
+    /// Keys of the curve, sorted by time.
+    ///
+    keys: Vec<CurveKey>,
+}
+impl Curve {
+    /// Constructs curve without keys.
+    ///
+    pub fn new() -> Self {
+        Curve { keys: Vec::new() }
+    }
+    /// Constructs curve that always evaluates to given value.
+    ///
+    pub fn constant(value: f32) -> Self {
+        Curve::new().with_key(CurveKey::new(0.0, value))
+    }
+    /// Constructs curve that linearly changes from `from` at `0.0` to `to` at `1.0`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::curves::Curve;
+    /// let fade_out: Curve = Curve::linear(1.0, 0.0);
+    /// assert_eq!(fade_out.evaluate(0.25), 0.75);
+    /// ```
+    ///
+    pub fn linear(from: f32, to: f32) -> Self {
+        Curve::new()
+            .with_key(CurveKey::new(0.0, from))
+            .with_key(CurveKey::new(1.0, to))
+    }
+
+    /// Adds key to the curve and returns the curve.
+    ///
+    pub fn with_key(mut self, key: CurveKey) -> Self {
+        self.add_key(key);
+        self
+    }
+    /// Adds key to the curve.
+    ///
+    /// Key is inserted after keys with the same time, so that curve can have discontinuities.
+    ///
+    pub fn add_key(&mut self, key: CurveKey) {
+        let index: usize = self.keys.partition_point(|other| other.time <= key.time);
+        self.keys.insert(index, key);
+    }
+    /// Removes key at given index and returns it.
+    ///
+    /// Returns `None` if index is out of bounds.
+    ///
+    pub fn remove_key(&mut self, index: usize) -> Option<CurveKey> {
+        if index < self.keys.len() {
+            Some(self.keys.remove(index))
+        } else {
+            None
+        }
+    }
+    /// Returns keys of the curve, sorted by time.
+    ///
+    pub fn keys(&self) -> &[CurveKey] {
+        &self.keys
+    }
+
+    /// Returns time of the first key and time of the last key or `None` if curve has no keys.
+    ///
+    pub fn time_range(&self) -> Option<(f32, f32)> {
+        Some((self.keys.first()?.time, self.keys.last()?.time))
+    }
+
+    /// Evaluates curve at given time.
+    ///
+    pub fn evaluate(&self, time: f32) -> f32 {
+        let next: usize = self.keys.partition_point(|key| key.time <= time);
+        if next == 0 {
+            return self.keys.first().map_or(0.0, |key| key.value);
+        }
+        if next == self.keys.len() {
+            return self.keys[next - 1].value;
+        }
+
+        let (previous, next): (usize, usize) = (next - 1, next);
+        let (a, b): (CurveKey, CurveKey) = (self.keys[previous], self.keys[next]);
+        let t: f32 = (time - a.time) / (b.time - a.time);
+        match a.interpolation {
+            CurveInterpolation::Constant => a.value,
+            CurveInterpolation::Linear => a.value + (b.value - a.value) * t,
+            CurveInterpolation::EaseInOut => {
+                a.value + (b.value - a.value) * t * t * (3.0 - 2.0 * t)
+            }
+            CurveInterpolation::Cubic => {
+                let span: f32 = b.time - a.time;
+                let (tangent_a, tangent_b): (f32, f32) =
+                    (self.tangent(previous), self.tangent(next));
+                let (t2, t3): (f32, f32) = (t * t, t * t * t);
+                (2.0 * t3 - 3.0 * t2 + 1.0) * a.value
+                    + (t3 - 2.0 * t2 + t) * span * tangent_a
+                    + (-2.0 * t3 + 3.0 * t2) * b.value
+                    + (t3 - t2) * span * tangent_b
+            }
+        }
+    }
+    /// Returns slope of the curve at key with given index, which is estimated from neighbouring keys.
+    ///
+    fn tangent(&self, index: usize) -> f32 {
+        let previous: CurveKey = self.keys[index.saturating_sub(1)];
+        let next: CurveKey = self.keys[(index + 1).min(self.keys.len() - 1)];
+        let span: f32 = next.time - previous.time;
+        if span > 0.0 {
+            (next.value - previous.value) / span
+        } else {
+            0.0
+        }
+    }
+}
+impl From<Vec<CurveKey>> for Curve {
+    /// Constructs curve from keys, sorting them by time.
+    ///
+    fn from(keys: Vec<CurveKey>) -> Self {
+        let mut keys: Vec<CurveKey> = keys;
+        keys.sort_by(|a, b| a.time.total_cmp(&b.time));
+        Curve { keys }
+    }
+}
+impl From<Curve> for Vec<CurveKey> {
+    fn from(curve: Curve) -> Self {
+        curve.keys
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Curve, CurveInterpolation, CurveKey};
+    use crate::mathcore::floats::equal;
+
+    #[test]
+    fn evaluation() {
+        assert_eq!(Curve::new().evaluate(0.5), 0.0);
+        assert_eq!(Curve::constant(3.0).evaluate(-10.0), 3.0);
+        assert_eq!(Curve::constant(3.0).evaluate(10.0), 3.0);
+
+        let steps: Curve = Curve::new()
+            .with_key(CurveKey::with_interpolation(
+                1.0,
+                2.0,
+                CurveInterpolation::Constant,
+            ))
+            .with_key(CurveKey::with_interpolation(
+                0.0,
+                1.0,
+                CurveInterpolation::Constant,
+            ));
+        assert_eq!(steps.keys()[0].time, 0.0);
+        assert_eq!(steps.evaluate(0.99), 1.0);
+        assert_eq!(steps.evaluate(1.0), 2.0);
+        assert_eq!(steps.time_range(), Some((0.0, 1.0)));
+
+        let cubic: Curve = Curve::from(vec![
+            CurveKey::with_interpolation(0.0, 0.0, CurveInterpolation::Cubic),
+            CurveKey::with_interpolation(1.0, 1.0, CurveInterpolation::Cubic),
+            CurveKey::with_interpolation(2.0, 0.0, CurveInterpolation::Cubic),
+        ]);
+        assert!(equal(cubic.evaluate(1.0), 1.0));
+        assert!(cubic.evaluate(0.5) > 0.5);
+        assert!(equal(cubic.evaluate(0.5), cubic.evaluate(1.5)));
+
+        // discontinuity
+        let mut jump: Curve = Curve::linear(0.0, 1.0);
+        jump.add_key(CurveKey::new(0.5, 10.0));
+        jump.add_key(CurveKey::new(0.5, 0.0));
+        assert_eq!(jump.evaluate(0.25), 5.0);
+        assert_eq!(jump.evaluate(0.75), 0.5);
+        assert_eq!(jump.remove_key(1), Some(CurveKey::new(0.5, 10.0)));
+        assert!(jump.remove_key(10).is_none());
+    }
+
+    #[test]
+    fn serialization() {
+        let curve: Curve = serde_json::from_str(
+            r#"[{"time": 1.0, "value": 4.0}, {"time": 0.0, "value": 2.0, "interpolation": "Constant"}]"#,
+        )
+        .expect("Curve is valid");
+        assert_eq!(curve.keys()[0].interpolation, CurveInterpolation::Constant);
+        assert_eq!(curve.evaluate(0.5), 2.0);
+
+        let json: String = serde_json::to_string(&curve).expect("Curve is serializable");
+        assert_eq!(
+            serde_json::from_str::<Curve>(&json).expect("Curve is valid"),
+            curve
+        );
+    }
+}
@@ -3,9 +3,9 @@
 //!
 
 use crate::mathcore::floats::{equal, FloatOperations};
-use serde::{Deserialize, Serialize};
+use serde::{de::Error, Deserialize, Deserializer, Serialize};
 use std::{
-    f32::consts::{FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6, TAU},
+    f32::consts::{FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6, PI, TAU},
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
@@ -128,7 +128,7 @@ impl_sign_from!(f(f32, 0.0), (f64, 0.0),);
 /// assert_eq!(angle.radians(), 3.0 * FRAC_PI_2);
 /// ```
 ///
-#[derive(Serialize, Deserialize, Copy, Clone, Debug, Default, PartialOrd)]
+#[derive(Serialize, Copy, Clone, Debug, Default, PartialOrd)]
 pub struct Angle(f32);
 impl Angle {
     /// Angle that corresponds to zero.
@@ -300,6 +300,111 @@ impl Angle {
     pub fn sin_cos(&self) -> (f32, f32) {
         self.0.sin_cos()
     }
+
+    /// Returns iterator over `count` angles which evenly divide full circle, starting from zero angle.
+    ///
+    /// Useful for radial menus and circular bursts.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::Angle;
+    /// # use ggengine::mathcore::vectors::Vector2;
+    /// let angles: Vec<Angle> = Angle::circle_steps(4).collect();
+    /// assert_eq!(angles, [Angle::ZERO, Angle::DEG90, Angle::DEG180, Angle::DEG270]);
+    ///
+    /// let directions: Vec<Vector2> = Angle::circle_steps(8).map(Vector2::from_angle).collect();
+    /// assert_eq!(directions.len(), 8);
+    /// ```
+    ///
+    pub fn circle_steps(count: usize) -> impl Iterator<Item = Angle> {
+        let step: f32 = TAU / count.max(1) as f32;
+        (0..count).map(move |i| Angle::from_radians(step * i as f32))
+    }
+    /// Returns iterator over `count` angles which are evenly spread across an arc that is centered on this angle.
+    ///
+    /// The first and the last angles lie on the edges of the arc; single angle is this angle itself.
+    /// Useful for shotgun pellets and cone attacks (see [`Rng::jitter_angle`](crate::mathcore::random::Rng::jitter_angle)
+    /// to randomize them).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::Angle;
+    /// let pellets: Vec<Angle> = Angle::DEG90.spread(Angle::from_degrees(40.0), 5).collect();
+    /// assert_eq!(
+    ///     pellets,
+    ///     [70.0, 80.0, 90.0, 100.0, 110.0].map(Angle::from_degrees)
+    /// );
+    /// assert_eq!(Angle::DEG90.spread(Angle::from_degrees(40.0), 1).collect::<Vec<Angle>>(), [Angle::DEG90]);
+    /// ```
+    ///
+    pub fn spread(self, arc: Angle, count: usize) -> impl Iterator<Item = Angle> {
+        let (start, step): (f32, f32) = if count > 1 {
+            (self.0 - arc.0 / 2.0, arc.0 / (count - 1) as f32)
+        } else {
+            (self.0, 0.0)
+        };
+        (0..count).map(move |i| Angle::from_radians(start + step * i as f32))
+    }
+
+    /// Returns signed shortest distance (in radians) from this angle to the target angle.
+    ///
+    /// Returned value lies in [-PI; PI), positive values correspond to counterclockwise rotation.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::Angle;
+    /// # use ggengine::mathcore::floats::FloatOperations;
+    /// let angle: Angle = Angle::from_degrees(315.0);
+    /// assert_eq!(angle.shortest_distance_to(Angle::from_degrees(45.0)).to_degrees().round_up_to(3), 90.0);
+    /// assert_eq!(Angle::from_degrees(45.0).shortest_distance_to(angle).to_degrees().round_up_to(3), -90.0);
+    /// ```
+    ///
+    pub fn shortest_distance_to(self, target: Angle) -> f32 {
+        let distance: f32 = (target - self).0;
+        if distance >= PI {
+            distance - TAU
+        } else {
+            distance
+        }
+    }
+    /// Interpolates between two angles by `t` along the shortest arc.
+    ///
+    /// `t` will be clamped between [0.0; 1.0]; `t = 0.0` returns `self` and `t = 1.0` returns `target`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::Angle;
+    /// let angle: Angle = Angle::from_degrees(350.0);
+    /// assert_eq!(angle.lerp(Angle::from_degrees(30.0), 0.5), Angle::from_degrees(10.0));
+    /// ```
+    ///
+    pub fn lerp(self, target: Angle, t: f32) -> Angle {
+        Angle::from_radians(self.0 + self.shortest_distance_to(target) * t.clamp(0.0, 1.0))
+    }
+    /// Rotates this angle towards target angle along the shortest arc, but no further than `max_delta`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::Angle;
+    /// let angle: Angle = Angle::from_degrees(10.0);
+    /// assert_eq!(
+    ///     angle.rotate_towards(Angle::from_degrees(300.0), Angle::from_degrees(15.0)),
+    ///     Angle::from_degrees(355.0)
+    /// );
+    /// assert_eq!(
+    ///     angle.rotate_towards(Angle::from_degrees(20.0), Angle::from_degrees(15.0)),
+    ///     Angle::from_degrees(20.0)
+    /// );
+    /// ```
+    ///
+    pub fn rotate_towards(self, target: Angle, max_delta: Angle) -> Angle {
+        let distance: f32 = self.shortest_distance_to(target);
+        if distance.abs() <= max_delta.0 {
+            target
+        } else {
+            Angle::from_radians(self.0 + max_delta.0.copysign(distance))
+        }
+    }
 }
 impl FloatOperations for Angle {
     fn correct(self, digits: i32) -> Self {
@@ -365,6 +470,25 @@ impl DivAssign<f32> for Angle {
         *self = *self / rhs;
     }
 }
+impl<'de> Deserialize<'de> for Angle {
+    /// Deserializes angle, normalizing it to [0.0; 2 * PI).
+    ///
+    /// Non-finite angles are rejected.
+    ///
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// Raw representation of [`Angle`] which is not normalized.
+        ///
+        #[derive(Deserialize)]
+        #[serde(rename = "Angle")]
+        struct RawAngle(f32);
+
+        let RawAngle(radians) = RawAngle::deserialize(deserializer)?;
+        if !radians.is_finite() {
+            return Err(D::Error::custom("angle should be finite"));
+        }
+        Ok(Angle::from_radians(radians))
+    }
+}
 impl PartialEq for Angle {
     fn eq(&self, other: &Self) -> bool {
         equal(self.0, other.0)
@@ -383,7 +507,7 @@ impl Eq for Angle {}
 /// assert_eq!(Size::from_value(0.1).get(), 0.1);
 /// ```
 ///
-#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialOrd)]
+#[derive(Serialize, Copy, Clone, Debug, PartialOrd)]
 pub struct Size(f32);
 impl Size {
     /// Normalizes given size to (0.0; +inf).
@@ -471,6 +595,25 @@ impl DivAssign<Self> for Size {
         *self = *self / rhs;
     }
 }
+impl<'de> Deserialize<'de> for Size {
+    /// Deserializes size.
+    ///
+    /// Sizes that are not finite or not positive are rejected.
+    ///
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// Raw representation of [`Size`] which is not validated.
+        ///
+        #[derive(Deserialize)]
+        #[serde(rename = "Size")]
+        struct RawSize(f32);
+
+        let RawSize(value) = RawSize::deserialize(deserializer)?;
+        if !value.is_finite() || value <= 0.0 {
+            return Err(D::Error::custom("size should be finite and positive"));
+        }
+        Ok(Size(value))
+    }
+}
 impl PartialEq for Size {
     fn eq(&self, other: &Self) -> bool {
         equal(self.0, other.0)
@@ -596,6 +739,33 @@ impl Color {
             (255.0 * (b + m)).round() as u8,
         )
     }
+    /// Converts sRGB-encoded component to linear light value.
+    ///
+    fn srgb_to_linear(component: u8) -> f32 {
+        let c: f32 = f32::from(component) / 255.0;
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    }
+    /// Converts linear light value to sRGB-encoded component.
+    ///
+    /// Value is clamped to [0.0; 1.0] range.
+    ///
+    fn linear_to_srgb(value: f32) -> u8 {
+        let value: f32 = if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(0.0, 1.0)
+        };
+        let c: f32 = if value <= 0.003_130_8 {
+            value * 12.92
+        } else {
+            1.055 * value.powf(1.0 / 2.4) - 0.055
+        };
+        (255.0 * c).round() as u8
+    }
     /// Performs RGB conversion to hue angle value.
     ///
     fn hue_from_rgb(min: f32, max: f32, r: f32, g: f32, b: f32) -> (f32, Angle) {
@@ -789,4 +959,304 @@ impl Color {
 
         (h, s, l, self.a)
     }
+
+    /// Initializes `Color` from hex string.
+    ///
+    /// Supported formats are `#rgb`, `#rgba`, `#rrggbb` and `#rrggbbaa` (`#` is optional, case is ignored).
+    /// If alpha channel is omitted, it is set to 255.
+    /// If string is not in one of those formats, `None` is returned.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ggengine::mathcore::Color;
+    /// assert_eq!(Color::from_hex("#ff8000"), Some(Color::from_rgba(255, 128, 0, 255)));
+    /// assert_eq!(Color::from_hex("FF800080"), Some(Color::from_rgba(255, 128, 0, 128)));
+    /// assert_eq!(Color::from_hex("#f80"), Some(Color::from_rgba(255, 136, 0, 255)));
+    /// assert_eq!(Color::from_hex("#f808"), Some(Color::from_rgba(255, 136, 0, 136)));
+    /// assert_eq!(Color::from_hex("#ff80"), Some(Color::from_rgba(255, 255, 136, 0)));
+    /// assert_eq!(Color::from_hex("#gg0000"), None);
+    /// assert_eq!(Color::from_hex("#12345"), None);
+    /// ```
+    ///
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let digits: &str = hex.strip_prefix('#').unwrap_or(hex);
+        if !digits.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let mut components: [u8; 4] = [255; 4];
+        match digits.len() {
+            3 | 4 => {
+                for (component, digit) in components.iter_mut().zip(digits.chars()) {
+                    // `#f80` is a shorthand for `#ff8800`
+                    *component = u8::from_str_radix(&digit.to_string(), 16).ok()? * 17;
+                }
+            }
+            6 | 8 => {
+                for (i, component) in components.iter_mut().take(digits.len() / 2).enumerate() {
+                    *component = u8::from_str_radix(&digits[(i * 2)..(i * 2 + 2)], 16).ok()?;
+                }
+            }
+            _ => return None,
+        }
+
+        let [r, g, b, a] = components;
+        Some(Color { r, g, b, a })
+    }
+    /// Returns hex string of color in `#rrggbbaa` format.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ggengine::mathcore::Color;
+    /// assert_eq!(Color::from_rgba(255, 128, 0, 10).to_hex(), "#ff80000a");
+    /// assert_eq!(Color::from_hex(&Color::RED.to_hex()), Some(Color::RED));
+    /// ```
+    ///
+    pub fn to_hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}{:02x}", self.r, self.g, self.b, self.a)
+    }
+
+    /// Returns copy of color with given alpha channel value.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ggengine::mathcore::Color;
+    /// assert_eq!(Color::RED.with_alpha(100), Color::from_rgba(255, 0, 0, 100));
+    /// ```
+    ///
+    pub fn with_alpha(self, a: u8) -> Self {
+        Color { a, ..self }
+    }
+    /// Linearly interpolates between two colors component-wise.
+    ///
+    /// `t` will be clamped between [0.0; 1.0]; `t = 0.0` returns `self` and `t = 1.0` returns `target`.
+    /// Interpolation is performed on sRGB-encoded values; use [`Color::lerp_oklab`]
+    /// if perceptually smooth transition is needed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ggengine::mathcore::Color;
+    /// let (from, to): (Color, Color) = (Color::from_rgba(0, 0, 0, 0), Color::from_rgba(255, 100, 50, 255));
+    /// assert_eq!(from.lerp(to, 0.0), from);
+    /// assert_eq!(from.lerp(to, 0.5), Color::from_rgba(128, 50, 25, 128));
+    /// assert_eq!(from.lerp(to, 2.0), to);
+    /// ```
+    ///
+    pub fn lerp(self, target: Color, t: f32) -> Self {
+        let t: f32 = t.clamp(0.0, 1.0);
+        let lerp = |a: u8, b: u8| -> u8 {
+            (f32::from(a) + (f32::from(b) - f32::from(a)) * t).round() as u8
+        };
+        Color {
+            r: lerp(self.r, target.r),
+            g: lerp(self.g, target.g),
+            b: lerp(self.b, target.b),
+            a: lerp(self.a, target.a),
+        }
+    }
+    /// Interpolates between two colors in OKLab color space.
+    ///
+    /// OKLab is perceptually uniform, so such interpolation does not produce
+    /// muddy or overly dark intermediate colors (which component-wise interpolation of sRGB does).
+    /// `t` will be clamped between [0.0; 1.0]; alpha channel is interpolated linearly.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ggengine::mathcore::Color;
+    /// assert_eq!(Color::RED.lerp_oklab(Color::BLUE, 0.0), Color::RED);
+    /// assert_eq!(Color::RED.lerp_oklab(Color::BLUE, 1.0), Color::BLUE);
+    ///
+    /// let middle: Color = Color::RED.lerp_oklab(Color::GREEN, 0.5);
+    /// // sRGB interpolation would give dark (128, 128, 0) color
+    /// assert!(middle.r > 128 && middle.g > 128);
+    /// ```
+    ///
+    pub fn lerp_oklab(self, target: Color, t: f32) -> Self {
+        let t: f32 = t.clamp(0.0, 1.0);
+        let lerp = |a: f32, b: f32| -> f32 { a + (b - a) * t };
+
+        let (l1, a1, b1, alpha1): (f32, f32, f32, u8) = self.to_oklaba();
+        let (l2, a2, b2, alpha2): (f32, f32, f32, u8) = target.to_oklaba();
+        Color::from_oklaba(
+            lerp(l1, l2),
+            lerp(a1, a2),
+            lerp(b1, b2),
+            (lerp(f32::from(alpha1), f32::from(alpha2))).round() as u8,
+        )
+    }
+
+    /// Returns color with red, green and blue components multiplied by alpha channel.
+    ///
+    /// Premultiplied colors blend correctly with filtering and additive blending.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ggengine::mathcore::Color;
+    /// assert_eq!(Color::from_rgba(255, 100, 0, 128).premultiplied(), Color::from_rgba(128, 50, 0, 128));
+    /// assert_eq!(Color::RED.premultiplied(), Color::RED);
+    /// ```
+    ///
+    pub fn premultiplied(self) -> Self {
+        let multiply = |c: u8| -> u8 { (f32::from(c) * f32::from(self.a) / 255.0).round() as u8 };
+        Color {
+            r: multiply(self.r),
+            g: multiply(self.g),
+            b: multiply(self.b),
+            a: self.a,
+        }
+    }
+    /// Reverts premultiplication of a color (divides red, green and blue components by alpha channel).
+    ///
+    /// Fully transparent colors have no information about their components,
+    /// so they are turned into transparent black.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ggengine::mathcore::Color;
+    /// assert_eq!(Color::from_rgba(128, 50, 0, 128).unpremultiplied(), Color::from_rgba(255, 100, 0, 128));
+    /// assert_eq!(Color::from_rgba(10, 10, 10, 0).unpremultiplied(), Color::from_rgba(0, 0, 0, 0));
+    /// ```
+    ///
+    pub fn unpremultiplied(self) -> Self {
+        if self.a == 0 {
+            return Color::from_rgba(0, 0, 0, 0);
+        }
+        let divide = |c: u8| -> u8 {
+            (f32::from(c) * 255.0 / f32::from(self.a))
+                .round()
+                .min(255.0) as u8
+        };
+        Color {
+            r: divide(self.r),
+            g: divide(self.g),
+            b: divide(self.b),
+            a: self.a,
+        }
+    }
+
+    /// Initializes `Color` from linear RGB components (sRGB gamma is applied to them).
+    ///
+    /// `r`, `g` and `b` should be in [0.0; 1.0] range or else they would be clamped to that range.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ggengine::mathcore::Color;
+    /// assert_eq!(Color::from_linear_rgba(1.0, 0.0, 0.0, 255), Color::RED);
+    /// assert_eq!(Color::from_linear_rgba(0.2159, 0.2159, 0.2159, 255), Color::from_rgba(128, 128, 128, 255));
+    /// ```
+    ///
+    pub fn from_linear_rgba(r: f32, g: f32, b: f32, a: u8) -> Self {
+        Color {
+            r: Color::linear_to_srgb(r),
+            g: Color::linear_to_srgb(g),
+            b: Color::linear_to_srgb(b),
+            a,
+        }
+    }
+    /// Returns tuple of linear red, green, blue components and alpha channel that corresponds to this color.
+    ///
+    /// Linear values are proportional to light intensity, so they should be used for
+    /// lighting and blending calculations.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ggengine::mathcore::Color;
+    /// # use ggengine::mathcore::floats::FloatOperations;
+    /// let (r, g, b, a): (f32, f32, f32, u8) = Color::from_rgba(255, 128, 0, 255).to_linear_rgba();
+    /// assert_eq!((r, g.round_up_to(3), b, a), (1.0, 0.216, 0.0, 255));
+    /// ```
+    ///
+    pub fn to_linear_rgba(self) -> (f32, f32, f32, u8) {
+        (
+            Color::srgb_to_linear(self.r),
+            Color::srgb_to_linear(self.g),
+            Color::srgb_to_linear(self.b),
+            self.a,
+        )
+    }
+    /// Initializes `Color` from OKLab color space.
+    ///
+    /// `l` is perceived lightness in [0.0; 1.0] range, `a` and `b` are green-red and blue-yellow axes.
+    /// Colors that lie outside of sRGB gamut are clamped.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ggengine::mathcore::Color;
+    /// assert_eq!(Color::from_oklaba(1.0, 0.0, 0.0, 255), Color::from_rgba(255, 255, 255, 255));
+    /// assert_eq!(Color::from_oklaba(0.0, 0.0, 0.0, 255), Color::from_rgba(0, 0, 0, 255));
+    /// ```
+    ///
+    pub fn from_oklaba(l: f32, a: f32, b: f32, alpha: u8) -> Self {
+        let l_: f32 = l + 0.396_337_78 * a + 0.215_803_76 * b;
+        let m_: f32 = l - 0.105_561_346 * a - 0.063_854_17 * b;
+        let s_: f32 = l - 0.089_484_18 * a - 1.291_485_5 * b;
+        let (l, m, s): (f32, f32, f32) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+
+        Color::from_linear_rgba(
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+            alpha,
+        )
+    }
+    /// Returns tuple of OKLab lightness, green-red axis, blue-yellow axis and alpha channel that
+    /// corresponds to this color.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ggengine::mathcore::Color;
+    /// # use ggengine::mathcore::floats::FloatOperations;
+    /// let (l, a, b, alpha): (f32, f32, f32, u8) = Color::RED.to_oklaba();
+    /// assert_eq!((l.round_up_to(3), a.round_up_to(3), b.round_up_to(3), alpha), (0.628, 0.225, 0.126, 255));
+    /// assert_eq!(Color::from_oklaba(l, a, b, alpha), Color::RED);
+    /// ```
+    ///
+    pub fn to_oklaba(self) -> (f32, f32, f32, u8) {
+        let (r, g, b, alpha): (f32, f32, f32, u8) = self.to_linear_rgba();
+
+        let l: f32 = (0.412_221_46 * r + 0.536_332_5 * g + 0.051_445_995 * b).cbrt();
+        let m: f32 = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s: f32 = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+        (
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+            alpha,
+        )
+    }
+    /// Initializes `Color` from OKLCH color space (polar form of OKLab).
+    ///
+    /// `l` is perceived lightness in [0.0; 1.0] range, `c` is chroma and `h` is hue angle.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ggengine::mathcore::Color;
+    /// let (l, c, h, a) = Color::BLUE.to_oklcha();
+    /// assert_eq!(Color::from_oklcha(l, c, h, a), Color::BLUE);
+    /// ```
+    ///
+    pub fn from_oklcha(l: f32, c: f32, h: Angle, a: u8) -> Self {
+        let (sin, cos): (f32, f32) = h.sin_cos();
+        Color::from_oklaba(l, c.max(0.0) * cos, c.max(0.0) * sin, a)
+    }
+    /// Returns tuple of OKLCH lightness, chroma, hue and alpha channel that corresponds to this color.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use ggengine::mathcore::{Color, Angle};
+    /// # use ggengine::mathcore::floats::FloatOperations;
+    /// let (l, c, h, a): (f32, f32, Angle, u8) = Color::from_rgba(128, 128, 128, 255).to_oklcha();
+    /// assert_eq!((l.round_up_to(2), c.round_up_to(3), a), (0.6, 0.0, 255));
+    /// ```
+    ///
+    pub fn to_oklcha(self) -> (f32, f32, Angle, u8) {
+        let (l, a, b, alpha): (f32, f32, f32, u8) = self.to_oklaba();
+        (
+            l,
+            (a * a + b * b).sqrt(),
+            Angle::from_radians(b.atan2(a)),
+            alpha,
+        )
+    }
 }
@@ -1,5 +1,5 @@
 //! `mathcore::floats` submodule implements several consts, functions and traits that help in
-//! work with `f32` type.
+//! work with `f32` (and `f64`) types.
 //!
 //! [`equal`] function and [`EPSILON`] const are dealing with floating point equality.
 //!
@@ -31,6 +31,26 @@ pub fn equal(a: f32, b: f32) -> bool {
     diff < (norm * EPSILON).max(f32::MIN)
 }
 
+/// This function implements floating point equality for `f64` values.
+///
+/// It uses the same relative [`EPSILON`] as [`equal`].
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::floats::equal_f64;
+/// assert!(equal_f64(1_000_000.15 + 0.15, 1_000_000.1 + 0.2));
+/// ```
+///
+pub fn equal_f64(a: f64, b: f64) -> bool {
+    if a == b {
+        return true;
+    }
+
+    let diff: f64 = (a - b).abs();
+    let norm: f64 = (a.abs() + b.abs()).min(f64::MAX);
+    diff < (norm * f64::from(EPSILON)).max(f64::MIN)
+}
+
 /// Constant that is used in floating point correction.
 ///
 /// It defines the threshold for number to be considered small enough to then be floored.
@@ -117,6 +137,52 @@ impl FloatOperations for f32 {
         (self * mul).round() / mul
     }
 }
+impl FloatOperations for f64 {
+    /// Corrects distortions that may be caused by float operations.
+    ///
+    /// Works exactly like `f32` implementation.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::floats::FloatOperations;
+    /// assert_eq!(-0.0_f64.correct(0), 0.0);
+    /// assert_eq!(0.99999_f64.correct(0), 1.0);
+    /// assert_eq!(0.200009_f64.correct(1), 0.2);
+    /// ```
+    ///
+    fn correct(self, digits: i32) -> Self {
+        let mul: f64 = 10_f64.powi(digits);
+
+        let n: f64 = self * mul;
+
+        if n == -0.0 {
+            return 0.0;
+        }
+
+        let fract: f64 = n.abs().fract();
+        if !(f64::from(CLOSE_TO_ZERO)..=f64::from(CLOSE_TO_ONE)).contains(&fract) {
+            return n.round() / mul;
+        }
+
+        n / mul
+    }
+
+    /// Rounds to given amount of digits after floating point.
+    ///
+    /// Passing negative number shifts floating point to the left.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::floats::FloatOperations;
+    /// assert_eq!(12.345_f64.round_up_to(2), 12.35);
+    /// assert_eq!(12.345_f64.round_up_to(-1), 10.0);
+    /// ```
+    ///
+    fn round_up_to(self, digits: i32) -> Self {
+        let mul: f64 = 10_f64.powi(digits);
+        (self * mul).round() / mul
+    }
+}
 impl<T, const N: usize> FloatOperations for [T; N]
 where
     T: FloatOperations,
@@ -0,0 +1,270 @@
+//! `mathcore::gradients` submodule implements [`ColorGradient`] - serializable set of positioned color stops
+//! which is used to describe how color changes along some parameter (color of a particle over its lifetime,
+//! falloff of a light, background of UI element and so on).
+//!
+
+use crate::mathcore::Color;
+use serde::{Deserialize, Serialize};
+
+/// [`ColorSpace`] enum lists color spaces in which colors of a [`ColorGradient`] can be interpolated.
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColorSpace {
+    /// Components are interpolated as they are stored (see [`Color::lerp`]).
+    ///
+    /// That is the cheapest option, but intermediate colors may look muddy or dark.
+    ///
+    Srgb,
+    /// Components are interpolated in linear light (physically correct mixing of light).
+    ///
+    LinearRgb,
+    /// Colors are interpolated in perceptually uniform OKLab color space (see [`Color::lerp_oklab`]).
+    ///
+    #[default]
+    Oklab,
+}
+impl ColorSpace {
+    /// Interpolates between two colors in this color space.
+    ///
+    /// `t` will be clamped between [0.0; 1.0]; alpha channel is always interpolated linearly.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::gradients::ColorSpace;
+    /// # use ggengine::mathcore::Color;
+    /// assert_eq!(ColorSpace::Srgb.lerp(Color::RED, Color::GREEN, 0.5), Color::from_rgba(128, 128, 0, 255));
+    /// assert_eq!(ColorSpace::LinearRgb.lerp(Color::RED, Color::GREEN, 0.5), Color::from_rgba(188, 188, 0, 255));
+    /// ```
+    ///
+    pub fn lerp(self, from: Color, to: Color, t: f32) -> Color {
+        match self {
+            ColorSpace::Srgb => from.lerp(to, t),
+            ColorSpace::LinearRgb => {
+                let t: f32 = t.clamp(0.0, 1.0);
+                let lerp = |a: f32, b: f32| -> f32 { a + (b - a) * t };
+
+                let (r1, g1, b1, a1): (f32, f32, f32, u8) = from.to_linear_rgba();
+                let (r2, g2, b2, a2): (f32, f32, f32, u8) = to.to_linear_rgba();
+                Color::from_linear_rgba(
+                    lerp(r1, r2),
+                    lerp(g1, g2),
+                    lerp(b1, b2),
+                    lerp(f32::from(a1), f32::from(a2)).round() as u8,
+                )
+            }
+            ColorSpace::Oklab => from.lerp_oklab(to, t),
+        }
+    }
+}
+
+/// [`ColorStop`] struct is a color of a [`ColorGradient`] at given position.
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq)]
+pub struct ColorStop {
+    /// Position of the stop.
+    ///
+    pub position: f32,
+    /// Color of the gradient at the position of the stop.
+    ///
+    pub color: Color,
+}
+impl ColorStop {
+    /// Constructs color stop.
+    ///
+    pub fn new(position: f32, color: Color) -> Self {
+        ColorStop { position, color }
+    }
+}
+
+/// [`ColorGradient`] struct is a function that maps parameter to color by interpolating between positioned color stops.
+///
+/// Stops are always kept sorted by position; before the first stop and after the last stop gradient is constant.
+/// Gradient without stops evaluates to transparent black.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::gradients::{ColorGradient, ColorSpace, ColorStop};
+/// # use ggengine::mathcore::Color;
+/// // fire particle: yellow -> red -> transparent
+/// let fire: ColorGradient = ColorGradient::new(ColorSpace::Oklab)
+///     .with_stop(ColorStop::new(0.0, Color::YELLOW))
+///     .with_stop(ColorStop::new(0.2, Color::YELLOW))
+///     .with_stop(ColorStop::new(0.5, Color::RED))
+///     .with_stop(ColorStop::new(1.0, Color::RED.with_alpha(0)));
+/// assert_eq!(fire.evaluate(-1.0), Color::YELLOW);
+/// assert_eq!(fire.evaluate(0.1), Color::YELLOW);
+/// assert_eq!(fire.evaluate(0.2), Color::YELLOW);
+/// assert_eq!(fire.evaluate(0.75).a, 128);
+/// assert_eq!(fire.evaluate(1.0), Color::RED.with_alpha(0));
+/// ```
+///
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(from = "RawColorGradient")]
+pub struct ColorGradient {
+    /// Stops of the gradient, sorted by position.
+    ///
+    stops: Vec<ColorStop>,
+    /// Color space in which colors between stops are interpolated.
+    ///
+    pub color_space: ColorSpace,
+}
+impl ColorGradient {
+    /// Constructs gradient without stops that interpolates colors in given color space.
+    ///
+    pub fn new(color_space: ColorSpace) -> Self {
+        ColorGradient {
+            stops: Vec::new(),
+            color_space,
+        }
+    }
+    /// Constructs gradient that changes from `from` at `0.0` to `to` at `1.0` in OKLab color space.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::gradients::ColorGradient;
+    /// # use ggengine::mathcore::Color;
+    /// let fade: ColorGradient = ColorGradient::between(Color::BLUE, Color::BLUE.with_alpha(0));
+    /// assert_eq!(fade.evaluate(0.5), Color::BLUE.with_alpha(128));
+    /// ```
+    ///
+    pub fn between(from: Color, to: Color) -> Self {
+        ColorGradient::new(ColorSpace::Oklab)
+            .with_stop(ColorStop::new(0.0, from))
+            .with_stop(ColorStop::new(1.0, to))
+    }
+
+    /// Adds stop to the gradient and returns the gradient.
+    ///
+    pub fn with_stop(mut self, stop: ColorStop) -> Self {
+        self.add_stop(stop);
+        self
+    }
+    /// Adds stop to the gradient.
+    ///
+    /// Stop is inserted after stops with the same position, so that gradient can have hard edges.
+    ///
+    pub fn add_stop(&mut self, stop: ColorStop) {
+        let index: usize = self
+            .stops
+            .partition_point(|other| other.position <= stop.position);
+        self.stops.insert(index, stop);
+    }
+    /// Removes stop at given index and returns it.
+    ///
+    /// Returns `None` if index is out of bounds.
+    ///
+    pub fn remove_stop(&mut self, index: usize) -> Option<ColorStop> {
+        if index < self.stops.len() {
+            Some(self.stops.remove(index))
+        } else {
+            None
+        }
+    }
+    /// Returns stops of the gradient, sorted by position.
+    ///
+    pub fn stops(&self) -> &[ColorStop] {
+        &self.stops
+    }
+
+    /// Evaluates gradient at given position.
+    ///
+    pub fn evaluate(&self, position: f32) -> Color {
+        let next: usize = self.stops.partition_point(|stop| stop.position <= position);
+        if next == 0 {
+            return self
+                .stops
+                .first()
+                .map_or(Color::from_rgba(0, 0, 0, 0), |stop| stop.color);
+        }
+        if next == self.stops.len() {
+            return self.stops[next - 1].color;
+        }
+
+        let (a, b): (ColorStop, ColorStop) = (self.stops[next - 1], self.stops[next]);
+        self.color_space.lerp(
+            a.color,
+            b.color,
+            (position - a.position) / (b.position - a.position),
+        )
+    }
+}
+
+/// Raw representation of [`ColorGradient`] whose stops may be unsorted.
+///
+#[derive(Deserialize)]
+struct RawColorGradient {
+    /// Stops of the gradient.
+    ///
+    stops: Vec<ColorStop>,
+    /// Color space in which colors between stops are interpolated.
+    ///
+    #[serde(default)]
+    color_space: ColorSpace,
+}
+impl From<RawColorGradient> for ColorGradient {
+    /// Constructs gradient, sorting stops by position.
+    ///
+    fn from(raw: RawColorGradient) -> Self {
+        let mut stops: Vec<ColorStop> = raw.stops;
+        stops.sort_by(|a, b| a.position.total_cmp(&b.position));
+        ColorGradient {
+            stops,
+            color_space: raw.color_space,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ColorGradient, ColorSpace, ColorStop};
+    use crate::mathcore::Color;
+
+    #[test]
+    fn evaluation() {
+        assert_eq!(
+            ColorGradient::default().evaluate(0.5),
+            Color::from_rgba(0, 0, 0, 0)
+        );
+
+        let mut hard_edge: ColorGradient = ColorGradient::new(ColorSpace::Srgb)
+            .with_stop(ColorStop::new(1.0, Color::BLUE))
+            .with_stop(ColorStop::new(0.0, Color::CYAN));
+        hard_edge.add_stop(ColorStop::new(0.5, Color::MAGENTA));
+        hard_edge.add_stop(ColorStop::new(0.5, Color::RED));
+        assert_eq!(hard_edge.stops()[0].color, Color::CYAN);
+        assert_eq!(
+            hard_edge.evaluate(0.25),
+            Color::from_rgba(128, 128, 255, 255)
+        );
+        assert_eq!(hard_edge.evaluate(0.5), Color::RED);
+        assert_eq!(hard_edge.evaluate(2.0), Color::BLUE);
+        assert_eq!(
+            hard_edge.remove_stop(1),
+            Some(ColorStop::new(0.5, Color::MAGENTA))
+        );
+
+        // perceptual interpolation does not darken the middle
+        let gradient: ColorGradient = ColorGradient::between(Color::RED, Color::GREEN);
+        let middle: Color = gradient.evaluate(0.5);
+        assert!(middle.r > 128 && middle.g > 128);
+    }
+
+    #[test]
+    fn serialization() {
+        let gradient: ColorGradient = serde_json::from_str(
+            r#"{"stops": [
+                {"position": 1.0, "color": {"r": 0, "g": 0, "b": 0, "a": 255}},
+                {"position": 0.0, "color": {"r": 255, "g": 255, "b": 255, "a": 255}}
+            ]}"#,
+        )
+        .expect("Gradient is valid");
+        assert_eq!(gradient.color_space, ColorSpace::Oklab);
+        assert_eq!(gradient.evaluate(0.0), Color::from_rgba(255, 255, 255, 255));
+
+        let json: String = serde_json::to_string(&gradient).expect("Gradient is serializable");
+        assert_eq!(
+            serde_json::from_str::<ColorGradient>(&json).expect("Gradient is valid"),
+            gradient
+        );
+    }
+}
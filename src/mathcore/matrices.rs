@@ -5,7 +5,7 @@
 use crate::mathcore::{
     floats::{equal, FloatOperations},
     vectors::Vector2,
-    Sign,
+    Angle, Sign,
 };
 use serde::{Deserialize, Serialize};
 use serde_big_array::Array;
@@ -76,6 +76,45 @@ impl<const ROWS: usize, const COLUMNS: usize> Matrix<ROWS, COLUMNS> {
         }
         arr
     }
+    /// Returns view of matrix row without copying it.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::matrices::Matrix;
+    /// let matrix: Matrix<2, 3> = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// assert_eq!(matrix.row(1), &[4.0, 5.0, 6.0]);
+    /// ```
+    ///
+    pub fn row(&self, row: usize) -> &[f32; COLUMNS] {
+        &self.arr[row]
+    }
+    /// Returns mutable view of matrix row without copying it.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::matrices::Matrix;
+    /// let mut matrix: Matrix<2, 3> = Matrix::zero();
+    /// matrix.row_mut(0).copy_from_slice(&[1.0, 2.0, 3.0]);
+    /// assert_eq!(matrix.as_array(), [[1.0, 2.0, 3.0], [0.0; 3]]);
+    /// ```
+    ///
+    pub fn row_mut(&mut self, row: usize) -> &mut [f32; COLUMNS] {
+        &mut self.arr[row]
+    }
+    /// Returns matrix column.
+    ///
+    /// Columns are not contiguous in memory, so they are copied into an array.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::matrices::Matrix;
+    /// let matrix: Matrix<2, 3> = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// assert_eq!(matrix.column(2), [3.0, 6.0]);
+    /// ```
+    ///
+    pub fn column(&self, column: usize) -> [f32; ROWS] {
+        std::array::from_fn(|r| self[r][column])
+    }
 
     /// Initializes matrix with zeroes.
     ///
@@ -186,47 +225,6 @@ impl<const ROWS: usize, const COLUMNS: usize> Matrix<ROWS, COLUMNS> {
         matrix
     }
 
-    /// `internal_rref` operates with `Vec<Vec<f32>>`.
-    ///
-    fn internal_rref(vec: Vec<Vec<f32>>) -> Vec<Vec<f32>> {
-        let mut matrix: Vec<Vec<f32>> = vec;
-        if matrix.is_empty() {
-            return matrix;
-        }
-        let (rows, columns): (usize, usize) = (matrix.len(), matrix[0].len());
-
-        if matrix[0][0] == 0.0 {
-            let mut row_i: usize = 0;
-            for (r, row) in matrix.iter().enumerate().take(rows) {
-                if row[0] > 0.0 {
-                    row_i = r;
-                    break;
-                }
-            }
-            for c in 0..columns {
-                (matrix[row_i][c], matrix[0][c]) = (matrix[0][c], matrix[row_i][c]);
-            }
-        }
-        let mut lead: usize = 0;
-        while lead < rows {
-            for r in 0..rows {
-                let div: f32 = matrix[lead][lead];
-                let mult: f32 = matrix[r][lead] / div;
-                if r == lead {
-                    for c in 0..columns {
-                        matrix[lead][c] /= div;
-                    }
-                } else {
-                    for c in 0..columns {
-                        matrix[r][c] -= matrix[lead][c] * mult;
-                    }
-                }
-            }
-            lead += 1;
-        }
-        matrix
-    }
-
     /// Returns reduced row echelon form of initial matrix.
     ///
     /// # Example
@@ -250,15 +248,59 @@ impl<const ROWS: usize, const COLUMNS: usize> Matrix<ROWS, COLUMNS> {
     ///
     pub fn rref(&self) -> Matrix<ROWS, COLUMNS> {
         let mut matrix: Matrix<ROWS, COLUMNS> = *self;
-        let mut m: Vec<Vec<f32>> = vec![vec![0.0; COLUMNS]; ROWS];
-        for (r, row) in m.iter_mut().enumerate().take(ROWS) {
-            row[..COLUMNS].copy_from_slice(&matrix[r][..COLUMNS]);
+        matrix.rref_in_place();
+        matrix
+    }
+    /// Transforms matrix into its reduced row echelon form in place.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::matrices::Matrix;
+    /// let mut matrix: Matrix<2, 3> = Matrix::from([[2.0, 0.0, 4.0], [0.0, 4.0, 2.0]]);
+    /// matrix.rref_in_place();
+    /// assert_eq!(matrix.as_array(), [[1.0, 0.0, 2.0], [0.0, 1.0, 0.5]]);
+    /// ```
+    ///
+    pub fn rref_in_place(&mut self) {
+        let mut lead: usize = 0;
+        for c in 0..COLUMNS {
+            if lead == ROWS {
+                break;
+            }
+            let pivot: usize = Self::pivot_row(self, lead, c);
+            if self[pivot][c] == 0.0 {
+                continue;
+            }
+            self.arr.swap(pivot, lead);
+
+            let div: f32 = self[lead][c];
+            for k in 0..COLUMNS {
+                self[lead][k] /= div;
+            }
+            for r in 0..ROWS {
+                let mult: f32 = self[r][c];
+                if r == lead || mult == 0.0 {
+                    continue;
+                }
+                for k in 0..COLUMNS {
+                    self[r][k] -= self[lead][k] * mult;
+                }
+            }
+            lead += 1;
         }
-        m = Self::internal_rref(m);
-        for (r, row) in m.iter().enumerate().take(ROWS) {
-            matrix[r][..COLUMNS].copy_from_slice(&row[..COLUMNS]);
+    }
+    /// Returns index of a row (starting from `from` row) that has the biggest absolute value in given column.
+    ///
+    /// Choosing such row as a pivot makes Gauss-Jordan elimination numerically stable.
+    ///
+    fn pivot_row(matrix: &Matrix<ROWS, COLUMNS>, from: usize, column: usize) -> usize {
+        let mut pivot: usize = from;
+        for r in (from + 1)..ROWS {
+            if matrix[r][column].abs() > matrix[pivot][column].abs() {
+                pivot = r;
+            }
         }
-        matrix
+        pivot
     }
 
     /// Performs dot product operation on two matrices.
@@ -276,6 +318,17 @@ impl<const ROWS: usize, const COLUMNS: usize> Matrix<ROWS, COLUMNS> {
         other: Matrix<COLUMNS, RHS_COLUMNS>,
     ) -> Matrix<ROWS, RHS_COLUMNS> {
         let mut matrix: Matrix<ROWS, RHS_COLUMNS> = Matrix::zero();
+        if ROWS == 3 && COLUMNS == 3 && RHS_COLUMNS == 3 {
+            // transform composition is the hottest path, so it has its own implementation
+            let product: [[f32; 3]; 3] = matmul3x3(
+                &std::array::from_fn(|r| std::array::from_fn(|c| self[r][c])),
+                &std::array::from_fn(|r| std::array::from_fn(|c| other[r][c])),
+            );
+            for (r, row) in product.iter().enumerate() {
+                matrix[r][..3].copy_from_slice(row);
+            }
+            return matrix;
+        }
         for r in 0..ROWS {
             for c in 0..RHS_COLUMNS {
                 let mut res: f32 = 0.0;
@@ -376,7 +429,8 @@ impl<const N: usize> Matrix<N, N> {
 
     /// Returns determinant of initial matrix.
     ///
-    /// Calculates determinant of a square matrix using echelon form of initial matrix. Product of
+    /// Determinants of matrices up to 3x3 are calculated by closed-form formulas.
+    /// Bigger matrices use echelon form of initial matrix - product of
     /// its diagonal and the sign is equal to determinant.
     ///
     /// # Examples
@@ -401,18 +455,30 @@ impl<const N: usize> Matrix<N, N> {
     /// ```
     ///
     pub fn determinant(&self) -> f32 {
-        if N == 0 {
-            return 0.0;
-        }
-        let (ef, sign): (Matrix<N, N>, Sign) = self.internal_echelon_form();
-        let mut product: f32 = 1.0;
-        for i in 0..N {
-            product *= ef[i][i];
+        match N {
+            0 => 0.0,
+            1 => self[0][0],
+            2 => self[0][0] * self[1][1] - self[0][1] * self[1][0],
+            3 => {
+                self[0][0] * (self[1][1] * self[2][2] - self[1][2] * self[2][1])
+                    - self[0][1] * (self[1][0] * self[2][2] - self[1][2] * self[2][0])
+                    + self[0][2] * (self[1][0] * self[2][1] - self[1][1] * self[2][0])
+            }
+            _ => {
+                let (ef, sign): (Matrix<N, N>, Sign) = self.internal_echelon_form();
+                let mut product: f32 = 1.0;
+                for i in 0..N {
+                    product *= ef[i][i];
+                }
+                product * f32::from(sign as i8)
+            }
         }
-        product * f32::from(sign as i8)
     }
     /// Returns inverse of an initial matrix
     ///
+    /// 2x2 and 3x3 matrices are inverted by using closed-form formulas (adjugate matrix divided by determinant),
+    /// bigger matrices are inverted by Gauss-Jordan elimination.
+    ///
     /// # Examples
     /// ```rust
     /// # use ggengine::mathcore::matrices::Matrix;
@@ -446,20 +512,96 @@ impl<const N: usize> Matrix<N, N> {
     /// ```
     ///
     pub fn inverse(&self) -> Option<Matrix<N, N>> {
-        if self.determinant() == 0.0 {
-            return None;
+        let mut matrix: Matrix<N, N> = *self;
+        if matrix.invert() {
+            Some(matrix)
+        } else {
+            None
+        }
+    }
+    /// Inverts matrix in place.
+    ///
+    /// Returns `false` and leaves matrix unchanged if it is not invertible.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::matrices::Matrix;
+    /// let mut matrix: Matrix<2, 2> = Matrix::from([[2.0, 0.0], [0.0, 4.0]]);
+    /// assert!(matrix.invert());
+    /// assert_eq!(matrix.as_array(), [[0.5, 0.0], [0.0, 0.25]]);
+    ///
+    /// let mut singular: Matrix<2, 2> = Matrix::from([[1.0, 2.0], [2.0, 4.0]]);
+    /// assert!(!singular.invert());
+    /// assert_eq!(singular.as_array(), [[1.0, 2.0], [2.0, 4.0]]);
+    /// ```
+    ///
+    pub fn invert(&mut self) -> bool {
+        if N == 0 {
+            return false;
+        }
+        if N > 3 {
+            return self.gauss_jordan_invert();
         }
-        let (m, mut i): (Matrix<N, N>, Matrix<N, N>) = (*self, Matrix::identity());
-        let mut vec: Vec<Vec<f32>> = vec![vec![0.0; N * 2]; N];
-        for (r, row) in vec.iter_mut().enumerate().take(N) {
-            row[..N].copy_from_slice(&m[r][..N]);
-            row[N..(N + N)].copy_from_slice(&i[r][..N]);
+
+        let determinant: f32 = self.determinant();
+        if determinant == 0.0 {
+            return false;
+        }
+        let mut adjugate: Matrix<N, N> = Matrix::zero();
+        match N {
+            1 => adjugate[0][0] = 1.0,
+            2 => {
+                adjugate[0][0] = self[1][1];
+                adjugate[0][1] = -self[0][1];
+                adjugate[1][0] = -self[1][0];
+                adjugate[1][1] = self[0][0];
+            }
+            _ => {
+                for r in 0..3 {
+                    for c in 0..3 {
+                        // cofactor of (c, r) element, cyclic indices take care of the sign
+                        let (r1, r2, c1, c2): (usize, usize, usize, usize) =
+                            ((c + 1) % 3, (c + 2) % 3, (r + 1) % 3, (r + 2) % 3);
+                        adjugate[r][c] = self[r1][c1] * self[r2][c2] - self[r1][c2] * self[r2][c1];
+                    }
+                }
+            }
         }
-        let rref: Vec<Vec<f32>> = Matrix::<N, N>::internal_rref(vec);
-        for (r, row) in rref.iter().enumerate().take(N) {
-            i[r][..N].copy_from_slice(&row[N..(N + N)]);
+        *self = adjugate / determinant;
+        true
+    }
+    /// Inverts matrix in place by Gauss-Jordan elimination with partial pivoting.
+    ///
+    /// Returns `false` and leaves matrix unchanged if it is not invertible.
+    ///
+    fn gauss_jordan_invert(&mut self) -> bool {
+        let (mut matrix, mut inverse): (Matrix<N, N>, Matrix<N, N>) = (*self, Matrix::identity());
+        for c in 0..N {
+            let pivot: usize = Self::pivot_row(&matrix, c, c);
+            if matrix[pivot][c] == 0.0 {
+                return false;
+            }
+            matrix.arr.swap(pivot, c);
+            inverse.arr.swap(pivot, c);
+
+            let div: f32 = matrix[c][c];
+            for k in 0..N {
+                matrix[c][k] /= div;
+                inverse[c][k] /= div;
+            }
+            for r in 0..N {
+                let mult: f32 = matrix[r][c];
+                if r == c || mult == 0.0 {
+                    continue;
+                }
+                for k in 0..N {
+                    matrix[r][k] -= matrix[c][k] * mult;
+                    inverse[r][k] -= inverse[c][k] * mult;
+                }
+            }
         }
-        Some(i)
+        *self = inverse;
+        true
     }
 }
 impl<const ROWS: usize, const COLUMNS: usize> FloatOperations for Matrix<ROWS, COLUMNS> {
@@ -681,6 +823,45 @@ impl<const ROWS: usize, const COLUMNS: usize> From<[[f32; COLUMNS]; ROWS]>
     }
 }
 
+/// Multiplies two 3x3 matrices that are represented by arrays.
+///
+/// If `simd` feature is enabled and target architecture is `x86_64`, rows of a product are
+/// calculated by SSE instructions (those are always available on `x86_64`).
+/// Both implementations perform operations in the same order, so their results are identical.
+///
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+fn matmul3x3(lhs: &[[f32; 3]; 3], rhs: &[[f32; 3]; 3]) -> [[f32; 3]; 3] {
+    use std::arch::x86_64::{
+        __m128, _mm_add_ps, _mm_mul_ps, _mm_set1_ps, _mm_set_ps, _mm_storeu_ps,
+    };
+
+    // SAFETY: SSE is a part of `x86_64` baseline, so those intrinsics are always supported;
+    // `lanes` has room for 4 values and `_mm_storeu_ps` does not require aligned pointer.
+    unsafe {
+        let rhs_rows: [__m128; 3] = rhs.map(|row| _mm_set_ps(0.0, row[2], row[1], row[0]));
+        lhs.map(|row| {
+            let sum: __m128 = _mm_add_ps(
+                _mm_add_ps(
+                    _mm_mul_ps(_mm_set1_ps(row[0]), rhs_rows[0]),
+                    _mm_mul_ps(_mm_set1_ps(row[1]), rhs_rows[1]),
+                ),
+                _mm_mul_ps(_mm_set1_ps(row[2]), rhs_rows[2]),
+            );
+            let mut lanes: [f32; 4] = [0.0; 4];
+            _mm_storeu_ps(lanes.as_mut_ptr(), sum);
+            [lanes[0], lanes[1], lanes[2]]
+        })
+    }
+}
+/// Multiplies two 3x3 matrices that are represented by arrays.
+///
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+fn matmul3x3(lhs: &[[f32; 3]; 3], rhs: &[[f32; 3]; 3]) -> [[f32; 3]; 3] {
+    lhs.map(|row| {
+        std::array::from_fn(|c| row[0] * rhs[0][c] + row[1] * rhs[1][c] + row[2] * rhs[2][c])
+    })
+}
+
 /// Type alias for 3x1 [`Matrix`] (is used to represent two-dimensional vector).
 ///
 pub type Matrix3x1 = Matrix<3, 1>;
@@ -738,10 +919,149 @@ impl From<Matrix3x1> for Vector2 {
 ///
 pub type Matrix3x3 = Matrix<3, 3>;
 impl Matrix3x3 {
+    /// Constructs matrix that translates vectors by given offset.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::matrices::Matrix3x3;
+    /// # use ggengine::mathcore::vectors::Vector2;
+    /// let matrix: Matrix3x3 = Matrix3x3::translation(Vector2::from([2.0, 3.0]));
+    /// assert_eq!(matrix.apply_to(Vector2::from([1.0, 1.0])), Vector2::from([3.0, 4.0]));
+    /// ```
+    ///
+    pub fn translation(offset: Vector2) -> Self {
+        Matrix::from([[1.0, 0.0, offset.x], [0.0, 1.0, offset.y], [0.0, 0.0, 1.0]])
+    }
+    /// Constructs matrix that rotates vectors counterclockwise by given angle.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::matrices::Matrix3x3;
+    /// # use ggengine::mathcore::vectors::Vector2;
+    /// # use ggengine::mathcore::floats::FloatOperations;
+    /// # use ggengine::mathcore::Angle;
+    /// let matrix: Matrix3x3 = Matrix3x3::rotation(Angle::DEG90);
+    /// assert_eq!(
+    ///     matrix.apply_to(Vector2::from([0.0, 2.0])).round_up_to(3),
+    ///     Vector2::from([-2.0, 0.0])
+    /// );
+    /// ```
+    ///
+    pub fn rotation(angle: Angle) -> Self {
+        let (sin, cos): (f32, f32) = angle.sin_cos();
+        Matrix::from([[cos, -sin, 0.0], [sin, cos, 0.0], [0.0, 0.0, 1.0]])
+    }
+    /// Constructs matrix that scales vectors component-wise.
+    ///
+    /// Negative factors reflect vectors along corresponding axis.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::matrices::Matrix3x3;
+    /// # use ggengine::mathcore::vectors::Vector2;
+    /// let matrix: Matrix3x3 = Matrix3x3::scaling(Vector2::from([3.0, -2.0]));
+    /// assert_eq!(matrix.apply_to(Vector2::from([2.0, 2.0])), Vector2::from([6.0, -4.0]));
+    /// ```
+    ///
+    pub fn scaling(scale: Vector2) -> Self {
+        Matrix::from([[scale.x, 0.0, 0.0], [0.0, scale.y, 0.0], [0.0, 0.0, 1.0]])
+    }
+    /// Constructs matrix that shears vectors.
+    ///
+    /// `shear.x` shifts x coordinate proportionally to y (`x' = x + shear.x * y`),
+    /// `shear.y` shifts y coordinate proportionally to x (`y' = y + shear.y * x`).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::matrices::Matrix3x3;
+    /// # use ggengine::mathcore::vectors::Vector2;
+    /// let matrix: Matrix3x3 = Matrix3x3::shear(Vector2::from([0.5, 0.0]));
+    /// assert_eq!(matrix.apply_to(Vector2::from([1.0, 2.0])), Vector2::from([2.0, 2.0]));
+    /// ```
+    ///
+    pub fn shear(shear: Vector2) -> Self {
+        Matrix::from([[1.0, shear.x, 0.0], [shear.y, 1.0, 0.0], [0.0, 0.0, 1.0]])
+    }
+    /// Constructs matrix that is equal to `translation * rotation * scaling`
+    /// (vectors are scaled first, then rotated and then translated).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::matrices::Matrix3x3;
+    /// # use ggengine::mathcore::vectors::Vector2;
+    /// # use ggengine::mathcore::floats::FloatOperations;
+    /// # use ggengine::mathcore::Angle;
+    /// let (translation, rotation, scale): (Vector2, Angle, Vector2) =
+    ///     (Vector2::from([10.0, 0.0]), Angle::DEG90, Vector2::from([2.0, 1.0]));
+    /// let matrix: Matrix3x3 = Matrix3x3::trs(translation, rotation, scale);
+    /// assert_eq!(
+    ///     matrix.round_up_to(3),
+    ///     (Matrix3x3::translation(translation)
+    ///         * Matrix3x3::rotation(rotation)
+    ///         * Matrix3x3::scaling(scale))
+    ///     .round_up_to(3)
+    /// );
+    /// assert_eq!(
+    ///     matrix.apply_to(Vector2::from([1.0, 0.0])).round_up_to(3),
+    ///     Vector2::from([10.0, 2.0])
+    /// );
+    /// ```
+    ///
+    pub fn trs(translation: Vector2, rotation: Angle, scale: Vector2) -> Self {
+        let (sin, cos): (f32, f32) = rotation.sin_cos();
+        Matrix::from([
+            [scale.x * cos, -scale.y * sin, translation.x],
+            [scale.x * sin, scale.y * cos, translation.y],
+            [0.0, 0.0, 1.0],
+        ])
+    }
+    /// Decomposes matrix back into translation, rotation and scale,
+    /// so that `Matrix3x3::trs(translation, rotation, scale)` gives back the initial matrix.
+    ///
+    /// Reflection is represented by negative y scale.
+    /// Shear cannot be represented by those components, so decomposition of sheared matrix is only approximate.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::matrices::Matrix3x3;
+    /// # use ggengine::mathcore::vectors::Vector2;
+    /// # use ggengine::mathcore::floats::FloatOperations;
+    /// # use ggengine::mathcore::Angle;
+    /// let matrix: Matrix3x3 =
+    ///     Matrix3x3::trs(Vector2::from([1.0, 2.0]), Angle::from_degrees(30.0), Vector2::from([3.0, -4.0]));
+    /// let (translation, rotation, scale): (Vector2, Angle, Vector2) = matrix.decompose();
+    /// assert_eq!(translation, Vector2::from([1.0, 2.0]));
+    /// assert_eq!(rotation, Angle::from_degrees(30.0));
+    /// assert_eq!(scale.round_up_to(3), Vector2::from([3.0, -4.0]));
+    /// ```
+    ///
+    pub fn decompose(&self) -> (Vector2, Angle, Vector2) {
+        let translation: Vector2 = Vector2::from([self[0][2], self[1][2]]);
+        let x_axis: Vector2 = Vector2::from([self[0][0], self[1][0]]);
+        let scale_x: f32 = x_axis.magnitude();
+        if equal(scale_x, 0.0) {
+            return (
+                translation,
+                Angle::zero(),
+                Vector2::from([0.0, Vector2::from([self[0][1], self[1][1]]).magnitude()]),
+            );
+        }
+        let rotation: Angle = Angle::from_radians(x_axis.y.atan2(x_axis.x));
+        let determinant: f32 = self[0][0] * self[1][1] - self[0][1] * self[1][0];
+        (
+            translation,
+            rotation,
+            Vector2::from([scale_x, determinant / scale_x]),
+        )
+    }
+
     /// Transforms given vector by using dot product (shorthand for writing `Vector2::from(self * Matrix3x1::from(vector))`).
     ///
     pub fn apply_to(self, vector: Vector2) -> Vector2 {
-        Vector2::from(self * Matrix3x1::from(vector))
+        Vector2::from([
+            self[0][0] * vector.x + self[0][1] * vector.y + self[0][2],
+            self[1][0] * vector.x + self[1][1] * vector.y + self[1][2],
+        ])
     }
 }
 
@@ -780,4 +1100,77 @@ mod tests {
         m3 /= 2.0;
         assert_eq!(m3.as_array(), [[1.0, 2.0, 3.0]]);
     }
+
+    #[test]
+    fn transform_matrices() {
+        use super::Matrix3x3;
+        use crate::mathcore::{floats::FloatOperations, vectors::Vector2, Angle};
+
+        let matrix: Matrix3x3 = Matrix3x3::trs(
+            Vector2::from([-5.0, 7.0]),
+            Angle::from_degrees(200.0),
+            Vector2::from([0.5, 2.0]),
+        );
+        let (translation, rotation, scale): (Vector2, Angle, Vector2) = matrix.decompose();
+        assert_eq!(translation, Vector2::from([-5.0, 7.0]));
+        assert_eq!(rotation, Angle::from_degrees(200.0));
+        assert_eq!(scale.round_up_to(3), Vector2::from([0.5, 2.0]));
+        assert_eq!(
+            Matrix3x3::trs(translation, rotation, scale).round_up_to(3),
+            matrix.round_up_to(3)
+        );
+
+        let (_, _, reflected): (Vector2, Angle, Vector2) =
+            Matrix3x3::scaling(Vector2::from([-2.0, 3.0])).decompose();
+        assert_eq!(reflected.round_up_to(3), Vector2::from([2.0, -3.0]));
+
+        let sheared: Matrix3x3 = Matrix3x3::shear(Vector2::from([1.0, 0.0]));
+        assert_eq!(
+            sheared.apply_to(Vector2::from([0.0, 1.0])),
+            Vector2::from([1.0, 1.0])
+        );
+        assert_eq!(
+            (sheared * Matrix3x3::shear(Vector2::from([-1.0, 0.0]))),
+            Matrix3x3::identity()
+        );
+    }
+
+    #[test]
+    fn square_matrix() {
+        use crate::mathcore::floats::FloatOperations;
+
+        let m2: Matrix<2, 2> = Matrix::from([[4.0, 7.0], [2.0, 6.0]]);
+        assert_eq!(m2.determinant(), 10.0);
+        assert_eq!(
+            m2.inverse().expect("Determinant is not zero").as_array(),
+            [[0.6, -0.7], [-0.2, 0.4]]
+        );
+        assert!(Matrix::from([[1.0, 2.0], [2.0, 4.0]]).inverse().is_none());
+
+        let m3: Matrix<3, 3> = Matrix::from([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]);
+        let inverse: Matrix<3, 3> = m3.inverse().expect("Determinant is not zero");
+        assert_eq!((m3 * inverse).round_up_to(3), Matrix::identity());
+        assert_eq!((inverse * m3).round_up_to(3), Matrix::identity());
+
+        let m4: Matrix<4, 4> = Matrix::from([
+            [0.0, 2.0, 0.0, 1.0],
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 3.0, 0.0],
+            [0.0, 1.0, 0.0, 1.0],
+        ]);
+        assert_eq!(m4.determinant().round_up_to(3), -3.0);
+        let inverse: Matrix<4, 4> = m4.inverse().expect("Determinant is not zero");
+        assert_eq!((m4 * inverse).round_up_to(3), Matrix::identity());
+
+        let mut singular: Matrix<4, 4> = m4;
+        singular[3] = singular[0];
+        assert!(singular.inverse().is_none());
+
+        let mut inverted: Matrix<4, 4> = m4;
+        assert!(inverted.invert());
+        assert_eq!(inverted, inverse);
+        assert!(!singular.invert());
+        assert_eq!(singular.row(3), singular.row(0));
+        assert_eq!(m4.column(1), [2.0, 0.0, 0.0, 1.0]);
+    }
 }
@@ -0,0 +1,335 @@
+//! `mathcore::noise` submodule implements seedable gradient noise functions (Perlin and simplex noise)
+//! which are commonly used in procedural generation of terrains, textures and animations.
+//!
+
+use crate::mathcore::{
+    random::Rng,
+    vectors::{Point, Vector2},
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Amount of elements in permutation table (it is doubled to avoid index wrapping).
+///
+const PERMUTATION_SIZE: usize = 256;
+/// Gradients that are used by noise functions.
+///
+const GRADIENTS: [[f32; 2]; 8] = [
+    [1.0, 1.0],
+    [-1.0, 1.0],
+    [1.0, -1.0],
+    [-1.0, -1.0],
+    [1.0, 0.0],
+    [-1.0, 0.0],
+    [0.0, 1.0],
+    [0.0, -1.0],
+];
+
+/// Builds permutation table that is shuffled by using given seed.
+///
+fn permutation_table(seed: u64) -> [u8; PERMUTATION_SIZE * 2] {
+    let mut permutation: [u8; PERMUTATION_SIZE] = [0; PERMUTATION_SIZE];
+    permutation
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, value)| *value = i as u8);
+    Rng::new(seed).shuffle(&mut permutation);
+
+    let mut table: [u8; PERMUTATION_SIZE * 2] = [0; PERMUTATION_SIZE * 2];
+    for (i, value) in table.iter_mut().enumerate() {
+        *value = permutation[i % PERMUTATION_SIZE];
+    }
+    table
+}
+/// Returns dot product of pseudorandom gradient (chosen by hash) and given offset.
+///
+fn gradient_dot(hash: u8, offset: Vector2) -> f32 {
+    let [x, y] = GRADIENTS[usize::from(hash) % GRADIENTS.len()];
+    x * offset.x + y * offset.y
+}
+
+/// [`Noise`] trait defines two-dimensional coherent noise functions.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::noise::{Noise, Perlin};
+/// # use ggengine::mathcore::vectors::Point;
+/// let perlin: Perlin = Perlin::new(42);
+/// let value: f32 = perlin.fbm(Point::from([0.3, 1.7]), 4, 2.0, 0.5);
+/// assert!((-1.0..=1.0).contains(&value));
+/// assert_eq!(value, Perlin::new(42).fbm(Point::from([0.3, 1.7]), 4, 2.0, 0.5));
+/// ```
+///
+pub trait Noise {
+    /// Returns value of noise at given point.
+    ///
+    /// Returned value lies in `[-1.0; 1.0]`.
+    ///
+    fn get(&self, point: Point) -> f32;
+
+    /// Returns value of fractal Brownian motion at given point.
+    ///
+    /// fBm sums several octaves of noise, each next octave has its frequency multiplied by `lacunarity`
+    /// and its amplitude multiplied by `gain`. Result is normalized, so it lies in `[-1.0; 1.0]`.
+    /// If `octaves` is equal to zero, zero is returned.
+    ///
+    fn fbm(&self, point: Point, octaves: u32, lacunarity: f32, gain: f32) -> f32 {
+        let (mut sum, mut amplitude, mut frequency, mut total_amplitude): (f32, f32, f32, f32) =
+            (0.0, 1.0, 1.0, 0.0);
+        for _ in 0..octaves {
+            sum += amplitude * self.get(point * frequency);
+            total_amplitude += amplitude;
+
+            amplitude *= gain;
+            frequency *= lacunarity;
+        }
+
+        if total_amplitude == 0.0 {
+            0.0
+        } else {
+            (sum / total_amplitude).clamp(-1.0, 1.0)
+        }
+    }
+}
+
+/// [`Perlin`] struct implements seedable two-dimensional Perlin gradient noise.
+///
+/// Noise is equal to zero at every point with integral coordinates.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::noise::{Noise, Perlin};
+/// # use ggengine::mathcore::vectors::Point;
+/// let perlin: Perlin = Perlin::new(1);
+/// assert_eq!(perlin.get(Point::from([3.0, 5.0])), 0.0);
+/// assert_ne!(perlin.get(Point::from([3.5, 5.5])), 0.0);
+/// ```
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Perlin {
+    /// Seed that was used to build permutation table.
+    ///
+    seed: u64,
+    /// Permutation table which is used for hashing lattice points.
+    ///
+    permutation: [u8; PERMUTATION_SIZE * 2],
+}
+impl Perlin {
+    /// Constructs Perlin noise generator with given seed.
+    ///
+    pub fn new(seed: u64) -> Self {
+        Perlin {
+            seed,
+            permutation: permutation_table(seed),
+        }
+    }
+
+    /// Returns seed of noise generator.
+    ///
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+impl Serialize for Perlin {
+    /// Serializes only the seed, since permutation table is fully determined by it.
+    ///
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.seed.serialize(serializer)
+    }
+}
+impl<'de> Deserialize<'de> for Perlin {
+    /// Deserializes seed and rebuilds permutation table from it.
+    ///
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u64::deserialize(deserializer).map(Perlin::new)
+    }
+}
+impl Noise for Perlin {
+    fn get(&self, point: Point) -> f32 {
+        let fade = |t: f32| -> f32 { t * t * t * (t * (t * 6.0 - 15.0) + 10.0) };
+        let lerp = |a: f32, b: f32, t: f32| -> f32 { a + (b - a) * t };
+
+        let (floor_x, floor_y): (f32, f32) = (point.x.floor(), point.y.floor());
+        let (x, y): (usize, usize) = (
+            (floor_x as i64).rem_euclid(PERMUTATION_SIZE as i64) as usize,
+            (floor_y as i64).rem_euclid(PERMUTATION_SIZE as i64) as usize,
+        );
+        let offset: Vector2 = Vector2::from([point.x - floor_x, point.y - floor_y]);
+        let (u, v): (f32, f32) = (fade(offset.x), fade(offset.y));
+
+        let p: &[u8; PERMUTATION_SIZE * 2] = &self.permutation;
+        let (a, b): (usize, usize) = (usize::from(p[x]) + y, usize::from(p[x + 1]) + y);
+
+        let value: f32 = lerp(
+            lerp(
+                gradient_dot(p[a], offset),
+                gradient_dot(p[b], offset - Vector2::from([1.0, 0.0])),
+                u,
+            ),
+            lerp(
+                gradient_dot(p[a + 1], offset - Vector2::from([0.0, 1.0])),
+                gradient_dot(p[b + 1], offset - Vector2::from([1.0, 1.0])),
+                u,
+            ),
+            v,
+        );
+        value.clamp(-1.0, 1.0)
+    }
+}
+
+/// [`Simplex`] struct implements seedable two-dimensional simplex noise.
+///
+/// Simplex noise has fewer directional artifacts than Perlin noise and is cheaper to compute.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::noise::{Noise, Simplex};
+/// # use ggengine::mathcore::vectors::Point;
+/// let simplex: Simplex = Simplex::new(7);
+/// let value: f32 = simplex.get(Point::from([0.25, 0.75]));
+/// assert!((-1.0..=1.0).contains(&value));
+/// ```
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Simplex {
+    /// Seed that was used to build permutation table.
+    ///
+    seed: u64,
+    /// Permutation table which is used for hashing lattice points.
+    ///
+    permutation: [u8; PERMUTATION_SIZE * 2],
+}
+impl Simplex {
+    /// Constructs simplex noise generator with given seed.
+    ///
+    pub fn new(seed: u64) -> Self {
+        Simplex {
+            seed,
+            permutation: permutation_table(seed),
+        }
+    }
+
+    /// Returns seed of noise generator.
+    ///
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+impl Serialize for Simplex {
+    /// Serializes only the seed, since permutation table is fully determined by it.
+    ///
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.seed.serialize(serializer)
+    }
+}
+impl<'de> Deserialize<'de> for Simplex {
+    /// Deserializes seed and rebuilds permutation table from it.
+    ///
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u64::deserialize(deserializer).map(Simplex::new)
+    }
+}
+impl Noise for Simplex {
+    fn get(&self, point: Point) -> f32 {
+        // skewing and unskewing factors for two dimensions
+        let f2: f32 = 0.5 * (3.0_f32.sqrt() - 1.0);
+        let g2: f32 = (3.0 - 3.0_f32.sqrt()) / 6.0;
+
+        let s: f32 = (point.x + point.y) * f2;
+        let (i, j): (f32, f32) = ((point.x + s).floor(), (point.y + s).floor());
+        let t: f32 = (i + j) * g2;
+        let offset0: Vector2 = Vector2::from([point.x - (i - t), point.y - (j - t)]);
+
+        let corner1: Vector2 = if offset0.x > offset0.y {
+            Vector2::from([1.0, 0.0])
+        } else {
+            Vector2::from([0.0, 1.0])
+        };
+        let offset1: Vector2 = offset0 - corner1 + g2;
+        let offset2: Vector2 = offset0 - 1.0 + 2.0 * g2;
+
+        let p: &[u8; PERMUTATION_SIZE * 2] = &self.permutation;
+        let (ii, jj): (usize, usize) = (
+            (i as i64).rem_euclid(PERMUTATION_SIZE as i64) as usize,
+            (j as i64).rem_euclid(PERMUTATION_SIZE as i64) as usize,
+        );
+        let (i1, j1): (usize, usize) = (corner1.x as usize, corner1.y as usize);
+        let hashes: [u8; 3] = [
+            p[ii + usize::from(p[jj])],
+            p[ii + i1 + usize::from(p[jj + j1])],
+            p[ii + 1 + usize::from(p[jj + 1])],
+        ];
+
+        let contribution = |hash: u8, offset: Vector2| -> f32 {
+            let t: f32 = 0.5 - offset.sqr_magnitude();
+            if t < 0.0 {
+                0.0
+            } else {
+                t * t * t * t * gradient_dot(hash, offset)
+            }
+        };
+        let value: f32 = 70.0
+            * (contribution(hashes[0], offset0)
+                + contribution(hashes[1], offset1)
+                + contribution(hashes[2], offset2));
+        value.clamp(-1.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Noise;
+    use crate::mathcore::vectors::Point;
+
+    #[test]
+    fn perlin() {
+        use super::Perlin;
+
+        let (perlin1, perlin2, perlin3): (Perlin, Perlin, Perlin) =
+            (Perlin::new(1), Perlin::new(1), Perlin::new(2));
+        let mut differs: bool = false;
+        for i in 0..100 {
+            let point: Point = Point::from([i as f32 * 0.37 - 10.0, i as f32 * 0.11 - 3.0]);
+            let value: f32 = perlin1.get(point);
+            assert!((-1.0..=1.0).contains(&value));
+            assert_eq!(value, perlin2.get(point));
+            differs |= value != perlin3.get(point);
+        }
+        assert!(differs);
+        assert_eq!(perlin1.get(Point::from([-4.0, 7.0])), 0.0);
+
+        let bytes: Vec<u8> = serde_cbor::to_vec(&perlin1).expect("Noise is serializable");
+        assert_eq!(
+            serde_cbor::from_slice::<Perlin>(&bytes).expect("Noise is deserializable"),
+            perlin1
+        );
+
+        // continuity
+        let (a, b): (f32, f32) = (
+            perlin1.get(Point::from([1.5, 1.5])),
+            perlin1.get(Point::from([1.5001, 1.5])),
+        );
+        assert!((a - b).abs() < 0.01);
+    }
+
+    #[test]
+    fn simplex() {
+        use super::Simplex;
+
+        let (simplex1, simplex2): (Simplex, Simplex) = (Simplex::new(5), Simplex::new(6));
+        let mut differs: bool = false;
+        let mut non_zero: bool = false;
+        for i in 0..100 {
+            let point: Point = Point::from([i as f32 * 0.29 - 5.0, i as f32 * 0.53 - 20.0]);
+            let value: f32 = simplex1.get(point);
+            assert!((-1.0..=1.0).contains(&value));
+            assert_eq!(value, Simplex::new(5).get(point));
+            differs |= value != simplex2.get(point);
+            non_zero |= value != 0.0;
+        }
+        assert!(differs && non_zero);
+
+        assert_eq!(simplex1.fbm(Point::from([1.0, 2.0]), 0, 2.0, 0.5), 0.0);
+        let fbm: f32 = simplex1.fbm(Point::from([1.3, 2.9]), 5, 2.0, 0.5);
+        assert!((-1.0..=1.0).contains(&fbm));
+    }
+}
@@ -0,0 +1,294 @@
+//! `mathcore::polylines` submodule implements polylines (open chains of connected segments)
+//! and helper functions that work with them, such as measuring, sampling, simplification and offsetting.
+//!
+//! Polylines are used by path following, navigation paths and rendering of roads or rivers.
+//!
+
+use crate::mathcore::{
+    shapes::Segment,
+    vectors::{Point, Vector2},
+};
+use serde::{Deserialize, Serialize};
+
+/// Maximal length of a miter join (relative to offset distance) that is produced by [`Polyline::offset`].
+///
+/// Joins of very sharp corners are clamped to that length to avoid long spikes.
+///
+const MITER_LIMIT: f32 = 4.0;
+
+/// [`Polyline`] struct represents two-dimensional open polyline - sequence of points connected by segments.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::polylines::Polyline;
+/// # use ggengine::mathcore::vectors::Point;
+/// let path: Polyline = Polyline::from(vec![
+///     Point::from([0.0, 0.0]),
+///     Point::from([4.0, 0.0]),
+///     Point::from([4.0, 3.0]),
+/// ]);
+/// assert_eq!(path.length(), 7.0);
+/// assert_eq!(path.point_at_distance(5.0), Some(Point::from([4.0, 1.0])));
+/// assert_eq!(path.closest_point(Point::from([5.0, 2.0])), Some((Point::from([4.0, 2.0]), 6.0)));
+/// ```
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Polyline {
+    /// Vector of polyline's points.
+    ///
+    pub points: Vec<Point>,
+}
+impl Polyline {
+    /// Returns segments that form polyline.
+    ///
+    pub fn segments(&self) -> Vec<Segment> {
+        self.points
+            .windows(2)
+            .map(|pair| Segment::from([pair[0], pair[1]]))
+            .collect()
+    }
+
+    /// Returns total length of a polyline.
+    ///
+    pub fn length(&self) -> f32 {
+        self.segments().iter().map(Segment::length).sum()
+    }
+
+    /// Returns point that lies on polyline at given distance (measured along the polyline from its first point).
+    ///
+    /// Distance is clamped between zero and length of a polyline.
+    /// If polyline has no points, `None` is returned.
+    ///
+    pub fn point_at_distance(&self, distance: f32) -> Option<Point> {
+        let mut remaining: f32 = distance.max(0.0);
+        for segment in self.segments() {
+            let length: f32 = segment.length();
+            if remaining <= length {
+                if length == 0.0 {
+                    return Some(segment.points[0]);
+                }
+                return Some(segment.points[0].lerp(segment.points[1], remaining / length));
+            }
+            remaining -= length;
+        }
+        self.points.last().copied()
+    }
+
+    /// Returns point of a polyline that is the closest to the given point
+    /// and distance along the polyline at which that point lies.
+    ///
+    /// If polyline has no points, `None` is returned.
+    ///
+    pub fn closest_point(&self, point: Point) -> Option<(Point, f32)> {
+        let first: Point = *self.points.first()?;
+        let (mut closest, mut closest_distance, mut distance_along): (Point, f32, f32) =
+            (first, (first - point).magnitude(), 0.0);
+
+        let mut travelled: f32 = 0.0;
+        for segment in self.segments() {
+            let candidate: Point = segment.closest_point(point);
+            let candidate_distance: f32 = (candidate - point).magnitude();
+            if candidate_distance < closest_distance {
+                (closest, closest_distance, distance_along) = (
+                    candidate,
+                    candidate_distance,
+                    travelled + (candidate - segment.points[0]).magnitude(),
+                );
+            }
+            travelled += segment.length();
+        }
+        Some((closest, distance_along))
+    }
+
+    /// Simplifies polyline by using Ramer-Douglas-Peucker algorithm.
+    ///
+    /// Points that deviate from simplified polyline by no more than `epsilon` are removed;
+    /// first and last points are always kept.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::polylines::Polyline;
+    /// # use ggengine::mathcore::vectors::Point;
+    /// let path: Polyline = Polyline::from(vec![
+    ///     Point::from([0.0, 0.0]),
+    ///     Point::from([1.0, 0.1]),
+    ///     Point::from([2.0, -0.1]),
+    ///     Point::from([3.0, 5.0]),
+    ///     Point::from([4.0, 6.0]),
+    ///     Point::from([5.0, 7.0]),
+    /// ]);
+    /// assert_eq!(
+    ///     path.simplify(0.5).points,
+    ///     vec![Point::from([0.0, 0.0]), Point::from([2.0, -0.1]), Point::from([3.0, 5.0]), Point::from([5.0, 7.0])]
+    /// );
+    /// ```
+    ///
+    pub fn simplify(&self, epsilon: f32) -> Polyline {
+        let n: usize = self.points.len();
+        if n < 3 {
+            return self.clone();
+        }
+
+        let mut keep: Vec<bool> = vec![false; n];
+        (keep[0], keep[n - 1]) = (true, true);
+        let mut ranges: Vec<(usize, usize)> = vec![(0, n - 1)];
+        while let Some((start, end)) = ranges.pop() {
+            let chord: Segment = Segment::from([self.points[start], self.points[end]]);
+            let (mut farthest, mut farthest_distance): (usize, f32) = (start, 0.0);
+            for i in (start + 1)..end {
+                let distance: f32 = chord.distance_to_point(self.points[i]);
+                if distance > farthest_distance {
+                    (farthest, farthest_distance) = (i, distance);
+                }
+            }
+
+            if farthest_distance > epsilon {
+                keep[farthest] = true;
+                ranges.push((start, farthest));
+                ranges.push((farthest, end));
+            }
+        }
+
+        Polyline {
+            points: self
+                .points
+                .iter()
+                .zip(keep)
+                .filter_map(|(point, keep)| keep.then_some(*point))
+                .collect(),
+        }
+    }
+
+    /// Returns polyline that is parallel to this one and lies at given distance from it.
+    ///
+    /// Positive distance offsets polyline to the left side (counterclockwise from its direction),
+    /// negative distance offsets it to the right side.
+    /// Corners are joined by miters, which are clamped for very sharp corners.
+    /// Consecutive coincident points are merged.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::polylines::Polyline;
+    /// # use ggengine::mathcore::vectors::Point;
+    /// # use ggengine::mathcore::floats::FloatOperations;
+    /// let path: Polyline = Polyline::from(vec![
+    ///     Point::from([0.0, 0.0]),
+    ///     Point::from([4.0, 0.0]),
+    ///     Point::from([4.0, 4.0]),
+    /// ]);
+    /// let offset: Vec<Point> = path.offset(1.0).points.into_iter().map(|point| point.round_up_to(3)).collect();
+    /// assert_eq!(offset, vec![Point::from([0.0, 1.0]), Point::from([3.0, 1.0]), Point::from([3.0, 4.0])]);
+    /// ```
+    ///
+    pub fn offset(&self, distance: f32) -> Polyline {
+        let mut points: Vec<Point> = self.points.clone();
+        points.dedup_by(|a, b| (*a - *b).try_normalize().is_none());
+        if points.len() < 2 {
+            return Polyline { points };
+        }
+
+        let normals: Vec<Vector2> = points
+            .windows(2)
+            .map(|pair| {
+                (pair[1] - pair[0])
+                    .try_normalize()
+                    .expect("Coincident points were merged")
+                    .perpendicular()
+            })
+            .collect();
+
+        let last: usize = points.len() - 1;
+        let offset: Vec<Point> = points
+            .iter()
+            .enumerate()
+            .map(|(i, point)| {
+                if i == 0 {
+                    return *point + normals[0] * distance;
+                } else if i == last {
+                    return *point + normals[last - 1] * distance;
+                }
+
+                let (previous, next): (Vector2, Vector2) = (normals[i - 1], normals[i]);
+                match (previous + next).try_normalize() {
+                    Some(miter) => {
+                        let scale: f32 = (1.0 / miter.dot(previous)).min(MITER_LIMIT);
+                        *point + miter * (distance * scale)
+                    }
+                    // polyline turns back on itself
+                    None => *point + previous * distance,
+                }
+            })
+            .collect();
+        Polyline { points: offset }
+    }
+}
+impl From<Vec<Point>> for Polyline {
+    /// Shorthand for writing `Polyline { points: ... }`.
+    ///
+    fn from(points: Vec<Point>) -> Self {
+        Polyline { points }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Polyline;
+    use crate::mathcore::vectors::Point;
+
+    #[test]
+    fn polyline() {
+        use crate::mathcore::floats::FloatOperations;
+
+        assert_eq!(Polyline::from(vec![]).point_at_distance(1.0), None);
+        assert_eq!(Polyline::from(vec![]).closest_point(Point::zero()), None);
+
+        let single: Polyline = Polyline::from(vec![Point::from([1.0, 1.0])]);
+        assert_eq!(single.length(), 0.0);
+        assert_eq!(single.point_at_distance(3.0), Some(Point::from([1.0, 1.0])));
+
+        let path: Polyline = Polyline::from(vec![
+            Point::from([0.0, 0.0]),
+            Point::from([3.0, 4.0]),
+            Point::from([3.0, 4.0]),
+            Point::from([3.0, 10.0]),
+        ]);
+        assert_eq!(path.length(), 11.0);
+        assert_eq!(path.point_at_distance(-1.0), Some(Point::zero()));
+        assert_eq!(path.point_at_distance(2.5), Some(Point::from([1.5, 2.0])));
+        assert_eq!(path.point_at_distance(8.0), Some(Point::from([3.0, 7.0])));
+        assert_eq!(
+            path.point_at_distance(100.0),
+            Some(Point::from([3.0, 10.0]))
+        );
+        assert_eq!(
+            path.closest_point(Point::from([5.0, 8.0])),
+            Some((Point::from([3.0, 8.0]), 9.0))
+        );
+
+        assert_eq!(path.simplify(0.1).points.len(), 3);
+        let line: Polyline = Polyline::from(vec![
+            Point::from([0.0, 0.0]),
+            Point::from([1.0, 1.0]),
+            Point::from([2.0, 2.0]),
+        ]);
+        assert_eq!(
+            line.simplify(0.01).points,
+            vec![Point::from([0.0, 0.0]), Point::from([2.0, 2.0])]
+        );
+
+        let offset: Polyline = path.offset(-1.0);
+        assert_eq!(offset.points.len(), 3);
+        assert_eq!(offset.points[0].round_up_to(3), Point::from([0.8, -0.6]));
+        assert_eq!(offset.points[2].round_up_to(3), Point::from([4.0, 10.0]));
+
+        // sharp corner is clamped by miter limit
+        let spike: Polyline = Polyline::from(vec![
+            Point::from([0.0, 0.0]),
+            Point::from([10.0, 0.0]),
+            Point::from([0.0, 0.1]),
+        ]);
+        assert!(
+            (spike.offset(1.0).points[1] - Point::from([10.0, 0.0])).magnitude() <= 4.0 + 0.001
+        );
+    }
+}
@@ -10,8 +10,14 @@
 
 // re-exports
 pub use crate::mathcore::collisions::*;
+pub use crate::mathcore::curves::*;
 pub use crate::mathcore::floats::*;
+pub use crate::mathcore::gradients::*;
 pub use crate::mathcore::matrices::*;
+pub use crate::mathcore::noise::*;
+pub use crate::mathcore::polylines::*;
+pub use crate::mathcore::quadtrees::*;
+pub use crate::mathcore::random::*;
 pub use crate::mathcore::shapes::*;
 pub use crate::mathcore::transforms::*;
 pub use crate::mathcore::vectors::*;
@@ -0,0 +1,228 @@
+//! `mathcore::quadtrees` submodule implements [`QuadTree`] - spatial index of axis-aligned boxes,
+//! which quickly finds items that intersect given area (e.g. drawables that are visible by camera).
+//!
+
+use crate::mathcore::{shapes::Aabb, vectors::Point};
+
+/// [`QuadNode`] struct is a node of [`QuadTree`].
+///
+#[derive(Clone, Debug)]
+struct QuadNode<T> {
+    /// Area that is covered by the node.
+    ///
+    bounds: Aabb,
+    /// Items that are stored in the node - items that do not fit into any child (or all items of a leaf).
+    ///
+    items: Vec<(Aabb, T)>,
+    /// Children of the node that cover its quadrants (`None` for leaves).
+    ///
+    children: Option<Box<[QuadNode<T>; 4]>>,
+}
+impl<T> QuadNode<T> {
+    /// Constructs empty leaf that covers given area.
+    ///
+    fn new(bounds: Aabb) -> Self {
+        QuadNode {
+            bounds,
+            items: Vec::new(),
+            children: None,
+        }
+    }
+
+    /// Inserts item into the node or into one of its children, splitting the node if it is overfull.
+    ///
+    fn insert(&mut self, aabb: Aabb, item: T, depth: usize) {
+        if let Some(children) = &mut self.children {
+            match children
+                .iter_mut()
+                .find(|child| child.bounds.contains(aabb))
+            {
+                Some(child) => child.insert(aabb, item, depth + 1),
+                None => self.items.push((aabb, item)),
+            }
+            return;
+        }
+
+        self.items.push((aabb, item));
+        if self.items.len() > QuadTree::<T>::NODE_CAPACITY && depth < QuadTree::<T>::MAX_DEPTH {
+            let (min, center, max): (Point, Point, Point) =
+                (self.bounds.min, self.bounds.center(), self.bounds.max);
+            self.children = Some(Box::new([
+                QuadNode::new(Aabb::new(min, center)),
+                QuadNode::new(Aabb::new(
+                    Point::from([center.x, min.y]),
+                    Point::from([max.x, center.y]),
+                )),
+                QuadNode::new(Aabb::new(
+                    Point::from([min.x, center.y]),
+                    Point::from([center.x, max.y]),
+                )),
+                QuadNode::new(Aabb::new(center, max)),
+            ]));
+            for (aabb, item) in std::mem::take(&mut self.items) {
+                self.insert(aabb, item, depth);
+            }
+        }
+    }
+
+    /// Collects items of the node and of its children that intersect given area.
+    ///
+    fn query<'a>(&'a self, area: Aabb, found: &mut Vec<&'a T>) {
+        found.extend(
+            self.items
+                .iter()
+                .filter(|(aabb, _)| aabb.intersects(area))
+                .map(|(_, item)| item),
+        );
+        for child in self.children.iter().flat_map(|children| children.iter()) {
+            if child.bounds.intersects(area) {
+                child.query(area, found);
+            }
+        }
+    }
+}
+
+/// [`QuadTree`] struct is a spatial index that stores items together with their bounding boxes.
+///
+/// Tree recursively splits its area into quadrants when a node holds too many items, so queries
+/// only visit nodes that intersect queried area. Items that do not fit into a single quadrant
+/// (and items outside of bounds of the tree) are stored in the deepest node that contains them.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::{quadtrees::QuadTree, shapes::Aabb, vectors::Point};
+/// let aabb = |x: f32, y: f32| Aabb::new(Point::from([x, y]), Point::from([x + 1.0, y + 1.0]));
+/// let mut tree: QuadTree<u32> = QuadTree::new(aabb(0.0, 0.0).union(aabb(99.0, 99.0)));
+/// for i in 0..100 {
+///     tree.insert(aabb(i as f32, i as f32), i);
+/// }
+///
+/// let mut found: Vec<u32> = tree.query(aabb(10.5, 10.5)).into_iter().copied().collect();
+/// found.sort();
+/// assert_eq!(found, [10, 11]);
+/// assert_eq!(tree.len(), 100);
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct QuadTree<T> {
+    /// Root node of the tree.
+    ///
+    root: QuadNode<T>,
+    /// Number of items in the tree.
+    ///
+    len: usize,
+}
+impl<T> QuadTree<T> {
+    /// Maximal number of items that leaf holds before it is split.
+    ///
+    pub const NODE_CAPACITY: usize = 8;
+    /// Maximal depth of nodes (nodes of that depth are never split).
+    ///
+    pub const MAX_DEPTH: usize = 8;
+
+    /// Constructs empty tree that covers given area.
+    ///
+    pub fn new(bounds: Aabb) -> Self {
+        QuadTree {
+            root: QuadNode::new(bounds),
+            len: 0,
+        }
+    }
+
+    /// Returns area that is covered by the tree.
+    ///
+    pub fn bounds(&self) -> Aabb {
+        self.root.bounds
+    }
+    /// Returns number of items in the tree.
+    ///
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Returns whether tree has no items.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Removes all items from the tree.
+    ///
+    pub fn clear(&mut self) {
+        self.root = QuadNode::new(self.root.bounds);
+        self.len = 0;
+    }
+
+    /// Inserts item with given bounding box into the tree.
+    ///
+    /// Boxes with non-finite coordinates are ignored.
+    ///
+    pub fn insert(&mut self, aabb: Aabb, item: T) {
+        if !(aabb.min.x.is_finite()
+            && aabb.min.y.is_finite()
+            && aabb.max.x.is_finite()
+            && aabb.max.y.is_finite())
+        {
+            return;
+        }
+        self.root.insert(aabb, item, 0);
+        self.len += 1;
+    }
+    /// Returns items which bounding boxes intersect given area.
+    ///
+    /// Items are not sorted.
+    ///
+    pub fn query(&self, area: Aabb) -> Vec<&T> {
+        let mut found: Vec<&T> = Vec::new();
+        self.root.query(area, &mut found);
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QuadTree;
+    use crate::mathcore::{shapes::Aabb, vectors::Point};
+
+    #[test]
+    fn queries() {
+        let aabb = |x: f32, y: f32, size: f32| {
+            Aabb::new(Point::from([x, y]), Point::from([x + size, y + size]))
+        };
+        let boxes: Vec<Aabb> = (0..500)
+            .map(|i| {
+                let (x, y): (f32, f32) = ((i * 37 % 1000) as f32, (i * 91 % 1000) as f32);
+                aabb(x, y, (i % 7) as f32 * 10.0)
+            })
+            .collect();
+        let mut tree: QuadTree<usize> = QuadTree::new(aabb(0.0, 0.0, 1000.0));
+        for (index, item) in boxes.iter().enumerate() {
+            tree.insert(*item, index);
+        }
+        // items outside of bounds are still found
+        tree.insert(aabb(-500.0, -500.0, 1.0), boxes.len());
+        tree.insert(aabb(f32::NAN, 0.0, 1.0), boxes.len() + 1);
+        assert_eq!(tree.len(), boxes.len() + 1);
+
+        for area in [
+            aabb(0.0, 0.0, 100.0),
+            aabb(450.0, 450.0, 200.0),
+            aabb(-1000.0, -1000.0, 3000.0),
+        ] {
+            let mut found: Vec<usize> = tree.query(area).into_iter().copied().collect();
+            found.sort_unstable();
+            let mut expected: Vec<usize> = boxes
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| item.intersects(area))
+                .map(|(index, _)| index)
+                .collect();
+            if area.intersects(aabb(-500.0, -500.0, 1.0)) {
+                expected.push(boxes.len());
+            }
+            assert_eq!(found, expected);
+        }
+
+        tree.clear();
+        assert!(tree.is_empty());
+        assert!(tree.query(tree.bounds()).is_empty());
+    }
+}
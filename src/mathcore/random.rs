@@ -0,0 +1,383 @@
+//! `mathcore::random` submodule implements seedable pseudorandom number generator and helper functions
+//! that use it to sample numbers, vectors, points and collections.
+//!
+//! Generator is fully deterministic - same seed always produces the same sequence of values
+//! on every platform, which allows reproducing gameplay and procedural generation.
+//! Independent sub-streams can be forked from generator by name (see `Rng::fork`),
+//! so systems that draw random values do not affect sequences of each other.
+//!
+
+use crate::mathcore::{
+    shapes::{Aabb, Shape},
+    vectors::{Point, Vector2},
+    Angle,
+};
+use serde::{Deserialize, Serialize};
+
+/// Maximal number of attempts that are made by rejection sampling in [`Rng::point_in`].
+///
+const MAX_SAMPLING_ATTEMPTS: usize = 64;
+
+/// [`Rng`] struct implements seedable pseudorandom number generator (SplitMix64 algorithm).
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::random::Rng;
+/// let mut rng1: Rng = Rng::new(42);
+/// let mut rng2: Rng = Rng::new(42);
+/// assert_eq!(rng1.next_u64(), rng2.next_u64());
+///
+/// let value: f32 = rng1.range_f32(-1.0, 1.0);
+/// assert!((-1.0..1.0).contains(&value));
+/// let value: i32 = rng1.range_i32(0, 10);
+/// assert!((0..10).contains(&value));
+/// ```
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rng {
+    /// Seed with which generator was constructed.
+    ///
+    seed: u64,
+    /// Inner state of a generator.
+    ///
+    state: u64,
+}
+impl Rng {
+    /// Constructs generator with given seed.
+    ///
+    pub fn new(seed: u64) -> Self {
+        Rng { seed, state: seed }
+    }
+    /// Returns seed with which generator was constructed.
+    ///
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Forks independent generator for a stream with given name (e.g. name of a system).
+    ///
+    /// Seed of forked generator depends only on the seed of this generator and on the name of the stream,
+    /// so it does not change no matter how many values were drawn from either generator.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::random::Rng;
+    /// let mut rng: Rng = Rng::new(42);
+    /// let particles: Rng = rng.fork("particles");
+    /// let _ = rng.next_u64();
+    /// assert_eq!(rng.fork("particles"), particles);
+    /// assert_ne!(rng.fork("audio"), particles);
+    /// ```
+    ///
+    pub fn fork(&self, stream: &str) -> Rng {
+        // FNV-1a is used, because hashers of `std` are not guaranteed to be stable between platforms and releases
+        let hash: u64 = stream.bytes().fold(0xCBF2_9CE4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01B3)
+        });
+        self.fork_by_id(hash)
+    }
+    /// Forks independent generator for a stream with given id (e.g. index of particle emitter).
+    ///
+    /// See `Rng::fork`.
+    ///
+    pub fn fork_by_id(&self, stream: u64) -> Rng {
+        Rng::new(Rng::new(self.seed ^ stream).next_u64())
+    }
+
+    /// Returns next pseudorandom `u64` value.
+    ///
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z: u64 = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+    /// Returns next pseudorandom `u32` value.
+    ///
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+    /// Returns next pseudorandom `f32` value in `[0.0; 1.0)`.
+    ///
+    pub fn next_f32(&mut self) -> f32 {
+        // 24 bits of mantissa give uniformly distributed values
+        (self.next_u64() >> 40) as f32 / (1_u64 << 24) as f32
+    }
+    /// Returns `true` with given probability (which is clamped to `[0.0; 1.0]`).
+    ///
+    pub fn chance(&mut self, probability: f32) -> bool {
+        self.next_f32() < probability.clamp(0.0, 1.0)
+    }
+
+    /// Returns pseudorandom `u64` value in `[0; bound)`.
+    ///
+    /// If `bound` is equal to zero, zero is returned.
+    ///
+    pub fn below(&mut self, bound: u64) -> u64 {
+        if bound == 0 {
+            return 0;
+        }
+        // Lemire's nearly divisionless method with rejection of biased values.
+        let threshold: u64 = bound.wrapping_neg() % bound;
+        loop {
+            let product: u128 = u128::from(self.next_u64()) * u128::from(bound);
+            if (product as u64) >= threshold {
+                return (product >> 64) as u64;
+            }
+        }
+    }
+    /// Returns pseudorandom `i32` value in `[min; max)`.
+    ///
+    /// If range is empty, `min` is returned.
+    ///
+    pub fn range_i32(&mut self, min: i32, max: i32) -> i32 {
+        if max <= min {
+            return min;
+        }
+        let span: u64 = (i64::from(max) - i64::from(min)) as u64;
+        (i64::from(min) + self.below(span) as i64) as i32
+    }
+    /// Returns pseudorandom `f32` value in `[min; max)`.
+    ///
+    /// If range is empty, `min` is returned.
+    ///
+    pub fn range_f32(&mut self, min: f32, max: f32) -> f32 {
+        if max <= min {
+            return min;
+        }
+        let value: f32 = min + (max - min) * self.next_f32();
+        // Rounding may produce `max`, which should be excluded.
+        if value < max {
+            value
+        } else {
+            min
+        }
+    }
+
+    /// Returns pseudorandom angle.
+    ///
+    pub fn angle(&mut self) -> Angle {
+        Angle::from_radians(self.range_f32(0.0, std::f32::consts::TAU))
+    }
+    /// Returns angle that is randomly offset from given angle by no more than `max_offset` in either direction.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::random::Rng;
+    /// # use ggengine::mathcore::Angle;
+    /// let mut rng: Rng = Rng::new(7);
+    /// let pellets: Vec<Angle> = Angle::DEG90
+    ///     .spread(Angle::from_degrees(30.0), 6)
+    ///     .map(|angle| rng.jitter_angle(angle, Angle::from_degrees(2.0)))
+    ///     .collect();
+    /// assert!(pellets
+    ///     .iter()
+    ///     .all(|pellet| Angle::DEG90.shortest_distance_to(*pellet).abs() <= 17.0_f32.to_radians()));
+    /// ```
+    ///
+    pub fn jitter_angle(&mut self, angle: Angle, max_offset: Angle) -> Angle {
+        let max_offset: f32 = max_offset.radians();
+        Angle::from_radians(angle.radians() + self.range_f32(-max_offset, max_offset))
+    }
+    /// Returns pseudorandom vector with magnitude of 1 which lies inside of a cone
+    /// that is centered on `direction` and has given `arc`.
+    ///
+    pub fn direction_in_cone(&mut self, direction: Angle, arc: Angle) -> Vector2 {
+        Vector2::from_angle(self.jitter_angle(direction, arc / 2.0))
+    }
+    /// Returns pseudorandom vector with magnitude of 1.
+    ///
+    pub fn unit_vector(&mut self) -> Vector2 {
+        Vector2::from_angle(self.angle())
+    }
+    /// Returns pseudorandom point inside of an axis-aligned bounding box.
+    ///
+    pub fn point_in_aabb(&mut self, aabb: Aabb) -> Point {
+        Point::from([
+            self.range_f32(aabb.min.x, aabb.max.x),
+            self.range_f32(aabb.min.y, aabb.max.y),
+        ])
+    }
+    /// Returns pseudorandom point inside of a shape.
+    ///
+    /// Point is found by rejection sampling of shape's bounding box; in the unlikely case of
+    /// every attempt being rejected (which only happens for extremely thin shapes) origin of a shape is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::random::Rng;
+    /// # use ggengine::mathcore::shapes::{Circle, Shape};
+    /// # use ggengine::mathcore::vectors::Point;
+    /// # use ggengine::mathcore::Size;
+    /// let mut rng: Rng = Rng::new(7);
+    /// let circle: Circle = Circle::from_origin(Point::from([2.0, 3.0]), Size::from_value(1.5));
+    /// for _ in 0..100 {
+    ///     assert!(circle.contains_point(rng.point_in(&circle)));
+    /// }
+    /// ```
+    ///
+    pub fn point_in(&mut self, shape: &impl Shape) -> Point {
+        let aabb: Aabb = shape.aabb();
+        for _ in 0..MAX_SAMPLING_ATTEMPTS {
+            let point: Point = self.point_in_aabb(aabb);
+            if shape.contains_point(point) {
+                return point;
+            }
+        }
+        shape.origin()
+    }
+
+    /// Returns reference to pseudorandom element of a slice or `None` if slice is empty.
+    ///
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            return None;
+        }
+        items.get(self.below(items.len() as u64) as usize)
+    }
+    /// Returns reference to pseudorandom element of a slice, where each element is chosen with probability
+    /// that is proportional to its weight.
+    ///
+    /// Negative and non-finite weights are treated as zero weights.
+    /// If slice is empty or all weights are equal to zero, `None` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::random::Rng;
+    /// let mut rng: Rng = Rng::new(1);
+    /// let loot: [(&str, f32); 3] = [("common", 10.0), ("rare", 1.0), ("never", 0.0)];
+    /// for _ in 0..100 {
+    ///     assert_ne!(rng.weighted_choice(&loot), Some(&"never"));
+    /// }
+    /// ```
+    ///
+    pub fn weighted_choice<'a, T>(&mut self, items: &'a [(T, f32)]) -> Option<&'a T> {
+        let weight = |weight: f32| -> f32 {
+            if weight.is_finite() {
+                weight.max(0.0)
+            } else {
+                0.0
+            }
+        };
+
+        let total: f32 = items.iter().map(|(_, w)| weight(*w)).sum();
+        if total <= 0.0 {
+            return None;
+        }
+
+        let mut target: f32 = self.next_f32() * total;
+        let mut last: Option<&'a T> = None;
+        for (item, w) in items {
+            let w: f32 = weight(*w);
+            if w == 0.0 {
+                continue;
+            }
+            if target < w {
+                return Some(item);
+            }
+            target -= w;
+            last = Some(item);
+        }
+        // Floating point errors may leave a bit of `target`, which belongs to the last element.
+        last
+    }
+    /// Shuffles slice in place by using Fisher-Yates algorithm.
+    ///
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j: usize = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rng;
+
+    #[test]
+    fn determinism() {
+        let (mut rng1, mut rng2, mut rng3): (Rng, Rng, Rng) =
+            (Rng::new(1), Rng::new(1), Rng::new(2));
+        let sequence1: Vec<u64> = (0..16).map(|_| rng1.next_u64()).collect();
+        let sequence2: Vec<u64> = (0..16).map(|_| rng2.next_u64()).collect();
+        let sequence3: Vec<u64> = (0..16).map(|_| rng3.next_u64()).collect();
+        assert_eq!(sequence1, sequence2);
+        assert_ne!(sequence1, sequence3);
+
+        // SplitMix64 reference values for zero seed
+        let mut rng: Rng = Rng::new(0);
+        assert_eq!(rng.next_u64(), 0xE220_A839_7B1D_CDAF);
+        assert_eq!(rng.next_u64(), 0x6E78_9E6A_A1B9_65F4);
+        assert_eq!(rng.seed(), 0);
+    }
+
+    #[test]
+    fn forks() {
+        let mut rng: Rng = Rng::new(9);
+        let mut stream: Rng = rng.fork("stream");
+        let sequence: Vec<u64> = (0..16).map(|_| stream.next_u64()).collect();
+        let _ = rng.next_u64();
+        let mut replayed: Rng = rng.fork("stream");
+        assert_eq!(
+            (0..16).map(|_| replayed.next_u64()).collect::<Vec<u64>>(),
+            sequence
+        );
+        assert_ne!(rng.fork("other"), rng.fork("stream"));
+        assert_ne!(rng.fork_by_id(1), rng.fork_by_id(2));
+        assert_ne!(Rng::new(10).fork("stream"), rng.fork("stream"));
+    }
+
+    #[test]
+    fn sampling() {
+        use crate::mathcore::{
+            floats::equal,
+            shapes::{Rect, Shape},
+            vectors::{Point, Vector2},
+            {Angle, Size},
+        };
+
+        let mut rng: Rng = Rng::new(123);
+        for _ in 0..1000 {
+            assert!((0.0..1.0).contains(&rng.next_f32()));
+            assert!((-3..3).contains(&rng.range_i32(-3, 3)));
+            assert!((2.0..2.5).contains(&rng.range_f32(2.0, 2.5)));
+            assert!(equal(rng.unit_vector().magnitude(), 1.0));
+        }
+        for _ in 0..100 {
+            let direction: Vector2 = rng.direction_in_cone(Angle::DEG180, Angle::DEG90);
+            assert!(direction.x < -0.7);
+            assert!(equal(direction.magnitude(), 1.0));
+        }
+        assert_eq!(rng.range_i32(5, 5), 5);
+        assert!(rng.range_i32(i32::MIN, i32::MAX) < i32::MAX);
+
+        let rect: Rect = Rect::from_origin(
+            Point::from([10.0, -10.0]),
+            Angle::from_degrees(30.0),
+            Size::from_value(4.0),
+            Size::from_value(0.5),
+        );
+        for _ in 0..100 {
+            assert!(rect.contains_point(rng.point_in(&rect)));
+        }
+
+        let mut counts: [usize; 2] = [0, 0];
+        for _ in 0..1000 {
+            counts[*rng
+                .weighted_choice(&[(0, 1.0), (1, 3.0)])
+                .expect("Weights are positive")] += 1;
+        }
+        assert!(counts[1] > counts[0] * 2);
+        assert!(rng.weighted_choice::<i32>(&[]).is_none());
+        assert!(rng.weighted_choice(&[(0, 0.0), (1, -1.0)]).is_none());
+        assert!(rng.choose::<Vector2>(&[]).is_none());
+
+        let mut items: Vec<u32> = (0..50).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..50).collect::<Vec<u32>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..50).collect::<Vec<u32>>());
+    }
+}
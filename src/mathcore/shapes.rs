@@ -2,13 +2,14 @@
 //!
 
 use crate::mathcore::{
-    floats::{equal, FloatOperations},
+    collisions::intersects,
+    floats::{equal, FloatOperations, EPSILON},
     matrices::Matrix3x3,
     transforms::{combine_matrices, Rotatable, Scalable, Transform, Transformable, Translatable},
     vectors::{Point, Vector2, Vertex},
     {Angle, Sign, Size},
 };
-use serde::{Deserialize, Serialize};
+use serde::{de::Error, Deserialize, Deserializer, Serialize};
 
 /// [`Segment`] struct represents two-dimensional line segment.
 ///
@@ -102,6 +103,47 @@ impl Segment {
             None
         }
     }
+
+    /// Returns point of a segment that is the closest to the given point.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::shapes::Segment;
+    /// # use ggengine::mathcore::vectors::Point;
+    /// let segment: Segment = Segment::from([Point::from([0.0, 0.0]), Point::from([4.0, 0.0])]);
+    /// assert_eq!(segment.closest_point(Point::from([1.0, 3.0])), Point::from([1.0, 0.0]));
+    /// assert_eq!(segment.closest_point(Point::from([-2.0, 1.0])), Point::from([0.0, 0.0]));
+    /// assert_eq!(segment.distance_to_point(Point::from([1.0, 3.0])), 3.0);
+    /// ```
+    ///
+    pub fn closest_point(&self, point: Point) -> Point {
+        let slope: Vector2 = self.slope();
+        let sqr_length: f32 = slope.sqr_magnitude();
+        if equal(sqr_length, 0.0) {
+            return self.points[0];
+        }
+
+        let t: f32 = ((point - self.points[0]) * slope / sqr_length).clamp(0.0, 1.0);
+        self.points[0] + slope * t
+    }
+    /// Returns distance from a segment to the given point.
+    ///
+    pub fn distance_to_point(&self, point: Point) -> f32 {
+        (point - self.closest_point(point)).magnitude()
+    }
+    /// Returns distance between two segments.
+    ///
+    /// If segments intersect, distance is equal to zero.
+    ///
+    pub fn distance_to_segment(&self, other: Segment) -> f32 {
+        if self.intersection(other).is_some() {
+            return 0.0;
+        }
+        self.distance_to_point(other.points[0])
+            .min(self.distance_to_point(other.points[1]))
+            .min(other.distance_to_point(self.points[0]))
+            .min(other.distance_to_point(self.points[1]))
+    }
 }
 impl FloatOperations for Segment {
     fn correct(self, digits: i32) -> Self {
@@ -132,7 +174,7 @@ impl Rotatable for Segment {
 
     fn rotate_on(&mut self, angle: Angle) {
         let origin: Vector2 = self.origin();
-        let transform_matrix: Matrix3x3 = combine_matrices(vec![
+        let transform_matrix: Matrix3x3 = combine_matrices([
             Transform::Translation { vector: -origin }.matrix(),
             Transform::Rotation { angle }.matrix(),
             Transform::Translation { vector: origin }.matrix(),
@@ -150,6 +192,150 @@ impl From<[Point; 2]> for Segment {
     }
 }
 
+/// [`Aabb`] struct represents axis-aligned bounding box.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::shapes::Aabb;
+/// # use ggengine::mathcore::vectors::Point;
+/// let aabb1: Aabb = Aabb::from_points(&[
+///     Point::from([0.0, 0.0]),
+///     Point::from([2.0, 1.0]),
+///     Point::from([1.0, 2.0]),
+/// ]).expect("Slice of points is not empty");
+/// assert_eq!(aabb1, Aabb::new(Point::from([0.0, 0.0]), Point::from([2.0, 2.0])));
+///
+/// let aabb2: Aabb = Aabb::new(Point::from([1.0, 1.0]), Point::from([3.0, 3.0]));
+/// assert!(aabb1.intersects(aabb2));
+/// assert_eq!(aabb1.union(aabb2), Aabb::new(Point::from([0.0, 0.0]), Point::from([3.0, 3.0])));
+/// assert_eq!(
+///     aabb1.intersection(aabb2),
+///     Some(Aabb::new(Point::from([1.0, 1.0]), Point::from([2.0, 2.0])))
+/// );
+/// assert!(aabb1.expand(1.0).contains(aabb2));
+/// ```
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Aabb {
+    /// Corner of a box with the smallest coordinates (`(min_x, min_y)`).
+    ///
+    pub min: Point,
+    /// Corner of a box with the largest coordinates (`(max_x, max_y)`).
+    ///
+    pub max: Point,
+}
+impl Aabb {
+    /// Constructs axis-aligned bounding box from two of its corners.
+    ///
+    /// Corners can be passed in any order, since box is built from their smallest and largest coordinates.
+    ///
+    pub fn new(corner1: Point, corner2: Point) -> Self {
+        Aabb {
+            min: corner1.min(corner2),
+            max: corner1.max(corner2),
+        }
+    }
+    /// Constructs the smallest axis-aligned bounding box that contains all given points.
+    ///
+    /// If slice of points is empty, `None` is returned.
+    ///
+    pub fn from_points(points: &[Point]) -> Option<Self> {
+        let first: Point = *points.first()?;
+        Some(
+            points
+                .iter()
+                .fold(Aabb::new(first, first), |aabb, point| Aabb {
+                    min: aabb.min.min(*point),
+                    max: aabb.max.max(*point),
+                }),
+        )
+    }
+
+    /// Returns width of a box.
+    ///
+    pub fn width(&self) -> f32 {
+        self.max.x - self.min.x
+    }
+    /// Returns height of a box.
+    ///
+    pub fn height(&self) -> f32 {
+        self.max.y - self.min.y
+    }
+    /// Returns center point of a box.
+    ///
+    pub fn center(&self) -> Point {
+        (self.min + self.max) / 2.0
+    }
+
+    /// Returns whether box contains point or not. Point that lies on the border of a box is considered lying inside it.
+    ///
+    pub fn contains_point(&self, point: Point) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+    }
+    /// Returns whether box fully contains other box or not.
+    ///
+    pub fn contains(&self, other: Aabb) -> bool {
+        self.contains_point(other.min) && self.contains_point(other.max)
+    }
+    /// Returns whether two boxes intersect or not. Touching boxes are considered intersecting.
+    ///
+    pub fn intersects(&self, other: Aabb) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    /// Returns the smallest box that contains both boxes.
+    ///
+    pub fn union(self, other: Aabb) -> Aabb {
+        Aabb {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+    /// Returns box that is an intersection of two boxes or `None` if they do not intersect.
+    ///
+    pub fn intersection(self, other: Aabb) -> Option<Aabb> {
+        if !self.intersects(other) {
+            return None;
+        }
+        Some(Aabb {
+            min: self.min.max(other.min),
+            max: self.max.min(other.max),
+        })
+    }
+    /// Returns box that is expanded by given margin in every direction.
+    ///
+    /// Negative margin shrinks box, although box never shrinks past its center.
+    ///
+    pub fn expand(self, margin: f32) -> Aabb {
+        let center: Point = self.center();
+        Aabb {
+            min: (self.min - margin).min(center),
+            max: (self.max + margin).max(center),
+        }
+    }
+}
+impl FloatOperations for Aabb {
+    fn correct(self, digits: i32) -> Self {
+        Aabb {
+            min: self.min.correct(digits),
+            max: self.max.correct(digits),
+        }
+    }
+
+    fn round_up_to(self, digits: i32) -> Self {
+        Aabb {
+            min: self.min.round_up_to(digits),
+            max: self.max.round_up_to(digits),
+        }
+    }
+}
+
 /// [`Shape`] trait defines two-dimensional shape on a plane which can be transformed.
 ///
 pub trait Shape: Transformable {
@@ -163,6 +349,10 @@ pub trait Shape: Transformable {
     /// Returns whether shape contains point or not. Point that lies on the edge or shape's border is considered lying inside shape.
     ///
     fn contains_point(&self, point: Point) -> bool;
+
+    /// Returns axis-aligned bounding box that contains shape.
+    ///
+    fn aabb(&self) -> Aabb;
 }
 /// [`PolygonLike`] trait defines shapes that can be represented by a list of vertices.
 ///
@@ -325,6 +515,23 @@ pub trait Convex: PolygonLike {}
 /// );
 /// ```
 ///
+/// ### Bounding box
+/// ```rust
+/// # use ggengine::mathcore::shapes::{Aabb, Rect, Shape};
+/// # use ggengine::mathcore::vectors::Point;
+/// # use ggengine::mathcore::floats::FloatOperations;
+/// # use ggengine::mathcore::{Angle, Size};
+/// let rect: Rect = Rect::from_origin(
+///     Point::zero(),
+///     Angle::from_degrees(45.0),
+///     Size::from_value(2.0), Size::from_value(2.0)
+/// );
+/// assert_eq!(
+///     rect.aabb().round_up_to(1),
+///     Aabb::new(Point::from([-1.4, -1.4]), Point::from([1.4, 1.4])) // sqrt(2)
+/// );
+/// ```
+///
 #[derive(Serialize, Deserialize, Copy, Clone, Debug)]
 pub struct Rect {
     /// Array of rectangle's vertices.
@@ -352,6 +559,34 @@ impl Rect {
     pub fn height(&self) -> f32 {
         self.size.1.get()
     }
+    /// Returns center point of a rectangle.
+    ///
+    /// Is equal to [`Translatable::origin`].
+    ///
+    pub fn center(&self) -> Point {
+        self.origin
+    }
+    /// Returns corner of rectangle's bounding box with the smallest coordinates.
+    ///
+    /// For axis-aligned rectangles, that is the corner of a rectangle itself.
+    ///
+    pub fn min(&self) -> Point {
+        self.aabb().min
+    }
+    /// Returns corner of rectangle's bounding box with the largest coordinates.
+    ///
+    /// For axis-aligned rectangles, that is the corner of a rectangle itself.
+    ///
+    pub fn max(&self) -> Point {
+        self.aabb().max
+    }
+    /// Returns top-left corner of rectangle's bounding box in screen space (where y-axis is directed down).
+    ///
+    /// Is equal to [`Rect::min`].
+    ///
+    pub fn top_left(&self) -> Point {
+        self.min()
+    }
 
     /// Constructs rectangle with given origin, angle and size.
     ///
@@ -364,7 +599,7 @@ impl Rect {
             Vertex::from([0.5, -0.5]),
             Vertex::from([-0.5, -0.5]),
         ];
-        let transform_matrix: Matrix3x3 = combine_matrices(vec![
+        let transform_matrix: Matrix3x3 = combine_matrices([
             Transform::Scaling { size_scale: size }.matrix(),
             Transform::Rotation { angle }.matrix(),
             Transform::Translation { vector: origin }.matrix(),
@@ -379,43 +614,142 @@ impl Rect {
             size,
         }
     }
+    /// Constructs axis-aligned rectangle with given center and size.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::shapes::Rect;
+    /// # use ggengine::mathcore::vectors::Point;
+    /// # use ggengine::mathcore::Size;
+    /// let rect: Rect = Rect::from_center_size(Point::from([1.0, 1.0]), Size::from_value(4.0), Size::from_value(2.0));
+    /// assert_eq!(rect.center(), Point::from([1.0, 1.0]));
+    /// assert_eq!(rect.min(), Point::from([-1.0, 0.0]));
+    /// assert_eq!(rect.max(), Point::from([3.0, 2.0]));
+    /// ```
+    ///
+    pub fn from_center_size(center: Point, width: Size, height: Size) -> Self {
+        Rect::from_origin(center, Angle::zero(), width, height)
+    }
+    /// Constructs axis-aligned rectangle from its corners with the smallest and the largest coordinates.
+    ///
+    /// Corners can be passed in any order (see [`Aabb::new`]); degenerate corners are handled like in [`Rect::from_aabb`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::shapes::Rect;
+    /// # use ggengine::mathcore::vectors::Point;
+    /// let rect: Rect = Rect::from_min_max(Point::from([-1.0, 0.0]), Point::from([3.0, 2.0]));
+    /// assert_eq!((rect.width(), rect.height()), (4.0, 2.0));
+    /// assert_eq!(rect.center(), Point::from([1.0, 1.0]));
+    /// ```
+    ///
+    pub fn from_min_max(min: Point, max: Point) -> Self {
+        Rect::from_aabb(Aabb::new(min, max))
+    }
+    /// Constructs axis-aligned rectangle from its top-left corner in screen space
+    /// (where y-axis is directed down, so top-left corner is the one with the smallest coordinates) and size.
+    ///
+    /// This is the usual way to describe areas of textures and windows.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::shapes::Rect;
+    /// # use ggengine::mathcore::vectors::Point;
+    /// # use ggengine::mathcore::Size;
+    /// let frame: Rect = Rect::from_top_left_size(Point::from([32.0, 16.0]), Size::from_value(16.0), Size::from_value(16.0));
+    /// assert_eq!(frame.top_left(), Point::from([32.0, 16.0]));
+    /// assert_eq!(frame.center(), Point::from([40.0, 24.0]));
+    /// ```
+    ///
+    pub fn from_top_left_size(top_left: Point, width: Size, height: Size) -> Self {
+        Rect::from_center_size(
+            top_left + Vector2::from([width.get(), height.get()]) / 2.0,
+            width,
+            height,
+        )
+    }
+    /// Constructs axis-aligned rectangle that covers given axis-aligned bounding box.
+    ///
+    /// Since rectangle's size can not be equal to zero, degenerate boxes produce rectangles with the smallest possible size.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::shapes::{Aabb, Rect, Shape};
+    /// # use ggengine::mathcore::vectors::Point;
+    /// let aabb: Aabb = Aabb::new(Point::from([-1.0, 0.0]), Point::from([3.0, 2.0]));
+    /// let rect: Rect = Rect::from_aabb(aabb);
+    /// assert_eq!((rect.width(), rect.height()), (4.0, 2.0));
+    /// assert_eq!(rect.aabb(), aabb);
+    /// ```
+    ///
+    pub fn from_aabb(aabb: Aabb) -> Self {
+        Rect::from_origin(
+            aabb.center(),
+            Angle::zero(),
+            Size::from_value(aabb.width().max(EPSILON)),
+            Size::from_value(aabb.height().max(EPSILON)),
+        )
+    }
 
-    /// Returns array of two corner points of axis-aligned bounding box that contains rectangle.
+    /// Returns whether two rectangles intersect or not. Touching rectangles are considered intersecting.
+    ///
+    /// Rotated rectangles are also supported (separating axis theorem is used).
     ///
-    /// First point is `(min_x, min_y)` and the second one is `(max_x, max_y)`.
+    pub fn intersects(&self, other: &Rect) -> bool {
+        intersects(self, other)
+    }
+    /// Returns whether rectangle fully contains other rectangle or not.
     ///
     /// # Example
     /// ```rust
-    /// # use ggengine::mathcore::shapes::{Rect, Shape, PolygonLike};
-    /// # use ggengine::mathcore::transforms::{Rotatable};
-    /// # use ggengine::mathcore::vectors::{Vector2, Vertex, Point};
-    /// # use ggengine::mathcore::floats::FloatOperations;
+    /// # use ggengine::mathcore::shapes::Rect;
+    /// # use ggengine::mathcore::vectors::Point;
     /// # use ggengine::mathcore::{Angle, Size};
-    /// let rect: Rect = Rect::from_origin(
-    ///     Point::zero(),
-    ///     Angle::from_degrees(45.0),
-    ///     Size::from_value(2.0), Size::from_value(2.0)
-    /// );
-    /// assert_eq!(
-    ///     rect.aabb().round_up_to(1),
-    ///     [Point::from([-1.4, -1.4]), Point::from([1.4, 1.4])] // sqrt(2)
-    /// );
+    /// let rect1: Rect = Rect::from_origin(Point::zero(), Angle::zero(), Size::from_value(4.0), Size::from_value(4.0));
+    /// let rect2: Rect = Rect::from_origin(Point::zero(), Angle::from_degrees(45.0), Size::from_value(2.0), Size::from_value(2.0));
+    /// assert!(rect1.contains_rect(&rect2));
+    /// assert!(!rect2.contains_rect(&rect1));
+    /// assert!(rect1.intersects(&rect2));
     /// ```
     ///
-    pub fn aabb(self) -> [Point; 2] {
-        let (mut min_x, mut max_x, mut min_y, mut max_y): (f32, f32, f32, f32) = (
-            f32::INFINITY,
-            f32::NEG_INFINITY,
-            f32::INFINITY,
-            f32::NEG_INFINITY,
-        );
-        for vertex in self.vertices {
-            min_x = min_x.min(vertex.x);
-            max_x = max_x.max(vertex.x);
-            min_y = min_y.min(vertex.y);
-            max_y = max_y.max(vertex.y);
-        }
-        [Point::from([min_x, min_y]), Point::from([max_x, max_y])]
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        other
+            .vertices
+            .iter()
+            .all(|vertex| self.contains_point(*vertex))
+    }
+
+    /// Returns rectangle that is inflated by given amount in every direction (so that width and height grow by double amount).
+    ///
+    /// Negative amount deflates rectangle, although rectangle never shrinks past the smallest possible size.
+    ///
+    pub fn inflate(self, amount: f32) -> Rect {
+        Rect::from_origin(
+            self.origin,
+            self.angle,
+            Size::from_value((self.width() + 2.0 * amount).max(EPSILON)),
+            Size::from_value((self.height() + 2.0 * amount).max(EPSILON)),
+        )
+    }
+    /// Returns axis-aligned rectangle which covers intersection of rectangle's bounding box and given box,
+    /// or `None` if they do not intersect.
+    ///
+    /// For axis-aligned rectangles, that is exactly the part of a rectangle that lies inside of a box.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::shapes::{Aabb, Rect, Shape};
+    /// # use ggengine::mathcore::vectors::Point;
+    /// # use ggengine::mathcore::{Angle, Size};
+    /// let rect: Rect = Rect::from_origin(Point::zero(), Angle::zero(), Size::from_value(4.0), Size::from_value(4.0));
+    /// let clipped: Rect = rect
+    ///     .clip_to(&Aabb::new(Point::from([1.0, 1.0]), Point::from([5.0, 5.0])))
+    ///     .expect("Rectangle and box intersect");
+    /// assert_eq!(clipped.aabb(), Aabb::new(Point::from([1.0, 1.0]), Point::from([2.0, 2.0])));
+    /// ```
+    ///
+    pub fn clip_to(&self, aabb: &Aabb) -> Option<Rect> {
+        self.aabb().intersection(*aabb).map(Rect::from_aabb)
     }
 }
 impl Shape for Rect {
@@ -428,6 +762,10 @@ impl Shape for Rect {
     }
 
     impl_contains_point_for_polygonlike!();
+
+    fn aabb(&self) -> Aabb {
+        Aabb::from_points(&self.vertices).expect("Polygon has vertices")
+    }
 }
 impl PolygonLike for Rect {
     fn vertices(&self) -> &[Vertex] {
@@ -456,7 +794,7 @@ impl Rotatable for Rect {
     fn rotate_on(&mut self, angle: Angle) {
         self.angle = angle;
 
-        let transform_matrix: Matrix3x3 = combine_matrices(vec![
+        let transform_matrix: Matrix3x3 = combine_matrices([
             Transform::Translation {
                 vector: -self.origin,
             }
@@ -481,7 +819,7 @@ impl Scalable for Rect {
         self.size.0 *= size_scale.0;
         self.size.1 *= size_scale.1;
 
-        let transform_matrix: Matrix3x3 = combine_matrices(vec![
+        let transform_matrix: Matrix3x3 = combine_matrices([
             Transform::Translation {
                 vector: -self.origin,
             }
@@ -499,68 +837,1282 @@ impl Scalable for Rect {
 }
 impl Transformable for Rect {}
 
-#[cfg(test)]
-mod tests {
-    use crate::mathcore::{
-        shapes::Segment,
-        transforms::{Rotatable, Translatable},
-        vectors::{Point, Vector2},
-        Angle,
-    };
+/// Returns signed area of a simple polygon which is represented by given vertices (shoelace formula).
+///
+/// Area is positive if vertices are ordered counterclockwise and negative if they are ordered clockwise.
+/// Polygons with less than 3 vertices have zero area.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::shapes::polygon_signed_area;
+/// # use ggengine::mathcore::vectors::Vertex;
+/// let square: [Vertex; 4] = [
+///     Vertex::from([0.0, 0.0]),
+///     Vertex::from([2.0, 0.0]),
+///     Vertex::from([2.0, 2.0]),
+///     Vertex::from([0.0, 2.0]),
+/// ];
+/// assert_eq!(polygon_signed_area(&square), 4.0);
+/// assert_eq!(polygon_signed_area(&[square[3], square[2], square[1], square[0]]), -4.0);
+/// ```
+///
+pub fn polygon_signed_area(vertices: &[Vertex]) -> f32 {
+    let n: usize = vertices.len();
+    if n < 3 {
+        return 0.0;
+    }
+    // translating polygon to its first vertex keeps precision for polygons that are far from the origin
+    let first: Vertex = vertices[0];
+    let mut doubled_area: f32 = 0.0;
+    for i in 1..(n - 1) {
+        doubled_area += (vertices[i] - first) ^ (vertices[i + 1] - first);
+    }
+    doubled_area / 2.0
+}
+/// Returns area of a simple (not self-intersecting) polygon which is represented by given vertices.
+///
+/// Polygon may be concave and its vertices may be ordered in any winding order.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::shapes::polygon_area;
+/// # use ggengine::mathcore::vectors::Vertex;
+/// let l_shape: [Vertex; 6] = [
+///     Vertex::from([0.0, 0.0]),
+///     Vertex::from([2.0, 0.0]),
+///     Vertex::from([2.0, 1.0]),
+///     Vertex::from([1.0, 1.0]),
+///     Vertex::from([1.0, 2.0]),
+///     Vertex::from([0.0, 2.0]),
+/// ];
+/// assert_eq!(polygon_area(&l_shape), 3.0);
+/// ```
+///
+pub fn polygon_area(vertices: &[Vertex]) -> f32 {
+    polygon_signed_area(vertices).abs()
+}
+/// Returns perimeter of a polygon which is represented by given vertices.
+///
+pub fn polygon_perimeter(vertices: &[Vertex]) -> f32 {
+    let n: usize = vertices.len();
+    (0..n)
+        .map(|i| (vertices[(i + 1) % n] - vertices[i]).magnitude())
+        .sum()
+}
+/// Returns centroid (center of mass) of a simple polygon which is represented by given vertices.
+///
+/// If polygon has zero area (there are less than 3 vertices or all of them are collinear), `None` is returned.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::shapes::polygon_centroid;
+/// # use ggengine::mathcore::vectors::{Point, Vertex};
+/// let l_shape: [Vertex; 6] = [
+///     Vertex::from([0.0, 0.0]),
+///     Vertex::from([2.0, 0.0]),
+///     Vertex::from([2.0, 1.0]),
+///     Vertex::from([1.0, 1.0]),
+///     Vertex::from([1.0, 2.0]),
+///     Vertex::from([0.0, 2.0]),
+/// ];
+/// assert_eq!(polygon_centroid(&l_shape), Some(Point::from([5.0 / 6.0, 5.0 / 6.0])));
+/// assert!(polygon_centroid(&l_shape[..2]).is_none());
+/// ```
+///
+pub fn polygon_centroid(vertices: &[Vertex]) -> Option<Point> {
+    let signed_area: f32 = polygon_signed_area(vertices);
+    if equal(signed_area, 0.0) {
+        return None;
+    }
 
-    #[test]
-    fn line_segment2d() {
-        use super::Segment;
+    let first: Vertex = vertices[0];
+    let mut weighted_sum: Vector2 = Vector2::zero();
+    for i in 1..(vertices.len() - 1) {
+        let (a, b): (Vector2, Vector2) = (vertices[i] - first, vertices[i + 1] - first);
+        weighted_sum += (a + b) * (a ^ b);
+    }
+    Some(first + weighted_sum / (6.0 * signed_area))
+}
 
-        let mut line1: Segment = Segment::from([Point::from([0.0, 0.0]), Point::from([4.0, 4.0])]);
-        assert_eq!(line1.length(), 4.0 * 2.0_f32.sqrt());
+/// Returns edges of a convex shape together with a sign that makes `edge ^ (point - edge_start)`
+/// non-negative for points that lie inside of a shape.
+///
+fn inward_edges(shape: &impl Convex) -> (Vec<Segment>, f32) {
+    let orientation: f32 = if polygon_signed_area(shape.vertices()) < 0.0 {
+        -1.0
+    } else {
+        1.0
+    };
+    (shape.edges(), orientation)
+}
+/// Clips segment to convex shape (Cyrus-Beck algorithm), returning the part of a segment that lies inside of a shape,
+/// or `None` if segment lies outside of it.
+///
+/// Direction of a segment is preserved.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::shapes::{clip_segment_to, Rect, Segment};
+/// # use ggengine::mathcore::vectors::Point;
+/// let view: Rect = Rect::from_min_max(Point::from([0.0, 0.0]), Point::from([4.0, 4.0]));
+/// let ray: Segment = Segment::from([Point::from([-2.0, 1.0]), Point::from([6.0, 1.0])]);
+/// assert_eq!(
+///     clip_segment_to(ray, &view),
+///     Some(Segment::from([Point::from([0.0, 1.0]), Point::from([4.0, 1.0])]))
+/// );
+/// assert!(clip_segment_to(Segment::from([Point::from([5.0, 0.0]), Point::from([5.0, 4.0])]), &view).is_none());
+/// ```
+///
+pub fn clip_segment_to(segment: Segment, shape: &impl Convex) -> Option<Segment> {
+    let (edges, orientation): (Vec<Segment>, f32) = inward_edges(shape);
+    let (start, direction): (Point, Vector2) = (segment.points[0], segment.slope());
 
-        let mut line2: Segment = Segment::from([Point::from([0.0, 6.0]), Point::from([3.0, 0.0])]);
+    let (mut t_enter, mut t_exit): (f32, f32) = (0.0, 1.0);
+    for edge in edges {
+        let slope: Vector2 = edge.slope();
+        let numerator: f32 = orientation * (slope ^ (start - edge.points[0]));
+        let denominator: f32 = orientation * (slope ^ direction);
+        if equal(denominator, 0.0) {
+            // segment is parallel to an edge
+            if numerator < 0.0 {
+                return None;
+            }
+            continue;
+        }
 
-        assert_eq!((line1.k(), line1.b()), (1.0, 0.0));
-        assert_eq!((line2.k(), line2.b()), (-2.0, 6.0));
+        let t: f32 = -numerator / denominator;
+        if denominator > 0.0 {
+            t_enter = t_enter.max(t);
+        } else {
+            t_exit = t_exit.min(t);
+        }
+        if t_enter > t_exit {
+            return None;
+        }
+    }
+    Some(Segment::from([
+        start + direction * t_enter,
+        start + direction * t_exit,
+    ]))
+}
+/// Clips polygon to convex shape (Sutherland-Hodgman algorithm), returning vertices of their intersection.
+///
+/// Clipped polygon may be concave, but it should not be self-intersecting;
+/// vertices of resulting polygon keep winding order of clipped polygon.
+/// If polygons do not intersect (or their intersection is degenerate), empty `Vec` is returned.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::shapes::{clip_polygon_to, polygon_area, Rect};
+/// # use ggengine::mathcore::vectors::{Point, Vertex};
+/// let light: [Vertex; 3] = [
+///     Vertex::from([2.0, 2.0]),
+///     Vertex::from([8.0, -1.0]),
+///     Vertex::from([8.0, 5.0]),
+/// ];
+/// let room: Rect = Rect::from_min_max(Point::from([0.0, 0.0]), Point::from([4.0, 4.0]));
+/// let lit: Vec<Vertex> = clip_polygon_to(&light, &room);
+/// assert_eq!(lit.len(), 3);
+/// assert_eq!(polygon_area(&lit), 2.0);
+/// ```
+///
+pub fn clip_polygon_to(polygon: &[Vertex], shape: &impl Convex) -> Vec<Vertex> {
+    let (edges, orientation): (Vec<Segment>, f32) = inward_edges(shape);
 
-        assert_eq!(line1.intersection(line2).unwrap(), Point::from([2.0, 2.0]));
+    let mut output: Vec<Vertex> = polygon.to_vec();
+    let mut input: Vec<Vertex> = Vec::with_capacity(polygon.len());
+    for edge in edges {
+        if output.is_empty() {
+            break;
+        }
+        std::mem::swap(&mut input, &mut output);
+        output.clear();
 
-        line1.translate_on(Vector2::from([-2.0, -2.0]));
-        line1.rotate_on(Angle::from_degrees(45.0));
-        line2 = Segment::from([Point::from([0.0, 0.0]), Point::from([0.0, 4.0])]);
+        let slope: Vector2 = edge.slope();
+        let distance = |point: Vertex| orientation * (slope ^ (point - edge.points[0]));
+        let mut previous: Vertex = input[input.len() - 1];
+        for &current in &input {
+            let (previous_distance, current_distance): (f32, f32) =
+                (distance(previous), distance(current));
+            if (previous_distance >= 0.0) != (current_distance >= 0.0) {
+                let t: f32 = previous_distance / (previous_distance - current_distance);
+                output.push(previous + (current - previous) * t);
+            }
+            if current_distance >= 0.0 {
+                output.push(current);
+            }
+            previous = current;
+        }
+    }
 
-        assert_eq!(line1.k(), f32::INFINITY);
-        assert!(line1.intersection(line2).is_none());
+    if polygon_area(&output) <= EPSILON {
+        output.clear();
+    }
+    output
+}
 
-        line1 = Segment::from([Point::from([-1.0, 2.0]), Point::from([1.0, 2.0])]);
-        line2 = Segment::from([Point::from([1.0, 2.0]), Point::from([2.0, 2.0])]);
-        assert!(line1.intersection(line2).is_some());
+/// Returns winding of a convex polygon which is represented by given vertices.
+///
+/// `Sign::Positive` corresponds to counterclockwise winding, `Sign::Negative` corresponds to clockwise winding.
+/// If vertices do not form strictly convex polygon (there are less than 3 vertices, some of them are
+/// collinear or polygon is self-intersecting), `None` is returned.
+///
+fn convex_winding(vertices: &[Vertex]) -> Option<Sign> {
+    let n: usize = vertices.len();
+    if n < 3 {
+        return None;
     }
 
-    #[test]
-    fn rect2d() {
-        use super::{PolygonLike, Rect};
-        use crate::mathcore::{transforms::Scalable, Size};
+    let mut winding: Sign = Sign::Zero;
+    let mut total_turn: f32 = 0.0;
+    for i in 0..n {
+        let (a, b, c): (Vertex, Vertex, Vertex) =
+            (vertices[i], vertices[(i + 1) % n], vertices[(i + 2) % n]);
+        let (ab, bc): (Vector2, Vector2) = (b - a, c - b);
+        let cross: f32 = ab ^ bc;
+        if equal(cross, 0.0) {
+            return None;
+        }
 
-        let mut rect1: Rect = Rect::from_origin(
-            Point::from([1.0, 1.0]),
-            Angle::zero(),
-            Size::from_value(3.0),
-            Size::from_value(2.0),
-        );
+        let sign: Sign = Sign::from(cross);
+        if winding == Sign::Zero {
+            winding = sign;
+        } else if winding != sign {
+            return None;
+        }
+        total_turn += cross.atan2(ab * bc);
+    }
+    // Polygon that winds around its interior more than once is self-intersecting.
+    if equal(
+        total_turn.abs().round_up_to(3),
+        std::f32::consts::TAU.round_up_to(3),
+    ) {
+        Some(winding)
+    } else {
+        None
+    }
+}
+/// Transforms given vertices by applying transformation matrix to them.
+///
+fn transform_vertices(vertices: &mut [Vertex], transform_matrix: Matrix3x3) {
+    vertices
+        .iter_mut()
+        .for_each(|vertex| *vertex = transform_matrix.apply_to(*vertex));
+}
 
-        assert_eq!(
-            rect1.vertices(),
-            [
-                Point::from([-0.5, 2.0]),
-                Point::from([2.5, 2.0]),
-                Point::from([2.5, 0.0]),
-                Point::from([-0.5, 0.0])
-            ]
-        );
-        assert_eq!(
-            rect1.edges(),
-            [
-                Segment::from([Point::from([-0.5, 2.0]), Point::from([2.5, 2.0])]),
-                Segment::from([Point::from([2.5, 2.0]), Point::from([2.5, 0.0])]),
-                Segment::from([Point::from([2.5, 0.0]), Point::from([-0.5, 0.0])]),
+/// [`Triangle`] struct represents transformable two-dimensional triangle on a surface.
+///
+/// Triangle is constructed from its vertices, which are stored in clockwise order (same as [`Rect`] vertices).
+/// Origin of a triangle is its centroid; angle and size are counted relative to the shape triangle was constructed with
+/// (so newly constructed triangle has zero angle and `(1.0, 1.0)` size).
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::shapes::{Triangle, Shape, PolygonLike};
+/// # use ggengine::mathcore::transforms::Translatable;
+/// # use ggengine::mathcore::vectors::{Vector2, Vertex, Point};
+/// let mut triangle: Triangle = Triangle::new([
+///     Vertex::from([0.0, 0.0]),
+///     Vertex::from([3.0, 0.0]),
+///     Vertex::from([0.0, 3.0]),
+/// ]).expect("Vertices form a triangle");
+/// assert_eq!(triangle.area(), 4.5);
+/// assert_eq!(triangle.origin(), Point::from([1.0, 1.0]));
+/// assert!(triangle.contains_point(Point::from([1.0, 1.0])));
+///
+/// triangle.translate_on(Vector2::from([1.0, 1.0]));
+/// assert_eq!(
+///     triangle.vertices(),
+///     [
+///         Vertex::from([1.0, 4.0]),
+///         Vertex::from([4.0, 1.0]),
+///         Vertex::from([1.0, 1.0]),
+///     ]
+/// );
+///
+/// assert!(Triangle::new([
+///     Vertex::from([0.0, 0.0]),
+///     Vertex::from([1.0, 1.0]),
+///     Vertex::from([2.0, 2.0]),
+/// ]).is_none()); // degenerate triangle
+/// ```
+///
+#[derive(Serialize, Copy, Clone, Debug)]
+pub struct Triangle {
+    /// Array of triangle's vertices.
+    ///
+    vertices: [Vertex; 3],
+
+    /// Origin of a triangle (centroid).
+    ///
+    origin: Point,
+    /// Angle at which triangle is currently rotated.
+    ///
+    angle: Angle,
+    /// Scale of a triangle relative to its initial size.
+    ///
+    size: (Size, Size),
+}
+impl Triangle {
+    /// Constructs triangle from given vertices.
+    ///
+    /// Vertices can be passed in any winding order, they will be reordered clockwise.
+    /// If vertices do not form a triangle (two of them coincide or all of them are collinear), `None` is returned.
+    ///
+    pub fn new(vertices: [Vertex; 3]) -> Option<Self> {
+        let mut vertices: [Vertex; 3] = vertices;
+        if convex_winding(&vertices)? == Sign::Positive {
+            vertices.reverse();
+        }
+
+        Some(Triangle {
+            vertices,
+
+            origin: (vertices[0] + vertices[1] + vertices[2]) / 3.0,
+            angle: Angle::zero(),
+            size: (Size::from_value(1.0), Size::from_value(1.0)),
+        })
+    }
+}
+impl<'de> Deserialize<'de> for Triangle {
+    /// Deserializes triangle, checking that its vertices form a triangle.
+    ///
+    /// Vertices are reordered and origin is recalculated like in [`Triangle::new`].
+    ///
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// Raw representation of [`Triangle`] which is not validated.
+        ///
+        #[derive(Deserialize)]
+        #[serde(rename = "Triangle")]
+        struct RawTriangle {
+            /// Array of triangle's vertices.
+            ///
+            vertices: [Vertex; 3],
+            /// Angle at which triangle is currently rotated.
+            ///
+            angle: Angle,
+            /// Scale of a triangle relative to its initial size.
+            ///
+            size: (Size, Size),
+        }
+
+        let raw: RawTriangle = RawTriangle::deserialize(deserializer)?;
+        let mut triangle: Triangle = Triangle::new(raw.vertices)
+            .ok_or_else(|| D::Error::custom("vertices do not form a triangle"))?;
+        triangle.angle = raw.angle;
+        triangle.size = raw.size;
+        Ok(triangle)
+    }
+}
+impl Shape for Triangle {
+    fn perimeter(&self) -> f32 {
+        polygon_perimeter(&self.vertices)
+    }
+
+    fn area(&self) -> f32 {
+        polygon_area(&self.vertices)
+    }
+
+    impl_contains_point_for_polygonlike!();
+
+    fn aabb(&self) -> Aabb {
+        Aabb::from_points(&self.vertices).expect("Polygon has vertices")
+    }
+}
+impl PolygonLike for Triangle {
+    fn vertices(&self) -> &[Vertex] {
+        &self.vertices
+    }
+}
+impl Convex for Triangle {}
+impl Translatable for Triangle {
+    fn origin(&self) -> Point {
+        self.origin
+    }
+
+    fn translate_on(&mut self, vector: Vector2) {
+        self.origin += vector;
+
+        self.vertices
+            .iter_mut()
+            .for_each(|vertex| *vertex += vector);
+    }
+}
+impl Rotatable for Triangle {
+    fn angle(&self) -> Angle {
+        self.angle
+    }
+
+    fn rotate_on(&mut self, angle: Angle) {
+        self.angle += angle;
+
+        let transform_matrix: Matrix3x3 = combine_matrices([
+            Transform::Translation {
+                vector: -self.origin,
+            }
+            .matrix(),
+            Transform::Rotation { angle }.matrix(),
+            Transform::Translation {
+                vector: self.origin,
+            }
+            .matrix(),
+        ]);
+        transform_vertices(&mut self.vertices, transform_matrix);
+    }
+}
+impl Scalable for Triangle {
+    fn size(&self) -> (Size, Size) {
+        self.size
+    }
+
+    fn scale(&mut self, size_scale: (Size, Size)) {
+        self.size.0 *= size_scale.0;
+        self.size.1 *= size_scale.1;
+
+        let transform_matrix: Matrix3x3 = combine_matrices([
+            Transform::Translation {
+                vector: -self.origin,
+            }
+            .matrix(),
+            Transform::Scaling { size_scale }.matrix(),
+            Transform::Translation {
+                vector: self.origin,
+            }
+            .matrix(),
+        ]);
+        transform_vertices(&mut self.vertices, transform_matrix);
+    }
+}
+impl Transformable for Triangle {}
+
+/// [`ConvexPolygon`] struct represents transformable two-dimensional convex polygon with arbitrary amount of vertices.
+///
+/// Polygon is constructed from its vertices, which are stored in clockwise order (same as [`Rect`] vertices).
+/// Origin of a polygon is its centroid (see [`polygon_centroid`]); angle and size are counted relative to the shape polygon was constructed with
+/// (so newly constructed polygon has zero angle and `(1.0, 1.0)` size).
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::shapes::{ConvexPolygon, Shape, PolygonLike};
+/// # use ggengine::mathcore::vectors::{Vertex, Point};
+/// let hexagon: ConvexPolygon = ConvexPolygon::new(vec![
+///     Vertex::from([1.0, 0.0]),
+///     Vertex::from([2.0, 0.0]),
+///     Vertex::from([3.0, 1.0]),
+///     Vertex::from([2.0, 2.0]),
+///     Vertex::from([1.0, 2.0]),
+///     Vertex::from([0.0, 1.0]),
+/// ]).expect("Vertices form a convex polygon");
+/// assert_eq!(hexagon.vertices().len(), 6);
+/// assert_eq!(hexagon.area(), 4.0);
+/// assert!(hexagon.contains_point(Point::from([0.5, 1.0])));
+///
+/// assert!(ConvexPolygon::new(vec![
+///     Vertex::from([0.0, 0.0]),
+///     Vertex::from([2.0, 0.0]),
+///     Vertex::from([1.0, 0.5]),
+///     Vertex::from([1.0, 2.0]),
+/// ]).is_none()); // concave polygon
+/// ```
+///
+#[derive(Serialize, Clone, Debug)]
+pub struct ConvexPolygon {
+    /// Vector of polygon's vertices.
+    ///
+    vertices: Vec<Vertex>,
+
+    /// Origin of a polygon (centroid).
+    ///
+    origin: Point,
+    /// Angle at which polygon is currently rotated.
+    ///
+    angle: Angle,
+    /// Scale of a polygon relative to its initial size.
+    ///
+    size: (Size, Size),
+}
+impl ConvexPolygon {
+    /// Constructs convex polygon from given vertices.
+    ///
+    /// Vertices can be passed in any winding order, they will be reordered clockwise.
+    /// If vertices do not form strictly convex polygon (there are less than 3 vertices, some of them are
+    /// collinear or polygon is self-intersecting), `None` is returned.
+    ///
+    pub fn new(vertices: Vec<Vertex>) -> Option<Self> {
+        let mut vertices: Vec<Vertex> = vertices;
+        if convex_winding(&vertices)? == Sign::Positive {
+            vertices.reverse();
+        }
+
+        let origin: Point = polygon_centroid(&vertices)?;
+        Some(ConvexPolygon {
+            vertices,
+
+            origin,
+            angle: Angle::zero(),
+            size: (Size::from_value(1.0), Size::from_value(1.0)),
+        })
+    }
+}
+impl<'de> Deserialize<'de> for ConvexPolygon {
+    /// Deserializes polygon, checking that its vertices form strictly convex polygon.
+    ///
+    /// Vertices are reordered and origin is recalculated like in [`ConvexPolygon::new`].
+    ///
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// Raw representation of [`ConvexPolygon`] which is not validated.
+        ///
+        #[derive(Deserialize)]
+        #[serde(rename = "ConvexPolygon")]
+        struct RawConvexPolygon {
+            /// Vector of polygon's vertices.
+            ///
+            vertices: Vec<Vertex>,
+            /// Angle at which polygon is currently rotated.
+            ///
+            angle: Angle,
+            /// Scale of a polygon relative to its initial size.
+            ///
+            size: (Size, Size),
+        }
+
+        let raw: RawConvexPolygon = RawConvexPolygon::deserialize(deserializer)?;
+        let mut polygon: ConvexPolygon = ConvexPolygon::new(raw.vertices)
+            .ok_or_else(|| D::Error::custom("vertices do not form strictly convex polygon"))?;
+        polygon.angle = raw.angle;
+        polygon.size = raw.size;
+        Ok(polygon)
+    }
+}
+impl Shape for ConvexPolygon {
+    fn perimeter(&self) -> f32 {
+        polygon_perimeter(&self.vertices)
+    }
+
+    fn area(&self) -> f32 {
+        polygon_area(&self.vertices)
+    }
+
+    impl_contains_point_for_polygonlike!();
+
+    fn aabb(&self) -> Aabb {
+        Aabb::from_points(&self.vertices).expect("Polygon has vertices")
+    }
+}
+impl PolygonLike for ConvexPolygon {
+    fn vertices(&self) -> &[Vertex] {
+        &self.vertices
+    }
+}
+impl Convex for ConvexPolygon {}
+impl Translatable for ConvexPolygon {
+    fn origin(&self) -> Point {
+        self.origin
+    }
+
+    fn translate_on(&mut self, vector: Vector2) {
+        self.origin += vector;
+
+        self.vertices
+            .iter_mut()
+            .for_each(|vertex| *vertex += vector);
+    }
+}
+impl Rotatable for ConvexPolygon {
+    fn angle(&self) -> Angle {
+        self.angle
+    }
+
+    fn rotate_on(&mut self, angle: Angle) {
+        self.angle += angle;
+
+        let transform_matrix: Matrix3x3 = combine_matrices([
+            Transform::Translation {
+                vector: -self.origin,
+            }
+            .matrix(),
+            Transform::Rotation { angle }.matrix(),
+            Transform::Translation {
+                vector: self.origin,
+            }
+            .matrix(),
+        ]);
+        transform_vertices(&mut self.vertices, transform_matrix);
+    }
+}
+impl Scalable for ConvexPolygon {
+    fn size(&self) -> (Size, Size) {
+        self.size
+    }
+
+    fn scale(&mut self, size_scale: (Size, Size)) {
+        self.size.0 *= size_scale.0;
+        self.size.1 *= size_scale.1;
+
+        let transform_matrix: Matrix3x3 = combine_matrices([
+            Transform::Translation {
+                vector: -self.origin,
+            }
+            .matrix(),
+            Transform::Scaling { size_scale }.matrix(),
+            Transform::Translation {
+                vector: self.origin,
+            }
+            .matrix(),
+        ]);
+        transform_vertices(&mut self.vertices, transform_matrix);
+    }
+}
+impl Transformable for ConvexPolygon {}
+impl From<Triangle> for ConvexPolygon {
+    fn from(value: Triangle) -> Self {
+        ConvexPolygon {
+            vertices: value.vertices.to_vec(),
+
+            origin: value.origin,
+            angle: value.angle,
+            size: value.size,
+        }
+    }
+}
+impl From<Rect> for ConvexPolygon {
+    fn from(value: Rect) -> Self {
+        ConvexPolygon {
+            vertices: value.vertices.to_vec(),
+
+            origin: value.origin,
+            angle: value.angle,
+            size: (Size::from_value(1.0), Size::from_value(1.0)),
+        }
+    }
+}
+
+/// Returns segments that form a core of a rounded shape.
+///
+/// Core that consists of one point is represented by degenerate segment, core that consists of two points
+/// is represented by one segment and core that consists of three or more points is represented by polygon edges.
+///
+fn core_segments(core: &[Point]) -> Vec<Segment> {
+    match core.len() {
+        0 => Vec::new(),
+        1 => vec![Segment::from([core[0], core[0]])],
+        2 => vec![Segment::from([core[0], core[1]])],
+        n => (0..n)
+            .map(|i| Segment::from([core[i], core[(i + 1) % n]]))
+            .collect(),
+    }
+}
+/// Returns whether convex polygon which is represented by given vertices contains point.
+///
+/// If there are less than 3 vertices, `false` is returned.
+///
+fn convex_vertices_contain(vertices: &[Vertex], point: Point) -> bool {
+    let n: usize = vertices.len();
+    if n < 3 {
+        return false;
+    }
+
+    let mut winding: Sign = Sign::Zero;
+    for i in 0..n {
+        let sign: Sign =
+            Sign::from(((vertices[(i + 1) % n] - vertices[i]) ^ (point - vertices[i])).correct(4));
+        if sign == Sign::Zero {
+            continue;
+        }
+        if winding == Sign::Zero {
+            winding = sign;
+        } else if winding != sign {
+            return false;
+        }
+    }
+    true
+}
+/// Returns distance from the core of a rounded shape to the given point.
+///
+fn core_distance_to_point(core: &[Point], point: Point) -> f32 {
+    if convex_vertices_contain(core, point) {
+        return 0.0;
+    }
+    core_segments(core)
+        .iter()
+        .map(|segment| segment.distance_to_point(point))
+        .fold(f32::INFINITY, f32::min)
+}
+
+/// [`Rounded`] trait defines shapes that can be represented as a set of points which lie no further than radius from the core.
+///
+/// Core can be a point (circles), a segment (capsules) or a convex polygon (rounded rectangles).
+/// That representation allows cheap and precise overlap tests between rounded shapes and polygons.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::shapes::{Capsule, Circle, Rect, Rounded, Segment};
+/// # use ggengine::mathcore::vectors::Point;
+/// # use ggengine::mathcore::{Angle, Size};
+/// let circle: Circle = Circle::from_origin(Point::from([0.0, 3.0]), Size::from_value(1.0));
+/// let capsule: Capsule = Capsule::new(
+///     Segment::from([Point::from([-2.0, 0.0]), Point::from([2.0, 0.0])]),
+///     Size::from_value(2.0),
+/// );
+/// assert!(capsule.overlaps_rounded(&circle));
+///
+/// let rect: Rect = Rect::from_origin(
+///     Point::from([6.0, 0.0]),
+///     Angle::zero(),
+///     Size::from_value(2.0),
+///     Size::from_value(2.0),
+/// );
+/// assert!(!capsule.overlaps_polygon(&rect));
+/// assert!(!circle.overlaps_polygon(&rect));
+/// ```
+///
+pub trait Rounded: Shape {
+    /// Returns points which form a core of a rounded shape.
+    ///
+    /// One point represents a point core, two points represent a segment core and three or more points
+    /// represent a convex polygon core.
+    ///
+    fn core(&self) -> Vec<Point>;
+    /// Returns radius of rounding around the core.
+    ///
+    fn radius(&self) -> f32;
+
+    /// Returns whether two rounded shapes overlap or not. Touching shapes are considered overlapping.
+    ///
+    fn overlaps_rounded<R: Rounded>(&self, other: &R) -> bool
+    where
+        Self: Sized,
+    {
+        let (core1, core2): (Vec<Point>, Vec<Point>) = (self.core(), other.core());
+        if core2
+            .iter()
+            .any(|point| convex_vertices_contain(&core1, *point))
+            || core1
+                .iter()
+                .any(|point| convex_vertices_contain(&core2, *point))
+        {
+            return true;
+        }
+
+        let distance: f32 = core_segments(&core1)
+            .iter()
+            .flat_map(|segment1| {
+                core_segments(&core2)
+                    .into_iter()
+                    .map(move |segment2| segment1.distance_to_segment(segment2))
+            })
+            .fold(f32::INFINITY, f32::min);
+        distance <= self.radius() + other.radius()
+            || equal(distance, self.radius() + other.radius())
+    }
+    /// Returns whether rounded shape overlaps polygon or not. Touching shapes are considered overlapping.
+    ///
+    fn overlaps_polygon<P: PolygonLike>(&self, polygon: &P) -> bool
+    where
+        Self: Sized,
+    {
+        let core: Vec<Point> = self.core();
+        if core.iter().any(|point| polygon.contains_point(*point))
+            || polygon
+                .vertices()
+                .iter()
+                .any(|vertex| convex_vertices_contain(&core, *vertex))
+        {
+            return true;
+        }
+
+        let distance: f32 = core_segments(&core)
+            .iter()
+            .flat_map(|segment| {
+                polygon
+                    .edges()
+                    .into_iter()
+                    .map(move |edge| segment.distance_to_segment(edge))
+            })
+            .fold(f32::INFINITY, f32::min);
+        distance <= self.radius() || equal(distance, self.radius())
+    }
+}
+
+/// [`Circle`] struct represents transformable two-dimensional circle on a surface.
+///
+/// Since circle can only be scaled uniformly, its radius is scaled by the largest of two scaling factors.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::shapes::{Circle, Shape};
+/// # use ggengine::mathcore::transforms::Scalable;
+/// # use ggengine::mathcore::vectors::Point;
+/// # use ggengine::mathcore::Size;
+/// # use std::f32::consts::PI;
+/// let mut circle: Circle = Circle::from_origin(Point::zero(), Size::from_value(2.0));
+/// assert_eq!(circle.area(), 4.0 * PI);
+/// assert!(circle.contains_point(Point::from([0.0, 2.0])));
+///
+/// circle.scale((Size::from_value(2.0), Size::from_value(1.0)));
+/// assert_eq!(circle.perimeter(), 8.0 * PI);
+/// ```
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug)]
+pub struct Circle {
+    /// Origin of a circle (its center).
+    ///
+    origin: Point,
+    /// Angle at which circle is currently rotated.
+    ///
+    angle: Angle,
+    /// Radius of a circle.
+    ///
+    radius: Size,
+}
+impl Circle {
+    /// Constructs circle with given origin and radius.
+    ///
+    pub fn from_origin(origin: Point, radius: Size) -> Self {
+        Circle {
+            origin,
+            angle: Angle::zero(),
+            radius,
+        }
+    }
+}
+impl Shape for Circle {
+    fn perimeter(&self) -> f32 {
+        std::f32::consts::TAU * self.radius.get()
+    }
+
+    fn area(&self) -> f32 {
+        std::f32::consts::PI * self.radius.get() * self.radius.get()
+    }
+
+    fn contains_point(&self, point: Point) -> bool {
+        let distance: f32 = (point - self.origin).magnitude();
+        distance <= self.radius.get() || equal(distance, self.radius.get())
+    }
+
+    fn aabb(&self) -> Aabb {
+        Aabb::new(self.origin, self.origin).expand(self.radius.get())
+    }
+}
+impl Rounded for Circle {
+    fn core(&self) -> Vec<Point> {
+        vec![self.origin]
+    }
+
+    fn radius(&self) -> f32 {
+        self.radius.get()
+    }
+}
+impl Translatable for Circle {
+    fn origin(&self) -> Point {
+        self.origin
+    }
+
+    fn translate_on(&mut self, vector: Vector2) {
+        self.origin += vector;
+    }
+}
+impl Rotatable for Circle {
+    fn angle(&self) -> Angle {
+        self.angle
+    }
+
+    fn rotate_on(&mut self, angle: Angle) {
+        self.angle += angle;
+    }
+}
+impl Scalable for Circle {
+    /// For a circle, size is a tuple of two diameters.
+    ///
+    fn size(&self) -> (Size, Size) {
+        let diameter: Size = self.radius * Size::from_value(2.0);
+        (diameter, diameter)
+    }
+
+    fn scale(&mut self, size_scale: (Size, Size)) {
+        self.radius *= Size::from_value(size_scale.0.get().max(size_scale.1.get()));
+    }
+}
+impl Transformable for Circle {}
+
+/// [`Capsule`] struct represents transformable two-dimensional capsule (stadium) on a surface,
+/// which is a set of points that lie no further than radius from the line segment.
+///
+/// Width of a capsule is measured along its segment (and includes both rounded caps) and height
+/// of a capsule is equal to its diameter.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::shapes::{Capsule, Segment, Shape};
+/// # use ggengine::mathcore::transforms::Scalable;
+/// # use ggengine::mathcore::vectors::Point;
+/// # use ggengine::mathcore::Size;
+/// let mut capsule: Capsule = Capsule::new(
+///     Segment::from([Point::from([0.0, 0.0]), Point::from([4.0, 0.0])]),
+///     Size::from_value(1.0),
+/// );
+/// assert!(capsule.contains_point(Point::from([5.0, 0.0])));
+/// assert!(!capsule.contains_point(Point::from([2.0, 1.5])));
+/// assert_eq!(capsule.size(), (Size::from_value(6.0), Size::from_value(2.0)));
+///
+/// capsule.scale((Size::from_value(1.0), Size::from_value(2.0)));
+/// assert_eq!(capsule.radius(), 2.0);
+/// assert_eq!(capsule.segment().length(), 2.0);
+/// ```
+///
+#[derive(Serialize, Copy, Clone, Debug)]
+pub struct Capsule {
+    /// Segment that forms a core of a capsule.
+    ///
+    segment: Segment,
+    /// Radius of a capsule.
+    ///
+    radius: Size,
+
+    /// Origin of a capsule (midpoint of its segment).
+    ///
+    origin: Point,
+    /// Angle at which capsule is currently rotated (inclination angle of its segment).
+    ///
+    angle: Angle,
+}
+impl Capsule {
+    /// Constructs capsule from segment and radius.
+    ///
+    pub fn new(segment: Segment, radius: Size) -> Self {
+        Capsule {
+            segment,
+            radius,
+
+            origin: segment.origin(),
+            angle: segment.points[0].direction_to(segment.points[1]),
+        }
+    }
+
+    /// Returns segment that forms a core of a capsule.
+    ///
+    pub fn segment(&self) -> Segment {
+        self.segment
+    }
+    /// Returns radius of a capsule.
+    ///
+    pub fn radius(&self) -> f32 {
+        self.radius.get()
+    }
+}
+impl<'de> Deserialize<'de> for Capsule {
+    /// Deserializes capsule from its segment and radius.
+    ///
+    /// Origin and angle are recalculated like in [`Capsule::new`], so they always agree with the segment.
+    ///
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// Raw representation of [`Capsule`] which is not validated.
+        ///
+        #[derive(Deserialize)]
+        #[serde(rename = "Capsule")]
+        struct RawCapsule {
+            /// Segment that forms a core of a capsule.
+            ///
+            segment: Segment,
+            /// Radius of a capsule.
+            ///
+            radius: Size,
+        }
+
+        let raw: RawCapsule = RawCapsule::deserialize(deserializer)?;
+        Ok(Capsule::new(raw.segment, raw.radius))
+    }
+}
+impl Shape for Capsule {
+    fn perimeter(&self) -> f32 {
+        2.0 * self.segment.length() + std::f32::consts::TAU * self.radius.get()
+    }
+
+    fn area(&self) -> f32 {
+        let radius: f32 = self.radius.get();
+        2.0 * radius * self.segment.length() + std::f32::consts::PI * radius * radius
+    }
+
+    fn contains_point(&self, point: Point) -> bool {
+        let distance: f32 = self.segment.distance_to_point(point);
+        distance <= self.radius.get() || equal(distance, self.radius.get())
+    }
+
+    fn aabb(&self) -> Aabb {
+        Aabb::new(self.segment.points[0], self.segment.points[1]).expand(self.radius.get())
+    }
+}
+impl Rounded for Capsule {
+    fn core(&self) -> Vec<Point> {
+        self.segment.points.to_vec()
+    }
+
+    fn radius(&self) -> f32 {
+        self.radius.get()
+    }
+}
+impl Translatable for Capsule {
+    fn origin(&self) -> Point {
+        self.origin
+    }
+
+    fn translate_on(&mut self, vector: Vector2) {
+        self.origin += vector;
+        self.segment.translate_on(vector);
+    }
+}
+impl Rotatable for Capsule {
+    fn angle(&self) -> Angle {
+        self.angle
+    }
+
+    fn rotate_on(&mut self, angle: Angle) {
+        self.angle += angle;
+        self.segment.rotate_on(angle);
+    }
+}
+impl Scalable for Capsule {
+    fn size(&self) -> (Size, Size) {
+        let diameter: f32 = 2.0 * self.radius.get();
+        (
+            Size::from_value(self.segment.length() + diameter),
+            Size::from_value(diameter),
+        )
+    }
+
+    fn scale(&mut self, size_scale: (Size, Size)) {
+        let (width, _): (Size, Size) = self.size();
+        self.radius *= size_scale.1;
+
+        let half_length: f32 = ((width * size_scale.0).get() / 2.0 - self.radius.get()).max(0.0);
+        let direction: Vector2 = Vector2::from([half_length, 0.0]).rotate_by(self.angle);
+        self.segment = Segment::from([self.origin - direction, self.origin + direction]);
+    }
+}
+impl Transformable for Capsule {}
+
+/// [`RoundedRect`] struct represents transformable two-dimensional rectangle with rounded corners on a surface.
+///
+/// Radius of corners is clamped so that it never exceeds half of the smallest rectangle's side.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::shapes::{RoundedRect, Shape};
+/// # use ggengine::mathcore::vectors::Point;
+/// # use ggengine::mathcore::{Angle, Size};
+/// let rounded_rect: RoundedRect = RoundedRect::from_origin(
+///     Point::zero(),
+///     Angle::zero(),
+///     Size::from_value(4.0), Size::from_value(2.0),
+///     Size::from_value(0.5),
+/// );
+/// assert!(rounded_rect.contains_point(Point::from([1.5, 0.5])));
+/// assert!(!rounded_rect.contains_point(Point::from([1.95, 0.95])));
+/// assert_eq!(rounded_rect.width(), 4.0);
+/// assert_eq!(rounded_rect.height(), 2.0);
+/// ```
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug)]
+pub struct RoundedRect {
+    /// Origin of a rectangle (center point).
+    ///
+    origin: Point,
+    /// Angle at which rectangle is currently rotated.
+    ///
+    angle: Angle,
+    /// Tuple of rectangle's width and height.
+    ///
+    size: (Size, Size),
+    /// Radius of rectangle's corners.
+    ///
+    radius: Size,
+}
+impl RoundedRect {
+    /// Returns width of a rectangle.
+    ///
+    pub fn width(&self) -> f32 {
+        self.size.0.get()
+    }
+    /// Returns height of a rectangle.
+    ///
+    pub fn height(&self) -> f32 {
+        self.size.1.get()
+    }
+    /// Returns radius of rectangle's corners.
+    ///
+    pub fn corner_radius(&self) -> f32 {
+        self.radius
+            .get()
+            .min(self.width() / 2.0)
+            .min(self.height() / 2.0)
+    }
+
+    /// Constructs rectangle with rounded corners with given origin, angle, size and corner radius.
+    ///
+    pub fn from_origin(
+        origin: Point,
+        angle: Angle,
+        width: Size,
+        height: Size,
+        corner_radius: Size,
+    ) -> Self {
+        RoundedRect {
+            origin,
+            angle,
+            size: (width, height),
+            radius: corner_radius,
+        }
+    }
+}
+impl Shape for RoundedRect {
+    fn perimeter(&self) -> f32 {
+        let radius: f32 = self.corner_radius();
+        2.0 * (self.width() + self.height()) - 8.0 * radius + std::f32::consts::TAU * radius
+    }
+
+    fn area(&self) -> f32 {
+        let radius: f32 = self.corner_radius();
+        self.width() * self.height() - (4.0 - std::f32::consts::PI) * radius * radius
+    }
+
+    fn contains_point(&self, point: Point) -> bool {
+        let distance: f32 = core_distance_to_point(&self.core(), point);
+        distance <= self.corner_radius() || equal(distance, self.corner_radius())
+    }
+
+    fn aabb(&self) -> Aabb {
+        Aabb::from_points(&self.core())
+            .expect("Core of a rectangle is not empty")
+            .expand(self.corner_radius())
+    }
+}
+impl Rounded for RoundedRect {
+    fn core(&self) -> Vec<Point> {
+        let radius: f32 = self.corner_radius();
+        let (half_width, half_height): (f32, f32) = (
+            (self.width() / 2.0 - radius).max(0.0),
+            (self.height() / 2.0 - radius).max(0.0),
+        );
+
+        let model: Vec<Vector2> = match (equal(half_width, 0.0), equal(half_height, 0.0)) {
+            (true, true) => vec![Vector2::zero()],
+            (false, true) => vec![
+                Vector2::from([-half_width, 0.0]),
+                Vector2::from([half_width, 0.0]),
+            ],
+            (true, false) => vec![
+                Vector2::from([0.0, half_height]),
+                Vector2::from([0.0, -half_height]),
+            ],
+            (false, false) => vec![
+                Vector2::from([-half_width, half_height]),
+                Vector2::from([half_width, half_height]),
+                Vector2::from([half_width, -half_height]),
+                Vector2::from([-half_width, -half_height]),
+            ],
+        };
+        model
+            .into_iter()
+            .map(|vector| self.origin + vector.rotate_by(self.angle))
+            .collect()
+    }
+
+    fn radius(&self) -> f32 {
+        self.corner_radius()
+    }
+}
+impl Translatable for RoundedRect {
+    fn origin(&self) -> Point {
+        self.origin
+    }
+
+    fn translate_on(&mut self, vector: Vector2) {
+        self.origin += vector;
+    }
+}
+impl Rotatable for RoundedRect {
+    fn angle(&self) -> Angle {
+        self.angle
+    }
+
+    fn rotate_on(&mut self, angle: Angle) {
+        self.angle += angle;
+    }
+}
+impl Scalable for RoundedRect {
+    fn size(&self) -> (Size, Size) {
+        self.size
+    }
+
+    fn scale(&mut self, size_scale: (Size, Size)) {
+        self.size.0 *= size_scale.0;
+        self.size.1 *= size_scale.1;
+    }
+}
+impl Transformable for RoundedRect {}
+
+#[cfg(test)]
+mod tests {
+    use crate::mathcore::{
+        shapes::Segment,
+        transforms::{Rotatable, Translatable},
+        vectors::{Point, Vector2},
+        Angle,
+    };
+
+    #[test]
+    fn line_segment2d() {
+        use super::Segment;
+
+        let mut line1: Segment = Segment::from([Point::from([0.0, 0.0]), Point::from([4.0, 4.0])]);
+        assert_eq!(line1.length(), 4.0 * 2.0_f32.sqrt());
+
+        let mut line2: Segment = Segment::from([Point::from([0.0, 6.0]), Point::from([3.0, 0.0])]);
+
+        assert_eq!((line1.k(), line1.b()), (1.0, 0.0));
+        assert_eq!((line2.k(), line2.b()), (-2.0, 6.0));
+
+        assert_eq!(line1.intersection(line2).unwrap(), Point::from([2.0, 2.0]));
+
+        line1.translate_on(Vector2::from([-2.0, -2.0]));
+        line1.rotate_on(Angle::from_degrees(45.0));
+        line2 = Segment::from([Point::from([0.0, 0.0]), Point::from([0.0, 4.0])]);
+
+        assert_eq!(line1.k(), f32::INFINITY);
+        assert!(line1.intersection(line2).is_none());
+
+        line1 = Segment::from([Point::from([-1.0, 2.0]), Point::from([1.0, 2.0])]);
+        line2 = Segment::from([Point::from([1.0, 2.0]), Point::from([2.0, 2.0])]);
+        assert!(line1.intersection(line2).is_some());
+    }
+
+    #[test]
+    fn rect2d() {
+        use super::{PolygonLike, Rect};
+        use crate::mathcore::{transforms::Scalable, Size};
+
+        let mut rect1: Rect = Rect::from_origin(
+            Point::from([1.0, 1.0]),
+            Angle::zero(),
+            Size::from_value(3.0),
+            Size::from_value(2.0),
+        );
+
+        assert_eq!(
+            rect1.vertices(),
+            [
+                Point::from([-0.5, 2.0]),
+                Point::from([2.5, 2.0]),
+                Point::from([2.5, 0.0]),
+                Point::from([-0.5, 0.0])
+            ]
+        );
+        assert_eq!(
+            rect1.edges(),
+            [
+                Segment::from([Point::from([-0.5, 2.0]), Point::from([2.5, 2.0])]),
+                Segment::from([Point::from([2.5, 2.0]), Point::from([2.5, 0.0])]),
+                Segment::from([Point::from([2.5, 0.0]), Point::from([-0.5, 0.0])]),
                 Segment::from([Point::from([-0.5, 0.0]), Point::from([-0.5, 2.0])]),
             ]
         );
@@ -587,4 +2139,428 @@ mod tests {
         rect2.set_size((Size::from_value(9.0), Size::from_value(6.0)));
         assert_eq!(rect1.vertices(), rect2.vertices());
     }
+
+    #[test]
+    fn triangle2d() {
+        use super::{PolygonLike, Shape, Triangle};
+        use crate::mathcore::{floats::FloatOperations, transforms::Scalable, Size};
+
+        let mut triangle: Triangle = Triangle::new([
+            Point::from([0.0, 0.0]),
+            Point::from([4.0, 0.0]),
+            Point::from([0.0, 3.0]),
+        ])
+        .expect("Vertices form a triangle");
+        assert_eq!(
+            triangle.vertices(),
+            [
+                Point::from([0.0, 3.0]),
+                Point::from([4.0, 0.0]),
+                Point::from([0.0, 0.0]),
+            ]
+        );
+        assert_eq!(triangle.perimeter(), 12.0);
+        assert_eq!(triangle.area(), 6.0);
+        assert!(triangle.contains_point(Point::from([2.0, 1.5])));
+        assert!(!triangle.contains_point(Point::from([3.0, 3.0])));
+
+        triangle.rotate_on(Angle::from_degrees(90.0));
+        triangle.rotate_on(Angle::from_degrees(90.0));
+        assert_eq!(triangle.angle(), Angle::from_degrees(180.0));
+        assert_eq!(triangle.area().round_up_to(3), 6.0);
+
+        triangle.scale((Size::from_value(2.0), Size::from_value(2.0)));
+        assert_eq!(triangle.area().round_up_to(3), 24.0);
+
+        assert!(Triangle::new([Point::zero(), Point::zero(), Point::one()]).is_none());
+    }
+
+    #[test]
+    fn convex_polygon2d() {
+        use super::{ConvexPolygon, PolygonLike, Rect, Shape};
+        use crate::mathcore::Size;
+
+        let square: ConvexPolygon = ConvexPolygon::new(vec![
+            Point::from([0.0, 0.0]),
+            Point::from([2.0, 0.0]),
+            Point::from([2.0, 2.0]),
+            Point::from([0.0, 2.0]),
+        ])
+        .expect("Vertices form a convex polygon");
+        let rect: ConvexPolygon = ConvexPolygon::from(Rect::from_origin(
+            Point::from([1.0, 1.0]),
+            Angle::zero(),
+            Size::from_value(2.0),
+            Size::from_value(2.0),
+        ));
+        assert_eq!(square.origin(), rect.origin());
+        assert_eq!(square.area(), rect.area());
+        assert_eq!(square.perimeter(), 8.0);
+        assert_eq!(
+            square.vertices(),
+            [
+                Point::from([0.0, 2.0]),
+                Point::from([2.0, 2.0]),
+                Point::from([2.0, 0.0]),
+                Point::from([0.0, 0.0]),
+            ]
+        );
+
+        // origin is the centroid, not the mean of vertices
+        let trapezoid: ConvexPolygon = ConvexPolygon::new(vec![
+            Point::from([0.0, 0.0]),
+            Point::from([4.0, 0.0]),
+            Point::from([3.0, 3.0]),
+            Point::from([1.0, 3.0]),
+        ])
+        .expect("Vertices form a convex polygon");
+        assert_eq!(trapezoid.area(), 9.0);
+        assert_eq!(trapezoid.origin(), Point::from([2.0, 4.0 / 3.0]));
+        assert_eq!(
+            super::polygon_centroid(trapezoid.vertices()),
+            Some(trapezoid.origin())
+        );
+
+        // self-intersecting
+        assert!(ConvexPolygon::new(vec![
+            Point::from([0.0, 0.0]),
+            Point::from([2.0, 2.0]),
+            Point::from([2.0, 0.0]),
+            Point::from([0.0, 2.0]),
+        ])
+        .is_none());
+        // pentagram
+        assert!(ConvexPolygon::new(
+            (0..5)
+                .map(|i| {
+                    let (sin, cos): (f32, f32) = Angle::from_degrees(144.0 * i as f32).sin_cos();
+                    Point::from([cos, sin])
+                })
+                .collect()
+        )
+        .is_none());
+        // collinear
+        assert!(ConvexPolygon::new(vec![
+            Point::from([0.0, 0.0]),
+            Point::from([1.0, 0.0]),
+            Point::from([2.0, 0.0]),
+            Point::from([1.0, 1.0]),
+        ])
+        .is_none());
+    }
+
+    #[test]
+    fn rounded_shapes2d() {
+        use super::{Capsule, Circle, Rect, Rounded, RoundedRect, Shape, Triangle};
+        use crate::mathcore::{floats::FloatOperations, Size};
+
+        let circle: Circle = Circle::from_origin(Point::from([0.0, 0.0]), Size::from_value(1.0));
+        let mut capsule: Capsule = Capsule::new(
+            Segment::from([Point::from([2.0, -2.0]), Point::from([2.0, 2.0])]),
+            Size::from_value(1.0),
+        );
+        assert!(capsule.overlaps_rounded(&circle));
+        assert!(circle.overlaps_rounded(&capsule));
+        capsule.translate_on(Vector2::from([0.5, 0.0]));
+        assert!(!capsule.overlaps_rounded(&circle));
+
+        capsule.rotate_on(Angle::from_degrees(90.0));
+        assert_eq!(
+            capsule.segment().points.correct(3),
+            [Point::from([4.5, 0.0]), Point::from([0.5, 0.0])]
+        );
+        assert!(capsule.overlaps_rounded(&circle));
+
+        let rounded_rect: RoundedRect = RoundedRect::from_origin(
+            Point::from([0.0, 2.0]),
+            Angle::zero(),
+            Size::from_value(4.0),
+            Size::from_value(2.0),
+            Size::from_value(1.0),
+        );
+        assert_eq!(rounded_rect.core().len(), 2);
+        assert_eq!(
+            rounded_rect.area().round_up_to(3),
+            (8.0 - (4.0 - std::f32::consts::PI)).round_up_to(3)
+        );
+        assert!(rounded_rect.overlaps_rounded(&circle));
+        assert!(!rounded_rect.contains_point(Point::from([1.9, 2.9])));
+
+        let rect: Rect = Rect::from_origin(
+            Point::from([0.0, -2.0]),
+            Angle::zero(),
+            Size::from_value(2.0),
+            Size::from_value(2.0),
+        );
+        assert!(circle.overlaps_polygon(&rect));
+        assert!(!rounded_rect.overlaps_polygon(&rect));
+
+        let triangle: Triangle = Triangle::new([
+            Point::from([-0.5, -0.5]),
+            Point::from([0.5, -0.5]),
+            Point::from([0.0, 0.5]),
+        ])
+        .expect("Vertices form a triangle");
+        assert!(circle.overlaps_polygon(&triangle));
+        let big_triangle: Triangle = Triangle::new([
+            Point::from([-10.0, -10.0]),
+            Point::from([10.0, -10.0]),
+            Point::from([0.0, 10.0]),
+        ])
+        .expect("Vertices form a triangle");
+        assert!(circle.overlaps_polygon(&big_triangle));
+    }
+
+    #[test]
+    fn aabb2d() {
+        use super::{Aabb, Capsule, Circle, RoundedRect, Shape};
+        use crate::mathcore::Size;
+
+        let circle: Circle = Circle::from_origin(Point::from([1.0, 1.0]), Size::from_value(1.0));
+        assert_eq!(
+            circle.aabb(),
+            Aabb::new(Point::from([0.0, 0.0]), Point::from([2.0, 2.0]))
+        );
+        let capsule: Capsule = Capsule::new(
+            Segment::from([Point::from([0.0, 0.0]), Point::from([2.0, 2.0])]),
+            Size::from_value(1.0),
+        );
+        assert_eq!(
+            capsule.aabb(),
+            Aabb::new(Point::from([-1.0, -1.0]), Point::from([3.0, 3.0]))
+        );
+        let rounded_rect: RoundedRect = RoundedRect::from_origin(
+            Point::zero(),
+            Angle::zero(),
+            Size::from_value(4.0),
+            Size::from_value(2.0),
+            Size::from_value(0.5),
+        );
+        assert_eq!(
+            rounded_rect.aabb(),
+            Aabb::new(Point::from([-2.0, -1.0]), Point::from([2.0, 1.0]))
+        );
+
+        assert!(circle.aabb().intersects(capsule.aabb()));
+        assert!(capsule.aabb().contains(circle.aabb()));
+        assert!(Aabb::from_points(&[]).is_none());
+        assert_eq!(
+            circle.aabb().intersection(rounded_rect.aabb()),
+            Some(Aabb::new(Point::from([0.0, 0.0]), Point::from([2.0, 1.0])))
+        );
+        assert_eq!(
+            circle.aabb().expand(-5.0),
+            Aabb::new(Point::from([1.0, 1.0]), Point::from([1.0, 1.0]))
+        );
+    }
+
+    #[test]
+    fn clipping2d() {
+        use super::{
+            clip_polygon_to, clip_segment_to, polygon_area, ConvexPolygon, PolygonLike, Rect,
+            Triangle,
+        };
+
+        let square: Rect = Rect::from_min_max(Point::from([0.0, 0.0]), Point::from([2.0, 2.0]));
+        let diagonal: Segment = Segment::from([Point::from([3.0, 3.0]), Point::from([-1.0, -1.0])]);
+        assert_eq!(
+            clip_segment_to(diagonal, &square),
+            Some(Segment::from([
+                Point::from([2.0, 2.0]),
+                Point::from([0.0, 0.0])
+            ]))
+        );
+        let inside: Segment = Segment::from([Point::from([0.5, 0.5]), Point::from([1.5, 1.0])]);
+        assert_eq!(clip_segment_to(inside, &square), Some(inside));
+        let triangle: Triangle = Triangle::new([
+            Point::from([0.0, 0.0]),
+            Point::from([2.0, 0.0]),
+            Point::from([0.0, 2.0]),
+        ])
+        .expect("Vertices form a triangle");
+        assert!(clip_segment_to(
+            Segment::from([Point::from([2.0, 1.0]), Point::from([1.0, 2.0])]),
+            &triangle
+        )
+        .is_none());
+
+        // concave polygon is clipped by convex one
+        let l_shape: [Point; 6] = [
+            Point::from([-1.0, -1.0]),
+            Point::from([1.0, -1.0]),
+            Point::from([1.0, 1.0]),
+            Point::from([0.0, 1.0]),
+            Point::from([0.0, 3.0]),
+            Point::from([-1.0, 3.0]),
+        ];
+        assert_eq!(polygon_area(&clip_polygon_to(&l_shape, &square)), 1.0);
+        assert_eq!(
+            polygon_area(&clip_polygon_to(square.vertices(), &triangle)),
+            2.0
+        );
+        let hexagon: ConvexPolygon = ConvexPolygon::new(
+            (0..6)
+                .map(|i| Point::from_angle(Angle::from_degrees(60.0 * i as f32)) * 10.0)
+                .collect(),
+        )
+        .expect("Vertices form a convex polygon");
+        assert_eq!(polygon_area(&clip_polygon_to(&l_shape, &hexagon)), 6.0);
+        assert!(clip_polygon_to(
+            &l_shape,
+            &Rect::from_min_max(Point::from([5.0, 5.0]), Point::from([6.0, 6.0]))
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn rect_helpers2d() {
+        use super::{Aabb, Rect, Shape};
+        use crate::mathcore::Size;
+
+        let rect1: Rect =
+            Rect::from_aabb(Aabb::new(Point::from([0.0, 0.0]), Point::from([4.0, 2.0])));
+        let rect2: Rect = Rect::from_origin(
+            Point::from([4.0, 1.0]),
+            Angle::from_degrees(45.0),
+            Size::from_value(1.0),
+            Size::from_value(1.0),
+        );
+        assert!(rect1.intersects(&rect2));
+        assert!(!rect1.contains_rect(&rect2));
+        assert!(rect1.inflate(1.0).contains_rect(&rect2));
+        assert_eq!(rect1.inflate(-10.0).aabb().center(), rect1.origin());
+
+        let clipped: Rect = rect1
+            .clip_to(&Aabb::new(
+                Point::from([3.0, -1.0]),
+                Point::from([10.0, 1.0]),
+            ))
+            .expect("Rectangle and box intersect");
+        assert_eq!((clipped.width(), clipped.height()), (1.0, 1.0));
+        assert!(rect1
+            .clip_to(&Aabb::new(Point::from([5.0, 5.0]), Point::from([6.0, 6.0])))
+            .is_none());
+
+        let rect3: Rect = Rect::from_min_max(Point::from([4.0, 2.0]), Point::from([0.0, 0.0]));
+        assert_eq!(rect3.aabb(), rect1.aabb());
+        assert_eq!(
+            Rect::from_top_left_size(Point::zero(), Size::from_value(4.0), Size::from_value(2.0))
+                .aabb(),
+            rect1.aabb()
+        );
+        assert_eq!(
+            Rect::from_center_size(
+                Point::from([2.0, 1.0]),
+                Size::from_value(4.0),
+                Size::from_value(2.0)
+            )
+            .aabb(),
+            rect1.aabb()
+        );
+        assert_eq!(rect3.center(), Point::from([2.0, 1.0]));
+        assert_eq!(rect3.top_left(), Point::zero());
+        assert_eq!(rect3.max(), Point::from([4.0, 2.0]));
+        assert_eq!(rect2.min(), rect2.aabb().min);
+    }
+
+    #[test]
+    fn serialization() {
+        use super::{Capsule, Circle, ConvexPolygon, Rect, Segment, Shape, Triangle, Vertex};
+        use crate::mathcore::{floats::FloatOperations, transforms::Scalable, Size};
+        use serde::Serialize;
+
+        let mut triangle: Triangle = Triangle::new([
+            Vertex::from([0.0, 0.0]),
+            Vertex::from([4.0, 0.0]),
+            Vertex::from([0.0, 3.0]),
+        ])
+        .expect("Vertices form a triangle");
+        triangle.rotate_on(Angle::from_degrees(30.0));
+        triangle.scale((Size::from_value(2.0), Size::from_value(1.0)));
+        let bytes: Vec<u8> = serde_cbor::to_vec(&triangle).expect("Triangle is serializable");
+        let deserialized: Triangle =
+            serde_cbor::from_slice(&bytes).expect("Triangle is deserializable");
+        assert_eq!(deserialized.vertices, triangle.vertices);
+        assert_eq!(deserialized.origin(), triangle.origin());
+        assert_eq!(deserialized.angle(), triangle.angle());
+        assert_eq!(deserialized.size(), triangle.size());
+
+        let polygon: ConvexPolygon = ConvexPolygon::from(Rect::from_origin(
+            Point::from([1.0, 2.0]),
+            Angle::from_degrees(45.0),
+            Size::from_value(3.0),
+            Size::from_value(1.0),
+        ));
+        let bytes: Vec<u8> = serde_cbor::to_vec(&polygon).expect("Polygon is serializable");
+        let deserialized: ConvexPolygon =
+            serde_cbor::from_slice(&bytes).expect("Polygon is deserializable");
+        assert_eq!(deserialized.vertices, polygon.vertices);
+        assert_eq!(deserialized.aabb(), polygon.aabb());
+
+        let circle: Circle = Circle::from_origin(Point::from([1.0, -1.0]), Size::from_value(2.5));
+        let bytes: Vec<u8> = serde_cbor::to_vec(&circle).expect("Circle is serializable");
+        let deserialized: Circle =
+            serde_cbor::from_slice(&bytes).expect("Circle is deserializable");
+        assert_eq!(deserialized.aabb(), circle.aabb());
+
+        // invariants are preserved on deserialization
+        let bytes: Vec<u8> =
+            serde_cbor::to_vec(&(7.0 * std::f32::consts::PI)).expect("f32 is serializable");
+        let angle: Angle = serde_cbor::from_slice(&bytes).expect("Angle is deserializable");
+        assert_eq!(angle.degrees().round_up_to(3), 180.0);
+        for value in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            let bytes: Vec<u8> = serde_cbor::to_vec(&value).expect("f32 is serializable");
+            assert!(serde_cbor::from_slice::<Size>(&bytes).is_err());
+        }
+        assert!(serde_cbor::from_slice::<Angle>(
+            &serde_cbor::to_vec(&f32::NAN).expect("f32 is serializable")
+        )
+        .is_err());
+
+        #[derive(Serialize)]
+        struct Degenerate {
+            vertices: Vec<Vertex>,
+            angle: Angle,
+            size: (Size, Size),
+        }
+        let bytes: Vec<u8> = serde_cbor::to_vec(&Degenerate {
+            vertices: vec![
+                Vertex::from([0.0, 0.0]),
+                Vertex::from([1.0, 1.0]),
+                Vertex::from([2.0, 2.0]),
+            ],
+            angle: Angle::zero(),
+            size: (Size::from_value(1.0), Size::from_value(1.0)),
+        })
+        .expect("Struct is serializable");
+        assert!(serde_cbor::from_slice::<Triangle>(&bytes).is_err());
+        assert!(serde_cbor::from_slice::<ConvexPolygon>(&bytes).is_err());
+
+        #[derive(Serialize)]
+        struct Inconsistent {
+            segment: Segment,
+            radius: Size,
+            origin: Point,
+            angle: Angle,
+        }
+        let segment: Segment = Segment::from([Point::from([0.0, 0.0]), Point::from([0.0, 4.0])]);
+        let bytes: Vec<u8> = serde_cbor::to_vec(&Inconsistent {
+            segment,
+            radius: Size::from_value(1.0),
+            origin: Point::from([10.0, 10.0]),
+            angle: Angle::zero(),
+        })
+        .expect("Struct is serializable");
+        let capsule: Capsule = serde_cbor::from_slice(&bytes).expect("Capsule is deserializable");
+        assert_eq!(capsule.origin(), Point::from([0.0, 2.0]));
+        assert_eq!(
+            capsule.angle(),
+            Capsule::new(segment, Size::from_value(1.0)).angle()
+        );
+        let bytes: Vec<u8> = serde_cbor::to_vec(&capsule).expect("Capsule is serializable");
+        let deserialized: Capsule =
+            serde_cbor::from_slice(&bytes).expect("Capsule is deserializable");
+        assert_eq!(deserialized.segment(), capsule.segment());
+        assert_eq!(deserialized.aabb(), capsule.aabb());
+    }
 }
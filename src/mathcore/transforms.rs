@@ -9,43 +9,38 @@ use crate::mathcore::{
     {Angle, Size},
 };
 use serde::{Deserialize, Serialize};
+use std::ops::{Mul, MulAssign};
 
 /// Combines given transform matrices by using dot product.
 ///
 /// This function automatically reverses the order, so if you need to combine transforms `A -> B -> C`
-/// just pass `vec![A, B, C]` (matrices multiplication will be performed in order `C * B * A`).
+/// just pass `[A, B, C]` (matrices multiplication will be performed in order `C * B * A`).
+/// Any collection that can be iterated from the back is accepted, so arrays can be used to avoid allocations.
 ///
 /// # Example
 /// ```rust
 /// # use ggengine::mathcore::{transforms::combine_matrices, matrices::Matrix3x3};
-/// let matrix1: Matrix3x3 = Matrix3x3::from([ // rotation
-///     [0.5, -0.87, 0.0],
-///     [0.87, 0.5, 0.0],
-///     [0.0, 0.0, 1.0],
-/// ]);
-/// let matrix2: Matrix3x3 = Matrix3x3::from([ // translation
-///     [1.0, 0.0, 3.0],
-///     [0.0, 1.0, 2.0],
-///     [0.0, 0.0, 1.0],
-/// ]);
-/// let matrix3: Matrix3x3 = Matrix3x3::from([ // scaling
-///     [2.0, 0.0, 0.0],
-///     [0.0, 1.0, 0.0],
-///     [0.0, 0.0, 1.0],
-/// ]);
-/// assert_eq!(combine_matrices(vec![matrix1, matrix2, matrix3]).as_array(),
+/// # use ggengine::mathcore::{floats::FloatOperations, vectors::Vector2, Angle};
+/// let matrix1: Matrix3x3 = Matrix3x3::rotation(Angle::from_degrees(60.0));
+/// let matrix2: Matrix3x3 = Matrix3x3::translation(Vector2::from([3.0, 2.0]));
+/// let matrix3: Matrix3x3 = Matrix3x3::scaling(Vector2::from([2.0, 1.0]));
+/// assert_eq!(combine_matrices([matrix1, matrix2, matrix3]).round_up_to(2).as_array(),
 /// [
-///     [1.0, -1.74, 6.0],
+///     [1.0, -1.73, 6.0],
 ///     [0.87, 0.5, 2.0],
 ///     [0.0, 0.0, 1.0]
-/// ]);  // translation -> rotation -> scaling
+/// ]);  // rotation -> translation -> scaling
 /// ```
 ///
-pub fn combine_matrices<const N: usize>(matrices: Vec<Matrix<N, N>>) -> Matrix<N, N> {
+pub fn combine_matrices<const N: usize, I>(matrices: I) -> Matrix<N, N>
+where
+    I: IntoIterator<Item = Matrix<N, N>>,
+    I::IntoIter: DoubleEndedIterator,
+{
     matrices
-        .iter()
+        .into_iter()
         .rev()
-        .fold(Matrix::<N, N>::identity(), |acc, elem| acc * *elem)
+        .fold(Matrix::<N, N>::identity(), |acc, elem| acc * elem)
 }
 
 /// [`Transform`] struct-like enum represents 3 basic matrix transformations.
@@ -140,25 +135,187 @@ impl Transform {
     /// ```
     ///
     pub fn matrix(self) -> Matrix3x3 {
-        let mut matrix: Matrix3x3 = Matrix3x3::identity();
         match self {
-            Self::Translation { vector } => {
-                matrix[0][2] = vector.x;
-                matrix[1][2] = vector.y;
-            }
-            Self::Rotation { angle } => {
-                let (sin, cos): (f32, f32) = angle.sin_cos();
-                matrix[0][0] = cos;
-                matrix[0][1] = -sin;
-                matrix[1][0] = sin;
-                matrix[1][1] = cos;
-            }
+            Self::Translation { vector } => Matrix3x3::translation(vector),
+            Self::Rotation { angle } => Matrix3x3::rotation(angle),
             Self::Scaling { size_scale } => {
-                matrix[0][0] = size_scale.0.get();
-                matrix[1][1] = size_scale.1.get();
+                Matrix3x3::scaling(Vector2::from([size_scale.0.get(), size_scale.1.get()]))
             }
-        };
-        matrix
+        }
+    }
+}
+
+/// [`Transform2D`] struct represents decomposed two-dimensional transform - translation, rotation and scale.
+///
+/// Transform is applied in order `scale -> rotation -> translation`, which corresponds to
+/// `combine_matrices([scaling, rotation, translation])`.
+/// Decomposed transforms are cheaper to compose and easier to work with,
+/// so [`Matrix3x3`] should only be built when it is really needed (e.g. by [`Transform2D::to_matrix`]).
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::transforms::Transform2D;
+/// # use ggengine::mathcore::vectors::{Point, Vector2};
+/// # use ggengine::mathcore::floats::FloatOperations;
+/// # use ggengine::mathcore::{Angle, Size};
+/// let parent: Transform2D = Transform2D::new(
+///     Vector2::from([10.0, 0.0]),
+///     Angle::DEG90,
+///     (Size::from_value(2.0), Size::from_value(2.0)),
+/// );
+/// let child: Transform2D = Transform2D::from_translation(Vector2::from([1.0, 0.0]));
+///
+/// let world: Transform2D = parent * child;
+/// let point: Point = world.transform_point(Point::zero()).round_up_to(3);
+/// assert_eq!(point, Point::from([10.0, 2.0]));
+/// assert_eq!(world.to_matrix().apply_to(Point::zero()).round_up_to(3), point);
+/// assert_eq!(world.inverse().transform_point(point).round_up_to(3), Point::zero());
+/// ```
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Transform2D {
+    /// Translation of transform.
+    ///
+    pub translation: Vector2,
+    /// Rotation of transform.
+    ///
+    pub rotation: Angle,
+    /// Scale of transform.
+    ///
+    pub scale: (Size, Size),
+}
+impl Transform2D {
+    /// Constructs transform from its components.
+    ///
+    pub fn new(translation: Vector2, rotation: Angle, scale: (Size, Size)) -> Self {
+        Transform2D {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+    /// Constructs identity transform (transform that does not change anything).
+    ///
+    pub fn identity() -> Self {
+        Transform2D::new(
+            Vector2::zero(),
+            Angle::zero(),
+            (Size::from_value(1.0), Size::from_value(1.0)),
+        )
+    }
+    /// Constructs transform that only translates.
+    ///
+    pub fn from_translation(translation: Vector2) -> Self {
+        Transform2D {
+            translation,
+            ..Transform2D::identity()
+        }
+    }
+    /// Constructs transform that only rotates.
+    ///
+    pub fn from_rotation(rotation: Angle) -> Self {
+        Transform2D {
+            rotation,
+            ..Transform2D::identity()
+        }
+    }
+    /// Constructs transform that only scales.
+    ///
+    pub fn from_scale(scale: (Size, Size)) -> Self {
+        Transform2D {
+            scale,
+            ..Transform2D::identity()
+        }
+    }
+
+    /// Builds transformation matrix which is equal to `translation * rotation * scaling`.
+    ///
+    pub fn to_matrix(&self) -> Matrix3x3 {
+        Matrix3x3::trs(
+            self.translation,
+            self.rotation,
+            Vector2::from([self.scale.0.get(), self.scale.1.get()]),
+        )
+    }
+
+    /// Transforms vector, ignoring translation (only scale and rotation are applied).
+    ///
+    pub fn transform_vector(&self, vector: Vector2) -> Vector2 {
+        Vector2::from([vector.x * self.scale.0.get(), vector.y * self.scale.1.get()])
+            .rotate_by(self.rotation)
+    }
+    /// Transforms point by applying scale, rotation and translation.
+    ///
+    pub fn transform_point(&self, point: Point) -> Point {
+        self.transform_vector(point) + self.translation
+    }
+    /// Transforms point by applying inverse of this transform.
+    ///
+    /// Unlike [`Transform2D::inverse`], this function is always exact.
+    ///
+    pub fn inverse_transform_point(&self, point: Point) -> Point {
+        let local: Vector2 = (point - self.translation).rotate_by(-self.rotation);
+        Vector2::from([local.x / self.scale.0.get(), local.y / self.scale.1.get()])
+    }
+
+    /// Returns inverse transform.
+    ///
+    /// Inverse of transform that has non-uniform scale and rotation requires shearing,
+    /// which cannot be represented by [`Transform2D`],
+    /// so in that case result is only approximate (use [`Transform2D::inverse_transform_point`]
+    /// or invert matrix that was obtained by [`Transform2D::to_matrix`] if exact result is needed).
+    ///
+    pub fn inverse(&self) -> Self {
+        let mut inverse: Transform2D = Transform2D::new(
+            Vector2::zero(),
+            -self.rotation,
+            (
+                Size::from_value(1.0) / self.scale.0,
+                Size::from_value(1.0) / self.scale.1,
+            ),
+        );
+        inverse.translation = inverse.transform_vector(-self.translation);
+        inverse
+    }
+}
+impl Default for Transform2D {
+    /// Returns identity transform.
+    ///
+    fn default() -> Self {
+        Transform2D::identity()
+    }
+}
+impl Mul<Self> for Transform2D {
+    type Output = Self;
+
+    /// Composes transforms - resulting transform applies `rhs` first and `self` after it
+    /// (like multiplication of corresponding matrices does).
+    ///
+    /// Composition is exact when scale of `self` is uniform or `rhs` is not rotated,
+    /// otherwise result requires shearing and is only approximate.
+    ///
+    fn mul(self, rhs: Self) -> Self::Output {
+        Transform2D::new(
+            self.transform_point(rhs.translation),
+            self.rotation + rhs.rotation,
+            (self.scale.0 * rhs.scale.0, self.scale.1 * rhs.scale.1),
+        )
+    }
+}
+impl MulAssign<Self> for Transform2D {
+    /// Composes transforms in place.
+    ///
+    /// Is equal to `*self = *self * rhs`.
+    ///
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl From<Transform2D> for Matrix3x3 {
+    /// Is equal to `transform.to_matrix()`.
+    ///
+    fn from(transform: Transform2D) -> Self {
+        transform.to_matrix()
     }
 }
 
@@ -221,3 +378,79 @@ pub trait Scalable {
 /// This trait requires [`Translatable`], [`Rotatable`] and [`Scalable`] traits to be implemented.
 ///
 pub trait Transformable: Translatable + Rotatable + Scalable {}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn transform2d() {
+        use super::{combine_matrices, Transform, Transform2D};
+        use crate::mathcore::{
+            floats::FloatOperations,
+            matrices::Matrix3x3,
+            vectors::{Point, Vector2},
+            {Angle, Size},
+        };
+
+        let transform: Transform2D = Transform2D::new(
+            Vector2::from([3.0, -2.0]),
+            Angle::from_degrees(30.0),
+            (Size::from_value(2.0), Size::from_value(0.5)),
+        );
+        let matrix: Matrix3x3 = combine_matrices([
+            Transform::Scaling {
+                size_scale: transform.scale,
+            }
+            .matrix(),
+            Transform::Rotation {
+                angle: transform.rotation,
+            }
+            .matrix(),
+            Transform::Translation {
+                vector: transform.translation,
+            }
+            .matrix(),
+        ]);
+        assert_eq!(transform.to_matrix().round_up_to(3), matrix.round_up_to(3));
+
+        let point: Point = Point::from([1.5, 4.0]);
+        let transformed: Point = transform.transform_point(point);
+        assert_eq!(
+            transformed.round_up_to(3),
+            matrix.apply_to(point).round_up_to(3)
+        );
+        assert_eq!(
+            transform
+                .inverse_transform_point(transformed)
+                .round_up_to(3),
+            point
+        );
+        assert_eq!(Transform2D::default() * transform, transform);
+
+        let (parent, child): (Transform2D, Transform2D) = (
+            Transform2D::new(
+                Vector2::from([1.0, 1.0]),
+                Angle::from_degrees(45.0),
+                (Size::from_value(3.0), Size::from_value(3.0)),
+            ),
+            transform,
+        );
+        assert_eq!(
+            (parent * child).to_matrix().round_up_to(3),
+            (parent.to_matrix() * child.to_matrix()).round_up_to(3)
+        );
+        assert_eq!(
+            (parent * parent.inverse())
+                .transform_point(point)
+                .round_up_to(3),
+            point
+        );
+        assert_eq!(
+            parent.inverse().to_matrix().round_up_to(3),
+            parent
+                .to_matrix()
+                .inverse()
+                .expect("Transform matrix is invertible")
+                .round_up_to(3)
+        );
+    }
+}
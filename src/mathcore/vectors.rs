@@ -3,7 +3,7 @@
 //!
 
 use crate::mathcore::{
-    floats::{equal, FloatOperations},
+    floats::{equal, equal_f64, FloatOperations},
     Angle,
 };
 use serde::{Deserialize, Serialize};
@@ -91,6 +91,11 @@ macro_rules! impl_vector {
                     .zip(other.elements().iter())
                     .fold($zero, |acc, (a, b)| acc + *a * *b)
             }
+            /// Shorthand for [`dot_product`](Self::dot_product).
+            ///
+            pub fn dot(self, other: Self) -> $type {
+                self.dot_product(other)
+            }
         }
     };
 }
@@ -98,22 +103,25 @@ macro_rules! impl_vector {
 /// [`impl_vectorf`] macro implements all common associated functions and methods on vectors with
 /// float values.
 ///
+/// `$equal` is the floating point equality function for `$type`.
 /// This macro depends on [`impl_vector`] macro.
 ///
 macro_rules! impl_vectorf {
-    ($struct:ident, $istruct:ident, $size:expr) => {
+    ($struct:ident, $istruct:ident, $size:expr, $type:ty, $equal:ident) => {
         impl $struct {
             /// Returns angle between two vectors.
             ///
+            #[allow(trivial_numeric_casts)] // cast is only trivial for `f32` vectors
             pub fn angle(self, other: Self) -> Angle {
                 Angle::from_radians(
-                    (self.dot_product(other) / (self.magnitude() * other.magnitude())).acos(),
+                    (self.dot_product(other) / (self.magnitude() * other.magnitude())).acos()
+                        as f32,
                 )
             }
 
             /// Returns magnitude of vector.
             ///
-            pub fn magnitude(&self) -> f32 {
+            pub fn magnitude(&self) -> $type {
                 self.sqr_magnitude().sqrt()
             }
 
@@ -122,20 +130,73 @@ macro_rules! impl_vectorf {
             pub fn normalized(self) -> Self {
                 self / self.magnitude()
             }
+            /// Normalizes vector in place.
+            ///
+            /// Zero vector is left unchanged.
+            ///
+            pub fn normalize(&mut self) {
+                if let Some(normalized) = self.try_normalize() {
+                    *self = normalized;
+                }
+            }
+            /// Returns new vector that is normalized or `None` if vector's magnitude is zero or not finite.
+            ///
+            pub fn try_normalize(self) -> Option<Self> {
+                let magnitude: $type = self.magnitude();
+                if $equal(magnitude, 0.0) || !magnitude.is_finite() {
+                    None
+                } else {
+                    Some(self / magnitude)
+                }
+            }
             /// Returns new vector, which magnitude is clamped to max_magnitude.
             ///
-            pub fn clamped_magnitude(self, max_magnitude: f32) -> Self {
-                let magnitude: f32 = self.magnitude();
+            pub fn clamped_magnitude(self, max_magnitude: $type) -> Self {
+                let magnitude: $type = self.magnitude();
                 self * magnitude.min(max_magnitude) / magnitude
             }
+            /// Returns new vector, which magnitude is clamped between `min` and `max`.
+            ///
+            /// Direction of zero vector is undefined, so zero vector is returned unchanged.
+            ///
+            pub fn clamp_length(self, min: $type, max: $type) -> Self {
+                match self.try_normalize() {
+                    Some(direction) => direction * self.magnitude().clamp(min, max.max(min)),
+                    None => self,
+                }
+            }
+
+            /// Returns projection of vector onto other vector.
+            ///
+            /// Projection onto zero vector is zero vector.
+            ///
+            pub fn project_onto(self, other: Self) -> Self {
+                let sqr_magnitude: $type = other.sqr_magnitude();
+                if $equal(sqr_magnitude, 0.0) {
+                    return Self::zero();
+                }
+                other * (self.dot_product(other) / sqr_magnitude)
+            }
+            /// Returns vector that is reflected off the surface with given normal.
+            ///
+            /// `normal` is normalized before reflecting.
+            ///
+            pub fn reflect(self, normal: Self) -> Self {
+                match normal.try_normalize() {
+                    Some(normal) => self - normal * (2.0 * self.dot_product(normal)),
+                    None => self,
+                }
+            }
 
-            /// Linearly interpolates between vectors a and b by t.
+            /// Linearly interpolates between vectors `self` and `other` by `t`.
             ///
-            /// t will be clamped between [0.0; 1.0].
+            /// `t` will be clamped between [0.0; 1.0]; `t = 0.0` returns `self` and `t = 1.0` returns `other`.
+            /// Earlier versions interpolated in the opposite direction (`t = 0.0` returned `other`),
+            /// so callers that relied on that should swap arguments.
             ///
-            pub fn lerp(self, other: Self, t: f32) -> Self {
-                let t: f32 = t.clamp(0.0, 1.0);
-                self * t + other * (1.0 - t)
+            pub fn lerp(self, other: Self, t: $type) -> Self {
+                let t: $type = t.clamp(0.0, 1.0);
+                self * (1.0 - t) + other * t
             }
         }
         impl FloatOperations for $struct {
@@ -155,13 +216,13 @@ macro_rules! impl_vectorf {
                 self.elements()
                     .iter()
                     .zip(other.elements().iter())
-                    .all(|(&a, &b)| equal(a, b))
+                    .all(|(&a, &b)| $equal(a, b))
             }
         }
         impl Eq for $struct {}
         impl From<$istruct> for $struct {
             fn from(value: $istruct) -> Self {
-                $struct::from(value.elements().map(|elem| elem as f32))
+                $struct::from(value.elements().map(|elem| elem as $type))
             }
         }
     };
@@ -296,9 +357,62 @@ impl Vector2 {
     pub fn cross_product(self, other: Self) -> f32 {
         (self.x * other.y) - (self.y * other.x)
     }
+
+    /// Returns vector that is perpendicular to this one (rotated by 90 degrees counterclockwise).
+    ///
+    pub fn perpendicular(self) -> Self {
+        Vector2::from([-self.y, self.x])
+    }
+    /// Returns vector that is rotated counterclockwise by given angle.
+    ///
+    pub fn rotate_by(self, angle: Angle) -> Self {
+        let (sin, cos): (f32, f32) = angle.sin_cos();
+        Vector2::from([self.x * cos - self.y * sin, self.x * sin + self.y * cos])
+    }
+    /// Returns angle by which this vector should be rotated counterclockwise to be co-directed with other vector.
+    ///
+    /// Unlike [`angle`](Self::angle), which returns the smallest unsigned angle between vectors,
+    /// this function takes direction of rotation into account.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::vectors::Vector2;
+    /// # use ggengine::mathcore::Angle;
+    /// let (right, up): (Vector2, Vector2) = (Vector2::from([1.0, 0.0]), Vector2::from([0.0, 1.0]));
+    /// assert_eq!(right.angle_between(up), Angle::from_degrees(90.0));
+    /// assert_eq!(up.angle_between(right), Angle::from_degrees(270.0));
+    /// ```
+    ///
+    pub fn angle_between(self, other: Self) -> Angle {
+        Angle::from_radians(self.cross_product(other).atan2(self.dot_product(other)))
+    }
+
+    /// Constructs unit vector that is directed at given angle (counting counterclockwise from x-axis).
+    ///
+    pub fn from_angle(angle: Angle) -> Self {
+        let (sin, cos): (f32, f32) = angle.sin_cos();
+        Vector2::from([cos, sin])
+    }
+    /// Returns angle at which target point is located relative to this point (counting counterclockwise from x-axis).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::mathcore::vectors::{Point, Vector2};
+    /// # use ggengine::mathcore::floats::FloatOperations;
+    /// # use ggengine::mathcore::Angle;
+    /// let position: Point = Point::from([1.0, 1.0]);
+    /// let angle: Angle = position.direction_to(Point::from([1.0, -2.0]));
+    /// assert_eq!(angle, Angle::from_degrees(270.0));
+    /// assert_eq!(Vector2::from_angle(angle).round_up_to(3), Vector2::from([0.0, -1.0]));
+    /// ```
+    ///
+    pub fn direction_to(self, target: Point) -> Angle {
+        let direction: Vector2 = target - self;
+        Angle::from_radians(direction.y.atan2(direction.x))
+    }
 }
 impl_vector!(Vector2, 2, f32, 0.0, 1.0);
-impl_vectorf!(Vector2, Vector2Int, 2);
+impl_vectorf!(Vector2, Vector2Int, 2, f32, equal);
 impl_vector_operations!(Vector2, f32);
 impl BitXor for Vector2 {
     type Output = f32;
@@ -325,6 +439,120 @@ pub type Point = Vector2;
 ///
 pub type Vertex = Point;
 
+/// [`Vector2D`] struct represents two-dimensional vector and two-dimensional point with `f64` coordinates on a plane.
+///
+/// `f32` loses precision quickly as coordinates grow (at 100 000 units from the origin the step between
+/// two neighbouring values is already ~0.008), so large worlds can store positions in [`Vector2D`] and
+/// convert them to [`Vector2`] relative to some nearby origin (e.g. camera position)
+/// right before rendering or physics.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::mathcore::vectors::{PointD, Vector2};
+/// let camera: PointD = PointD::from([1_000_000.0, 0.0]);
+/// let position: PointD = PointD::from([1_000_000.125, 2.5]);
+/// assert_eq!(position.relative_to(camera), Vector2::from([0.125, 2.5]));
+/// assert_eq!(PointD::from_relative(camera, Vector2::from([0.125, 2.5])), position);
+/// ```
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug)]
+pub struct Vector2D {
+    /// X component of vector.
+    ///
+    pub x: f64,
+
+    /// Y component of vector.
+    ///
+    pub y: f64,
+}
+impl Vector2D {
+    /// Returns elements of vector.
+    ///
+    pub fn elements(&self) -> [f64; 2] {
+        [self.x, self.y]
+    }
+    /// Sets from values to elements of vector.
+    ///
+    pub fn set(&mut self, elements: [f64; 2]) {
+        self.x = elements[0];
+        self.y = elements[1];
+    }
+
+    /// Returns scalar that represents cross product of two-dimensional vectors.
+    ///
+    pub fn cross_product(self, other: Self) -> f64 {
+        (self.x * other.y) - (self.y * other.x)
+    }
+
+    /// Returns vector that is perpendicular to this one (rotated by 90 degrees counterclockwise).
+    ///
+    pub fn perpendicular(self) -> Self {
+        Vector2D::from([-self.y, self.x])
+    }
+    /// Returns vector that is rotated counterclockwise by given angle.
+    ///
+    pub fn rotate_by(self, angle: Angle) -> Self {
+        let (sin, cos): (f64, f64) = f64::from(angle.radians()).sin_cos();
+        Vector2D::from([self.x * cos - self.y * sin, self.x * sin + self.y * cos])
+    }
+
+    /// Returns `f32` vector that represents this point relative to `origin`.
+    ///
+    /// Subtraction is performed in `f64`, so result is precise as long as it is near `origin`.
+    ///
+    pub fn relative_to(self, origin: Self) -> Vector2 {
+        Vector2::from(self - origin)
+    }
+    /// Constructs `f64` point from `f32` vector that is relative to `origin`.
+    ///
+    /// This is inverse of [`relative_to`](Self::relative_to).
+    ///
+    pub fn from_relative(origin: Self, offset: Vector2) -> Self {
+        origin + Vector2D::from(offset)
+    }
+}
+impl_vector!(Vector2D, 2, f64, 0.0, 1.0);
+impl_vectorf!(Vector2D, Vector2Int, 2, f64, equal_f64);
+impl_vector_operations!(Vector2D, f64);
+impl BitXor for Vector2D {
+    type Output = f64;
+
+    /// Returns scalar that represents cross product of two-dimensional vectors.
+    ///
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.cross_product(rhs)
+    }
+}
+impl From<[f64; 2]> for Vector2D {
+    fn from(arr: [f64; 2]) -> Self {
+        Vector2D {
+            x: arr[0],
+            y: arr[1],
+        }
+    }
+}
+impl From<Vector2> for Vector2D {
+    fn from(value: Vector2) -> Self {
+        Vector2D::from(value.elements().map(f64::from))
+    }
+}
+impl From<Vector2D> for Vector2 {
+    /// Converts `f64` vector to `f32` vector, which may lose precision.
+    ///
+    /// Use [`Vector2D::relative_to`] to keep precision for points that are far from the origin.
+    ///
+    fn from(value: Vector2D) -> Self {
+        Vector2::from(value.elements().map(|elem| elem as f32))
+    }
+}
+
+/// Type alias for [`Vector2D`].
+///
+pub type PointD = Vector2D;
+/// Type alias for [`Vector2D`].
+///
+pub type VertexD = PointD;
+
 /// [`Vector2Int`] struct represents two-dimensional vector and two-dimensional point with `i32` coordinates on a plane.
 ///
 #[derive(Serialize, Deserialize, Copy, Clone, Debug)]
@@ -525,11 +753,56 @@ mod tests {
 
         let vec5: Vector2 = Vector2::from([0.0, 2.0]).lerp(Vector2::from([2.0, 0.0]), 0.5);
         assert_eq!(vec5.sqr_magnitude(), 2.0);
+        assert_eq!(
+            Vector2::from([0.0, 2.0]).lerp(Vector2::from([2.0, 0.0]), 0.25),
+            Vector2::from([0.5, 1.5])
+        );
+
+        let mut vec6: Vector2 = Vector2::from([0.0, -3.0]);
+        vec6.normalize();
+        assert_eq!(vec6, Vector2::from([0.0, -1.0]));
+        assert!(Vector2::zero().try_normalize().is_none());
+
+        assert_eq!(
+            Vector2::from([3.0, 4.0])
+                .clamp_length(10.0, 20.0)
+                .magnitude(),
+            10.0
+        );
+        assert_eq!(
+            Vector2::from([2.0, 3.0]).project_onto(Vector2::from([5.0, 0.0])),
+            Vector2::from([2.0, 0.0])
+        );
+        assert_eq!(
+            Vector2::from([1.0, -1.0]).reflect(Vector2::from([0.0, 3.0])),
+            Vector2::from([1.0, 1.0])
+        );
+        assert_eq!(
+            Vector2::from([1.0, 2.0]).dot(Vector2::from([3.0, 4.0])),
+            11.0
+        );
     }
 
     #[test]
     fn vectori() {}
 
+    #[test]
+    fn lerp() {
+        use super::{Vector2, Vector2D};
+
+        let (from, to): (Vector2, Vector2) =
+            (Vector2::from([1.0, -2.0]), Vector2::from([5.0, 6.0]));
+        assert_eq!(from.lerp(to, 0.0), from);
+        assert_eq!(from.lerp(to, 1.0), to);
+        assert_eq!(from.lerp(to, -1.0), from);
+        assert_eq!(from.lerp(to, 2.0), to);
+
+        let (from, to): (Vector2D, Vector2D) =
+            (Vector2D::from([1.0, -2.0]), Vector2D::from([5.0, 6.0]));
+        assert_eq!(from.lerp(to, 0.0), from);
+        assert_eq!(from.lerp(to, 1.0), to);
+    }
+
     #[test]
     fn vector2() {
         use super::{Vector2, Vector2Int};
@@ -538,12 +811,47 @@ mod tests {
         let vec2: Vector2 = Vector2::from([1.0, 2.0]);
         assert_eq!(vec1.cross_product(vec2), -8.0);
 
+        assert_eq!(vec2.perpendicular(), Vector2::from([-2.0, 1.0]));
+        assert_eq!(
+            vec2.rotate_by(crate::mathcore::Angle::from_degrees(180.0)),
+            Vector2::from([-1.0, -2.0])
+        );
+        assert_eq!(
+            vec2.angle_between(vec2.perpendicular()).degrees().round(),
+            90.0
+        );
+
         assert_eq!(
             Vector2::from(Vector2Int::from([2, 2])),
             Vector2::from([2.0, 2.0])
         );
     }
 
+    #[test]
+    fn vector2d() {
+        use super::{PointD, Vector2, Vector2D, Vector2Int};
+
+        let vec1: Vector2D = Vector2D::from([3.0, 4.0]);
+        assert_eq!(vec1.magnitude(), 5.0);
+        assert_eq!(vec1.normalized(), Vector2D::from([0.6, 0.8]));
+        assert_eq!(vec1.perpendicular(), Vector2D::from([-4.0, 3.0]));
+        assert_eq!(vec1 ^ Vector2D::from([1.0, 2.0]), 2.0);
+        assert_eq!(
+            Vector2D::from(Vector2Int::from([2, 2])),
+            Vector2D::from([2.0, 2.0])
+        );
+
+        // f32 can't tell these points apart, f64 can
+        let far: PointD = PointD::from([50_000_000.0, -50_000_000.0]);
+        let near: PointD = far + Vector2D::from([0.25, -0.5]);
+        assert_eq!(Vector2::from(far), Vector2::from(near));
+        assert_eq!(near.relative_to(far), Vector2::from([0.25, -0.5]));
+        assert_eq!(
+            PointD::from_relative(far, Vector2::from([0.25, -0.5])),
+            near
+        );
+    }
+
     #[test]
     fn vector2int() {
         use super::{Vector2, Vector2Int};
@@ -0,0 +1,24 @@
+//! `ggengine::netcore` module is a core that implements networking essentials for the engine.
+//!
+//! # Prelude
+//! `ggengine::netcore` prelude can be imported with `use ggengine::netcore::prelude::*`.
+//!
+//! # Model
+//! [`Transport`](transport::Transport) is a message-oriented transport that is built on top of UDP sockets.
+//! Every transport can both accept connections and connect to other transports; connections are established
+//! by a handshake and are kept alive by periodic packets, so dropped peers are detected by timeouts.
+//! Messages are encoded with Concise Binary Object Representation format and are sent through
+//! [`Channel`](channels::Channel)s, which provide optional reliability and ordering.
+//! State of entities can be replicated from server to clients by snapshots (see [`replication`] submodule),
+//! either manually or directly from game objects of scenes (see [`replicate_server`](replication::replicate_server)),
+//! or peers can exchange only inputs of players in deterministic lockstep (see [`lockstep`] submodule).
+//!
+
+// submodules and public re-exports
+pub mod channels;
+pub mod lockstep;
+pub mod replication;
+pub mod transport;
+
+// prelude
+pub mod prelude;
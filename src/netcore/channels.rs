@@ -0,0 +1,246 @@
+//! `netcore::channels` submodule implements [`Channel`] enum, which lists delivery guarantees
+//! that messages can be sent with, and bookkeeping that is needed to provide those guarantees
+//! over unreliable datagrams.
+//!
+
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+/// Number of sequence numbers starting from the oldest undelivered one that reliable receiver accepts.
+///
+/// Messages that are further ahead are dropped (sender resends them later),
+/// so peer cannot make receiver hold arbitrary number of messages.
+///
+const RECEIVE_WINDOW: u64 = 1024;
+
+/// [`Channel`] enum lists delivery guarantees of messages.
+///
+/// Unreliable channels are suitable for frequently updated state (positions, inputs),
+/// where the newest message makes older ones obsolete; reliable channels are suitable for events
+/// that should not be lost (chat messages, spawns, scores).
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Channel {
+    /// Messages can be lost, duplicated or arrive out of order.
+    ///
+    Unreliable,
+    /// Messages can be lost, but messages that are older than already received ones are dropped.
+    ///
+    Sequenced,
+    /// Messages are resent until they are acknowledged and are delivered exactly once,
+    /// but possibly out of order.
+    ///
+    Reliable,
+    /// Messages are resent until they are acknowledged and are delivered exactly once
+    /// in order in which they were sent.
+    ///
+    ReliableOrdered,
+}
+impl Channel {
+    /// Returns whether messages of this channel are resent until they are acknowledged.
+    ///
+    pub fn is_reliable(self) -> bool {
+        matches!(self, Channel::Reliable | Channel::ReliableOrdered)
+    }
+    /// Returns whether messages of this channel are never delivered after newer ones.
+    ///
+    pub fn is_ordered(self) -> bool {
+        matches!(self, Channel::Sequenced | Channel::ReliableOrdered)
+    }
+}
+
+/// [`ChannelSender`] struct is a sending side of a channel.
+///
+#[derive(Clone, Debug)]
+pub(crate) struct ChannelSender {
+    /// Channel of a sender.
+    ///
+    channel: Channel,
+    /// Sequence number of the next message.
+    ///
+    next_sequence: u64,
+    /// Reliable messages that were not acknowledged yet and time of their last sending.
+    ///
+    pending: BTreeMap<u64, (Vec<u8>, Instant)>,
+}
+impl ChannelSender {
+    /// Constructs sender of given channel.
+    ///
+    pub(crate) fn new(channel: Channel) -> Self {
+        ChannelSender {
+            channel,
+            next_sequence: 0,
+            pending: BTreeMap::new(),
+        }
+    }
+
+    /// Assigns sequence number to the message that is being sent at `now`.
+    ///
+    /// Reliable messages are stored until they are acknowledged.
+    ///
+    pub(crate) fn send(&mut self, payload: &[u8], now: Instant) -> u64 {
+        let sequence: u64 = self.next_sequence;
+        self.next_sequence += 1;
+        if self.channel.is_reliable() {
+            let _ = self.pending.insert(sequence, (payload.to_vec(), now));
+        }
+        sequence
+    }
+    /// Marks message with given sequence number as delivered.
+    ///
+    pub(crate) fn acknowledge(&mut self, sequence: u64) {
+        let _ = self.pending.remove(&sequence);
+    }
+    /// Returns messages that were not acknowledged for `resend_interval` and marks them as resent at `now`.
+    ///
+    pub(crate) fn resend(
+        &mut self,
+        now: Instant,
+        resend_interval: Duration,
+    ) -> Vec<(u64, Vec<u8>)> {
+        self.pending
+            .iter_mut()
+            .filter(|(_, (_, sent))| now.saturating_duration_since(*sent) >= resend_interval)
+            .map(|(sequence, (payload, sent))| {
+                *sent = now;
+                (*sequence, payload.clone())
+            })
+            .collect()
+    }
+    /// Returns number of reliable messages that were not acknowledged yet.
+    ///
+    pub(crate) fn pending(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+/// [`ChannelReceiver`] struct is a receiving side of a channel.
+///
+#[derive(Clone, Debug)]
+pub(crate) struct ChannelReceiver {
+    /// Channel of a receiver.
+    ///
+    channel: Channel,
+    /// Sequence number of the oldest message that can still be delivered.
+    ///
+    next_expected: u64,
+    /// Messages that arrived ahead of `next_expected` - payloads of ordered channels
+    /// are held until preceding messages arrive, while unordered ones are only remembered
+    /// as delivered (`None`) to drop their duplicates.
+    ///
+    received: BTreeMap<u64, Option<Vec<u8>>>,
+}
+impl ChannelReceiver {
+    /// Constructs receiver of given channel.
+    ///
+    pub(crate) fn new(channel: Channel) -> Self {
+        ChannelReceiver {
+            channel,
+            next_expected: 0,
+            received: BTreeMap::new(),
+        }
+    }
+
+    /// Receives message with given sequence number and returns messages that should be delivered.
+    ///
+    /// Reliable channels drop messages that are outside of receive window.
+    ///
+    pub(crate) fn receive(&mut self, sequence: u64, payload: Vec<u8>) -> Vec<Vec<u8>> {
+        match self.channel {
+            Channel::Unreliable => vec![payload],
+            Channel::Sequenced => {
+                let Some(next_expected) = sequence
+                    .checked_add(1)
+                    .filter(|_| sequence >= self.next_expected)
+                else {
+                    return Vec::new();
+                };
+                self.next_expected = next_expected;
+                vec![payload]
+            }
+            Channel::Reliable | Channel::ReliableOrdered => {
+                if sequence < self.next_expected
+                    || sequence >= self.next_expected.saturating_add(RECEIVE_WINDOW)
+                    || self.received.contains_key(&sequence)
+                {
+                    return Vec::new();
+                }
+
+                let mut delivered: Vec<Vec<u8>> = Vec::new();
+                if self.channel == Channel::Reliable {
+                    delivered.push(payload);
+                    let _ = self.received.insert(sequence, None);
+                } else {
+                    let _ = self.received.insert(sequence, Some(payload));
+                }
+                while let Some(entry) = self.received.remove(&self.next_expected) {
+                    delivered.extend(entry);
+                    let Some(next_expected) = self.next_expected.checked_add(1) else {
+                        break;
+                    };
+                    self.next_expected = next_expected;
+                }
+                delivered
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Channel, ChannelReceiver, ChannelSender, RECEIVE_WINDOW};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn receiving() {
+        let mut ordered: ChannelReceiver = ChannelReceiver::new(Channel::ReliableOrdered);
+        assert!(ordered.receive(1, vec![1]).is_empty());
+        assert_eq!(ordered.receive(0, vec![0]), [vec![0], vec![1]]);
+        assert!(ordered.receive(1, vec![1]).is_empty());
+
+        let mut reliable: ChannelReceiver = ChannelReceiver::new(Channel::Reliable);
+        assert_eq!(reliable.receive(1, vec![1]), [vec![1]]);
+        assert!(reliable.receive(1, vec![1]).is_empty());
+        assert_eq!(reliable.receive(0, vec![0]), [vec![0]]);
+        assert!(reliable.receive(0, vec![0]).is_empty());
+
+        // messages beyond receive window are dropped until window reaches them
+        assert!(reliable.receive(RECEIVE_WINDOW + 2, vec![3]).is_empty());
+        assert!(reliable.receive(u64::MAX, vec![4]).is_empty());
+        assert_eq!(reliable.receive(RECEIVE_WINDOW + 1, vec![3]), [vec![3]]);
+
+        let mut sequenced: ChannelReceiver = ChannelReceiver::new(Channel::Sequenced);
+        assert_eq!(sequenced.receive(2, vec![2]), [vec![2]]);
+        assert!(sequenced.receive(1, vec![1]).is_empty());
+        assert!(sequenced.receive(u64::MAX, vec![3]).is_empty());
+        assert_eq!(sequenced.receive(3, vec![3]), [vec![3]]);
+
+        let mut unreliable: ChannelReceiver = ChannelReceiver::new(Channel::Unreliable);
+        assert_eq!(unreliable.receive(1, vec![1]), [vec![1]]);
+        assert_eq!(unreliable.receive(1, vec![1]), [vec![1]]);
+    }
+
+    #[test]
+    fn resending() {
+        let now: Instant = Instant::now();
+        let interval: Duration = Duration::from_millis(100);
+
+        let mut sender: ChannelSender = ChannelSender::new(Channel::Reliable);
+        assert_eq!(sender.send(&[1], now), 0);
+        assert_eq!(sender.send(&[2], now), 1);
+        assert!(sender.resend(now, interval).is_empty());
+
+        sender.acknowledge(0);
+        assert_eq!(sender.resend(now + interval, interval), [(1, vec![2])]);
+        assert!(sender.resend(now + interval, interval).is_empty());
+        sender.acknowledge(1);
+        assert_eq!(sender.pending(), 0);
+
+        let mut unreliable: ChannelSender = ChannelSender::new(Channel::Unreliable);
+        let _ = unreliable.send(&[1], now);
+        assert_eq!(unreliable.pending(), 0);
+    }
+}
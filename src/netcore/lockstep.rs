@@ -0,0 +1,434 @@
+//! `netcore::lockstep` submodule implements [`Lockstep`] - deterministic lockstep netcode mode,
+//! in which peers exchange only inputs of players.
+//!
+//! Every peer simulates the game by itself, advancing it by fixed ticks
+//! (see [`FixedTimestep`](crate::physicscore::world::FixedTimestep)) only when inputs of all players
+//! for the tick are known. Local inputs are scheduled several ticks ahead (input delay),
+//! which hides latency of the network. Simulation should be fully deterministic - all randomness
+//! should come from [`Rng`](crate::mathcore::random::Rng) with shared seed, and peers periodically
+//! exchange hashes of their states (see [`StateHasher`]) to detect desynchronization.
+//!
+
+use crate::{
+    log::{self, LogTarget},
+    netcore::{
+        channels::Channel,
+        transport::{decode, NetEvent, Transport},
+    },
+    GGError,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{collections::BTreeMap, hash::Hasher, mem};
+
+/// [`StateHasher`] struct implements FNV-1a hash function, which produces the same hashes
+/// on every platform and with every version of Rust (unlike hasher of standard library).
+///
+/// # Note
+/// Some types write platform-dependent data into hashers (e.g. `usize` and slices, which write their lengths as `usize`),
+/// and floats do not implement `Hash` at all - those should be converted
+/// to fixed-size integers (e.g. with `f32::to_bits`) before hashing.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::netcore::lockstep::StateHasher;
+/// # use std::hash::Hasher;
+/// let mut hasher: StateHasher = StateHasher::new();
+/// hasher.write_u32(10.5_f32.to_bits());
+/// hasher.write_u64(42);
+/// assert_eq!(hasher.finish(), 0x6b52_a51c_1b3d_f6b0);
+/// ```
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StateHasher(u64);
+impl StateHasher {
+    /// Offset basis of FNV-1a hash function.
+    ///
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    /// Prime of FNV-1a hash function.
+    ///
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    /// Constructs hasher with empty state.
+    ///
+    pub fn new() -> Self {
+        StateHasher(StateHasher::OFFSET_BASIS)
+    }
+}
+impl Default for StateHasher {
+    fn default() -> Self {
+        StateHasher::new()
+    }
+}
+impl Hasher for StateHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(StateHasher::PRIME);
+        }
+    }
+    fn write_u16(&mut self, value: u16) {
+        self.write(&value.to_le_bytes());
+    }
+    fn write_u32(&mut self, value: u32) {
+        self.write(&value.to_le_bytes());
+    }
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+    fn write_u128(&mut self, value: u128) {
+        self.write(&value.to_le_bytes());
+    }
+}
+
+/// [`LockstepMessage`] enum lists messages that are exchanged by [`Lockstep`] peers.
+///
+#[derive(Serialize, Deserialize, Clone, Debug)]
+enum LockstepMessage<I> {
+    /// Input of a player for a tick.
+    ///
+    Input {
+        /// Index of a player.
+        ///
+        player: usize,
+        /// Tick for which input is scheduled.
+        ///
+        tick: u64,
+        /// Input of a player.
+        ///
+        input: I,
+    },
+    /// Hash of a state of a player after a tick.
+    ///
+    Hash {
+        /// Index of a player.
+        ///
+        player: usize,
+        /// Tick after which state was hashed.
+        ///
+        tick: u64,
+        /// Hash of a state.
+        ///
+        hash: u64,
+    },
+}
+
+/// [`Lockstep`] struct implements deterministic lockstep with input delay.
+///
+/// `I` is a type of input of one player for one tick.
+/// Players are identified by their indices, which should be agreed upon before the game starts
+/// (as well as seed of random number generator).
+///
+/// # Example
+/// ```rust
+/// # use ggengine::netcore::lockstep::{Lockstep, StateHasher};
+/// # use std::hash::{Hash, Hasher};
+/// // two players, local one has index 0, inputs are delayed by 2 ticks
+/// let mut lockstep: Lockstep<i8> = Lockstep::new(2, 0, 2).expect("Player index is valid");
+/// let mut state: i64 = 0;
+///
+/// // the first ticks use default inputs
+/// lockstep.add_local_input(1);
+/// while let Some((tick, inputs)) = lockstep.advance() {
+///     state += inputs.iter().map(|input| i64::from(*input)).sum::<i64>();
+///     if lockstep.should_hash(tick) {
+///         let mut hasher: StateHasher = StateHasher::new();
+///         state.hash(&mut hasher);
+///         lockstep.record_hash(tick, hasher.finish());
+///     }
+/// }
+/// assert_eq!(lockstep.tick(), 2);
+///
+/// // simulation stalls until remote input for the tick arrives
+/// assert!(lockstep.advance().is_none());
+/// lockstep.receive_input(1, 2, -1);
+/// assert_eq!(lockstep.advance(), Some((2, vec![1, -1])));
+/// assert!(lockstep.desync().is_none());
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct Lockstep<I> {
+    /// Number of players.
+    ///
+    players: usize,
+    /// Index of a local player.
+    ///
+    local_player: usize,
+    /// Number of ticks by which local inputs are delayed.
+    ///
+    input_delay: u64,
+    /// Number of ticks between state hashes.
+    ///
+    hash_interval: u64,
+
+    /// Tick that will be simulated next.
+    ///
+    tick: u64,
+    /// Tick for which the next local input is scheduled.
+    ///
+    next_local_tick: u64,
+    /// Inputs of players for ticks that were not simulated yet.
+    ///
+    inputs: BTreeMap<u64, Vec<Option<I>>>,
+
+    /// Hashes of a local state for ticks that were not compared with all players yet
+    /// and numbers of remote hashes that were not compared with them.
+    ///
+    local_hashes: BTreeMap<u64, (u64, usize)>,
+    /// Hashes of remote states that were not compared with local ones yet.
+    ///
+    remote_hashes: BTreeMap<u64, Vec<(usize, u64)>>,
+    /// The first tick at which desynchronization was detected.
+    ///
+    desync: Option<u64>,
+
+    /// Messages that were not sent yet.
+    ///
+    outgoing: Vec<LockstepMessage<I>>,
+}
+impl<I: Clone + Default> Lockstep<I> {
+    /// Default number of ticks between state hashes.
+    ///
+    pub const DEFAULT_HASH_INTERVAL: u64 = 60;
+
+    /// Constructs lockstep for given number of players and index of a local player.
+    ///
+    /// Inputs of all players for the first `input_delay` ticks are default.
+    ///
+    /// # Errors
+    /// Error is returned if index of a local player is not less than number of players.
+    ///
+    pub fn new(players: usize, local_player: usize, input_delay: u64) -> Result<Self, GGError> {
+        if local_player >= players {
+            return Err(GGError::InvalidArgument(format!(
+                "Local player {local_player} is out of {players} players"
+            )));
+        }
+        Ok(Lockstep {
+            players,
+            local_player,
+            input_delay,
+            hash_interval: Lockstep::<I>::DEFAULT_HASH_INTERVAL,
+
+            tick: 0,
+            next_local_tick: input_delay,
+            inputs: (0..input_delay)
+                .map(|tick| (tick, vec![Some(I::default()); players]))
+                .collect(),
+
+            local_hashes: BTreeMap::new(),
+            remote_hashes: BTreeMap::new(),
+            desync: None,
+
+            outgoing: Vec::new(),
+        })
+    }
+
+    /// Returns number of players.
+    ///
+    pub fn players(&self) -> usize {
+        self.players
+    }
+    /// Returns index of a local player.
+    ///
+    pub fn local_player(&self) -> usize {
+        self.local_player
+    }
+    /// Returns number of ticks by which local inputs are delayed.
+    ///
+    pub fn input_delay(&self) -> u64 {
+        self.input_delay
+    }
+    /// Returns number of ticks between state hashes.
+    ///
+    pub fn hash_interval(&self) -> u64 {
+        self.hash_interval
+    }
+    /// Sets number of ticks between state hashes (0 disables hashing).
+    ///
+    pub fn set_hash_interval(&mut self, hash_interval: u64) {
+        self.hash_interval = hash_interval;
+    }
+    /// Returns tick that will be simulated next.
+    ///
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Stores input of a player for a tick.
+    ///
+    /// Inputs for ticks that were already simulated and repeated inputs are ignored.
+    ///
+    fn store_input(&mut self, player: usize, tick: u64, input: I) {
+        if player >= self.players || tick < self.tick {
+            return;
+        }
+        let inputs: &mut Vec<Option<I>> = self
+            .inputs
+            .entry(tick)
+            .or_insert_with(|| vec![None; self.players]);
+        if inputs[player].is_none() {
+            inputs[player] = Some(input);
+        }
+    }
+    /// Schedules input of a local player for the next tick that has no local input
+    /// (which is `input_delay` ticks ahead of simulation if inputs are added once per tick).
+    ///
+    pub fn add_local_input(&mut self, input: I) {
+        let tick: u64 = self.next_local_tick;
+        self.next_local_tick += 1;
+        self.store_input(self.local_player, tick, input.clone());
+        self.outgoing.push(LockstepMessage::Input {
+            player: self.local_player,
+            tick,
+            input,
+        });
+    }
+    /// Stores input of a remote player for a tick.
+    ///
+    pub fn receive_input(&mut self, player: usize, tick: u64, input: I) {
+        self.store_input(player, tick, input);
+    }
+
+    /// Returns whether inputs of all players for the next tick are known.
+    ///
+    pub fn is_ready(&self) -> bool {
+        self.inputs
+            .get(&self.tick)
+            .is_some_and(|inputs| inputs.iter().all(Option::is_some))
+    }
+    /// Returns the next tick and inputs of all players for it if they are known,
+    /// moving simulation to the following tick.
+    ///
+    /// If `None` is returned, simulation should stall until remote inputs arrive.
+    ///
+    pub fn advance(&mut self) -> Option<(u64, Vec<I>)> {
+        if !self.is_ready() {
+            return None;
+        }
+        let inputs: Vec<I> = self
+            .inputs
+            .remove(&self.tick)?
+            .into_iter()
+            .flatten()
+            .collect();
+        let tick: u64 = self.tick;
+        self.tick += 1;
+        Some((tick, inputs))
+    }
+
+    /// Returns whether state should be hashed after given tick.
+    ///
+    pub fn should_hash(&self, tick: u64) -> bool {
+        self.hash_interval != 0 && tick.is_multiple_of(self.hash_interval)
+    }
+    /// Records hash of a local state after given tick and schedules it for sending.
+    ///
+    pub fn record_hash(&mut self, tick: u64, hash: u64) {
+        let _ = self.local_hashes.insert(tick, (hash, self.players - 1));
+        self.outgoing.push(LockstepMessage::Hash {
+            player: self.local_player,
+            tick,
+            hash,
+        });
+        self.compare_hashes(tick);
+    }
+    /// Stores hash of a state of a remote player after given tick.
+    ///
+    pub fn receive_hash(&mut self, player: usize, tick: u64, hash: u64) {
+        self.remote_hashes
+            .entry(tick)
+            .or_default()
+            .push((player, hash));
+        self.compare_hashes(tick);
+    }
+    /// Compares local hash of a tick with remote ones that are known.
+    ///
+    fn compare_hashes(&mut self, tick: u64) {
+        let Some((local_hash, remaining)) = self.local_hashes.get_mut(&tick) else {
+            return;
+        };
+        for (player, hash) in self.remote_hashes.remove(&tick).into_iter().flatten() {
+            *remaining = remaining.saturating_sub(1);
+            if hash != *local_hash && self.desync.is_none_or(|desync| tick < desync) {
+                log::error(
+                    LogTarget::NetCore,
+                    format_args!("State of player {player} has desynchronized at tick {tick}"),
+                );
+                self.desync = Some(tick);
+            }
+        }
+        if *remaining == 0 {
+            let _ = self.local_hashes.remove(&tick);
+        }
+    }
+    /// Returns the first tick after which states of players were different.
+    ///
+    pub fn desync(&self) -> Option<u64> {
+        self.desync
+    }
+}
+impl<I: Clone + Default + Serialize + DeserializeOwned> Lockstep<I> {
+    /// Broadcasts inputs and hashes that were not sent yet to all established connections
+    /// through [`Channel::ReliableOrdered`].
+    ///
+    pub fn send(&mut self, transport: &mut Transport) -> Result<(), GGError> {
+        for message in mem::take(&mut self.outgoing) {
+            transport.broadcast(Channel::ReliableOrdered, &message)?;
+        }
+        Ok(())
+    }
+    /// Handles event if it is a message that was sent by another [`Lockstep`] peer.
+    ///
+    /// Returns whether event was handled (if it was not, it should be handled by a game).
+    ///
+    pub fn receive(&mut self, event: &NetEvent) -> bool {
+        let NetEvent::Message(_, _, payload) = event else {
+            return false;
+        };
+        match decode::<LockstepMessage<I>>(payload) {
+            Ok(LockstepMessage::Input {
+                player,
+                tick,
+                input,
+            }) => self.receive_input(player, tick, input),
+            Ok(LockstepMessage::Hash { player, tick, hash }) => {
+                self.receive_hash(player, tick, hash)
+            }
+            Err(_) => return false,
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Lockstep;
+
+    #[test]
+    fn desync_detection() {
+        let mut lockstep: Lockstep<u8> = Lockstep::new(3, 1, 0).expect("Player index is valid");
+        assert!(Lockstep::<u8>::new(2, 2, 0).is_err());
+        lockstep.set_hash_interval(2);
+
+        lockstep.add_local_input(1);
+        lockstep.receive_input(0, 0, 0);
+        assert!(!lockstep.is_ready());
+        lockstep.receive_input(2, 0, 2);
+        lockstep.receive_input(2, 0, 5);
+        assert_eq!(lockstep.advance(), Some((0, vec![0, 1, 2])));
+        assert!(lockstep.should_hash(0));
+        assert!(!lockstep.should_hash(1));
+
+        lockstep.receive_hash(0, 0, 10);
+        lockstep.record_hash(0, 10);
+        lockstep.receive_hash(2, 0, 10);
+        assert!(lockstep.desync().is_none());
+
+        lockstep.record_hash(2, 20);
+        lockstep.receive_hash(0, 2, 21);
+        assert_eq!(lockstep.desync(), Some(2));
+    }
+}
@@ -0,0 +1,15 @@
+//! Prelude module: `netcore::prelude` re-exports all `ggengine::netcore` items.
+//!
+//! # Examples
+//! Import all the exports.
+//!
+//! ```rust
+//! use ggengine::netcore::prelude::*;
+//! ```
+//!
+
+// re-exports
+pub use crate::netcore::channels::*;
+pub use crate::netcore::lockstep::*;
+pub use crate::netcore::replication::*;
+pub use crate::netcore::transport::*;
@@ -0,0 +1,971 @@
+//! `netcore::replication` submodule implements snapshot-based replication of entity state.
+//!
+//! Server registers replicated entities in [`ReplicationServer`] and updates their [`Replicated`]
+//! components every tick; changes are diffed against the last sent state and are broadcast
+//! as [`Snapshot`]s. Client applies snapshots in [`ReplicationClient`], which keeps interpolation
+//! buffers for remote entities, so their movement is smooth despite network jitter.
+//!
+//! Large component states are sent as [`Patch`]es against previously sent states
+//! whenever the patch is smaller than the state itself.
+//!
+//! Game objects of a [`Scene`] are replicated by [`replicate_server`] and [`replicate_client`] systems,
+//! which copy components that are registered in [`SceneReplication`] resource between scene and
+//! [`ReplicationServer`] or [`ReplicationClient`] resources.
+//!
+
+use crate::{
+    datacore::patches::Patch,
+    gamecore::{
+        components::{Component, Resource},
+        identifiers::GameObjectId,
+        scenes::Scene,
+        time::Time,
+        ComponentStorage,
+    },
+    log::{self, LogTarget},
+    mathcore::{transforms::Transform2D, Size},
+    netcore::{
+        channels::Channel,
+        transport::{decode, encode, ConnectionId, NetEvent, Transport},
+    },
+    GGError,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::{btree_map::Entry, BTreeMap, BTreeSet, VecDeque},
+    time::Duration,
+};
+
+/// [`Replicated`] trait marks components which state is replicated from server to clients.
+///
+/// # Implementation
+/// Name of a component identifies it in snapshots, so it should be unique
+/// and should not change between builds of a game.
+///
+/// ```rust
+/// # use ggengine::gamecore::components::Component;
+/// # use ggengine::netcore::replication::Replicated;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+/// struct Health(f32);
+/// impl Component for Health {}
+/// impl Replicated for Health {
+///     const NAME: &'static str = "Health";
+///
+///     fn interpolate(&self, other: &Self, t: f32) -> Self {
+///         Health(self.0 + (other.0 - self.0) * t)
+///     }
+/// }
+/// ```
+///
+pub trait Replicated: Component + Clone + Serialize + DeserializeOwned {
+    /// Name that identifies component in snapshots.
+    ///
+    const NAME: &'static str;
+
+    /// Interpolates between two states of component by `t` in [0; 1] range.
+    ///
+    /// By default, state is switched when `t` reaches 1.0 (no interpolation).
+    ///
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        if t < 1.0 {
+            self.clone()
+        } else {
+            other.clone()
+        }
+    }
+}
+impl Replicated for Transform2D {
+    const NAME: &'static str = "Transform2D";
+
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let lerp = |from: Size, to: Size| -> Size {
+            Size::from_value(from.get() + (to.get() - from.get()) * t)
+        };
+        Transform2D {
+            translation: self.translation.lerp(other.translation, t),
+            rotation: self.rotation.lerp(other.rotation, t),
+            scale: (
+                lerp(self.scale.0, other.scale.0),
+                lerp(self.scale.1, other.scale.1),
+            ),
+        }
+    }
+}
+
+/// [`NetworkId`] id struct is needed to identify replicated entities.
+///
+/// It is assigned by [`ReplicationServer`] and is the same on server and all clients.
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NetworkId(u64);
+impl NetworkId {
+    /// Returns underlying id.
+    ///
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+/// [`Snapshot`] struct describes changes of replicated state since the previous snapshot.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Snapshot {
+    /// Time of server at which snapshot was taken.
+    ///
+    pub time: Duration,
+    /// Entities that were spawned.
+    ///
+    pub spawned: Vec<NetworkId>,
+    /// Components that were added or changed - ids of entities, names of components and their encoded state.
+    ///
+    pub changed: Vec<(NetworkId, String, Vec<u8>)>,
+    /// Components that were changed - ids of entities, names of components and patches
+    /// against their previously sent state.
+    ///
+    pub patched: Vec<(NetworkId, String, Patch)>,
+    /// Components that were removed - ids of entities and names of components.
+    ///
+    pub removed: Vec<(NetworkId, String)>,
+    /// Entities that were despawned.
+    ///
+    pub despawned: Vec<NetworkId>,
+}
+impl Snapshot {
+    /// Returns whether snapshot contains no changes.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.spawned.is_empty()
+            && self.changed.is_empty()
+            && self.patched.is_empty()
+            && self.removed.is_empty()
+            && self.despawned.is_empty()
+    }
+}
+
+/// [`ReplicationMessage`] enum is an envelope in which snapshots are sent through [`Transport`].
+///
+/// Envelope is tagged, so messages of replication can share transport and channel
+/// with messages of a game.
+///
+#[derive(Serialize, Deserialize, Clone, Debug)]
+enum ReplicationMessage {
+    /// Snapshot of replicated state.
+    ///
+    Snapshot(Snapshot),
+}
+
+/// Components of an entity - names of components and their encoded state.
+///
+type EncodedComponents = BTreeMap<String, Vec<u8>>;
+/// Received states of a component - times of server at which they were taken and encoded states.
+///
+type InterpolationBuffer = VecDeque<(Duration, Vec<u8>)>;
+
+/// [`ReplicationServer`] struct tracks state of replicated entities on the server.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::netcore::replication::{NetworkId, ReplicationClient, ReplicationServer, Snapshot};
+/// # use ggengine::gamecore::time::Time;
+/// # use ggengine::mathcore::{transforms::Transform2D, vectors::Vector2};
+/// # use std::time::Duration;
+/// let mut server: ReplicationServer = ReplicationServer::new();
+/// let mut client: ReplicationClient = ReplicationClient::new(Duration::ZERO);
+/// let mut time: Time = Time::new();
+///
+/// let player: NetworkId = server.spawn();
+/// server.set(player, &Transform2D::identity()).expect("Entity is spawned");
+/// let snapshot: Snapshot = server.snapshot(&time).expect("Player was spawned");
+/// let _ = client.apply(snapshot);
+///
+/// time.update_with(Duration::from_millis(50));
+/// server.set(player, &Transform2D::identity()).expect("Entity is spawned");
+/// assert!(server.snapshot(&time).is_none());
+///
+/// server
+///     .set(player, &Transform2D::from_translation(Vector2::from([10.0, 0.0])))
+///     .expect("Entity is spawned");
+/// let _ = client.apply(server.snapshot(&time).expect("Player has moved"));
+/// client.update(&time);
+/// assert_eq!(
+///     client.get::<Transform2D>(player).expect("Player is replicated").translation,
+///     Vector2::from([10.0, 0.0]),
+/// );
+/// ```
+///
+#[derive(Clone, Debug, Default)]
+pub struct ReplicationServer {
+    /// Id that will be assigned to the next spawned entity.
+    ///
+    next_id: u64,
+    /// Current state of entities.
+    ///
+    current: BTreeMap<NetworkId, EncodedComponents>,
+    /// State of entities that was sent in the last snapshot.
+    ///
+    sent: BTreeMap<NetworkId, EncodedComponents>,
+}
+impl ReplicationServer {
+    /// Constructs server with no entities.
+    ///
+    pub fn new() -> Self {
+        ReplicationServer::default()
+    }
+
+    /// Spawns replicated entity and returns its id.
+    ///
+    pub fn spawn(&mut self) -> NetworkId {
+        let id: NetworkId = NetworkId(self.next_id);
+        self.next_id += 1;
+        let _ = self.current.insert(id, BTreeMap::new());
+        id
+    }
+    /// Despawns replicated entity and returns whether it existed.
+    ///
+    pub fn despawn(&mut self, id: NetworkId) -> bool {
+        self.current.remove(&id).is_some()
+    }
+    /// Returns whether entity is spawned.
+    ///
+    pub fn contains(&self, id: NetworkId) -> bool {
+        self.current.contains_key(&id)
+    }
+    /// Returns ids of spawned entities.
+    ///
+    pub fn entities(&self) -> impl Iterator<Item = NetworkId> + '_ {
+        self.current.keys().copied()
+    }
+
+    /// Sets current state of component of an entity.
+    ///
+    /// # Errors
+    /// Error is returned if entity is not spawned or if component cannot be encoded.
+    ///
+    pub fn set<C: Replicated>(&mut self, id: NetworkId, component: &C) -> Result<(), GGError> {
+        let components: &mut EncodedComponents = self
+            .current
+            .get_mut(&id)
+            .ok_or_else(|| GGError::InvalidArgument(format!("Entity {} is not spawned", id.0)))?;
+        let _ = components.insert(String::from(C::NAME), encode(component)?);
+        Ok(())
+    }
+    /// Removes component from an entity and returns whether it existed.
+    ///
+    pub fn remove<C: Replicated>(&mut self, id: NetworkId) -> bool {
+        self.current
+            .get_mut(&id)
+            .and_then(|components| components.remove(C::NAME))
+            .is_some()
+    }
+
+    /// Diffs current state against state that was sent in the last snapshot
+    /// and returns snapshot of changes, or `None` if nothing has changed.
+    ///
+    pub fn snapshot(&mut self, time: &Time) -> Option<Snapshot> {
+        let mut snapshot: Snapshot = Snapshot {
+            time: time.elapsed(),
+            ..Snapshot::default()
+        };
+        for (id, components) in &self.current {
+            let sent: Option<&EncodedComponents> = self.sent.get(id);
+            if sent.is_none() {
+                snapshot.spawned.push(*id);
+            }
+            for (name, state) in components {
+                let previous: Option<&Vec<u8>> = sent.and_then(|sent| sent.get(name));
+                if previous == Some(state) {
+                    continue;
+                }
+                match previous.map(|previous| Patch::diff(previous, state)) {
+                    Some(patch) if patch.encoded_size() < state.len() => {
+                        snapshot.patched.push((*id, name.clone(), patch));
+                    }
+                    _ => snapshot.changed.push((*id, name.clone(), state.clone())),
+                }
+            }
+            for name in sent.into_iter().flat_map(BTreeMap::keys) {
+                if !components.contains_key(name) {
+                    snapshot.removed.push((*id, name.clone()));
+                }
+            }
+        }
+        snapshot.despawned = self
+            .sent
+            .keys()
+            .filter(|id| !self.current.contains_key(id))
+            .copied()
+            .collect();
+
+        if snapshot.is_empty() {
+            return None;
+        }
+        self.sent.clone_from(&self.current);
+        Some(snapshot)
+    }
+    /// Returns snapshot of the whole state that was sent in the last snapshot.
+    ///
+    /// This snapshot should be sent to newly connected clients, after which they can apply
+    /// snapshots of changes.
+    ///
+    pub fn full_snapshot(&self, time: &Time) -> Snapshot {
+        Snapshot {
+            time: time.elapsed(),
+            spawned: self.sent.keys().copied().collect(),
+            changed: self
+                .sent
+                .iter()
+                .flat_map(|(id, components)| {
+                    components
+                        .iter()
+                        .map(|(name, state)| (*id, name.clone(), state.clone()))
+                })
+                .collect(),
+            ..Snapshot::default()
+        }
+    }
+
+    /// Sends full snapshot to given connection through [`Channel::ReliableOrdered`].
+    ///
+    pub fn send_full_state(
+        &self,
+        transport: &mut Transport,
+        id: ConnectionId,
+        time: &Time,
+    ) -> Result<(), GGError> {
+        transport.send(
+            id,
+            Channel::ReliableOrdered,
+            &ReplicationMessage::Snapshot(self.full_snapshot(time)),
+        )
+    }
+    /// Diffs state and broadcasts snapshot of changes to all established connections
+    /// through [`Channel::ReliableOrdered`].
+    ///
+    /// This system should be run once per network tick, after components of entities are set.
+    ///
+    pub fn broadcast(&mut self, transport: &mut Transport, time: &Time) -> Result<(), GGError> {
+        match self.snapshot(time) {
+            Some(snapshot) => transport.broadcast(
+                Channel::ReliableOrdered,
+                &ReplicationMessage::Snapshot(snapshot),
+            ),
+            None => Ok(()),
+        }
+    }
+}
+impl Resource for ReplicationServer {}
+
+/// [`ReplicationEvent`] enum lists changes of remote entities that are reported by [`ReplicationClient`].
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReplicationEvent {
+    /// Remote entity was spawned.
+    ///
+    Spawned(NetworkId),
+    /// Remote entity was despawned.
+    ///
+    Despawned(NetworkId),
+}
+
+/// [`ReplicationClient`] struct holds state of remote entities on the client.
+///
+/// Every component of remote entity has an interpolation buffer of received states.
+/// Components are sampled at server time that is delayed by interpolation delay,
+/// so there are usually two states to interpolate between.
+///
+#[derive(Clone, Debug)]
+pub struct ReplicationClient {
+    /// Delay of sampling behind the latest known server time.
+    ///
+    pub interpolation_delay: Duration,
+    /// Estimate of current server time.
+    ///
+    server_time: Duration,
+    /// Interpolation buffers of components of remote entities.
+    ///
+    entities: BTreeMap<NetworkId, BTreeMap<String, InterpolationBuffer>>,
+}
+impl ReplicationClient {
+    /// Constructs client with no entities and given interpolation delay.
+    ///
+    /// Delay should be slightly bigger than interval between snapshots.
+    ///
+    pub fn new(interpolation_delay: Duration) -> Self {
+        ReplicationClient {
+            interpolation_delay,
+            server_time: Duration::ZERO,
+            entities: BTreeMap::new(),
+        }
+    }
+
+    /// Returns ids of remote entities.
+    ///
+    pub fn entities(&self) -> impl Iterator<Item = NetworkId> + '_ {
+        self.entities.keys().copied()
+    }
+    /// Returns whether remote entity exists.
+    ///
+    pub fn contains(&self, id: NetworkId) -> bool {
+        self.entities.contains_key(&id)
+    }
+    /// Returns server time at which components are sampled.
+    ///
+    pub fn sample_time(&self) -> Duration {
+        self.server_time.saturating_sub(self.interpolation_delay)
+    }
+
+    /// Applies snapshot and returns spawns and despawns of remote entities.
+    ///
+    pub fn apply(&mut self, snapshot: Snapshot) -> Vec<ReplicationEvent> {
+        self.server_time = self.server_time.max(snapshot.time);
+
+        let mut events: Vec<ReplicationEvent> = Vec::new();
+        for id in snapshot.spawned {
+            if let Entry::Vacant(entry) = self.entities.entry(id) {
+                let _ = entry.insert(BTreeMap::new());
+                events.push(ReplicationEvent::Spawned(id));
+            }
+        }
+        for (id, name, state) in snapshot.changed {
+            if let Some(components) = self.entities.get_mut(&id) {
+                components
+                    .entry(name)
+                    .or_default()
+                    .push_back((snapshot.time, state));
+            }
+        }
+        for (id, name, patch) in snapshot.patched {
+            let Some(buffer) = self
+                .entities
+                .get_mut(&id)
+                .and_then(|components| components.get_mut(&name))
+            else {
+                continue;
+            };
+            match buffer.back().map(|(_, base)| patch.apply(base)) {
+                Some(Ok(state)) => buffer.push_back((snapshot.time, state)),
+                Some(Err(error)) => log::warn(
+                    LogTarget::NetCore,
+                    format_args!("Patch of {name} of entity {id:?} was not applied: {error}"),
+                ),
+                None => {}
+            }
+        }
+        for (id, name) in snapshot.removed {
+            if let Some(components) = self.entities.get_mut(&id) {
+                let _ = components.remove(&name);
+            }
+        }
+        for id in snapshot.despawned {
+            if self.entities.remove(&id).is_some() {
+                events.push(ReplicationEvent::Despawned(id));
+            }
+        }
+        events
+    }
+    /// Applies snapshot if event is a replication message that was sent by [`ReplicationServer`].
+    ///
+    /// Returns `None` if event is not a replication message (so it should be handled by a game).
+    ///
+    pub fn receive(&mut self, event: &NetEvent) -> Option<Vec<ReplicationEvent>> {
+        let NetEvent::Message(_, _, payload) = event else {
+            return None;
+        };
+        let ReplicationMessage::Snapshot(snapshot) = decode(payload).ok()?;
+        Some(self.apply(snapshot))
+    }
+
+    /// Advances estimate of server time and drops states that are no longer needed for interpolation.
+    ///
+    /// This system should be run once per frame, after [`Time`] is updated.
+    ///
+    pub fn update(&mut self, time: &Time) {
+        self.server_time += time.delta();
+        let sample_time: Duration = self.sample_time();
+        for buffer in self.entities.values_mut().flat_map(BTreeMap::values_mut) {
+            while buffer.len() > 1 && buffer[1].0 <= sample_time {
+                let _ = buffer.pop_front();
+            }
+        }
+    }
+
+    /// Returns interpolated state of component of remote entity.
+    ///
+    /// Returns `None` if entity or component does not exist or if state cannot be decoded.
+    ///
+    pub fn get<C: Replicated>(&self, id: NetworkId) -> Option<C> {
+        let buffer: &InterpolationBuffer = self.entities.get(&id)?.get(C::NAME)?;
+        let sample_time: Duration = self.sample_time();
+
+        let next: usize = buffer.partition_point(|(time, _)| *time <= sample_time);
+        match (next.checked_sub(1).map(|i| &buffer[i]), buffer.get(next)) {
+            (Some((from_time, from)), Some((to_time, to))) => {
+                let (from, to): (C, C) = (decode(from).ok()?, decode(to).ok()?);
+                let t: f32 = ((sample_time - *from_time).as_secs_f32()
+                    / (*to_time - *from_time).as_secs_f32())
+                .clamp(0.0, 1.0);
+                Some(from.interpolate(&to, t))
+            }
+            (Some((_, state)), None) | (None, Some((_, state))) => decode(state).ok(),
+            (None, None) => None,
+        }
+    }
+}
+impl Resource for ReplicationClient {}
+
+/// [`Replicate`] struct is a marker component of game objects that are replicated by [`replicate_server`] system.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Replicate;
+impl Component for Replicate {}
+
+/// [`NetworkEntity`] struct is a component that binds game object to replicated entity.
+///
+/// It is inserted by [`replicate_server`] into replicated game objects of the server
+/// and by [`replicate_client`] into game objects that represent remote entities on the client.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NetworkEntity(NetworkId);
+impl NetworkEntity {
+    /// Returns id of replicated entity.
+    ///
+    pub fn id(&self) -> NetworkId {
+        self.0
+    }
+}
+impl Component for NetworkEntity {}
+
+/// Function that sets state of replicated entity from component of a game object.
+///
+type ComponentCollector =
+    fn(&ComponentStorage, GameObjectId, &mut ReplicationServer, NetworkId) -> Result<(), GGError>;
+/// Function that writes interpolated state of remote entity to component of a game object.
+///
+type ComponentApplier = fn(&ReplicationClient, &mut ComponentStorage, GameObjectId, NetworkId);
+
+/// [`SceneReplication`] struct is a resource that lists components which are replicated between scenes.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::{identifiers::GameObjectId, scenes::Scene, time::Time};
+/// # use ggengine::netcore::replication::{
+/// #     replicate_client, replicate_server, Replicate, ReplicationClient, ReplicationServer, SceneReplication,
+/// #     Snapshot,
+/// # };
+/// # use ggengine::mathcore::{transforms::Transform2D, vectors::Vector2};
+/// # use std::time::Duration;
+/// let replication: SceneReplication = SceneReplication::new().with_component::<Transform2D>();
+///
+/// let mut server: Scene = Scene::new();
+/// let _ = server.resources_mut().insert_resource(replication.clone());
+/// let _ = server.resources_mut().insert_resource(ReplicationServer::new());
+/// let _ = server.spawn((Replicate, Transform2D::from_translation(Vector2::from([1.0, 2.0]))));
+///
+/// let mut client: Scene = Scene::new();
+/// let _ = client.resources_mut().insert_resource(replication);
+/// let _ = client.resources_mut().insert_resource(ReplicationClient::new(Duration::ZERO));
+///
+/// replicate_server(&mut server);
+/// let snapshot: Snapshot = server
+///     .resources_mut()
+///     .get_resource_mut::<ReplicationServer>()
+///     .expect("Server exists")
+///     .snapshot(&Time::new())
+///     .expect("Game object was spawned");
+/// let _ = client
+///     .resources_mut()
+///     .get_resource_mut::<ReplicationClient>()
+///     .expect("Client exists")
+///     .apply(snapshot);
+/// replicate_client(&mut client);
+///
+/// let (_, transform): (GameObjectId, &Transform2D) = client
+///     .components()
+///     .components::<Transform2D>()
+///     .next()
+///     .expect("Game object was replicated");
+/// assert_eq!(transform.translation, Vector2::from([1.0, 2.0]));
+/// ```
+///
+#[derive(Clone, Debug, Default)]
+pub struct SceneReplication {
+    /// Functions of registered components by their names.
+    ///
+    components: BTreeMap<&'static str, (ComponentCollector, ComponentApplier)>,
+}
+impl SceneReplication {
+    /// Constructs registry without components.
+    ///
+    pub fn new() -> Self {
+        SceneReplication::default()
+    }
+
+    /// Registers component and returns the registry.
+    ///
+    pub fn with_component<C: Replicated>(mut self) -> Self {
+        self.add_component::<C>();
+        self
+    }
+    /// Registers component, so it is replicated by [`replicate_server`] and [`replicate_client`].
+    ///
+    pub fn add_component<C: Replicated>(&mut self) {
+        let _ = self
+            .components
+            .insert(C::NAME, (collect_component::<C>, apply_component::<C>));
+    }
+    /// Returns names of registered components.
+    ///
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.components.keys().copied()
+    }
+}
+impl Resource for SceneReplication {}
+
+/// Sets state of replicated entity from component of a game object, removing state if there is no component.
+///
+fn collect_component<C: Replicated>(
+    components: &ComponentStorage,
+    gameobject_id: GameObjectId,
+    server: &mut ReplicationServer,
+    id: NetworkId,
+) -> Result<(), GGError> {
+    match components.get_component::<C>(gameobject_id) {
+        Some(component) => server.set(id, component),
+        None => {
+            let _ = server.remove::<C>(id);
+            Ok(())
+        }
+    }
+}
+/// Writes interpolated state of remote entity to component of a game object, removing component if there is no state.
+///
+fn apply_component<C: Replicated>(
+    client: &ReplicationClient,
+    components: &mut ComponentStorage,
+    gameobject_id: GameObjectId,
+    id: NetworkId,
+) {
+    match client.get::<C>(id) {
+        Some(component) => {
+            let _ = components.insert_component(gameobject_id, component);
+        }
+        None => {
+            let _ = components.remove_component::<C>(gameobject_id);
+        }
+    }
+}
+
+/// Copies components of replicated game objects to [`ReplicationServer`] resource.
+///
+/// Game objects that have [`Replicate`] component are spawned as replicated entities
+/// (and receive [`NetworkEntity`] component); their components that are registered in
+/// [`SceneReplication`] resource are set as current state of those entities.
+/// Entities of the server which game objects were despawned or lost [`Replicate`] component are despawned.
+///
+/// This system should be run once per network tick, before changes are diffed and sent
+/// (see [`ReplicationServer::broadcast`]). Nothing happens if scene has no [`ReplicationServer`] resource.
+///
+pub fn replicate_server(scene: &mut Scene) {
+    let Some(mut server) = scene.resources_mut().remove_resource::<ReplicationServer>() else {
+        return;
+    };
+    let replication: SceneReplication = scene
+        .resources()
+        .get_resource::<SceneReplication>()
+        .cloned()
+        .unwrap_or_default();
+
+    let replicated: Vec<(GameObjectId, Option<NetworkId>)> = scene
+        .components()
+        .components::<Replicate>()
+        .map(|(gameobject_id, _)| {
+            (
+                gameobject_id,
+                scene
+                    .components()
+                    .get_component::<NetworkEntity>(gameobject_id)
+                    .map(NetworkEntity::id),
+            )
+        })
+        .collect();
+    let mut alive: BTreeSet<NetworkId> = BTreeSet::new();
+    for (gameobject_id, id) in replicated {
+        let id: NetworkId = match id {
+            Some(id) if server.contains(id) => id,
+            _ => {
+                let id: NetworkId = server.spawn();
+                let _ = scene
+                    .components_mut()
+                    .insert_component(gameobject_id, NetworkEntity(id));
+                id
+            }
+        };
+        let _ = alive.insert(id);
+        for (name, (collect, _)) in &replication.components {
+            if let Err(error) = collect(scene.components(), gameobject_id, &mut server, id) {
+                log::warn(
+                    LogTarget::NetCore,
+                    format_args!(
+                        "{name} of game object {gameobject_id:?} was not replicated: {error}"
+                    ),
+                );
+            }
+        }
+    }
+    let despawned: Vec<NetworkId> = server.entities().filter(|id| !alive.contains(id)).collect();
+    for id in despawned {
+        let _ = server.despawn(id);
+    }
+
+    let _ = scene.resources_mut().insert_resource(server);
+}
+
+/// Copies interpolated state of remote entities from [`ReplicationClient`] resource to game objects.
+///
+/// Every remote entity is represented by game object with [`NetworkEntity`] component - game objects
+/// are spawned and despawned together with entities, and their components that are registered in
+/// [`SceneReplication`] resource are inserted, updated or removed according to the state of entities.
+///
+/// This system should be run once per frame, after snapshots are applied and [`ReplicationClient::update`] is called.
+/// Nothing happens if scene has no [`ReplicationClient`] resource.
+///
+pub fn replicate_client(scene: &mut Scene) {
+    let Some(client) = scene.resources_mut().remove_resource::<ReplicationClient>() else {
+        return;
+    };
+    let replication: SceneReplication = scene
+        .resources()
+        .get_resource::<SceneReplication>()
+        .cloned()
+        .unwrap_or_default();
+
+    let bound: BTreeMap<NetworkId, GameObjectId> = scene
+        .components()
+        .components::<NetworkEntity>()
+        .map(|(gameobject_id, entity)| (entity.id(), gameobject_id))
+        .collect();
+    for (id, gameobject_id) in &bound {
+        if !client.contains(*id) {
+            let _ = scene.despawn(*gameobject_id);
+        }
+    }
+    for id in client.entities() {
+        let gameobject_id: GameObjectId = match bound.get(&id) {
+            Some(gameobject_id) => *gameobject_id,
+            None => scene.spawn(NetworkEntity(id)),
+        };
+        for (_, apply) in replication.components.values() {
+            apply(&client, scene.components_mut(), gameobject_id, id);
+        }
+    }
+
+    let _ = scene.resources_mut().insert_resource(client);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        replicate_client, replicate_server, NetworkEntity, NetworkId, Replicate, Replicated,
+        ReplicationClient, ReplicationEvent, ReplicationServer, SceneReplication, Snapshot,
+    };
+    use crate::{
+        gamecore::{components::Component, identifiers::GameObjectId, scenes::Scene, time::Time},
+        mathcore::{transforms::Transform2D, vectors::Vector2},
+    };
+    use serde::{Deserialize, Serialize};
+    use std::time::Duration;
+
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+    struct Inventory(Vec<u32>);
+    impl Component for Inventory {}
+    impl Replicated for Inventory {
+        const NAME: &'static str = "Inventory";
+    }
+
+    #[test]
+    fn diffing() {
+        let mut server: ReplicationServer = ReplicationServer::new();
+        let time: Time = Time::new();
+        let (a, b): (NetworkId, NetworkId) = (server.spawn(), server.spawn());
+        server
+            .set(a, &Transform2D::identity())
+            .expect("Entity is spawned");
+        let snapshot: Snapshot = server.snapshot(&time).expect("Entities were spawned");
+        assert_eq!(snapshot.spawned, [a, b]);
+        assert_eq!(snapshot.changed.len(), 1);
+        assert!(server.snapshot(&time).is_none());
+
+        assert!(server.remove::<Transform2D>(a));
+        assert!(server.despawn(b));
+        assert!(server.set(b, &Transform2D::identity()).is_err());
+        let snapshot: Snapshot = server.snapshot(&time).expect("State has changed");
+        assert_eq!(snapshot.removed, [(a, String::from("Transform2D"))]);
+        assert_eq!(snapshot.despawned, [b]);
+        assert_eq!(server.full_snapshot(&time).spawned, [a]);
+
+        let mut client: ReplicationClient = ReplicationClient::new(Duration::ZERO);
+        assert_eq!(
+            client.apply(server.full_snapshot(&time)),
+            [ReplicationEvent::Spawned(a)]
+        );
+        assert!(client.get::<Transform2D>(a).is_none());
+    }
+
+    #[test]
+    fn patches() {
+        let mut server: ReplicationServer = ReplicationServer::new();
+        let mut client: ReplicationClient = ReplicationClient::new(Duration::ZERO);
+        let time: Time = Time::new();
+        let id: NetworkId = server.spawn();
+
+        let mut inventory: Inventory = Inventory((0..100).map(|item| item * 1000).collect());
+        server.set(id, &inventory).expect("Entity is spawned");
+        let snapshot: Snapshot = server.snapshot(&time).expect("Entity was spawned");
+        assert!(snapshot.patched.is_empty());
+        let _ = client.apply(snapshot);
+        let mut lost: ReplicationClient = ReplicationClient::new(Duration::ZERO);
+        let _ = lost.apply(server.full_snapshot(&time));
+
+        inventory.0[50] = 7;
+        server.set(id, &inventory).expect("Entity is spawned");
+        let snapshot: Snapshot = server.snapshot(&time).expect("Inventory has changed");
+        assert!(snapshot.changed.is_empty());
+        assert_eq!(snapshot.patched.len(), 1);
+        let _ = client.apply(snapshot);
+        assert_eq!(client.get::<Inventory>(id), Some(inventory.clone()));
+
+        // patch against state that client has not received is ignored
+        inventory.0[0] = 7;
+        server.set(id, &inventory).expect("Entity is spawned");
+        let snapshot: Snapshot = server.snapshot(&time).expect("Inventory has changed");
+        let _ = lost.apply(snapshot.clone());
+        let _ = client.apply(snapshot);
+        assert_eq!(client.get::<Inventory>(id), Some(inventory));
+        assert_ne!(lost.get::<Inventory>(id), client.get::<Inventory>(id));
+    }
+
+    #[test]
+    fn interpolation() {
+        let mut server: ReplicationServer = ReplicationServer::new();
+        let mut client: ReplicationClient = ReplicationClient::new(Duration::from_millis(100));
+        let mut time: Time = Time::new();
+        let id: NetworkId = server.spawn();
+
+        for x in [0.0, 10.0, 20.0] {
+            time.update_with(Duration::from_millis(100));
+            server
+                .set(id, &Transform2D::from_translation(Vector2::from([x, 0.0])))
+                .expect("Entity is spawned");
+            let _ = client.apply(server.snapshot(&time).expect("Entity has moved"));
+        }
+        // sampling happens at 200ms
+        assert_eq!(
+            client
+                .get::<Transform2D>(id)
+                .expect("Entity exists")
+                .translation,
+            Vector2::from([10.0, 0.0])
+        );
+
+        time.update_with(Duration::from_millis(50));
+        client.update(&time);
+        assert_eq!(
+            client
+                .get::<Transform2D>(id)
+                .expect("Entity exists")
+                .translation,
+            Vector2::from([15.0, 0.0])
+        );
+
+        time.update_with(Duration::from_millis(100));
+        client.update(&time);
+        assert_eq!(
+            client
+                .get::<Transform2D>(id)
+                .expect("Entity exists")
+                .translation,
+            Vector2::from([20.0, 0.0])
+        );
+    }
+
+    #[test]
+    fn scenes() {
+        let replication: SceneReplication = SceneReplication::new()
+            .with_component::<Transform2D>()
+            .with_component::<Inventory>();
+        let mut server: Scene = Scene::new();
+        let _ = server.resources_mut().insert_resource(replication.clone());
+        let _ = server
+            .resources_mut()
+            .insert_resource(ReplicationServer::new());
+        let mut client: Scene = Scene::new();
+        let _ = client.resources_mut().insert_resource(replication);
+        let _ = client
+            .resources_mut()
+            .insert_resource(ReplicationClient::new(Duration::ZERO));
+        let sync = |server: &mut Scene, client: &mut Scene| {
+            replicate_server(server);
+            let snapshot: Option<Snapshot> = server
+                .resources_mut()
+                .get_resource_mut::<ReplicationServer>()
+                .expect("Server exists")
+                .snapshot(&Time::new());
+            if let Some(snapshot) = snapshot {
+                let _ = client
+                    .resources_mut()
+                    .get_resource_mut::<ReplicationClient>()
+                    .expect("Client exists")
+                    .apply(snapshot);
+            }
+            replicate_client(client);
+        };
+
+        let player: GameObjectId =
+            server.spawn((Replicate, Transform2D::identity(), Inventory(vec![1, 2])));
+        // game object without marker is not replicated
+        let _ = server.spawn(Transform2D::identity());
+        sync(&mut server, &mut client);
+        let id: NetworkId = server
+            .components()
+            .get_component::<NetworkEntity>(player)
+            .expect("Player is replicated")
+            .id();
+        let remote: Vec<(GameObjectId, NetworkId)> = client
+            .components()
+            .components::<NetworkEntity>()
+            .map(|(gameobject_id, entity)| (gameobject_id, entity.id()))
+            .collect();
+        assert_eq!(remote.len(), 1);
+        let (remote, remote_id): (GameObjectId, NetworkId) = remote[0];
+        assert_eq!(remote_id, id);
+        assert_eq!(
+            client.components().get_component::<Inventory>(remote),
+            Some(&Inventory(vec![1, 2]))
+        );
+
+        // changes and removals of components are replicated
+        server
+            .components_mut()
+            .get_component_mut::<Transform2D>(player)
+            .expect("Player has transform")
+            .translation = Vector2::from([5.0, 0.0]);
+        let _ = server
+            .components_mut()
+            .remove_component::<Inventory>(player);
+        sync(&mut server, &mut client);
+        assert_eq!(
+            client
+                .components()
+                .get_component::<Transform2D>(remote)
+                .expect("Transform is replicated")
+                .translation,
+            Vector2::from([5.0, 0.0])
+        );
+        assert!(!client.components().contains_component::<Inventory>(remote));
+
+        assert!(server.despawn(player));
+        sync(&mut server, &mut client);
+        assert!(!client.components().contains_gameobject(remote));
+    }
+}
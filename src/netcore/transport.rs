@@ -0,0 +1,811 @@
+//! `netcore::transport` submodule implements [`Transport`] - message-oriented transport
+//! that is built on top of UDP sockets.
+//!
+//! Transport establishes connections with a handshake, keeps them alive with periodic packets,
+//! detects timeouts and provides reliability and ordering of messages for channels that require them
+//! (see [`Channel`]).
+//!
+
+use crate::{
+    log::{self, LogTarget},
+    netcore::channels::{Channel, ChannelReceiver, ChannelSender},
+    GGError,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    io,
+    net::{SocketAddr, ToSocketAddrs, UdpSocket},
+    time::{Duration, Instant},
+};
+
+/// Encodes message into bytes by using Concise Binary Object Representation format.
+///
+pub fn encode<M: Serialize>(message: &M) -> Result<Vec<u8>, GGError> {
+    serde_cbor::to_vec(message)
+        .map_err(|error| GGError::InvalidArgument(format!("Message is not serializable: {error}")))
+}
+/// Decodes message from bytes that were produced by [`encode`].
+///
+pub fn decode<M: DeserializeOwned>(bytes: &[u8]) -> Result<M, GGError> {
+    serde_cbor::from_slice(bytes)
+        .map_err(|error| GGError::Decode(format!("Wrong message format: {error}")))
+}
+
+/// [`TransportConfig`] struct holds parameters of [`Transport`].
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TransportConfig {
+    /// Id of a protocol - packets with different id are ignored,
+    /// so incompatible versions of a game do not connect to each other.
+    ///
+    pub protocol_id: u32,
+    /// Whether transport accepts incoming connections (servers and peers do, clients do not).
+    ///
+    pub accept_connections: bool,
+    /// Maximal number of connections.
+    ///
+    pub max_connections: usize,
+    /// Maximal size of a message payload in bytes.
+    ///
+    pub max_payload_size: usize,
+
+    /// Time after which keepalive packet is sent if nothing else was sent.
+    ///
+    pub keepalive_interval: Duration,
+    /// Time after which connection is dropped if nothing was received.
+    ///
+    pub timeout: Duration,
+    /// Time after which unacknowledged reliable messages and handshake requests are resent.
+    ///
+    pub resend_interval: Duration,
+}
+impl Default for TransportConfig {
+    /// Returns config with 64 connections, 1 KiB payloads, 1 second keepalive interval,
+    /// 10 seconds timeout and 100 milliseconds resend interval, which does not accept connections.
+    ///
+    fn default() -> Self {
+        TransportConfig {
+            protocol_id: 0,
+            accept_connections: false,
+            max_connections: 64,
+            max_payload_size: 1024,
+
+            keepalive_interval: Duration::from_secs(1),
+            timeout: Duration::from_secs(10),
+            resend_interval: Duration::from_millis(100),
+        }
+    }
+}
+
+/// [`ConnectionId`] id struct is needed to identify connections of [`Transport`].
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ConnectionId(u64);
+impl ConnectionId {
+    /// Returns underlying id.
+    ///
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+/// [`DisconnectReason`] enum lists reasons for which connection can be closed.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DisconnectReason {
+    /// Remote side has closed the connection.
+    ///
+    Closed,
+    /// Nothing was received from the remote side for [`TransportConfig::timeout`].
+    ///
+    TimedOut,
+    /// Remote side has denied the connection (reason is attached).
+    ///
+    Denied(String),
+}
+
+/// [`NetEvent`] enum lists events that are produced by [`Transport::update`].
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NetEvent {
+    /// Connection was established.
+    ///
+    Connected(ConnectionId),
+    /// Connection was closed.
+    ///
+    Disconnected(ConnectionId, DisconnectReason),
+    /// Message was received (it can be decoded with [`decode`]).
+    ///
+    Message(ConnectionId, Channel, Vec<u8>),
+}
+
+/// [`Packet`] struct is a datagram that is sent by [`Transport`].
+///
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct Packet {
+    /// Id of a protocol.
+    ///
+    protocol_id: u32,
+    /// Content of a packet.
+    ///
+    kind: PacketKind,
+}
+/// [`PacketKind`] enum lists contents of packets.
+///
+#[derive(Serialize, Deserialize, Clone, Debug)]
+enum PacketKind {
+    /// Request to establish connection.
+    ///
+    ConnectRequest,
+    /// Connection was accepted.
+    ///
+    ConnectAccept,
+    /// Connection was denied.
+    ///
+    ConnectDeny(String),
+    /// Connection is closed.
+    ///
+    Disconnect,
+    /// Packet that keeps connection alive.
+    ///
+    KeepAlive,
+    /// Message of a channel.
+    ///
+    Message {
+        /// Channel of a message.
+        ///
+        channel: Channel,
+        /// Sequence number of a message in its channel.
+        ///
+        sequence: u64,
+        /// Encoded message.
+        ///
+        payload: Vec<u8>,
+    },
+    /// Acknowledgement of a reliable message.
+    ///
+    Ack {
+        /// Channel of acknowledged message.
+        ///
+        channel: Channel,
+        /// Sequence number of acknowledged message.
+        ///
+        sequence: u64,
+    },
+}
+
+/// [`Connection`] struct holds state of one connection.
+///
+#[derive(Clone, Debug)]
+struct Connection {
+    /// Address of the remote side.
+    ///
+    address: SocketAddr,
+    /// Whether handshake is completed.
+    ///
+    is_connected: bool,
+    /// Time at which the last packet was received.
+    ///
+    last_received: Instant,
+    /// Time at which the last packet was sent.
+    ///
+    last_sent: Instant,
+
+    /// Sending sides of channels.
+    ///
+    senders: BTreeMap<Channel, ChannelSender>,
+    /// Receiving sides of channels.
+    ///
+    receivers: BTreeMap<Channel, ChannelReceiver>,
+}
+impl Connection {
+    /// Constructs connection with given address.
+    ///
+    fn new(address: SocketAddr, is_connected: bool, now: Instant) -> Self {
+        Connection {
+            address,
+            is_connected,
+            last_received: now,
+            last_sent: now,
+
+            senders: BTreeMap::new(),
+            receivers: BTreeMap::new(),
+        }
+    }
+}
+
+/// [`Transport`] struct is a message-oriented transport that is built on top of non-blocking UDP socket.
+///
+/// Transport should be updated once per frame by [`Transport::update`] - this receives packets,
+/// resends unacknowledged messages, sends keepalive packets and drops timed out connections.
+/// Changes of connections and received messages are reported by [`NetEvent`]s.
+///
+/// # Example
+/// ```rust, no_run
+/// # use ggengine::netcore::transport::{decode, NetEvent, Transport, TransportConfig};
+/// # use ggengine::netcore::channels::Channel;
+/// let mut server: Transport = Transport::bind(
+///     "127.0.0.1:7777",
+///     TransportConfig { accept_connections: true, ..TransportConfig::default() },
+/// )
+/// .expect("Port is free");
+/// let mut client: Transport = Transport::bind("127.0.0.1:0", TransportConfig::default())
+///     .expect("Port is free");
+/// let _ = client.connect("127.0.0.1:7777").expect("Address is valid");
+///
+/// loop {
+///     for event in client.update() {
+///         if let NetEvent::Connected(server_id) = event {
+///             client
+///                 .send(server_id, Channel::ReliableOrdered, &String::from("Hello!"))
+///                 .expect("Connection is established");
+///         }
+///     }
+///     for event in server.update() {
+///         if let NetEvent::Message(_, _, payload) = event {
+///             let message: String = decode(&payload).expect("Message is a string");
+///             println!("{message}");
+///         }
+///     }
+/// }
+/// ```
+///
+#[derive(Debug)]
+pub struct Transport {
+    /// Socket of transport.
+    ///
+    socket: UdpSocket,
+    /// Parameters of transport.
+    ///
+    config: TransportConfig,
+    /// Time of the last update.
+    ///
+    now: Instant,
+
+    /// Id that will be assigned to the next connection.
+    ///
+    next_id: u64,
+    /// Connections of transport.
+    ///
+    connections: BTreeMap<ConnectionId, Connection>,
+    /// Ids of connections by addresses of their remote sides.
+    ///
+    addresses: HashMap<SocketAddr, ConnectionId>,
+    /// Events that were not returned yet.
+    ///
+    events: Vec<NetEvent>,
+}
+impl Transport {
+    /// Binds transport to given local address.
+    ///
+    /// Port 0 lets operating system choose a free port (see [`Transport::local_address`]).
+    ///
+    pub fn bind(address: impl ToSocketAddrs, config: TransportConfig) -> Result<Self, GGError> {
+        let socket: UdpSocket = UdpSocket::bind(address)?;
+        socket.set_nonblocking(true)?;
+        Ok(Transport {
+            socket,
+            config,
+            now: Instant::now(),
+
+            next_id: 0,
+            connections: BTreeMap::new(),
+            addresses: HashMap::new(),
+            events: Vec::new(),
+        })
+    }
+
+    /// Returns local address to which transport is bound.
+    ///
+    pub fn local_address(&self) -> Result<SocketAddr, GGError> {
+        Ok(self.socket.local_addr()?)
+    }
+    /// Returns parameters of transport.
+    ///
+    pub fn config(&self) -> &TransportConfig {
+        &self.config
+    }
+    /// Returns mutable reference to parameters of transport.
+    ///
+    pub fn config_mut(&mut self) -> &mut TransportConfig {
+        &mut self.config
+    }
+
+    /// Returns ids of established connections.
+    ///
+    pub fn connections(&self) -> impl Iterator<Item = ConnectionId> + '_ {
+        self.connections
+            .iter()
+            .filter(|(_, connection)| connection.is_connected)
+            .map(|(id, _)| *id)
+    }
+    /// Returns whether connection is established.
+    ///
+    pub fn is_connected(&self, id: ConnectionId) -> bool {
+        self.connections
+            .get(&id)
+            .is_some_and(|connection| connection.is_connected)
+    }
+    /// Returns address of the remote side of connection.
+    ///
+    pub fn remote_address(&self, id: ConnectionId) -> Option<SocketAddr> {
+        self.connections
+            .get(&id)
+            .map(|connection| connection.address)
+    }
+    /// Returns number of reliable messages of connection that were not acknowledged yet.
+    ///
+    pub fn pending_messages(&self, id: ConnectionId) -> usize {
+        self.connections.get(&id).map_or(0, |connection| {
+            connection
+                .senders
+                .values()
+                .map(ChannelSender::pending)
+                .sum()
+        })
+    }
+
+    /// Registers connection with given address and returns its id.
+    ///
+    fn add_connection(&mut self, address: SocketAddr, is_connected: bool) -> ConnectionId {
+        let id: ConnectionId = ConnectionId(self.next_id);
+        self.next_id += 1;
+        let _ = self
+            .connections
+            .insert(id, Connection::new(address, is_connected, self.now));
+        let _ = self.addresses.insert(address, id);
+        id
+    }
+    /// Removes connection and returns it.
+    ///
+    fn remove_connection(&mut self, id: ConnectionId) -> Option<Connection> {
+        let connection: Connection = self.connections.remove(&id)?;
+        let _ = self.addresses.remove(&connection.address);
+        Some(connection)
+    }
+
+    /// Starts handshake with transport at given address and returns id of a new connection.
+    ///
+    /// [`NetEvent::Connected`] is produced when handshake is completed.
+    /// If connection with that address already exists, its id is returned.
+    ///
+    pub fn connect(&mut self, address: impl ToSocketAddrs) -> Result<ConnectionId, GGError> {
+        let address: SocketAddr = address
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| GGError::InvalidArgument(String::from("Address was not resolved")))?;
+        if let Some(id) = self.addresses.get(&address) {
+            return Ok(*id);
+        }
+
+        let id: ConnectionId = self.add_connection(address, false);
+        self.send_packet(address, PacketKind::ConnectRequest);
+        log::info(LogTarget::NetCore, format_args!("Connecting to {address}"));
+        Ok(id)
+    }
+    /// Closes connection, notifying the remote side.
+    ///
+    /// No event is produced for connections that are closed locally.
+    /// Returns whether connection existed.
+    ///
+    pub fn disconnect(&mut self, id: ConnectionId) -> bool {
+        match self.remove_connection(id) {
+            Some(connection) => {
+                self.send_packet(connection.address, PacketKind::Disconnect);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sends raw bytes through given channel of connection.
+    ///
+    /// # Errors
+    /// Error is returned if connection does not exist or is not established yet,
+    /// or if payload exceeds [`TransportConfig::max_payload_size`].
+    ///
+    pub fn send_bytes(
+        &mut self,
+        id: ConnectionId,
+        channel: Channel,
+        payload: Vec<u8>,
+    ) -> Result<(), GGError> {
+        if payload.len() > self.config.max_payload_size {
+            return Err(GGError::InvalidArgument(format!(
+                "Payload of {} bytes exceeds maximal size of {} bytes",
+                payload.len(),
+                self.config.max_payload_size
+            )));
+        }
+        let now: Instant = self.now;
+        let connection: &mut Connection = self.connections.get_mut(&id).ok_or_else(|| {
+            GGError::InvalidArgument(format!("Connection {} does not exist", id.0))
+        })?;
+        if !connection.is_connected {
+            return Err(GGError::InvalidState(format!(
+                "Connection {} is not established yet",
+                id.0
+            )));
+        }
+
+        let sequence: u64 = connection
+            .senders
+            .entry(channel)
+            .or_insert_with(|| ChannelSender::new(channel))
+            .send(&payload, now);
+        let address: SocketAddr = connection.address;
+        self.send_packet(
+            address,
+            PacketKind::Message {
+                channel,
+                sequence,
+                payload,
+            },
+        );
+        Ok(())
+    }
+    /// Encodes message and sends it through given channel of connection (see [`Transport::send_bytes`]).
+    ///
+    pub fn send<M: Serialize>(
+        &mut self,
+        id: ConnectionId,
+        channel: Channel,
+        message: &M,
+    ) -> Result<(), GGError> {
+        self.send_bytes(id, channel, encode(message)?)
+    }
+    /// Encodes message and sends it through given channel of all established connections.
+    ///
+    pub fn broadcast<M: Serialize>(
+        &mut self,
+        channel: Channel,
+        message: &M,
+    ) -> Result<(), GGError> {
+        let payload: Vec<u8> = encode(message)?;
+        let ids: Vec<ConnectionId> = self.connections().collect();
+        for id in ids {
+            self.send_bytes(id, channel, payload.clone())?;
+        }
+        Ok(())
+    }
+
+    /// Sends packet to given address.
+    ///
+    /// Failures are logged, since datagrams can be lost anyway.
+    ///
+    fn send_packet(&mut self, address: SocketAddr, kind: PacketKind) {
+        let packet: Packet = Packet {
+            protocol_id: self.config.protocol_id,
+            kind,
+        };
+        let bytes: Vec<u8> = match encode(&packet) {
+            Ok(bytes) => bytes,
+            Err(error) => {
+                log::error(LogTarget::NetCore, error);
+                return;
+            }
+        };
+        if let Err(error) = self.socket.send_to(&bytes, address) {
+            log::warn(
+                LogTarget::NetCore,
+                format_args!("Failed to send packet to {address}: {error}"),
+            );
+        }
+        if let Some(connection) = self
+            .addresses
+            .get(&address)
+            .and_then(|id| self.connections.get_mut(id))
+        {
+            connection.last_sent = self.now;
+        }
+    }
+
+    /// Handles packet that was received from given address.
+    ///
+    fn handle_packet(&mut self, address: SocketAddr, kind: PacketKind) {
+        let id: Option<ConnectionId> = self.addresses.get(&address).copied();
+        if let Some(connection) = id.and_then(|id| self.connections.get_mut(&id)) {
+            connection.last_received = self.now;
+        }
+
+        match (kind, id) {
+            (PacketKind::ConnectRequest, Some(id)) if self.is_connected(id) => {
+                self.send_packet(address, PacketKind::ConnectAccept);
+            }
+            (PacketKind::ConnectRequest, Some(id)) if self.config.accept_connections => {
+                // both sides are connecting to each other
+                if let Some(connection) = self.connections.get_mut(&id) {
+                    connection.is_connected = true;
+                }
+                self.send_packet(address, PacketKind::ConnectAccept);
+                log::info(LogTarget::NetCore, format_args!("Connected to {address}"));
+                self.events.push(NetEvent::Connected(id));
+            }
+            (PacketKind::ConnectRequest, None) => {
+                if !self.config.accept_connections {
+                    self.send_packet(
+                        address,
+                        PacketKind::ConnectDeny(String::from("Connections are not accepted")),
+                    );
+                } else if self.connections.len() >= self.config.max_connections {
+                    self.send_packet(
+                        address,
+                        PacketKind::ConnectDeny(String::from("Server is full")),
+                    );
+                } else {
+                    let id: ConnectionId = self.add_connection(address, true);
+                    self.send_packet(address, PacketKind::ConnectAccept);
+                    log::info(
+                        LogTarget::NetCore,
+                        format_args!("Accepted connection from {address}"),
+                    );
+                    self.events.push(NetEvent::Connected(id));
+                }
+            }
+            (PacketKind::ConnectAccept, Some(id)) => {
+                if let Some(connection) = self.connections.get_mut(&id) {
+                    if !connection.is_connected {
+                        connection.is_connected = true;
+                        log::info(LogTarget::NetCore, format_args!("Connected to {address}"));
+                        self.events.push(NetEvent::Connected(id));
+                    }
+                }
+            }
+            (PacketKind::ConnectDeny(reason), Some(id)) if !self.is_connected(id) => {
+                let _ = self.remove_connection(id);
+                log::warn(
+                    LogTarget::NetCore,
+                    format_args!("Connection to {address} was denied: {reason}"),
+                );
+                self.events
+                    .push(NetEvent::Disconnected(id, DisconnectReason::Denied(reason)));
+            }
+            (PacketKind::Disconnect, Some(id)) => {
+                let _ = self.remove_connection(id);
+                log::info(
+                    LogTarget::NetCore,
+                    format_args!("Connection with {address} was closed"),
+                );
+                self.events
+                    .push(NetEvent::Disconnected(id, DisconnectReason::Closed));
+            }
+            (
+                PacketKind::Message {
+                    channel,
+                    sequence,
+                    payload,
+                },
+                Some(id),
+            ) => {
+                let Some(connection) = self
+                    .connections
+                    .get_mut(&id)
+                    .filter(|connection| connection.is_connected)
+                else {
+                    return;
+                };
+                let delivered: Vec<Vec<u8>> = connection
+                    .receivers
+                    .entry(channel)
+                    .or_insert_with(|| ChannelReceiver::new(channel))
+                    .receive(sequence, payload);
+                self.events.extend(
+                    delivered
+                        .into_iter()
+                        .map(|payload| NetEvent::Message(id, channel, payload)),
+                );
+                if channel.is_reliable() {
+                    self.send_packet(address, PacketKind::Ack { channel, sequence });
+                }
+            }
+            (PacketKind::Ack { channel, sequence }, Some(id)) => {
+                if let Some(sender) = self
+                    .connections
+                    .get_mut(&id)
+                    .and_then(|connection| connection.senders.get_mut(&channel))
+                {
+                    sender.acknowledge(sequence);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Updates transport and returns events that happened since the last update.
+    ///
+    pub fn update(&mut self) -> Vec<NetEvent> {
+        self.update_at(Instant::now())
+    }
+    /// Updates transport as if current time was `now` and returns events that happened since the last update.
+    ///
+    /// This function is useful for simulating time in tests; in other cases [`Transport::update`] should be used.
+    ///
+    pub fn update_at(&mut self, now: Instant) -> Vec<NetEvent> {
+        self.now = self.now.max(now);
+
+        let mut buffer: Vec<u8> = vec![0; self.config.max_payload_size * 2 + 64];
+        loop {
+            let (size, address): (usize, SocketAddr) = match self.socket.recv_from(&mut buffer) {
+                Ok(received) => received,
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => break,
+                Err(error) if error.kind() == io::ErrorKind::ConnectionReset => continue,
+                Err(error) => {
+                    log::warn(
+                        LogTarget::NetCore,
+                        format_args!("Failed to receive packet: {error}"),
+                    );
+                    break;
+                }
+            };
+            match decode::<Packet>(&buffer[..size]) {
+                Ok(packet) if packet.protocol_id == self.config.protocol_id => {
+                    self.handle_packet(address, packet.kind);
+                }
+                Ok(_) => log::trace(
+                    LogTarget::NetCore,
+                    format_args!("Ignored packet of another protocol from {address}"),
+                ),
+                Err(error) => log::trace(
+                    LogTarget::NetCore,
+                    format_args!("Ignored malformed packet from {address}: {error}"),
+                ),
+            }
+        }
+
+        let ids: Vec<ConnectionId> = self.connections.keys().copied().collect();
+        for id in ids {
+            let Some(connection) = self.connections.get_mut(&id) else {
+                continue;
+            };
+            let address: SocketAddr = connection.address;
+            if self.now.saturating_duration_since(connection.last_received) > self.config.timeout {
+                let _ = self.remove_connection(id);
+                log::warn(
+                    LogTarget::NetCore,
+                    format_args!("Connection with {address} has timed out"),
+                );
+                self.events
+                    .push(NetEvent::Disconnected(id, DisconnectReason::TimedOut));
+                continue;
+            }
+
+            let since_sent: Duration = self.now.saturating_duration_since(connection.last_sent);
+            if !connection.is_connected {
+                if since_sent >= self.config.resend_interval {
+                    self.send_packet(address, PacketKind::ConnectRequest);
+                }
+                continue;
+            }
+            let resent: Vec<(Channel, u64, Vec<u8>)> = connection
+                .senders
+                .iter_mut()
+                .flat_map(|(channel, sender)| {
+                    sender
+                        .resend(self.now, self.config.resend_interval)
+                        .into_iter()
+                        .map(|(sequence, payload)| (*channel, sequence, payload))
+                })
+                .collect();
+            if resent.is_empty() && since_sent >= self.config.keepalive_interval {
+                self.send_packet(address, PacketKind::KeepAlive);
+            }
+            for (channel, sequence, payload) in resent {
+                self.send_packet(
+                    address,
+                    PacketKind::Message {
+                        channel,
+                        sequence,
+                        payload,
+                    },
+                );
+            }
+        }
+
+        std::mem::take(&mut self.events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, ConnectionId, DisconnectReason, NetEvent, Transport, TransportConfig};
+    use crate::netcore::channels::Channel;
+    use std::{
+        net::SocketAddr,
+        thread,
+        time::{Duration, Instant},
+    };
+
+    /// Updates transports until predicate is satisfied by events of the second transport.
+    ///
+    fn pump(
+        first: &mut Transport,
+        second: &mut Transport,
+        mut predicate: impl FnMut(&NetEvent) -> bool,
+    ) -> Vec<NetEvent> {
+        let mut collected: Vec<NetEvent> = Vec::new();
+        for _ in 0..200 {
+            let _ = first.update();
+            let events: Vec<NetEvent> = second.update();
+            let is_satisfied: bool = events.iter().any(&mut predicate);
+            collected.extend(events);
+            if is_satisfied {
+                return collected;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        panic!("Expected event was not received");
+    }
+
+    #[test]
+    fn handshake_and_messages() {
+        let mut server: Transport = Transport::bind(
+            "127.0.0.1:0",
+            TransportConfig {
+                accept_connections: true,
+                ..TransportConfig::default()
+            },
+        )
+        .expect("Port is free");
+        let mut client: Transport =
+            Transport::bind("127.0.0.1:0", TransportConfig::default()).expect("Port is free");
+        let server_address: SocketAddr = server.local_address().expect("Socket is bound");
+
+        let server_id: ConnectionId = client.connect(server_address).expect("Address is valid");
+        assert!(client.send(server_id, Channel::Reliable, &0_u32).is_err());
+        let _ = pump(&mut server, &mut client, |event| {
+            *event == NetEvent::Connected(server_id)
+        });
+        assert!(client.is_connected(server_id));
+
+        for i in 0..5_u32 {
+            client
+                .send(server_id, Channel::ReliableOrdered, &i)
+                .expect("Connection is established");
+        }
+        let mut received: Vec<u32> = Vec::new();
+        let _ = pump(&mut client, &mut server, |event| {
+            if let NetEvent::Message(_, Channel::ReliableOrdered, payload) = event {
+                received.push(decode(payload).expect("Message is u32"));
+            }
+            received.len() == 5
+        });
+        assert_eq!(received, [0, 1, 2, 3, 4]);
+        for _ in 0..200 {
+            if client.pending_messages(server_id) == 0 {
+                break;
+            }
+            let _ = (server.update(), client.update());
+            thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(client.pending_messages(server_id), 0);
+
+        let client_id: ConnectionId = server.connections().next().expect("Client is connected");
+        assert!(server.disconnect(client_id));
+        let _ = pump(&mut server, &mut client, |event| {
+            *event == NetEvent::Disconnected(server_id, DisconnectReason::Closed)
+        });
+    }
+
+    #[test]
+    fn denial_and_timeout() {
+        let mut closed: Transport =
+            Transport::bind("127.0.0.1:0", TransportConfig::default()).expect("Port is free");
+        let mut client: Transport =
+            Transport::bind("127.0.0.1:0", TransportConfig::default()).expect("Port is free");
+        let id: ConnectionId = client
+            .connect(closed.local_address().expect("Socket is bound"))
+            .expect("Address is valid");
+        let _ = pump(&mut closed, &mut client, |event| {
+            matches!(
+                event,
+                NetEvent::Disconnected(disconnected, DisconnectReason::Denied(_)) if *disconnected == id
+            )
+        });
+
+        let id: ConnectionId = client.connect("127.0.0.1:9").expect("Address is valid");
+        let events: Vec<NetEvent> =
+            client.update_at(Instant::now() + client.config().timeout + Duration::from_secs(1));
+        assert!(events.contains(&NetEvent::Disconnected(id, DisconnectReason::TimedOut)));
+    }
+}
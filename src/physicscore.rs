@@ -0,0 +1,44 @@
+//! `ggengine::physicscore` module is a core that implements two-dimensional rigid body physics
+//! for the engine.
+//!
+//! # Prelude
+//! `ggengine::physicscore` prelude can be imported with `use ggengine::physicscore::prelude::*`.
+//!
+//! # Model
+//! Physical properties of an object are described by [`RigidBody`](bodies::RigidBody) component,
+//! and its placement is described by [`Transform2D`](crate::mathcore::transforms::Transform2D) component.
+//! Simulation is advanced in fixed steps (see [`FixedTimestep`](world::FixedTimestep)), which makes it
+//! stable and independent of frame rate.
+//! Collision shapes are described by [`Collider`](colliders::Collider) component, and collisions are
+//! found by the pipeline that is implemented in [`contacts`] submodule.
+//! Contacts are resolved by [`ContactSolver`](solver::ContactSolver) and their changes are reported by
+//! [`PhysicsEvent`](events::PhysicsEvent)s.
+//! Which colliders can collide is decided by their layers and masks and by global interaction
+//! matrix of layers ([`CollisionLayers`](layers::CollisionLayers)).
+//! Platformer characters can be controlled by [`PlatformerController`](characters::PlatformerController),
+//! which works together with one-way colliders and moving platforms of the world.
+//! Top-down characters can be moved through tilemaps without rigid bodies by [`TopDownMover`](movers::TopDownMover),
+//! which collides with [`TileColliders`](tilemaps::TileColliders) that can be imported from Tiled.
+//! World can also be queried for colliders that are hit by rays, overlap shapes or contain points
+//! (see [`queries`] submodule).
+//! Game objects of a [`Scene`](crate::gamecore::scenes::Scene) are simulated by [`step_physics`](scenes::step_physics)
+//! system, which reports events and query hits for game objects (see [`ScenePhysics`](scenes::ScenePhysics)).
+//!
+
+// submodules and public re-exports
+pub mod bodies;
+pub mod characters;
+pub mod colliders;
+pub mod contacts;
+pub mod events;
+pub mod layers;
+pub mod materials;
+pub mod movers;
+pub mod queries;
+pub mod scenes;
+pub mod solver;
+pub mod tilemaps;
+pub mod world;
+
+// prelude
+pub mod prelude;
@@ -0,0 +1,335 @@
+//! `physicscore::bodies` submodule implements [`RigidBody`] component, which describes physical
+//! properties of an object, and functions that integrate motion of rigid bodies.
+//!
+
+use crate::{
+    gamecore::components::Component,
+    mathcore::{transforms::Transform2D, vectors::Vector2, Angle},
+};
+use serde::{Deserialize, Serialize};
+
+/// [`BodyType`] enum lists types of rigid bodies.
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BodyType {
+    /// Dynamic bodies are fully simulated - they are affected by gravity, forces and collisions.
+    ///
+    #[default]
+    Dynamic,
+    /// Kinematic bodies move only by their velocity, which is controlled by user.
+    /// They are not affected by gravity, forces or collisions, but dynamic bodies collide with them.
+    ///
+    Kinematic,
+    /// Static bodies never move (e.g. walls and ground).
+    ///
+    Static,
+}
+
+/// [`RigidBody`] struct is a component that describes physical properties of an object.
+///
+/// Position and rotation of a body are not stored in [`RigidBody`] - they belong
+/// to [`Transform2D`] component of an object.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::physicscore::bodies::{BodyType, RigidBody};
+/// # use ggengine::mathcore::transforms::Transform2D;
+/// # use ggengine::mathcore::vectors::Vector2;
+/// let mut body: RigidBody = RigidBody::new(BodyType::Dynamic, 2.0);
+/// let mut transform: Transform2D = Transform2D::identity();
+///
+/// body.apply_impulse(Vector2::from([4.0, 0.0]));
+/// assert_eq!(body.velocity, Vector2::from([2.0, 0.0]));
+///
+/// body.integrate(&mut transform, Vector2::zero(), 0.5);
+/// assert_eq!(transform.translation, Vector2::from([1.0, 0.0]));
+/// ```
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq)]
+pub struct RigidBody {
+    /// Type of a body.
+    ///
+    body_type: BodyType,
+    /// Mass of a body.
+    ///
+    mass: f32,
+    /// Moment of inertia of a body.
+    ///
+    inertia: f32,
+
+    /// Linear velocity of a body (in units per second).
+    ///
+    pub velocity: Vector2,
+    /// Angular velocity of a body (in radians per second, positive values correspond to counterclockwise rotation).
+    ///
+    pub angular_velocity: f32,
+
+    /// Linear damping coefficient - the bigger it is, the faster body loses its linear velocity.
+    ///
+    pub linear_damping: f32,
+    /// Angular damping coefficient - the bigger it is, the faster body loses its angular velocity.
+    ///
+    pub angular_damping: f32,
+    /// Multiplier of gravity that affects this body.
+    ///
+    pub gravity_scale: f32,
+
+    /// Force that was accumulated since last integration.
+    ///
+    force: Vector2,
+    /// Torque that was accumulated since last integration.
+    ///
+    torque: f32,
+}
+impl RigidBody {
+    /// Constructs body of given type with given mass.
+    ///
+    /// Moment of inertia is initialized as the moment of inertia of a unit square with given mass.
+    /// Non-positive or non-finite mass is replaced with 1.0.
+    ///
+    pub fn new(body_type: BodyType, mass: f32) -> Self {
+        let mass: f32 = if mass.is_finite() && mass > 0.0 {
+            mass
+        } else {
+            1.0
+        };
+        RigidBody {
+            body_type,
+            mass,
+            inertia: mass / 6.0,
+
+            velocity: Vector2::zero(),
+            angular_velocity: 0.0,
+
+            linear_damping: 0.0,
+            angular_damping: 0.0,
+            gravity_scale: 1.0,
+
+            force: Vector2::zero(),
+            torque: 0.0,
+        }
+    }
+    /// Constructs dynamic body with given mass.
+    ///
+    pub fn dynamic(mass: f32) -> Self {
+        RigidBody::new(BodyType::Dynamic, mass)
+    }
+    /// Constructs kinematic body.
+    ///
+    pub fn kinematic() -> Self {
+        RigidBody::new(BodyType::Kinematic, 1.0)
+    }
+    /// Constructs static body.
+    ///
+    pub fn fixed() -> Self {
+        RigidBody::new(BodyType::Static, 1.0)
+    }
+
+    /// Returns type of a body.
+    ///
+    pub fn body_type(&self) -> BodyType {
+        self.body_type
+    }
+    /// Sets type of a body.
+    ///
+    /// Static bodies lose their velocity.
+    ///
+    pub fn set_body_type(&mut self, body_type: BodyType) {
+        self.body_type = body_type;
+        if body_type == BodyType::Static {
+            self.velocity = Vector2::zero();
+            self.angular_velocity = 0.0;
+        }
+    }
+
+    /// Returns mass of a body.
+    ///
+    pub fn mass(&self) -> f32 {
+        self.mass
+    }
+    /// Sets mass of a body, scaling its moment of inertia proportionally.
+    ///
+    /// Non-positive or non-finite mass is ignored.
+    ///
+    pub fn set_mass(&mut self, mass: f32) {
+        if mass.is_finite() && mass > 0.0 {
+            self.inertia *= mass / self.mass;
+            self.mass = mass;
+        }
+    }
+    /// Returns inverse mass of a body.
+    ///
+    /// Only dynamic bodies have non-zero inverse mass - other bodies behave as if their mass is infinite.
+    ///
+    pub fn inverse_mass(&self) -> f32 {
+        match self.body_type {
+            BodyType::Dynamic => 1.0 / self.mass,
+            BodyType::Kinematic | BodyType::Static => 0.0,
+        }
+    }
+    /// Returns moment of inertia of a body.
+    ///
+    pub fn inertia(&self) -> f32 {
+        self.inertia
+    }
+    /// Sets moment of inertia of a body.
+    ///
+    /// Non-positive or non-finite values are ignored.
+    ///
+    pub fn set_inertia(&mut self, inertia: f32) {
+        if inertia.is_finite() && inertia > 0.0 {
+            self.inertia = inertia;
+        }
+    }
+    /// Returns inverse moment of inertia of a body.
+    ///
+    /// Only dynamic bodies have non-zero inverse moment of inertia.
+    ///
+    pub fn inverse_inertia(&self) -> f32 {
+        match self.body_type {
+            BodyType::Dynamic => 1.0 / self.inertia,
+            BodyType::Kinematic | BodyType::Static => 0.0,
+        }
+    }
+
+    /// Applies force to the center of mass of a body.
+    ///
+    /// Force is accumulated and is applied on the next integration.
+    ///
+    pub fn apply_force(&mut self, force: Vector2) {
+        self.force += force;
+    }
+    /// Applies torque to a body.
+    ///
+    /// Torque is accumulated and is applied on the next integration.
+    ///
+    pub fn apply_torque(&mut self, torque: f32) {
+        self.torque += torque;
+    }
+    /// Applies impulse to the center of mass of a body, immediately changing its velocity.
+    ///
+    pub fn apply_impulse(&mut self, impulse: Vector2) {
+        self.velocity += impulse * self.inverse_mass();
+    }
+    /// Applies angular impulse to a body, immediately changing its angular velocity.
+    ///
+    pub fn apply_angular_impulse(&mut self, impulse: f32) {
+        self.angular_velocity += impulse * self.inverse_inertia();
+    }
+    /// Applies impulse at given offset from the center of mass of a body.
+    ///
+    /// Such impulse changes both linear and angular velocity of a body.
+    ///
+    pub fn apply_impulse_at(&mut self, impulse: Vector2, offset: Vector2) {
+        self.apply_impulse(impulse);
+        self.apply_angular_impulse(offset ^ impulse);
+    }
+    /// Returns velocity of a point that is located at given offset from the center of mass of a body.
+    ///
+    pub fn velocity_at(&self, offset: Vector2) -> Vector2 {
+        self.velocity + offset.perpendicular() * self.angular_velocity
+    }
+
+    /// Advances motion of a body by `dt` seconds by using semi-implicit Euler method.
+    ///
+    /// Dynamic bodies are affected by gravity, accumulated forces and damping, kinematic bodies
+    /// just move with their velocity and static bodies do not move at all.
+    /// Accumulated forces are cleared after integration.
+    ///
+    pub fn integrate(&mut self, transform: &mut Transform2D, gravity: Vector2, dt: f32) {
+        match self.body_type {
+            BodyType::Dynamic => {
+                self.velocity += (gravity * self.gravity_scale + self.force / self.mass) * dt;
+                self.angular_velocity += self.torque / self.inertia * dt;
+
+                self.velocity *= 1.0 / (1.0 + dt * self.linear_damping.max(0.0));
+                self.angular_velocity *= 1.0 / (1.0 + dt * self.angular_damping.max(0.0));
+            }
+            BodyType::Kinematic => {}
+            BodyType::Static => {
+                self.velocity = Vector2::zero();
+                self.angular_velocity = 0.0;
+            }
+        }
+        (self.force, self.torque) = (Vector2::zero(), 0.0);
+
+        transform.translation += self.velocity * dt;
+        transform.rotation += Angle::from_radians(self.angular_velocity * dt);
+    }
+}
+impl Default for RigidBody {
+    /// Returns dynamic body with mass of 1.0.
+    ///
+    fn default() -> Self {
+        RigidBody::dynamic(1.0)
+    }
+}
+impl Component for RigidBody {}
+
+/// Integrates motion of all given bodies by `dt` seconds.
+///
+/// This function is a system that operates on pairs of [`RigidBody`] and [`Transform2D`] components
+/// (see [`RigidBody::integrate`]).
+///
+pub fn integrate_bodies<'a>(
+    bodies: impl IntoIterator<Item = (&'a mut RigidBody, &'a mut Transform2D)>,
+    gravity: Vector2,
+    dt: f32,
+) {
+    for (body, transform) in bodies {
+        body.integrate(transform, gravity, dt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BodyType, RigidBody};
+    use crate::mathcore::{transforms::Transform2D, vectors::Vector2};
+
+    #[test]
+    fn integration() {
+        use crate::mathcore::floats::FloatOperations;
+
+        let gravity: Vector2 = Vector2::from([0.0, 10.0]);
+        let (mut dynamic, mut kinematic, mut fixed): (RigidBody, RigidBody, RigidBody) = (
+            RigidBody::dynamic(1.0),
+            RigidBody::kinematic(),
+            RigidBody::fixed(),
+        );
+        kinematic.velocity = Vector2::from([1.0, 0.0]);
+        fixed.velocity = Vector2::from([1.0, 0.0]);
+
+        let mut transforms: [Transform2D; 3] = [Transform2D::identity(); 3];
+        for _ in 0..10 {
+            dynamic.integrate(&mut transforms[0], gravity, 0.1);
+            kinematic.integrate(&mut transforms[1], gravity, 0.1);
+            fixed.integrate(&mut transforms[2], gravity, 0.1);
+        }
+        // semi-implicit Euler: sum of (i * g * dt) * dt for i in 1..=10
+        assert_eq!(
+            transforms[0].translation.round_up_to(3),
+            Vector2::from([0.0, 5.5])
+        );
+        assert_eq!(dynamic.velocity.round_up_to(3), Vector2::from([0.0, 10.0]));
+        assert_eq!(
+            transforms[1].translation.round_up_to(3),
+            Vector2::from([1.0, 0.0])
+        );
+        assert_eq!(transforms[2].translation, Vector2::zero());
+
+        let mut body: RigidBody = RigidBody::new(BodyType::Dynamic, -5.0);
+        assert_eq!(body.mass(), 1.0);
+        body.gravity_scale = 0.0;
+        body.linear_damping = 1.0;
+        body.velocity = Vector2::from([2.0, 0.0]);
+        body.apply_torque(1.0);
+        body.integrate(&mut transforms[0], gravity, 1.0);
+        assert_eq!(body.velocity, Vector2::from([1.0, 0.0]));
+        assert_eq!(body.angular_velocity, 6.0);
+
+        body.apply_impulse_at(Vector2::from([0.0, 1.0]), Vector2::from([1.0, 0.0]));
+        assert_eq!(body.velocity, Vector2::from([1.0, 1.0]));
+        assert_eq!(body.angular_velocity, 12.0);
+        assert_eq!(RigidBody::fixed().inverse_mass(), 0.0);
+    }
+}
@@ -0,0 +1,215 @@
+//! `physicscore::characters` submodule implements [`PlatformerController`] component, which
+//! controls jumping of a platformer character and makes it forgiving to the player.
+//!
+
+use crate::{
+    gamecore::components::Component,
+    mathcore::vectors::Vector2,
+    physicscore::world::{BodyId, PhysicsWorld},
+};
+use serde::{Deserialize, Serialize};
+
+/// [`PlatformerController`] struct is a component that decides when platformer character can jump.
+///
+/// Controller implements two common helpers:
+/// - coyote time - character can still jump for a short time after it walked off a ledge;
+/// - jump buffer - jump that was pressed shortly before landing is performed on landing.
+///
+/// Controller can be used on its own (see [`PlatformerController::update`] and [`PlatformerController::try_jump`])
+/// or together with [`PhysicsWorld`] (see [`PlatformerController::control`]).
+///
+/// # Example
+/// ```rust
+/// # use ggengine::physicscore::characters::PlatformerController;
+/// let mut controller: PlatformerController = PlatformerController::new(10.0);
+/// controller.update(0.05, true);
+/// // player walks off the ledge and presses jump a bit too late
+/// controller.update(0.05, false);
+/// controller.press_jump();
+/// assert!(controller.try_jump());
+/// assert!(!controller.try_jump());
+///
+/// // player presses jump right before landing
+/// controller.update(0.5, false);
+/// controller.press_jump();
+/// controller.update(0.05, false);
+/// assert!(!controller.try_jump());
+/// controller.update(0.05, true);
+/// assert!(controller.try_jump());
+/// ```
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq)]
+pub struct PlatformerController {
+    /// Speed with which character jumps (in units per second).
+    ///
+    pub jump_speed: f32,
+    /// Time (in seconds) after leaving the ground during which character can still jump.
+    ///
+    pub coyote_time: f32,
+    /// Time (in seconds) during which pressed jump is remembered.
+    ///
+    pub jump_buffer: f32,
+
+    /// Time since character stood on the ground.
+    ///
+    since_grounded: f32,
+    /// Time since jump was pressed.
+    ///
+    since_jump_pressed: f32,
+    /// Time since character jumped.
+    ///
+    since_jumped: f32,
+}
+impl PlatformerController {
+    /// Constructs controller with given jump speed, 0.1 seconds of coyote time and 0.1 seconds of jump buffer.
+    ///
+    pub fn new(jump_speed: f32) -> Self {
+        PlatformerController {
+            jump_speed,
+            coyote_time: 0.1,
+            jump_buffer: 0.1,
+
+            since_grounded: f32::INFINITY,
+            since_jump_pressed: f32::INFINITY,
+            since_jumped: f32::INFINITY,
+        }
+    }
+
+    /// Advances timers of controller by `dt` seconds.
+    ///
+    /// `grounded` tells whether character stands on the ground now.
+    /// Ground is ignored for [`PlatformerController::coyote_time`] after the jump,
+    /// so ground that was left during the jump would not grant another jump.
+    ///
+    pub fn update(&mut self, dt: f32, grounded: bool) {
+        self.since_grounded += dt;
+        self.since_jump_pressed += dt;
+        self.since_jumped += dt;
+        if grounded && self.since_jumped > self.coyote_time {
+            self.since_grounded = 0.0;
+        }
+    }
+    /// Remembers that jump was pressed.
+    ///
+    pub fn press_jump(&mut self) {
+        self.since_jump_pressed = 0.0;
+    }
+
+    /// Returns whether character stood on the ground no longer than [`PlatformerController::coyote_time`] ago.
+    ///
+    pub fn can_jump(&self) -> bool {
+        self.since_grounded <= self.coyote_time
+    }
+    /// Returns whether jump was pressed no longer than [`PlatformerController::jump_buffer`] ago.
+    ///
+    pub fn is_jump_buffered(&self) -> bool {
+        self.since_jump_pressed <= self.jump_buffer
+    }
+    /// Returns whether character should jump now.
+    ///
+    /// If it should, pressed jump and coyote time are consumed, so the jump is performed only once.
+    ///
+    pub fn try_jump(&mut self) -> bool {
+        if !(self.can_jump() && self.is_jump_buffered()) {
+            return false;
+        }
+        self.since_grounded = f32::INFINITY;
+        self.since_jump_pressed = f32::INFINITY;
+        self.since_jumped = 0.0;
+        true
+    }
+
+    /// Updates controller by state of given body in the world and makes it jump if it should.
+    ///
+    /// Velocity of a body along [`PhysicsWorld::up_direction`] is set to the sum of
+    /// [`PlatformerController::jump_speed`] and velocity of its ground, so characters can jump
+    /// from moving platforms. Returns whether body has jumped.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::physicscore::characters::PlatformerController;
+    /// # use ggengine::physicscore::world::{BodyId, PhysicsWorld};
+    /// # use ggengine::physicscore::bodies::RigidBody;
+    /// # use ggengine::physicscore::colliders::Collider;
+    /// # use ggengine::mathcore::{transforms::Transform2D, vectors::Vector2};
+    /// let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::from([0.0, 10.0]));
+    /// let ground: BodyId = world.add_body(RigidBody::fixed(), Transform2D::identity());
+    /// let _ = world.attach_collider(ground, Collider::rect(10.0, 1.0));
+    /// let player: BodyId = world.add_body(
+    ///     RigidBody::dynamic(1.0),
+    ///     Transform2D::from_translation(Vector2::from([0.0, -0.9])),
+    /// );
+    /// let _ = world.attach_collider(player, Collider::rect(1.0, 1.0));
+    /// world.step(0.0);
+    ///
+    /// let mut controller: PlatformerController = PlatformerController::new(5.0);
+    /// controller.press_jump();
+    /// assert!(controller.control(&mut world, player, 0.0));
+    /// assert_eq!(world.body(player).expect("Body exists").velocity, Vector2::from([0.0, -5.0]));
+    /// ```
+    ///
+    pub fn control(&mut self, world: &mut PhysicsWorld, id: BodyId, dt: f32) -> bool {
+        let ground: Option<BodyId> = world.ground(id).map(|(ground, _)| ground);
+        self.update(dt, ground.is_some());
+        if !self.try_jump() {
+            return false;
+        }
+
+        let ground_velocity: Vector2 = ground
+            .and_then(|ground| world.body(ground))
+            .map_or(Vector2::zero(), |body| body.velocity);
+        let (Some(up), Some(body)) = (world.up_direction(), world.body_mut(id)) else {
+            return false;
+        };
+        body.velocity += up * (ground_velocity * up + self.jump_speed - body.velocity * up);
+        true
+    }
+}
+impl Component for PlatformerController {}
+
+#[cfg(test)]
+mod tests {
+    use super::PlatformerController;
+    use crate::{
+        mathcore::{transforms::Transform2D, vectors::Vector2},
+        physicscore::{
+            bodies::RigidBody,
+            colliders::Collider,
+            world::{BodyId, PhysicsWorld},
+        },
+    };
+
+    #[test]
+    fn jumping() {
+        let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::from([0.0, 10.0]));
+        let ground: BodyId = world.add_body(RigidBody::fixed(), Transform2D::identity());
+        let _ = world.attach_collider(ground, Collider::rect(100.0, 1.0));
+        let player: BodyId = world.add_body(
+            RigidBody::dynamic(1.0),
+            Transform2D::from_translation(Vector2::from([0.0, -2.0])),
+        );
+        let _ = world.attach_collider(player, Collider::rect(1.0, 1.0));
+
+        let mut controller: PlatformerController = PlatformerController::new(5.0);
+        let dt: f32 = 1.0 / 60.0;
+        let mut jumps: u32 = 0;
+        for frame in 0..120 {
+            // jump is held down, but it is performed only on landing and only once per landing
+            if frame % 5 == 0 {
+                controller.press_jump();
+            }
+            if controller.control(&mut world, player, dt) {
+                jumps += 1;
+                assert!(world.body(player).expect("Body exists").velocity.y < 0.0);
+            }
+            world.step(dt);
+        }
+        // landing, jump of one second, landing again, the second jump
+        assert_eq!(jumps, 2);
+        assert!(!world.is_grounded(player));
+
+        world.gravity = Vector2::zero();
+        controller.press_jump();
+        assert!(!controller.control(&mut world, player, dt));
+    }
+}
@@ -0,0 +1,244 @@
+//! `physicscore::colliders` submodule implements [`Collider`] component, which describes
+//! collision shape of an object and how it interacts with other colliders.
+//!
+
+use crate::{
+    gamecore::components::Component,
+    mathcore::{
+        shapes::{Aabb, ConvexPolygon, PolygonLike},
+        transforms::Transform2D,
+        vectors::{Point, Vector2},
+    },
+    physicscore::materials::PhysicsMaterial,
+};
+use serde::{Deserialize, Serialize};
+
+/// [`ColliderShape`] enum lists shapes that can be used by colliders.
+///
+/// All shapes are described in local space of a body - they are centered at the origin of a body
+/// (up to the offset of a collider) and are transformed by [`Transform2D`] of a body.
+///
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum ColliderShape {
+    /// Circle with given radius.
+    ///
+    Circle {
+        /// Radius of a circle.
+        ///
+        radius: f32,
+    },
+    /// Axis-aligned (in local space) rectangle with given width and height.
+    ///
+    Rect {
+        /// Width of a rectangle.
+        ///
+        width: f32,
+        /// Height of a rectangle.
+        ///
+        height: f32,
+    },
+    /// Capsule, which core segment lies on local x axis.
+    ///
+    Capsule {
+        /// Half of a length of a core segment.
+        ///
+        half_length: f32,
+        /// Radius of a capsule.
+        ///
+        radius: f32,
+    },
+    /// Convex polygon, which vertices are given in local space.
+    ///
+    Polygon(ConvexPolygon),
+}
+
+/// [`Collider`] struct is a component that describes collision shape of an object.
+///
+/// Colliders interact only if layers of each collider intersect with mask of the other one
+/// (see [`Collider::interacts_with`]).
+///
+/// # Example
+/// ```rust
+/// # use ggengine::physicscore::colliders::Collider;
+/// # use ggengine::mathcore::transforms::Transform2D;
+/// # use ggengine::mathcore::vectors::{Point, Vector2};
+/// # use ggengine::mathcore::shapes::Aabb;
+/// let mut collider: Collider = Collider::rect(2.0, 4.0);
+/// collider.offset = Vector2::from([1.0, 0.0]);
+///
+/// let transform: Transform2D = Transform2D::from_translation(Vector2::from([10.0, 10.0]));
+/// assert_eq!(
+///     collider.aabb(&transform),
+///     Aabb::new(Point::from([10.0, 8.0]), Point::from([12.0, 12.0]))
+/// );
+///
+/// let mut other: Collider = Collider::circle(1.0);
+/// other.layers = 0b10;
+/// assert!(collider.interacts_with(&other));
+/// collider.mask = 0b01;
+/// assert!(!collider.interacts_with(&other));
+/// ```
+///
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Collider {
+    /// Shape of a collider.
+    ///
+    pub shape: ColliderShape,
+    /// Offset of a collider relative to the origin of a body (in local space of a body).
+    ///
+    pub offset: Vector2,
+
+    /// Bitmask of layers to which collider belongs.
+    ///
+    pub layers: u32,
+    /// Bitmask of layers with which collider can interact.
+    ///
+    pub mask: u32,
+
+    /// Material of a collider's surface.
+    ///
+    pub material: PhysicsMaterial,
+
+    /// Whether collider is a sensor.
+    ///
+    /// Sensors detect overlapping with other colliders, but do not produce physical response.
+    ///
+    pub is_sensor: bool,
+    /// Direction in which one-way collider is solid (see [`Collider::blocks`]).
+    ///
+    /// One-way colliders block only bodies that are on the side of this direction and that do not move
+    /// further along it - for example, platform with direction of `(0.0, -1.0)` can be passed from below
+    /// (y axis is directed down) and can be stood upon.
+    ///
+    #[serde(default)]
+    pub one_way: Option<Vector2>,
+}
+impl Collider {
+    /// Constructs collider with given shape.
+    ///
+    /// Collider belongs to the first layer, interacts with all layers, has default material,
+    /// is not a sensor and is not one-way.
+    ///
+    pub fn new(shape: ColliderShape) -> Self {
+        Collider {
+            shape,
+            offset: Vector2::zero(),
+
+            layers: 1,
+            mask: u32::MAX,
+
+            material: PhysicsMaterial::default(),
+
+            is_sensor: false,
+            one_way: None,
+        }
+    }
+    /// Constructs circle collider.
+    ///
+    pub fn circle(radius: f32) -> Self {
+        Collider::new(ColliderShape::Circle { radius })
+    }
+    /// Constructs rectangle collider.
+    ///
+    pub fn rect(width: f32, height: f32) -> Self {
+        Collider::new(ColliderShape::Rect { width, height })
+    }
+    /// Constructs capsule collider.
+    ///
+    pub fn capsule(half_length: f32, radius: f32) -> Self {
+        Collider::new(ColliderShape::Capsule {
+            half_length,
+            radius,
+        })
+    }
+    /// Constructs convex polygon collider.
+    ///
+    pub fn polygon(polygon: ConvexPolygon) -> Self {
+        Collider::new(ColliderShape::Polygon(polygon))
+    }
+
+    /// Returns whether two colliders can interact according to their layers and masks.
+    ///
+    pub fn interacts_with(&self, other: &Collider) -> bool {
+        self.layers & other.mask != 0 && other.layers & self.mask != 0
+    }
+    /// Returns whether collider blocks body that touches it.
+    ///
+    /// `normal` is a normal of a contact which is directed from this collider to the body,
+    /// and `relative_velocity` is a velocity of the body relative to the collider.
+    /// Colliders that are not one-way block every body.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::physicscore::colliders::Collider;
+    /// # use ggengine::mathcore::vectors::Vector2;
+    /// let mut platform: Collider = Collider::rect(10.0, 1.0);
+    /// let (up, down): (Vector2, Vector2) = (Vector2::from([0.0, -1.0]), Vector2::from([0.0, 1.0]));
+    /// assert!(platform.blocks(down, Vector2::zero()));
+    ///
+    /// platform.one_way = Some(up);
+    /// // body is on top of the platform and falls or stands on it
+    /// assert!(platform.blocks(up, down));
+    /// assert!(platform.blocks(up, Vector2::zero()));
+    /// // body jumps through the platform from below
+    /// assert!(!platform.blocks(down, up));
+    /// assert!(!platform.blocks(up, up));
+    /// ```
+    ///
+    pub fn blocks(&self, normal: Vector2, relative_velocity: Vector2) -> bool {
+        self.one_way.is_none_or(|direction| {
+            normal * direction > 0.0 && relative_velocity * direction <= 0.0
+        })
+    }
+
+    /// Returns core and radius of a collider in world space.
+    ///
+    /// Every supported shape is a core (point, segment or convex polygon) that is rounded by radius
+    /// (radius of polygons is zero). Radius is scaled by the largest of two scaling factors of a transform.
+    ///
+    pub(crate) fn world_core(&self, transform: &Transform2D) -> (Vec<Point>, f32) {
+        let (core, radius): (Vec<Point>, f32) = match &self.shape {
+            ColliderShape::Circle { radius } => (vec![Point::zero()], *radius),
+            ColliderShape::Rect { width, height } => {
+                let (x, y): (f32, f32) = (width / 2.0, height / 2.0);
+                (
+                    vec![
+                        Point::from([-x, -y]),
+                        Point::from([x, -y]),
+                        Point::from([x, y]),
+                        Point::from([-x, y]),
+                    ],
+                    0.0,
+                )
+            }
+            ColliderShape::Capsule {
+                half_length,
+                radius,
+            } => (
+                vec![
+                    Point::from([-half_length, 0.0]),
+                    Point::from([*half_length, 0.0]),
+                ],
+                *radius,
+            ),
+            ColliderShape::Polygon(polygon) => (polygon.vertices().to_vec(), 0.0),
+        };
+
+        let scale: f32 = transform.scale.0.get().max(transform.scale.1.get());
+        (
+            core.into_iter()
+                .map(|point| transform.transform_point(point + self.offset))
+                .collect(),
+            radius.max(0.0) * scale,
+        )
+    }
+    /// Returns axis-aligned bounding box of a collider in world space.
+    ///
+    pub fn aabb(&self, transform: &Transform2D) -> Aabb {
+        let (core, radius): (Vec<Point>, f32) = self.world_core(transform);
+        Aabb::from_points(&core)
+            .expect("Collider core has points")
+            .expand(radius)
+    }
+}
+impl Component for Collider {}
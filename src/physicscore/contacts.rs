@@ -0,0 +1,523 @@
+//! `physicscore::contacts` submodule implements collision pipeline of physics engine:
+//! broadphase ([`SpatialGrid`]), which quickly finds pairs of colliders that can collide,
+//! and narrowphase ([`Contact::between`]), which finds exact [`Contact`] of two colliders.
+//!
+
+use crate::{
+    mathcore::{
+        floats::equal,
+        shapes::{Aabb, Segment},
+        transforms::Transform2D,
+        vectors::{Point, Vector2},
+    },
+    physicscore::{colliders::Collider, world::BodyId},
+};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt,
+    sync::Arc,
+};
+
+/// [`SpatialGrid`] struct is a broadphase that splits space into square cells of equal size.
+///
+/// Every body is registered in all cells that its bounding box overlaps, so only bodies
+/// that share a cell need to be checked for collision.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::physicscore::contacts::SpatialGrid;
+/// # use ggengine::physicscore::world::{BodyId, PhysicsWorld};
+/// # use ggengine::physicscore::bodies::RigidBody;
+/// # use ggengine::mathcore::{shapes::Aabb, transforms::Transform2D, vectors::{Point, Vector2}};
+/// let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::zero());
+/// let (a, b, c): (BodyId, BodyId, BodyId) = (
+///     world.add_body(RigidBody::default(), Transform2D::identity()),
+///     world.add_body(RigidBody::default(), Transform2D::identity()),
+///     world.add_body(RigidBody::default(), Transform2D::identity()),
+/// );
+///
+/// let mut grid: SpatialGrid = SpatialGrid::new(10.0);
+/// grid.insert(a, Aabb::new(Point::from([0.0, 0.0]), Point::from([5.0, 5.0])));
+/// grid.insert(b, Aabb::new(Point::from([4.0, 4.0]), Point::from([12.0, 12.0])));
+/// grid.insert(c, Aabb::new(Point::from([30.0, 30.0]), Point::from([35.0, 35.0])));
+/// assert_eq!(grid.pairs(), vec![(a, b)]);
+/// assert_eq!(grid.query(Aabb::new(Point::from([11.0, 11.0]), Point::from([40.0, 40.0]))), vec![b, c]);
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct SpatialGrid {
+    /// Length of a side of a cell.
+    ///
+    cell_size: f32,
+    /// Bodies that are registered in each cell.
+    ///
+    cells: HashMap<(i32, i32), Vec<BodyId>>,
+    /// Bounding boxes of registered bodies.
+    ///
+    boxes: BTreeMap<BodyId, Aabb>,
+}
+impl SpatialGrid {
+    /// Default length of a side of a cell.
+    ///
+    pub const DEFAULT_CELL_SIZE: f32 = 64.0;
+
+    /// Constructs empty grid with given size of cells.
+    ///
+    /// Non-positive or non-finite size is replaced with [`SpatialGrid::DEFAULT_CELL_SIZE`].
+    ///
+    pub fn new(cell_size: f32) -> Self {
+        SpatialGrid {
+            cell_size: if cell_size.is_finite() && cell_size > 0.0 {
+                cell_size
+            } else {
+                SpatialGrid::DEFAULT_CELL_SIZE
+            },
+            cells: HashMap::new(),
+            boxes: BTreeMap::new(),
+        }
+    }
+
+    /// Returns length of a side of a cell.
+    ///
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+    /// Returns range of cells (inclusive) that are overlapped by given box.
+    ///
+    fn cells_range(&self, aabb: Aabb) -> ((i32, i32), (i32, i32)) {
+        let cell = |point: Point| -> (i32, i32) {
+            (
+                (point.x / self.cell_size).floor() as i32,
+                (point.y / self.cell_size).floor() as i32,
+            )
+        };
+        (cell(aabb.min), cell(aabb.max))
+    }
+
+    /// Removes all bodies from the grid.
+    ///
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.boxes.clear();
+    }
+    /// Registers body with given bounding box in the grid.
+    ///
+    /// Boxes with non-finite coordinates are ignored.
+    ///
+    pub fn insert(&mut self, id: BodyId, aabb: Aabb) {
+        if !(aabb.min.x.is_finite()
+            && aabb.min.y.is_finite()
+            && aabb.max.x.is_finite()
+            && aabb.max.y.is_finite())
+        {
+            return;
+        }
+
+        let ((min_x, min_y), (max_x, max_y)): ((i32, i32), (i32, i32)) = self.cells_range(aabb);
+        for x in min_x..=max_x {
+            for y in min_y..=max_y {
+                self.cells.entry((x, y)).or_default().push(id);
+            }
+        }
+        let _ = self.boxes.insert(id, aabb);
+    }
+
+    /// Returns sorted ids of bodies which bounding boxes intersect given box.
+    ///
+    pub fn query(&self, aabb: Aabb) -> Vec<BodyId> {
+        let ((min_x, min_y), (max_x, max_y)): ((i32, i32), (i32, i32)) = self.cells_range(aabb);
+        let mut found: BTreeSet<BodyId> = BTreeSet::new();
+        for x in min_x..=max_x {
+            for y in min_y..=max_y {
+                for id in self.cells.get(&(x, y)).into_iter().flatten() {
+                    if self.boxes[id].intersects(aabb) {
+                        let _ = found.insert(*id);
+                    }
+                }
+            }
+        }
+        found.into_iter().collect()
+    }
+    /// Returns sorted pairs of bodies which bounding boxes intersect.
+    ///
+    /// In each pair the first id is less than the second one.
+    ///
+    pub fn pairs(&self) -> Vec<(BodyId, BodyId)> {
+        let mut pairs: BTreeSet<(BodyId, BodyId)> = BTreeSet::new();
+        for ids in self.cells.values() {
+            for (i, a) in ids.iter().enumerate() {
+                for b in &ids[i + 1..] {
+                    if self.boxes[a].intersects(self.boxes[b]) {
+                        let _ = pairs.insert((*a.min(b), *a.max(b)));
+                    }
+                }
+            }
+        }
+        pairs.into_iter().collect()
+    }
+}
+impl Default for SpatialGrid {
+    /// Returns empty grid with cells of [`SpatialGrid::DEFAULT_CELL_SIZE`] size.
+    ///
+    fn default() -> Self {
+        SpatialGrid::new(SpatialGrid::DEFAULT_CELL_SIZE)
+    }
+}
+
+/// [`Contact`] struct describes contact of two colliding bodies.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Contact {
+    /// Ids of bodies that are in contact.
+    ///
+    pub bodies: (BodyId, BodyId),
+    /// Unit normal of a contact which is directed from the first body to the second one.
+    ///
+    pub normal: Vector2,
+    /// Penetration depth (zero for touching bodies).
+    ///
+    pub depth: f32,
+    /// Point of a contact in world space.
+    ///
+    pub point: Point,
+
+    /// Friction of a contact (see [`PhysicsMaterial::combine_friction`](crate::physicscore::materials::PhysicsMaterial::combine_friction)).
+    ///
+    pub friction: f32,
+    /// Restitution of a contact (see [`PhysicsMaterial::combine_restitution`](crate::physicscore::materials::PhysicsMaterial::combine_restitution)).
+    ///
+    pub restitution: f32,
+}
+impl Contact {
+    /// Finds contact of two colliders or returns `None` if they do not collide.
+    ///
+    /// This is a narrowphase of collision pipeline which uses separating axis theorem.
+    /// Every collider is treated as convex core that is rounded by some radius - axes are formed
+    /// by normals of edges of both cores and by the direction between closest points of cores.
+    /// Layers and masks of colliders are not checked.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::physicscore::contacts::Contact;
+    /// # use ggengine::physicscore::colliders::Collider;
+    /// # use ggengine::physicscore::world::{BodyId, PhysicsWorld};
+    /// # use ggengine::physicscore::bodies::RigidBody;
+    /// # use ggengine::mathcore::{transforms::Transform2D, vectors::{Point, Vector2}};
+    /// # use ggengine::mathcore::floats::FloatOperations;
+    /// let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::zero());
+    /// let (a, b): (BodyId, BodyId) = (
+    ///     world.add_body(RigidBody::default(), Transform2D::identity()),
+    ///     world.add_body(RigidBody::fixed(), Transform2D::identity()),
+    /// );
+    ///
+    /// let ball: Transform2D = Transform2D::from_translation(Vector2::from([0.0, 1.5]));
+    /// let contact: Contact = Contact::between(
+    ///     (a, &Collider::circle(1.0), &ball),
+    ///     (b, &Collider::rect(10.0, 2.0), &Transform2D::identity()),
+    /// ).expect("Ball lies on the box");
+    /// assert_eq!(contact.normal, Vector2::from([0.0, -1.0]));
+    /// assert_eq!(contact.depth, 0.5);
+    /// assert_eq!(contact.point.round_up_to(3), Point::from([0.0, 0.75]));
+    /// ```
+    ///
+    pub fn between(
+        first: (BodyId, &Collider, &Transform2D),
+        second: (BodyId, &Collider, &Transform2D),
+    ) -> Option<Contact> {
+        let (core1, radius1): (Vec<Point>, f32) = first.1.world_core(first.2);
+        let (core2, radius2): (Vec<Point>, f32) = second.1.world_core(second.2);
+        let (normal, depth, point): (Vector2, f32, Point) =
+            overlap_cores((&core1, radius1), (&core2, radius2))?;
+        Some(Contact {
+            bodies: (first.0, second.0),
+            normal,
+            depth,
+            point,
+
+            friction: first.1.material.combine_friction(&second.1.material),
+            restitution: first.1.material.combine_restitution(&second.1.material),
+        })
+    }
+}
+
+/// [`ContactFilter`] struct wraps user callback that is called by narrowphase for every found contact.
+///
+/// Callback receives contact and colliders of both bodies (in order of `contact.bodies`);
+/// it can modify contact (e.g. change its friction) or veto it by returning `false`.
+/// Vetoed contacts are neither resolved nor reported by events.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::physicscore::contacts::{Contact, ContactFilter};
+/// # use ggengine::physicscore::colliders::Collider;
+/// # use ggengine::physicscore::world::{BodyId, PhysicsWorld};
+/// # use ggengine::physicscore::bodies::RigidBody;
+/// # use ggengine::mathcore::{transforms::Transform2D, vectors::Vector2};
+/// // bodies of the same team pass through each other
+/// let filter: ContactFilter = ContactFilter::new(|_: &mut Contact, collider1: &Collider, collider2: &Collider| {
+///     collider1.layers != collider2.layers
+/// });
+///
+/// let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::zero());
+/// let (a, b): (BodyId, BodyId) = (
+///     world.add_body(RigidBody::default(), Transform2D::identity()),
+///     world.add_body(RigidBody::default(), Transform2D::identity()),
+/// );
+/// let mut contact: Contact = Contact::between(
+///     (a, &Collider::circle(1.0), &Transform2D::identity()),
+///     (b, &Collider::circle(1.0), &Transform2D::identity()),
+/// ).expect("Circles overlap");
+/// assert!(!filter.apply(&mut contact, &Collider::circle(1.0), &Collider::circle(1.0)));
+/// ```
+///
+#[derive(Clone)]
+pub struct ContactFilter(Arc<ContactCallback>);
+impl ContactFilter {
+    /// Wraps given callback.
+    ///
+    pub fn new(
+        callback: impl Fn(&mut Contact, &Collider, &Collider) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        ContactFilter(Arc::new(callback))
+    }
+
+    /// Calls callback, returning whether contact should be kept.
+    ///
+    pub fn apply(&self, contact: &mut Contact, collider1: &Collider, collider2: &Collider) -> bool {
+        (self.0)(contact, collider1, collider2)
+    }
+}
+/// Type of callback that is wrapped by [`ContactFilter`].
+///
+type ContactCallback = dyn Fn(&mut Contact, &Collider, &Collider) -> bool + Send + Sync;
+impl fmt::Debug for ContactFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ContactFilter").finish_non_exhaustive()
+    }
+}
+
+/// Finds overlap of two rounded cores by using separating axis theorem.
+///
+/// Returns unit normal (directed from the first core to the second one), penetration depth
+/// and point of a contact or `None` if cores do not overlap.
+///
+pub(crate) fn overlap_cores(
+    (core1, radius1): (&[Point], f32),
+    (core2, radius2): (&[Point], f32),
+) -> Option<(Vector2, f32, Point)> {
+    let mut axes: Vec<Vector2> = core_axes(core1);
+    axes.extend(core_axes(core2));
+    if radius1 > 0.0 || radius2 > 0.0 {
+        let (point1, point2): (Point, Point) = closest_core_points(core1, core2);
+        axes.extend((point2 - point1).try_normalize());
+    }
+
+    let (mut normal, mut depth): (Vector2, f32) = (Vector2::from([1.0, 0.0]), radius1 + radius2);
+    if !axes.is_empty() {
+        depth = f32::INFINITY;
+    }
+    for axis in axes {
+        let (min1, max1): (f32, f32) = project(core1, radius1, axis);
+        let (min2, max2): (f32, f32) = project(core2, radius2, axis);
+        if !(max2 >= min1 && max1 >= min2) {
+            return None;
+        }
+
+        // Normal is directed from the first collider to the second one.
+        let (forward, backward): (f32, f32) = (max1 - min2, max2 - min1);
+        if forward.min(backward) < depth {
+            (normal, depth) = if forward <= backward {
+                (axis, forward)
+            } else {
+                (-axis, backward)
+            };
+        }
+    }
+
+    let point1: Point = support(core1, normal) + normal * radius1;
+    let point2: Point = support(core2, -normal) - normal * radius2;
+    Some((normal, depth.max(0.0), (point1 + point2) / 2.0))
+}
+/// Returns segments that form a core (degenerate segment for point core).
+///
+pub(crate) fn core_segments(core: &[Point]) -> Vec<Segment> {
+    match core.len() {
+        0 => Vec::new(),
+        1 => vec![Segment::from([core[0], core[0]])],
+        2 => vec![Segment::from([core[0], core[1]])],
+        n => (0..n)
+            .map(|i| Segment::from([core[i], core[(i + 1) % n]]))
+            .collect(),
+    }
+}
+/// Returns unit normals of edges of a core.
+///
+fn core_axes(core: &[Point]) -> Vec<Vector2> {
+    if core.len() < 2 {
+        return Vec::new();
+    }
+    core_segments(core)
+        .into_iter()
+        .filter_map(|segment| segment.slope().perpendicular().try_normalize())
+        .collect()
+}
+/// Returns pair of closest points of two cores (boundaries of polygon cores are considered).
+///
+fn closest_core_points(core1: &[Point], core2: &[Point]) -> (Point, Point) {
+    let mut closest: (Point, Point, f32) = (core1[0], core2[0], f32::INFINITY);
+    for segment1 in core_segments(core1) {
+        for segment2 in core_segments(core2) {
+            let candidates: [(Point, Point); 4] = [
+                (
+                    segment1.points[0],
+                    segment2.closest_point(segment1.points[0]),
+                ),
+                (
+                    segment1.points[1],
+                    segment2.closest_point(segment1.points[1]),
+                ),
+                (
+                    segment1.closest_point(segment2.points[0]),
+                    segment2.points[0],
+                ),
+                (
+                    segment1.closest_point(segment2.points[1]),
+                    segment2.points[1],
+                ),
+            ];
+            for (point1, point2) in candidates {
+                let distance: f32 = (point2 - point1).sqr_magnitude();
+                if distance < closest.2 {
+                    closest = (point1, point2, distance);
+                }
+            }
+        }
+    }
+    (closest.0, closest.1)
+}
+/// Returns projection of a rounded core onto the axis.
+///
+fn project(core: &[Point], radius: f32, axis: Vector2) -> (f32, f32) {
+    let (min, max): (f32, f32) = core.iter().map(|point| *point * axis).fold(
+        (f32::INFINITY, f32::NEG_INFINITY),
+        |(min, max), projection| (min.min(projection), max.max(projection)),
+    );
+    (min - radius, max + radius)
+}
+/// Returns point of a core that is the furthest along given direction.
+///
+/// If several points are equally far, their mean is returned.
+///
+fn support(core: &[Point], direction: Vector2) -> Point {
+    let max: f32 = core
+        .iter()
+        .map(|point| *point * direction)
+        .fold(f32::NEG_INFINITY, f32::max);
+    let furthest: Vec<Point> = core
+        .iter()
+        .copied()
+        .filter(|point| equal(*point * direction, max))
+        .collect();
+    centroid(&furthest)
+}
+/// Returns mean of core points.
+///
+fn centroid(core: &[Point]) -> Point {
+    core.iter().fold(Point::zero(), |acc, point| acc + *point) / core.len() as f32
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn narrowphase() {
+        use super::Contact;
+        use crate::{
+            mathcore::{
+                floats::FloatOperations,
+                transforms::Transform2D,
+                vectors::{Point, Vector2},
+                Angle,
+            },
+            physicscore::{
+                bodies::RigidBody,
+                colliders::Collider,
+                world::{BodyId, PhysicsWorld},
+            },
+        };
+
+        let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::zero());
+        let (a, b): (BodyId, BodyId) = (
+            world.add_body(RigidBody::default(), Transform2D::identity()),
+            world.add_body(RigidBody::default(), Transform2D::identity()),
+        );
+
+        // circles
+        let contact: Contact = Contact::between(
+            (a, &Collider::circle(1.0), &Transform2D::identity()),
+            (
+                b,
+                &Collider::circle(2.0),
+                &Transform2D::from_translation(Vector2::from([2.0, 0.0])),
+            ),
+        )
+        .expect("Circles overlap");
+        assert_eq!(contact.normal.round_up_to(3), Vector2::from([1.0, 0.0]));
+        assert_eq!(contact.depth.round_up_to(3), 1.0);
+        assert_eq!(contact.point.round_up_to(3), Point::from([0.5, 0.0]));
+        assert!(Contact::between(
+            (a, &Collider::circle(1.0), &Transform2D::identity()),
+            (
+                b,
+                &Collider::circle(1.0),
+                &Transform2D::from_translation(Vector2::from([3.0, 0.0])),
+            ),
+        )
+        .is_none());
+
+        // rotated boxes
+        let rotated: Transform2D = Transform2D::new(
+            Vector2::from([2.0, 0.0]),
+            Angle::from_degrees(45.0),
+            Transform2D::identity().scale,
+        );
+        assert!(Contact::between(
+            (a, &Collider::rect(2.0, 2.0), &Transform2D::identity()),
+            (b, &Collider::rect(1.0, 1.0), &rotated),
+        )
+        .is_none());
+        let contact: Contact = Contact::between(
+            (a, &Collider::rect(2.0, 2.0), &Transform2D::identity()),
+            (b, &Collider::rect(2.0, 2.0), &rotated),
+        )
+        .expect("Boxes overlap");
+        assert_eq!(contact.normal.round_up_to(3), Vector2::from([1.0, 0.0]));
+        assert_eq!(
+            contact.depth.round_up_to(3),
+            (2.0_f32.sqrt() - 1.0).round_up_to(3)
+        );
+
+        // capsule lying on a box, circle deep inside a box
+        let contact: Contact = Contact::between(
+            (a, &Collider::rect(10.0, 2.0), &Transform2D::identity()),
+            (
+                b,
+                &Collider::capsule(2.0, 0.5),
+                &Transform2D::from_translation(Vector2::from([3.0, 1.25])),
+            ),
+        )
+        .expect("Capsule lies on a box");
+        assert_eq!(contact.normal.round_up_to(3), Vector2::from([0.0, 1.0]));
+        assert_eq!(contact.depth.round_up_to(3), 0.25);
+        let contact: Contact = Contact::between(
+            (
+                a,
+                &Collider::circle(0.5),
+                &Transform2D::from_translation(Vector2::from([0.0, 0.5])),
+            ),
+            (b, &Collider::rect(10.0, 4.0), &Transform2D::identity()),
+        )
+        .expect("Circle is inside a box");
+        assert_eq!(contact.normal.round_up_to(3), Vector2::from([0.0, -1.0]));
+        assert_eq!(contact.depth.round_up_to(3), 2.0);
+    }
+}
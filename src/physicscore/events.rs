@@ -0,0 +1,78 @@
+//! `physicscore::events` submodule implements [`PhysicsEvent`] enum, which describes
+//! changes in contacts between bodies that happen during simulation.
+//!
+
+use crate::physicscore::{contacts::Contact, world::BodyId};
+use std::collections::BTreeMap;
+
+/// [`PhysicsEvent`] enum lists events that are emitted by physics world.
+///
+/// Every event holds contact of a pair of bodies - for events that signal the end of a contact
+/// it is the last contact that was found for that pair.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PhysicsEvent {
+    /// Two solid colliders started touching.
+    ///
+    CollisionStarted(Contact),
+    /// Two solid colliders stopped touching.
+    ///
+    CollisionEnded(Contact),
+    /// Collider started overlapping sensor (or two sensors started overlapping).
+    ///
+    TriggerEntered(Contact),
+    /// Collider stopped overlapping sensor (or two sensors stopped overlapping).
+    ///
+    TriggerExited(Contact),
+}
+impl PhysicsEvent {
+    /// Returns contact that is held by an event.
+    ///
+    pub fn contact(&self) -> &Contact {
+        match self {
+            PhysicsEvent::CollisionStarted(contact)
+            | PhysicsEvent::CollisionEnded(contact)
+            | PhysicsEvent::TriggerEntered(contact)
+            | PhysicsEvent::TriggerExited(contact) => contact,
+        }
+    }
+    /// Returns pair of bodies that an event is about.
+    ///
+    pub fn bodies(&self) -> (BodyId, BodyId) {
+        self.contact().bodies
+    }
+}
+
+/// Compares contacts of the previous and the current step and returns events
+/// for pairs of bodies which started or stopped touching.
+///
+/// `started` and `ended` are constructors of events for new and lost contacts.
+///
+pub(crate) fn contact_events(
+    previous: &[Contact],
+    current: &[Contact],
+    started: fn(Contact) -> PhysicsEvent,
+    ended: fn(Contact) -> PhysicsEvent,
+) -> Vec<PhysicsEvent> {
+    let previous: BTreeMap<(BodyId, BodyId), Contact> = previous
+        .iter()
+        .map(|contact| (contact.bodies, *contact))
+        .collect();
+    let current: BTreeMap<(BodyId, BodyId), Contact> = current
+        .iter()
+        .map(|contact| (contact.bodies, *contact))
+        .collect();
+
+    let mut events: Vec<PhysicsEvent> = Vec::new();
+    for (bodies, contact) in &previous {
+        if !current.contains_key(bodies) {
+            events.push(ended(*contact));
+        }
+    }
+    for (bodies, contact) in &current {
+        if !previous.contains_key(bodies) {
+            events.push(started(*contact));
+        }
+    }
+    events
+}
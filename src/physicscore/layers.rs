@@ -0,0 +1,255 @@
+//! `physicscore::layers` submodule implements [`CollisionLayers`] - named collision layers and
+//! interaction matrix that decides which layers can collide with each other.
+//!
+//! Layers are configured as data: they are serializable, so they can be stored together with
+//! engine settings (see [`EngineSettings`](crate::datacore::settings::EngineSettings)) and edited at runtime.
+//!
+
+use serde::{Deserialize, Serialize};
+
+/// [`CollisionLayers`] struct names collision layers and stores symmetric interaction matrix of them.
+///
+/// Layer with index `i` corresponds to bit `1 << i` of [`Collider::layers`](crate::physicscore::colliders::Collider::layers),
+/// so there can be at most [`CollisionLayers::MAX_LAYERS`] layers.
+/// By default, every layer interacts with every layer.
+///
+/// Matrix is consulted by [`PhysicsWorld`](crate::physicscore::world::PhysicsWorld) before narrowphase
+/// in addition to layers and masks of colliders (see [`Collider::interacts_with`](crate::physicscore::colliders::Collider::interacts_with)).
+///
+/// # Example
+/// ```rust
+/// # use ggengine::physicscore::layers::CollisionLayers;
+/// let mut layers: CollisionLayers = CollisionLayers::new()
+///     .with_layer("world")
+///     .with_layer("players")
+///     .with_layer("bullets");
+/// assert!(layers.set_interaction("bullets", "bullets", false));
+///
+/// let (world, bullets): (u32, u32) = (
+///     layers.bits("world").expect("Layer exists"),
+///     layers.bits("bullets").expect("Layer exists"),
+/// );
+/// assert_eq!(bullets, 0b100);
+/// assert!(!layers.interacts(bullets, bullets));
+/// assert!(layers.interacts(bullets, world));
+/// ```
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(from = "RawCollisionLayers")]
+pub struct CollisionLayers {
+    /// Names of layers; index of a name is an index of a layer.
+    ///
+    names: Vec<String>,
+    /// Interaction matrix - bitmask of layers with which each layer interacts.
+    ///
+    matrix: [u32; CollisionLayers::MAX_LAYERS],
+}
+impl CollisionLayers {
+    /// Maximal number of layers, which is the number of bits in collider layers.
+    ///
+    pub const MAX_LAYERS: usize = 32;
+
+    /// Constructs configuration without named layers in which every layer interacts with every layer.
+    ///
+    pub fn new() -> Self {
+        CollisionLayers {
+            names: Vec::new(),
+            matrix: [u32::MAX; CollisionLayers::MAX_LAYERS],
+        }
+    }
+
+    /// Adds layer with given name and returns the configuration.
+    ///
+    pub fn with_layer(mut self, name: impl Into<String>) -> Self {
+        let _ = self.add_layer(name);
+        self
+    }
+    /// Adds layer with given name and returns its index.
+    ///
+    /// If layer with the same name already exists, its index is returned.
+    /// `None` is returned if all [`CollisionLayers::MAX_LAYERS`] layers are already named.
+    ///
+    pub fn add_layer(&mut self, name: impl Into<String>) -> Option<usize> {
+        let name: String = name.into();
+        if let Some(index) = self.index(&name) {
+            return Some(index);
+        }
+        if self.names.len() == CollisionLayers::MAX_LAYERS {
+            return None;
+        }
+        self.names.push(name);
+        Some(self.names.len() - 1)
+    }
+    /// Renames layer, keeping its index and interactions.
+    ///
+    /// Returns `false` if there is no layer with old name or if new name is already taken.
+    ///
+    pub fn rename_layer(&mut self, name: &str, new_name: impl Into<String>) -> bool {
+        let new_name: String = new_name.into();
+        match (self.index(name), self.index(&new_name)) {
+            (Some(index), None) => {
+                self.names[index] = new_name;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns names of layers in order of their indices.
+    ///
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+    /// Returns index of layer with given name.
+    ///
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|other| other == name)
+    }
+    /// Returns name of layer with given index.
+    ///
+    pub fn name(&self, index: usize) -> Option<&str> {
+        self.names.get(index).map(String::as_str)
+    }
+    /// Returns bit that corresponds to layer with given name, which can be assigned to
+    /// [`Collider::layers`](crate::physicscore::colliders::Collider::layers).
+    ///
+    pub fn bits(&self, name: &str) -> Option<u32> {
+        self.index(name).map(|index| 1 << index)
+    }
+
+    /// Sets whether two layers interact with each other.
+    ///
+    /// Returns `false` if one of layers does not exist.
+    ///
+    pub fn set_interaction(&mut self, layer1: &str, layer2: &str, interacts: bool) -> bool {
+        match (self.index(layer1), self.index(layer2)) {
+            (Some(index1), Some(index2)) => {
+                self.set_interaction_by_index(index1, index2, interacts);
+                true
+            }
+            _ => false,
+        }
+    }
+    /// Sets whether two layers with given indices interact with each other.
+    ///
+    /// Layers do not need to be named.
+    ///
+    /// # Panics
+    /// Panics if one of indices is not less than [`CollisionLayers::MAX_LAYERS`].
+    ///
+    pub fn set_interaction_by_index(&mut self, index1: usize, index2: usize, interacts: bool) {
+        if interacts {
+            self.matrix[index1] |= 1 << index2;
+            self.matrix[index2] |= 1 << index1;
+        } else {
+            self.matrix[index1] &= !(1 << index2);
+            self.matrix[index2] &= !(1 << index1);
+        }
+    }
+    /// Returns bitmask of layers with which layer with given index interacts.
+    ///
+    /// # Panics
+    /// Panics if index is not less than [`CollisionLayers::MAX_LAYERS`].
+    ///
+    pub fn interactions(&self, index: usize) -> u32 {
+        self.matrix[index]
+    }
+
+    /// Returns whether colliders that belong to given layers (bitmasks) can interact.
+    ///
+    /// Colliders interact if any of their layers interact.
+    ///
+    pub fn interacts(&self, layers1: u32, layers2: u32) -> bool {
+        (0..CollisionLayers::MAX_LAYERS)
+            .filter(|index| layers1 & (1 << index) != 0)
+            .any(|index| self.matrix[index] & layers2 != 0)
+    }
+}
+impl Default for CollisionLayers {
+    /// Returns configuration without named layers in which every layer interacts with every layer.
+    ///
+    fn default() -> Self {
+        CollisionLayers::new()
+    }
+}
+
+/// Raw representation of [`CollisionLayers`] which matrix may be asymmetric.
+///
+#[derive(Deserialize)]
+struct RawCollisionLayers {
+    /// Names of layers.
+    ///
+    names: Vec<String>,
+    /// Interaction matrix.
+    ///
+    matrix: [u32; CollisionLayers::MAX_LAYERS],
+}
+impl From<RawCollisionLayers> for CollisionLayers {
+    /// Constructs configuration, keeping only interactions that are set for both layers
+    /// and dropping names beyond [`CollisionLayers::MAX_LAYERS`].
+    ///
+    fn from(raw: RawCollisionLayers) -> Self {
+        let mut names: Vec<String> = raw.names;
+        names.truncate(CollisionLayers::MAX_LAYERS);
+
+        let mut matrix: [u32; CollisionLayers::MAX_LAYERS] = raw.matrix;
+        for (index1, row) in matrix.iter_mut().enumerate() {
+            for (index2, other) in raw.matrix.iter().enumerate() {
+                if other & (1 << index1) == 0 {
+                    *row &= !(1 << index2);
+                }
+            }
+        }
+        CollisionLayers { names, matrix }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CollisionLayers;
+
+    #[test]
+    fn layers() {
+        let mut layers: CollisionLayers = CollisionLayers::default();
+        assert!(layers.interacts(1, 1 << 31));
+        assert!(!layers.interacts(0, u32::MAX));
+
+        for index in 0..CollisionLayers::MAX_LAYERS {
+            assert_eq!(layers.add_layer(format!("layer{index}")), Some(index));
+        }
+        assert_eq!(layers.add_layer("layer3"), Some(3));
+        assert_eq!(layers.add_layer("extra"), None);
+        assert!(layers.rename_layer("layer0", "world"));
+        assert!(!layers.rename_layer("layer1", "world"));
+        assert_eq!(layers.name(0), Some("world"));
+        assert_eq!(layers.bits("layer31"), Some(1 << 31));
+
+        assert!(layers.set_interaction("world", "layer31", false));
+        assert!(!layers.set_interaction("world", "missing", false));
+        assert_eq!(layers.interactions(31), !1);
+        assert!(!layers.interacts(1, 1 << 31));
+        assert!(!layers.interacts(1 << 31, 1));
+        assert!(layers.interacts(1 | 1 << 31, 1 << 31));
+    }
+
+    #[test]
+    fn serialization() {
+        let mut layers: CollisionLayers = CollisionLayers::new()
+            .with_layer("world")
+            .with_layer("bullets");
+        layers.set_interaction_by_index(1, 1, false);
+        let cbor: Vec<u8> = serde_cbor::to_vec(&layers).expect("Layers are serializable");
+        assert_eq!(
+            serde_cbor::from_slice::<CollisionLayers>(&cbor).expect("Layers are valid"),
+            layers
+        );
+
+        // asymmetric interaction is dropped
+        let mut matrix: Vec<u32> = vec![u32::MAX; CollisionLayers::MAX_LAYERS];
+        matrix[0] = !0b10;
+        let json: String = format!(r#"{{"names": ["world"], "matrix": {matrix:?}}}"#);
+        let layers: CollisionLayers = serde_json::from_str(&json).expect("Layers are valid");
+        assert_eq!(layers.interactions(1), !0b1);
+        assert!(!layers.interacts(0b1, 0b10));
+    }
+}
@@ -0,0 +1,120 @@
+//! `physicscore::materials` submodule implements [`PhysicsMaterial`] - description of surface properties
+//! of colliders, and rules by which properties of two materials are combined.
+//!
+//! Materials are serializable, so they can be stored as assets and shared by several colliders.
+//!
+
+use serde::{Deserialize, Serialize};
+
+/// [`CombineRule`] enum lists rules by which two coefficients of materials in contact are combined.
+///
+/// If two materials have different rules, the rule that comes later in this enum is used
+/// (e.g. [`CombineRule::Maximum`] overrides all other rules).
+///
+#[derive(
+    Serialize, Deserialize, Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum CombineRule {
+    /// Arithmetic mean of coefficients.
+    ///
+    Average,
+    /// Geometric mean of coefficients.
+    ///
+    #[default]
+    GeometricMean,
+    /// The smallest of coefficients.
+    ///
+    Minimum,
+    /// Product of coefficients.
+    ///
+    Multiply,
+    /// The largest of coefficients.
+    ///
+    Maximum,
+}
+impl CombineRule {
+    /// Combines two coefficients by this rule.
+    ///
+    pub fn combine(self, a: f32, b: f32) -> f32 {
+        match self {
+            CombineRule::Average => (a + b) / 2.0,
+            CombineRule::GeometricMean => (a.max(0.0) * b.max(0.0)).sqrt(),
+            CombineRule::Minimum => a.min(b),
+            CombineRule::Multiply => a * b,
+            CombineRule::Maximum => a.max(b),
+        }
+    }
+}
+
+/// [`PhysicsMaterial`] struct describes surface properties of a collider.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::physicscore::materials::{CombineRule, PhysicsMaterial};
+/// let ice: PhysicsMaterial = PhysicsMaterial {
+///     friction_combine: CombineRule::Minimum,
+///     ..PhysicsMaterial::new(0.0, 0.0)
+/// };
+/// let rubber: PhysicsMaterial = PhysicsMaterial::new(1.0, 0.75);
+///
+/// assert_eq!(ice.combine_friction(&rubber), 0.0);
+/// assert_eq!(ice.combine_restitution(&rubber), 0.75);
+/// ```
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq)]
+pub struct PhysicsMaterial {
+    /// Friction coefficient of a surface.
+    ///
+    pub friction: f32,
+    /// Restitution (bounciness) coefficient of a surface.
+    ///
+    /// 0.0 corresponds to perfectly inelastic collision, 1.0 corresponds to perfectly elastic one.
+    ///
+    pub restitution: f32,
+
+    /// Rule by which friction coefficients are combined.
+    ///
+    pub friction_combine: CombineRule,
+    /// Rule by which restitution coefficients are combined.
+    ///
+    pub restitution_combine: CombineRule,
+}
+impl PhysicsMaterial {
+    /// Constructs material with given coefficients.
+    ///
+    /// Friction is combined by geometric mean and restitution is combined by maximum.
+    ///
+    pub fn new(friction: f32, restitution: f32) -> Self {
+        PhysicsMaterial {
+            friction,
+            restitution,
+
+            friction_combine: CombineRule::GeometricMean,
+            restitution_combine: CombineRule::Maximum,
+        }
+    }
+
+    /// Returns friction of a contact of two materials.
+    ///
+    pub fn combine_friction(&self, other: &PhysicsMaterial) -> f32 {
+        self.friction_combine
+            .max(other.friction_combine)
+            .combine(self.friction, other.friction)
+            .max(0.0)
+    }
+    /// Returns restitution of a contact of two materials.
+    ///
+    pub fn combine_restitution(&self, other: &PhysicsMaterial) -> f32 {
+        self.restitution_combine
+            .max(other.restitution_combine)
+            .combine(self.restitution, other.restitution)
+            .max(0.0)
+    }
+}
+impl Default for PhysicsMaterial {
+    /// Returns material with friction of 0.5 and no restitution.
+    ///
+    fn default() -> Self {
+        PhysicsMaterial::new(0.5, 0.0)
+    }
+}
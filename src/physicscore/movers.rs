@@ -0,0 +1,284 @@
+//! `physicscore::movers` submodule implements [`TopDownMover`] component - kinematic mover of
+//! top-down characters, which slides along walls of a tilemap ([`TileColliders`]).
+//!
+
+use crate::{
+    gamecore::components::Component,
+    mathcore::{
+        shapes::Aabb,
+        vectors::{Point, Vector2},
+    },
+    physicscore::tilemaps::TileColliders,
+};
+use serde::{Deserialize, Serialize};
+
+/// Distance within which mover is considered touching a box.
+///
+/// It hides rounding errors, so mover that was stopped by a wall would not get stuck in it.
+///
+const SKIN: f32 = 1e-3;
+/// Maximal number of boxes from which mover is pushed out during one call of [`TopDownMover::push_out`].
+///
+const MAX_PUSH_OUTS: usize = 4;
+
+/// [`MoveResult`] struct describes movement that was made by [`TopDownMover::move_and_slide`].
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MoveResult {
+    /// Translation that was applied to the mover (including push-out of overlaps).
+    ///
+    pub translation: Vector2,
+    /// Whether horizontal movement was blocked by a wall.
+    ///
+    pub blocked_x: bool,
+    /// Whether vertical movement was blocked by a wall.
+    ///
+    pub blocked_y: bool,
+}
+
+/// [`TopDownMover`] struct is a component that moves top-down characters through tilemaps.
+///
+/// Mover is an axis-aligned box that is centered at position of a character.
+/// Movement is kinematic - it is not affected by forces, but it is stopped by boxes of [`TileColliders`].
+/// Motion is resolved along each axis separately, so mover that runs into a wall at an angle slides along it.
+///
+/// Knockback is a velocity that is added to the velocity of a character and that decays over time,
+/// so hits can push characters around without taking control from the player.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::physicscore::movers::{MoveResult, TopDownMover};
+/// # use ggengine::physicscore::tilemaps::TileColliders;
+/// # use ggengine::mathcore::vectors::{Point, Vector2};
+/// let mut walls: TileColliders = TileColliders::new(10, 10, Vector2::from([16.0, 16.0]));
+/// let _ = walls.set_solid(2, 0);
+///
+/// let mut mover: TopDownMover = TopDownMover::new(Vector2::from([8.0, 8.0]));
+/// let mut position: Point = Point::from([20.0, 20.0]);
+/// // character moves diagonally into the wall above it and slides along it
+/// let result: MoveResult = mover.move_and_slide(&mut position, Vector2::from([10.0, -10.0]), 1.0, &walls);
+/// assert!(result.blocked_y && !result.blocked_x);
+/// assert_eq!(position, Point::from([30.0, 20.0]));
+/// ```
+///
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq)]
+pub struct TopDownMover {
+    /// Size of the box of a mover.
+    ///
+    pub size: Vector2,
+    /// Knockback damping coefficient - the bigger it is, the faster knockback decays.
+    ///
+    pub knockback_damping: f32,
+
+    /// Current knockback velocity.
+    ///
+    knockback: Vector2,
+}
+impl TopDownMover {
+    /// Constructs mover with given size and knockback damping of 10.0.
+    ///
+    pub fn new(size: Vector2) -> Self {
+        TopDownMover {
+            size,
+            knockback_damping: 10.0,
+
+            knockback: Vector2::zero(),
+        }
+    }
+
+    /// Returns box of a mover that is placed at given position.
+    ///
+    pub fn bounds(&self, position: Point) -> Aabb {
+        let half: Vector2 = self.size / 2.0;
+        Aabb::new(position - half, position + half)
+    }
+
+    /// Returns current knockback velocity.
+    ///
+    pub fn knockback(&self) -> Vector2 {
+        self.knockback
+    }
+    /// Applies knockback impulse, which immediately changes knockback velocity.
+    ///
+    pub fn apply_knockback(&mut self, impulse: Vector2) {
+        self.knockback += impulse;
+    }
+    /// Removes knockback.
+    ///
+    pub fn clear_knockback(&mut self) {
+        self.knockback = Vector2::zero();
+    }
+
+    /// Moves mover with given velocity (and its knockback) for `dt` seconds, sliding along walls.
+    ///
+    /// Mover is pushed out of overlaps first (see [`TopDownMover::push_out`]).
+    /// Knockback decays after movement and loses components that were blocked by walls.
+    ///
+    pub fn move_and_slide(
+        &mut self,
+        position: &mut Point,
+        velocity: Vector2,
+        dt: f32,
+        colliders: &TileColliders,
+    ) -> MoveResult {
+        let start: Point = *position;
+        let _ = self.push_out(position, colliders);
+
+        let motion: Vector2 = (velocity + self.knockback) * dt;
+        let bounds: Aabb = self.bounds(*position);
+        let reach: Aabb = bounds
+            .union(Aabb::new(bounds.min + motion, bounds.max + motion))
+            .expand(SKIN);
+        let boxes: Vec<Aabb> = colliders.boxes(reach);
+
+        let x: f32 = sweep(
+            (bounds.min.x, bounds.max.x),
+            (bounds.min.y, bounds.max.y),
+            motion.x,
+            boxes
+                .iter()
+                .map(|other| ((other.min.x, other.max.x), (other.min.y, other.max.y))),
+        );
+        position.x += x;
+        let bounds: Aabb = self.bounds(*position);
+        let y: f32 = sweep(
+            (bounds.min.y, bounds.max.y),
+            (bounds.min.x, bounds.max.x),
+            motion.y,
+            boxes
+                .iter()
+                .map(|other| ((other.min.y, other.max.y), (other.min.x, other.max.x))),
+        );
+        position.y += y;
+
+        let (blocked_x, blocked_y): (bool, bool) = (x != motion.x, y != motion.y);
+        self.knockback *= (-self.knockback_damping.max(0.0) * dt).exp();
+        if blocked_x {
+            self.knockback.x = 0.0;
+        }
+        if blocked_y {
+            self.knockback.y = 0.0;
+        }
+        MoveResult {
+            translation: *position - start,
+            blocked_x,
+            blocked_y,
+        }
+    }
+    /// Pushes mover out of boxes that it overlaps (e.g. after it was teleported or tilemap has changed).
+    ///
+    /// Mover is pushed by the shortest way out of the deepest overlap, which is repeated several times
+    /// if mover overlaps several boxes. Returns whether mover was moved.
+    ///
+    pub fn push_out(&self, position: &mut Point, colliders: &TileColliders) -> bool {
+        let mut moved: bool = false;
+        for _ in 0..MAX_PUSH_OUTS {
+            let bounds: Aabb = self.bounds(*position);
+            let Some((other, overlap)): Option<(Aabb, Aabb)> = colliders
+                .boxes(bounds)
+                .into_iter()
+                .filter_map(|other| Some((other, bounds.intersection(other)?)))
+                .filter(|(_, overlap)| overlap.width() > SKIN && overlap.height() > SKIN)
+                .max_by(|(_, a), (_, b)| {
+                    (a.width() * a.height()).total_cmp(&(b.width() * b.height()))
+                })
+            else {
+                break;
+            };
+
+            let away: Vector2 = bounds.center() - other.center();
+            *position += if overlap.width() < overlap.height() {
+                Vector2::from([overlap.width().copysign(away.x), 0.0])
+            } else {
+                Vector2::from([0.0, overlap.height().copysign(away.y)])
+            };
+            moved = true;
+        }
+        moved
+    }
+}
+impl Component for TopDownMover {}
+
+/// Returns how far box can move along one axis before it hits one of obstacles.
+///
+/// Box and obstacles are given by their ranges along the axis of movement and across it.
+/// Obstacles that box already overlaps do not block it.
+///
+fn sweep(
+    along: (f32, f32),
+    across: (f32, f32),
+    distance: f32,
+    obstacles: impl Iterator<Item = ((f32, f32), (f32, f32))>,
+) -> f32 {
+    let mut allowed: f32 = distance;
+    for (obstacle_along, obstacle_across) in obstacles {
+        if obstacle_across.0 >= across.1 - SKIN || obstacle_across.1 <= across.0 + SKIN {
+            continue;
+        }
+        if distance > 0.0 && obstacle_along.0 >= along.1 - SKIN {
+            allowed = allowed.min((obstacle_along.0 - along.1).max(0.0));
+        } else if distance < 0.0 && obstacle_along.1 <= along.0 + SKIN {
+            allowed = allowed.max((obstacle_along.1 - along.0).min(0.0));
+        }
+    }
+    allowed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MoveResult, TopDownMover};
+    use crate::{
+        mathcore::{
+            floats::FloatOperations,
+            vectors::{Point, Vector2},
+        },
+        physicscore::tilemaps::TileColliders,
+    };
+
+    #[test]
+    fn sliding() {
+        // room of 5x5 tiles surrounded by walls
+        let mut room: TileColliders = TileColliders::new(5, 5, Vector2::from([10.0, 10.0]));
+        for index in 0..5 {
+            for (x, y) in [(index, 0), (index, 4), (0, index), (4, index)] {
+                assert!(room.set_solid(x, y));
+            }
+        }
+        let mut mover: TopDownMover = TopDownMover::new(Vector2::from([6.0, 6.0]));
+
+        // running into the corner stops at both walls
+        let mut position: Point = Point::from([25.0, 25.0]);
+        let result: MoveResult =
+            mover.move_and_slide(&mut position, Vector2::from([100.0, 50.0]), 1.0, &room);
+        assert!(result.blocked_x && result.blocked_y);
+        assert_eq!(position, Point::from([37.0, 37.0]));
+        assert_eq!(result.translation, Vector2::from([12.0, 12.0]));
+        // mover that touches the wall can still move away from it and along it
+        let result: MoveResult =
+            mover.move_and_slide(&mut position, Vector2::from([-1.0, -1.0]), 1.0, &room);
+        assert!(!result.blocked_x && !result.blocked_y);
+        assert_eq!(position, Point::from([36.0, 36.0]));
+
+        // knockback pushes mover and decays
+        mover.apply_knockback(Vector2::from([-10.0, 0.0]));
+        let _ = mover.move_and_slide(&mut position, Vector2::zero(), 0.1, &room);
+        assert_eq!(position.round_up_to(3), Point::from([35.0, 36.0]));
+        assert_eq!(mover.knockback().x.round_up_to(3), -3.679);
+        // knockback into the wall is cancelled
+        mover.apply_knockback(Vector2::from([-1000.0, 0.0]));
+        let result: MoveResult = mover.move_and_slide(&mut position, Vector2::zero(), 0.1, &room);
+        assert!(result.blocked_x);
+        assert_eq!(position.round_up_to(3), Point::from([13.0, 36.0]));
+        assert_eq!(mover.knockback(), Vector2::zero());
+
+        // mover that was placed inside the wall is pushed out by the shortest way
+        let mut position: Point = Point::from([8.0, 25.0]);
+        assert!(mover.push_out(&mut position, &room));
+        assert_eq!(position, Point::from([13.0, 25.0]));
+        assert!(!mover.push_out(&mut position, &room));
+        // and then moves as usual
+        let result: MoveResult =
+            mover.move_and_slide(&mut position, Vector2::from([0.0, 5.0]), 1.0, &room);
+        assert_eq!(result.translation, Vector2::from([0.0, 5.0]));
+    }
+}
@@ -0,0 +1,24 @@
+//! Prelude module: `physicscore::prelude` re-exports all `ggengine::physicscore` items.
+//!
+//! # Examples
+//! Import all the exports.
+//!
+//! ```rust
+//! use ggengine::physicscore::prelude::*;
+//! ```
+//!
+
+// re-exports
+pub use crate::physicscore::bodies::*;
+pub use crate::physicscore::characters::*;
+pub use crate::physicscore::colliders::*;
+pub use crate::physicscore::contacts::*;
+pub use crate::physicscore::events::*;
+pub use crate::physicscore::layers::*;
+pub use crate::physicscore::materials::*;
+pub use crate::physicscore::movers::*;
+pub use crate::physicscore::queries::*;
+pub use crate::physicscore::scenes::*;
+pub use crate::physicscore::solver::*;
+pub use crate::physicscore::tilemaps::*;
+pub use crate::physicscore::world::*;
@@ -0,0 +1,133 @@
+//! `physicscore::queries` submodule implements types that are used by spatial queries
+//! of [`PhysicsWorld`](crate::physicscore::world::PhysicsWorld), such as raycasts, shape overlaps and point queries.
+//!
+
+use crate::{
+    mathcore::vectors::{Point, Vector2},
+    physicscore::{colliders::Collider, contacts::core_segments, world::BodyId},
+};
+
+/// [`QueryFilter`] struct describes which colliders should be considered by a query.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct QueryFilter {
+    /// Bitmask of layers that are considered - collider is considered only if it belongs to one of those layers.
+    ///
+    pub mask: u32,
+    /// Whether sensor colliders are considered.
+    ///
+    pub include_sensors: bool,
+    /// Body that is ignored (e.g. body that casts a ray).
+    ///
+    pub exclude: Option<BodyId>,
+}
+impl QueryFilter {
+    /// Returns whether collider of given body passes the filter.
+    ///
+    pub fn accepts(&self, id: BodyId, collider: &Collider) -> bool {
+        collider.layers & self.mask != 0
+            && (self.include_sensors || !collider.is_sensor)
+            && self.exclude != Some(id)
+    }
+}
+impl Default for QueryFilter {
+    /// Returns filter that considers all layers, ignores sensors and excludes no bodies.
+    ///
+    fn default() -> Self {
+        QueryFilter {
+            mask: u32::MAX,
+            include_sensors: false,
+            exclude: None,
+        }
+    }
+}
+
+/// [`QueryHit`] struct describes collider that was hit by a query.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct QueryHit {
+    /// Id of a body which collider was hit.
+    ///
+    pub body: BodyId,
+    /// Point of a hit in world space.
+    ///
+    pub point: Point,
+    /// Unit normal of a collider's surface at the point of a hit.
+    ///
+    /// For overlap queries normal points from the query shape to the collider.
+    ///
+    pub normal: Vector2,
+    /// Distance of a hit.
+    ///
+    /// For raycasts this is a distance along the ray, for overlap queries this is a signed distance
+    /// between shapes, which is equal to negated penetration depth.
+    ///
+    pub distance: f32,
+}
+
+/// Casts ray against rounded core and returns distance to the hit and normal at the hit.
+///
+/// `direction` should be normalized; hits that are further than `max_distance` are ignored.
+/// If ray starts inside a core, distance is zero and normal is opposite to the direction of a ray.
+///
+pub(crate) fn raycast_core(
+    (core, radius): (&[Point], f32),
+    origin: Point,
+    direction: Vector2,
+    max_distance: f32,
+) -> Option<(f32, Vector2)> {
+    let inside: bool = core_segments(core)
+        .iter()
+        .any(|segment| segment.distance_to_point(origin) <= radius)
+        || (core.len() >= 3 && {
+            let n: usize = core.len();
+            let signs: Vec<f32> = (0..n)
+                .map(|i| (core[(i + 1) % n] - core[i]) ^ (origin - core[i]))
+                .collect();
+            signs.iter().all(|sign| *sign >= 0.0) || signs.iter().all(|sign| *sign <= 0.0)
+        });
+    if inside {
+        return Some((0.0, -direction));
+    }
+
+    let mut closest: Option<(f32, Vector2)> = None;
+    let mut consider = |distance: f32, normal: Vector2| {
+        if (0.0..=max_distance).contains(&distance)
+            && closest.is_none_or(|(closest, _)| distance < closest)
+        {
+            closest = Some((distance, normal));
+        }
+    };
+    for segment in core_segments(core) {
+        let [a, b] = segment.points;
+        // rounded ends
+        if radius > 0.0 {
+            for center in [a, b] {
+                let offset: Vector2 = origin - center;
+                let projection: f32 = offset * direction;
+                let discriminant: f32 =
+                    projection * projection - (offset * offset - radius * radius);
+                if discriminant >= 0.0 {
+                    let distance: f32 = -projection - discriminant.sqrt();
+                    consider(distance, (offset + direction * distance) / radius);
+                }
+            }
+        }
+        // sides
+        if let Some(slope) = (b - a).try_normalize() {
+            for normal in [slope.perpendicular(), -slope.perpendicular()] {
+                let approach: f32 = direction * normal;
+                if approach >= 0.0 {
+                    continue;
+                }
+                let side: Point = a + normal * radius;
+                let distance: f32 = (side - origin) * normal / approach;
+                let along: f32 = (origin + direction * distance - side) * slope;
+                if (0.0..=(b - a).magnitude()).contains(&along) {
+                    consider(distance, normal);
+                }
+            }
+        }
+    }
+    closest
+}
@@ -0,0 +1,442 @@
+//! `physicscore::scenes` submodule implements [`ScenePhysics`] resource and [`step_physics`] system,
+//! which simulate game objects of a [`Scene`] that have [`RigidBody`] and [`Transform2D`] components.
+//!
+//! [`PhysicsWorld`] identifies its bodies by [`BodyId`]s, while game logic works with [`GameObjectId`]s -
+//! [`ScenePhysics`] keeps mapping between them, so events and query hits are reported for game objects.
+//!
+
+use crate::{
+    gamecore::{components::Resource, identifiers::GameObjectId, scenes::Scene, time::Time},
+    mathcore::{
+        transforms::Transform2D,
+        vectors::{Point, Vector2},
+    },
+    physicscore::{
+        bodies::RigidBody,
+        colliders::Collider,
+        events::PhysicsEvent,
+        queries::{QueryFilter, QueryHit},
+        world::{BodyId, PhysicsWorld},
+    },
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// [`SceneEvent`] struct is a [`PhysicsEvent`] together with game objects that it is about.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SceneEvent {
+    /// Game objects which bodies are described by the event (in order of [`PhysicsEvent::bodies`]).
+    ///
+    pub gameobjects: (GameObjectId, GameObjectId),
+    /// Event that was emitted by physics world.
+    ///
+    pub event: PhysicsEvent,
+}
+
+/// [`SceneHit`] struct is a [`QueryHit`] together with game object which collider was hit.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SceneHit {
+    /// Game object which collider was hit.
+    ///
+    pub gameobject: GameObjectId,
+    /// Hit that was found by physics world.
+    ///
+    pub hit: QueryHit,
+}
+
+/// [`ScenePhysics`] struct is a resource that holds [`PhysicsWorld`] of a [`Scene`].
+///
+/// Every game object that has both [`RigidBody`] and [`Transform2D`] components is registered
+/// as a body of the world by [`step_physics`] system; its [`Collider`] component (if there is one)
+/// is attached to that body. Bodies of game objects that were despawned or lost one of those components
+/// are removed from the world.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::gamecore::{identifiers::GameObjectId, scenes::Scene, time::Time};
+/// # use ggengine::physicscore::scenes::{step_physics, ScenePhysics};
+/// # use ggengine::physicscore::world::PhysicsWorld;
+/// # use ggengine::physicscore::bodies::RigidBody;
+/// # use ggengine::mathcore::{transforms::Transform2D, vectors::Vector2};
+/// # use std::time::Duration;
+/// let mut scene: Scene = Scene::new();
+/// let _ = scene
+///     .resources_mut()
+///     .insert_resource(ScenePhysics::new(PhysicsWorld::new(Vector2::from([0.0, 10.0]))));
+/// let ball: GameObjectId = scene.spawn((RigidBody::dynamic(1.0), Transform2D::identity()));
+///
+/// let mut time: Time = Time::new();
+/// time.update_with(Duration::from_secs_f32(0.5));
+/// let _ = scene.resources_mut().insert_resource(time);
+/// step_physics(&mut scene);
+/// let transform: &Transform2D = scene
+///     .components()
+///     .get_component::<Transform2D>(ball)
+///     .expect("Ball has transform");
+/// assert!(transform.translation.y > 0.0);
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct ScenePhysics {
+    /// World that simulates bodies of game objects.
+    ///
+    pub world: PhysicsWorld,
+
+    /// Bodies of registered game objects.
+    ///
+    bodies: BTreeMap<GameObjectId, BodyId>,
+    /// Game objects of registered bodies.
+    ///
+    gameobjects: BTreeMap<BodyId, GameObjectId>,
+    /// Queue of events that were not taken yet.
+    ///
+    events: Vec<SceneEvent>,
+}
+impl ScenePhysics {
+    /// Constructs resource which simulates game objects in given world.
+    ///
+    /// Bodies that are already registered in the world are not bound to game objects,
+    /// so they are not affected by [`step_physics`] and are not reported in events and query hits.
+    ///
+    pub fn new(world: PhysicsWorld) -> Self {
+        ScenePhysics {
+            world,
+
+            bodies: BTreeMap::new(),
+            gameobjects: BTreeMap::new(),
+            events: Vec::new(),
+        }
+    }
+
+    /// Returns body of given game object.
+    ///
+    pub fn body(&self, gameobject_id: GameObjectId) -> Option<BodyId> {
+        self.bodies.get(&gameobject_id).copied()
+    }
+    /// Returns game object of given body.
+    ///
+    pub fn gameobject(&self, id: BodyId) -> Option<GameObjectId> {
+        self.gameobjects.get(&id).copied()
+    }
+
+    /// Returns events that were queued, but were not taken yet.
+    ///
+    pub fn events(&self) -> &[SceneEvent] {
+        &self.events
+    }
+    /// Takes all queued events, leaving the queue empty.
+    ///
+    pub fn take_events(&mut self) -> Vec<SceneEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Casts ray from origin (see [`PhysicsWorld::raycast`]) and returns the closest hit game object.
+    ///
+    pub fn raycast(
+        &self,
+        origin: Point,
+        direction: Vector2,
+        filter: &QueryFilter,
+    ) -> Option<SceneHit> {
+        let hit: QueryHit = self.world.raycast(origin, direction, filter)?;
+        self.scene_hit(hit)
+    }
+    /// Returns game objects which colliders overlap given collider placed with given transform
+    /// (see [`PhysicsWorld::shape_overlap`]).
+    ///
+    pub fn shape_overlap(
+        &self,
+        collider: &Collider,
+        transform: &Transform2D,
+        filter: &QueryFilter,
+    ) -> Vec<SceneHit> {
+        self.world
+            .shape_overlap(collider, transform, filter)
+            .into_iter()
+            .filter_map(|hit| self.scene_hit(hit))
+            .collect()
+    }
+    /// Returns game objects which colliders contain given point (see [`PhysicsWorld::point_query`]).
+    ///
+    pub fn point_query(&self, point: Point) -> Vec<SceneHit> {
+        self.world
+            .point_query(point)
+            .into_iter()
+            .filter_map(|hit| self.scene_hit(hit))
+            .collect()
+    }
+    /// Binds hit to its game object.
+    ///
+    fn scene_hit(&self, hit: QueryHit) -> Option<SceneHit> {
+        Some(SceneHit {
+            gameobject: self.gameobject(hit.body)?,
+            hit,
+        })
+    }
+
+    /// Registers, updates and removes bodies of game objects according to their components.
+    ///
+    fn pull(&mut self, scene: &Scene) {
+        let objects: Vec<(GameObjectId, RigidBody, Transform2D, Option<Collider>)> = scene
+            .components()
+            .components::<RigidBody>()
+            .filter_map(|(gameobject_id, body)| {
+                let transform: Transform2D = *scene
+                    .components()
+                    .get_component::<Transform2D>(gameobject_id)?;
+                let collider: Option<Collider> = scene
+                    .components()
+                    .get_component::<Collider>(gameobject_id)
+                    .cloned();
+                Some((gameobject_id, *body, transform, collider))
+            })
+            .collect();
+
+        let alive: BTreeSet<GameObjectId> = objects
+            .iter()
+            .map(|(gameobject_id, ..)| *gameobject_id)
+            .collect();
+        let stale: Vec<(GameObjectId, BodyId)> = self
+            .bodies
+            .iter()
+            .filter(|(gameobject_id, _)| !alive.contains(gameobject_id))
+            .map(|(gameobject_id, id)| (*gameobject_id, *id))
+            .collect();
+        for (gameobject_id, id) in stale {
+            let _ = self.bodies.remove(&gameobject_id);
+            let _ = self.gameobjects.remove(&id);
+            let _ = self.world.remove_body(id);
+        }
+
+        for (gameobject_id, body, transform, collider) in objects {
+            let id: BodyId = match self.body(gameobject_id) {
+                Some(id) => {
+                    if let Some(registered) = self.world.body_mut(id) {
+                        *registered = body;
+                    }
+                    if let Some(registered) = self.world.transform_mut(id) {
+                        *registered = transform;
+                    }
+                    id
+                }
+                None => {
+                    let id: BodyId = self.world.add_body(body, transform);
+                    let _ = self.bodies.insert(gameobject_id, id);
+                    let _ = self.gameobjects.insert(id, gameobject_id);
+                    id
+                }
+            };
+            let _ = match collider {
+                Some(collider) => self.world.attach_collider(id, collider),
+                None => self.world.detach_collider(id),
+            };
+        }
+    }
+    /// Writes simulated bodies and transforms back to components of game objects
+    /// and queues events of the world.
+    ///
+    fn push(&mut self, scene: &mut Scene) {
+        for (gameobject_id, id) in &self.bodies {
+            if let Some(body) = self.world.body(*id) {
+                if let Some(component) = scene
+                    .components_mut()
+                    .get_component_mut::<RigidBody>(*gameobject_id)
+                {
+                    *component = *body;
+                }
+            }
+            if let Some(transform) = self.world.transform(*id) {
+                if let Some(component) = scene
+                    .components_mut()
+                    .get_component_mut::<Transform2D>(*gameobject_id)
+                {
+                    *component = *transform;
+                }
+            }
+        }
+
+        for event in self.world.take_events() {
+            let (body1, body2): (BodyId, BodyId) = event.bodies();
+            if let (Some(gameobject1), Some(gameobject2)) =
+                (self.gameobject(body1), self.gameobject(body2))
+            {
+                self.events.push(SceneEvent {
+                    gameobjects: (gameobject1, gameobject2),
+                    event,
+                });
+            }
+        }
+    }
+}
+impl Resource for ScenePhysics {}
+
+/// Advances physics of the scene by game time delta of [`Time`] resource
+/// (so physics is paused and scaled with the game).
+///
+/// This system synchronizes [`ScenePhysics`] resource with [`RigidBody`], [`Transform2D`] and [`Collider`]
+/// components of game objects, updates its world (see [`PhysicsWorld::update`]) and writes
+/// simulated bodies and transforms back to components.
+/// Events of the world are queued in the resource as [`SceneEvent`]s.
+///
+/// Nothing happens if scene has no [`ScenePhysics`] resource;
+/// world is not advanced (but is still synchronized) if there is no [`Time`] resource.
+///
+pub fn step_physics(scene: &mut Scene) {
+    let delta: f32 = scene
+        .resources()
+        .get_resource::<Time>()
+        .map_or(0.0, Time::delta_seconds);
+    let Some(mut physics) = scene.resources_mut().remove_resource::<ScenePhysics>() else {
+        return;
+    };
+    physics.pull(scene);
+    let _ = physics.world.update(delta);
+    physics.push(scene);
+    let _ = scene.resources_mut().insert_resource(physics);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{step_physics, SceneEvent, SceneHit, ScenePhysics};
+    use crate::{
+        gamecore::{identifiers::GameObjectId, scenes::Scene, time::Time},
+        mathcore::{
+            transforms::Transform2D,
+            vectors::{Point, Vector2},
+        },
+        physicscore::{
+            bodies::RigidBody,
+            colliders::Collider,
+            events::PhysicsEvent,
+            queries::QueryFilter,
+            world::{BodyId, PhysicsWorld},
+        },
+    };
+    use std::time::Duration;
+
+    fn step(scene: &mut Scene, delta: Duration) {
+        scene
+            .resources_mut()
+            .get_resource_or_insert_with(Time::new)
+            .update_with(delta);
+        step_physics(scene);
+    }
+
+    #[test]
+    fn scene_physics() {
+        let mut scene: Scene = Scene::new();
+        let _ = scene
+            .resources_mut()
+            .insert_resource(ScenePhysics::new(PhysicsWorld::new(Vector2::from([
+                0.0, 10.0,
+            ]))));
+        let ground: GameObjectId = scene.spawn((
+            RigidBody::fixed(),
+            Transform2D::identity(),
+            Collider::rect(10.0, 1.0),
+        ));
+        let ball: GameObjectId = scene.spawn((
+            RigidBody::dynamic(1.0),
+            Transform2D::from_translation(Vector2::from([0.0, -2.0])),
+            Collider::rect(1.0, 1.0),
+        ));
+        // game object without transform is not simulated
+        let ghost: GameObjectId = scene.spawn(RigidBody::dynamic(1.0));
+
+        let mut events: Vec<SceneEvent> = Vec::new();
+        for _ in 0..60 {
+            step(&mut scene, Duration::from_secs_f32(1.0 / 60.0));
+            events.extend(
+                scene
+                    .resources_mut()
+                    .get_resource_mut::<ScenePhysics>()
+                    .expect("Resource exists")
+                    .take_events(),
+            );
+        }
+        let translation: Vector2 = scene
+            .components()
+            .get_component::<Transform2D>(ball)
+            .expect("Ball has transform")
+            .translation;
+        assert!(translation.y > -1.1 && translation.y < -0.9);
+        assert!(matches!(
+            events.as_slice(),
+            [SceneEvent {
+                gameobjects,
+                event: PhysicsEvent::CollisionStarted(_),
+            }] if *gameobjects == (ground, ball)
+        ));
+
+        let physics: &ScenePhysics = scene
+            .resources()
+            .get_resource::<ScenePhysics>()
+            .expect("Resource exists");
+        assert!(physics.body(ghost).is_none());
+        let hit: SceneHit = physics
+            .raycast(
+                Point::from([0.0, -5.0]),
+                Vector2::from([0.0, 10.0]),
+                &QueryFilter::default(),
+            )
+            .expect("Ray hits the ball");
+        assert_eq!(hit.gameobject, ball);
+        let hits: Vec<GameObjectId> = physics
+            .point_query(Point::from([3.0, 0.0]))
+            .into_iter()
+            .map(|hit| hit.gameobject)
+            .collect();
+        assert_eq!(hits, vec![ground]);
+
+        // paused time does not advance the world
+        scene
+            .resources_mut()
+            .get_resource_mut::<Time>()
+            .expect("Time was inserted")
+            .is_paused = true;
+        let paused: GameObjectId = scene.spawn((
+            RigidBody::dynamic(1.0),
+            Transform2D::from_translation(Vector2::from([50.0, 0.0])),
+        ));
+        step(&mut scene, Duration::from_secs_f32(1.0));
+        assert_eq!(
+            scene
+                .components()
+                .get_component::<Transform2D>(paused)
+                .expect("Game object has transform")
+                .translation,
+            Vector2::from([50.0, 0.0])
+        );
+        assert!(scene.despawn(paused));
+        scene
+            .resources_mut()
+            .get_resource_mut::<Time>()
+            .expect("Time was inserted")
+            .is_paused = false;
+
+        // components changed by game logic are picked up and despawned game objects are removed
+        scene
+            .components_mut()
+            .get_component_mut::<Transform2D>(ball)
+            .expect("Ball has transform")
+            .translation = Vector2::from([20.0, 0.0]);
+        assert!(scene.despawn(ground));
+        step(&mut scene, Duration::from_secs_f32(1.0 / 60.0));
+        let physics: &ScenePhysics = scene
+            .resources()
+            .get_resource::<ScenePhysics>()
+            .expect("Resource exists");
+        assert_eq!(physics.world.bodies().count(), 1);
+        let body: BodyId = physics.body(ball).expect("Ball is registered");
+        assert!(
+            physics
+                .world
+                .transform(body)
+                .expect("Body exists")
+                .translation
+                .x
+                >= 20.0
+        );
+    }
+}
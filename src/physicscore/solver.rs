@@ -0,0 +1,254 @@
+//! `physicscore::solver` submodule implements [`ContactSolver`], which resolves contacts
+//! by applying impulses to colliding bodies.
+//!
+
+use crate::{
+    mathcore::{transforms::Transform2D, vectors::Vector2},
+    physicscore::{bodies::RigidBody, contacts::Contact, world::BodyId},
+};
+use std::collections::BTreeMap;
+
+/// [`ContactSolver`] struct resolves contacts by using sequential impulses.
+///
+/// Velocity iterations apply normal impulses (which stop bodies from moving into each other
+/// and make them bounce according to restitution) and friction impulses.
+/// Position iterations push bodies apart to resolve interpenetration that is left.
+/// More iterations make stacks of bodies more stable at the cost of performance.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ContactSolver {
+    /// Number of velocity iterations per step.
+    ///
+    pub velocity_iterations: u32,
+    /// Number of position iterations per step.
+    ///
+    pub position_iterations: u32,
+
+    /// Fraction of penetration depth that is resolved by each position iteration.
+    ///
+    pub correction: f32,
+    /// Penetration depth that is allowed without correction.
+    ///
+    /// Small allowed penetration keeps resting contacts alive and prevents jittering.
+    ///
+    pub slop: f32,
+    /// Relative speed below which collisions are not bouncy.
+    ///
+    /// Ignoring restitution for slow collisions lets bouncing bodies come to rest.
+    ///
+    pub restitution_threshold: f32,
+}
+impl ContactSolver {
+    /// Default number of velocity iterations.
+    ///
+    pub const DEFAULT_VELOCITY_ITERATIONS: u32 = 8;
+    /// Default number of position iterations.
+    ///
+    pub const DEFAULT_POSITION_ITERATIONS: u32 = 3;
+
+    /// Constructs solver with given numbers of iterations.
+    ///
+    pub fn new(velocity_iterations: u32, position_iterations: u32) -> Self {
+        ContactSolver {
+            velocity_iterations,
+            position_iterations,
+
+            correction: 0.8,
+            slop: 0.01,
+            restitution_threshold: 1.0,
+        }
+    }
+
+    /// Resolves given contacts by changing velocities and positions of bodies.
+    ///
+    pub(crate) fn solve(
+        &self,
+        contacts: &[Contact],
+        bodies: &mut BTreeMap<BodyId, (RigidBody, Transform2D)>,
+    ) {
+        // Restitution is based on relative velocity before any impulses are applied.
+        let bounces: Vec<f32> = contacts
+            .iter()
+            .map(|contact| {
+                let (body1, transform1): (RigidBody, Transform2D) = bodies[&contact.bodies.0];
+                let (body2, transform2): (RigidBody, Transform2D) = bodies[&contact.bodies.1];
+                let speed: f32 = (body2.velocity_at(contact.point - transform2.translation)
+                    - body1.velocity_at(contact.point - transform1.translation))
+                    * contact.normal;
+                if speed < -self.restitution_threshold {
+                    -contact.restitution * speed
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+
+        let mut impulses: Vec<(f32, f32)> = vec![(0.0, 0.0); contacts.len()];
+        for _ in 0..self.velocity_iterations {
+            for (i, contact) in contacts.iter().enumerate() {
+                let (mut body1, transform1): (RigidBody, Transform2D) = bodies[&contact.bodies.0];
+                let (mut body2, transform2): (RigidBody, Transform2D) = bodies[&contact.bodies.1];
+                let (r1, r2): (Vector2, Vector2) = (
+                    contact.point - transform1.translation,
+                    contact.point - transform2.translation,
+                );
+                let (normal, tangent): (Vector2, Vector2) =
+                    (contact.normal, contact.normal.perpendicular());
+
+                let effective_mass = |direction: Vector2| -> f32 {
+                    body1.inverse_mass()
+                        + body2.inverse_mass()
+                        + body1.inverse_inertia() * (r1 ^ direction).powi(2)
+                        + body2.inverse_inertia() * (r2 ^ direction).powi(2)
+                };
+                let (mass, tangent_mass): (f32, f32) =
+                    (effective_mass(normal), effective_mass(tangent));
+                if mass <= 0.0 {
+                    continue;
+                }
+
+                // normal impulse
+                let relative: Vector2 = body2.velocity_at(r2) - body1.velocity_at(r1);
+                let previous: f32 = impulses[i].0;
+                impulses[i].0 = (previous + (bounces[i] - relative * normal) / mass).max(0.0);
+                let impulse: Vector2 = normal * (impulses[i].0 - previous);
+                body1.apply_impulse_at(-impulse, r1);
+                body2.apply_impulse_at(impulse, r2);
+
+                // friction impulse
+                let relative: Vector2 = body2.velocity_at(r2) - body1.velocity_at(r1);
+                let limit: f32 = contact.friction * impulses[i].0;
+                let previous: f32 = impulses[i].1;
+                impulses[i].1 = (previous - relative * tangent / tangent_mass).clamp(-limit, limit);
+                let impulse: Vector2 = tangent * (impulses[i].1 - previous);
+                body1.apply_impulse_at(-impulse, r1);
+                body2.apply_impulse_at(impulse, r2);
+
+                let _ = bodies.insert(contact.bodies.0, (body1, transform1));
+                let _ = bodies.insert(contact.bodies.1, (body2, transform2));
+            }
+        }
+
+        // Depth is tracked by translations that bodies received since contacts were found.
+        let mut translations: BTreeMap<BodyId, Vector2> = BTreeMap::new();
+        for _ in 0..self.position_iterations {
+            for contact in contacts {
+                let (a, b): (BodyId, BodyId) = contact.bodies;
+                let (inverse_mass1, inverse_mass2): (f32, f32) =
+                    (bodies[&a].0.inverse_mass(), bodies[&b].0.inverse_mass());
+                if inverse_mass1 + inverse_mass2 <= 0.0 {
+                    continue;
+                }
+
+                let (moved1, moved2): (Vector2, Vector2) = (
+                    translations.get(&a).copied().unwrap_or(Vector2::zero()),
+                    translations.get(&b).copied().unwrap_or(Vector2::zero()),
+                );
+                let depth: f32 = contact.depth - (moved2 - moved1) * contact.normal;
+                let correction: f32 = (depth - self.slop).max(0.0) * self.correction
+                    / (inverse_mass1 + inverse_mass2);
+                if correction <= 0.0 {
+                    continue;
+                }
+
+                let correction: Vector2 = contact.normal * correction;
+                for (id, translation) in [
+                    (a, -correction * inverse_mass1),
+                    (b, correction * inverse_mass2),
+                ] {
+                    if let Some((_, transform)) = bodies.get_mut(&id) {
+                        transform.translation += translation;
+                    }
+                    *translations.entry(id).or_insert(Vector2::zero()) += translation;
+                }
+            }
+        }
+    }
+}
+impl Default for ContactSolver {
+    /// Returns solver with default numbers of iterations.
+    ///
+    fn default() -> Self {
+        ContactSolver::new(
+            ContactSolver::DEFAULT_VELOCITY_ITERATIONS,
+            ContactSolver::DEFAULT_POSITION_ITERATIONS,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        mathcore::{transforms::Transform2D, vectors::Vector2},
+        physicscore::{
+            bodies::RigidBody,
+            colliders::Collider,
+            world::{BodyId, PhysicsWorld},
+        },
+    };
+
+    #[test]
+    fn bouncing_ball() {
+        let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::from([0.0, 10.0]));
+        let ground: BodyId = world.add_body(
+            RigidBody::fixed(),
+            Transform2D::from_translation(Vector2::from([0.0, 11.0])),
+        );
+        let _ = world.attach_collider(ground, Collider::rect(20.0, 2.0));
+
+        let ball: BodyId = world.add_body(RigidBody::dynamic(1.0), Transform2D::identity());
+        let mut collider: Collider = Collider::circle(1.0);
+        collider.material.restitution = 0.8;
+        let _ = world.attach_collider(ball, collider);
+
+        let mut highest_bounce: f32 = f32::INFINITY;
+        let mut falling: bool = true;
+        for _ in 0..120 {
+            world.step(1.0 / 60.0);
+            let body: &RigidBody = world.body(ball).expect("Body exists");
+            if falling && body.velocity.y < 0.0 {
+                falling = false;
+            }
+            if !falling {
+                highest_bounce =
+                    highest_bounce.min(world.transform(ball).expect("Body exists").translation.y);
+            }
+        }
+        // ball has bounced, but did not reach its initial height
+        assert!(!falling);
+        assert!(highest_bounce > 0.0 && highest_bounce < 8.0);
+        // and it never falls through the ground
+        assert!(world.transform(ball).expect("Body exists").translation.y < 9.1);
+    }
+
+    #[test]
+    fn stacked_boxes() {
+        let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::from([0.0, 10.0]));
+        let ground: BodyId = world.add_body(
+            RigidBody::fixed(),
+            Transform2D::from_translation(Vector2::from([0.0, 1.0])),
+        );
+        let _ = world.attach_collider(ground, Collider::rect(20.0, 2.0));
+
+        let boxes: Vec<BodyId> = (0..3)
+            .map(|i| {
+                let id: BodyId = world.add_body(
+                    RigidBody::dynamic(1.0),
+                    Transform2D::from_translation(Vector2::from([0.0, -0.5 - i as f32])),
+                );
+                let _ = world.attach_collider(id, Collider::rect(1.0, 1.0));
+                id
+            })
+            .collect();
+
+        for _ in 0..300 {
+            world.step(1.0 / 60.0);
+        }
+        for (i, id) in boxes.into_iter().enumerate() {
+            let translation: Vector2 = world.transform(id).expect("Body exists").translation;
+            assert!(translation.x.abs() < 0.05);
+            assert!((translation.y - (-0.5 - i as f32)).abs() < 0.1);
+            assert!(world.body(id).expect("Body exists").velocity.magnitude() < 0.5);
+        }
+    }
+}
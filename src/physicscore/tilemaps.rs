@@ -0,0 +1,615 @@
+//! `physicscore::tilemaps` submodule implements [`TileColliders`] - collision boxes of tiles of a tilemap,
+//! which can be imported from maps that were made in Tiled (see [`TileColliders::from_tiled_json`]).
+//!
+
+use crate::{
+    gamecore::components::Resource,
+    mathcore::{
+        shapes::Aabb,
+        vectors::{Point, Vector2},
+    },
+    GGError,
+};
+use std::collections::BTreeMap;
+
+/// [`TileColliders`] struct stores collision boxes of tiles of a rectangular tilemap.
+///
+/// Tilemap starts at the origin and uses the same axes as Tiled - tile `(x, y)` occupies box
+/// from `(x * tile_width, y * tile_height)` to `((x + 1) * tile_width, (y + 1) * tile_height)`.
+/// Every tile can have several boxes, which are given relative to the top left corner of the tile,
+/// so tiles can be partially solid (e.g. thin walls or half-height fences).
+///
+/// # Example
+/// ```rust
+/// # use ggengine::physicscore::tilemaps::TileColliders;
+/// # use ggengine::mathcore::shapes::Aabb;
+/// # use ggengine::mathcore::vectors::{Point, Vector2};
+/// let mut colliders: TileColliders = TileColliders::new(10, 10, Vector2::from([16.0, 16.0]));
+/// assert!(colliders.set_solid(1, 0));
+/// assert!(colliders.set_shapes(2, 0, vec![Aabb::new(Point::zero(), Point::from([16.0, 4.0]))]));
+/// assert!(!colliders.set_solid(10, 0));
+///
+/// assert_eq!(colliders.tile_at(Point::from([20.0, 5.0])), Some((1, 0)));
+/// assert_eq!(
+///     colliders.boxes(Aabb::new(Point::from([30.0, 0.0]), Point::from([40.0, 2.0]))),
+///     vec![
+///         Aabb::new(Point::from([16.0, 0.0]), Point::from([32.0, 16.0])),
+///         Aabb::new(Point::from([32.0, 0.0]), Point::from([48.0, 4.0])),
+///     ]
+/// );
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct TileColliders {
+    /// Width of a tilemap in tiles.
+    ///
+    width: usize,
+    /// Height of a tilemap in tiles.
+    ///
+    height: usize,
+    /// Size of one tile.
+    ///
+    tile_size: Vector2,
+    /// Boxes of tiles that have them, relative to top left corners of tiles (tiles are keyed by rows and columns).
+    ///
+    tiles: BTreeMap<(usize, usize), Vec<Aabb>>,
+}
+impl TileColliders {
+    /// Constructs tilemap of given size without solid tiles.
+    ///
+    pub fn new(width: usize, height: usize, tile_size: Vector2) -> Self {
+        TileColliders {
+            width,
+            height,
+            tile_size,
+            tiles: BTreeMap::new(),
+        }
+    }
+
+    /// Returns width of a tilemap in tiles.
+    ///
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    /// Returns height of a tilemap in tiles.
+    ///
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    /// Returns size of one tile.
+    ///
+    pub fn tile_size(&self) -> Vector2 {
+        self.tile_size
+    }
+
+    /// Makes the whole tile solid.
+    ///
+    /// Returns `false` if tile is out of bounds of a tilemap.
+    ///
+    pub fn set_solid(&mut self, x: usize, y: usize) -> bool {
+        self.set_shapes(x, y, vec![Aabb::new(Point::zero(), self.tile_size)])
+    }
+    /// Sets boxes of a tile (relative to its top left corner); empty list makes tile passable.
+    ///
+    /// Returns `false` if tile is out of bounds of a tilemap.
+    ///
+    pub fn set_shapes(&mut self, x: usize, y: usize, shapes: Vec<Aabb>) -> bool {
+        if x >= self.width || y >= self.height {
+            return false;
+        }
+        if shapes.is_empty() {
+            let _ = self.tiles.remove(&(y, x));
+        } else {
+            let _ = self.tiles.insert((y, x), shapes);
+        }
+        true
+    }
+    /// Returns boxes of a tile (relative to its top left corner).
+    ///
+    pub fn shapes(&self, x: usize, y: usize) -> &[Aabb] {
+        self.tiles.get(&(y, x)).map_or(&[], Vec::as_slice)
+    }
+    /// Returns whether tile has any boxes.
+    ///
+    pub fn is_solid(&self, x: usize, y: usize) -> bool {
+        self.tiles.contains_key(&(y, x))
+    }
+
+    /// Returns tile that contains given point or `None` if point is outside of a tilemap.
+    ///
+    pub fn tile_at(&self, point: Point) -> Option<(usize, usize)> {
+        let (x, y): (f32, f32) = (
+            (point.x / self.tile_size.x).floor(),
+            (point.y / self.tile_size.y).floor(),
+        );
+        if x < 0.0 || y < 0.0 || x >= self.width as f32 || y >= self.height as f32 {
+            return None;
+        }
+        Some((x as usize, y as usize))
+    }
+    /// Returns boxes (in world space) of all tiles that are touched by given area.
+    ///
+    /// Boxes are ordered by rows and then by columns of their tiles.
+    ///
+    pub fn boxes(&self, area: Aabb) -> Vec<Aabb> {
+        if self.width == 0 || self.height == 0 {
+            return Vec::new();
+        }
+        let range = |min: f32, max: f32, size: f32, count: usize| -> Option<(usize, usize)> {
+            let (first, last): (f32, f32) = ((min / size).floor(), (max / size).floor());
+            if last < 0.0 || first >= count as f32 {
+                return None;
+            }
+            Some((first.max(0.0) as usize, (last as usize).min(count - 1)))
+        };
+        let (Some((x1, x2)), Some((y1, y2))) = (
+            range(area.min.x, area.max.x, self.tile_size.x, self.width),
+            range(area.min.y, area.max.y, self.tile_size.y, self.height),
+        ) else {
+            return Vec::new();
+        };
+
+        let mut boxes: Vec<Aabb> = Vec::new();
+        for y in y1..=y2 {
+            for (&(_, x), shapes) in self.tiles.range((y, x1)..=(y, x2)) {
+                let corner: Vector2 =
+                    Vector2::from([x as f32 * self.tile_size.x, y as f32 * self.tile_size.y]);
+                boxes.extend(
+                    shapes
+                        .iter()
+                        .map(|shape| Aabb::new(shape.min + corner, shape.max + corner)),
+                );
+            }
+        }
+        boxes
+    }
+
+    /// Imports collision boxes of given tile layer of Tiled map in JSON format.
+    ///
+    /// Tile is solid if it has collision shapes (which are edited in tile collision editor of Tiled)
+    /// or if it has boolean property `collides` that is set to `true` (then the whole tile is solid).
+    /// Collision rectangles are imported as they are, while ellipses and polygons are replaced with
+    /// their bounding boxes. Flipped and rotated tiles flip their boxes accordingly.
+    /// Size of a tile is taken from the map, so all boxes are measured in pixels.
+    ///
+    /// Only orthogonal finite maps with embedded tilesets and uncompressed layer data are supported.
+    ///
+    /// # Errors
+    /// Error is returned if JSON is malformed, if map has no tile layer with given name,
+    /// or if map uses features that are not supported.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::physicscore::tilemaps::TileColliders;
+    /// # use ggengine::mathcore::shapes::Aabb;
+    /// # use ggengine::mathcore::vectors::Point;
+    /// let colliders: TileColliders = TileColliders::from_tiled_json(r#"{
+    ///     "width": 3, "height": 1, "tilewidth": 16, "tileheight": 16,
+    ///     "layers": [{ "type": "tilelayer", "name": "walls", "width": 3, "height": 1, "data": [1, 0, 2] }],
+    ///     "tilesets": [{
+    ///         "firstgid": 1, "tilewidth": 16, "tileheight": 16,
+    ///         "tiles": [
+    ///             { "id": 0, "properties": [{ "name": "collides", "type": "bool", "value": true }] },
+    ///             { "id": 1, "objectgroup": { "objects": [{ "x": 0, "y": 12, "width": 16, "height": 4 }] } }
+    ///         ]
+    ///     }]
+    /// }"#, "walls").expect("Map is valid");
+    /// assert!(colliders.is_solid(0, 0) && !colliders.is_solid(1, 0));
+    /// assert_eq!(colliders.shapes(2, 0), &[Aabb::new(Point::from([0.0, 12.0]), Point::from([16.0, 16.0]))]);
+    /// ```
+    ///
+    pub fn from_tiled_json(json: &str, layer: &str) -> Result<TileColliders, GGError> {
+        let map: tiled::MapData =
+            serde_json::from_str(json).map_err(|error| GGError::Decode(error.to_string()))?;
+        map.into_colliders(layer)
+    }
+}
+impl Resource for TileColliders {}
+
+/// `tiled` submodule declares subset of Tiled JSON format that is supported by `TileColliders::from_tiled_json`.
+///
+mod tiled {
+    use super::TileColliders;
+    use crate::{
+        mathcore::{
+            shapes::Aabb,
+            vectors::{Point, Vector2},
+        },
+        GGError,
+    };
+    use serde::Deserialize;
+
+    /// Bit of global tile id that marks horizontally flipped tile.
+    ///
+    const FLIPPED_HORIZONTALLY: u32 = 0x8000_0000;
+    /// Bit of global tile id that marks vertically flipped tile.
+    ///
+    const FLIPPED_VERTICALLY: u32 = 0x4000_0000;
+    /// Bit of global tile id that marks diagonally flipped (transposed) tile.
+    ///
+    const FLIPPED_DIAGONALLY: u32 = 0x2000_0000;
+    /// Bits of global tile id that do not store flags.
+    ///
+    const ID_MASK: u32 = 0x0FFF_FFFF;
+
+    /// Root of Tiled map JSON.
+    ///
+    #[derive(Deserialize)]
+    pub(super) struct MapData {
+        /// Width of the map in tiles.
+        ///
+        width: usize,
+        /// Height of the map in tiles.
+        ///
+        height: usize,
+        /// Width of a tile in pixels.
+        ///
+        tilewidth: f32,
+        /// Height of a tile in pixels.
+        ///
+        tileheight: f32,
+        /// Whether map is infinite (consists of chunks).
+        ///
+        #[serde(default)]
+        infinite: bool,
+        /// Layers of the map.
+        ///
+        #[serde(default)]
+        layers: Vec<LayerData>,
+        /// Tilesets of the map.
+        ///
+        #[serde(default)]
+        tilesets: Vec<TilesetData>,
+    }
+
+    /// Layer of Tiled map.
+    ///
+    #[derive(Deserialize)]
+    struct LayerData {
+        /// Type of the layer (`tilelayer`, `group` and so on).
+        ///
+        #[serde(rename = "type")]
+        kind: String,
+        /// Name of the layer.
+        ///
+        #[serde(default)]
+        name: String,
+        /// Global tile ids in row-major order (or encoded string).
+        ///
+        #[serde(default)]
+        data: serde_json::Value,
+        /// Layers of the group.
+        ///
+        #[serde(default)]
+        layers: Vec<LayerData>,
+    }
+    impl LayerData {
+        /// Finds tile layer with given name among this layer and its children.
+        ///
+        fn find(&self, name: &str) -> Option<&LayerData> {
+            if self.kind == "tilelayer" && self.name == name {
+                return Some(self);
+            }
+            self.layers.iter().find_map(|layer| layer.find(name))
+        }
+    }
+
+    /// Tileset of Tiled map.
+    ///
+    #[derive(Deserialize)]
+    struct TilesetData {
+        /// Global id of the first tile of the tileset.
+        ///
+        firstgid: u32,
+        /// Path to external tileset.
+        ///
+        source: Option<String>,
+        /// Height of a tile in pixels.
+        ///
+        tileheight: Option<f32>,
+        /// Tiles that have additional data.
+        ///
+        #[serde(default)]
+        tiles: Vec<TileData>,
+    }
+
+    /// Tile of Tiled tileset.
+    ///
+    #[derive(Deserialize)]
+    struct TileData {
+        /// Local id of the tile.
+        ///
+        id: u32,
+        /// Collision shapes of the tile.
+        ///
+        objectgroup: Option<ObjectGroupData>,
+        /// Custom properties of the tile.
+        ///
+        #[serde(default)]
+        properties: Vec<PropertyData>,
+    }
+    impl TileData {
+        /// Returns boxes of the tile in tileset space.
+        ///
+        fn shapes(&self, tile_size: Vector2) -> Vec<Aabb> {
+            let collides: bool = self.properties.iter().any(|property| {
+                property.name == "collides" && property.value == serde_json::Value::Bool(true)
+            });
+            let mut shapes: Vec<Aabb> = self
+                .objectgroup
+                .iter()
+                .flat_map(|group| &group.objects)
+                .filter_map(ObjectData::bounds)
+                .collect();
+            if shapes.is_empty() && collides {
+                shapes.push(Aabb::new(Point::zero(), tile_size));
+            }
+            shapes
+        }
+    }
+
+    /// Collision shapes of Tiled tile.
+    ///
+    #[derive(Deserialize)]
+    struct ObjectGroupData {
+        /// Shapes of the group.
+        ///
+        #[serde(default)]
+        objects: Vec<ObjectData>,
+    }
+
+    /// Collision shape of Tiled tile.
+    ///
+    #[derive(Deserialize)]
+    struct ObjectData {
+        /// Horizontal position of the shape.
+        ///
+        #[serde(default)]
+        x: f32,
+        /// Vertical position of the shape.
+        ///
+        #[serde(default)]
+        y: f32,
+        /// Width of the shape.
+        ///
+        #[serde(default)]
+        width: f32,
+        /// Height of the shape.
+        ///
+        #[serde(default)]
+        height: f32,
+        /// Vertices of polygon relative to position of the shape.
+        ///
+        polygon: Option<Vec<PointData>>,
+    }
+    impl ObjectData {
+        /// Returns bounding box of the shape or `None` if shape has no area.
+        ///
+        fn bounds(&self) -> Option<Aabb> {
+            let origin: Point = Point::from([self.x, self.y]);
+            let bounds: Aabb = match &self.polygon {
+                Some(polygon) => Aabb::from_points(
+                    &polygon
+                        .iter()
+                        .map(|point| origin + Point::from([point.x, point.y]))
+                        .collect::<Vec<Point>>(),
+                )?,
+                None => Aabb::new(origin, origin + Vector2::from([self.width, self.height])),
+            };
+            (bounds.width() > 0.0 && bounds.height() > 0.0).then_some(bounds)
+        }
+    }
+
+    /// Point of Tiled polygon.
+    ///
+    #[derive(Deserialize)]
+    struct PointData {
+        /// Horizontal coordinate.
+        ///
+        x: f32,
+        /// Vertical coordinate.
+        ///
+        y: f32,
+    }
+
+    /// Custom property of Tiled tile.
+    ///
+    #[derive(Deserialize)]
+    struct PropertyData {
+        /// Name of the property.
+        ///
+        name: String,
+        /// Value of the property.
+        ///
+        value: serde_json::Value,
+    }
+
+    /// Flips box inside of a tile according to flags of global tile id.
+    ///
+    fn flip(shape: Aabb, gid: u32, tile_size: Vector2) -> Aabb {
+        let (mut min, mut max): (Point, Point) = (shape.min, shape.max);
+        if gid & FLIPPED_DIAGONALLY != 0 {
+            (min, max) = (Point::from([min.y, min.x]), Point::from([max.y, max.x]));
+        }
+        if gid & FLIPPED_HORIZONTALLY != 0 {
+            (min.x, max.x) = (tile_size.x - max.x, tile_size.x - min.x);
+        }
+        if gid & FLIPPED_VERTICALLY != 0 {
+            (min.y, max.y) = (tile_size.y - max.y, tile_size.y - min.y);
+        }
+        Aabb::new(min, max)
+    }
+
+    impl MapData {
+        /// Builds [`TileColliders`] from given tile layer of the map.
+        ///
+        pub(super) fn into_colliders(self, layer: &str) -> Result<TileColliders, GGError> {
+            if self.infinite {
+                return Err(GGError::Unsupported(String::from(
+                    "Infinite Tiled maps are not supported",
+                )));
+            }
+            if let Some(source) = self
+                .tilesets
+                .iter()
+                .find_map(|tileset| tileset.source.as_ref())
+            {
+                return Err(GGError::Unsupported(format!(
+                    "External Tiled tileset {source} is not supported, embed it into the map"
+                )));
+            }
+            let data: &LayerData = self
+                .layers
+                .iter()
+                .find_map(|other| other.find(layer))
+                .ok_or_else(|| {
+                    GGError::InvalidArgument(format!("Tiled map has no tile layer {layer}"))
+                })?;
+            let gids: &Vec<serde_json::Value> = data.data.as_array().ok_or_else(|| {
+                GGError::Unsupported(String::from("Encoded Tiled layers are not supported"))
+            })?;
+            if gids.len() != self.width * self.height {
+                return Err(GGError::Decode(format!(
+                    "Tiled layer {layer} has {} tiles instead of {}",
+                    gids.len(),
+                    self.width * self.height
+                )));
+            }
+
+            let tile_size: Vector2 = Vector2::from([self.tilewidth, self.tileheight]);
+            let mut colliders: TileColliders =
+                TileColliders::new(self.width, self.height, tile_size);
+            for (index, gid) in gids.iter().enumerate() {
+                let gid: u32 = gid
+                    .as_u64()
+                    .and_then(|gid| u32::try_from(gid).ok())
+                    .ok_or_else(|| GGError::Decode(format!("{gid} is not a valid tile id")))?;
+                let id: u32 = gid & ID_MASK;
+                let Some(tileset) = self
+                    .tilesets
+                    .iter()
+                    .filter(|tileset| tileset.firstgid <= id)
+                    .max_by_key(|tileset| tileset.firstgid)
+                else {
+                    continue;
+                };
+                let Some(tile) = tileset
+                    .tiles
+                    .iter()
+                    .find(|tile| tile.id == id - tileset.firstgid)
+                else {
+                    continue;
+                };
+
+                // tiles that are taller than the grid are aligned by their bottom edge
+                let offset: Vector2 = Vector2::from([
+                    0.0,
+                    self.tileheight - tileset.tileheight.unwrap_or(self.tileheight),
+                ]);
+                let shapes: Vec<Aabb> = tile
+                    .shapes(tile_size)
+                    .into_iter()
+                    .map(|shape| {
+                        flip(
+                            Aabb::new(shape.min + offset, shape.max + offset),
+                            gid,
+                            tile_size,
+                        )
+                    })
+                    .collect();
+                let _ = colliders.set_shapes(index % self.width, index / self.width, shapes);
+            }
+            Ok(colliders)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TileColliders;
+    use crate::mathcore::{shapes::Aabb, vectors::Point};
+
+    /// Tiled map with group layer, flipped tiles and tiles of two tilesets.
+    ///
+    const MAP: &str = r#"{
+        "width": 4, "height": 2, "tilewidth": 8, "tileheight": 8, "infinite": false,
+        "layers": [
+            { "type": "tilelayer", "name": "decor", "width": 4, "height": 2, "data": [0, 0, 0, 0, 0, 0, 0, 0] },
+            { "type": "group", "name": "level", "layers": [
+                { "type": "tilelayer", "name": "walls", "width": 4, "height": 2,
+                  "data": [1, 2, 2147483650, 0, 1073741826, 3, 10, 11] }
+            ] }
+        ],
+        "tilesets": [
+            { "firstgid": 1, "tilewidth": 8, "tileheight": 8, "tiles": [
+                { "id": 0, "properties": [{ "name": "collides", "type": "bool", "value": true }] },
+                { "id": 1, "objectgroup": { "objects": [
+                    { "id": 1, "x": 0, "y": 0, "width": 2, "height": 8 },
+                    { "id": 2, "x": 4, "y": 4, "polygon": [{ "x": 0, "y": 0 }, { "x": 4, "y": 0 }, { "x": 0, "y": 4 }] }
+                ] } },
+                { "id": 2, "properties": [{ "name": "collides", "type": "bool", "value": false }] }
+            ] },
+            { "firstgid": 10, "tilewidth": 8, "tileheight": 16, "tiles": [
+                { "id": 1, "objectgroup": { "objects": [{ "x": 0, "y": 12, "width": 8, "height": 4 }] } }
+            ] }
+        ]
+    }"#;
+
+    #[test]
+    fn tiled_import() {
+        let colliders: TileColliders =
+            TileColliders::from_tiled_json(MAP, "walls").expect("Map is valid");
+        assert_eq!((colliders.width(), colliders.height()), (4, 2));
+        let boxes = |points: [[f32; 4]; 2]| -> Vec<Aabb> {
+            points
+                .iter()
+                .map(|[x1, y1, x2, y2]| Aabb::new(Point::from([*x1, *y1]), Point::from([*x2, *y2])))
+                .collect()
+        };
+
+        assert_eq!(
+            colliders.shapes(0, 0),
+            &[Aabb::new(Point::zero(), Point::from([8.0, 8.0]))]
+        );
+        assert_eq!(
+            colliders.shapes(1, 0),
+            boxes([[0.0, 0.0, 2.0, 8.0], [4.0, 4.0, 8.0, 8.0]])
+        );
+        // flipped horizontally
+        assert_eq!(
+            colliders.shapes(2, 0),
+            boxes([[6.0, 0.0, 8.0, 8.0], [0.0, 4.0, 4.0, 8.0]])
+        );
+        // flipped vertically
+        assert_eq!(
+            colliders.shapes(0, 1),
+            boxes([[0.0, 0.0, 2.0, 8.0], [4.0, 0.0, 8.0, 4.0]])
+        );
+        // passable tile, tile without data and tall tile of the second tileset
+        assert!(
+            !colliders.is_solid(3, 0) && !colliders.is_solid(1, 1) && !colliders.is_solid(2, 1)
+        );
+        assert_eq!(
+            colliders.shapes(3, 1),
+            &[Aabb::new(Point::from([0.0, 4.0]), Point::from([8.0, 8.0]))]
+        );
+
+        assert!(!TileColliders::from_tiled_json(MAP, "decor")
+            .expect("Map is valid")
+            .is_solid(0, 0));
+        assert!(TileColliders::from_tiled_json(MAP, "level").is_err());
+        assert!(TileColliders::from_tiled_json(
+            r#"{ "width": 1, "height": 1, "tilewidth": 8, "tileheight": 8,
+                 "layers": [{ "type": "tilelayer", "name": "walls", "data": "AQAAAA==", "encoding": "base64" }] }"#,
+            "walls"
+        )
+        .is_err());
+        assert!(TileColliders::from_tiled_json(
+            r#"{ "width": 1, "height": 1, "tilewidth": 8, "tileheight": 8,
+                 "layers": [{ "type": "tilelayer", "name": "walls", "data": [1] }],
+                 "tilesets": [{ "firstgid": 1, "source": "walls.tsj" }] }"#,
+            "walls"
+        )
+        .is_err());
+    }
+}
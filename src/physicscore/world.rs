@@ -0,0 +1,1087 @@
+//! `physicscore::world` submodule implements [`PhysicsWorld`] - struct that stores physical bodies and
+//! advances simulation in fixed steps, which are measured by [`FixedTimestep`].
+//!
+
+use crate::{
+    mathcore::{
+        shapes::Aabb,
+        transforms::Transform2D,
+        vectors::{Point, Vector2},
+        Angle,
+    },
+    physicscore::{
+        bodies::{BodyType, RigidBody},
+        colliders::Collider,
+        contacts::{overlap_cores, Contact, ContactFilter, SpatialGrid},
+        events::{contact_events, PhysicsEvent},
+        layers::CollisionLayers,
+        queries::{raycast_core, QueryFilter, QueryHit},
+        solver::ContactSolver,
+    },
+};
+use std::collections::BTreeMap;
+
+/// [`FixedTimestep`] struct accumulates frame time and splits it into steps of fixed length.
+///
+/// Fixed steps make simulation deterministic and independent of frame rate.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::physicscore::world::FixedTimestep;
+/// let mut timestep: FixedTimestep = FixedTimestep::new(0.25);
+/// assert_eq!(timestep.advance(0.625), 2);
+/// assert_eq!(timestep.advance(0.25), 1);
+/// assert_eq!(timestep.alpha(), 0.5);
+/// ```
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FixedTimestep {
+    /// Length of a step in seconds.
+    ///
+    step: f32,
+    /// Time that was accumulated, but was not consumed by steps yet.
+    ///
+    accumulator: f32,
+    /// Maximal number of steps that can be made during one advance.
+    ///
+    max_steps: u32,
+}
+impl FixedTimestep {
+    /// Default maximal number of steps per advance.
+    ///
+    /// Limiting number of steps prevents the 'spiral of death' - situation in which
+    /// simulation takes longer than the time it simulates and falls further behind each frame.
+    ///
+    pub const DEFAULT_MAX_STEPS: u32 = 8;
+
+    /// Constructs timestep with given step length (in seconds).
+    ///
+    /// Non-positive or non-finite step is replaced with 1/60 of a second.
+    ///
+    pub fn new(step: f32) -> Self {
+        FixedTimestep {
+            step: if step.is_finite() && step > 0.0 {
+                step
+            } else {
+                1.0 / 60.0
+            },
+            accumulator: 0.0,
+            max_steps: FixedTimestep::DEFAULT_MAX_STEPS,
+        }
+    }
+
+    /// Returns length of a step.
+    ///
+    pub fn step(&self) -> f32 {
+        self.step
+    }
+    /// Returns maximal number of steps per advance.
+    ///
+    pub fn max_steps(&self) -> u32 {
+        self.max_steps
+    }
+    /// Sets maximal number of steps per advance.
+    ///
+    pub fn set_max_steps(&mut self, max_steps: u32) {
+        self.max_steps = max_steps;
+    }
+
+    /// Accumulates frame time and returns how many fixed steps should be made.
+    ///
+    /// If more than `max_steps` steps are needed, excess time is dropped.
+    ///
+    pub fn advance(&mut self, dt: f32) -> u32 {
+        self.accumulator += dt.max(0.0);
+        let steps: u32 = (self.accumulator / self.step).floor() as u32;
+        if steps > self.max_steps {
+            self.accumulator = 0.0;
+            return self.max_steps;
+        }
+        self.accumulator -= steps as f32 * self.step;
+        steps
+    }
+    /// Returns fraction of a step that is accumulated, but was not simulated yet.
+    ///
+    /// This value lies in [0.0; 1.0) and can be used to interpolate rendered positions between two steps.
+    ///
+    pub fn alpha(&self) -> f32 {
+        (self.accumulator / self.step).clamp(0.0, 1.0)
+    }
+}
+impl Default for FixedTimestep {
+    /// Returns timestep with step of 1/60 of a second.
+    ///
+    fn default() -> Self {
+        FixedTimestep::new(1.0 / 60.0)
+    }
+}
+
+/// [`BodyId`] id struct is needed to identify bodies in [`PhysicsWorld`].
+///
+/// It is assigned by the [`PhysicsWorld`] in which body is registered.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BodyId(u64);
+impl BodyId {
+    /// Returns underlying id.
+    ///
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+/// [`PhysicsWorld`] struct stores rigid bodies together with their transforms and simulates them.
+///
+/// Bodies are iterated in order of their registration, so simulation is deterministic.
+/// Bodies that have colliders attached are checked for collisions after each step - broadphase
+/// ([`SpatialGrid`]) finds pairs of bodies which bounding boxes overlap and narrowphase
+/// ([`Contact::between`]) finds contacts of those pairs. Contacts are generated only for pairs
+/// in which at least one body is dynamic and which colliders can interact according to their layers and masks
+/// and to [`CollisionLayers`] of the world. Found contacts are resolved by [`ContactSolver`].
+///
+/// Contacts with one-way colliders are dropped if they do not block the other body (see [`Collider::blocks`]).
+/// Overlaps with sensor colliders are not treated as contacts - they are tracked separately as triggers.
+/// Changes in contacts and triggers are reported by [`PhysicsEvent`]s, which are queued in the world
+/// until they are taken by [`PhysicsWorld::take_events`].
+///
+/// Body stands on the ground if it is in contact with a body that supports it against gravity
+/// (see [`PhysicsWorld::ground`]). Kinematic bodies carry dynamic bodies that stand on them -
+/// riders receive changes in velocity of their platform along its surface, so they move with
+/// the platform even without friction and keep its momentum when they leave it.
+///
+/// # Example
+/// ```rust
+/// # use ggengine::physicscore::world::{BodyId, PhysicsWorld};
+/// # use ggengine::physicscore::bodies::RigidBody;
+/// # use ggengine::mathcore::transforms::Transform2D;
+/// # use ggengine::mathcore::vectors::Vector2;
+/// let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::from([0.0, 9.8]));
+/// let ball: BodyId = world.add_body(RigidBody::dynamic(1.0), Transform2D::identity());
+/// let ground: BodyId = world.add_body(RigidBody::fixed(), Transform2D::identity());
+///
+/// assert_eq!(world.update(1.0 / 30.0), 2);
+/// assert!(world.transform(ball).expect("Body exists").translation.y > 0.0);
+/// assert_eq!(world.transform(ground).expect("Body exists").translation, Vector2::zero());
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct PhysicsWorld {
+    /// Gravity acceleration.
+    ///
+    pub gravity: Vector2,
+    /// Timestep that splits frame time into fixed steps.
+    ///
+    pub timestep: FixedTimestep,
+    /// Maximal angle between normal of a ground and direction opposite to gravity.
+    ///
+    /// Steeper surfaces are treated as walls.
+    ///
+    pub max_ground_slope: Angle,
+
+    /// Id that will be assigned to the next registered body.
+    ///
+    next_id: u64,
+    /// Registered bodies and their transforms.
+    ///
+    bodies: BTreeMap<BodyId, (RigidBody, Transform2D)>,
+    /// Colliders that are attached to bodies.
+    ///
+    colliders: BTreeMap<BodyId, Collider>,
+    /// Dynamic bodies that stand on kinematic bodies and velocities that they received from them.
+    ///
+    riders: BTreeMap<BodyId, Vector2>,
+
+    /// Interaction matrix of collision layers.
+    ///
+    pub collision_layers: CollisionLayers,
+    /// Broadphase of collision pipeline.
+    ///
+    pub broadphase: SpatialGrid,
+    /// Solver that resolves found contacts.
+    ///
+    pub solver: ContactSolver,
+    /// User callback that can modify or veto contacts.
+    ///
+    contact_filter: Option<ContactFilter>,
+    /// Contacts that were found during the last step.
+    ///
+    contacts: Vec<Contact>,
+    /// Overlaps with sensors that were found during the last step.
+    ///
+    triggers: Vec<Contact>,
+    /// Queue of events that were not taken yet.
+    ///
+    events: Vec<PhysicsEvent>,
+}
+impl PhysicsWorld {
+    /// Constructs empty world with given gravity and default timestep.
+    ///
+    pub fn new(gravity: Vector2) -> Self {
+        PhysicsWorld {
+            gravity,
+            timestep: FixedTimestep::default(),
+            max_ground_slope: Angle::DEG45,
+
+            next_id: 0,
+            bodies: BTreeMap::new(),
+            colliders: BTreeMap::new(),
+            riders: BTreeMap::new(),
+
+            collision_layers: CollisionLayers::default(),
+            broadphase: SpatialGrid::default(),
+            solver: ContactSolver::default(),
+            contact_filter: None,
+            contacts: Vec::new(),
+            triggers: Vec::new(),
+            events: Vec::new(),
+        }
+    }
+
+    /// Registers body in the world and returns its id.
+    ///
+    pub fn add_body(&mut self, body: RigidBody, transform: Transform2D) -> BodyId {
+        let id: BodyId = BodyId(self.next_id);
+        self.next_id += 1;
+        let _ = self.bodies.insert(id, (body, transform));
+        id
+    }
+    /// Removes body from the world, returning it with its transform.
+    ///
+    /// Collider that was attached to the body is removed too (see [`PhysicsWorld::detach_collider`]).
+    ///
+    pub fn remove_body(&mut self, id: BodyId) -> Option<(RigidBody, Transform2D)> {
+        let _ = self.detach_collider(id);
+        let _ = self.riders.remove(&id);
+        self.bodies.remove(&id)
+    }
+    /// Returns whether body with given id is registered in the world.
+    ///
+    pub fn contains_body(&self, id: BodyId) -> bool {
+        self.bodies.contains_key(&id)
+    }
+
+    /// Returns reference to a body.
+    ///
+    pub fn body(&self, id: BodyId) -> Option<&RigidBody> {
+        self.bodies.get(&id).map(|(body, _)| body)
+    }
+    /// Returns mutable reference to a body.
+    ///
+    pub fn body_mut(&mut self, id: BodyId) -> Option<&mut RigidBody> {
+        self.bodies.get_mut(&id).map(|(body, _)| body)
+    }
+    /// Returns reference to a transform of a body.
+    ///
+    pub fn transform(&self, id: BodyId) -> Option<&Transform2D> {
+        self.bodies.get(&id).map(|(_, transform)| transform)
+    }
+    /// Returns mutable reference to a transform of a body.
+    ///
+    pub fn transform_mut(&mut self, id: BodyId) -> Option<&mut Transform2D> {
+        self.bodies.get_mut(&id).map(|(_, transform)| transform)
+    }
+    /// Returns iterator over all registered bodies and their transforms.
+    ///
+    pub fn bodies(&self) -> impl Iterator<Item = (BodyId, &RigidBody, &Transform2D)> {
+        self.bodies
+            .iter()
+            .map(|(id, (body, transform))| (*id, body, transform))
+    }
+
+    /// Attaches collider to a body, returning collider that was previously attached to it.
+    ///
+    /// If there is no body with given id, collider is not attached and `None` is returned.
+    ///
+    pub fn attach_collider(&mut self, id: BodyId, collider: Collider) -> Option<Collider> {
+        if !self.contains_body(id) {
+            return None;
+        }
+        self.colliders.insert(id, collider)
+    }
+    /// Detaches collider from a body and returns it.
+    ///
+    /// Contacts and triggers of the body are forgotten, since body can no longer touch anything,
+    /// and events about their end are queued immediately.
+    ///
+    pub fn detach_collider(&mut self, id: BodyId) -> Option<Collider> {
+        let involves = |contact: &Contact| contact.bodies.0 == id || contact.bodies.1 == id;
+        for contact in self.contacts.iter().filter(|contact| involves(contact)) {
+            self.events.push(PhysicsEvent::CollisionEnded(*contact));
+        }
+        for contact in self.triggers.iter().filter(|contact| involves(contact)) {
+            self.events.push(PhysicsEvent::TriggerExited(*contact));
+        }
+        self.contacts.retain(|contact| !involves(contact));
+        self.triggers.retain(|contact| !involves(contact));
+        let _ = self.riders.remove(&id);
+        self.colliders.remove(&id)
+    }
+    /// Returns reference to a collider of a body.
+    ///
+    pub fn collider(&self, id: BodyId) -> Option<&Collider> {
+        self.colliders.get(&id)
+    }
+    /// Returns mutable reference to a collider of a body.
+    ///
+    pub fn collider_mut(&mut self, id: BodyId) -> Option<&mut Collider> {
+        self.colliders.get_mut(&id)
+    }
+    /// Sets callback that is called for every contact found by narrowphase (see [`ContactFilter`]).
+    ///
+    /// Callback can be used to implement one-way platforms or pass-through between bodies of the same team.
+    ///
+    pub fn set_contact_filter(
+        &mut self,
+        callback: impl Fn(&mut Contact, &Collider, &Collider) -> bool + Send + Sync + 'static,
+    ) {
+        self.contact_filter = Some(ContactFilter::new(callback));
+    }
+    /// Removes contact filter callback.
+    ///
+    pub fn clear_contact_filter(&mut self) {
+        self.contact_filter = None;
+    }
+
+    /// Returns contacts that were found during the last step.
+    ///
+    pub fn contacts(&self) -> &[Contact] {
+        &self.contacts
+    }
+    /// Returns overlaps with sensors that were found during the last step.
+    ///
+    pub fn triggers(&self) -> &[Contact] {
+        &self.triggers
+    }
+
+    /// Returns events that were queued, but were not taken yet.
+    ///
+    pub fn events(&self) -> &[PhysicsEvent] {
+        &self.events
+    }
+    /// Takes all queued events, leaving the queue empty.
+    ///
+    pub fn take_events(&mut self) -> Vec<PhysicsEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Returns unit vector that is opposite to gravity or `None` if there is no gravity.
+    ///
+    pub fn up_direction(&self) -> Option<Vector2> {
+        (-self.gravity).try_normalize()
+    }
+    /// Returns body on which given body stands and normal of the ground (directed from the ground to the body).
+    ///
+    /// Ground is found among contacts of the last step - it is a body whose contact normal deviates from
+    /// [`PhysicsWorld::up_direction`] by no more than [`PhysicsWorld::max_ground_slope`].
+    /// If body stands on several bodies, the flattest one is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::physicscore::world::{BodyId, PhysicsWorld};
+    /// # use ggengine::physicscore::bodies::RigidBody;
+    /// # use ggengine::physicscore::colliders::Collider;
+    /// # use ggengine::mathcore::{transforms::Transform2D, vectors::Vector2};
+    /// let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::from([0.0, 10.0]));
+    /// let ground: BodyId = world.add_body(RigidBody::fixed(), Transform2D::identity());
+    /// let _ = world.attach_collider(ground, Collider::rect(10.0, 1.0));
+    /// let player: BodyId = world.add_body(
+    ///     RigidBody::dynamic(1.0),
+    ///     Transform2D::from_translation(Vector2::from([0.0, -0.9])),
+    /// );
+    /// let _ = world.attach_collider(player, Collider::rect(1.0, 1.0));
+    /// assert!(!world.is_grounded(player));
+    ///
+    /// world.step(0.0);
+    /// assert_eq!(world.ground(player), Some((ground, Vector2::from([0.0, -1.0]))));
+    /// assert!(world.is_grounded(player));
+    /// ```
+    ///
+    pub fn ground(&self, id: BodyId) -> Option<(BodyId, Vector2)> {
+        let up: Vector2 = self.up_direction()?;
+        let min_alignment: f32 = self.max_ground_slope.cos();
+
+        let mut ground: Option<(BodyId, Vector2)> = None;
+        for contact in &self.contacts {
+            let (other, normal): (BodyId, Vector2) = if contact.bodies.1 == id {
+                (contact.bodies.0, contact.normal)
+            } else if contact.bodies.0 == id {
+                (contact.bodies.1, -contact.normal)
+            } else {
+                continue;
+            };
+            let alignment: f32 = normal * up;
+            if alignment >= min_alignment
+                && ground.is_none_or(|(_, ground_normal)| alignment > ground_normal * up)
+            {
+                ground = Some((other, normal));
+            }
+        }
+        ground
+    }
+    /// Returns whether body stands on the ground (see [`PhysicsWorld::ground`]).
+    ///
+    pub fn is_grounded(&self, id: BodyId) -> bool {
+        self.ground(id).is_some()
+    }
+
+    /// Advances simulation by exactly one step of given length.
+    ///
+    /// Riders are carried by their platforms and bodies are integrated first,
+    /// and then contacts are found for their new positions and resolved.
+    ///
+    pub fn step(&mut self, dt: f32) {
+        self.carry_riders();
+        let gravity: Vector2 = self.gravity;
+        for (body, transform) in self.bodies.values_mut() {
+            body.integrate(transform, gravity, dt);
+        }
+        self.find_contacts();
+        self.solver.solve(&self.contacts, &mut self.bodies);
+    }
+    /// Passes changes in velocities of kinematic bodies along their surface to bodies that stand on them.
+    ///
+    fn carry_riders(&mut self) {
+        let mut riders: Vec<(BodyId, Vector2, Vector2)> = Vec::new();
+        for (id, (body, _)) in &self.bodies {
+            if body.body_type() != BodyType::Dynamic {
+                continue;
+            }
+            let Some((platform, normal)) = self.ground(*id) else {
+                continue;
+            };
+            let Some((platform, _)) = self.bodies.get(&platform) else {
+                continue;
+            };
+            if platform.body_type() == BodyType::Kinematic {
+                riders.push((*id, platform.velocity, normal));
+            }
+        }
+
+        let mut carried: BTreeMap<BodyId, Vector2> = BTreeMap::new();
+        for (id, velocity, normal) in riders {
+            let previous: Vector2 = self.riders.get(&id).copied().unwrap_or(Vector2::zero());
+            let tangent: Vector2 = normal.perpendicular();
+            if let Some((body, _)) = self.bodies.get_mut(&id) {
+                body.velocity += tangent * ((velocity - previous) * tangent);
+            }
+            let _ = carried.insert(id, velocity);
+        }
+        // bodies that left their platforms keep velocity that they have received
+        self.riders = carried;
+    }
+    /// Runs collision pipeline, stores found contacts and triggers and queues events about their changes.
+    ///
+    /// Sensors detect bodies of any type except for the static ones, if sensor itself is static.
+    ///
+    fn find_contacts(&mut self) {
+        self.update_broadphase();
+
+        let (mut contacts, mut triggers): (Vec<Contact>, Vec<Contact>) = (Vec::new(), Vec::new());
+        for (a, b) in self.broadphase.pairs() {
+            let ((body1, transform1), (body2, transform2)): (
+                &(RigidBody, Transform2D),
+                &(RigidBody, Transform2D),
+            ) = (&self.bodies[&a], &self.bodies[&b]);
+            let (collider1, collider2): (&Collider, &Collider) =
+                (&self.colliders[&a], &self.colliders[&b]);
+            let is_trigger: bool = collider1.is_sensor || collider2.is_sensor;
+            let skipped: bool = if is_trigger {
+                body1.body_type() == BodyType::Static && body2.body_type() == BodyType::Static
+            } else {
+                body1.body_type() != BodyType::Dynamic && body2.body_type() != BodyType::Dynamic
+            };
+            if skipped
+                || !collider1.interacts_with(collider2)
+                || !self
+                    .collision_layers
+                    .interacts(collider1.layers, collider2.layers)
+            {
+                continue;
+            }
+
+            if let Some(mut contact) =
+                Contact::between((a, collider1, transform1), (b, collider2, transform2))
+            {
+                let relative_velocity: Vector2 = body2.velocity - body1.velocity;
+                let blocked: bool = is_trigger
+                    || (collider1.blocks(contact.normal, relative_velocity)
+                        && collider2.blocks(-contact.normal, -relative_velocity));
+                if !blocked {
+                    continue;
+                }
+                if let Some(filter) = &self.contact_filter {
+                    if !filter.apply(&mut contact, collider1, collider2) {
+                        continue;
+                    }
+                }
+                if is_trigger {
+                    triggers.push(contact);
+                } else {
+                    contacts.push(contact);
+                }
+            }
+        }
+
+        self.events.extend(contact_events(
+            &self.contacts,
+            &contacts,
+            PhysicsEvent::CollisionStarted,
+            PhysicsEvent::CollisionEnded,
+        ));
+        self.events.extend(contact_events(
+            &self.triggers,
+            &triggers,
+            PhysicsEvent::TriggerEntered,
+            PhysicsEvent::TriggerExited,
+        ));
+        (self.contacts, self.triggers) = (contacts, triggers);
+    }
+    /// Registers all colliders in broadphase according to current transforms of bodies.
+    ///
+    /// Broadphase is updated on every step, so this function should be called only if bodies were moved
+    /// or colliders were attached after the last step and queries should take that into account.
+    ///
+    pub fn update_broadphase(&mut self) {
+        self.broadphase.clear();
+        for (id, collider) in &self.colliders {
+            let (_, transform): &(RigidBody, Transform2D) = &self.bodies[id];
+            self.broadphase.insert(*id, collider.aabb(transform));
+        }
+    }
+
+    /// Casts ray from `origin` along `direction` and returns the closest hit.
+    ///
+    /// Length of `direction` is the length of a ray.
+    /// Query uses broadphase that was built on the last step (see [`PhysicsWorld::update_broadphase`]).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ggengine::physicscore::world::{BodyId, PhysicsWorld};
+    /// # use ggengine::physicscore::bodies::RigidBody;
+    /// # use ggengine::physicscore::colliders::Collider;
+    /// # use ggengine::physicscore::queries::{QueryFilter, QueryHit};
+    /// # use ggengine::mathcore::{transforms::Transform2D, vectors::{Point, Vector2}};
+    /// let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::zero());
+    /// let wall: BodyId = world.add_body(
+    ///     RigidBody::fixed(),
+    ///     Transform2D::from_translation(Vector2::from([10.0, 0.0])),
+    /// );
+    /// let _ = world.attach_collider(wall, Collider::rect(2.0, 10.0));
+    /// world.update_broadphase();
+    ///
+    /// let hit: QueryHit = world
+    ///     .raycast(Point::zero(), Vector2::from([20.0, 0.0]), &QueryFilter::default())
+    ///     .expect("Ray hits the wall");
+    /// assert_eq!(hit.body, wall);
+    /// assert_eq!(hit.point, Point::from([9.0, 0.0]));
+    /// assert_eq!(hit.normal, Vector2::from([-1.0, 0.0]));
+    /// assert_eq!(hit.distance, 9.0);
+    /// assert!(world.raycast(Point::zero(), Vector2::from([5.0, 0.0]), &QueryFilter::default()).is_none());
+    /// ```
+    ///
+    pub fn raycast(
+        &self,
+        origin: Point,
+        direction: Vector2,
+        filter: &QueryFilter,
+    ) -> Option<QueryHit> {
+        let max_distance: f32 = direction.magnitude();
+        let unit: Vector2 = direction.try_normalize()?;
+
+        let mut closest: Option<QueryHit> = None;
+        for id in self.broadphase.query(Aabb::new(origin, origin + direction)) {
+            let (Some(collider), Some((_, transform))) =
+                (self.colliders.get(&id), self.bodies.get(&id))
+            else {
+                continue;
+            };
+            if !filter.accepts(id, collider) {
+                continue;
+            }
+
+            let (core, radius): (Vec<Point>, f32) = collider.world_core(transform);
+            if let Some((distance, normal)) =
+                raycast_core((&core, radius), origin, unit, max_distance)
+            {
+                if closest.is_none_or(|hit| distance < hit.distance) {
+                    closest = Some(QueryHit {
+                        body: id,
+                        point: origin + unit * distance,
+                        normal,
+                        distance,
+                    });
+                }
+            }
+        }
+        closest
+    }
+    /// Returns all colliders that overlap given collider placed with given transform, sorted by ids of their bodies.
+    ///
+    /// Layers and mask of given collider are ignored - only the filter is used.
+    /// Query uses broadphase that was built on the last step (see [`PhysicsWorld::update_broadphase`]).
+    ///
+    pub fn shape_overlap(
+        &self,
+        collider: &Collider,
+        transform: &Transform2D,
+        filter: &QueryFilter,
+    ) -> Vec<QueryHit> {
+        let core: (Vec<Point>, f32) = collider.world_core(transform);
+        self.overlap((&core.0, core.1), collider.aabb(transform), filter)
+    }
+    /// Returns all colliders (including sensors) that contain given point, sorted by ids of their bodies.
+    ///
+    /// Query uses broadphase that was built on the last step (see [`PhysicsWorld::update_broadphase`]).
+    ///
+    pub fn point_query(&self, point: Point) -> Vec<QueryHit> {
+        let filter: QueryFilter = QueryFilter {
+            include_sensors: true,
+            ..QueryFilter::default()
+        };
+        self.overlap((&[point], 0.0), Aabb::new(point, point), &filter)
+    }
+    /// Returns all colliders that overlap given rounded core.
+    ///
+    fn overlap(&self, core: (&[Point], f32), aabb: Aabb, filter: &QueryFilter) -> Vec<QueryHit> {
+        self.broadphase
+            .query(aabb)
+            .into_iter()
+            .filter_map(|id| {
+                let (collider, (_, transform)): (&Collider, &(RigidBody, Transform2D)) =
+                    (self.colliders.get(&id)?, self.bodies.get(&id)?);
+                if !filter.accepts(id, collider) {
+                    return None;
+                }
+
+                let other: (Vec<Point>, f32) = collider.world_core(transform);
+                let (normal, depth, point): (Vector2, f32, Point) =
+                    overlap_cores(core, (&other.0, other.1))?;
+                Some(QueryHit {
+                    body: id,
+                    point,
+                    normal,
+                    distance: -depth,
+                })
+            })
+            .collect()
+    }
+
+    /// Accumulates frame time and advances simulation by as many fixed steps as needed.
+    ///
+    /// Returns number of steps that were made.
+    ///
+    pub fn update(&mut self, frame_dt: f32) -> u32 {
+        let steps: u32 = self.timestep.advance(frame_dt);
+        let step: f32 = self.timestep.step();
+        for _ in 0..steps {
+            self.step(step);
+        }
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn fixed_timestep() {
+        use super::FixedTimestep;
+
+        let mut timestep: FixedTimestep = FixedTimestep::new(0.1);
+        assert_eq!(timestep.advance(0.05), 0);
+        assert_eq!(timestep.advance(0.05), 1);
+        assert_eq!(timestep.advance(-1.0), 0);
+
+        timestep.set_max_steps(3);
+        assert_eq!(timestep.advance(10.0), 3);
+        assert_eq!(timestep.alpha(), 0.0);
+        assert_eq!(FixedTimestep::new(f32::NAN).step(), 1.0 / 60.0);
+    }
+
+    #[test]
+    fn physics_world() {
+        use super::{BodyId, PhysicsWorld};
+        use crate::{
+            mathcore::{transforms::Transform2D, vectors::Vector2},
+            physicscore::bodies::RigidBody,
+        };
+
+        let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::from([0.0, 10.0]));
+        let (a, b): (BodyId, BodyId) = (
+            world.add_body(RigidBody::dynamic(1.0), Transform2D::identity()),
+            world.add_body(RigidBody::kinematic(), Transform2D::identity()),
+        );
+        assert_ne!(a, b);
+        world.body_mut(b).expect("Body exists").velocity = Vector2::from([1.0, 0.0]);
+
+        world.step(1.0);
+        assert_eq!(
+            world.transform(a).expect("Body exists").translation,
+            Vector2::from([0.0, 10.0])
+        );
+        assert_eq!(
+            world.transform(b).expect("Body exists").translation,
+            Vector2::from([1.0, 0.0])
+        );
+
+        assert!(world.remove_body(a).is_some());
+        assert!(!world.contains_body(a));
+        assert_eq!(world.bodies().count(), 1);
+    }
+
+    #[test]
+    fn contacts() {
+        use super::{BodyId, PhysicsWorld};
+        use crate::{
+            mathcore::{transforms::Transform2D, vectors::Vector2},
+            physicscore::{bodies::RigidBody, colliders::Collider, layers::CollisionLayers},
+        };
+
+        let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::zero());
+        let ball: BodyId = world.add_body(
+            RigidBody::dynamic(1.0),
+            Transform2D::from_translation(Vector2::from([0.0, -1.5])),
+        );
+        let ground: BodyId = world.add_body(RigidBody::fixed(), Transform2D::identity());
+        let wall: BodyId = world.add_body(
+            RigidBody::fixed(),
+            Transform2D::from_translation(Vector2::from([1.0, 0.0])),
+        );
+        let far: BodyId = world.add_body(
+            RigidBody::dynamic(1.0),
+            Transform2D::from_translation(Vector2::from([1000.0, 0.0])),
+        );
+        assert!(world.attach_collider(ball, Collider::circle(1.0)).is_none());
+        assert!(world
+            .attach_collider(ground, Collider::rect(100.0, 2.0))
+            .is_none());
+        assert!(world
+            .attach_collider(wall, Collider::rect(1.0, 1.0))
+            .is_none());
+        assert!(world.attach_collider(far, Collider::circle(1.0)).is_none());
+
+        world.step(0.0);
+        // static bodies do not collide with each other
+        assert_eq!(world.contacts().len(), 1);
+        assert_eq!(world.contacts()[0].bodies, (ball, ground));
+        assert_eq!(world.contacts()[0].normal, Vector2::from([0.0, 1.0]));
+        assert_eq!(world.contacts()[0].depth, 0.5);
+
+        world.collider_mut(ball).expect("Collider exists").mask = 0;
+        world.step(0.0);
+        assert!(world.contacts().is_empty());
+        world.collider_mut(ball).expect("Collider exists").mask = u32::MAX;
+
+        // balls do not hit ground after interaction of their layers is disabled
+        world.collision_layers = CollisionLayers::new()
+            .with_layer("ground")
+            .with_layer("balls");
+        world.collider_mut(ball).expect("Collider exists").layers = 0b10;
+        world.step(0.0);
+        assert_eq!(world.contacts().len(), 1);
+        assert!(world
+            .collision_layers
+            .set_interaction("balls", "ground", false));
+        world.step(0.0);
+        assert!(world.contacts().is_empty());
+
+        assert!(world.detach_collider(ball).is_some());
+        world.step(0.0);
+        assert!(world.remove_body(far).is_some());
+        assert!(world.collider(far).is_none());
+        assert!(world.attach_collider(far, Collider::circle(1.0)).is_none());
+        assert!(world.collider(far).is_none());
+    }
+
+    #[test]
+    fn queries() {
+        use super::{BodyId, PhysicsWorld};
+        use crate::{
+            mathcore::{
+                floats::FloatOperations,
+                transforms::Transform2D,
+                vectors::{Point, Vector2},
+            },
+            physicscore::{
+                bodies::RigidBody,
+                colliders::Collider,
+                queries::{QueryFilter, QueryHit},
+            },
+        };
+
+        let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::zero());
+        let player: BodyId = world.add_body(RigidBody::dynamic(1.0), Transform2D::identity());
+        let enemy: BodyId = world.add_body(
+            RigidBody::dynamic(1.0),
+            Transform2D::from_translation(Vector2::from([10.0, 0.0])),
+        );
+        let pillar: BodyId = world.add_body(
+            RigidBody::fixed(),
+            Transform2D::from_translation(Vector2::from([5.0, 3.0])),
+        );
+        let zone: BodyId = world.add_body(
+            RigidBody::fixed(),
+            Transform2D::from_translation(Vector2::from([5.0, 0.0])),
+        );
+        let _ = world.attach_collider(player, Collider::circle(1.0));
+        let _ = world.attach_collider(enemy, Collider::circle(2.0));
+        let _ = world.attach_collider(pillar, Collider::capsule(1.0, 0.5));
+        let mut sensor: Collider = Collider::rect(1.0, 1.0);
+        sensor.is_sensor = true;
+        let _ = world.attach_collider(zone, sensor);
+        world.update_broadphase();
+
+        // line of sight from player to enemy ignores player itself and sensors
+        let filter: QueryFilter = QueryFilter {
+            exclude: Some(player),
+            ..QueryFilter::default()
+        };
+        let hit: QueryHit = world
+            .raycast(Point::zero(), Vector2::from([20.0, 0.0]), &filter)
+            .expect("Ray hits enemy");
+        assert_eq!(hit.body, enemy);
+        assert_eq!(hit.distance.round_up_to(3), 8.0);
+        assert_eq!(hit.normal.round_up_to(3), Vector2::from([-1.0, 0.0]));
+        // ray that starts inside a collider hits it immediately
+        let hit: QueryHit = world
+            .raycast(
+                Point::zero(),
+                Vector2::from([20.0, 0.0]),
+                &QueryFilter::default(),
+            )
+            .expect("Ray hits player");
+        assert_eq!((hit.body, hit.distance), (player, 0.0));
+        // ray that hits side of a capsule
+        let hit: QueryHit = world
+            .raycast(
+                Point::from([5.0, 10.0]),
+                Vector2::from([0.0, -10.0]),
+                &filter,
+            )
+            .expect("Ray hits pillar");
+        assert_eq!(hit.body, pillar);
+        assert_eq!(hit.point.round_up_to(3), Point::from([5.0, 3.5]));
+        assert_eq!(hit.normal.round_up_to(3), Vector2::from([0.0, 1.0]));
+
+        // area damage
+        let blast: Vec<BodyId> = world
+            .shape_overlap(
+                &Collider::circle(3.0),
+                &Transform2D::from_translation(Vector2::from([6.0, 0.0])),
+                &QueryFilter::default(),
+            )
+            .into_iter()
+            .map(|hit| hit.body)
+            .collect();
+        assert_eq!(blast, vec![enemy, pillar]);
+
+        // mouse picking
+        let picked: Vec<BodyId> = world
+            .point_query(Point::from([5.2, 0.0]))
+            .into_iter()
+            .map(|hit| hit.body)
+            .collect();
+        assert_eq!(picked, vec![zone]);
+        assert!(world.point_query(Point::from([-5.0, -5.0])).is_empty());
+    }
+
+    #[test]
+    fn contact_filter() {
+        use super::{BodyId, PhysicsWorld};
+        use crate::{
+            mathcore::{transforms::Transform2D, vectors::Vector2},
+            physicscore::{
+                bodies::RigidBody,
+                colliders::Collider,
+                contacts::Contact,
+                materials::{CombineRule, PhysicsMaterial},
+            },
+        };
+
+        let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::zero());
+        let platform: BodyId = world.add_body(RigidBody::fixed(), Transform2D::identity());
+        let mut collider: Collider = Collider::rect(10.0, 1.0);
+        collider.layers = 0b10;
+        collider.material = PhysicsMaterial {
+            friction_combine: CombineRule::Maximum,
+            ..PhysicsMaterial::new(0.9, 0.0)
+        };
+        let _ = world.attach_collider(platform, collider);
+        let player: BodyId = world.add_body(
+            RigidBody::dynamic(1.0),
+            Transform2D::from_translation(Vector2::from([0.0, -0.75])),
+        );
+        let _ = world.attach_collider(player, Collider::rect(1.0, 1.0));
+
+        world.step(0.0);
+        assert_eq!(world.contacts().len(), 1);
+        assert_eq!(world.contacts()[0].friction, 0.9);
+
+        // one-way platform: player that is below it passes through it
+        world.set_contact_filter(
+            |contact: &mut Contact, collider1: &Collider, _: &Collider| {
+                contact.friction = 0.0;
+                // normal is directed from platform to player, and up is negative y
+                collider1.layers != 0b10 || contact.normal.y < 0.0
+            },
+        );
+        world.step(0.0);
+        assert_eq!(world.contacts().len(), 1);
+        assert_eq!(world.contacts()[0].friction, 0.0);
+        let _ = world.transform_mut(player).map(|transform| {
+            transform.translation = Vector2::from([0.0, 0.75]);
+        });
+        world.step(0.0);
+        assert!(world.contacts().is_empty());
+
+        world.clear_contact_filter();
+        world.step(0.0);
+        assert_eq!(world.contacts().len(), 1);
+    }
+
+    #[test]
+    fn platforms() {
+        use super::{BodyId, PhysicsWorld};
+        use crate::{
+            mathcore::{floats::FloatOperations, transforms::Transform2D, vectors::Vector2},
+            physicscore::{bodies::RigidBody, colliders::Collider, materials::PhysicsMaterial},
+        };
+
+        let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::from([0.0, 10.0]));
+        let platform: BodyId = world.add_body(RigidBody::fixed(), Transform2D::identity());
+        let mut collider: Collider = Collider::rect(10.0, 1.0);
+        collider.one_way = Some(Vector2::from([0.0, -1.0]));
+        let _ = world.attach_collider(platform, collider);
+        let player: BodyId = world.add_body(
+            RigidBody::dynamic(1.0),
+            Transform2D::from_translation(Vector2::from([0.0, 0.75])),
+        );
+        let _ = world.attach_collider(player, Collider::rect(1.0, 1.0));
+
+        // player that is below one-way platform or jumps through it is not blocked
+        world.step(0.0);
+        assert!(world.contacts().is_empty());
+        let _ = world.transform_mut(player).map(|transform| {
+            transform.translation = Vector2::from([0.0, -0.75]);
+        });
+        world.body_mut(player).expect("Body exists").velocity = Vector2::from([0.0, -5.0]);
+        world.step(0.0);
+        assert!(world.contacts().is_empty());
+        assert!(!world.is_grounded(player));
+        // player that stands on it is blocked
+        world.body_mut(player).expect("Body exists").velocity = Vector2::zero();
+        world.step(0.0);
+        assert_eq!(
+            world.ground(player),
+            Some((platform, Vector2::from([0.0, -1.0])))
+        );
+
+        // moving platform carries rider that has no friction
+        let elevator: BodyId = world.add_body(
+            RigidBody::kinematic(),
+            Transform2D::from_translation(Vector2::from([100.0, 0.0])),
+        );
+        let _ = world.attach_collider(elevator, Collider::rect(10.0, 1.0));
+        world.body_mut(elevator).expect("Body exists").velocity = Vector2::from([2.0, 0.0]);
+        let rider: BodyId = world.add_body(
+            RigidBody::dynamic(1.0),
+            Transform2D::from_translation(Vector2::from([100.0, -1.0])),
+        );
+        let mut collider: Collider = Collider::rect(1.0, 1.0);
+        collider.material = PhysicsMaterial::new(0.0, 0.0);
+        let _ = world.attach_collider(rider, collider);
+
+        for _ in 0..10 {
+            world.step(0.1);
+        }
+        assert_eq!(
+            world.ground(rider).map(|(ground, _)| ground),
+            Some(elevator)
+        );
+        assert_eq!(world.body(rider).expect("Body exists").velocity.x, 2.0);
+        // rider starts moving on the step after landing
+        assert_eq!(
+            world
+                .transform(rider)
+                .expect("Body exists")
+                .translation
+                .x
+                .round_up_to(3),
+            101.8
+        );
+
+        world.body_mut(elevator).expect("Body exists").velocity = Vector2::zero();
+        world.step(0.1);
+        assert_eq!(world.body(rider).expect("Body exists").velocity.x, 0.0);
+
+        // removed platform is forgotten together with its contacts
+        assert!(world.remove_body(elevator).is_some());
+        assert!(world
+            .contacts()
+            .iter()
+            .all(|contact| contact.bodies.0 != elevator && contact.bodies.1 != elevator));
+        assert_eq!(world.ground(rider), None);
+        world.step(0.1);
+        let _ = world.detach_collider(player);
+        assert_eq!(world.ground(player), None);
+    }
+
+    #[test]
+    fn events() {
+        use super::{BodyId, PhysicsWorld};
+        use crate::{
+            mathcore::{transforms::Transform2D, vectors::Vector2},
+            physicscore::{bodies::RigidBody, colliders::Collider, events::PhysicsEvent},
+        };
+
+        let mut world: PhysicsWorld = PhysicsWorld::new(Vector2::zero());
+        let ball: BodyId = world.add_body(RigidBody::dynamic(1.0), Transform2D::identity());
+        let wall: BodyId = world.add_body(
+            RigidBody::fixed(),
+            Transform2D::from_translation(Vector2::from([3.0, 0.0])),
+        );
+        let zone: BodyId = world.add_body(
+            RigidBody::fixed(),
+            Transform2D::from_translation(Vector2::from([8.0, 0.0])),
+        );
+        let _ = world.attach_collider(ball, Collider::circle(1.0));
+        let _ = world.attach_collider(wall, Collider::rect(1.0, 1.0));
+        let mut sensor: Collider = Collider::rect(2.0, 2.0);
+        sensor.is_sensor = true;
+        let _ = world.attach_collider(zone, sensor);
+
+        let mut events: Vec<PhysicsEvent> = Vec::new();
+        for _ in 0..15 {
+            world.body_mut(ball).expect("Body exists").velocity = Vector2::from([1.0, 0.0]);
+            world.step(1.0);
+            events.extend(world.take_events());
+            assert!(world.events().is_empty());
+
+            // wall is removed after being hit, so ball can pass through the sensor
+            if !events.is_empty() && world.contains_body(wall) {
+                assert!(world.remove_body(wall).is_some());
+            }
+        }
+        let kinds: Vec<(u8, (BodyId, BodyId))> = events
+            .iter()
+            .map(|event| {
+                let kind: u8 = match event {
+                    PhysicsEvent::CollisionStarted(_) => 0,
+                    PhysicsEvent::CollisionEnded(_) => 1,
+                    PhysicsEvent::TriggerEntered(_) => 2,
+                    PhysicsEvent::TriggerExited(_) => 3,
+                };
+                (kind, event.bodies())
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (0, (ball, wall)),
+                (1, (ball, wall)),
+                (2, (ball, zone)),
+                (3, (ball, zone)),
+            ]
+        );
+        assert!(world.contacts().is_empty() && world.triggers().is_empty());
+    }
+}
@@ -6,14 +6,21 @@
 
 use crate::{
     datacore::images::{Image, PixelFormat},
-    mathcore::vectors::Vector2Int,
-    GGEngine,
+    mathcore::{
+        shapes::Aabb,
+        vectors::{Point, Vector2Int},
+    },
+    GGEngine, GGError,
 };
-use sdl2::video::{
-    DisplayMode as SdlDisplayMode, FlashOperation as SdlFlashOperation,
-    FullscreenType as SdlFullscreenType, Window as SdlWindow, WindowBuilder as SdlWindowBuilder,
-    WindowPos as SdlWindowPos,
+use sdl2::{
+    rect::Rect as SdlRect,
+    video::{
+        DisplayMode as SdlDisplayMode, FlashOperation as SdlFlashOperation,
+        FullscreenType as SdlFullscreenType, Window as SdlWindow,
+        WindowBuilder as SdlWindowBuilder, WindowPos as SdlWindowPos,
+    },
 };
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// [`Position`] enum encapsulates possible position settings.
@@ -33,7 +40,7 @@ pub enum Position {
 ///
 /// Example of usage is shown in [`WindowSettings`] docs.
 ///
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum FullscreenType {
     /// Fullscreen is a real fullscreen mode - OS changes video mode for your application, but
     /// tabbing to another program might switch video mode back.
@@ -215,11 +222,15 @@ impl Ping {
 impl GGEngine {
     /// Builds window with given settings.
     ///
+    /// Error is returned if window cannot be built (e.g. title contains incompatible symbols or given size is too big).
+    ///
     /// # Example
     /// ```rust, no_run
     /// # use ggengine::{GGEngine, utils::Window};
-    /// let engine: GGEngine = GGEngine::init();
-    /// let window: Window = engine.build_window("GGENGINE", 1600, 900, Default::default());
+    /// let engine: GGEngine = GGEngine::init().expect("Engine should be initialized");
+    /// let window: Window = engine
+    ///     .build_window("GGENGINE", 1600, 900, Default::default())
+    ///     .expect("Window should be built");
     /// ```
     ///
     pub fn build_window(
@@ -228,13 +239,13 @@ impl GGEngine {
         width: u32,
         height: u32,
         window_settings: WindowSettings,
-    ) -> Window {
-        Window {
+    ) -> Result<Window, GGError> {
+        Ok(Window {
             window: window_settings
                 .apply_to_builder(&mut self.get_sdl_videosubsystem().window(title, width, height))
                 .build()
-                .expect("`ggengine` should be able to build a window (maybe incompatible symbols are given or given size is too big)"),
-        }
+                .map_err(|error| GGError::Sdl(error.to_string()))?,
+        })
     }
 }
 /// [`Window`] struct represents the shell of OS window.
@@ -246,8 +257,10 @@ impl GGEngine {
 /// # Example
 /// ```rust, no_run
 /// # use ggengine::{GGEngine, utils::Window};
-/// let engine: GGEngine = GGEngine::init();
-/// let window: Window = engine.build_window("GGENGINE", 1600, 900, Default::default());
+/// let engine: GGEngine = GGEngine::init().expect("Engine should be initialized");
+/// let window: Window = engine
+///     .build_window("GGENGINE", 1600, 900, Default::default())
+///     .expect("Window should be built");
 /// ```
 ///
 pub struct Window {
@@ -277,27 +290,27 @@ impl Window {
 
     /// Sets new refresh rate to the window.
     ///
-    pub fn set_refresh_rate(&mut self, refresh_rate: u16) {
+    pub fn set_refresh_rate(&mut self, refresh_rate: u16) -> Result<(), GGError> {
         self.window
             .set_display_mode(Some(SdlDisplayMode {
                 refresh_rate: i32::from(refresh_rate),
-                ..self
-                    .window
-                    .display_mode()
-                    .expect("`ggengine` should be able to get display mode")
+                ..self.window.display_mode().map_err(GGError::Sdl)?
             }))
-            .expect("`ggengine` should be able to set new refresh rate to window")
+            .map_err(GGError::Sdl)
     }
     /// Returns current refresh rate of the window.
     ///
-    pub fn refresh_rate(&self) -> u16 {
-        u16::try_from(
-            self.window
-                .display_mode()
-                .expect("`ggengine` should be able to get display mode")
-                .refresh_rate,
-        )
-        .expect("Conversion should not fail")
+    /// Error is returned if display mode cannot be obtained or if its refresh rate does not fit into `u16`.
+    ///
+    pub fn refresh_rate(&self) -> Result<u16, GGError> {
+        let refresh_rate: i32 = self
+            .window
+            .display_mode()
+            .map_err(GGError::Sdl)?
+            .refresh_rate;
+        u16::try_from(refresh_rate).map_err(|_| {
+            GGError::InvalidState(format!("Refresh rate {refresh_rate} is out of range"))
+        })
     }
 
     /// Sets new pixel format for the window.
@@ -305,36 +318,33 @@ impl Window {
     /// You should call this function only if you really know what you are doing -
     /// default pixel format for window is the most optimised, so you can degrade performance severely.
     ///
-    pub fn set_pixel_format(&mut self, pixel_format: PixelFormat) {
+    pub fn set_pixel_format(&mut self, pixel_format: PixelFormat) -> Result<(), GGError> {
         self.window
             .set_display_mode(Some(SdlDisplayMode {
                 format: PixelFormat::to_sdl_pixel_format_enum(pixel_format),
-                ..self
-                    .window
-                    .display_mode()
-                    .expect("`ggengine` should be able to get display mode")
+                ..self.window.display_mode().map_err(GGError::Sdl)?
             }))
-            .expect("`ggengine` should be able to set pixel format to window")
+            .map_err(GGError::Sdl)
     }
     /// Returns window's pixel format or `None`, if format wasn't recognised.
     ///
     /// Even if format was not recognised, all `Window` methods would still work.
+    /// Error is returned if display mode cannot be obtained.
     ///
-    pub fn pixel_format(&self) -> Option<PixelFormat> {
-        PixelFormat::from_sdl_pixel_format_enum(
-            self.window
-                .display_mode()
-                .expect("`ggengine` should be able to get display mode")
-                .format,
-        )
+    pub fn pixel_format(&self) -> Result<Option<PixelFormat>, GGError> {
+        Ok(PixelFormat::from_sdl_pixel_format_enum(
+            self.window.display_mode().map_err(GGError::Sdl)?.format,
+        ))
     }
 
     /// Sets new title for the window.
     ///
-    pub fn set_title(&mut self, title: &str) {
-        self.window.set_title(title).expect(
-            "`ggengine` should be able to rename title (maybe incompatible symbols are used)",
-        );
+    /// Error is returned if title contains incompatible symbols.
+    ///
+    pub fn set_title(&mut self, title: &str) -> Result<(), GGError> {
+        self.window
+            .set_title(title)
+            .map_err(|error| GGError::InvalidArgument(error.to_string()))
     }
     /// Returns window's title.
     ///
@@ -368,10 +378,10 @@ impl Window {
 
     /// Sets new size for the window.
     ///
-    pub fn set_size(&mut self, width: u32, height: u32) {
+    pub fn set_size(&mut self, width: u32, height: u32) -> Result<(), GGError> {
         self.window
             .set_size(width, height)
-            .expect("`ggengine` should be able to resize window (maybe given size is too big)");
+            .map_err(|error| GGError::InvalidArgument(error.to_string()))
     }
     /// Returns current window size.
     ///
@@ -387,8 +397,10 @@ impl Window {
 
     /// Sets minimal possible size for the window.
     ///
-    pub fn set_minimal_size(&mut self, width: u32, height: u32) {
-        self.window.set_minimum_size(width, height).expect("`ggengine` should be able to set minimal size for window (maybe given size is too big)");
+    pub fn set_minimal_size(&mut self, width: u32, height: u32) -> Result<(), GGError> {
+        self.window
+            .set_minimum_size(width, height)
+            .map_err(|error| GGError::InvalidArgument(error.to_string()))
     }
     /// Returns window's minimal size.
     ///
@@ -408,8 +420,10 @@ impl Window {
 
     /// Sets maximal possible size for the window.
     ///
-    pub fn set_maximal_size(&mut self, width: u32, height: u32) {
-        self.window.set_maximum_size(width, height).expect("`ggengine` should be able to set maximal size for window (maybe given size is too big)");
+    pub fn set_maximal_size(&mut self, width: u32, height: u32) -> Result<(), GGError> {
+        self.window
+            .set_maximum_size(width, height)
+            .map_err(|error| GGError::InvalidArgument(error.to_string()))
     }
     /// Returns window's maximal size.
     ///
@@ -431,10 +445,13 @@ impl Window {
     ///
     /// If `None` is passed, disables current fullscreen type.
     ///
-    pub fn set_fullscreen_type(&mut self, fullscreen_type: Option<FullscreenType>) {
+    pub fn set_fullscreen_type(
+        &mut self,
+        fullscreen_type: Option<FullscreenType>,
+    ) -> Result<(), GGError> {
         self.window
             .set_fullscreen(FullscreenType::to_sdl_fullscreen_type(fullscreen_type))
-            .expect("`ggengine` should be able to set fullscreen type");
+            .map_err(GGError::Sdl)
     }
     /// Returns current window fullscreen type.
     ///
@@ -458,10 +475,10 @@ impl Window {
 
     /// Requests a window to demand attention from the user by pinging.
     ///
-    pub fn window_pinging(&mut self, ping: Option<Ping>) {
+    pub fn window_pinging(&mut self, ping: Option<Ping>) -> Result<(), GGError> {
         self.window
             .flash(Ping::to_sdl_flash_operation(ping))
-            .expect("`ggengine` should be able to ping window")
+            .map_err(GGError::Sdl)
     }
 
     /// Grabs keyboard focus to the window if `true` is passed.
@@ -487,6 +504,50 @@ impl Window {
     pub fn is_mouse_grabbed(&self) -> bool {
         self.window.mouse_grab()
     }
+
+    /// Enables relative mouse mode if `true` is passed (`false` disables it).
+    ///
+    /// In relative mouse mode cursor is hidden and stays in place, but movement of the mouse is still reported
+    /// (see [`Mouse`](crate::inputcore::mouse::Mouse)), which is needed for drag-to-look controls.
+    ///
+    pub fn set_relative_mouse_mode(&mut self, enabled: bool) {
+        self.window
+            .subsystem()
+            .sdl()
+            .mouse()
+            .set_relative_mouse_mode(enabled);
+    }
+    /// Returns whether relative mouse mode is enabled.
+    ///
+    pub fn is_relative_mouse_mode(&self) -> bool {
+        self.window.subsystem().sdl().mouse().relative_mouse_mode()
+    }
+    /// Confines cursor to the area of the window (`None` removes confinement).
+    ///
+    /// Area is given in window coordinates and is truncated towards integers.
+    ///
+    pub fn confine_cursor(&mut self, area: Option<Aabb>) -> Result<(), GGError> {
+        self.window
+            .set_mouse_rect(area.map(|area| {
+                SdlRect::new(
+                    area.min.x as i32,
+                    area.min.y as i32,
+                    area.width() as u32,
+                    area.height() as u32,
+                )
+            }))
+            .map_err(GGError::Sdl)
+    }
+    /// Returns area of the window to which cursor is confined.
+    ///
+    pub fn cursor_confinement(&self) -> Option<Aabb> {
+        self.window.mouse_rect().map(|rect| {
+            Aabb::new(
+                Point::from([rect.x() as f32, rect.y() as f32]),
+                Point::from([rect.right() as f32, rect.bottom() as f32]),
+            )
+        })
+    }
 }
 impl fmt::Debug for Window {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {